@@ -0,0 +1,267 @@
+//! Append-only activity log of completed package operations.
+//!
+//! Each completed install, removal, downgrade, or system update appends one JSON object per line
+//! to `lists_dir/activity_log.jsonl` (oldest first). After appending, the file is trimmed to the
+//! newest `activity_log_max_lines` entries so it cannot grow without bound.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Kind of operation recorded in the activity log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityAction {
+    /// Packages were installed.
+    Install,
+    /// Packages were removed.
+    Remove,
+    /// Packages were downgraded.
+    Downgrade,
+    /// A system update ran.
+    Update,
+}
+
+impl ActivityAction {
+    /// What: Stable lowercase name of the action.
+    ///
+    /// Inputs: None.
+    ///
+    /// Output:
+    /// - `"install"`, `"remove"`, `"downgrade"`, or `"update"` (matches the on-disk value).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Install => "install",
+            Self::Remove => "remove",
+            Self::Downgrade => "downgrade",
+            Self::Update => "update",
+        }
+    }
+}
+
+/// One line of the activity log.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ActivityEntry {
+    /// Unix timestamp (seconds) when the operation completed.
+    pub timestamp: i64,
+    /// Operation that completed.
+    pub action: ActivityAction,
+    /// Package names affected (the pending update list for system updates; may be empty).
+    #[serde(default)]
+    pub packages: Vec<String>,
+}
+
+/// What: Location of the activity log.
+///
+/// Inputs: None.
+///
+/// Output:
+/// - `lists_dir/activity_log.jsonl`.
+#[must_use]
+pub fn activity_log_path() -> PathBuf {
+    crate::theme::lists_dir().join("activity_log.jsonl")
+}
+
+/// What: Append one entry and trim the log to its newest lines.
+///
+/// Inputs:
+/// - `path`: Log file (created when missing).
+/// - `entry`: Entry to append.
+/// - `max_lines`: Maximum number of entries kept; `0` disables trimming.
+///
+/// Output:
+/// - `Ok(())` on success, or the serialization/I/O error.
+///
+/// # Errors
+/// - Returns `Err` when the entry cannot be serialized or the file cannot be written.
+///
+/// Details:
+/// - Trimming rewrites the file through a temporary sibling and a rename, so a crash mid-trim
+///   leaves either the old or the new log, never a partial one.
+pub fn append_activity_entry(
+    path: &Path,
+    entry: &ActivityEntry,
+    max_lines: usize,
+) -> std::io::Result<()> {
+    let line = serde_json::to_string(entry).map_err(std::io::Error::other)?;
+    {
+        let mut f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(f, "{line}")?;
+    }
+    if max_lines == 0 {
+        return Ok(());
+    }
+    let content = fs::read_to_string(path)?;
+    let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    if lines.len() <= max_lines {
+        return Ok(());
+    }
+    let mut kept = lines[lines.len() - max_lines..].join("\n");
+    kept.push('\n');
+    let tmp = path.with_extension("jsonl.tmp");
+    fs::write(&tmp, kept)?;
+    fs::rename(&tmp, path)
+}
+
+/// What: Read all entries from an activity log file.
+///
+/// Inputs:
+/// - `path`: Log file.
+///
+/// Output:
+/// - Entries in file order (oldest first); empty when the file is missing.
+///
+/// Details:
+/// - Lines that fail to parse are skipped so one damaged line does not hide the rest.
+#[must_use]
+pub fn read_activity_log(path: &Path) -> Vec<ActivityEntry> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| match serde_json::from_str(l) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                tracing::debug!(error = %e, "[Activity] Skipping unreadable activity log line");
+                None
+            }
+        })
+        .collect()
+}
+
+/// What: Read the activity log from its default location.
+///
+/// Inputs: None.
+///
+/// Output:
+/// - Entries oldest first (see [`read_activity_log`]).
+#[must_use]
+pub fn activity_log() -> Vec<ActivityEntry> {
+    read_activity_log(&activity_log_path())
+}
+
+/// What: Record a completed operation in the activity log.
+///
+/// Inputs:
+/// - `action`: Operation that completed.
+/// - `packages`: Affected package names.
+///
+/// Output:
+/// - None; write failures are logged and otherwise ignored.
+///
+/// Details:
+/// - Stamps the entry with the current time and honours the `activity_log_max_lines` setting.
+pub fn record_activity(action: ActivityAction, packages: &[String]) {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .and_then(|d| i64::try_from(d.as_secs()).ok())
+        .unwrap_or(0);
+    let entry = ActivityEntry {
+        timestamp,
+        action,
+        packages: packages.to_vec(),
+    };
+    let path = activity_log_path();
+    let max_lines = crate::theme::settings().activity_log_max_lines;
+    if let Err(e) = append_activity_entry(&path, &entry, max_lines) {
+        tracing::warn!(
+            path = %path.display(),
+            error = %e,
+            "[Activity] Failed to append activity log entry"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Build an entry for activity log tests.
+    ///
+    /// Inputs:
+    /// - `timestamp`: Entry timestamp
+    /// - `action`: Recorded action
+    /// - `name`: Single package name
+    ///
+    /// Output:
+    /// - Entry with one package.
+    fn entry(timestamp: i64, action: ActivityAction, name: &str) -> ActivityEntry {
+        ActivityEntry {
+            timestamp,
+            action,
+            packages: vec![name.to_string()],
+        }
+    }
+
+    #[test]
+    /// What: Verify appended entries read back in order with their actions.
+    ///
+    /// Inputs:
+    /// - Install and remove entries appended to a fresh file, plus a corrupt line.
+    ///
+    /// Output:
+    /// - Both entries are returned oldest first; the corrupt line is skipped and the on-disk
+    ///   action is the lowercase name.
+    fn append_and_read_back_round_trip() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("activity_log.jsonl");
+        assert!(read_activity_log(&path).is_empty());
+
+        append_activity_entry(&path, &entry(1, ActivityAction::Install, "ripgrep"), 0)
+            .expect("append install");
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut f| writeln!(f, "not json"))
+            .expect("append garbage");
+        append_activity_entry(&path, &entry(2, ActivityAction::Remove, "fd"), 0)
+            .expect("append remove");
+
+        let entries = read_activity_log(&path);
+        assert_eq!(
+            entries,
+            vec![
+                entry(1, ActivityAction::Install, "ripgrep"),
+                entry(2, ActivityAction::Remove, "fd"),
+            ]
+        );
+        let raw = fs::read_to_string(&path).expect("read log");
+        assert!(raw.contains("\"action\":\"install\""), "raw log: {raw}");
+    }
+
+    #[test]
+    /// What: Verify the log is trimmed to the newest `max_lines` entries.
+    ///
+    /// Inputs:
+    /// - Five downgrade/update entries appended with `max_lines = 3`.
+    ///
+    /// Output:
+    /// - Only the last three entries remain, oldest first, and no temporary file is left.
+    fn append_trims_to_max_lines() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("activity_log.jsonl");
+        for ts in 1..=5 {
+            let action = if ts % 2 == 0 {
+                ActivityAction::Update
+            } else {
+                ActivityAction::Downgrade
+            };
+            append_activity_entry(&path, &entry(ts, action, "glibc"), 3).expect("append");
+        }
+
+        let timestamps: Vec<i64> = read_activity_log(&path)
+            .iter()
+            .map(|e| e.timestamp)
+            .collect();
+        assert_eq!(timestamps, vec![3, 4, 5]);
+        assert_eq!(fs::read_to_string(&path).expect("read").lines().count(), 3);
+        assert!(!path.with_extension("jsonl.tmp").exists());
+    }
+}
@@ -145,6 +145,8 @@ mod tests {
             depends_on: Vec::new(),
             is_core: true,
             is_system: false,
+            optional: false,
+            description: None,
         }]
     }
 
@@ -3,6 +3,8 @@
 //! This module organizes the TUI runtime into smaller files to improve
 //! maintainability and keep individual files under 500 lines.
 
+/// Append-only log of completed install/remove/downgrade/update operations.
+mod activity;
 /// Shared helpers for the signature-validated install-list caches.
 mod cache_common;
 /// Dependency cache for storing resolved dependency information.
@@ -20,8 +22,13 @@ pub mod services_cache;
 /// Terminal setup and restoration utilities.
 pub mod terminal;
 
-// Re-export the public entrypoint so callers keep using `app::run(...)`.
-pub use runtime::run;
+// Re-export the public entrypoints so callers keep using `app::run(...)`.
+pub use runtime::{run, search_once};
+
+pub use activity::{
+    ActivityAction, ActivityEntry, activity_log, activity_log_path, append_activity_entry,
+    read_activity_log, record_activity,
+};
 
 // Re-export functions needed by event handlers
 pub use runtime::init::{apply_settings_to_app_state, initialize_locale_system};
@@ -1,10 +1,103 @@
+use std::borrow::Cow;
 use std::fs;
+use std::path::Path;
 
 use super::deps_cache;
 use super::files_cache;
 use super::sandbox_cache;
 use super::services_cache;
 use crate::state::AppState;
+use crate::state::types::NewsFeedItem;
+
+/// Schema version of the news feed cache; bump whenever [`NewsFeedCache`] changes shape.
+pub const NEWS_FEED_CACHE_VERSION: u32 = 1;
+
+/// On-disk news feed cache written after every fetch and read at startup.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NewsFeedCache<'a> {
+    /// Schema version; caches with any other version are discarded.
+    version: u32,
+    /// Unix timestamp (seconds) of the fetch that produced `items`.
+    fetched_at: i64,
+    /// Fetched news feed items.
+    items: Cow<'a, [NewsFeedItem]>,
+}
+
+/// What: Write the news feed cache.
+///
+/// Inputs:
+/// - `path`: Cache file path
+/// - `items`: Feed items to store
+/// - `fetched_at`: Unix timestamp (seconds) of the fetch
+///
+/// Output:
+/// - `Ok(())` on success, or the serialization/write error.
+pub fn save_news_feed_cache(
+    path: &Path,
+    items: &[NewsFeedItem],
+    fetched_at: i64,
+) -> std::io::Result<()> {
+    let cache = NewsFeedCache {
+        version: NEWS_FEED_CACHE_VERSION,
+        fetched_at,
+        items: Cow::Borrowed(items),
+    };
+    let s = serde_json::to_string(&cache).map_err(std::io::Error::other)?;
+    fs::write(path, s)
+}
+
+/// What: Read the news feed cache.
+///
+/// Inputs:
+/// - `path`: Cache file path
+///
+/// Output:
+/// - `Some((items, fetched_at))` for a readable cache of the current schema version; `None` when
+///   the file is missing, unparsable, or written with another version (including the legacy
+///   unversioned item array).
+#[must_use]
+pub fn load_news_feed_cache(path: &Path) -> Option<(Vec<NewsFeedItem>, i64)> {
+    let s = fs::read_to_string(path).ok()?;
+    let cache: NewsFeedCache<'static> = match serde_json::from_str(&s) {
+        Ok(cache) => cache,
+        Err(e) => {
+            tracing::debug!(path = %path.display(), error = %e, "[Persist] Discarding unreadable news feed cache");
+            return None;
+        }
+    };
+    if cache.version != NEWS_FEED_CACHE_VERSION {
+        tracing::debug!(
+            path = %path.display(),
+            version = cache.version,
+            expected = NEWS_FEED_CACHE_VERSION,
+            "[Persist] Discarding news feed cache with stale schema version"
+        );
+        return None;
+    }
+    Some((cache.items.into_owned(), cache.fetched_at))
+}
+
+/// What: Persist the current news feed to its cache file.
+///
+/// Inputs:
+/// - `app`: Application state containing `news_items` and `news_feed_path`
+///
+/// Output:
+/// - Writes the versioned cache stamped with the current time; failures are logged.
+pub fn persist_news_feed(app: &AppState) {
+    let fetched_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .and_then(|d| i64::try_from(d.as_secs()).ok())
+        .unwrap_or(0);
+    if let Err(e) = save_news_feed_cache(&app.news_feed_path, &app.news_items, fetched_at) {
+        tracing::warn!(
+            path = %app.news_feed_path.display(),
+            error = %e,
+            "[Persist] Failed to write news feed cache"
+        );
+    }
+}
 
 /// What: Persist the details cache to disk if marked dirty.
 ///
@@ -50,6 +143,7 @@ pub fn maybe_flush_cache(app: &mut AppState) {
 ///
 /// Output:
 /// - Writes `recent` JSON to `recent_path` and clears the dirty flag on success.
+/// - Also writes per-query result counts and sort/filter views next to `recent_path`.
 pub fn maybe_flush_recent(app: &mut AppState) {
     if !app.recent_dirty {
         return;
@@ -78,6 +172,59 @@ pub fn maybe_flush_recent(app: &mut AppState) {
         }
         app.recent_dirty = false;
     }
+    flush_recent_counts(app);
+    flush_recent_views(app);
+}
+
+/// What: Persist result counts for the entries currently in the recent list.
+///
+/// Inputs:
+/// - `app`: Application state containing `recent` and `recent_counts`
+///
+/// Output:
+/// - Writes a `query -> count` JSON map next to `recent_path`.
+///
+/// Details:
+/// - Counts for queries evicted from the recent list are dropped before writing.
+fn flush_recent_counts(app: &mut AppState) {
+    let recent = &app.recent;
+    app.recent_counts
+        .retain(|key, _| recent.peek(key).is_some());
+    let path = app.recent_counts_path();
+    if let Ok(s) = serde_json::to_string(&app.recent_counts)
+        && let Err(e) = fs::write(&path, &s)
+    {
+        tracing::warn!(
+            path = %path.display(),
+            error = %e,
+            "[Persist] Failed to write recent search counts"
+        );
+    }
+}
+
+/// What: Persist sort/filter views for the entries currently in the recent list.
+///
+/// Inputs:
+/// - `app`: Application state containing `recent` and `recent_views`
+///
+/// Output:
+/// - Writes a `query -> view` JSON map next to `recent_path`.
+///
+/// Details:
+/// - Views for queries evicted from the recent list are dropped before writing.
+fn flush_recent_views(app: &mut AppState) {
+    let recent = &app.recent;
+    app.recent_views.retain(|key, _| recent.peek(key).is_some());
+    let path = app.recent_views_path();
+    if let Ok(s) = serde_json::to_string(&app.recent_views)
+        && let Err(e) = fs::write(&path, &s)
+    {
+        tracing::warn!(
+            path = %path.display(),
+            error = %e,
+            "[Persist] Failed to write recent search views"
+        );
+    }
 }
 
 /// What: Persist config-editor recent searches to disk when marked dirty.
@@ -716,6 +863,75 @@ mod tests {
         AppState::default()
     }
 
+    #[test]
+    /// What: Ensure news feed items round-trip through the versioned cache.
+    ///
+    /// Inputs:
+    /// - Two feed items saved with a fixed `fetched_at`, then a cache with another version and
+    ///   a legacy bare item array.
+    ///
+    /// Output:
+    /// - The saved items and timestamp load back unchanged; the stale formats load as `None`.
+    ///
+    /// Details:
+    /// - Uses a temporary file that is removed at the end.
+    fn news_feed_cache_round_trips_and_rejects_stale_versions() {
+        let path = std::env::temp_dir().join(format!(
+            "pacsea_news_feed_{}_{}.json",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("System time is before UNIX epoch")
+                .as_nanos()
+        ));
+        let items = vec![
+            NewsFeedItem {
+                id: "https://archlinux.org/news/a/".into(),
+                date: "2025-01-02".into(),
+                title: "Manual intervention".into(),
+                summary: Some("Read before updating".into()),
+                url: Some("https://archlinux.org/news/a/".into()),
+                source: crate::state::types::NewsFeedSource::ArchNews,
+                severity: None,
+                packages: Vec::new(),
+            },
+            NewsFeedItem {
+                id: "AVG-1".into(),
+                date: "2025-01-03".into(),
+                title: "openssl issue".into(),
+                summary: None,
+                url: None,
+                source: crate::state::types::NewsFeedSource::SecurityAdvisory,
+                severity: Some(crate::state::types::AdvisorySeverity::High),
+                packages: vec!["openssl".into()],
+            },
+        ];
+
+        save_news_feed_cache(&path, &items, 1_735_862_400).expect("write cache");
+        let (loaded, fetched_at) = load_news_feed_cache(&path).expect("load cache");
+        assert_eq!(fetched_at, 1_735_862_400);
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].id, items[0].id);
+        assert_eq!(loaded[0].summary, items[0].summary);
+        assert_eq!(loaded[1].packages, items[1].packages);
+        assert!(matches!(
+            loaded[1].severity,
+            Some(crate::state::types::AdvisorySeverity::High)
+        ));
+
+        let stale = serde_json::json!({
+            "version": NEWS_FEED_CACHE_VERSION + 1,
+            "fetched_at": 0,
+            "items": items,
+        });
+        std::fs::write(&path, stale.to_string()).expect("write stale cache");
+        assert!(load_news_feed_cache(&path).is_none());
+
+        std::fs::write(&path, serde_json::to_string(&items).expect("serialize")).expect("write");
+        assert!(load_news_feed_cache(&path).is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     /// What: Ensure `maybe_flush_cache` persists the details cache and clears the dirty flag.
     ///
@@ -762,10 +978,11 @@ mod tests {
     /// - `AppState` seeded with recent entries, temp path, and `recent_dirty = true`.
     ///
     /// Output:
-    /// - JSON file includes both entries and `recent_dirty` becomes `false`.
+    /// - JSON file includes both entries, the counts file keeps only counts for listed
+    ///   queries, and `recent_dirty` becomes `false`.
     ///
     /// Details:
-    /// - Cleans up the generated file to avoid cluttering the system temp directory.
+    /// - Cleans up the generated files to avoid cluttering the system temp directory.
     fn flush_recent_writes_and_clears_flag() {
         let mut app = new_app();
         let mut path = std::env::temp_dir();
@@ -779,13 +996,19 @@ mod tests {
         ));
         app.recent_path = path.clone();
         app.load_recent_items(&["rg".to_string(), "fd".to_string()]);
+        app.record_recent_count("rg", 3);
+        app.recent_counts.insert("evicted".to_string(), 9);
         app.recent_dirty = true;
         maybe_flush_recent(&mut app);
         assert!(!app.recent_dirty);
         let body =
             std::fs::read_to_string(&app.recent_path).expect("Failed to read test recent file");
         assert!(body.contains("rg") && body.contains("fd"));
+        let counts = std::fs::read_to_string(app.recent_counts_path())
+            .expect("Failed to read test recent counts file");
+        assert!(counts.contains("\"rg\":3") && !counts.contains("evicted"));
         let _ = std::fs::remove_file(&app.recent_path);
+        let _ = std::fs::remove_file(app.recent_counts_path());
     }
 
     #[test]
@@ -939,6 +1162,8 @@ mod tests {
             depends_on: Vec::new(),
             is_core: true,
             is_system: false,
+            optional: false,
+            description: None,
         }];
         app.deps_cache_dirty = true;
         maybe_flush_deps_cache(&mut app);
@@ -27,6 +27,8 @@ pub fn maybe_save_recent(app: &mut AppState) {
     let key = value.to_ascii_lowercase();
     app.recent.resize(recent_capacity());
     app.recent.put(key, value.clone());
+    app.record_recent_count(&value, app.results.len());
+    app.record_recent_view(&value);
     app.last_saved_value = Some(value);
     app.recent_dirty = true;
 }
@@ -158,6 +160,107 @@ mod tests {
         assert!(app.recent_dirty);
     }
 
+    #[test]
+    /// What: Ensure recording a recent search stores the current result count.
+    ///
+    /// Inputs:
+    /// - Input `ripgrep` beyond the debounce window with three results shown.
+    ///
+    /// Output:
+    /// - `recent_count_for("ripgrep")` returns `Some(3)`.
+    ///
+    /// Details:
+    /// - Lookup is case-insensitive like the recent list itself.
+    fn maybe_save_recent_records_result_count() {
+        let mut app = new_app();
+        app.input = "ripgrep".into();
+        app.results = ["ripgrep", "ripgrep-all", "ripgrep-git"]
+            .iter()
+            .map(|name| crate::state::PackageItem::test_item(name, "aur"))
+            .collect();
+        app.last_input_change = std::time::Instant::now()
+            .checked_sub(std::time::Duration::from_secs(3))
+            .unwrap_or_else(std::time::Instant::now);
+        maybe_save_recent(&mut app);
+        assert_eq!(app.recent_count_for("ripgrep"), Some(3));
+        assert_eq!(app.recent_count_for("RipGrep"), Some(3));
+    }
+
+    #[test]
+    /// What: Ensure saving a recent search captures the sort mode and filters, and restoring it
+    /// reapplies them.
+    ///
+    /// Inputs:
+    /// - Input `ripgrep` saved with `AurPopularityThenOfficial`, AUR hidden, and orphaned-only on;
+    ///   state then reset before restoring `RIPGREP`.
+    ///
+    /// Output:
+    /// - The saved sort mode and filters are back in place and `restore_recent_view` returns `true`.
+    ///
+    /// Details:
+    /// - Re-sorting alone leaves the saved view untouched; a toggle handler recording the view
+    ///   while the query is shown updates it.
+    fn recent_view_saved_and_restored() {
+        let mut app = new_app();
+        app.input = "ripgrep".into();
+        app.sort_mode = crate::state::SortMode::AurPopularityThenOfficial;
+        app.results_filter_show_aur = false;
+        app.results_filter_only_orphaned = true;
+        app.last_input_change = std::time::Instant::now()
+            .checked_sub(std::time::Duration::from_secs(3))
+            .unwrap_or_else(std::time::Instant::now);
+        maybe_save_recent(&mut app);
+        let saved = app.recent_views.get("ripgrep").expect("view saved");
+        assert_eq!(saved.sort_mode, "aur_popularity");
+        assert_eq!(saved.filters.get("aur"), Some(&false));
+        assert_eq!(saved.filters.get("only_orphaned"), Some(&true));
+
+        app.results_filter_show_core = false;
+        crate::logic::sort_results_preserve_selection(&mut app);
+        assert_eq!(app.recent_views["ripgrep"].filters.get("core"), Some(&true));
+        app.record_current_view();
+        assert_eq!(
+            app.recent_views["ripgrep"].filters.get("core"),
+            Some(&false)
+        );
+
+        app.sort_mode = crate::state::SortMode::RepoThenName;
+        app.results_filter_show_aur = true;
+        app.results_filter_show_core = true;
+        app.results_filter_only_orphaned = false;
+        assert!(app.restore_recent_view("RIPGREP"));
+        assert_eq!(
+            app.sort_mode,
+            crate::state::SortMode::AurPopularityThenOfficial
+        );
+        assert!(!app.results_filter_show_aur);
+        assert!(!app.results_filter_show_core);
+        assert!(app.results_filter_only_orphaned);
+        assert!(app.results_filter_show_extra);
+    }
+
+    #[test]
+    /// What: Ensure restoring a query without a saved view applies the defaults.
+    ///
+    /// Inputs:
+    /// - Non-default sort mode and filters, then `restore_recent_view` for an unknown query.
+    ///
+    /// Output:
+    /// - Configured sort mode, every repo filter shown, status filters off, and `false` returned.
+    fn recent_view_restore_defaults_without_saved_state() {
+        let mut app = new_app();
+        app.sort_mode = crate::state::SortMode::BestMatches;
+        app.results_filter_show_aur = false;
+        app.results_filter_show_multilib = false;
+        app.results_filter_only_outofdate = true;
+        assert!(!app.restore_recent_view("never-searched"));
+        assert_eq!(app.sort_mode, crate::theme::settings().sort_mode);
+        assert!(app.results_filter_show_aur);
+        assert!(app.results_filter_show_multilib);
+        assert!(!app.results_filter_only_outofdate);
+        assert!(!app.results_filter_only_orphaned);
+    }
+
     #[test]
     /// What: Confirm existing case-insensitive matches move to the front without duplication.
     ///
@@ -69,12 +69,14 @@ pub struct Channels {
     pub pkgb_check_res_rx: mpsc::UnboundedReceiver<PkgbuildCheckResponse>,
     /// Sender for AUR comments requests (package name).
     pub comments_req_tx: mpsc::UnboundedSender<String>,
+    /// Sender for AUR comments "load more" requests (package name, page offset).
+    pub comments_page_req_tx: mpsc::UnboundedSender<(String, usize)>,
     /// Sender for AUR comments responses (package name, comments or error).
     pub comments_res_tx:
-        mpsc::UnboundedSender<(String, Result<Vec<crate::state::types::AurComment>, String>)>,
+        mpsc::UnboundedSender<(String, Result<crate::state::types::AurCommentsPage, String>)>,
     /// Receiver for AUR comments responses in the main event loop.
     pub comments_res_rx:
-        mpsc::UnboundedReceiver<(String, Result<Vec<crate::state::types::AurComment>, String>)>,
+        mpsc::UnboundedReceiver<(String, Result<crate::state::types::AurCommentsPage, String>)>,
     /// Sender for Arch Linux status updates (status text, color).
     pub status_tx: mpsc::UnboundedSender<(String, ArchStatusColor)>,
     /// Receiver for Arch Linux status updates in the main event loop.
@@ -130,9 +132,12 @@ pub struct Channels {
     pub sandbox_res_tx: mpsc::UnboundedSender<Vec<crate::logic::sandbox::SandboxInfo>>,
     /// Receiver for sandbox analysis responses in the main event loop.
     pub sandbox_res_rx: mpsc::UnboundedReceiver<Vec<crate::logic::sandbox::SandboxInfo>>,
-    /// Sender for preflight summary requests (packages, action).
-    pub summary_req_tx:
-        mpsc::UnboundedSender<(Vec<PackageItem>, crate::state::modal::PreflightAction)>,
+    /// Sender for preflight summary requests (packages, action, cancellation flag).
+    pub summary_req_tx: mpsc::UnboundedSender<(
+        Vec<PackageItem>,
+        crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
+    )>,
     /// Sender for preflight summary responses.
     pub summary_res_tx: mpsc::UnboundedSender<crate::logic::preflight::PreflightSummaryOutcome>,
     /// Receiver for preflight summary responses in the main event loop.
@@ -228,10 +233,17 @@ struct PreflightChannels {
     /// Receiver for sandbox analysis responses.
     sandbox_res_rx: mpsc::UnboundedReceiver<Vec<crate::logic::sandbox::SandboxInfo>>,
     /// Sender for preflight summary requests.
-    summary_req_tx: mpsc::UnboundedSender<(Vec<PackageItem>, crate::state::modal::PreflightAction)>,
+    summary_req_tx: mpsc::UnboundedSender<(
+        Vec<PackageItem>,
+        crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
+    )>,
     /// Receiver for preflight summary requests.
-    summary_req_rx:
-        mpsc::UnboundedReceiver<(Vec<PackageItem>, crate::state::modal::PreflightAction)>,
+    summary_req_rx: mpsc::UnboundedReceiver<(
+        Vec<PackageItem>,
+        crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
+    )>,
     /// Sender for preflight summary responses.
     summary_res_tx: mpsc::UnboundedSender<crate::logic::preflight::PreflightSummaryOutcome>,
     /// Receiver for preflight summary responses.
@@ -280,12 +292,16 @@ struct UtilityChannels {
     comments_req_tx: mpsc::UnboundedSender<String>,
     /// Receiver for AUR comments requests.
     comments_req_rx: mpsc::UnboundedReceiver<String>,
+    /// Sender for AUR comments "load more" requests.
+    comments_page_req_tx: mpsc::UnboundedSender<(String, usize)>,
+    /// Receiver for AUR comments "load more" requests.
+    comments_page_req_rx: mpsc::UnboundedReceiver<(String, usize)>,
     /// Sender for AUR comments responses.
     comments_res_tx:
-        mpsc::UnboundedSender<(String, Result<Vec<crate::state::types::AurComment>, String>)>,
+        mpsc::UnboundedSender<(String, Result<crate::state::types::AurCommentsPage, String>)>,
     /// Receiver for AUR comments responses.
     comments_res_rx:
-        mpsc::UnboundedReceiver<(String, Result<Vec<crate::state::types::AurComment>, String>)>,
+        mpsc::UnboundedReceiver<(String, Result<crate::state::types::AurCommentsPage, String>)>,
     /// Sender for Arch Linux status updates.
     status_tx: mpsc::UnboundedSender<(String, ArchStatusColor)>,
     /// Receiver for Arch Linux status updates.
@@ -412,8 +428,11 @@ fn create_preflight_channels() -> PreflightChannels {
     let (sandbox_req_tx, sandbox_req_rx) = mpsc::unbounded_channel::<Vec<PackageItem>>();
     let (sandbox_res_tx, sandbox_res_rx) =
         mpsc::unbounded_channel::<Vec<crate::logic::sandbox::SandboxInfo>>();
-    let (summary_req_tx, summary_req_rx) =
-        mpsc::unbounded_channel::<(Vec<PackageItem>, crate::state::modal::PreflightAction)>();
+    let (summary_req_tx, summary_req_rx) = mpsc::unbounded_channel::<(
+        Vec<PackageItem>,
+        crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
+    )>();
     let (summary_res_tx, summary_res_rx) =
         mpsc::unbounded_channel::<crate::logic::preflight::PreflightSummaryOutcome>();
     PreflightChannels {
@@ -455,8 +474,9 @@ fn create_utility_channels() -> UtilityChannels {
     let (pkgb_check_req_tx, pkgb_check_req_rx) = mpsc::unbounded_channel::<PkgbuildCheckRequest>();
     let (pkgb_check_res_tx, pkgb_check_res_rx) = mpsc::unbounded_channel::<PkgbuildCheckResponse>();
     let (comments_req_tx, comments_req_rx) = mpsc::unbounded_channel::<String>();
+    let (comments_page_req_tx, comments_page_req_rx) = mpsc::unbounded_channel::<(String, usize)>();
     let (comments_res_tx, comments_res_rx) =
-        mpsc::unbounded_channel::<(String, Result<Vec<crate::state::types::AurComment>, String>)>();
+        mpsc::unbounded_channel::<(String, Result<crate::state::types::AurCommentsPage, String>)>();
     let (status_tx, status_rx) = mpsc::unbounded_channel::<(String, ArchStatusColor)>();
     let (news_tx, news_rx) = mpsc::unbounded_channel::<Vec<crate::state::types::NewsFeedItem>>();
     let (news_feed_tx, news_feed_rx) = mpsc::unbounded_channel::<NewsFeedPayload>();
@@ -502,6 +522,8 @@ fn create_utility_channels() -> UtilityChannels {
         pkgb_check_res_rx,
         comments_req_tx,
         comments_req_rx,
+        comments_page_req_tx,
+        comments_page_req_rx,
         comments_res_tx,
         comments_res_rx,
         status_tx,
@@ -570,6 +592,7 @@ impl Channels {
         );
         crate::app::runtime::workers::comments::spawn_comments_worker(
             utility_channels.comments_req_rx,
+            utility_channels.comments_page_req_rx,
             utility_channels.comments_res_tx.clone(),
         );
         crate::app::runtime::workers::news_content::spawn_news_content_worker(
@@ -644,6 +667,7 @@ impl Channels {
             pkgb_check_req_tx: utility_channels.pkgb_check_req_tx,
             pkgb_check_res_rx: utility_channels.pkgb_check_res_rx,
             comments_req_tx: utility_channels.comments_req_tx,
+            comments_page_req_tx: utility_channels.comments_page_req_tx,
             comments_res_tx: utility_channels.comments_res_tx,
             comments_res_rx: utility_channels.comments_res_rx,
             status_tx: utility_channels.status_tx,
@@ -206,6 +206,7 @@ fn handle_index_notification(app: &mut AppState, channels: &Channels) -> bool {
 /// Details:
 /// - Updates app state with update count, list, and whether the official-repo probe was authoritative
 /// - Shows a transient toast when the check ran in degraded mode (stale DB / sandbox issues)
+///   and marks the updates fetch as failed so it can be retried on demand
 /// - If pending updates modal is set, opens the updates modal
 fn handle_updates_list(
     app: &mut AppState,
@@ -217,6 +218,7 @@ fn handle_updates_list(
     app.updates_count = Some(count);
     app.updates_list = list;
     app.updates_loading = false;
+    app.set_fetch_failed(crate::state::FetchCategory::Updates, !payload.authoritative);
     if !payload.authoritative {
         app.toast_message = Some(i18n::t(app, "app.toasts.update_check_degraded"));
         app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(8));
@@ -412,14 +414,7 @@ fn handle_news_feed_items(app: &mut AppState, payload: NewsFeedPayload) {
     app.news_seen_pkg_versions_dirty = true;
     app.news_seen_aur_comments = payload.seen_aur_comments;
     app.news_seen_aur_comments_dirty = true;
-    match serde_json::to_string_pretty(&app.news_items) {
-        Ok(serialized) => {
-            if let Err(e) = std::fs::write(&app.news_feed_path, serialized) {
-                tracing::warn!(error = %e, path = ?app.news_feed_path, "failed to persist news feed cache");
-            }
-        }
-        Err(e) => tracing::warn!(error = %e, "failed to serialize news feed cache"),
-    }
+    super::super::persist::persist_news_feed(app);
     app.refresh_news_results();
 
     // News feed is now loaded - clear loading flag and toast
@@ -477,11 +472,7 @@ fn handle_incremental_news_item(app: &mut AppState, item: crate::state::types::N
     app.refresh_news_results();
 
     // Persist to disk
-    if let Ok(serialized) = serde_json::to_string_pretty(&app.news_items)
-        && let Err(e) = std::fs::write(&app.news_feed_path, serialized)
-    {
-        tracing::warn!(error = %e, path = ?app.news_feed_path, "failed to persist incremental news feed cache");
-    }
+    super::super::persist::persist_news_feed(app);
 }
 
 /// What: Handle news article content response.
@@ -504,6 +495,14 @@ fn handle_news_content(app: &mut AppState, url: &str, content: String) {
             .insert(url.to_string(), content.clone());
         app.news_content_cache_dirty = true;
     }
+    // Freshly fetched content invalidates remembered reading positions for this article
+    for item in app
+        .news_results
+        .iter()
+        .filter(|item| item.url.as_deref() == Some(url))
+    {
+        app.news_content_scroll_by_item.remove(&item.id);
+    }
 
     // Update displayed content if this is for the currently selected item
     if let Some(selected_url) = app
@@ -519,6 +518,7 @@ fn handle_news_content(app: &mut AppState, url: &str, content: String) {
             "news_content: response matches selection"
         );
         app.news_content_loading = false;
+        app.news_content_scroll = 0;
         app.news_content = if content.is_empty() {
             None
         } else {
@@ -541,6 +541,28 @@ fn handle_news_content(app: &mut AppState, url: &str, content: String) {
     app.news_content_loading_since = None;
 }
 
+/// What: Send a queued "load more" AUR comments page request to the comments worker.
+///
+/// Inputs:
+/// - `app`: Application state (`comments_page_requested`, `comments_package_name`)
+/// - `comments_page_req_tx`: Channel to the comments worker
+///
+/// Details:
+/// - Event handlers only queue the page offset; the request is sent here since the event
+///   handlers do not carry the page channel.
+fn dispatch_comments_page_request(
+    app: &mut AppState,
+    comments_page_req_tx: &tokio::sync::mpsc::UnboundedSender<(String, usize)>,
+) {
+    if let Some(offset) = app.comments_page_requested.take() {
+        if let Some(pkgname) = app.comments_package_name.clone() {
+            let _ = comments_page_req_tx.send((pkgname, offset));
+        } else {
+            app.comments_page_loading = false;
+        }
+    }
+}
+
 /// What: Process one iteration of channel message handling.
 ///
 /// Inputs:
@@ -557,7 +579,7 @@ fn handle_news_content(app: &mut AppState, url: &str, content: String) {
 async fn process_channel_messages(app: &mut AppState, channels: &mut Channels) -> bool {
     select! {
         Some(ev) = channels.event_rx.recv() => {
-            crate::events::handle_event_with_pkgbuild_checks(
+            let exit = crate::events::handle_event_with_pkgbuild_checks(
                 &ev,
                 app,
                 &channels.query_tx,
@@ -567,7 +589,9 @@ async fn process_channel_messages(app: &mut AppState, channels: &mut Channels) -
                 &channels.pkgb_req_tx,
                 &channels.comments_req_tx,
                 &channels.pkgb_check_req_tx,
-            )
+            );
+            dispatch_comments_page_request(app, &channels.comments_page_req_tx);
+            exit
         }
         Some(()) = channels.index_notify_rx.recv() => {
             handle_index_notification(app, channels)
@@ -579,10 +603,12 @@ async fn process_channel_messages(app: &mut AppState, channels: &mut Channels) -
                 &channels.details_req_tx,
                 &channels.index_notify_tx,
             );
+            crate::logic::viewers::apply_startup_viewers(app, &channels.comments_req_tx);
             false
         }
         Some(details) = channels.details_res_rx.recv() => {
             handle_details_update(app, &details, &channels.tick_tx);
+            crate::logic::viewers::apply_startup_viewers(app, &channels.comments_req_tx);
             false
         }
         Some(item) = channels.preview_rx.recv() => {
@@ -701,6 +727,7 @@ async fn process_channel_messages(app: &mut AppState, channels: &mut Channels) -
         Some(aur_vote_state_response) = channels.aur_vote_state_res_rx.recv() => { handle_aur_vote_state_response(app, aur_vote_state_response); false }
         Some(executor_output) = channels.executor_res_rx.recv() => {
             handle_executor_output(app, executor_output);
+            drain_executor_backlog(app, &mut channels.executor_res_rx);
             false
         }
         Some(post_summary_data) = channels.post_summary_res_rx.recv() => {
@@ -754,7 +781,15 @@ fn handle_post_summary_result(app: &mut AppState, data: crate::logic::summary::P
 /// Details:
 /// - Tracks installed packages and triggers refresh of installed packages pane
 /// - Only tracks pending install names if items is non-empty (system updates use empty items)
+/// - Records a finished system update (see `pending_update_activity`) in the activity log
 fn handle_install_success(app: &mut AppState, items: &[crate::state::PackageItem]) {
+    // System updates run with empty items; their package list was captured when they started
+    if items.is_empty()
+        && let Some(packages) = app.pending_update_activity.take()
+    {
+        crate::app::record_activity(crate::app::ActivityAction::Update, &packages);
+    }
+
     // Only track pending install names if items is non-empty.
     // System updates use empty items, and setting pending_install_names
     // to empty would cause install_list to be cleared in tick handler
@@ -772,6 +807,9 @@ fn handle_install_success(app: &mut AppState, items: &[crate::state::PackageItem
     // Refresh updates count after installation completes
     app.refresh_updates = true;
 
+    // Installed versions changed, so cached preflight summaries are stale
+    app.invalidate_preflight_summary_cache();
+
     tracing::info!(
         "Install operation completed: triggered refresh of installed packages and updates"
     );
@@ -794,6 +832,7 @@ fn handle_remove_success(app: &mut AppState, items: &[crate::state::PackageItem]
     // Clear remove list
     app.remove_list.clear();
     app.remove_list_names.clear();
+    app.invalidate_preflight_summary_cache();
     app.remove_state.select(None);
 
     // Set pending remove names to track removal completion
@@ -822,6 +861,8 @@ fn handle_remove_success(app: &mut AppState, items: &[crate::state::PackageItem]
 ///
 /// Details:
 /// - Clears downgrade list and triggers refresh of installed packages pane
+/// - Records the downgrade in the activity log right away; downgraded packages stay installed,
+///   so the installed-cache polling has no completion signal for them
 fn handle_downgrade_success(app: &mut AppState, items: &[crate::state::PackageItem]) {
     let downgraded_names: Vec<String> = items.iter().map(|p| p.name.clone()).collect();
 
@@ -829,6 +870,11 @@ fn handle_downgrade_success(app: &mut AppState, items: &[crate::state::PackageIt
     app.downgrade_list.clear();
     app.downgrade_list_names.clear();
     app.downgrade_state.select(None);
+    app.invalidate_preflight_summary_cache();
+
+    if !app.dry_run {
+        crate::app::record_activity(crate::app::ActivityAction::Downgrade, &downgraded_names);
+    }
 
     // Set pending downgrade names to track downgrade completion
     app.pending_remove_names = Some(downgraded_names);
@@ -845,6 +891,33 @@ fn handle_downgrade_success(app: &mut AppState, items: &[crate::state::PackageIt
     tracing::info!("Downgrade operation completed: cleared downgrade list and triggered refresh");
 }
 
+/// What: Apply executor output that queued up while the previous frame was drawn.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `rx`: Executor output channel
+///
+/// Output:
+/// - Number of messages applied (at most `MAX_EXEC_OUTPUTS_PER_FRAME - 1`)
+///
+/// Details:
+/// - Redrawing after every streamed line would let a chatty build starve the UI; batching
+///   the backlog means one redraw per batch while leftovers wait for the next iteration.
+fn drain_executor_backlog(
+    app: &mut AppState,
+    rx: &mut tokio::sync::mpsc::UnboundedReceiver<crate::install::ExecutorOutput>,
+) -> usize {
+    let mut applied = 0;
+    while applied + 1 < crate::logic::exec_log::MAX_EXEC_OUTPUTS_PER_FRAME {
+        let Ok(output) = rx.try_recv() else {
+            break;
+        };
+        handle_executor_output(app, output);
+        applied += 1;
+    }
+    applied
+}
+
 /// What: Handle executor output and update UI state accordingly.
 ///
 /// Inputs:
@@ -891,6 +964,15 @@ fn handle_executor_output(app: &mut AppState, output: crate::install::ExecutorOu
         }
     }
 
+    // Failed transactions may still have written .pacnew files before aborting
+    if matches!(output, crate::install::ExecutorOutput::Finished { .. }) {
+        crate::logic::pacnew::finish_pac_files_check_with(app, || {
+            crate::logic::pacnew::scan_pac_files(std::path::Path::new(
+                crate::logic::pacnew::PAC_FILES_ROOT,
+            ))
+        });
+    }
+
     if let crate::state::Modal::PreflightExec {
         ref mut log_lines,
         ref mut abortable,
@@ -902,11 +984,12 @@ fn handle_executor_output(app: &mut AppState, output: crate::install::ExecutorOu
     {
         match output {
             crate::install::ExecutorOutput::Line(line) => {
-                log_lines.push(line);
-                // Keep only last 1000 lines to avoid memory issues
-                if log_lines.len() > 1000 {
-                    log_lines.remove(0);
-                }
+                crate::logic::exec_log::push_exec_log_line(
+                    log_lines,
+                    line,
+                    app.exec_log_follow,
+                    &mut app.exec_log_from_bottom,
+                );
                 tracing::debug!(
                     "[EventLoop] PreflightExec log_lines count: {}",
                     log_lines.len()
@@ -973,6 +1056,7 @@ fn handle_executor_output(app: &mut AppState, output: crate::install::ExecutorOu
                     }
                 } else {
                     log_lines.push(format!("Execution failed (exit code: {exit_code:?})"));
+                    app.pending_update_activity = None;
 
                     // If this was a system update (empty items) and AUR update is pending, show confirmation
                     if items.is_empty() && app.pending_aur_update_command.is_some() {
@@ -1052,6 +1136,47 @@ fn handle_executor_output(app: &mut AppState, output: crate::install::ExecutorOu
     }
 }
 
+/// What: Re-run fetches queued by the "retry failed fetches" action.
+///
+/// Inputs:
+/// - `channels`: Communication channels for background workers
+/// - `app`: Application state holding `pending_fetch_retries`
+///
+/// Output: None
+///
+/// Details:
+/// - News and advisories share one aggregated feed fetch, spawned at most once per retry.
+/// - Status is fetched once immediately; updates are re-run through `refresh_updates` instead.
+fn retry_pending_fetches(channels: &Channels, app: &mut AppState) {
+    use crate::app::runtime::workers::{auxiliary, news};
+    use crate::state::FetchCategory;
+
+    let mut news_requeued = false;
+    for category in std::mem::take(&mut app.pending_fetch_retries) {
+        tracing::info!(category = category.as_str(), "retrying failed fetch");
+        match category {
+            FetchCategory::News | FetchCategory::Advisories => {
+                if news_requeued {
+                    continue;
+                }
+                news_requeued = true;
+                // Nothing else is fetching from archlinux.org, so release the worker right away
+                let (completion_tx, completion_rx) = tokio::sync::oneshot::channel();
+                let _ = completion_tx.send(());
+                news::spawn_aggregated_news_feed_worker(
+                    &channels.news_feed_tx,
+                    &channels.news_incremental_tx,
+                    &app.news_seen_pkg_versions,
+                    &app.news_seen_aur_comments,
+                    Some(completion_rx),
+                );
+            }
+            FetchCategory::Status => auxiliary::spawn_status_fetch(&channels.status_tx),
+            FetchCategory::Updates => app.refresh_updates = true,
+        }
+    }
+}
+
 /// What: Trigger startup news fetch using current startup news settings.
 ///
 /// Inputs:
@@ -1124,6 +1249,8 @@ fn trigger_startup_news_fetch(channels: &Channels, app: &mut AppState) {
             seen_pkg_versions: &mut seen_versions,
             seen_aur_comments: &mut seen_aur_comments,
             max_age_days: prefs.startup_news_max_age_days,
+            source_urls: sources::NewsSourceUrls::from_settings(&prefs),
+            max_parallel_slow_fetches: prefs.news_max_parallel_slow_fetches,
         };
         tracing::info!(
             limit = updates_limit,
@@ -1296,6 +1423,7 @@ mod startup_news_tests {
 ///   preflight data, PKGBUILD, news, status, etc.)
 /// - Exits when event handler returns true (e.g., quit command)
 /// - Checks for `trigger_startup_news_fetch` flag and triggers fetch if set
+/// - Re-runs fetches queued by the retry-failed-fetches action
 pub async fn run_event_loop(
     terminal: &mut Option<Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>>,
     app: &mut AppState,
@@ -1308,6 +1436,10 @@ pub async fn run_event_loop(
             trigger_startup_news_fetch(channels, &mut *app);
         }
 
+        if !app.pending_fetch_retries.is_empty() {
+            retry_pending_fetches(channels, &mut *app);
+        }
+
         if let Some(t) = terminal.as_mut() {
             let _ = t.draw(|f| ui(f, app));
         }
@@ -1410,6 +1542,33 @@ mod tests {
         assert!(app.news_content_cache.contains_key("https://example.com/a"));
     }
 
+    #[test]
+    /// What: Ensure refreshed news content resets the remembered scroll position.
+    ///
+    /// Inputs:
+    /// - Selected item `a` scrolled to offset 7 with a remembered offset for `a`.
+    /// - Fresh content response for `a`.
+    ///
+    /// Output:
+    /// - Scroll offset is 0 and the remembered offset for `a` is dropped.
+    ///
+    /// Details:
+    /// - Reading positions refer to the old content and must not survive a refetch.
+    fn handle_news_content_resets_remembered_scroll() {
+        let mut app = AppState {
+            news_results: vec![make_news_item("a", "https://example.com/a")],
+            news_content_scroll: 7,
+            news_content_loading: true,
+            ..AppState::default()
+        };
+        app.news_content_scroll_by_item.insert("a".to_string(), 7);
+
+        handle_news_content(&mut app, "https://example.com/a", "fresh".to_string());
+
+        assert_eq!(app.news_content_scroll, 0);
+        assert!(!app.news_content_scroll_by_item.contains_key("a"));
+    }
+
     #[test]
     /// What: Verify degraded update-check payloads set authoritative flag and user toast.
     ///
@@ -1743,4 +1902,74 @@ mod tests {
         ));
         assert!(!app.aur_vote_state_lookup_supported);
     }
+
+    #[test]
+    /// What: Ensure streamed executor lines reach the log pane incrementally.
+    ///
+    /// Inputs:
+    /// - Fake process thread emitting 4 lines 40ms apart; following paused after the first.
+    ///
+    /// Output:
+    /// - The pane grows one batch at a time instead of all at once, and the paused view's
+    ///   window start stays on the first line while later lines arrive.
+    ///
+    /// Details:
+    /// - Mirrors the event loop: block on one message, then drain the backlog.
+    fn executor_lines_stream_incrementally() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let producer = std::thread::spawn(move || {
+            for i in 0..4 {
+                let _ = tx.send(crate::install::ExecutorOutput::Line(format!(
+                    "build step {i}"
+                )));
+                std::thread::sleep(std::time::Duration::from_millis(40));
+            }
+        });
+        let mut app = AppState {
+            modal: crate::state::Modal::PreflightExec {
+                items: Vec::new(),
+                action: crate::state::PreflightAction::Install,
+                tab: crate::state::PreflightTab::Summary,
+                verbose: false,
+                log_lines: Vec::new(),
+                abortable: true,
+                header_chips: crate::state::modal::PreflightHeaderChips::default(),
+                success: None,
+            },
+            ..Default::default()
+        };
+        let log_len = |app: &AppState| match &app.modal {
+            crate::state::Modal::PreflightExec { log_lines, .. } => log_lines.len(),
+            _ => 0,
+        };
+
+        let mut seen = Vec::new();
+        while let Some(output) = rx.blocking_recv() {
+            super::handle_executor_output(&mut app, output);
+            super::drain_executor_backlog(&mut app, &mut rx);
+            seen.push(log_len(&app));
+            if seen.len() == 1 {
+                crate::logic::exec_log::toggle_exec_log_follow(&mut app);
+            }
+        }
+        producer.join().expect("producer thread");
+
+        assert_eq!(seen.last(), Some(&4));
+        assert!(
+            seen[0] < 4,
+            "first frame should not wait for the whole build"
+        );
+        assert!(seen.windows(2).all(|w| w[0] < w[1]));
+        assert!(!app.exec_log_follow);
+        assert_eq!(app.exec_log_from_bottom, 3);
+        assert_eq!(
+            crate::logic::exec_log::exec_log_window_start(
+                4,
+                1,
+                app.exec_log_follow,
+                app.exec_log_from_bottom
+            ),
+            0
+        );
+    }
 }
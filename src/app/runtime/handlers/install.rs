@@ -435,6 +435,8 @@ mod tests {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         }];
 
         handle_dependency_result(&mut app, &deps, &tick_tx);
@@ -481,6 +483,8 @@ mod tests {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         }];
 
         handle_dependency_result(&mut app, &deps, &tick_tx);
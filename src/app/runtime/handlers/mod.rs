@@ -14,6 +14,6 @@ pub mod services;
 
 pub use files::handle_file_result;
 pub use install::{handle_add_to_install_list, handle_dependency_result};
-pub use sandbox::handle_sandbox_result;
+pub use sandbox::{apply_sandbox_risk_to_modal, handle_sandbox_result};
 pub use search::{handle_details_update, handle_preview, handle_search_results};
 pub use services::handle_service_result;
@@ -177,6 +177,39 @@ fn sync_sandbox_to_modal(
     );
 }
 
+/// What: Fold the preflight modal's sandbox analysis into its summary risk.
+///
+/// Inputs:
+/// - `modal`: Preflight modal state
+///
+/// Output: None (side effect: may add a risk reason and raise the summary/header risk)
+///
+/// Details:
+/// - Called when either the sandbox data or the summary reaches the modal, since they
+///   resolve independently; `apply_sandbox_risk` only adds its reason once.
+pub fn apply_sandbox_risk_to_modal(modal: &mut crate::state::Modal) {
+    let crate::state::Modal::Preflight {
+        summary: Some(summary),
+        header_chips,
+        sandbox_info,
+        ..
+    } = modal
+    else {
+        return;
+    };
+    if crate::logic::preflight::apply_sandbox_risk(
+        summary,
+        header_chips,
+        sandbox_info,
+        &crate::theme::settings().preflight_risk_weights,
+    ) {
+        tracing::info!(
+            risk_score = summary.risk_score,
+            "[Runtime] Sandbox analysis flagged high-risk AUR packages"
+        );
+    }
+}
+
 /// What: Sync matching `SandboxInfo` to modal.
 ///
 /// Inputs:
@@ -383,6 +416,7 @@ impl HandlerConfig for SandboxHandlerConfig {
 
     fn sync_to_modal(&self, app: &mut AppState, results: &[Self::Result], _was_preflight: bool) {
         sync_sandbox_to_modal(&mut app.modal, results);
+        apply_sandbox_risk_to_modal(&mut app.modal);
     }
 
     fn log_flag_clear(&self, app: &AppState, was_preflight: bool, cancelled: bool) {
@@ -2,6 +2,38 @@ use tokio::sync::mpsc;
 
 use crate::state::{AppState, PackageDetails, PackageItem, SearchResults, Source};
 
+/// What: Pick the selection index for a freshly arrived result set.
+///
+/// Inputs:
+/// - `results`: Filtered and sorted results that will be displayed
+/// - `prev_name`: Name of the package selected before the update, if any
+/// - `prev_index`: Selection index before the update
+/// - `auto_select_first`: Value of the `auto_select_first_result` setting
+///
+/// Output:
+/// - Index into `results` to select (0 when `results` is empty)
+///
+/// Details:
+/// - Keeps the previously selected package when it is still present.
+/// - Otherwise selects the first result when `auto_select_first` is true, or keeps
+///   the previous position clamped to the new length when it is false.
+fn resolve_selection_after_results(
+    results: &[PackageItem],
+    prev_name: Option<&str>,
+    prev_index: usize,
+    auto_select_first: bool,
+) -> usize {
+    let last = results.len().saturating_sub(1);
+    if let Some(pos) = prev_name.and_then(|name| results.iter().position(|p| p.name == name)) {
+        return pos;
+    }
+    if auto_select_first {
+        0
+    } else {
+        prev_index.min(last)
+    }
+}
+
 /// What: Handle search results update event.
 ///
 /// Inputs:
@@ -11,8 +43,10 @@ use crate::state::{AppState, PackageDetails, PackageItem, SearchResults, Source}
 /// - `index_notify_tx`: Channel sender for index update notifications
 ///
 /// Details:
-/// - Filters results based on installed-only mode if enabled
-/// - Updates selection to preserve previously selected item
+/// - Filters results based on installed-only mode if enabled, requesting installed sizes
+///   for the installed-only view
+/// - Updates selection to preserve previously selected item, falling back to the first
+///   result when `auto_select_first_result` is enabled
 /// - Triggers detail fetch and ring prefetch for selected item
 /// - Requests index enrichment for official packages near selection
 pub fn handle_search_results(
@@ -29,6 +63,7 @@ pub fn handle_search_results(
     // Startup index refresh can produce newer non-empty data for the same empty query.
     let query_text = app.input.trim().to_string();
 
+    let prev_selected_index = app.selected;
     let prev_selected_name = app.results.get(app.selected).map(|p| p.name.clone());
     // Respect installed-only mode: keep results restricted to explicit installs
     let mut incoming = new_results.items;
@@ -71,16 +106,29 @@ pub fn handle_search_results(
         }
     }
     app.all_results = incoming;
+    if app.installed_only_mode
+        && (crate::theme::settings().installed_only_show_size
+            || app.sort_mode == crate::state::SortMode::InstalledSize)
+    {
+        crate::index::request_installed_sizes_for(
+            index_notify_tx.clone(),
+            app.all_results.iter().map(|p| p.name.clone()).collect(),
+        );
+    }
     crate::logic::apply_filters_and_sort_preserve_selection(app);
-    let new_sel = prev_selected_name
-        .and_then(|name| app.results.iter().position(|p| p.name == name))
-        .unwrap_or(0);
-    app.selected = new_sel.min(app.results.len().saturating_sub(1));
+    crate::logic::update_search_suggestions(app);
+    app.selected = resolve_selection_after_results(
+        &app.results,
+        prev_selected_name.as_deref(),
+        prev_selected_index,
+        crate::theme::settings().auto_select_first_result,
+    );
     app.list_state.select(if app.results.is_empty() {
         None
     } else {
         Some(app.selected)
     });
+    app.record_recent_count(&query_text, app.results.len());
     if let Some(item) = app.results.get(app.selected).cloned() {
         app.details_focus = Some(item.name.clone());
         crate::logic::clear_stale_pkgbuild_checks_for_selection(app, item.name.as_str());
@@ -130,8 +178,11 @@ pub fn handle_search_results(
         );
     }
 
-    // Update search cache with current query and results
-    app.search_cache_query = Some(query_text);
+    // Update search cache with current (normalized) query and results
+    app.search_cache_query = Some(crate::logic::normalize_query(
+        &app.input,
+        &crate::theme::settings().search_normalize,
+    ));
     app.search_cache_fuzzy = app.fuzzy_search_enabled;
     app.search_cache_results = Some(app.results.clone());
 }
@@ -363,6 +414,209 @@ mod tests {
         assert_eq!(app.search_cache_results.as_ref().map(Vec::len), Some(1));
     }
 
+    #[test]
+    /// What: Verify trailing-space and case variants of a query share one search-cache entry.
+    ///
+    /// Inputs:
+    /// - The same result set delivered for inputs `ripgrep `, `RipGrep`, and `ripgrep`
+    ///
+    /// Output:
+    /// - `search_cache_query` is `ripgrep` each time and the displayed ranking is identical
+    ///
+    /// Details:
+    /// - Relies on the default `search_normalize = trim`.
+    fn handle_search_results_normalizes_cache_key_and_ranking() {
+        let (details_tx, _details_rx) = mpsc::unbounded_channel();
+        let (index_tx, _index_rx) = mpsc::unbounded_channel();
+        let mut keys = Vec::new();
+        let mut rankings = Vec::new();
+        for (id, input) in [(1, "ripgrep "), (2, "RipGrep"), (3, "ripgrep")] {
+            let mut app = new_app();
+            app.sort_mode = crate::state::SortMode::BestMatches;
+            app.latest_query_id = id;
+            app.input = input.to_string();
+            let fresh = SearchResults {
+                id,
+                items: vec![
+                    PackageItem::test_item("ripgrep-all", "aur"),
+                    PackageItem::test_item("fzf-ripgrep", "aur"),
+                    PackageItem::test_item("ripgrep", "aur"),
+                ],
+            };
+            handle_search_results(&mut app, fresh, &details_tx, &index_tx);
+            keys.push(app.search_cache_query.clone());
+            rankings.push(
+                app.results
+                    .iter()
+                    .map(|p| p.name.clone())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert!(keys.iter().all(|k| k.as_deref() == Some("ripgrep")));
+        assert_eq!(rankings[0], rankings[1]);
+        assert_eq!(rankings[1], rankings[2]);
+        assert_eq!(rankings[0][0], "ripgrep");
+    }
+
+    #[test]
+    /// What: Verify a fresh result set auto-selects the first entry and requests its details.
+    ///
+    /// Inputs:
+    /// - `AppState` whose selected package is absent from the incoming results
+    ///
+    /// Output:
+    /// - Selection moves to index 0 and a details request is sent for that package
+    ///
+    /// Details:
+    /// - Covers the default `auto_select_first_result = true` behavior.
+    fn handle_search_results_auto_selects_first_and_requests_details() {
+        let mut app = new_app();
+        app.latest_query_id = 3;
+        app.input = "al".to_string();
+        app.results = vec![
+            PackageItem::test_item("zzz-a", "aur"),
+            PackageItem::test_item("zzz-b", "aur"),
+            PackageItem::test_item("zzz-c", "aur"),
+        ];
+        app.selected = 2;
+
+        let (details_tx, mut details_rx) = mpsc::unbounded_channel();
+        let (index_tx, _index_rx) = mpsc::unbounded_channel();
+        let fresh = SearchResults {
+            id: 3,
+            items: vec![
+                PackageItem::test_item("alpha", "aur"),
+                PackageItem::test_item("alpine", "aur"),
+            ],
+        };
+
+        handle_search_results(&mut app, fresh, &details_tx, &index_tx);
+
+        assert_eq!(app.selected, 0);
+        assert_eq!(app.list_state.selected(), Some(0));
+        let first = app.results[0].name.clone();
+        assert_eq!(app.details_focus.as_deref(), Some(first.as_str()));
+        let requested = details_rx
+            .try_recv()
+            .expect("details request for first result");
+        assert_eq!(requested.name, first);
+    }
+
+    #[test]
+    /// What: Verify replaying a recent query records its result count.
+    ///
+    /// Inputs:
+    /// - `AppState` whose recent list contains `al`, input `al`, and two incoming results
+    ///
+    /// Output:
+    /// - `recent_count_for("al")` is `Some(2)` and the recent list is marked dirty
+    ///
+    /// Details:
+    /// - Queries absent from the recent list are not recorded.
+    fn handle_search_results_records_count_for_recent_query() {
+        let mut app = new_app();
+        app.load_recent_items(&["al".to_string()]);
+        app.latest_query_id = 5;
+        app.input = "AL".to_string();
+        let (details_tx, _details_rx) = mpsc::unbounded_channel();
+        let (index_tx, _index_rx) = mpsc::unbounded_channel();
+        let fresh = SearchResults {
+            id: 5,
+            items: vec![
+                PackageItem::test_item("alpha", "aur"),
+                PackageItem::test_item("alpine", "aur"),
+            ],
+        };
+
+        handle_search_results(&mut app, fresh, &details_tx, &index_tx);
+
+        assert_eq!(app.recent_count_for("al"), Some(2));
+        assert!(app.recent_dirty);
+
+        app.latest_query_id = 6;
+        app.input = "zz".to_string();
+        let other = SearchResults {
+            id: 6,
+            items: vec![PackageItem::test_item("zzz", "aur")],
+        };
+        handle_search_results(&mut app, other, &details_tx, &index_tx);
+        assert_eq!(app.recent_count_for("zz"), None);
+    }
+
+    #[test]
+    /// What: Verify a package that stays in the new result set keeps the selection.
+    ///
+    /// Inputs:
+    /// - `AppState` with `beta` selected and incoming results that still contain `beta`
+    ///
+    /// Output:
+    /// - Selection follows `beta` to its new index instead of jumping to the top
+    ///
+    /// Details:
+    /// - Guards the preserve-selection branch ahead of the auto-select fallback.
+    fn handle_search_results_retains_persisting_selection() {
+        let mut app = new_app();
+        app.latest_query_id = 4;
+        app.input = "a".to_string();
+        app.results = vec![
+            PackageItem::test_item("alpha", "aur"),
+            PackageItem::test_item("beta", "aur"),
+        ];
+        app.selected = 1;
+
+        let (details_tx, _details_rx) = mpsc::unbounded_channel();
+        let (index_tx, _index_rx) = mpsc::unbounded_channel();
+        let fresh = SearchResults {
+            id: 4,
+            items: vec![
+                PackageItem::test_item("aardvark", "aur"),
+                PackageItem::test_item("alpha", "aur"),
+                PackageItem::test_item("beta", "aur"),
+            ],
+        };
+
+        handle_search_results(&mut app, fresh, &details_tx, &index_tx);
+
+        assert_eq!(app.results[app.selected].name, "beta");
+        assert_eq!(app.details_focus.as_deref(), Some("beta"));
+    }
+
+    #[test]
+    /// What: Verify `resolve_selection_after_results` honors the setting when the selection is gone.
+    ///
+    /// Inputs:
+    /// - Result list without the previously selected name, with the setting on and off
+    ///
+    /// Output:
+    /// - Index 0 when enabled; previous index clamped to the list when disabled
+    ///
+    /// Details:
+    /// - Exercises the pure helper so the disabled branch is covered without touching config files.
+    fn resolve_selection_after_results_respects_setting() {
+        let results = vec![
+            PackageItem::test_item("a", "aur"),
+            PackageItem::test_item("b", "aur"),
+            PackageItem::test_item("c", "aur"),
+        ];
+        assert_eq!(
+            resolve_selection_after_results(&results, Some("gone"), 2, true),
+            0
+        );
+        assert_eq!(
+            resolve_selection_after_results(&results, Some("gone"), 2, false),
+            2
+        );
+        assert_eq!(
+            resolve_selection_after_results(&results, Some("gone"), 9, false),
+            2
+        );
+        assert_eq!(
+            resolve_selection_after_results(&results, Some("b"), 0, true),
+            1
+        );
+        assert_eq!(resolve_selection_after_results(&[], None, 4, false), 0);
+    }
+
     #[test]
     /// What: Verify that `handle_details_update` updates cache and current details.
     ///
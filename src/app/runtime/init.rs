@@ -16,12 +16,14 @@ use super::super::services_cache;
 /// - `_prefs`: Settings struct (unused but kept for future use)
 ///
 /// Output:
-/// - Populates `app.locale`, `app.translations`, and `app.translations_fallback`
+/// - Populates `app.locale`, `app.translations`, `app.translations_chain`, and
+///   `app.translations_fallback`
 ///
 /// Details:
 /// - Resolves locale using fallback chain (settings -> system -> default)
 /// - Loads English fallback translations first (required)
-/// - Loads primary locale translations if different from English
+/// - Walks the locale fallback chain (e.g. `pt-BR` -> `pt` -> `en-US`): the first loadable locale
+///   becomes primary and further ones are consulted before English
 /// - Handles errors gracefully: falls back to English if locale file missing/invalid
 /// - Logs warnings for missing files but continues execution
 pub fn initialize_locale_system(
@@ -41,6 +43,7 @@ pub fn initialize_locale_system(
         );
         app.locale = "en-US".to_string();
         app.translations = std::collections::HashMap::new();
+        app.translations_chain.clear();
         app.translations_fallback = std::collections::HashMap::new();
         return;
     };
@@ -61,7 +64,9 @@ pub fn initialize_locale_system(
     app.locale.clone_from(&resolved_locale);
 
     // Load translations
-    let mut loader = crate::i18n::LocaleLoader::new(locales_dir);
+    let locale_chain = resolver.fallback_chain(locale_pref);
+    tracing::debug!("Locale fallback chain: {:?}", locale_chain);
+    let mut loader = crate::i18n::LocaleLoader::new(locales_dir.clone());
 
     // Load fallback (English) translations first - this is required
     match loader.load("en-US") {
@@ -79,45 +84,53 @@ pub fn initialize_locale_system(
         }
     }
 
-    // Load primary locale translations
-    if resolved_locale == "en-US" {
-        // Already loaded English as fallback, use it as primary too
-        app.translations = app.translations_fallback.clone();
-        tracing::debug!("Using English as primary locale");
-    } else {
-        match loader.load(&resolved_locale) {
-            Ok(translations) => {
-                let key_count = translations.len();
-                app.translations = translations;
+    // Walk the chain: the first loadable locale is primary, later ones sit between it and English
+    app.translations_chain.clear();
+    let mut primary: Option<crate::i18n::TranslationMap> = None;
+    for locale in locale_chain.iter().filter(|l| l.as_str() != "en-US") {
+        // Intermediate entries (e.g. `pt` for `pt-BR`) are optional; skip those without a file
+        if *locale != resolved_locale && !locales_dir.join(format!("{locale}.yml")).is_file() {
+            continue;
+        }
+        match loader.load(locale) {
+            Ok(translations) if primary.is_none() => {
                 tracing::info!(
                     "Loaded translations for locale '{}' ({} keys)",
-                    resolved_locale,
-                    key_count
+                    locale,
+                    translations.len()
                 );
-                // Debug: Check if specific keys exist
-                let test_keys = [
-                    "app.details.footer.search_hint",
-                    "app.details.footer.confirm_installation",
-                ];
-                for key in &test_keys {
-                    if app.translations.contains_key(*key) {
-                        tracing::debug!("  ✓ Key '{}' found in translations", key);
-                    } else {
-                        tracing::debug!("  ✗ Key '{}' NOT found in translations", key);
-                    }
-                }
+                app.locale.clone_from(locale);
+                primary = Some(translations);
+            }
+            Ok(translations) => {
+                tracing::debug!(
+                    "Loaded fallback translations for locale '{}' ({} keys)",
+                    locale,
+                    translations.len()
+                );
+                app.translations_chain.push(translations);
             }
             Err(e) => {
                 tracing::warn!(
-                    "Failed to load translations for locale '{}': {}. Using English fallback.",
-                    resolved_locale,
+                    "Failed to load translations for locale '{}': {}. Trying the next fallback.",
+                    locale,
                     e
                 );
-                // Use empty map - translate_with_fallback will use English fallback
-                app.translations = std::collections::HashMap::new();
             }
         }
     }
+    match primary {
+        Some(translations) => app.translations = translations,
+        None if resolved_locale == "en-US" => {
+            // Already loaded English as fallback, use it as primary too
+            app.translations = app.translations_fallback.clone();
+            tracing::debug!("Using English as primary locale");
+        }
+        None => {
+            // Use empty map - the English fallback answers every lookup
+            app.translations = std::collections::HashMap::new();
+        }
+    }
 }
 
 /// What: Run startup config preflight exactly once and return resolved settings.
@@ -304,13 +317,18 @@ pub fn apply_settings_to_app_state(app: &mut AppState, prefs: &crate::theme::Set
     );
     app.keymap = prefs.keymap.clone();
     app.sort_mode = prefs.sort_mode;
-    app.package_marker = prefs.package_marker;
+    app.package_marker = prefs.package_marker.clone();
     app.show_recent_pane = prefs.show_recent_pane;
     app.show_install_pane = prefs.show_install_pane;
     app.show_keybinds_footer = prefs.show_keybinds_footer;
     app.search_normal_mode = prefs.search_startup_mode;
     app.fuzzy_search_enabled = prefs.fuzzy_search;
     app.installed_packages_mode = prefs.installed_packages_mode;
+    app.expert_mode = prefs.expert_mode;
+    app.install_group_by_source = prefs.install_group_by_source;
+    crate::ui::helpers::set_size_units(
+        crate::ui::helpers::SizeUnits::from_config_key(&prefs.size_units).unwrap_or_default(),
+    );
     app.app_mode = if prefs.start_in_news {
         crate::state::types::AppMode::News
     } else {
@@ -379,6 +397,34 @@ fn load_details_cache(app: &mut AppState) {
     }
 }
 
+/// What: Load the cached news feed from disk.
+///
+/// Inputs:
+/// - `app`: Application state to update
+///
+/// Output: None (modifies app state in place)
+///
+/// Details:
+/// - Populates `news_items`/`news_results` from the versioned feed cache so the News view is
+///   filled before the background fetch completes; stale or unreadable caches are ignored.
+fn load_news_feed(app: &mut AppState) {
+    let Some((items, fetched_at)) =
+        super::super::persist::load_news_feed_cache(&app.news_feed_path)
+    else {
+        return;
+    };
+    tracing::info!(
+        path = %app.news_feed_path.display(),
+        count = items.len(),
+        fetched_at = %crate::util::ts_to_date(Some(fetched_at)),
+        "loaded news feed cache"
+    );
+    app.news_ready = !items.is_empty();
+    app.news_loading = items.is_empty();
+    app.news_results.clone_from(&items);
+    app.news_items = items;
+}
+
 /// What: Load recent searches from disk.
 ///
 /// Inputs:
@@ -395,6 +441,18 @@ fn load_recent_searches(app: &mut AppState) {
     {
         let count = list.len();
         app.load_recent_items(&list);
+        if let Ok(s) = std::fs::read_to_string(app.recent_counts_path())
+            && let Ok(counts) = serde_json::from_str::<std::collections::HashMap<String, usize>>(&s)
+        {
+            app.recent_counts = counts;
+        }
+        if let Ok(s) = std::fs::read_to_string(app.recent_views_path())
+            && let Ok(views) = serde_json::from_str::<
+                std::collections::HashMap<String, crate::state::RecentViewState>,
+            >(&s)
+        {
+            app.recent_views = views;
+        }
         if count > 0 {
             app.history_state.select(Some(0));
         }
@@ -655,7 +713,9 @@ pub fn initialize_app_state(
     );
 
     crate::logic::repos::load_repos_config_into_app(app, crate::theme::resolve_repos_config_path());
+    load_news_feed(app);
     apply_settings_to_app_state(app, prefs);
+    crate::logic::viewers::queue_startup_viewers(app, prefs);
 
     // Initialize locale system
     initialize_locale_system(app, &prefs.locale, prefs);
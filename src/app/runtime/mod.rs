@@ -218,3 +218,35 @@ pub async fn run(dry_run_flag: bool) -> Result<()> {
     // otherwise keep the tokio runtime alive until they complete
     std::process::exit(0);
 }
+
+/// What: Run one search through the TUI query pipeline without starting the TUI.
+///
+/// Inputs:
+/// - `query`: Search text as typed on the command line.
+///
+/// Output:
+/// - `Some((results, network_errors))` ranked and deduplicated exactly like the Results pane,
+///   or `None` when no official index has been fetched yet.
+///
+/// Details:
+/// - Loads the persisted official index, then dispatches the query via [`send_query`], so
+///   `search_normalize` and the `fuzzy_search` setting apply as they do in the TUI.
+/// - Returns before any AUR request when the index is empty, so callers can fall back.
+/// - Skips the search worker's debounce; AUR results still need network access.
+pub async fn search_once(query: &str) -> Option<(Vec<crate::state::PackageItem>, Vec<String>)> {
+    let mut app = AppState {
+        input: query.to_string(),
+        fuzzy_search_enabled: crate::theme::settings().fuzzy_search,
+        ..AppState::default()
+    };
+    crate::index::load_from_disk(&app.official_index_path);
+    if crate::index::official_is_empty() {
+        return None;
+    }
+    let (query_tx, mut query_rx) = tokio::sync::mpsc::unbounded_channel();
+    send_query(&mut app, &query_tx);
+    let Some(input) = query_rx.recv().await else {
+        return Some((Vec::new(), Vec::new()));
+    };
+    Some(workers::search::run_query_once(&input, &app.official_index_path).await)
+}
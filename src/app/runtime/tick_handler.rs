@@ -1,4 +1,6 @@
 use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Instant;
 
 use tokio::sync::mpsc;
@@ -24,6 +26,7 @@ use super::workers::UpdateCheckPayload;
 use super::workers::aur_vote::{AurVoteRequest, AurVoteStateRequest};
 use crate::install::ExecutorRequest;
 use crate::sources::VoteAction;
+use crate::util::notify::{CompletionNotifier, SystemNotifier, notify_completion_with};
 
 /// What: Queue AUR update after system update success when pacman + AUR were both selected.
 ///
@@ -75,6 +78,7 @@ fn maybe_queue_aur_after_system_update_success(app: &mut AppState) {
 ///
 /// Details:
 /// - Updates PKGBUILD text if still focused on the same package
+/// - Records the text as last viewed and keeps the previous visit's text for the diff view
 /// - Clears pending reload request
 pub fn handle_pkgbuild_result(
     app: &mut AppState,
@@ -85,6 +89,8 @@ pub fn handle_pkgbuild_result(
     if app.details_focus.as_deref() == Some(pkgname.as_str())
         || app.results.get(app.selected).map(|i| i.name.as_str()) == Some(pkgname.as_str())
     {
+        app.pkgb_previous_text =
+            crate::logic::pkgbuild_diff::remember_pkgbuild(&app.cache_path, &pkgname, &text);
         app.pkgb_text = Some(text);
         app.pkgb_package_name = Some(pkgname);
         // Clear any pending debounce request since we've successfully loaded
@@ -161,27 +167,60 @@ pub fn handle_pkgbuild_check_result(
 /// - `tick_tx`: Channel sender for tick events
 ///
 /// Details:
-/// - Updates comments if still focused on the same package
+/// - Updates comments if still focused on the same package, ordered and capped per settings
+/// - Pages after the first ("load more") are appended to the shown comments, skipping ones
+///   already present, and advance `comments_next_offset`
 /// - Sets loading state to false and error state if applicable
 pub fn handle_comments_result(
     app: &mut AppState,
     pkgname: String,
-    result: Result<Vec<crate::state::types::AurComment>, String>,
+    result: Result<crate::state::types::AurCommentsPage, String>,
     tick_tx: &mpsc::UnboundedSender<()>,
 ) {
+    let same_package = app.comments_package_name.as_deref() == Some(pkgname.as_str());
+    let is_next_page = match &result {
+        Ok(page) => page.offset > 0,
+        Err(_) => app.comments_page_loading && same_package,
+    };
+    if is_next_page {
+        app.comments_page_loading = false;
+        match result {
+            Ok(page) if same_package => append_comments_page(app, page),
+            Ok(_) => {}
+            Err(error) => {
+                app.toast_message = Some(error);
+                app.toast_expires_at = Some(Instant::now() + Duration::from_secs(5));
+            }
+        }
+        let _ = tick_tx.send(());
+        return;
+    }
     if app.details_focus.as_deref() == Some(pkgname.as_str())
         || app.results.get(app.selected).map(|i| i.name.as_str()) == Some(pkgname.as_str())
     {
         app.comments_loading = false;
+        app.comments_page_loading = false;
+        app.comments_page_requested = None;
         match result {
-            Ok(comments) => {
-                app.comments = comments;
+            Ok(page) => {
+                let prefs = crate::theme::settings();
+                let (shown, hidden) = crate::sources::arrange_comments(
+                    page.comments,
+                    &prefs.comments_order,
+                    prefs.comments_max_display,
+                );
+                app.comments = shown;
+                app.comments_hidden = hidden;
+                app.comments_next_offset =
+                    crate::sources::next_comments_offset(page.offset, page.has_more);
                 app.comments_package_name = Some(pkgname);
                 app.comments_fetched_at = Some(Instant::now());
                 app.comments_error = None;
             }
             Err(error) => {
                 app.comments.clear();
+                app.comments_hidden.clear();
+                app.comments_next_offset = None;
                 app.comments_package_name = None;
                 app.comments_fetched_at = None;
                 app.comments_error = Some(error);
@@ -191,6 +230,33 @@ pub fn handle_comments_result(
     let _ = tick_tx.send(());
 }
 
+/// What: Append a "load more" comments page to the shown comments.
+///
+/// Inputs:
+/// - `app`: Application state whose comments belong to the page's package
+/// - `page`: Fetched page at a non-zero offset
+///
+/// Output:
+/// - Extends `app.comments` with comments not already shown or hidden and advances the cursor.
+///
+/// Details:
+/// - Existing comments keep their positions, so click regions rebuilt on the next render stay
+///   where they were.
+fn append_comments_page(app: &mut AppState, page: crate::state::types::AurCommentsPage) {
+    let known: std::collections::HashSet<String> = app
+        .comments
+        .iter()
+        .chain(&app.comments_hidden)
+        .filter_map(|c| c.id.clone())
+        .collect();
+    app.comments.extend(
+        page.comments
+            .into_iter()
+            .filter(|c| c.id.as_ref().is_none_or(|id| !known.contains(id))),
+    );
+    app.comments_next_offset = crate::sources::next_comments_offset(page.offset, page.has_more);
+}
+
 /// What: Handle preflight summary result event.
 ///
 /// Inputs:
@@ -201,6 +267,7 @@ pub fn handle_comments_result(
 /// Details:
 /// - Updates preflight modal with computed summary
 /// - Respects cancellation flag
+/// - Caches completed (non-cancelled) results under the request's signature
 pub fn handle_summary_result(
     app: &mut AppState,
     summary_outcome: crate::logic::preflight::PreflightSummaryOutcome,
@@ -210,6 +277,7 @@ pub fn handle_summary_result(
     let cancelled = app
         .preflight_cancelled
         .load(std::sync::atomic::Ordering::Relaxed);
+    let signature = app.preflight_summary_pending_signature.take();
     if cancelled {
         tracing::debug!("[Runtime] Ignoring summary result (preflight cancelled)");
     } else {
@@ -219,17 +287,10 @@ pub fn handle_summary_result(
             package_count = summary_outcome.summary.package_count,
             "[Runtime] Preflight summary computation worker completed"
         );
-        if let crate::state::Modal::Preflight {
-            summary,
-            header_chips,
-            cached_reverse_deps_report,
-            ..
-        } = &mut app.modal
-        {
-            *summary = Some(Box::new(summary_outcome.summary));
-            *header_chips = summary_outcome.header;
-            *cached_reverse_deps_report = summary_outcome.reverse_deps_report;
+        if let Some(signature) = signature {
+            app.store_preflight_summary(signature, &summary_outcome);
         }
+        apply_summary_outcome(app, summary_outcome);
     }
     app.preflight_summary_resolving = false;
     // Clear preflight summary items
@@ -237,24 +298,65 @@ pub fn handle_summary_result(
     let _ = tick_tx.send(());
 }
 
+/// What: Show a preflight summary in the open Preflight modal.
+///
+/// Inputs:
+/// - `app`: Application state
+/// - `summary_outcome`: Computed or cached summary
+///
+/// Details:
+/// - Sandbox analysis already shown in the modal is folded into the summary risk.
+fn apply_summary_outcome(
+    app: &mut AppState,
+    summary_outcome: crate::logic::preflight::PreflightSummaryOutcome,
+) {
+    if let crate::state::Modal::Preflight {
+        summary,
+        header_chips,
+        cached_reverse_deps_report,
+        ..
+    } = &mut app.modal
+    {
+        *summary = Some(Box::new(summary_outcome.summary));
+        *header_chips = summary_outcome.header;
+        *cached_reverse_deps_report = summary_outcome.reverse_deps_report;
+    }
+    super::handlers::apply_sandbox_risk_to_modal(&mut app.modal);
+}
+
 /// What: Check and trigger summary resolution if conditions are met.
+///
+/// Details:
+/// - Reuses the cached summary when the package set and action match the last computation.
 fn check_and_trigger_summary_resolution(
     app: &mut AppState,
     summary_req_tx: &mpsc::UnboundedSender<(
         Vec<PackageItem>,
         crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
     )>,
 ) {
     if let Some((items, action)) = app.preflight_summary_items.take()
         && !app.preflight_summary_resolving
     {
+        if let Some(outcome) = app.cached_preflight_summary(&items, action) {
+            tracing::debug!(
+                "[Runtime] Tick: Reusing cached preflight summary for {} items, action={:?}",
+                items.len(),
+                action
+            );
+            apply_summary_outcome(app, outcome);
+            return;
+        }
         tracing::debug!(
             "[Runtime] Tick: Triggering summary computation for {} items, action={:?}",
             items.len(),
             action
         );
         app.preflight_summary_resolving = true;
-        let _ = summary_req_tx.send((items, action));
+        app.preflight_summary_pending_signature =
+            Some(crate::logic::preflight::summary_signature(&items, action));
+        let _ = summary_req_tx.send((items, action, Arc::clone(&app.preflight_cancelled)));
     } else if app.preflight_summary_items.is_some() {
         tracing::debug!(
             "[Runtime] Tick: NOT triggering summary - items={}, preflight_summary_resolving={}",
@@ -446,6 +548,7 @@ fn handle_preflight_resolution(
     summary_req_tx: &mpsc::UnboundedSender<(
         Vec<PackageItem>,
         crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
     )>,
 ) {
     let cancelled = app
@@ -509,6 +612,86 @@ fn handle_pkgbuild_reload_debounce(
     app.pkgb_reload_requested_for = None;
 }
 
+/// What: Reload the theme when the `theme_file` palette changes on disk.
+///
+/// Inputs:
+/// - `app`: Application state (watch state and toast)
+///
+/// Output: None
+///
+/// Details:
+/// - Polls the file's modification time at most once per second and reloads through
+///   `theme::reload_theme_checked`, the same path as the reload-config shortcut.
+fn handle_theme_file_watch(app: &mut AppState) {
+    handle_theme_file_watch_with(
+        app,
+        crate::theme::theme_file_path(),
+        Instant::now(),
+        crate::theme::reload_theme_checked,
+    );
+}
+
+/// What: Poll a theme palette file and reload when its modification time changes.
+///
+/// Inputs:
+/// - `app`: Application state (watch state and toast)
+/// - `path`: Configured `theme_file` path, or `None` when unset
+/// - `now`: Current time for throttling
+/// - `reload`: Reload action; `Err` carries a message for the error toast
+///
+/// Output:
+/// - `true` when a reload was attempted.
+///
+/// Details:
+/// - The first observation of a path only records its modification time, so startup and changes
+///   of the `theme_file` setting do not reload twice.
+/// - A missing file is recorded but not reloaded (editors may replace files via rename).
+/// - Shows a `theme_reloaded` toast on success, or a `theme_reload_failed` toast with the error.
+fn handle_theme_file_watch_with(
+    app: &mut AppState,
+    path: Option<std::path::PathBuf>,
+    now: Instant,
+    reload: impl FnOnce() -> Result<(), String>,
+) -> bool {
+    const THEME_FILE_POLL: Duration = Duration::from_secs(1);
+    if app
+        .theme_file_checked_at
+        .is_some_and(|at| now.saturating_duration_since(at) < THEME_FILE_POLL)
+    {
+        return false;
+    }
+    app.theme_file_checked_at = Some(now);
+    let Some(path) = path else {
+        app.theme_file_watch = None;
+        return false;
+    };
+    let mtime = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+    let changed = matches!(
+        &app.theme_file_watch,
+        Some((watched, seen)) if *watched == path && *seen != mtime
+    );
+    app.theme_file_watch = Some((path, mtime));
+    if !changed || mtime.is_none() {
+        return false;
+    }
+    match reload() {
+        Ok(()) => {
+            app.toast_message = Some(crate::i18n::t(app, "app.toasts.theme_reloaded"));
+            app.toast_expires_at = Some(now + Duration::from_secs(3));
+        }
+        Err(msg) => {
+            tracing::warn!(error = %msg, "theme_file reload failed");
+            app.toast_message = Some(crate::i18n::t_fmt1(
+                app,
+                "app.toasts.theme_reload_failed",
+                msg,
+            ));
+            app.toast_expires_at = Some(now + Duration::from_secs(6));
+        }
+    }
+    true
+}
+
 /// What: Handle installed cache polling logic.
 ///
 /// Inputs:
@@ -524,6 +707,50 @@ fn handle_pkgbuild_reload_debounce(
 fn handle_installed_cache_polling(
     app: &mut AppState,
     query_tx: &mpsc::UnboundedSender<QueryInput>,
+) {
+    handle_installed_cache_polling_with(app, query_tx, &SystemNotifier, || {
+        crate::theme::settings().completion_notify
+    });
+}
+
+/// What: Announce a finished install/remove transaction per the `completion_notify` setting.
+///
+/// Inputs:
+/// - `app`: Application state (for i18n)
+/// - `notifier`: Notification backend
+/// - `mode`: Configured `completion_notify` mode
+/// - `key`: Locale key of the message (takes the package list as argument)
+/// - `names`: Packages of the completed transaction
+///
+/// Output: None
+fn notify_transaction_complete<N: CompletionNotifier>(
+    app: &AppState,
+    notifier: &N,
+    mode: &str,
+    key: &str,
+    names: &[String],
+) {
+    let body = crate::i18n::t_fmt1(app, key, names.join(", "));
+    notify_completion_with(notifier, mode, "Pacsea", &body);
+}
+
+/// What: Installed cache polling with an injectable completion notifier.
+///
+/// Inputs:
+/// - `app`: Application state
+/// - `query_tx`: Channel sender for query input
+/// - `notifier`: Backend used when a tracked transaction completes
+/// - `notify_mode`: Returns the `completion_notify` mode (read only on completion)
+///
+/// Output: None
+///
+/// Details:
+/// - See [`handle_installed_cache_polling`]; notifications fire once per completed transaction
+fn handle_installed_cache_polling_with<N: CompletionNotifier>(
+    app: &mut AppState,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
+    notifier: &N,
+    notify_mode: impl Fn() -> String,
 ) {
     let Some(deadline) = app.refresh_installed_until else {
         return;
@@ -559,10 +786,21 @@ fn handle_installed_cache_polling(
     if let Some(pending) = maybe_pending_installs {
         let all_installed = pending.iter().all(|n| crate::index::is_installed(n));
         if all_installed {
+            notify_transaction_complete(
+                app,
+                notifier,
+                &notify_mode(),
+                "app.toasts.transaction_installed",
+                &pending,
+            );
+            if !app.dry_run {
+                crate::app::record_activity(crate::app::ActivityAction::Install, &pending);
+            }
             // Clear install list and stop tracking
             app.install_list.clear();
             app.install_list_names.clear();
             app.install_dirty = true;
+            app.invalidate_preflight_summary_cache();
             app.pending_install_names = None;
             // Clear dependency cache when install list is cleared
             app.install_list_deps.clear();
@@ -578,9 +816,19 @@ fn handle_installed_cache_polling(
     if let Some(pending_rm) = maybe_pending_removes {
         let all_removed = pending_rm.iter().all(|n| !crate::index::is_installed(n));
         if all_removed {
+            notify_transaction_complete(
+                app,
+                notifier,
+                &notify_mode(),
+                "app.toasts.transaction_removed",
+                &pending_rm,
+            );
             if let Err(e) = crate::install::log_removed(&pending_rm) {
                 let _ = e; // ignore logging errors
             }
+            if !app.dry_run {
+                crate::app::record_activity(crate::app::ActivityAction::Remove, &pending_rm);
+            }
 
             // Check for config directories after successful removal
             if let Ok(home) = std::env::var("HOME") {
@@ -682,6 +930,7 @@ pub fn handle_tick(
     summary_req_tx: &mpsc::UnboundedSender<(
         Vec<PackageItem>,
         crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
     )>,
     updates_tx: &mpsc::UnboundedSender<UpdateCheckPayload>,
     aur_vote_req_tx: &mpsc::UnboundedSender<AurVoteRequest>,
@@ -775,6 +1024,11 @@ pub fn handle_tick(
         }
     }
 
+    // Track failed background fetches so the retry action knows what to re-run
+    for (category, success) in crate::sources::take_fetch_outcomes() {
+        app.set_fetch_failed(category, !success);
+    }
+
     // Refresh updates list if flag is set (manual refresh via button click)
     if app.refresh_updates {
         app.refresh_updates = false;
@@ -835,9 +1089,17 @@ pub fn handle_tick(
     // Send pending executor request if PreflightExec modal is active
     if let Some(request) = app.pending_executor_request.take()
         && matches!(app.modal, crate::state::Modal::PreflightExec { .. })
-        && let Err(e) = executor_req_tx.send(request)
     {
-        tracing::error!("Failed to send executor request: {:?}", e);
+        crate::logic::exec_log::reset_exec_log_view(app);
+        // Snapshot existing .pacnew/.pacsave files so the post-scan only reports new ones
+        crate::logic::pacnew::begin_pac_files_check_with(app, || {
+            crate::logic::pacnew::scan_pac_files(std::path::Path::new(
+                crate::logic::pacnew::PAC_FILES_ROOT,
+            ))
+        });
+        if let Err(e) = executor_req_tx.send(request) {
+            tracing::error!("Failed to send executor request: {:?}", e);
+        }
     }
 
     // Send pending post-summary request if Loading modal is active
@@ -945,10 +1207,33 @@ pub fn handle_tick(
         }
     }
 
+    // Check plain-text import summary from the import thread
+    if let Some(summary_arc) = app.pending_import_summary.take() {
+        let summary = summary_arc.lock().ok().and_then(|mut s| s.take());
+        if let Some((added, unresolved)) = summary {
+            app.toast_message = Some(crate::i18n::t_fmt2(
+                app,
+                "app.toasts.import_summary",
+                added,
+                unresolved,
+            ));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(4));
+        } else {
+            app.pending_import_summary = Some(summary_arc);
+        }
+    }
+
     handle_pkgbuild_reload_debounce(app, pkgb_req_tx);
 
+    handle_theme_file_watch(app);
+
     handle_installed_cache_polling(app, query_tx);
 
+    if std::mem::take(&mut app.search_requery_pending) {
+        send_query(app, query_tx);
+    }
+
     if app.need_ring_prefetch
         && app
             .ring_resume_at
@@ -1686,6 +1971,9 @@ mod tests {
     /// - Tests that PKGBUILD results are properly handled
     fn handle_pkgbuild_result_updates_when_focused() {
         let mut app = new_app();
+        let cache_dir =
+            std::env::temp_dir().join(format!("pacsea_test_pkgb_result_{}", std::process::id()));
+        app.cache_path = cache_dir.join("details_cache.json");
         app.details_focus = Some("test-package".to_string());
         app.pkgb_reload_requested_at = Some(Instant::now());
         app.pkgb_reload_requested_for = Some("test-package".to_string());
@@ -1705,6 +1993,61 @@ mod tests {
         // Pending request should be cleared
         assert!(app.pkgb_reload_requested_at.is_none());
         assert!(app.pkgb_reload_requested_for.is_none());
+        let _ = std::fs::remove_dir_all(&cache_dir);
+    }
+
+    #[test]
+    /// What: Verify comment pages advance the cursor and "load more" pages append.
+    ///
+    /// Inputs:
+    /// - First page (offset 0, more available) for the focused package, then a second page
+    ///   repeating one comment and ending the pagination.
+    ///
+    /// Output:
+    /// - Cursor moves from one page to `None`; the second page appends only the new comment
+    ///   after the existing ones.
+    fn handle_comments_result_paginates_and_appends() {
+        use crate::state::types::{AurComment, AurCommentsPage};
+        let comment = |id: &str| AurComment {
+            id: Some(id.to_string()),
+            author: "user".to_string(),
+            date: String::new(),
+            date_timestamp: None,
+            date_url: None,
+            content: id.to_string(),
+            pinned: false,
+        };
+        let mut app = new_app();
+        app.details_focus = Some("demo".to_string());
+        let (tick_tx, _tick_rx) = mpsc::unbounded_channel();
+
+        let first = AurCommentsPage {
+            offset: 0,
+            comments: vec![comment("a"), comment("b")],
+            has_more: true,
+        };
+        handle_comments_result(&mut app, "demo".to_string(), Ok(first), &tick_tx);
+        assert_eq!(
+            app.comments_next_offset,
+            Some(crate::sources::COMMENTS_PAGE_SIZE)
+        );
+
+        app.comments_page_loading = true;
+        let second = AurCommentsPage {
+            offset: crate::sources::COMMENTS_PAGE_SIZE,
+            comments: vec![comment("b"), comment("c")],
+            has_more: false,
+        };
+        handle_comments_result(&mut app, "demo".to_string(), Ok(second), &tick_tx);
+        let ids: Vec<_> = app
+            .comments
+            .iter()
+            .chain(&app.comments_hidden)
+            .filter_map(|c| c.id.as_deref())
+            .collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        assert_eq!(app.comments_next_offset, None);
+        assert!(!app.comments_page_loading);
     }
 
     #[test]
@@ -1876,6 +2219,9 @@ mod tests {
                 service_restart_units: vec![],
                 summary_warnings: vec![],
                 summary_notes: vec![],
+                orphaned_dependencies: vec![],
+                conflicts: vec![],
+                hook_notes: vec![],
             },
             header: crate::state::modal::PreflightHeaderChips {
                 package_count: 1,
@@ -1907,4 +2253,256 @@ mod tests {
         assert!(!app.preflight_summary_resolving);
         assert!(app.preflight_summary_items.is_none());
     }
+
+    /// What: Build an empty Preflight modal on the Summary tab.
+    ///
+    /// Inputs: None
+    /// Output: `Modal::Preflight` without a summary
+    fn empty_preflight_modal() -> crate::state::Modal {
+        crate::state::Modal::Preflight {
+            items: vec![],
+            action: PreflightAction::Install,
+            tab: PreflightTab::Summary,
+            summary: None,
+            summary_scroll: 0,
+            header_chips: crate::state::modal::PreflightHeaderChips::default(),
+            dependency_info: vec![],
+            dep_selected: 0,
+            dep_tree_expanded: std::collections::HashSet::new(),
+            deps_error: None,
+            file_info: vec![],
+            file_selected: 0,
+            file_tree_expanded: std::collections::HashSet::new(),
+            files_error: None,
+            service_info: vec![],
+            service_selected: 0,
+            services_loaded: false,
+            services_error: None,
+            sandbox_info: vec![],
+            sandbox_selected: 0,
+            sandbox_tree_expanded: std::collections::HashSet::new(),
+            sandbox_loaded: false,
+            sandbox_error: None,
+            selected_optdepends: std::collections::HashMap::new(),
+            cascade_mode: crate::state::modal::CascadeMode::Basic,
+            cached_reverse_deps_report: None,
+        }
+    }
+
+    #[test]
+    /// What: Verify identical summary requests reuse the cache and list changes invalidate it.
+    ///
+    /// Inputs:
+    /// - Two summary requests for the same package, then one after adding a package
+    ///   to the install list
+    ///
+    /// Output:
+    /// - First request is sent to the worker; the second is answered from the cache without a
+    ///   request; the third is sent again
+    fn summary_cache_reused_until_list_changes() {
+        let mut app = new_app();
+        let items = vec![PackageItem::test_item("ripgrep", "extra")];
+        let (summary_tx, mut summary_rx) = mpsc::unbounded_channel();
+        let (tick_tx, _tick_rx) = mpsc::unbounded_channel();
+
+        app.modal = empty_preflight_modal();
+        app.preflight_summary_items = Some((items.clone(), PreflightAction::Install));
+        check_and_trigger_summary_resolution(&mut app, &summary_tx);
+        let (requested, action, _) = summary_rx.try_recv().expect("first request is computed");
+        assert_eq!(action, PreflightAction::Install);
+        let outcome = crate::logic::preflight::PreflightSummaryOutcome {
+            summary: crate::state::modal::PreflightSummaryData {
+                packages: vec![],
+                package_count: requested.len(),
+                aur_count: 0,
+                download_bytes: 0,
+                install_delta_bytes: 0,
+                risk_score: 0,
+                risk_level: crate::state::modal::RiskLevel::Low,
+                risk_reasons: vec![],
+                major_bump_packages: vec![],
+                core_system_updates: vec![],
+                pacnew_candidates: 0,
+                pacsave_candidates: 0,
+                config_warning_packages: vec![],
+                service_restart_units: vec![],
+                summary_warnings: vec![],
+                summary_notes: vec![],
+                orphaned_dependencies: vec![],
+                conflicts: vec![],
+                hook_notes: vec![],
+            },
+            header: crate::state::modal::PreflightHeaderChips::default(),
+            reverse_deps_report: None,
+        };
+        handle_summary_result(&mut app, outcome, &tick_tx);
+
+        app.modal = empty_preflight_modal();
+        app.preflight_summary_items = Some((items.clone(), PreflightAction::Install));
+        check_and_trigger_summary_resolution(&mut app, &summary_tx);
+        assert!(summary_rx.try_recv().is_err(), "cached summary is reused");
+        assert!(!app.preflight_summary_resolving);
+        let Modal::Preflight { summary, .. } = &app.modal else {
+            panic!("Expected Preflight modal");
+        };
+        assert_eq!(summary.as_ref().map(|s| s.package_count), Some(1));
+
+        let mut other = items[0].clone();
+        other.name = "fd".into();
+        crate::logic::add_to_install_list(&mut app, other);
+        app.preflight_summary_items = Some((items, PreflightAction::Install));
+        check_and_trigger_summary_resolution(&mut app, &summary_tx);
+        assert!(summary_rx.try_recv().is_ok(), "list change busts the cache");
+    }
+
+    #[test]
+    /// What: Verify a detected transaction completion fires the notifier unless disabled.
+    ///
+    /// Inputs:
+    /// - Pending removal of a package that is not installed (so completion is detected at once)
+    /// - Recording notifier with `completion_notify` set to `bell`, `both`, and `off`
+    ///
+    /// Output:
+    /// - `bell`/`both` record the matching notifications; `off` records nothing
+    /// - Removal tracking is cleared in every case
+    ///
+    /// Details:
+    /// - Redirects `HOME` so the removal log and config scan stay inside a temp dir
+    fn installed_cache_polling_notifies_on_completion() {
+        use std::cell::RefCell;
+
+        #[derive(Default)]
+        struct Recorder(RefCell<Vec<String>>);
+        impl CompletionNotifier for Recorder {
+            fn bell(&self) {
+                self.0.borrow_mut().push("bell".into());
+            }
+            fn desktop(&self, _summary: &str, _body: &str) {
+                self.0.borrow_mut().push("desktop".into());
+            }
+        }
+
+        let _guard = crate::theme::test_mutex()
+            .lock()
+            .expect("Test mutex poisoned");
+        let mut home = std::env::temp_dir();
+        home.push(format!(
+            "pacsea_test_completion_notify_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("System time is before UNIX epoch")
+                .as_nanos()
+        ));
+        let _ = std::fs::create_dir_all(&home);
+        let orig_home = std::env::var_os("HOME");
+        unsafe { std::env::set_var("HOME", home.display().to_string()) };
+
+        let rt = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime for test");
+        let _enter = rt.enter();
+        let (query_tx, _query_rx) = mpsc::unbounded_channel();
+        let pkg = "pacsea-test-not-installed-pkg".to_string();
+        for (mode, expected) in [
+            ("bell", vec!["bell"]),
+            ("both", vec!["bell", "desktop"]),
+            ("off", vec![]),
+        ] {
+            let mut app = new_app();
+            app.pending_remove_names = Some(vec![pkg.clone()]);
+            app.refresh_installed_until = Some(Instant::now() + Duration::from_secs(8));
+            let rec = Recorder::default();
+            handle_installed_cache_polling_with(&mut app, &query_tx, &rec, || mode.to_string());
+            assert_eq!(*rec.0.borrow(), expected, "mode {mode}");
+            assert!(app.pending_remove_names.is_none());
+        }
+
+        unsafe {
+            if let Some(v) = orig_home {
+                std::env::set_var("HOME", v);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    /// What: Verify a changed `theme_file` modification time triggers one reload attempt.
+    ///
+    /// Inputs:
+    /// - Temp palette file polled once, then its mtime moved forward and polled again; a third
+    ///   poll with a failing reload after another mtime change.
+    ///
+    /// Output:
+    /// - No reload on the first observation, a reload plus success toast after the change, and
+    ///   an error toast carrying the reload message.
+    ///
+    /// Details:
+    /// - Uses an injected reload closure so the global theme store is not touched.
+    fn theme_file_watch_reloads_on_mtime_change() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be creatable");
+        let path = dir.path().join("palette.toml");
+        std::fs::write(&path, "background_base = \"#000000\"\n").expect("write palette");
+        let set_mtime = |secs: u64| {
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .and_then(|f| f.set_modified(std::time::UNIX_EPOCH + Duration::from_secs(secs)))
+                .expect("set palette mtime");
+        };
+        set_mtime(1_000);
+        let mut app = new_app();
+        for (key, text) in [
+            ("app.toasts.theme_reloaded", "Theme reloaded"),
+            ("app.toasts.theme_reload_failed", "Theme file error: {}"),
+        ] {
+            app.translations.insert(key.to_string(), text.to_string());
+        }
+        let start = Instant::now();
+        let mut reloads = 0;
+
+        assert!(!handle_theme_file_watch_with(
+            &mut app,
+            Some(path.clone()),
+            start,
+            || {
+                reloads += 1;
+                Ok(())
+            }
+        ));
+        set_mtime(2_000);
+        // Throttled: a poll within a second does not look at the file
+        assert!(!handle_theme_file_watch_with(
+            &mut app,
+            Some(path.clone()),
+            start + Duration::from_millis(200),
+            || Ok(())
+        ));
+        assert!(handle_theme_file_watch_with(
+            &mut app,
+            Some(path.clone()),
+            start + Duration::from_secs(2),
+            || {
+                reloads += 1;
+                Ok(())
+            }
+        ));
+        assert_eq!(reloads, 1);
+        assert_eq!(app.toast_message.as_deref(), Some("Theme reloaded"));
+
+        set_mtime(3_000);
+        assert!(handle_theme_file_watch_with(
+            &mut app,
+            Some(path),
+            start + Duration::from_secs(4),
+            || Err("missing required theme colors: text_primary".to_string())
+        ));
+        assert!(
+            app.toast_message.as_deref().is_some_and(
+                |msg| msg.starts_with("Theme file error") && msg.contains("text_primary")
+            ),
+            "error toast should carry the reload message: {:?}",
+            app.toast_message
+        );
+    }
 }
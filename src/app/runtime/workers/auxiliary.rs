@@ -13,6 +13,40 @@ use crate::state::ArchStatusColor;
 use crate::app::runtime::workers::news;
 use crate::app::runtime::workers::updates;
 
+/// What: Fetch the Arch status text once and forward it to the UI.
+///
+/// Inputs:
+/// - `status_tx`: Channel sender for Arch status updates
+///
+/// Output:
+/// - None (spawns async task)
+///
+/// Details:
+/// - Records the [`FetchCategory::Status`](crate::state::FetchCategory::Status) outcome so a
+///   failure can be retried on demand.
+pub fn spawn_status_fetch(status_tx: &mpsc::UnboundedSender<(String, ArchStatusColor)>) {
+    let status_tx = status_tx.clone();
+    tokio::spawn(async move {
+        send_status_or_record_failure(&status_tx).await;
+    });
+}
+
+/// What: Fetch Arch status, send it on success, and record the fetch outcome.
+async fn send_status_or_record_failure(
+    status_tx: &mpsc::UnboundedSender<(String, ArchStatusColor)>,
+) {
+    match sources::fetch_arch_status_text().await {
+        Ok((txt, color)) => {
+            sources::record_fetch_outcome(crate::state::FetchCategory::Status, true);
+            let _ = status_tx.send((txt, color));
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "arch status fetch failed");
+            sources::record_fetch_outcome(crate::state::FetchCategory::Status, false);
+        }
+    }
+}
+
 /// What: Spawns Arch status worker that fetches status once at startup and periodically.
 ///
 /// Inputs:
@@ -26,21 +60,14 @@ use crate::app::runtime::workers::updates;
 /// - Periodically refreshes Arch status every 120 seconds
 fn spawn_status_worker(status_tx: &mpsc::UnboundedSender<(String, ArchStatusColor)>) {
     // Fetch Arch status text once at startup
-    let status_tx_once = status_tx.clone();
-    tokio::spawn(async move {
-        if let Ok((txt, color)) = sources::fetch_arch_status_text().await {
-            let _ = status_tx_once.send((txt, color));
-        }
-    });
+    spawn_status_fetch(status_tx);
 
     // Periodically refresh Arch status every 120 seconds
     let status_tx_periodic = status_tx.clone();
     tokio::spawn(async move {
         loop {
             sleep(Duration::from_mins(2)).await;
-            if let Ok((txt, color)) = sources::fetch_arch_status_text().await {
-                let _ = status_tx_periodic.send((txt, color));
-            }
+            send_status_or_record_failure(&status_tx_periodic).await;
         }
     });
 }
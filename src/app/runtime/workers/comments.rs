@@ -3,32 +3,39 @@
 use tokio::sync::mpsc;
 
 use crate::sources;
-use crate::state::types::AurComment;
+use crate::state::types::AurCommentsPage;
 
 /// What: Spawn background worker for AUR comments fetching.
 ///
 /// Inputs:
-/// - `comments_req_rx`: Channel receiver for comments requests (package name as String)
+/// - `comments_req_rx`: Channel receiver for first-page requests (package name as String)
+/// - `comments_page_req_rx`: Channel receiver for "load more" requests (package name, offset)
 /// - `comments_res_tx`: Channel sender for comments responses
 ///
 /// Output:
 /// - None (spawns async task)
 ///
 /// Details:
-/// - Listens for package name requests on the channel
-/// - Fetches comments asynchronously using `fetch_aur_comments`
-/// - Sends results as `(String, Result<Vec<AurComment>, String>)` matching PKGBUILD pattern
+/// - Listens for package name requests on the channel and fetches only the first page
+/// - "Load more" requests fetch the page at the given offset using `fetch_aur_comments_page`
+/// - Sends results as `(String, Result<AurCommentsPage, String>)` matching PKGBUILD pattern
 /// - Handles errors gracefully (sends error message instead of panicking)
 pub fn spawn_comments_worker(
     mut comments_req_rx: mpsc::UnboundedReceiver<String>,
-    comments_res_tx: mpsc::UnboundedSender<(String, Result<Vec<AurComment>, String>)>,
+    mut comments_page_req_rx: mpsc::UnboundedReceiver<(String, usize)>,
+    comments_res_tx: mpsc::UnboundedSender<(String, Result<AurCommentsPage, String>)>,
 ) {
     tokio::spawn(async move {
-        while let Some(pkgname) = comments_req_rx.recv().await {
+        loop {
+            let (pkgname, offset) = tokio::select! {
+                Some(pkgname) = comments_req_rx.recv() => (pkgname, 0),
+                Some(request) = comments_page_req_rx.recv() => request,
+                else => break,
+            };
             let name = pkgname.clone();
-            match sources::fetch_aur_comments(pkgname).await {
-                Ok(comments) => {
-                    let _ = comments_res_tx.send((name, Ok(comments)));
+            match sources::fetch_aur_comments_page(pkgname, offset).await {
+                Ok(page) => {
+                    let _ = comments_res_tx.send((name, Ok(page)));
                 }
                 Err(e) => {
                     let _ =
@@ -16,6 +16,7 @@ use crate::install::{
 /// - `items`: Packages to install
 /// - `password`: Optional sudo password
 /// - `dry_run`: Whether to run in dry-run mode
+/// - `aur_helper`: Explicit AUR helper chosen in the install flow, if any
 /// - `res_tx`: Channel sender for output
 ///
 /// Details:
@@ -28,14 +29,16 @@ fn handle_install_request(
     items: Vec<crate::state::PackageItem>,
     password: Option<crate::state::SecureString>,
     dry_run: bool,
+    aur_helper: Option<String>,
     res_tx: mpsc::UnboundedSender<ExecutorOutput>,
 ) {
     use crate::state::Source;
 
     tracing::info!(
-        "[Runtime] Executor worker received install request: {} items, dry_run={}",
+        "[Runtime] Executor worker received install request: {} items, dry_run={}, aur_helper={:?}",
         items.len(),
-        dry_run
+        dry_run,
+        aur_helper
     );
 
     // Check if there are AUR packages
@@ -45,10 +48,15 @@ fn handle_install_request(
     // For AUR packages: cache sudo credentials first, then run paru/yay (same sudo prompt flow)
     let cmd = match if has_aur {
         // Build AUR command without password embedded
-        build_install_command_for_executor(&items, None, dry_run)
+        build_install_command_for_executor(&items, None, dry_run, aur_helper.as_deref())
     } else {
         // Build official command with password piping
-        build_install_command_for_executor(&items, password.as_deref(), dry_run)
+        build_install_command_for_executor(
+            &items,
+            password.as_deref(),
+            dry_run,
+            aur_helper.as_deref(),
+        )
     } {
         Ok(c) => c,
         Err(err) => {
@@ -358,7 +366,8 @@ pub fn spawn_executor_worker(
                     items,
                     password,
                     dry_run,
-                } => handle_install_request(items, password, dry_run, res_tx),
+                    aur_helper,
+                } => handle_install_request(items, password, dry_run, aur_helper, res_tx),
                 ExecutorRequest::Remove {
                     names,
                     password,
@@ -690,7 +699,10 @@ fn execute_command_pty(
 
     tracing::debug!("[PTY] Spawning bash command");
     let mut child = match pty.slave.spawn_command(cmd_builder) {
-        Ok(child) => child,
+        Ok(child) => {
+            crate::install::audit_log_spawn(&["bash", "-c", cmd]);
+            child
+        }
         Err(e) => {
             tracing::error!("[PTY] Failed to spawn command: {e}");
             let _ = res_tx.send(ExecutorOutput::Error(format!("Failed to spawn: {e}")));
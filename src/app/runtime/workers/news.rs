@@ -209,6 +209,8 @@ pub fn spawn_startup_news_worker(
             seen_pkg_versions: &mut seen_versions,
             seen_aur_comments: &mut seen_aur_comments,
             max_age_days: optimized_max_age,
+            source_urls: sources::NewsSourceUrls::from_settings(&prefs),
+            max_parallel_slow_fetches: prefs.news_max_parallel_slow_fetches,
         };
         tracing::info!(
             limit = updates_limit,
@@ -320,6 +322,7 @@ pub fn spawn_aggregated_news_feed_worker(
             tokio::time::sleep(Duration::from_millis(stagger_ms)).await;
         }
         let installed_set = ensure_installed_set(installed).await;
+        let prefs = crate::theme::settings();
         let ctx = sources::NewsFeedContext {
             force_emit_all: true,
             updates_list_path: Some(crate::theme::lists_dir().join("available_updates.txt")),
@@ -334,6 +337,8 @@ pub fn spawn_aggregated_news_feed_worker(
             seen_pkg_versions: &mut seen_versions,
             seen_aur_comments: &mut seen_aur_comments,
             max_age_days: None, // Main feed doesn't use date filtering
+            source_urls: sources::NewsSourceUrls::from_settings(&prefs),
+            max_parallel_slow_fetches: prefs.news_max_parallel_slow_fetches,
         };
         match sources::fetch_news_feed(ctx).await {
             Ok(feed) => {
@@ -382,6 +387,7 @@ pub fn spawn_aggregated_news_feed_worker(
             }
             Err(e) => {
                 tracing::warn!(error = %e, "failed to fetch news feed");
+                sources::record_fetch_outcome(crate::state::FetchCategory::News, false);
             }
         }
     });
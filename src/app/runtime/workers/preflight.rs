@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
 use tokio::sync::mpsc;
 
 use crate::state::PackageItem;
@@ -241,7 +244,8 @@ pub fn spawn_sandbox_worker(
 /// What: Spawn background worker for preflight summary computation.
 ///
 /// Inputs:
-/// - `summary_req_rx`: Channel receiver for summary computation requests
+/// - `summary_req_rx`: Channel receiver for summary computation requests (with the
+///   preflight cancellation flag)
 /// - `summary_res_tx`: Channel sender for summary computation responses
 ///
 /// Details:
@@ -251,19 +255,24 @@ pub fn spawn_summary_worker(
     mut summary_req_rx: mpsc::UnboundedReceiver<(
         Vec<PackageItem>,
         crate::state::modal::PreflightAction,
+        Arc<AtomicBool>,
     )>,
     summary_res_tx: mpsc::UnboundedSender<crate::logic::preflight::PreflightSummaryOutcome>,
 ) {
     let summary_res_tx_bg = summary_res_tx;
     tokio::spawn(async move {
-        while let Some((items, action)) = summary_req_rx.recv().await {
+        while let Some((items, action, cancelled)) = summary_req_rx.recv().await {
             // Run blocking summary computation in a thread pool
             let items_clone = items.clone();
             let res_tx = summary_res_tx_bg.clone();
             let res_tx_error = summary_res_tx_bg.clone();
             let handle = tokio::task::spawn_blocking(move || {
-                let summary =
-                    crate::logic::preflight::compute_preflight_summary(&items_clone, action);
+                let summary = crate::logic::preflight::compute_preflight_summary_with_cancel(
+                    &items_clone,
+                    action,
+                    &crate::logic::preflight::SystemCommandRunner,
+                    &cancelled,
+                );
                 let _ = res_tx.send(summary);
             });
             // CRITICAL: Always await and send a result, even if task panics
@@ -298,6 +307,9 @@ pub fn spawn_summary_worker(
                                 service_restart_units: Vec::new(),
                                 summary_warnings: vec!["Summary computation failed".to_string()],
                                 summary_notes: Vec::new(),
+                                orphaned_dependencies: Vec::new(),
+                                conflicts: Vec::new(),
+                                hook_notes: Vec::new(),
                             },
                             header: crate::state::modal::PreflightHeaderChips {
                                 package_count: 0,
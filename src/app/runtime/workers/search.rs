@@ -4,14 +4,14 @@ use std::time::Instant;
 
 use tokio::{
     select,
-    sync::mpsc,
+    sync::{mpsc, watch},
     time::{Duration, sleep},
 };
 
 use crate::index as pkgindex;
 use crate::sources;
 use crate::state::{PackageItem, QueryInput, SearchResults, Source};
-use crate::util::{fuzzy_match_rank_with_matcher, match_rank, repo_order};
+use crate::util::{fuzzy_match_rank_with_matcher, match_rank, repo_order_with};
 
 /// What: Spawn background worker for search queries.
 ///
@@ -22,10 +22,12 @@ use crate::util::{fuzzy_match_rank_with_matcher, match_rank, repo_order};
 /// - `index_path`: Path to official package index
 ///
 /// Details:
-/// - Debounces queries with 250ms window
-/// - Enforces minimum 300ms interval between searches
+/// - Debounces queries by `search_debounce_ms` (read per query, so edits apply without restart);
+///   the debounce is the only delay before a search runs
 /// - Handles empty queries by returning all official packages
 /// - Searches both official and AUR repositories
+/// - Publishes the newest received query id on a cancellation channel; in-flight searches for
+///   older ids stop at their next await point and send neither results nor network errors
 pub fn spawn_search_worker(
     mut query_rx: mpsc::UnboundedReceiver<QueryInput>,
     search_result_tx: mpsc::UnboundedSender<SearchResults>,
@@ -34,17 +36,23 @@ pub fn spawn_search_worker(
 ) {
     let net_err_tx_search = net_err_tx.clone();
     tokio::spawn(async move {
-        const DEBOUNCE_MS: u64 = 250;
-        const MIN_INTERVAL_MS: u64 = 300;
-        let mut last_sent = Instant::now()
-            .checked_sub(Duration::from_millis(MIN_INTERVAL_MS))
-            .unwrap_or_else(Instant::now);
+        let (cancel_tx, _) = watch::channel(0u64);
         loop {
             let Some(mut latest) = query_rx.recv().await else {
                 break;
             };
-            loop {
-                select! { Some(new_q) = query_rx.recv() => { latest = new_q; } () = sleep(Duration::from_millis(DEBOUNCE_MS)) => { break; } }
+            cancel_tx.send_replace(latest.id);
+            let debounce = Duration::from_millis(crate::theme::settings().search_debounce_ms);
+            let mut last_received = Instant::now();
+            while !debounce_elapsed(last_received, Instant::now(), debounce) {
+                select! {
+                    Some(new_q) = query_rx.recv() => {
+                        latest = new_q;
+                        last_received = Instant::now();
+                        cancel_tx.send_replace(latest.id);
+                    }
+                    () = sleep(debounce.saturating_sub(last_received.elapsed())) => {}
+                }
             }
             if latest.text.trim().is_empty() {
                 let items = handle_empty_query(&index_path);
@@ -54,23 +62,19 @@ pub fn spawn_search_worker(
                 });
                 continue;
             }
-            let elapsed = last_sent.elapsed();
-            if elapsed < Duration::from_millis(MIN_INTERVAL_MS) {
-                sleep(
-                    Duration::from_millis(MIN_INTERVAL_MS)
-                        .checked_sub(elapsed)
-                        .expect("elapsed should be less than MIN_INTERVAL_MS"),
-                )
-                .await;
-            }
-            last_sent = Instant::now();
-
             let query = latest;
             let tx = search_result_tx.clone();
             let err_tx = net_err_tx_search.clone();
             let ipath = index_path.clone();
+            let cancel_rx = cancel_tx.subscribe();
             tokio::spawn(async move {
-                let (items, errors) = process_search_query(&query.text, query.fuzzy, &ipath).await;
+                let search = process_search_query(&query.text, query.fuzzy, &ipath);
+                let Some((items, errors)) =
+                    run_unless_superseded(query.id, cancel_rx, search).await
+                else {
+                    tracing::debug!(id = query.id, "search superseded by a newer query");
+                    return;
+                };
                 for e in errors {
                     let _ = err_tx.send(e);
                 }
@@ -83,6 +87,47 @@ pub fn spawn_search_worker(
     });
 }
 
+/// What: Decide whether the debounce window after the last query change has passed.
+///
+/// Inputs:
+/// - `last_received`: When the newest query arrived
+/// - `now`: Current instant
+/// - `debounce`: Configured quiet time (`search_debounce_ms`)
+///
+/// Output:
+/// - `true` once `debounce` has elapsed since `last_received`; always `true` for a zero interval.
+fn debounce_elapsed(last_received: Instant, now: Instant, debounce: Duration) -> bool {
+    now.saturating_duration_since(last_received) >= debounce
+}
+
+/// What: Drive a search future until it completes or a newer query supersedes it.
+///
+/// Inputs:
+/// - `id`: Id of the query being searched
+/// - `latest_rx`: Cancellation channel carrying the newest query id the worker has received
+/// - `work`: Search future for `id`
+///
+/// Output:
+/// - `Some(output)` when `work` finishes first; `None` once the channel holds another id.
+///
+/// Details:
+/// - Checks the channel before polling `work`, so a query superseded while waiting for its
+///   turn never starts network requests.
+/// - A closed channel never cancels; the search then runs to completion.
+/// - Dropping `work` abandons it; a blocking `curl` call already running finishes in the
+///   background and its output is discarded.
+async fn run_unless_superseded<F: std::future::Future>(
+    id: u64,
+    mut latest_rx: watch::Receiver<u64>,
+    work: F,
+) -> Option<F::Output> {
+    select! {
+        biased;
+        Ok(_) = latest_rx.wait_for(|latest| *latest != id) => None,
+        out = work => Some(out),
+    }
+}
+
 /// What: Handle empty query by returning all official packages.
 ///
 /// Inputs:
@@ -102,10 +147,32 @@ fn handle_empty_query(index_path: &Path) -> Vec<PackageItem> {
     items
 }
 
+/// What: Answer a single query the way the search worker does, without debouncing.
+///
+/// Inputs:
+/// - `query`: Query as produced by `send_query`
+/// - `index_path`: Path to official package index
+///
+/// Output:
+/// - Tuple of (sorted and deduplicated results, network errors)
+///
+/// Details:
+/// - Empty queries list all official packages, like the worker's empty-query branch.
+pub async fn run_query_once(
+    query: &QueryInput,
+    index_path: &Path,
+) -> (Vec<PackageItem>, Vec<String>) {
+    if query.text.trim().is_empty() {
+        (handle_empty_query(index_path), Vec::new())
+    } else {
+        process_search_query(&query.text, query.fuzzy, index_path).await
+    }
+}
+
 /// What: Process a search query and return sorted results.
 ///
 /// Inputs:
-/// - `query_text`: Search query text
+/// - `query_text`: Search query text, possibly with `-word` / `repo:name` operators
 /// - `fuzzy_mode`: Whether to use fuzzy matching
 /// - `index_path`: Path to official package index
 ///
@@ -114,9 +181,12 @@ fn handle_empty_query(index_path: &Path) -> Vec<PackageItem> {
 ///
 /// Details:
 /// - Ensures official index is loaded
+/// - Splits operators off with [`crate::logic::parse_query_operators`]; only the remaining terms
+///   are matched and ranked
 /// - Searches official packages
-/// - Fetches and filters AUR packages
-/// - Combines, scores, and sorts results
+/// - Fetches and filters AUR packages, unless a `repo:` restriction excludes the AUR or no
+///   terms remain (then all official packages are filtered instead)
+/// - Combines, scores, and sorts results, then drops those failing the operators
 /// - Deduplicates by repository/source and name so a binary repo row and an AUR row with the
 ///   same package name both remain visible
 async fn process_search_query(
@@ -127,16 +197,28 @@ async fn process_search_query(
     if crate::index::all_official().is_empty() {
         let _ = crate::index::all_official_or_fetch(index_path);
     }
-    let official_results = pkgindex::search_official(query_text, fuzzy_mode);
-    let (aur_items, errors) = sources::fetch_all_with_errors(query_text.to_string()).await;
+    let parsed = crate::logic::parse_query_operators(query_text);
+    if parsed.terms.is_empty() {
+        let mut items = handle_empty_query(index_path);
+        items.retain(|item| parsed.matches(item));
+        return (items, Vec::new());
+    }
+    let terms = parsed.terms.as_str();
+    let official_results = pkgindex::search_official(terms, fuzzy_mode);
+    let (aur_items, errors) = if parsed.allows_aur() {
+        sources::fetch_all_with_errors(terms.to_string()).await
+    } else {
+        (Vec::new(), Vec::new())
+    };
 
     let mut items_with_scores = official_results;
-    score_aur_items(&mut items_with_scores, aur_items, query_text, fuzzy_mode);
-    sort_scored_items(&mut items_with_scores, query_text, fuzzy_mode);
+    score_aur_items(&mut items_with_scores, aur_items, terms, fuzzy_mode);
+    sort_scored_items(&mut items_with_scores, terms, fuzzy_mode);
 
     let mut items: Vec<PackageItem> = items_with_scores
         .into_iter()
         .map(|(item, _)| item)
+        .filter(|item| parsed.matches(item))
         .collect();
     deduplicate_items(&mut items);
     (items, errors)
@@ -185,6 +267,8 @@ fn score_aur_items(
 /// - `fuzzy_mode`: Whether to use fuzzy matching
 ///
 /// Details:
+/// - Official packages built for another architecture than [`pkgindex::host_arch`] always sort
+///   after host-arch, `any`, and AUR packages
 /// - In fuzzy mode: sorts by fuzzy score (higher first), then repo order, then name
 /// - In normal mode: sorts by match rank, then repo order, then name
 fn sort_scored_items(
@@ -192,49 +276,100 @@ fn sort_scored_items(
     query_text: &str,
     fuzzy_mode: bool,
 ) {
+    let host = pkgindex::host_arch();
     if fuzzy_mode {
-        sort_items_fuzzy(items_with_scores);
+        sort_items_fuzzy(items_with_scores, &host);
     } else {
-        sort_items_normal(items_with_scores, query_text);
+        sort_items_normal(items_with_scores, query_text, &host);
     }
 }
 
-/// What: Sort items in fuzzy mode by score, repo order, and name.
+/// What: Rank a result by whether it installs on the host architecture.
+///
+/// Inputs:
+/// - `source`: Result source
+/// - `host`: Host architecture
+///
+/// Output:
+/// - `0` for AUR, host-arch, `any`, and not-yet-enriched official packages; `1` otherwise.
+fn arch_order(source: &Source, host: &str) -> u8 {
+    match source {
+        Source::Official { arch, .. } if !pkgindex::arch_matches(arch, host) => 1,
+        _ => 0,
+    }
+}
+
+/// What: Sort items in fuzzy mode by architecture, score, repo order, and name.
 ///
 /// Inputs:
 /// - `items_with_scores`: Mutable slice of (item, score) tuples
+/// - `host`: Host architecture
 ///
 /// Details:
+/// - Packages for other architectures come last (see [`arch_order`])
 /// - Higher fuzzy scores come first
 /// - Then sorted by repo order (official before AUR)
 /// - Finally sorted by name (case-insensitive)
-fn sort_items_fuzzy(items_with_scores: &mut [(PackageItem, Option<i64>)]) {
-    items_with_scores.sort_by(|a, b| match (a.1, b.1) {
+fn sort_items_fuzzy(items_with_scores: &mut [(PackageItem, Option<i64>)], host: &str) {
+    let priority = crate::theme::settings().repo_priority;
+    items_with_scores.sort_by(|a, b| {
+        arch_order(&a.0.source, host)
+            .cmp(&arch_order(&b.0.source, host))
+            .then_with(|| compare_fuzzy_scores(a, b, &priority))
+    });
+}
+
+/// What: Compare two scored items by fuzzy score, then repo order and name.
+///
+/// Inputs:
+/// - `a`/`b`: (item, score) tuples
+/// - `priority`: Configured `search_repo_priority` list
+///
+/// Output:
+/// - Ordering with higher scores first and unscored items last.
+fn compare_fuzzy_scores(
+    a: &(PackageItem, Option<i64>),
+    b: &(PackageItem, Option<i64>),
+    priority: &[String],
+) -> std::cmp::Ordering {
+    match (a.1, b.1) {
         (Some(sa), Some(sb)) => match sb.cmp(&sa) {
-            std::cmp::Ordering::Equal => compare_by_repo_and_name(&a.0, &b.0),
+            std::cmp::Ordering::Equal => compare_by_repo_and_name(&a.0, &b.0, priority),
             other => other,
         },
         (Some(_), None) => std::cmp::Ordering::Less,
         (None, Some(_)) => std::cmp::Ordering::Greater,
-        (None, None) => compare_by_repo_and_name(&a.0, &b.0),
-    });
+        (None, None) => compare_by_repo_and_name(&a.0, &b.0, priority),
+    }
 }
 
-/// What: Sort items in normal mode by match rank, repo order, and name.
+/// What: Sort items in normal mode by architecture, repo order, match rank, and name.
 ///
 /// Inputs:
 /// - `items_with_scores`: Mutable slice of (item, score) tuples
 /// - `query_text`: Search query text
+/// - `host`: Host architecture
 ///
 /// Details:
-/// - Lower match ranks come first (exact > prefix > substring > no match)
+/// - Packages for other architectures come last (see [`arch_order`])
 /// - Then sorted by repo order (official before AUR)
+/// - Lower match ranks come first (exact > prefix > word boundary > substring > no match)
 /// - Finally sorted by name (case-insensitive)
-fn sort_items_normal(items_with_scores: &mut [(PackageItem, Option<i64>)], query_text: &str) {
+fn sort_items_normal(
+    items_with_scores: &mut [(PackageItem, Option<i64>)],
+    query_text: &str,
+    host: &str,
+) {
     let query_lower = query_text.trim().to_lowercase();
+    let priority = crate::theme::settings().repo_priority;
     items_with_scores.sort_by(|a, b| {
-        let oa = repo_order(&a.0.source);
-        let ob = repo_order(&b.0.source);
+        let aa = arch_order(&a.0.source, host);
+        let ab = arch_order(&b.0.source, host);
+        if aa != ab {
+            return aa.cmp(&ab);
+        }
+        let oa = repo_order_with(&a.0.source, &priority);
+        let ob = repo_order_with(&b.0.source, &priority);
         if oa != ob {
             return oa.cmp(&ob);
         }
@@ -252,6 +387,7 @@ fn sort_items_normal(items_with_scores: &mut [(PackageItem, Option<i64>)], query
 /// Inputs:
 /// - `a`: First package item
 /// - `b`: Second package item
+/// - `priority`: Configured `search_repo_priority` list (empty for the default order)
 ///
 /// Output:
 /// - Ordering comparison result
@@ -259,9 +395,13 @@ fn sort_items_normal(items_with_scores: &mut [(PackageItem, Option<i64>)], query
 /// Details:
 /// - First compares by repo order (official before AUR)
 /// - Then compares by name (case-insensitive)
-fn compare_by_repo_and_name(a: &PackageItem, b: &PackageItem) -> std::cmp::Ordering {
-    let oa = repo_order(&a.source);
-    let ob = repo_order(&b.source);
+fn compare_by_repo_and_name(
+    a: &PackageItem,
+    b: &PackageItem,
+    priority: &[String],
+) -> std::cmp::Ordering {
+    let oa = repo_order_with(&a.source, priority);
+    let ob = repo_order_with(&b.source, priority);
     oa.cmp(&ob)
         .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
 }
@@ -272,10 +412,11 @@ fn compare_by_repo_and_name(a: &PackageItem, b: &PackageItem) -> std::cmp::Order
 /// - `items`: Mutable slice of package items
 ///
 /// Details:
-/// - Sorts by repo order (core > extra > others > AUR)
+/// - Sorts by repo order (core > extra > others > AUR, or `search_repo_priority`)
 /// - Then sorts by name (case-insensitive)
 fn sort_by_repo_and_name(items: &mut [PackageItem]) {
-    items.sort_by(compare_by_repo_and_name);
+    let priority = crate::theme::settings().repo_priority;
+    items.sort_by(|a, b| compare_by_repo_and_name(a, b, &priority));
 }
 
 /// What: Build a key so search rows dedupe per origin, not only per package name.
@@ -395,4 +536,131 @@ mod tests {
         deduplicate_items(&mut items);
         assert_eq!(items.len(), 1);
     }
+
+    #[test]
+    /// What: Ensure the debounce decision follows the configured interval.
+    ///
+    /// Inputs:
+    /// - A custom 600ms interval checked just before and at the deadline; a zero interval.
+    ///
+    /// Output:
+    /// - Not elapsed at 599ms, elapsed at 600ms and later; zero fires immediately.
+    fn debounce_elapsed_respects_custom_interval() {
+        let start = Instant::now();
+        let interval = Duration::from_millis(600);
+        assert!(!debounce_elapsed(start, start, interval));
+        assert!(!debounce_elapsed(
+            start,
+            start + Duration::from_millis(599),
+            interval
+        ));
+        assert!(debounce_elapsed(start, start + interval, interval));
+        assert!(debounce_elapsed(
+            start,
+            start + Duration::from_millis(900),
+            interval
+        ));
+        assert!(debounce_elapsed(start, start, Duration::ZERO));
+        assert!(!debounce_elapsed(
+            start,
+            start + Duration::from_millis(249),
+            Duration::from_millis(250)
+        ));
+    }
+
+    #[tokio::test]
+    /// What: Ensure a pending search is dropped once a newer query supersedes it.
+    ///
+    /// Inputs:
+    /// - Query 1 whose search never completes, then query 2 published on the channel with a
+    ///   search that is ready immediately.
+    ///
+    /// Output:
+    /// - Only query 2's results reach the result channel; query 1 resolves to `None`.
+    ///
+    /// Details:
+    /// - Mirrors the worker: each search task subscribes to the cancellation channel and only
+    ///   sends when it was not superseded. Ordering comes from the channel, not from timers.
+    async fn superseded_search_is_dropped_for_newer_query() {
+        fn spawn_search(
+            id: u64,
+            cancel_rx: watch::Receiver<u64>,
+            tx: mpsc::UnboundedSender<SearchResults>,
+            search: impl std::future::Future<Output = Vec<PackageItem>> + Send + 'static,
+        ) -> tokio::task::JoinHandle<bool> {
+            tokio::spawn(async move {
+                let outcome = run_unless_superseded(id, cancel_rx, search).await;
+                if let Some(items) = &outcome {
+                    let _ = tx.send(SearchResults {
+                        id,
+                        items: items.clone(),
+                    });
+                }
+                outcome.is_some()
+            })
+        }
+
+        let (cancel_tx, _) = watch::channel(1u64);
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel::<SearchResults>();
+        let slow = spawn_search(
+            1,
+            cancel_tx.subscribe(),
+            result_tx.clone(),
+            std::future::pending(),
+        );
+        tokio::task::yield_now().await;
+        cancel_tx.send_replace(2);
+        let fast = spawn_search(2, cancel_tx.subscribe(), result_tx.clone(), async {
+            Vec::new()
+        });
+
+        assert!(!slow.await.expect("first search task"));
+        assert!(fast.await.expect("second search task"));
+        drop(result_tx);
+        let mut delivered = Vec::new();
+        while let Some(results) = result_rx.recv().await {
+            delivered.push(results.id);
+        }
+        assert_eq!(delivered, vec![2]);
+    }
+
+    #[test]
+    /// What: Ensure foreign-architecture packages rank after host and `any` packages.
+    ///
+    /// Inputs:
+    /// - Query `foo` on an `aarch64` host; exact-match `foo` is `x86_64`, `foo-cli` is `aarch64`,
+    ///   and `foo-data` is `any`.
+    ///
+    /// Output:
+    /// - `foo-cli`, `foo-data`, then `foo` in both normal and fuzzy mode.
+    fn sort_prefers_host_arch_and_keeps_any() {
+        let official = |name: &str, arch: &str| {
+            (
+                PackageItem {
+                    source: Source::Official {
+                        repo: "extra".into(),
+                        arch: arch.into(),
+                    },
+                    ..PackageItem::test_item(name, "extra")
+                },
+                Some(0),
+            )
+        };
+        let mut items = vec![
+            official("foo", "x86_64"),
+            official("foo-cli", "aarch64"),
+            official("foo-data", "any"),
+        ];
+        let names = |items: &[(PackageItem, Option<i64>)]| {
+            items
+                .iter()
+                .map(|(p, _)| p.name.clone())
+                .collect::<Vec<_>>()
+        };
+        sort_items_normal(&mut items, "foo", "aarch64");
+        assert_eq!(names(&items), vec!["foo-cli", "foo-data", "foo"]);
+        items.reverse();
+        sort_items_fuzzy(&mut items, "aarch64");
+        assert_eq!(names(&items), vec!["foo-cli", "foo-data", "foo"]);
+    }
 }
@@ -13,7 +13,8 @@ use pacsea::theme;
 ///
 /// Details:
 /// - Removes all cache files including dependency, file, service, sandbox, details, PKGBUILD parse,
-///   news feed, news content, news seen updates/comments, and news/advisories article caches.
+///   news feed, news content, news seen updates/comments, and news/advisories article caches,
+///   plus the last-viewed PKGBUILD history.
 /// - Prints the number of cleared files to stdout.
 /// - Exits immediately after clearing (doesn't launch TUI).
 pub fn handle_clear_cache() -> ! {
@@ -52,6 +53,18 @@ pub fn handle_clear_cache() -> ! {
         }
     }
 
+    let history_dir = lists_dir.join(pacsea::logic::pkgbuild_diff::HISTORY_DIR);
+    match std::fs::remove_dir_all(&history_dir) {
+        Ok(()) => {
+            tracing::info!(path = %history_dir.display(), "cleared PKGBUILD history");
+            cleared_count += 1;
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => {
+            tracing::warn!(path = %history_dir.display(), error = %e, "failed to clear PKGBUILD history");
+        }
+    }
+
     if cleared_count > 0 {
         tracing::info!(cleared_count = cleared_count, "cleared cache files");
         println!("{}", i18n::t_fmt1("app.cli.cache.cleared", cleared_count));
@@ -0,0 +1,39 @@
+//! Command-line export of the effective configuration.
+
+use crate::args::{i18n, json};
+
+/// What: Print the effective settings, keymap, and locale, then exit.
+///
+/// Inputs:
+/// - `json_output`: When `true`, print a JSON envelope instead of TOML.
+///
+/// Output:
+/// - Exits 0 after printing to stdout, 1 when TOML serialization fails.
+///
+/// Details:
+/// - Uses the same merge as the TUI: defaults, `settings.conf`, `keybinds.conf`, normalization.
+/// - The TOML output is meant for bug reports; see `EffectiveConfig` for the layout.
+/// - Exits immediately after printing (doesn't launch TUI).
+pub fn handle_export_config(json_output: bool) -> ! {
+    tracing::info!("Effective config export requested from CLI");
+    let config = pacsea::theme::effective_config(&pacsea::theme::settings(), &i18n::cli_locale());
+
+    let printed = if json_output {
+        serde_json::to_value(&config)
+            .map(|data| json::print_envelope("export_config", &data))
+            .map_err(|e| e.to_string())
+    } else {
+        toml::to_string(&config)
+            .map(|text| print!("{text}"))
+            .map_err(|e| e.to_string())
+    };
+    if let Err(e) = printed {
+        eprintln!(
+            "{}",
+            i18n::t_fmt1("app.cli.export_config.serialize_failed", &e)
+        );
+        tracing::error!(error = %e, "Failed to serialize effective config");
+        std::process::exit(1);
+    }
+    std::process::exit(0);
+}
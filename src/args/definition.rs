@@ -25,7 +25,7 @@ pub struct Args {
     #[arg(long)]
     pub no_color: bool,
 
-    /// Output machine-readable JSON (`schema_version` envelope) on stdout; supported with --search, --list, and --news
+    /// Output machine-readable JSON (`schema_version` envelope) on stdout; supported with --search, --list, --news, and --export-config
     #[arg(long)]
     pub json: bool,
 
@@ -96,6 +96,10 @@ pub struct Args {
     /// List all installed packages (use with --list)
     #[arg(long)]
     pub all: bool,
+
+    /// Print the effective settings, keybinds, and locale as TOML (or JSON with --json) and exit
+    #[arg(long)]
+    pub export_config: bool,
 }
 
 /// What: Process all command-line arguments and handle early-exit flags.
@@ -109,16 +113,22 @@ pub struct Args {
 /// Details:
 /// - Handles search mode (exits immediately).
 /// - Handles clear cache flag (exits immediately).
+/// - Handles effective config export (exits immediately).
 /// - Logs warnings for unimplemented flags (install, remove, update, news).
 #[allow(unused_imports)]
 pub fn process_args(args: &Args) -> Option<bool> {
-    use crate::args::{cache, install, list, news, remove, search, update};
+    use crate::args::{cache, config, install, list, news, remove, search, update};
 
     // Handle command-line search mode
     if let Some(search_query) = &args.search {
         search::handle_search(search_query, args.json);
     }
 
+    // Handle effective config export
+    if args.export_config {
+        config::handle_export_config(args.json);
+    }
+
     // Handle clear cache flag
     if args.clear_cache {
         cache::handle_clear_cache();
@@ -4,6 +4,21 @@ use pacsea::i18n::translations::{TranslationMap, translate_with_fallback};
 use pacsea::i18n::{self, find_locales_dir, load_locale_file, resolve_locale};
 use std::collections::HashMap;
 
+/// What: Resolve the UI locale the same way the TUI does.
+///
+/// Inputs:
+/// - None (reads the `locale` setting and `i18n.yml`).
+///
+/// Output:
+/// - Resolved locale code, or `en-US` when `i18n.yml` cannot be found.
+pub fn cli_locale() -> String {
+    let Some(i18n_config_path) = i18n::find_config_file("i18n.yml") else {
+        tracing::debug!("i18n.yml not found, using default locale");
+        return "en-US".to_string();
+    };
+    resolve_locale(&pacsea::theme::settings().locale, &i18n_config_path)
+}
+
 /// What: Load translations for CLI usage.
 ///
 /// Inputs:
@@ -23,17 +38,7 @@ pub fn load_cli_translations() -> (TranslationMap, TranslationMap) {
         return (HashMap::new(), HashMap::new());
     };
 
-    // Resolve locale (try to read from settings, fallback to system/default)
-    let Some(i18n_config_path) = i18n::find_config_file("i18n.yml") else {
-        tracing::debug!("i18n.yml not found, using default locale");
-        let fallback = load_locale_file("en-US", &locales_dir).unwrap_or_default();
-        return (fallback.clone(), fallback);
-    };
-
-    // Try to read locale from settings
-    let settings_locale = &pacsea::theme::settings().locale;
-
-    let resolved_locale = resolve_locale(settings_locale, &i18n_config_path);
+    let resolved_locale = cli_locale();
 
     // Load primary locale
     let primary = load_locale_file(&resolved_locale, &locales_dir).unwrap_or_default();
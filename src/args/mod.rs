@@ -1,6 +1,7 @@
 //! Command-line argument parsing and handling.
 
 pub mod cache;
+pub mod config;
 pub mod definition;
 pub mod guardrails;
 pub mod i18n;
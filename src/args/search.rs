@@ -1,6 +1,7 @@
 //! Command-line search functionality.
 
 use crate::args::{i18n, json, utils};
+use pacsea::state::{PackageItem, Source};
 
 /// A single parsed entry from `-Ss` search output.
 struct SsEntry {
@@ -14,6 +15,35 @@ struct SsEntry {
     description: String,
 }
 
+impl SsEntry {
+    /// What: Convert a parsed `-Ss` entry into a result row.
+    ///
+    /// Output:
+    /// - `PackageItem` with `Source::Aur` for the `aur` repo, otherwise `Source::Official`.
+    ///
+    /// Details:
+    /// - `-Ss` output carries no architecture or popularity, so those stay empty.
+    fn into_item(self) -> PackageItem {
+        let source = if self.repo == "aur" {
+            Source::Aur
+        } else {
+            Source::Official {
+                repo: self.repo,
+                arch: String::new(),
+            }
+        };
+        PackageItem {
+            name: self.name,
+            version: self.version,
+            description: self.description,
+            source,
+            popularity: None,
+            out_of_date: None,
+            orphaned: false,
+        }
+    }
+}
+
 /// What: Parse `pacman`/`paru`/`yay` `-Ss` output into structured entries.
 ///
 /// Inputs:
@@ -59,64 +89,128 @@ fn parse_ss_output(output: &str) -> Vec<SsEntry> {
     entries
 }
 
-/// What: Run a captured `-Ss` search and print results as a JSON envelope.
+/// What: Build the JSON payload for headless search results.
+///
+/// Inputs:
+/// - `search_query`: The search pattern as given on the command line.
+/// - `tool`: Backend that produced the results (`"pacsea"` for the index pipeline, or the
+///   helper that ran `-Ss`).
+/// - `items`: Ranked results in display order.
+/// - `errors`: Network errors reported while searching (e.g. AUR unreachable).
+///
+/// Output:
+/// - `{"query", "tool", "results": [{name, version, repo, source, popularity, description}], "errors"}`.
+///
+/// Details:
+/// - AUR results report `repo` as `"aur"`; `popularity` is `null` when unknown.
+fn search_results_json(
+    search_query: &str,
+    tool: &str,
+    items: &[PackageItem],
+    errors: &[String],
+) -> serde_json::Value {
+    let results: Vec<serde_json::Value> = items
+        .iter()
+        .map(|item| {
+            let (repo, source) = match &item.source {
+                Source::Official { repo, .. } => (repo.as_str(), "official"),
+                Source::Aur => ("aur", "aur"),
+            };
+            serde_json::json!({
+                "name": item.name,
+                "version": item.version,
+                "repo": repo,
+                "source": source,
+                "popularity": item.popularity,
+                "description": item.description,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "query": search_query,
+        "tool": tool,
+        "results": results,
+        "errors": errors,
+    })
+}
+
+/// What: Capture a helper (or pacman) `-Ss` search as result rows.
 ///
 /// Inputs:
 /// - `search_query`: The search pattern.
-/// - `helper`: Resolved AUR helper, or `None` to fall back to `pacman -Ss`.
 ///
 /// Output:
-/// - Exits 0 with a JSON envelope on stdout (empty results on no match), 1 on execution failure.
+/// - `Ok((program, items))` with the program that ran, or the spawn error message.
 ///
 /// Details:
-/// - `-Ss` exits non-zero when nothing matches; that case emits an empty result list.
-/// - Diagnostics go to stderr so stdout stays parseable.
-fn handle_search_json(search_query: &str, helper: Option<&str>) -> ! {
+/// - `-Ss` exits non-zero when nothing matches; that case yields an empty list.
+fn helper_search(search_query: &str) -> Result<(&'static str, Vec<PackageItem>), String> {
     use std::process::{Command, Stdio};
 
-    let program = helper.unwrap_or("pacman");
+    let program = utils::get_aur_helper().unwrap_or("pacman");
     tracing::info!(program = %program, "Using {program} for JSON search");
 
-    let output = Command::new(program)
+    let out = Command::new(program)
         .args(["-Ss", search_query])
         .stdin(Stdio::null())
-        .output();
-
-    match output {
-        Ok(out) => {
-            let stdout = String::from_utf8_lossy(&out.stdout);
-            if !out.status.success() && !stdout.trim().is_empty() {
-                eprintln!("{}", String::from_utf8_lossy(&out.stderr));
-                tracing::error!(exit_code = out.status.code(), "Search command failed");
-                std::process::exit(out.status.code().unwrap_or(1));
+        .output()
+        .map_err(|e| e.to_string())?;
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    if !out.status.success() && !stdout.trim().is_empty() {
+        return Err(String::from_utf8_lossy(&out.stderr).trim().to_string());
+    }
+    let items = parse_ss_output(&stdout)
+        .into_iter()
+        .map(SsEntry::into_item)
+        .collect();
+    Ok((program, items))
+}
+
+/// What: Search the local index and the AUR and print results as a JSON envelope.
+///
+/// Inputs:
+/// - `search_query`: The search pattern.
+///
+/// Output:
+/// - Exits 0 with a JSON envelope on stdout (empty results on no match), 1 when the search
+///   cannot run.
+///
+/// Details:
+/// - Runs [`pacsea::app::search_once`] on `main`'s runtime via `block_in_place`.
+/// - When no official index has been fetched yet, skips the pipeline (no AUR request) and
+///   falls back to a captured helper (or pacman) `-Ss`, reporting that program as `tool`.
+/// - Network errors are reported in the payload and on stderr; stdout stays parseable.
+fn handle_search_json(search_query: &str) -> ! {
+    let outcome = tokio::task::block_in_place(|| {
+        tokio::runtime::Handle::current().block_on(pacsea::app::search_once(search_query))
+    });
+    let (tool, items, errors) = if let Some((items, errors)) = outcome {
+        ("pacsea", items, errors)
+    } else {
+        tracing::info!("Official index is empty; falling back to -Ss for JSON search");
+        match helper_search(search_query) {
+            Ok((program, items)) => (program, items, Vec::new()),
+            Err(e) => {
+                eprintln!("{}", i18n::t_fmt1("app.cli.search.exec_failed", &e));
+                tracing::error!(error = %e, "Failed to run JSON search");
+                std::process::exit(1);
             }
-            let results: Vec<serde_json::Value> = parse_ss_output(&stdout)
-                .iter()
-                .map(|e| {
-                    serde_json::json!({
-                        "repo": e.repo,
-                        "name": e.name,
-                        "version": e.version,
-                        "description": e.description,
-                    })
-                })
-                .collect();
-            json::print_envelope(
-                "search",
-                &serde_json::json!({
-                    "query": search_query,
-                    "tool": program,
-                    "results": results,
-                }),
-            );
-            std::process::exit(0);
-        }
-        Err(e) => {
-            eprintln!("{}", i18n::t_fmt1("app.cli.search.exec_failed", &e));
-            tracing::error!(error = %e, program = %program, "Failed to execute search");
-            std::process::exit(1);
         }
+    };
+    for err in &errors {
+        eprintln!("{err}");
     }
+    tracing::info!(
+        tool = %tool,
+        results = items.len(),
+        errors = errors.len(),
+        "JSON search finished"
+    );
+    json::print_envelope(
+        "search",
+        &search_results_json(search_query, tool, &items, &errors),
+    );
+    std::process::exit(0);
 }
 
 /// What: Handle command-line search mode by executing an AUR helper (or pacman) `-Ss`.
@@ -129,23 +223,21 @@ fn handle_search_json(search_query: &str, helper: Option<&str>) -> ! {
 /// - Exits the process with the command's exit code or 1 on error.
 ///
 /// Details:
-/// - Resolves the helper via [`utils::get_aur_helper`], honoring the `aur_helper`
-///   settings key (auto-detect prefers paru).
-/// - Without `--json`, the helper's output streams directly to the terminal.
-/// - With `--json`, falls back to `pacman -Ss` when no helper is available.
+/// - Without `--json`, resolves the helper via [`utils::get_aur_helper`], honoring the
+///   `aur_helper` settings key (auto-detect prefers paru), and streams its output directly.
+/// - With `--json`, searches the official index and the AUR through the TUI query pipeline
+///   (see [`handle_search_json`]); a helper is only used before the index exists.
 /// - Exits immediately after showing results (doesn't launch TUI).
 pub fn handle_search(search_query: &str, json_output: bool) -> ! {
     use std::process::Command;
 
     tracing::info!(query = %search_query, "Search mode requested from CLI");
 
-    let helper = utils::get_aur_helper();
-
     if json_output {
-        handle_search_json(search_query, helper);
+        handle_search_json(search_query);
     }
 
-    let Some(helper) = helper else {
+    let Some(helper) = utils::get_aur_helper() else {
         eprintln!("{}", i18n::t("app.cli.search.neither_helper_available"));
         tracing::error!("Neither paru nor yay is available for search");
         std::process::exit(1);
@@ -175,6 +267,61 @@ pub fn handle_search(search_query: &str, json_output: bool) -> ! {
 mod tests {
     use super::*;
 
+    #[test]
+    /// What: Verify headless search results serialize with source, repo, and popularity.
+    ///
+    /// Inputs:
+    /// - One official and one AUR result, plus a network error.
+    ///
+    /// Output:
+    /// - Results keep order; official rows carry their repo, AUR rows report `aur`, and
+    ///   unknown popularity is `null`.
+    ///
+    /// Details:
+    /// - Guards the integrator contract of `pacsea --search <term> --json`.
+    fn search_results_json_serializes_fields() {
+        let official = PackageItem {
+            name: "ripgrep".into(),
+            version: "14.1.0-1".into(),
+            description: "A fast search tool".into(),
+            source: Source::Official {
+                repo: "extra".into(),
+                arch: "x86_64".into(),
+            },
+            popularity: None,
+            out_of_date: None,
+            orphaned: false,
+        };
+        let aur = PackageItem {
+            name: "ripgrep-git".into(),
+            version: "14.1.0.r5-1".into(),
+            description: "Development version".into(),
+            source: Source::Aur,
+            popularity: Some(0.5),
+            ..official
+        };
+        let value = search_results_json(
+            "ripgrep",
+            "pacsea",
+            &[official, aur],
+            &["AUR timeout".into()],
+        );
+
+        assert_eq!(value["query"], "ripgrep");
+        assert_eq!(value["tool"], "pacsea");
+        let results = value["results"].as_array().expect("results array");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["name"], "ripgrep");
+        assert_eq!(results[0]["version"], "14.1.0-1");
+        assert_eq!(results[0]["repo"], "extra");
+        assert_eq!(results[0]["source"], "official");
+        assert!(results[0]["popularity"].is_null());
+        assert_eq!(results[1]["repo"], "aur");
+        assert_eq!(results[1]["source"], "aur");
+        assert_eq!(results[1]["popularity"], 0.5);
+        assert_eq!(value["errors"][0], "AUR timeout");
+    }
+
     #[test]
     /// What: Verify `-Ss` output parsing extracts repo, name, version, and description.
     ///
@@ -215,4 +362,29 @@ mod tests {
         assert!(parse_ss_output("no-slash-here 1.0\n    desc\n").is_empty());
         assert!(parse_ss_output("/missing-repo 1.0\n").is_empty());
     }
+
+    #[test]
+    /// What: Verify parsed `-Ss` entries map onto the JSON result sources.
+    ///
+    /// Inputs:
+    /// - One `extra` entry and one `aur` entry.
+    ///
+    /// Output:
+    /// - The `extra` row is official with its repo; the `aur` row is an AUR result.
+    ///
+    /// Details:
+    /// - Keeps the helper fallback payload in the same shape as the index pipeline.
+    fn ss_entry_into_item_maps_source() {
+        let items: Vec<PackageItem> =
+            parse_ss_output("extra/ripgrep 14.1.0-1\naur/ripgrep-git 1\n")
+                .into_iter()
+                .map(SsEntry::into_item)
+                .collect();
+        let value = search_results_json("ripgrep", "paru", &items, &[]);
+        assert_eq!(value["tool"], "paru");
+        assert_eq!(value["results"][0]["repo"], "extra");
+        assert_eq!(value["results"][0]["source"], "official");
+        assert_eq!(value["results"][1]["repo"], "aur");
+        assert_eq!(value["results"][1]["source"], "aur");
+    }
 }
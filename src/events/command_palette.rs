@@ -0,0 +1,293 @@
+//! Command palette: a fuzzy launcher for global actions.
+//!
+//! The palette owns the keyboard while open (its query accepts characters that are otherwise
+//! global chords), so `global::handle_global_key` routes keys here before any other binding.
+//! Selected actions run through the same handlers as their menus and shortcuts.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tokio::sync::mpsc;
+
+use crate::logic::command_palette::palette_matches;
+use crate::state::{AppState, Modal, PackageItem, QueryInput};
+
+use super::global;
+
+/// What: Open the command palette with an empty query.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `false` (never exits)
+///
+/// Details:
+/// - Closes open dropdown menus so the palette is the only active surface.
+pub(super) fn open_command_palette(app: &mut AppState) -> bool {
+    app.sort_menu_open = false;
+    app.options_menu_open = false;
+    app.panels_menu_open = false;
+    app.config_menu_open = false;
+    app.modal = Modal::CommandPalette {
+        query: String::new(),
+        selected: 0,
+    };
+    false
+}
+
+/// What: Handle a key while the command palette is open.
+///
+/// Inputs:
+/// - `ke`: Key event
+/// - `app`: Mutable application state (modal is `CommandPalette`)
+/// - `details_tx`/`pkgb_tx`/`comments_tx`/`query_tx`: Channels the dispatched actions may use
+///
+/// Output:
+/// - `false` (never exits; the exit chord is matched before the palette)
+///
+/// Details:
+/// - Esc closes; Up/Down move the selection; Enter runs the highlighted action.
+/// - Printable characters (without Ctrl/Alt) extend the query; Backspace shortens it. Editing
+///   resets the selection to the best match.
+pub(super) fn handle_command_palette_key(
+    ke: KeyEvent,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
+    comments_tx: &mpsc::UnboundedSender<String>,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
+) -> bool {
+    let Modal::CommandPalette {
+        mut query,
+        mut selected,
+    } = std::mem::take(&mut app.modal)
+    else {
+        return false;
+    };
+    let match_count = palette_matches(app, &query).len();
+    match ke.code {
+        KeyCode::Esc => return false,
+        KeyCode::Enter => {
+            if let Some(action) = palette_matches(app, &query).get(selected) {
+                run_palette_action(action.id, app, details_tx, pkgb_tx, comments_tx, query_tx);
+            }
+            return false;
+        }
+        KeyCode::Up => selected = selected.saturating_sub(1),
+        KeyCode::Down => selected = (selected + 1).min(match_count.saturating_sub(1)),
+        KeyCode::Backspace => {
+            query.pop();
+            selected = 0;
+        }
+        KeyCode::Char(ch)
+            if !ke
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            query.push(ch);
+            selected = 0;
+        }
+        _ => {}
+    }
+    app.modal = Modal::CommandPalette { query, selected };
+    false
+}
+
+/// What: Run a palette action by its stable id.
+///
+/// Inputs:
+/// - `id`: Action id from [`crate::logic::command_palette::PALETTE_ACTIONS`]
+/// - `app`: Mutable application state
+/// - `details_tx`/`pkgb_tx`/`comments_tx`/`query_tx`: Channels the handlers may use
+///
+/// Output:
+/// - `true` when the id is known and its action ran, `false` for unknown ids.
+///
+/// Details:
+/// - The palette is closed before dispatch so actions opening their own modal keep it.
+/// - Filter toggles re-apply filters and sort like their chip clicks.
+pub(super) fn run_palette_action(
+    id: &str,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
+    comments_tx: &mpsc::UnboundedSender<String>,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
+) -> bool {
+    if matches!(app.modal, Modal::CommandPalette { .. }) {
+        app.modal = Modal::None;
+    }
+    tracing::debug!(action = id, "[CommandPalette] Running action");
+    match id {
+        "help.open" => {
+            global::handle_help_overlay(app);
+        }
+        "options.system_update" => global::handle_options_system_update(app),
+        "mode.toggle_news" => super::mouse::menus::handle_mode_toggle(app, details_tx),
+        "sort.cycle" => {
+            global::handle_change_sort(app, details_tx);
+        }
+        "search.toggle_fuzzy" => {
+            app.fuzzy_search_enabled = !app.fuzzy_search_enabled;
+            crate::theme::save_fuzzy_search(app.fuzzy_search_enabled);
+            app.search_cache_query = None;
+            app.search_cache_results = None;
+            crate::logic::send_query(app, query_tx);
+        }
+        "filter.toggle_aur" => toggle_filter(app, |a| &mut a.results_filter_show_aur),
+        "filter.toggle_core" => toggle_filter(app, |a| &mut a.results_filter_show_core),
+        "filter.toggle_extra" => toggle_filter(app, |a| &mut a.results_filter_show_extra),
+        "filter.toggle_multilib" => toggle_filter(app, |a| &mut a.results_filter_show_multilib),
+        "options.installed_only" => global::handle_options_installed_only_toggle(app, details_tx),
+        "options.optional_deps" => global::handle_options_optional_deps(app),
+        "options.repositories" => global::handle_options_repositories(app),
+        "options.activity_log" => super::modals::open_activity_log_modal(app),
+        "panels.toggle_footer" => global::handle_panels_menu_selection(2, app),
+        "pkgbuild.toggle" => {
+            global::handle_toggle_pkgbuild(app, pkgb_tx);
+        }
+        "comments.toggle" => {
+            global::handle_toggle_comments(app, comments_tx);
+        }
+        "config.reload" => {
+            global::handle_reload_config(app, query_tx);
+        }
+        "fetch.retry_failed" => {
+            global::handle_retry_failed_fetches(app);
+        }
+        _ => {
+            tracing::warn!(action = id, "[CommandPalette] Unknown action id");
+            return false;
+        }
+    }
+    true
+}
+
+/// What: Flip one results filter and re-apply filtering.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `field`: Selects the filter flag to flip
+fn toggle_filter(app: &mut AppState, field: fn(&mut AppState) -> &mut bool) {
+    let flag = field(app);
+    *flag = !*flag;
+    crate::logic::apply_filters_and_sort_preserve_selection(app);
+    app.record_current_view();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Open channels for palette dispatch in tests.
+    fn channels() -> (
+        mpsc::UnboundedSender<PackageItem>,
+        mpsc::UnboundedSender<PackageItem>,
+        mpsc::UnboundedSender<String>,
+        mpsc::UnboundedSender<QueryInput>,
+    ) {
+        (
+            mpsc::unbounded_channel().0,
+            mpsc::unbounded_channel().0,
+            mpsc::unbounded_channel().0,
+            mpsc::unbounded_channel().0,
+        )
+    }
+
+    #[test]
+    /// What: Verify representative action ids resolve to their state mutations.
+    ///
+    /// Inputs:
+    /// - `filter.toggle_aur`, `help.open` (with the palette open), and an unknown id.
+    ///
+    /// Output:
+    /// - The AUR filter flips, Help replaces the palette, unknown ids report `false`; every
+    ///   registry id resolves.
+    fn palette_registry_resolves_ids_to_state_mutations() {
+        let (details_tx, pkgb_tx, comments_tx, query_tx) = channels();
+        let mut app = AppState::default();
+        let before = app.results_filter_show_aur;
+
+        assert!(run_palette_action(
+            "filter.toggle_aur",
+            &mut app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx
+        ));
+        assert_eq!(app.results_filter_show_aur, !before);
+
+        open_command_palette(&mut app);
+        assert!(run_palette_action(
+            "help.open",
+            &mut app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx
+        ));
+        assert!(matches!(app.modal, Modal::Help));
+
+        assert!(!run_palette_action(
+            "no.such.action",
+            &mut app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx
+        ));
+        for action in crate::logic::command_palette::PALETTE_ACTIONS {
+            assert!(
+                crate::logic::command_palette::find_action(action.id).is_some(),
+                "{} must be unique and resolvable",
+                action.id
+            );
+        }
+    }
+
+    #[test]
+    /// What: Verify typing filters the palette and Enter dispatches the best match.
+    ///
+    /// Inputs:
+    /// - Palette opened, query `filter_aur` typed, Enter pressed.
+    ///
+    /// Output:
+    /// - The AUR filter action ranks first, runs on Enter, and the palette closes.
+    fn palette_typing_and_enter_run_best_match() {
+        let (details_tx, pkgb_tx, comments_tx, query_tx) = channels();
+        let mut app = AppState::default();
+        let before = app.results_filter_show_aur;
+        open_command_palette(&mut app);
+
+        for ch in "filter_aur".chars() {
+            let key = KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE);
+            handle_command_palette_key(
+                key,
+                &mut app,
+                &details_tx,
+                &pkgb_tx,
+                &comments_tx,
+                &query_tx,
+            );
+        }
+        let Modal::CommandPalette { query, .. } = &app.modal else {
+            panic!("expected CommandPalette, got {:?}", app.modal);
+        };
+        assert_eq!(
+            palette_matches(&app, query).first().map(|a| a.id),
+            Some("filter.toggle_aur")
+        );
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        handle_command_palette_key(
+            enter,
+            &mut app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx,
+        );
+        assert_eq!(app.results_filter_show_aur, !before);
+        assert!(matches!(app.modal, Modal::None));
+    }
+}
@@ -7,7 +7,7 @@ use crate::app::{apply_settings_to_app_state, initialize_locale_system};
 use crate::events::mouse::menus::{handle_mode_toggle, handle_news_age_toggle};
 use crate::events::utils;
 use crate::state::{AppState, PackageItem, PkgbuildCheckRequest};
-use crate::theme::{reload_theme, settings};
+use crate::theme::{reload_theme_checked, settings};
 
 /// What: Close all open dropdown menus when ESC is pressed.
 ///
@@ -100,7 +100,7 @@ fn handle_config_editor_menu_toggle_shortcuts(ke: &KeyEvent, app: &mut AppState)
 /// Details:
 /// - Toggles between showing all packages and only explicitly installed packages.
 /// - When enabling, saves installed packages list to config directory.
-fn handle_options_installed_only_toggle(
+pub(super) fn handle_options_installed_only_toggle(
     app: &mut AppState,
     details_tx: &mpsc::UnboundedSender<PackageItem>,
 ) {
@@ -145,7 +145,10 @@ fn handle_options_installed_only_toggle(
         }
         app.all_results = items;
         app.installed_only_mode = true;
-        app.right_pane_focus = crate::state::RightPaneFocus::Remove;
+        utils::focus_installed_only_subpane(
+            app,
+            &crate::theme::settings().installed_only_default_focus,
+        );
         crate::logic::apply_filters_and_sort_preserve_selection(app);
         utils::refresh_selected_details(app, details_tx);
         let path = crate::theme::config_dir().join("installed_packages.txt");
@@ -163,7 +166,7 @@ fn handle_options_installed_only_toggle(
 ///
 /// Details:
 /// - Opens `SystemUpdate` modal with default settings.
-fn handle_options_system_update(app: &mut AppState) {
+pub(super) fn handle_options_system_update(app: &mut AppState) {
     let countries = vec![
         "Worldwide".to_string(),
         "Germany".to_string(),
@@ -205,7 +208,7 @@ fn handle_options_system_update(app: &mut AppState) {
 ///
 /// Details:
 /// - Builds optional dependencies rows and opens `OptionalDeps` modal.
-fn handle_options_optional_deps(app: &mut AppState) {
+pub(super) fn handle_options_optional_deps(app: &mut AppState) {
     let rows = crate::events::mouse::menu_options::build_optional_deps_rows(app);
     app.modal = crate::state::Modal::OptionalDeps {
         rows,
@@ -229,7 +232,7 @@ fn handle_options_optional_deps(app: &mut AppState) {
 /// Details:
 /// - Loads `repos.conf` from the resolved path and scans `/etc/pacman.conf` for section headers.
 /// - On non-Linux targets, shows the same unsupported-platform alert as the mouse menu path.
-fn handle_options_repositories(app: &mut AppState) {
+pub(super) fn handle_options_repositories(app: &mut AppState) {
     #[cfg(not(target_os = "linux"))]
     {
         app.modal = crate::state::Modal::Alert {
@@ -259,7 +262,7 @@ fn handle_options_repositories(app: &mut AppState) {
 ///
 /// Details:
 /// - Toggles visibility of recent pane, install pane, or keybinds footer.
-fn handle_panels_menu_selection(idx: usize, app: &mut AppState) {
+pub(super) fn handle_panels_menu_selection(idx: usize, app: &mut AppState) {
     let news_mode = matches!(app.app_mode, crate::state::types::AppMode::News);
     if news_mode {
         match idx {
@@ -356,22 +359,49 @@ fn matches_keybind(ke: &KeyEvent, chords: &[crate::theme::KeyChord]) -> bool {
     chords.iter().any(|c| (c.code, c.mods) == chord)
 }
 
-/// What: Handle escape key press - closes dropdown menus.
+/// What: Resolve an Escape key press against the active UI contexts in a fixed order.
 ///
 /// Inputs:
 /// - `app`: Mutable application state
 ///
 /// Output:
-/// - `Some(false)` if menus were closed, `None` otherwise
+/// - `Some(true)` when the confirmed quit-on-escape step requests exit
+/// - `Some(false)` when a context was closed or the quit prompt was shown
+/// - `None` when the key should continue to the modal/pane handlers
 ///
 /// Details:
-/// - Closes all open dropdown menus when ESC is pressed.
+/// - Precedence: topmost modal (left to the modal handlers) → pane find / result jump prompt →
+///   dropdown menus → non-Search pane (left to the pane handlers, which return focus to Search).
+/// - The final step only runs when `escape_quits_from_search` is enabled and Search normal mode
+///   has nothing else active: the first Escape shows a prompt, the next one exits.
 fn handle_escape(app: &mut AppState) -> Option<bool> {
+    if !matches!(app.modal, crate::state::Modal::None) {
+        return None;
+    }
+    if app.pane_find.take().is_some() {
+        return Some(false);
+    }
+    if app.result_jump_input.take().is_some() {
+        app.toast_message = None;
+        return Some(false);
+    }
     if close_all_dropdowns(app) {
-        Some(false)
-    } else {
-        None
+        return Some(false);
+    }
+    if !matches!(app.focus, crate::state::Focus::Search)
+        || matches!(app.app_mode, crate::state::types::AppMode::ConfigEditor)
+        || !app.search_normal_mode
+        || !settings().escape_quits_from_search
+    {
+        return None;
+    }
+    if std::mem::take(&mut app.escape_quit_armed) {
+        return Some(true);
     }
+    app.escape_quit_armed = true;
+    app.toast_message = Some(crate::i18n::t(app, "app.toasts.escape_quit_prompt"));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+    Some(false)
 }
 
 /// What: Handle help overlay keybind.
@@ -384,11 +414,52 @@ fn handle_escape(app: &mut AppState) -> Option<bool> {
 ///
 /// Details:
 /// - Opens the Help modal when the help overlay keybind is pressed.
-fn handle_help_overlay(app: &mut AppState) -> bool {
+pub(super) fn handle_help_overlay(app: &mut AppState) -> bool {
     app.modal = crate::state::Modal::Help;
     false
 }
 
+/// What: Handle the retry-failed-fetches keybind.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `false` (never exits)
+///
+/// Details:
+/// - Moves every category in `failed_fetches` to `pending_fetch_retries` for the event loop.
+/// - Resets the archlinux.org backoff when news or advisories are retried.
+/// - Shows a toast naming the retried categories, or that nothing has failed.
+pub(super) fn handle_retry_failed_fetches(app: &mut AppState) -> bool {
+    let failed = std::mem::take(&mut app.failed_fetches);
+    if failed.is_empty() {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.no_failed_fetches"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        return false;
+    }
+    if failed.iter().any(|c| c.uses_archlinux_backoff()) {
+        crate::sources::reset_archlinux_backoff();
+    }
+    let names = failed
+        .iter()
+        .map(|c| c.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    for category in failed {
+        if !app.pending_fetch_retries.contains(&category) {
+            app.pending_fetch_retries.push(category);
+        }
+    }
+    app.toast_message = Some(crate::i18n::t_fmt1(
+        app,
+        "app.toasts.retrying_failed_fetches",
+        names,
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+    false
+}
+
 /// What: Handle configuration reload keybind.
 ///
 /// Inputs:
@@ -404,7 +475,7 @@ fn handle_help_overlay(app: &mut AppState) -> bool {
 /// - Updates app state with new settings and reloads translations if locale changed.
 /// - If `installed_packages_mode` changed, refreshes the explicit cache in the background
 ///   and triggers a query refresh after the cache refresh completes (to avoid race conditions).
-fn handle_reload_config(
+pub(super) fn handle_reload_config(
     app: &mut AppState,
     query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>,
 ) -> bool {
@@ -414,8 +485,8 @@ fn handle_reload_config(
     let new_settings = settings();
     let old_locale = app.locale.clone();
 
-    // Reload theme (uses settings for use_terminal_theme)
-    if let Err(msg) = reload_theme() {
+    // Reload theme (uses settings for use_terminal_theme and theme_file)
+    if let Err(msg) = reload_theme_checked() {
         errors.push(format!("Theme reload failed: {msg}"));
     }
 
@@ -493,7 +564,7 @@ const fn handle_exit() -> bool {
 ///
 /// Details:
 /// - Toggles PKGBUILD viewer visibility and requests content if opening.
-fn handle_toggle_pkgbuild(
+pub(super) fn handle_toggle_pkgbuild(
     app: &mut AppState,
     pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
 ) -> bool {
@@ -512,6 +583,7 @@ fn handle_toggle_pkgbuild(
             let _ = pkgb_tx.send(item);
         }
     }
+    crate::logic::viewers::note_viewer_toggled(app);
     false
 }
 
@@ -585,7 +657,10 @@ fn handle_cycle_pkgbuild_sections(app: &mut AppState) -> bool {
 /// - Toggles comments viewer visibility
 /// - Clears comments when closing
 /// - Sends request via channel when opening (only if AUR package)
-fn handle_toggle_comments(app: &mut AppState, comments_tx: &mpsc::UnboundedSender<String>) -> bool {
+pub(super) fn handle_toggle_comments(
+    app: &mut AppState,
+    comments_tx: &mpsc::UnboundedSender<String>,
+) -> bool {
     // Only allow for AUR packages
     let is_aur = app
         .results
@@ -598,7 +673,9 @@ fn handle_toggle_comments(app: &mut AppState, comments_tx: &mpsc::UnboundedSende
 
     if app.comments_visible {
         app.comments_visible = false;
+        crate::logic::viewers::note_viewer_toggled(app);
         app.comments.clear();
+        app.comments_hidden.clear();
         app.comments_package_name = None;
         app.comments_fetched_at = None;
         app.comments_scroll = 0;
@@ -607,6 +684,7 @@ fn handle_toggle_comments(app: &mut AppState, comments_tx: &mpsc::UnboundedSende
         app.comments_error = None;
     } else {
         app.comments_visible = true;
+        crate::logic::viewers::note_viewer_toggled(app);
         app.comments_scroll = 0;
         app.comments_error = None;
         if let Some(item) = app.results.get(app.selected) {
@@ -622,6 +700,7 @@ fn handle_toggle_comments(app: &mut AppState, comments_tx: &mpsc::UnboundedSende
             }
             // Request new comments
             app.comments.clear();
+            app.comments_hidden.clear();
             app.comments_package_name = None;
             app.comments_fetched_at = None;
             app.comments_loading = true;
@@ -643,7 +722,10 @@ fn handle_toggle_comments(app: &mut AppState, comments_tx: &mpsc::UnboundedSende
 /// Details:
 /// - In News mode: cycles through news sort modes and refreshes news results.
 /// - In Package mode: cycles through package sort modes, persists preference, re-sorts results.
-fn handle_change_sort(app: &mut AppState, details_tx: &mpsc::UnboundedSender<PackageItem>) -> bool {
+pub(super) fn handle_change_sort(
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+) -> bool {
     if matches!(app.app_mode, crate::state::types::AppMode::News) {
         // News mode: cycle through news sort modes
         use crate::state::types::NewsSortMode;
@@ -665,11 +747,18 @@ fn handle_change_sort(app: &mut AppState, details_tx: &mpsc::UnboundedSender<Pac
             crate::state::SortMode::AurPopularityThenOfficial => {
                 crate::state::SortMode::BestMatches
             }
-            crate::state::SortMode::BestMatches => crate::state::SortMode::RepoThenName,
+            // Installed size is only offered in the installed-only view
+            crate::state::SortMode::BestMatches if app.installed_only_mode => {
+                crate::state::SortMode::InstalledSize
+            }
+            crate::state::SortMode::BestMatches | crate::state::SortMode::InstalledSize => {
+                crate::state::SortMode::RepoThenName
+            }
         };
         // Persist preference and apply immediately
         crate::theme::save_sort_mode(app.sort_mode);
         crate::logic::sort_results_preserve_selection(app);
+        app.record_current_view();
         // Jump selection to top and refresh details
         if app.results.is_empty() {
             app.list_state.select(None);
@@ -695,7 +784,7 @@ fn handle_change_sort(app: &mut AppState, details_tx: &mpsc::UnboundedSender<Pac
 /// - `Some(false)` if selection was handled, `None` otherwise
 ///
 /// Details:
-/// - Package mode display order: List installed (1), Update system (2), TUI Optional Deps (3), Repositories (4), News management (5)
+/// - Package mode display order: List installed (1), Update system (2), TUI Optional Deps (3), Repositories (4), News management (5), Activity log (6)
 /// - News mode display order: Update system (1), TUI Optional Deps (2), Repositories (3), Package mode (4)
 /// - Closes the options menu when a selection is handled.
 /// - Note: News age toggle (idx 5 in News mode) is not displayed in menu but handler remains for compatibility.
@@ -749,6 +838,10 @@ fn handle_options_menu_numeric(
             }
             3 => {
                 app.app_mode = crate::state::types::AppMode::Package;
+                utils::restore_mode_search_input(
+                    app,
+                    &crate::theme::settings().mode_switch_search_input,
+                );
                 if app.results.is_empty() {
                     app.list_state.select(None);
                 } else {
@@ -765,7 +858,8 @@ fn handle_options_menu_numeric(
             _ => false,
         }
     } else {
-        // Package mode display order: List installed (1), Update system (2), TUI Optional Deps (3), Repositories (4), News management (5)
+        // Package mode display order: List installed (1), Update system (2), TUI Optional Deps (3),
+        // Repositories (4), News management (5), Activity log (6)
         match idx {
             0 => {
                 handle_options_installed_only_toggle(app, details_tx);
@@ -787,6 +881,10 @@ fn handle_options_menu_numeric(
                 handle_mode_toggle(app, details_tx);
                 true
             }
+            5 => {
+                crate::events::modals::open_activity_log_modal(app);
+                true
+            }
             _ => false,
         }
     };
@@ -933,50 +1031,96 @@ fn handle_global_keybinds(
         return Some(handle_help_overlay(app));
     }
 
-    // Configuration reload (only if no modal is active - modals should handle their own keys)
+    // Remaining bindings act on the main view (only if no modal is active - modals should
+    // handle their own keys)
     if matches!(app.modal, crate::state::Modal::None)
         && !matches!(app.app_mode, crate::state::types::AppMode::ConfigEditor)
-        && matches_keybind(ke, &km.reload_config)
     {
-        return Some(handle_reload_config(app, query_tx));
+        return handle_main_view_keybinds(ke, app, details_tx, pkgb_tx, query_tx, pkgb_check_tx);
     }
 
-    // Exit (always works, even in modals)
-    if matches_keybind(ke, &km.exit) {
-        return Some(handle_exit());
-    }
+    None
+}
 
-    // PKGBUILD toggle (only if no modal is active - modals should handle their own keys)
-    if matches!(app.modal, crate::state::Modal::None)
-        && !matches!(app.app_mode, crate::state::types::AppMode::ConfigEditor)
-        && matches_keybind(ke, &km.show_pkgbuild)
-    {
-        return Some(handle_toggle_pkgbuild(app, pkgb_tx));
+/// What: Dispatch global keybinds that act on the main view.
+///
+/// Inputs:
+/// - `ke`: Key event from crossterm
+/// - `app`: Mutable application state
+/// - `details_tx`: Channel to request package details
+/// - `pkgb_tx`: Channel to request PKGBUILD content
+/// - `query_tx`: Channel to send search queries
+/// - `pkgb_check_tx`: Channel to request PKGBUILD checks
+///
+/// Output:
+/// - `Some(false)` if handled, `None` if not matched
+///
+/// Details:
+/// - Only called when no modal is open and the config editor is not active.
+/// - Covers config reload, command palette, package copy, fetch retry, PKGBUILD, and sort.
+fn handle_main_view_keybinds(
+    ke: &KeyEvent,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
+    query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>,
+    pkgb_check_tx: &mpsc::UnboundedSender<PkgbuildCheckRequest>,
+) -> Option<bool> {
+    let km = &app.keymap;
+    if matches_keybind(ke, &km.reload_config) {
+        return Some(handle_reload_config(app, query_tx));
     }
-    if matches!(app.modal, crate::state::Modal::None)
-        && !matches!(app.app_mode, crate::state::types::AppMode::ConfigEditor)
-        && matches_keybind(ke, &km.run_pkgbuild_checks)
-    {
-        return Some(handle_run_pkgbuild_checks(app, pkgb_check_tx));
+    if matches_keybind(ke, &km.command_palette) {
+        return Some(super::command_palette::open_command_palette(app));
     }
-    if matches!(app.modal, crate::state::Modal::None)
-        && !matches!(app.app_mode, crate::state::types::AppMode::ConfigEditor)
-        && matches_keybind(ke, &km.cycle_pkgbuild_sections)
-    {
-        return Some(handle_cycle_pkgbuild_sections(app));
+    if matches_keybind(ke, &km.copy_package_name) {
+        crate::logic::package_copy::copy_focused_package(app, false);
+        return Some(false);
     }
-
-    // Sort change (only if no modal is active - modals should handle their own keys)
-    if matches!(app.modal, crate::state::Modal::None)
-        && !matches!(app.app_mode, crate::state::types::AppMode::ConfigEditor)
-        && matches_keybind(ke, &km.change_sort)
-    {
+    if matches_keybind(ke, &km.copy_package_details) {
+        crate::logic::package_copy::copy_focused_package(app, true);
+        return Some(false);
+    }
+    if matches_keybind(ke, &km.retry_failed_fetches) {
+        return Some(handle_retry_failed_fetches(app));
+    }
+    if let Some(handled) = handle_pkgbuild_keybinds(ke, app, pkgb_tx, pkgb_check_tx) {
+        return Some(handled);
+    }
+    if matches_keybind(ke, &app.keymap.change_sort) {
         return Some(handle_change_sort(app, details_tx));
     }
-
     None
 }
 
+/// What: Dispatch the PKGBUILD pane keybinds (toggle, checks, section cycling).
+///
+/// Inputs:
+/// - `ke`: Key event from crossterm
+/// - `app`: Mutable application state
+/// - `pkgb_tx`: Channel to request PKGBUILD content
+/// - `pkgb_check_tx`: Channel to request PKGBUILD checks
+///
+/// Output:
+/// - `Some(false)` if handled, `None` if not matched
+fn handle_pkgbuild_keybinds(
+    ke: &KeyEvent,
+    app: &mut AppState,
+    pkgb_tx: &mpsc::UnboundedSender<PackageItem>,
+    pkgb_check_tx: &mpsc::UnboundedSender<PkgbuildCheckRequest>,
+) -> Option<bool> {
+    let km = &app.keymap;
+    if matches_keybind(ke, &km.show_pkgbuild) {
+        Some(handle_toggle_pkgbuild(app, pkgb_tx))
+    } else if matches_keybind(ke, &km.run_pkgbuild_checks) {
+        Some(handle_run_pkgbuild_checks(app, pkgb_check_tx))
+    } else if matches_keybind(ke, &km.cycle_pkgbuild_sections) {
+        Some(handle_cycle_pkgbuild_sections(app))
+    } else {
+        None
+    }
+}
+
 /// What: Handle config menu numeric selection.
 ///
 /// Inputs:
@@ -1048,8 +1192,9 @@ fn handle_config_menu_selection(
 /// - `None` when the key was not handled by global shortcuts
 ///
 /// Details:
-/// - Gives precedence to closing dropdown menus on `Esc` before other bindings.
-/// - Routes configured global chords (help overlay, theme reload, exit, PKGBUILD toggle, comments toggle, sort cycle).
+/// - Routes every key except the exit chord to the command palette while it is open.
+/// - Resolves `Esc` through the escape hierarchy (see `handle_escape`) before other bindings.
+/// - Routes configured global chords (help overlay, theme reload, command palette, fetch retry, exit, PKGBUILD toggle, comments toggle, sort cycle).
 /// - When sort mode changes it persists the preference, re-sorts results, and refreshes details.
 /// - Supports menu number shortcuts (1-9) for Options/Panels/Config dropdowns while they are open.
 pub(super) fn handle_global_key(
@@ -1061,11 +1206,27 @@ pub(super) fn handle_global_key(
     query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>,
     pkgb_check_tx: &mpsc::UnboundedSender<PkgbuildCheckRequest>,
 ) -> Option<bool> {
-    // First: handle ESC to close dropdown menus
-    if ke.code == KeyCode::Esc
-        && let Some(result) = handle_escape(app)
+    // The command palette owns the keyboard while open (except the exit chord)
+    if matches!(app.modal, crate::state::Modal::CommandPalette { .. })
+        && !matches_keybind(&ke, &app.keymap.exit)
     {
-        return Some(result);
+        return Some(super::command_palette::handle_command_palette_key(
+            ke,
+            app,
+            details_tx,
+            pkgb_tx,
+            comments_tx,
+            query_tx,
+        ));
+    }
+
+    // First: resolve ESC against modal, find, dropdown, pane and quit contexts
+    if ke.code == KeyCode::Esc {
+        if let Some(result) = handle_escape(app) {
+            return Some(result);
+        }
+    } else {
+        app.escape_quit_armed = false;
     }
 
     // Keep config-editor mode aligned with package mode for Shift+menu shortcuts.
@@ -1390,4 +1551,218 @@ mod tests {
         assert_eq!(handled, Some(false));
         assert!(matches!(app.app_mode, crate::state::types::AppMode::News));
     }
+
+    /// What: Send a single `Esc` through the global handler.
+    fn press_escape(app: &mut AppState) -> Option<bool> {
+        let (details_tx, _details_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let (comments_tx, _comments_rx) = mpsc::unbounded_channel::<String>();
+        let (query_tx, _query_rx) = mpsc::unbounded_channel::<crate::state::QueryInput>();
+        let (pkgb_check_tx, _pkgb_check_rx) =
+            mpsc::unbounded_channel::<crate::state::PkgbuildCheckRequest>();
+        handle_global_key(
+            KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+            app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx,
+            &pkgb_check_tx,
+        )
+    }
+
+    /// What: Run `f` with a temporary `HOME` whose settings set `escape_quits_from_search`.
+    fn with_escape_quit_setting(enabled: bool, f: impl FnOnce()) {
+        let _theme_guard = crate::theme::test_mutex()
+            .lock()
+            .expect("theme test mutex poisoned");
+        let orig_home = std::env::var_os("HOME");
+        let base = std::env::temp_dir().join(format!(
+            "pacsea_test_escape_quit_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("System time is before UNIX epoch")
+                .as_nanos()
+        ));
+        let cfg = base.join(".config").join("pacsea");
+        std::fs::create_dir_all(&cfg).expect("create test config dir");
+        unsafe {
+            std::env::set_var("HOME", base.display().to_string());
+        }
+        std::fs::write(
+            cfg.join("settings.conf"),
+            format!("escape_quits_from_search = {enabled}\n"),
+        )
+        .expect("write settings");
+
+        f();
+
+        unsafe {
+            if let Some(v) = orig_home {
+                std::env::set_var("HOME", v);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    /// What: Verify `Esc` resolves modal, find, and dropdown contexts one at a time in order.
+    ///
+    /// Inputs:
+    /// - App with the Help modal open, an active pane find, and the Options dropdown open.
+    ///
+    /// Output:
+    /// - First `Esc` is left to the modal handler, the next clears find, the last closes the dropdown.
+    ///
+    /// Details:
+    /// - Each step must leave the lower-precedence contexts untouched.
+    fn global_escape_resolves_contexts_in_order() {
+        let mut app = new_app();
+        app.modal = crate::state::Modal::Help;
+        app.pane_find = Some("rip".to_string());
+        app.options_menu_open = true;
+
+        assert_eq!(press_escape(&mut app), None);
+        assert!(app.pane_find.is_some());
+        assert!(app.options_menu_open);
+
+        app.modal = crate::state::Modal::None;
+        assert_eq!(press_escape(&mut app), Some(false));
+        assert!(app.pane_find.is_none());
+        assert!(app.options_menu_open);
+
+        assert_eq!(press_escape(&mut app), Some(false));
+        assert!(!app.options_menu_open);
+    }
+
+    #[test]
+    /// What: Verify the quit-on-escape step only fires when enabled and nothing else is active.
+    ///
+    /// Inputs:
+    /// - Search normal mode with `escape_quits_from_search` disabled, then enabled.
+    ///
+    /// Output:
+    /// - Disabled: `Esc` falls through (`None`). Enabled: a dropdown is closed first, then the
+    ///   first bare `Esc` prompts and the second requests exit.
+    ///
+    /// Details:
+    /// - A non-Escape key in between disarms the prompt.
+    fn global_escape_quit_only_when_enabled_and_idle() {
+        with_escape_quit_setting(false, || {
+            let mut app = new_app();
+            app.search_normal_mode = true;
+            assert_eq!(press_escape(&mut app), None);
+            assert!(!app.escape_quit_armed);
+        });
+
+        with_escape_quit_setting(true, || {
+            let mut app = new_app();
+            app.search_normal_mode = true;
+            app.sort_menu_open = true;
+
+            assert_eq!(press_escape(&mut app), Some(false));
+            assert!(!app.sort_menu_open);
+            assert!(!app.escape_quit_armed);
+
+            assert_eq!(press_escape(&mut app), Some(false));
+            assert!(app.escape_quit_armed);
+
+            let (details_tx, _details_rx) = mpsc::unbounded_channel::<PackageItem>();
+            let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel::<PackageItem>();
+            let (comments_tx, _comments_rx) = mpsc::unbounded_channel::<String>();
+            let (query_tx, _query_rx) = mpsc::unbounded_channel::<crate::state::QueryInput>();
+            let (pkgb_check_tx, _pkgb_check_rx) =
+                mpsc::unbounded_channel::<crate::state::PkgbuildCheckRequest>();
+            let _ = handle_global_key(
+                KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()),
+                &mut app,
+                &details_tx,
+                &pkgb_tx,
+                &comments_tx,
+                &query_tx,
+                &pkgb_check_tx,
+            );
+            assert!(!app.escape_quit_armed);
+
+            assert_eq!(press_escape(&mut app), Some(false));
+            assert_eq!(press_escape(&mut app), Some(true));
+        });
+    }
+
+    #[test]
+    /// What: Verify the retry action re-schedules only the failed fetch categories.
+    ///
+    /// Inputs:
+    /// - App with a recorded `Status` failure, then with no failures.
+    ///
+    /// Output:
+    /// - `Status` moves to `pending_fetch_retries`; with nothing failed nothing is queued.
+    ///
+    /// Details:
+    /// - Triggered through the default `Alt+R` chord.
+    fn global_retry_failed_fetches_reschedules_failed_category() {
+        use crate::state::FetchCategory;
+        let mut app = new_app();
+        app.failed_fetches = vec![FetchCategory::Status];
+
+        let (details_tx, _details_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel::<PackageItem>();
+        let (comments_tx, _comments_rx) = mpsc::unbounded_channel::<String>();
+        let (query_tx, _query_rx) = mpsc::unbounded_channel::<crate::state::QueryInput>();
+        let (pkgb_check_tx, _pkgb_check_rx) =
+            mpsc::unbounded_channel::<crate::state::PkgbuildCheckRequest>();
+        let retry_key = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT);
+
+        let exit = handle_global_key(
+            retry_key,
+            &mut app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx,
+            &pkgb_check_tx,
+        );
+        assert_eq!(exit, Some(false));
+        assert_eq!(app.pending_fetch_retries, vec![FetchCategory::Status]);
+        assert!(app.failed_fetches.is_empty());
+
+        app.pending_fetch_retries.clear();
+        let _ = handle_global_key(
+            retry_key,
+            &mut app,
+            &details_tx,
+            &pkgb_tx,
+            &comments_tx,
+            &query_tx,
+            &pkgb_check_tx,
+        );
+        assert!(app.pending_fetch_retries.is_empty());
+        assert!(app.toast_message.is_some());
+    }
+
+    #[test]
+    /// What: Verify retrying a failed news fetch resets the archlinux.org backoff.
+    ///
+    /// Inputs:
+    /// - Raised archlinux.org backoff and a recorded `News` failure.
+    ///
+    /// Output:
+    /// - Backoff returns to its base delay and `News` is queued for retry.
+    fn retry_failed_news_fetch_resets_archlinux_backoff() {
+        use crate::state::FetchCategory;
+        crate::sources::reset_archlinux_backoff();
+        let base = crate::sources::archlinux_backoff_ms();
+        crate::sources::increase_archlinux_backoff(None);
+        assert!(crate::sources::archlinux_backoff_ms() > base);
+
+        let mut app = new_app();
+        app.failed_fetches = vec![FetchCategory::News];
+        handle_retry_failed_fetches(&mut app);
+
+        assert_eq!(crate::sources::archlinux_backoff_ms(), base);
+        assert_eq!(app.pending_fetch_retries, vec![FetchCategory::News]);
+    }
 }
@@ -0,0 +1,275 @@
+//! Just-in-time AUR helper check before an install that includes AUR targets.
+
+use crate::state::modal::PreflightHeaderChips;
+use crate::state::{AppState, PackageItem, SecureString, Source};
+
+/// What: Probe whether an AUR helper (`paru` or `yay`) can be spawned right now.
+///
+/// Inputs: None
+///
+/// Output:
+/// - `true` when `paru` or `yay` responds to `--version`.
+///
+/// Details:
+/// - Checked at install time (not cached) so a helper removed after startup is noticed.
+#[must_use]
+pub fn aur_helper_available() -> bool {
+    crate::util::command::binary_available("paru") || crate::util::command::binary_available("yay")
+}
+
+/// What: Open [`crate::state::Modal::AurHelperMissing`] when AUR targets cannot be installed.
+///
+/// Inputs:
+/// - `app`: UI state (may set modal).
+/// - `items`: Packages about to be installed.
+/// - `header_chips`: Preflight metrics carried into a pacman-only install.
+/// - `password`: Password already collected for the install, if any.
+/// - `helper_available`: Lazy helper probe (only called when AUR targets are present).
+///
+/// Output:
+/// - `true` when the guidance modal was shown and the caller must stop the install flow.
+///
+/// Details:
+/// - Skipped in dry-run mode (nothing is spawned) and when `aur_helper_install_check` is off.
+#[must_use]
+pub fn try_open_aur_helper_missing_modal(
+    app: &mut AppState,
+    items: &[PackageItem],
+    header_chips: &PreflightHeaderChips,
+    password: Option<&SecureString>,
+    helper_available: impl FnOnce() -> bool,
+) -> bool {
+    if app.dry_run || !crate::theme::settings().aur_helper_install_check {
+        return false;
+    }
+    if !items.iter().any(|p| matches!(p.source, Source::Aur)) || helper_available() {
+        return false;
+    }
+    tracing::warn!("[Install] No AUR helper (paru/yay) found; showing guidance modal");
+    app.modal = crate::state::Modal::AurHelperMissing {
+        items: items.to_vec(),
+        header_chips: header_chips.clone(),
+        password: password.cloned(),
+    };
+    true
+}
+
+/// What: Advance the install-flow AUR helper selector.
+///
+/// Inputs:
+/// - `app`: UI state holding `install_aur_helper`.
+///
+/// Output:
+/// - The new choice (`None` means "follow settings").
+///
+/// Details:
+/// - Cycles settings default -> `paru` -> `yay` -> settings default.
+pub fn cycle_install_aur_helper(app: &mut AppState) -> Option<&'static str> {
+    let next = match app.install_aur_helper.as_deref() {
+        None => Some("paru"),
+        Some("paru") => Some("yay"),
+        Some(_) => None,
+    };
+    app.install_aur_helper = next.map(str::to_string);
+    next
+}
+
+/// What: Refuse an install whose explicitly selected AUR helper is not installed.
+///
+/// Inputs:
+/// - `app`: UI state (toast fields).
+/// - `items`: Packages about to be installed.
+/// - `helper`: Explicit helper from [`crate::install::selected_aur_helper`], if any.
+/// - `helper_available`: Probe for the named helper (only called when it matters).
+///
+/// Output:
+/// - `true` when an error toast was shown and the caller must stop the install flow.
+///
+/// Details:
+/// - An explicit choice never falls back to the other helper; the user picks again instead.
+/// - Skipped in dry-run mode (nothing is spawned) and for official-only sets.
+#[must_use]
+pub fn try_reject_unavailable_aur_helper(
+    app: &mut AppState,
+    items: &[PackageItem],
+    helper: Option<&str>,
+    helper_available: impl FnOnce(&str) -> bool,
+) -> bool {
+    let Some(helper) = helper else {
+        return false;
+    };
+    if app.dry_run
+        || !items.iter().any(|p| matches!(p.source, Source::Aur))
+        || helper_available(helper)
+    {
+        return false;
+    }
+    tracing::warn!(helper, "[Install] Selected AUR helper is not available");
+    app.toast_message = Some(crate::i18n::t_fmt1(
+        app,
+        "app.toasts.aur_helper_unavailable",
+        helper,
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
+    true
+}
+
+/// What: Split out the official-repository targets of an install set.
+///
+/// Inputs:
+/// - `items`: Mixed install set.
+///
+/// Output:
+/// - Only packages whose source is an official repository, in original order.
+#[must_use]
+pub fn official_only_items(items: &[PackageItem]) -> Vec<PackageItem> {
+    items
+        .iter()
+        .filter(|p| matches!(p.source, Source::Official { .. }))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify a missing helper opens the guidance modal for AUR installs.
+    ///
+    /// Inputs:
+    /// - Mixed install set (one official, one AUR) with the helper probe returning `false`.
+    ///
+    /// Output:
+    /// - Returns `true` and sets `Modal::AurHelperMissing` carrying the full install set.
+    ///
+    /// Details:
+    /// - Uses a stub probe so the test does not depend on paru/yay being installed.
+    fn missing_helper_opens_guidance_modal_for_aur_install() {
+        let mut app = AppState::default();
+        let items = vec![
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("yay-bin", "aur"),
+        ];
+
+        let opened = try_open_aur_helper_missing_modal(
+            &mut app,
+            &items,
+            &PreflightHeaderChips::default(),
+            None,
+            || false,
+        );
+
+        assert!(opened);
+        match &app.modal {
+            crate::state::Modal::AurHelperMissing { items, .. } => assert_eq!(items.len(), 2),
+            other => panic!("expected AurHelperMissing, got {other:?}"),
+        }
+    }
+
+    #[test]
+    /// What: Verify official-only installs and available helpers proceed without the modal.
+    ///
+    /// Inputs:
+    /// - Official-only set with the helper absent; AUR set with the helper present.
+    ///
+    /// Output:
+    /// - Both return `false` and leave `app.modal` unset; the probe is never called for
+    ///   official-only sets.
+    ///
+    /// Details:
+    /// - Official-only sets go straight to pacman, so the helper is irrelevant.
+    fn official_only_or_present_helper_skips_modal() {
+        let mut app = AppState::default();
+        let official = vec![PackageItem::test_item("ripgrep", "extra")];
+        let opened = try_open_aur_helper_missing_modal(
+            &mut app,
+            &official,
+            &PreflightHeaderChips::default(),
+            None,
+            || panic!("probe must not run for official-only sets"),
+        );
+        assert!(!opened);
+        assert!(matches!(app.modal, crate::state::Modal::None));
+
+        let aur = vec![PackageItem::test_item("yay-bin", "aur")];
+        let opened = try_open_aur_helper_missing_modal(
+            &mut app,
+            &aur,
+            &PreflightHeaderChips::default(),
+            None,
+            || true,
+        );
+        assert!(!opened);
+        assert!(matches!(app.modal, crate::state::Modal::None));
+        assert_eq!(
+            official_only_items(&[
+                PackageItem::test_item("a", "aur"),
+                PackageItem::test_item("b", "extra")
+            ])[0]
+                .name,
+            "b"
+        );
+    }
+
+    #[test]
+    /// What: Verify the install-flow helper selector cycles back to the settings default.
+    ///
+    /// Inputs:
+    /// - Three selector presses on a fresh state.
+    ///
+    /// Output:
+    /// - The selector walks `paru` -> `yay` -> settings default (`None`).
+    fn install_helper_selector_cycles_back_to_settings() {
+        let mut app = AppState::default();
+        assert_eq!(cycle_install_aur_helper(&mut app), Some("paru"));
+        assert_eq!(cycle_install_aur_helper(&mut app), Some("yay"));
+        assert_eq!(app.install_aur_helper.as_deref(), Some("yay"));
+        assert_eq!(cycle_install_aur_helper(&mut app), None);
+        assert!(app.install_aur_helper.is_none());
+    }
+
+    #[test]
+    /// What: Verify an unavailable selected helper stops the install with an error toast.
+    ///
+    /// Inputs:
+    /// - AUR install set with `yay` selected and a probe reporting it missing.
+    ///
+    /// Output:
+    /// - Returns `true` with the unavailable-helper toast; the probe is asked about `yay` only.
+    ///
+    /// Details:
+    /// - Guards against silently running `paru` instead of the chosen helper.
+    fn unavailable_selected_helper_shows_error_toast() {
+        let mut app = AppState::default();
+        let items = vec![PackageItem::test_item("yay-bin", "aur")];
+
+        let rejected = try_reject_unavailable_aur_helper(&mut app, &items, Some("yay"), |helper| {
+            assert_eq!(helper, "yay");
+            false
+        });
+
+        assert!(rejected);
+        let toast = app.toast_message.as_deref().expect("error toast");
+        assert!(
+            toast.contains("aur_helper_unavailable"),
+            "unexpected toast: {toast}"
+        );
+        assert!(matches!(app.modal, crate::state::Modal::None));
+
+        let mut app = AppState::default();
+        assert!(!try_reject_unavailable_aur_helper(
+            &mut app,
+            &items,
+            None,
+            |_| panic!("probe must not run without an explicit helper"),
+        ));
+        assert!(!try_reject_unavailable_aur_helper(
+            &mut app,
+            &items,
+            Some("paru"),
+            |_| true,
+        ));
+        assert!(app.toast_message.is_none());
+    }
+}
@@ -11,6 +11,8 @@ use super::utils::{
 
 /// AUR vs official duplicate-results warning before privileged install.
 mod aur_dup_warn;
+/// Just-in-time AUR helper availability check before spawning AUR installs.
+mod aur_helper_check;
 /// Preflight modal opening functions for install operations.
 mod preflight;
 
@@ -18,6 +20,10 @@ mod preflight;
 mod tests;
 
 pub use aur_dup_warn::try_open_warn_aur_repo_duplicate_modal;
+pub use aur_helper_check::{
+    aur_helper_available, cycle_install_aur_helper, official_only_items,
+    try_open_aur_helper_missing_modal, try_reject_unavailable_aur_helper,
+};
 
 pub use preflight::{
     open_preflight_downgrade_modal, open_preflight_install_modal, open_preflight_remove_modal,
@@ -193,13 +199,15 @@ fn load_news_bookmark(app: &mut AppState) {
 
 /// What: Handle key events inside the news bookmarks pane.
 fn handle_news_bookmarks_key(ke: KeyEvent, app: &mut AppState) -> bool {
+    let km = &app.keymap;
+    let down =
+        matches!(ke.code, KeyCode::Char('j') | KeyCode::Down) || matches_any(&ke, &km.pane_down);
+    let up = matches!(ke.code, KeyCode::Char('k') | KeyCode::Up) || matches_any(&ke, &km.pane_up);
+    let left = matches!(ke.code, KeyCode::Esc | KeyCode::Left) || matches_any(&ke, &km.pane_left);
+    let right = ke.code == KeyCode::Right || matches_any(&ke, &km.pane_right);
     match ke.code {
-        KeyCode::Char('j') | KeyCode::Down => {
-            move_news_bookmark_selection(app, true);
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            move_news_bookmark_selection(app, false);
-        }
+        _ if down => move_news_bookmark_selection(app, true),
+        _ if up => move_news_bookmark_selection(app, false),
         code if matches_any(&ke, &app.keymap.pane_next) && code == ke.code => {
             if app.history_state.selected().is_none() && !app.news_recent_values().is_empty() {
                 app.history_state.select(Some(0));
@@ -212,11 +220,11 @@ fn handle_news_bookmarks_key(ke: KeyEvent, app: &mut AppState) -> bool {
         KeyCode::Delete => {
             delete_news_bookmark_at_selection(app);
         }
-        KeyCode::Esc | KeyCode::Left => {
+        _ if left => {
             app.focus = crate::state::Focus::Search;
             app.search_normal_mode = true;
         }
-        KeyCode::Right => {
+        _ if right => {
             app.focus = crate::state::Focus::Recent;
         }
         _ => {}
@@ -288,11 +296,19 @@ fn handle_right_arrow_navigation(
 ///   - Installed-only: selection moves in Downgrade/Remove/Install subpane depending on focus.
 /// - Pane cycling: Configured `pane_next` chord cycles focus across panes. In installed-only mode
 ///   it cycles Search → Downgrade → Remove → Recent → Search; otherwise Search → Install → Recent.
-/// - Arrow focus: Left/Right move focus between Search/Install/Recent (and subpanes when installed-only).
+/// - Arrow focus: Left/Right (or configured `pane_left`/`pane_right`) move focus between
+///   Search/Install/Recent (and subpanes when installed-only); configured `pane_up`/`pane_down`
+///   move the selection like Up/Down.
 /// - Deletion: `Delete` (or configured `install_remove`) removes the selected entry from the active
 ///   list (Install/Remove/Downgrade) and updates selection and details.
 /// - Clear list: Configured `install_clear` clears the respective list (or all in normal mode),
 ///   and resets selection.
+/// - Reorder: Configured `install_reorder_up`/`install_reorder_down` swap the selected Install
+///   entry with its visible neighbor.
+/// - Copy command: Configured `install_copy_command` copies a `paru -S`/`pacman -S` command for
+///   the whole Install list (normal mode only).
+/// - Profiles: Configured `install_profiles` opens the named install-list profile picker
+///   (normal mode only).
 /// - Enter:
 ///   - Normal mode with non-empty Install list: opens `Modal::ConfirmInstall` for batch install.
 ///   - Installed-only Remove focus with non-empty list: opens `Modal::ConfirmRemove`.
@@ -345,6 +361,12 @@ pub fn handle_install_key(
     };
 
     match ke.code {
+        _ if super::utils::matches_any(&ke, &km.install_reorder_up) => {
+            reorder_install_item(app, true);
+        }
+        _ if super::utils::matches_any(&ke, &km.install_reorder_down) => {
+            reorder_install_item(app, false);
+        }
         KeyCode::Char('j') => {
             handle_navigation_down(app, details_tx);
         }
@@ -371,14 +393,14 @@ pub fn handle_install_key(
                 handle_pane_next_navigation(app, details_tx, preview_tx);
             }
         }
-        KeyCode::Left => {
+        code if code == KeyCode::Left || matches_any(&km.pane_left) => {
             if matches!(app.app_mode, crate::state::types::AppMode::News) {
                 app.focus = crate::state::Focus::Search;
             } else {
                 handle_left_arrow_navigation(app, details_tx);
             }
         }
-        KeyCode::Right => {
+        code if code == KeyCode::Right || matches_any(&km.pane_right) => {
             if matches!(app.app_mode, crate::state::types::AppMode::News) {
                 app.focus = crate::state::Focus::Recent;
             } else {
@@ -394,10 +416,22 @@ pub fn handle_install_key(
         code if matches_any(&km.install_remove) && code == ke.code => {
             handle_delete_item(app, details_tx);
         }
-        KeyCode::Up => {
+        code if matches_any(&km.install_copy_command)
+            && code == ke.code
+            && !app.installed_only_mode =>
+        {
+            crate::install::copy_install_list_command(app);
+        }
+        code if matches_any(&km.install_profiles)
+            && code == ke.code
+            && !app.installed_only_mode =>
+        {
+            crate::events::modals::open_install_profiles_modal(app);
+        }
+        code if code == KeyCode::Up || matches_any(&km.pane_up) => {
             handle_navigation_up(app, details_tx);
         }
-        KeyCode::Down => {
+        code if code == KeyCode::Down || matches_any(&km.pane_down) => {
             handle_navigation_down(app, details_tx);
         }
         _ => {}
@@ -481,52 +515,23 @@ fn handle_enter_key(app: &mut AppState) {
             // First, check if we're installing packages that are already installed (reinstall scenario)
             // BUT exclude packages that have updates available (those should go through normal update flow)
             let installed_set = crate::logic::deps::get_installed_packages();
-            let provided_set = crate::logic::deps::get_provided_packages(&installed_set);
             let upgradable_set = crate::logic::deps::get_upgradable_packages();
 
-            let installed_packages: Vec<crate::state::PackageItem> = app
-                .install_list
-                .iter()
-                .filter(|item| {
-                    // Check if package is installed or provided by an installed package
-                    let is_installed = crate::logic::deps::is_package_installed_or_provided(
-                        &item.name,
-                        &installed_set,
-                        &provided_set,
-                    );
-
-                    if !is_installed {
-                        return false;
-                    }
-
-                    // Check if package has an update available
-                    // For official packages: check if it's in upgradable_set OR version differs from installed
-                    // For AUR packages: check if target version is different from installed version
-                    let has_update = if upgradable_set.contains(&item.name) {
-                        // Package is in upgradable set (pacman -Qu)
-                        true
-                    } else if !item.version.is_empty() {
-                        // Normalize target version by removing revision suffix (same as installed version normalization)
-                        let normalized_target_version =
-                            item.version.split('-').next().unwrap_or(&item.version);
-                        // Compare normalized target version with normalized installed version
-                        // This works for both official and AUR packages
-                        crate::logic::deps::get_installed_version(&item.name).is_ok_and(
-                            |installed_version| normalized_target_version != installed_version,
-                        )
-                    } else {
-                        // No version info available, no update
-                        false
-                    };
-
-                    // Only show reinstall confirmation if installed AND no update available
-                    // If update is available, it should go through normal update flow
-                    !has_update
-                })
-                .cloned()
-                .collect();
+            let installed_packages = crate::logic::reinstall::reinstall_candidates(
+                &app.install_list,
+                &installed_set,
+                &upgradable_set,
+            );
 
-            if installed_packages.is_empty() {
+            if crate::logic::reinstall_confirm_required(&installed_packages) {
+                // Show reinstall confirmation modal
+                // Store both installed packages (for display) and all packages (for installation)
+                app.modal = crate::state::Modal::ConfirmReinstall {
+                    items: installed_packages,
+                    all_items: app.install_list.clone(),
+                    header_chips: crate::state::modal::PreflightHeaderChips::default(),
+                };
+            } else {
                 // Check if this is a batch update scenario requiring confirmation
                 // Only show if there's actually an update available (package is upgradable)
                 // AND the package has installed packages in its "Required By" field (dependency risk)
@@ -563,14 +568,6 @@ fn handle_enter_key(app: &mut AppState) {
                     app.toast_expires_at =
                         Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
                 }
-            } else {
-                // Show reinstall confirmation modal
-                // Store both installed packages (for display) and all packages (for installation)
-                app.modal = crate::state::Modal::ConfirmReinstall {
-                    items: installed_packages,
-                    all_items: app.install_list.clone(),
-                    header_chips: crate::state::modal::PreflightHeaderChips::default(),
-                };
             }
         } else {
             open_preflight_install_modal(app);
@@ -726,6 +723,50 @@ fn handle_navigation_up(app: &mut AppState, details_tx: &mpsc::UnboundedSender<P
     }
 }
 
+/// What: Swap the selected Install list entry with its visible neighbor.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `up`: `true` to move towards the top, `false` towards the bottom
+///
+/// Output:
+/// - `true` when the entry moved; `false` at the list edges or outside the Install subpane.
+///
+/// Details:
+/// - Neighbors come from `filtered_install_indices`, so with an active pane filter the entry
+///   swaps with the next visible match rather than a hidden package.
+/// - With `install_group_by_source`, entries never cross from the official into the AUR group.
+/// - The selection follows the moved entry and the list is marked dirty.
+fn reorder_install_item(app: &mut AppState, up: bool) -> bool {
+    if app.installed_only_mode
+        && !matches!(app.right_pane_focus, crate::state::RightPaneFocus::Install)
+    {
+        return false;
+    }
+    let inds = crate::ui::helpers::filtered_install_indices(app);
+    let Some(pos) = app.install_state.selected().filter(|&p| p < inds.len()) else {
+        return false;
+    };
+    let target = if up {
+        pos.checked_sub(1)
+    } else {
+        Some(pos + 1).filter(|&t| t < inds.len())
+    };
+    let Some(target) = target else {
+        return false;
+    };
+    let (from, to) = (inds[pos], inds[target]);
+    let is_aur = |i: usize| matches!(app.install_list[i].source, crate::state::Source::Aur);
+    if app.install_group_by_source && is_aur(from) != is_aur(to) {
+        return false;
+    }
+    app.install_list.swap(from, to);
+    app.install_state.select(Some(target));
+    app.install_dirty = true;
+    app.last_install_change = Some(std::time::Instant::now());
+    true
+}
+
 /// What: Delete the selected item from the active list.
 ///
 /// Inputs:
@@ -738,6 +779,7 @@ fn handle_navigation_up(app: &mut AppState, details_tx: &mpsc::UnboundedSender<P
 /// Details:
 /// - Handles deletion from Install/Remove/Downgrade lists based on mode and focus
 fn handle_delete_item(app: &mut AppState, details_tx: &mpsc::UnboundedSender<PackageItem>) {
+    app.invalidate_preflight_summary_cache();
     if app.installed_only_mode {
         match app.right_pane_focus {
             crate::state::RightPaneFocus::Downgrade => {
@@ -840,6 +882,7 @@ fn delete_from_install_list(app: &mut AppState, details_tx: &mpsc::UnboundedSend
 /// Details:
 /// - Clears Install/Remove/Downgrade list based on mode and focus
 fn handle_clear_list(app: &mut AppState) {
+    app.invalidate_preflight_summary_cache();
     if app.installed_only_mode {
         match app.right_pane_focus {
             crate::state::RightPaneFocus::Downgrade => {
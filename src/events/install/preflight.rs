@@ -273,12 +273,9 @@ fn create_minimal_summary(
     );
 
     let has_aur = aur_count > 0;
-    let risk_score = if has_aur { 2 } else { 0 };
-    let risk_level = if has_aur {
-        crate::state::modal::RiskLevel::Medium
-    } else {
-        crate::state::modal::RiskLevel::Low
-    };
+    let weights = crate::theme::settings().preflight_risk_weights;
+    let risk_score = if has_aur { weights.aur } else { 0 };
+    let risk_level = crate::logic::preflight::risk_level_for(risk_score, &weights);
     let aur_warning = if has_aur {
         vec![crate::i18n::t(
             app,
@@ -326,6 +323,9 @@ fn create_minimal_summary(
         service_restart_units: vec![],
         summary_warnings: aur_warning,
         summary_notes: aur_note,
+        orphaned_dependencies: vec![],
+        conflicts: vec![],
+        hook_notes: vec![],
     };
 
     let minimal_header = crate::state::modal::PreflightHeaderChips {
@@ -459,6 +459,7 @@ fn trigger_background_resolution(
 /// - Loads cached dependencies, files, services, and sandbox info
 /// - Creates minimal summary for immediate display
 /// - Triggers background resolution for missing data
+/// - Shows a blocking alert instead when pacman-only mode refuses AUR packages in the list
 pub fn open_preflight_install_modal(app: &mut AppState) {
     tracing::info!(
         "[Install] Opening preflight modal for {} packages",
@@ -467,6 +468,13 @@ pub fn open_preflight_install_modal(app: &mut AppState) {
     let start_time = std::time::Instant::now();
     let item_count = app.install_list.len();
     let items = app.install_list.clone();
+    if crate::install::reject_aur_in_pacman_only(
+        app,
+        &items,
+        crate::install::command::pacman_only_mode(),
+    ) {
+        return;
+    }
 
     let cache_start = std::time::Instant::now();
     let item_names: std::collections::HashSet<String> =
@@ -569,3 +569,236 @@ fn install_delete_last_item() {
     assert!(app.install_list.is_empty());
     assert_eq!(app.install_state.selected(), None);
 }
+
+#[test]
+/// What: Verify navigation in the grouped Install pane skips group headers.
+///
+/// Inputs:
+/// - Mixed install list with grouping enabled (official group: `core1`, `core2`; AUR group:
+///   `aur1`, `aur2`), selection on the last official package, then `j` and `k` key events.
+///
+/// Output:
+/// - `j` moves straight to the first AUR package and `k` back to the last official package.
+///
+/// Details:
+/// - Selection indices count packages only, so the AUR header between the groups is never
+///   selected; the details request identifies the package actually reached.
+fn install_grouped_navigation_skips_headers() {
+    let mut app = new_app();
+    app.install_group_by_source = true;
+    app.install_list = vec![
+        create_test_package("aur1", Source::Aur),
+        create_test_package(
+            "core1",
+            Source::Official {
+                repo: "core".into(),
+                arch: "x86_64".into(),
+            },
+        ),
+        create_test_package("aur2", Source::Aur),
+        create_test_package(
+            "core2",
+            Source::Official {
+                repo: "core".into(),
+                arch: "x86_64".into(),
+            },
+        ),
+    ];
+    app.install_state.select(Some(1));
+    let (dtx, mut drx) = mpsc::unbounded_channel::<PackageItem>();
+    let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+    let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+
+    let _ = handle_install_key(
+        KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()),
+        &mut app,
+        &dtx,
+        &ptx,
+        &atx,
+    );
+    assert_eq!(app.install_state.selected(), Some(2));
+    assert_eq!(drx.try_recv().map(|p| p.name).ok().as_deref(), Some("aur1"));
+
+    let _ = handle_install_key(
+        KeyEvent::new(KeyCode::Char('k'), KeyModifiers::empty()),
+        &mut app,
+        &dtx,
+        &ptx,
+        &atx,
+    );
+    assert_eq!(app.install_state.selected(), Some(1));
+    assert_eq!(
+        drx.try_recv().map(|p| p.name).ok().as_deref(),
+        Some("core2")
+    );
+
+    let rows = crate::ui::helpers::install_pane_rows(
+        &app,
+        &crate::ui::helpers::filtered_install_indices(&app),
+    );
+    assert_eq!(
+        crate::ui::helpers::install_row_selection(&rows, 3),
+        Some(2),
+        "clicking the AUR header selects the first AUR package"
+    );
+}
+
+/// What: Build an app whose Install list holds `a`, `b`, `c` (AUR) with a selection.
+///
+/// Inputs:
+/// - `selected`: Initial selection index.
+///
+/// Output:
+/// - `AppState` with a clean dirty flag.
+fn app_with_abc(selected: usize) -> AppState {
+    let mut app = new_app();
+    app.install_list = vec![
+        create_test_package("a", Source::Aur),
+        create_test_package("b", Source::Aur),
+        create_test_package("c", Source::Aur),
+    ];
+    app.install_state.select(Some(selected));
+    app.install_dirty = false;
+    app
+}
+
+/// What: Collect Install list names in order.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Package names in `install_list` order.
+fn install_names(app: &AppState) -> Vec<&str> {
+    app.install_list.iter().map(|p| p.name.as_str()).collect()
+}
+
+#[test]
+/// What: Verify moving the top entry up and the bottom entry down are no-ops.
+///
+/// Inputs:
+/// - List `a, b, c`; selection on `a` moved up, then on `c` moved down.
+///
+/// Output:
+/// - Order, selection, and dirty flag are unchanged.
+fn install_reorder_edges_are_noops() {
+    let mut app = app_with_abc(0);
+    assert!(!super::reorder_install_item(&mut app, true));
+    assert_eq!(install_names(&app), vec!["a", "b", "c"]);
+    assert_eq!(app.install_state.selected(), Some(0));
+    assert!(!app.install_dirty);
+
+    let mut app = app_with_abc(2);
+    assert!(!super::reorder_install_item(&mut app, false));
+    assert_eq!(install_names(&app), vec!["a", "b", "c"]);
+    assert_eq!(app.install_state.selected(), Some(2));
+    assert!(!app.install_dirty);
+}
+
+#[test]
+/// What: Verify the reorder keys swap a middle entry and the selection follows it.
+///
+/// Inputs:
+/// - List `a, b, c` with `b` selected; Shift+J (reported as uppercase `J`), then Shift+K.
+///
+/// Output:
+/// - `b` moves below `c` and back; selection tracks `b` and the list is marked dirty.
+fn install_reorder_swaps_middle_entry() {
+    let mut app = app_with_abc(1);
+    let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+    let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+    let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+
+    let _ = handle_install_key(
+        KeyEvent::new(KeyCode::Char('J'), KeyModifiers::SHIFT),
+        &mut app,
+        &dtx,
+        &ptx,
+        &atx,
+    );
+    assert_eq!(install_names(&app), vec!["a", "c", "b"]);
+    assert_eq!(app.install_state.selected(), Some(2));
+    assert!(app.install_dirty);
+
+    let _ = handle_install_key(
+        KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT),
+        &mut app,
+        &dtx,
+        &ptx,
+        &atx,
+    );
+    assert_eq!(install_names(&app), vec!["a", "b", "c"]);
+    assert_eq!(app.install_state.selected(), Some(1));
+}
+
+#[test]
+/// What: Verify reordering swaps with the visible neighbor while a pane filter is active.
+///
+/// Inputs:
+/// - List `rg, fd, ripgrep-all` with the Install pane filtered to `rg`; second match selected.
+///
+/// Output:
+/// - `ripgrep-all` swaps with `rg`, skipping the hidden `fd`; selection stays on it.
+fn install_reorder_respects_pane_filter() {
+    let mut app = new_app();
+    app.install_list = vec![
+        create_test_package("rg", Source::Aur),
+        create_test_package("fd", Source::Aur),
+        create_test_package("ripgrep-all", Source::Aur),
+    ];
+    app.focus = crate::state::Focus::Install;
+    app.pane_find = Some("r".into());
+    assert_eq!(
+        crate::ui::helpers::filtered_install_indices(&app),
+        vec![0, 2]
+    );
+    app.install_state.select(Some(1));
+
+    assert!(super::reorder_install_item(&mut app, true));
+    assert_eq!(install_names(&app), vec!["ripgrep-all", "fd", "rg"]);
+    assert_eq!(app.install_state.selected(), Some(0));
+}
+
+#[test]
+/// What: Verify a custom `pane_left` chord runs the Left-arrow navigation alongside the arrow.
+///
+/// Inputs:
+/// - Installed-only mode with Remove focus, `pane_left` rebound to `h`; `h`, then `Left`.
+///
+/// Output:
+/// - `h` moves to the Downgrade subpane like Left would; `Left` still moves on to Search.
+fn install_custom_pane_left_chord_navigates_left() {
+    let mut app = new_app();
+    app.keymap.pane_left = vec![crate::theme::KeyChord {
+        code: KeyCode::Char('h'),
+        mods: KeyModifiers::empty(),
+    }];
+    app.installed_only_mode = true;
+    app.focus = crate::state::Focus::Install;
+    app.right_pane_focus = crate::state::RightPaneFocus::Remove;
+    let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+    let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+    let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+
+    let _ = handle_install_key(
+        KeyEvent::new(KeyCode::Char('h'), KeyModifiers::empty()),
+        &mut app,
+        &dtx,
+        &ptx,
+        &atx,
+    );
+    assert_eq!(
+        app.right_pane_focus,
+        crate::state::RightPaneFocus::Downgrade
+    );
+    assert_eq!(app.focus, crate::state::Focus::Install);
+
+    let _ = handle_install_key(
+        KeyEvent::new(KeyCode::Left, KeyModifiers::empty()),
+        &mut app,
+        &dtx,
+        &ptx,
+        &atx,
+    );
+    assert_eq!(app.focus, crate::state::Focus::Search);
+}
@@ -10,9 +10,11 @@ use crate::state::{
     AppState, Focus, PackageItem, PkgbuildCheckRequest, QueryInput, types::AppMode,
 };
 
+/// Command palette (fuzzy action launcher).
+mod command_palette;
 mod global;
 /// TUI-side guardrail helpers (pacman db-lock alerts).
-mod guardrails;
+pub(crate) mod guardrails;
 /// Install pane event handling.
 mod install;
 mod modals;
@@ -30,6 +32,9 @@ pub use search::open_preflight_modal;
 // Re-export start_execution for use in install/direct.rs and other modules
 pub use preflight::start_execution;
 
+// Re-export start_update_execution for the full upgrade flow in install/upgrade.rs
+pub use modals::start_update_execution;
+
 /// What: Perform interactive privilege-tool authentication with a TUI terminal handoff.
 ///
 /// Inputs:
@@ -0,0 +1,99 @@
+//! Handlers for the activity log browser modal.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::state::{AppState, Modal};
+
+/// What: Open the activity log browser with the persisted entries.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Sets `app.modal` to [`Modal::ActivityLog`] with entries newest first.
+pub fn open_activity_log_modal(app: &mut AppState) {
+    let mut entries = crate::app::activity_log();
+    entries.reverse();
+    app.modal = Modal::ActivityLog { entries, scroll: 0 };
+}
+
+/// What: Handle keys for [`Modal::ActivityLog`].
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `entries`: Listed log entries (newest first).
+/// - `scroll`: Current list scroll offset.
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Esc/`q` closes; Up/Down (`k`/`j`) scroll one entry, clamped to the last entry.
+pub(super) fn handle_activity_log_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    entries: &[crate::app::ActivityEntry],
+    scroll: u16,
+) -> bool {
+    let max_scroll = u16::try_from(entries.len().saturating_sub(1)).unwrap_or(u16::MAX);
+    let scroll = match ke.code {
+        KeyCode::Esc | KeyCode::Char('q' | 'Q') => {
+            app.modal = Modal::None;
+            return true;
+        }
+        KeyCode::Up | KeyCode::Char('k') => scroll.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => scroll.saturating_add(1).min(max_scroll),
+        _ => return false,
+    };
+    app.modal = Modal::ActivityLog {
+        entries: entries.to_vec(),
+        scroll,
+    };
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{ActivityAction, ActivityEntry};
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    /// What: Verify scrolling is clamped and Esc closes the browser.
+    ///
+    /// Inputs:
+    /// - Two entries; Down pressed twice, then Esc.
+    ///
+    /// Output:
+    /// - Scroll stops at the last entry; Esc leaves `Modal::None`.
+    fn activity_log_scrolls_and_closes() {
+        let mut app = AppState::default();
+        let entries = vec![
+            ActivityEntry {
+                timestamp: 2,
+                action: ActivityAction::Remove,
+                packages: vec!["fd".into()],
+            },
+            ActivityEntry {
+                timestamp: 1,
+                action: ActivityAction::Install,
+                packages: vec!["ripgrep".into()],
+            },
+        ];
+        let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        let mut scroll = 0;
+        for _ in 0..2 {
+            assert!(handle_activity_log_modal(down, &mut app, &entries, scroll));
+            let Modal::ActivityLog { scroll: s, .. } = &app.modal else {
+                panic!("expected ActivityLog, got {:?}", app.modal);
+            };
+            scroll = *s;
+        }
+        assert_eq!(scroll, 1);
+
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(handle_activity_log_modal(esc, &mut app, &entries, scroll));
+        assert!(matches!(app.modal, Modal::None));
+    }
+}
@@ -0,0 +1,84 @@
+//! Handlers for the "AUR helper not found" guidance modal shown at install time.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::state::modal::PreflightHeaderChips;
+use crate::state::{AppState, Modal, PackageItem, SecureString};
+
+/// What: Open the Optional Deps modal with the `paru` row preselected.
+///
+/// Inputs:
+/// - `app`: Application state (sets modal and SSH help check).
+///
+/// Output:
+/// - None (mutates `app`).
+///
+/// Details:
+/// - Falls back to the first row when no `paru` row exists (e.g. filtered by environment).
+/// - Spawns the AUR SSH help check like other Optional Deps entry points.
+fn open_optional_deps_for_helper(app: &mut AppState) {
+    let rows = crate::events::mouse::menu_options::build_optional_deps_rows(app);
+    let selected = rows
+        .iter()
+        .position(|row| row.package == "paru")
+        .unwrap_or(0);
+    app.modal = Modal::OptionalDeps {
+        rows,
+        selected,
+        selected_pkg_names: std::collections::HashSet::new(),
+    };
+    let ssh_command = crate::theme::settings().aur_vote_ssh_command;
+    app.pending_aur_ssh_help_check_result = Some(
+        crate::logic::ssh_setup::spawn_aur_ssh_help_check(ssh_command),
+    );
+}
+
+/// What: Handle keys for [`Modal::AurHelperMissing`].
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `items`: Install set that was blocked.
+/// - `header_chips`: Preflight chips for the pacman-only install.
+/// - `password`: Password collected before execution, if any.
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Enter/`o` opens Optional Deps to install a helper; `p` installs only the official
+///   targets via pacman (ignored when the set is AUR-only); Esc/`q` cancels.
+pub(super) fn handle_aur_helper_missing_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    items: &[PackageItem],
+    header_chips: &PreflightHeaderChips,
+    password: Option<&SecureString>,
+) -> bool {
+    match ke.code {
+        KeyCode::Esc | KeyCode::Char('q' | 'Q') => {
+            app.modal = Modal::None;
+            true
+        }
+        KeyCode::Enter | KeyCode::Char('\n' | '\r' | 'o' | 'O') => {
+            open_optional_deps_for_helper(app);
+            true
+        }
+        KeyCode::Char('p' | 'P') => {
+            let official = crate::events::install::official_only_items(items);
+            if official.is_empty() {
+                return false;
+            }
+            app.modal = Modal::None;
+            crate::events::start_execution(
+                app,
+                &official,
+                crate::state::PreflightAction::Install,
+                header_chips.clone(),
+                password.cloned(),
+            );
+            true
+        }
+        _ => false,
+    }
+}
@@ -364,6 +364,9 @@ pub(super) fn handle_preflight_exec(
                 app.pending_repositories_modal_resume = None;
             }
             app.modal = crate::state::Modal::None;
+            if crate::logic::pacnew::show_pacnew_reminder_if_pending(app) {
+                return true;
+            }
             if success == Some(false) && !app.pending_startup_setup_steps.is_empty() {
                 show_next_startup_setup_step(app);
                 return true;
@@ -441,6 +444,9 @@ pub(super) fn handle_post_summary(
         // Close modal and stop propagation to prevent key from reaching other handlers
         KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q' | '\n' | '\r') => {
             app.modal = crate::state::Modal::None;
+            if crate::logic::pacnew::show_pacnew_reminder_if_pending(app) {
+                return true;
+            }
             if !app.pending_startup_setup_steps.is_empty() {
                 show_next_startup_setup_step(app);
                 return true;
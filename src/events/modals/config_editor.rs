@@ -45,6 +45,8 @@ pub(in crate::events) fn handle_config_editor_mode_key(ke: KeyEvent, app: &mut A
     let mut state = std::mem::take(&mut app.config_editor_state);
     if state.popup.is_some() {
         handle_popup_key(ke, app, &mut state);
+    } else if ke.code == KeyCode::Char('o') && ke.modifiers.contains(KeyModifiers::CONTROL) {
+        import_keymap_file(app, &mut state);
     } else {
         handle_editor_key(ke, &mut state, app);
     }
@@ -123,6 +125,9 @@ fn handle_search_key(ke: KeyEvent, state: &mut ConfigEditorState, app: &AppState
         (KeyCode::Char('e'), m) if m.contains(KeyModifiers::CONTROL) => {
             export_effective_config(state, app.dry_run);
         }
+        (KeyCode::Char('x'), m) if m.contains(KeyModifiers::CONTROL) => {
+            export_keymap_file(state, &app.keymap, app.dry_run);
+        }
         (KeyCode::Char(c), m)
             if matches!(state.search_focus, ConfigEditorSearchFocus::Input)
                 && !m.contains(KeyModifiers::CONTROL)
@@ -193,6 +198,88 @@ fn export_effective_config(state: &mut ConfigEditorState, dry_run: bool) {
     }
 }
 
+/// What: Path used for keymap export (`Ctrl+X`) in the config editor.
+fn keymap_export_path() -> std::path::PathBuf {
+    crate::theme::lists_dir().join("keymap_export.conf")
+}
+
+/// What: Path read by keymap import (`Ctrl+O`) in the config editor.
+fn keymap_import_path() -> std::path::PathBuf {
+    crate::theme::lists_dir().join("keymap_import.conf")
+}
+
+/// What: Export the resolved keymap so it can be shared.
+///
+/// Inputs:
+/// - `state`: Editor state (status line).
+/// - `keymap`: Live keymap from `AppState`.
+/// - `dry_run`: When `true`, report the would-be export without writing.
+///
+/// Output:
+/// - Writes `keymap_export.conf` under the Pacsea lists directory and reports
+///   the destination in the status line.
+///
+/// Details:
+/// - Content comes from [`crate::state::config_editor::export_keymap`].
+fn export_keymap_file(state: &mut ConfigEditorState, keymap: &crate::theme::KeyMap, dry_run: bool) {
+    let path = keymap_export_path();
+    if dry_run {
+        state.status = Some(format!("Dry-run: would export keymap → {}", path.display()));
+        return;
+    }
+    let content = crate::state::config_editor::export_keymap(keymap);
+    match std::fs::write(&path, content) {
+        Ok(()) => state.status = Some(format!("Exported keymap → {}", path.display())),
+        Err(e) => state.status = Some(format!("Keymap export failed: {e}")),
+    }
+}
+
+/// What: Import a shared keymap and apply it to the running app.
+///
+/// Inputs:
+/// - `app`: Application state whose `keymap` is replaced on success.
+/// - `state`: Editor state (status line).
+///
+/// Output:
+/// - Updates `app.keymap` live and reports the action count and any conflicts;
+///   leaves the keymap untouched and reports the reason when validation fails.
+///
+/// Details:
+/// - Reads `keymap_import.conf` from the Pacsea lists directory.
+/// - The imported keymap is not written to `keybinds.conf`; a config reload
+///   restores the on-disk bindings.
+fn import_keymap_file(app: &mut AppState, state: &mut ConfigEditorState) {
+    let path = keymap_import_path();
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            state.status = Some(format!("Keymap import failed ({}): {e}", path.display()));
+            return;
+        }
+    };
+    match crate::state::config_editor::import_keymap(&content, &app.keymap) {
+        Ok(imported) => {
+            app.keymap = imported.keymap;
+            state.status = Some(if imported.conflicts.is_empty() {
+                format!(
+                    "Imported {} keybinds from {}",
+                    imported.actions,
+                    path.display()
+                )
+            } else {
+                format!(
+                    "Imported {} keybinds; conflicts: {}",
+                    imported.actions,
+                    imported.conflicts.join("; ")
+                )
+            });
+        }
+        Err(reason) => {
+            state.status = Some(format!("Keymap import rejected: {reason}"));
+        }
+    }
+}
+
 /// What: Dispatch the Enter key in the middle search pane based on focus.
 ///
 /// Inputs:
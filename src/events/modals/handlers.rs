@@ -106,6 +106,10 @@ pub(super) fn handle_preflight_exec_modal(
             app.modal = modal;
             return true;
         }
+        if crate::logic::exec_log::handle_exec_log_key(app, ke.code, log_lines.len()) {
+            app.modal = modal;
+            return true;
+        }
         // Pass success to the handler since app.modal is taken during dispatch
         let should_stop =
             super::common::handle_preflight_exec(ke, app, verbose, *abortable, items, *success);
@@ -474,6 +478,129 @@ pub(super) fn handle_warn_aur_repo_duplicate_modal(
     false
 }
 
+/// What: Handle keys for `AurHelperMissing`, restoring modal when the key is not consumed.
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `modal`: Taken modal reference (original state before `mem::take`).
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Delegates to [`super::aur_helper_missing::handle_aur_helper_missing_modal`].
+pub(super) fn handle_aur_helper_missing_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    modal: &Modal,
+) -> bool {
+    if let Modal::AurHelperMissing {
+        items,
+        header_chips,
+        password,
+    } = modal
+    {
+        let consumed = super::aur_helper_missing::handle_aur_helper_missing_modal(
+            ke,
+            app,
+            items,
+            header_chips,
+            password.as_ref(),
+        );
+        if !consumed {
+            app.modal = modal.clone();
+        }
+        return consumed;
+    }
+    false
+}
+
+/// What: Handle keys for `PacnewReminder`, restoring modal when the key is not consumed.
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `modal`: Taken modal reference (original state before `mem::take`).
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Delegates to [`super::pacnew_reminder::handle_pacnew_reminder_modal`].
+pub(super) fn handle_pacnew_reminder_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    modal: &Modal,
+) -> bool {
+    if let Modal::PacnewReminder { paths, scroll } = modal {
+        let consumed =
+            super::pacnew_reminder::handle_pacnew_reminder_modal(ke, app, paths, *scroll);
+        if !consumed {
+            app.modal = modal.clone();
+        }
+        return consumed;
+    }
+    false
+}
+
+/// What: Handle keys for `ActivityLog`, restoring modal when the key is not consumed.
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `modal`: Taken modal reference (original state before `mem::take`).
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Delegates to [`super::activity_log::handle_activity_log_modal`].
+pub(super) fn handle_activity_log_modal(ke: KeyEvent, app: &mut AppState, modal: &Modal) -> bool {
+    if let Modal::ActivityLog { entries, scroll } = modal {
+        let consumed = super::activity_log::handle_activity_log_modal(ke, app, entries, *scroll);
+        if !consumed {
+            app.modal = modal.clone();
+        }
+        return consumed;
+    }
+    false
+}
+
+/// What: Handle keys for `InstallProfiles`, restoring modal when the key is not consumed.
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `modal`: Taken modal reference (original state before `mem::take`).
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Delegates to [`super::install_profiles::handle_install_profiles_modal`].
+pub(super) fn handle_install_profiles_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    modal: &Modal,
+) -> bool {
+    if let Modal::InstallProfiles {
+        profiles,
+        selected,
+        name_input,
+    } = modal
+    {
+        let consumed = super::install_profiles::handle_install_profiles_modal(
+            ke, app, profiles, *selected, name_input,
+        );
+        if !consumed {
+            app.modal = modal.clone();
+        }
+        return consumed;
+    }
+    false
+}
+
 /// What: Handle keys for `ForeignRepoOverlap`, restoring modal when the key is not consumed.
 ///
 /// Inputs:
@@ -1683,6 +1810,8 @@ pub(super) fn handle_password_prompt_modal(
                         success: None,
                     };
 
+                    app.pending_update_activity = (!app.dry_run).then(|| app.updates_list.clone());
+
                     // Store executor request with password
                     app.pending_executor_request = Some(ExecutorRequest::Update {
                         commands,
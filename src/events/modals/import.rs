@@ -25,7 +25,7 @@ pub(super) fn handle_import_help(
     match ke.code {
         KeyCode::Enter | KeyCode::Char('\n' | '\r') => {
             app.modal = crate::state::Modal::None;
-            handle_import_help_enter(add_tx);
+            handle_import_help_enter(app, add_tx);
         }
         KeyCode::Esc => app.modal = crate::state::Modal::None,
         _ => {}
@@ -36,23 +36,30 @@ pub(super) fn handle_import_help(
 /// What: Handle Enter key in `ImportHelp` modal - open file picker and import packages.
 ///
 /// Inputs:
+/// - `app`: Application state (install list membership and pending import summary)
 /// - `add_tx`: Channel for adding packages
 ///
 /// Output: None (spawns background thread)
 ///
 /// Details:
 /// - Opens file picker dialog and imports package names from selected file
+/// - Names already in the install list are skipped; names missing from the official index are
+///   queued as AUR packages. The tick handler reports the `(added, unresolved)` summary.
 /// - During tests, this is a no-op to avoid opening real file picker dialogs
 #[allow(clippy::missing_const_for_fn)]
-fn handle_import_help_enter(add_tx: &mpsc::UnboundedSender<PackageItem>) {
+fn handle_import_help_enter(app: &mut AppState, add_tx: &mpsc::UnboundedSender<PackageItem>) {
     // Skip actual file picker during tests
     // Note: add_tx is only used in non-test builds, but we acknowledge it for static analysis
     #[cfg(test)]
-    let _ = add_tx;
+    let _ = (app, add_tx);
     #[cfg(not(test))]
     {
         tracing::info!("import: Enter pressed in ImportHelp modal");
         let add_tx_clone = add_tx.clone();
+        let existing = app.install_list_names.clone();
+        let summary: crate::state::app_state::ImportSummaryResult =
+            std::sync::Arc::new(std::sync::Mutex::new(None));
+        app.pending_import_summary = Some(std::sync::Arc::clone(&summary));
         std::thread::spawn(move || {
             tracing::info!("import: thread started, opening file picker");
             #[cfg(target_os = "windows")]
@@ -111,38 +118,26 @@ fn handle_import_help_enter(add_tx: &mpsc::UnboundedSender<PackageItem>) {
                 let path = path.trim().to_string();
                 tracing::info!(path = %path, "import: selected file");
                 if let Ok(body) = std::fs::read_to_string(&path) {
-                    use std::collections::HashSet;
-                    let mut official_names: HashSet<String> = HashSet::new();
-                    for it in &crate::index::all_official() {
-                        official_names.insert(it.name.to_lowercase());
-                    }
-                    let mut imported: usize = 0;
-                    for line in body.lines() {
-                        let name = line.trim();
-                        if name.is_empty() || name.starts_with('#') {
-                            continue;
-                        }
-                        let src = if official_names.contains(&name.to_lowercase()) {
-                            crate::state::Source::Official {
-                                repo: String::new(),
-                                arch: String::new(),
-                            }
-                        } else {
-                            crate::state::Source::Aur
-                        };
-                        let item = crate::state::PackageItem {
-                            name: name.to_string(),
-                            version: String::new(),
-                            description: String::new(),
-                            source: src,
-                            popularity: None,
-                            out_of_date: None,
-                            orphaned: false,
-                        };
+                    let names = crate::logic::import_list::parse_package_list(&body);
+                    let res = crate::logic::import_list::resolve_import_names(
+                        &names,
+                        &existing,
+                        crate::index::find_package_by_name,
+                    );
+                    let added = res.items.len();
+                    for item in res.items {
                         let _ = add_tx_clone.send(item);
-                        imported += 1;
                     }
-                    tracing::info!(path = %path, imported, "import: queued items from list");
+                    tracing::info!(
+                        path = %path,
+                        added,
+                        unresolved = res.unresolved,
+                        skipped = res.skipped,
+                        "import: queued items from list"
+                    );
+                    if let Ok(mut slot) = summary.lock() {
+                        *slot = Some((added, res.unresolved));
+                    }
                 } else {
                     tracing::warn!(path = %path, "import: failed to read file");
                 }
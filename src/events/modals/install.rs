@@ -15,7 +15,7 @@ use crate::state::{AppState, PackageItem};
 /// - `false` (never stops propagation)
 ///
 /// Details:
-/// - Handles Esc to close, Enter to install, s to scan
+/// - Handles Esc to close, Enter to install (or log the plan in dry-run), s to scan
 pub(super) fn handle_confirm_install(
     ke: KeyEvent,
     app: &mut AppState,
@@ -25,12 +25,14 @@ pub(super) fn handle_confirm_install(
         KeyCode::Esc => {
             app.modal = crate::state::Modal::None;
         }
+        KeyCode::Enter if app.dry_run => {
+            handle_confirm_install_dry_run(app, items);
+        }
         KeyCode::Enter => {
             let new_modal = handle_confirm_install_enter(
                 &mut app.refresh_installed_until,
                 &mut app.next_installed_refresh_at,
                 &mut app.pending_install_names,
-                app.dry_run,
                 items,
             );
             app.modal = new_modal;
@@ -94,24 +96,45 @@ pub(super) fn handle_confirm_remove(
     false
 }
 
+/// What: Log the install plan instead of installing (dry-run).
+///
+/// Inputs:
+/// - `app`: Mutable application state (modal and toast fields)
+/// - `items`: Package items that would be installed
+///
+/// Output:
+/// - Closes the modal and toasts the number of planned commands, or the planning error.
+///
+/// Details:
+/// - Uses `install::plan_install`, so no terminal or process is spawned.
+fn handle_confirm_install_dry_run(app: &mut AppState, items: &[PackageItem]) {
+    let plan = crate::install::plan_install(app, items);
+    crate::install::log_plan("install", &plan);
+    app.toast_message = Some(match &plan {
+        Ok(commands) => crate::i18n::tn(app, "app.toasts.dry_run_planned", commands.len()),
+        Err(err) => err.clone(),
+    });
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
+    app.modal = crate::state::Modal::None;
+}
+
 /// What: Execute package installation.
 ///
 /// Inputs:
 /// - `refresh_installed_until`: Mutable reference to refresh timer
 /// - `next_installed_refresh_at`: Mutable reference to next refresh time
 /// - `pending_install_names`: Mutable reference to pending install names
-/// - `dry_run`: Whether to run in dry-run mode
 /// - `items`: Package items to install
 ///
 /// Output: New modal state (always None after install)
 ///
 /// Details:
-/// - Spawns install command(s) and sets up refresh tracking
+/// - Spawns install command(s) and sets up refresh tracking; dry-run is handled by
+///   [`handle_confirm_install_dry_run`]
 fn handle_confirm_install_enter(
     refresh_installed_until: &mut Option<std::time::Instant>,
     next_installed_refresh_at: &mut Option<std::time::Instant>,
     pending_install_names: &mut Option<Vec<String>>,
-    dry_run: bool,
     items: &[PackageItem],
 ) -> crate::state::Modal {
     let list = items.to_vec();
@@ -121,24 +144,20 @@ fn handle_confirm_install_enter(
     // TODO: Refactor ConfirmInstall modal to have AppState access or pass it as parameter
     if list.len() <= 1 {
         if let Some(it) = list.first() {
-            crate::install::spawn_install(it, None, dry_run);
-            if !dry_run {
-                *refresh_installed_until =
-                    Some(std::time::Instant::now() + std::time::Duration::from_secs(12));
-                *next_installed_refresh_at = None;
-                *pending_install_names = Some(vec![it.name.clone()]);
-            }
-        }
-    } else {
-        // Note: batch updates confirmation is handled in preflight or install handlers
-        // This path is for ConfirmInstall modal which doesn't have AppState access
-        crate::install::spawn_install_all(&list, dry_run);
-        if !dry_run {
+            crate::install::spawn_install(it, None, false);
             *refresh_installed_until =
                 Some(std::time::Instant::now() + std::time::Duration::from_secs(12));
             *next_installed_refresh_at = None;
-            *pending_install_names = Some(list.iter().map(|p| p.name.clone()).collect());
+            *pending_install_names = Some(vec![it.name.clone()]);
         }
+    } else {
+        // Note: batch updates confirmation is handled in preflight or install handlers
+        // This path is for ConfirmInstall modal which doesn't have AppState access
+        crate::install::spawn_install_all(&list, false);
+        *refresh_installed_until =
+            Some(std::time::Instant::now() + std::time::Duration::from_secs(12));
+        *next_installed_refresh_at = None;
+        *pending_install_names = Some(list.iter().map(|p| p.name.clone()).collect());
     }
     crate::state::Modal::None
 }
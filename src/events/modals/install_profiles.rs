@@ -0,0 +1,165 @@
+//! Handlers for the named install-list profile picker.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::state::{AppState, Modal};
+
+/// What: Show a toast for a profile action.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `message`: Toast text.
+/// - `secs`: Display duration in seconds.
+///
+/// Output:
+/// - None (sets the toast fields).
+fn show_toast(app: &mut AppState, message: String, secs: u64) {
+    app.toast_message = Some(message);
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(secs));
+}
+
+/// What: Open the install profile picker listing the saved profiles.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Sets `app.modal` to [`Modal::InstallProfiles`].
+pub fn open_install_profiles_modal(app: &mut AppState) {
+    app.modal = Modal::InstallProfiles {
+        profiles: app.install_profile_names(),
+        selected: 0,
+        name_input: String::new(),
+    };
+}
+
+/// What: Save the current install list under `name` and close the picker.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `name`: Profile name.
+///
+/// Output:
+/// - `true` when the profile was written; failures are reported as a toast.
+fn save_profile(app: &mut AppState, name: &str) -> bool {
+    match app.save_install_profile(name) {
+        Ok(()) => {
+            tracing::info!(
+                profile = name.trim(),
+                count = app.install_list.len(),
+                "saved install profile"
+            );
+            let msg = crate::i18n::t_fmt1(app, "app.toasts.install_profile_saved", name.trim());
+            show_toast(app, msg, 3);
+            true
+        }
+        Err(e) => {
+            tracing::warn!(profile = name, error = %e, "failed to save install profile");
+            let msg = crate::i18n::t_fmt1(app, "app.toasts.install_profile_failed", e);
+            show_toast(app, msg, 5);
+            false
+        }
+    }
+}
+
+/// What: Replace the install list with profile `name` and close the picker.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `name`: Profile name.
+///
+/// Output:
+/// - `true` when the profile was loaded; failures are reported as a toast.
+fn load_profile(app: &mut AppState, name: &str) -> bool {
+    match app.load_install_profile(name) {
+        Ok(count) => {
+            tracing::info!(profile = name, count, "loaded install profile");
+            let msg = crate::i18n::t_fmt2(app, "app.toasts.install_profile_loaded", name, count);
+            show_toast(app, msg, 3);
+            true
+        }
+        Err(e) => {
+            tracing::warn!(profile = name, error = %e, "failed to load install profile");
+            let msg = crate::i18n::t_fmt1(app, "app.toasts.install_profile_failed", e);
+            show_toast(app, msg, 5);
+            false
+        }
+    }
+}
+
+/// What: Handle keys for [`Modal::InstallProfiles`].
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `profiles`: Listed profile names.
+/// - `selected`: Highlighted profile index.
+/// - `name_input`: Name typed for saving.
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Up/Down move the highlight; Enter loads the highlighted profile.
+/// - Typing edits the name; Ctrl+S saves the current list under it (or overwrites the highlighted
+///   profile when the name is empty).
+/// - Esc closes the picker.
+pub(super) fn handle_install_profiles_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    profiles: &[String],
+    selected: usize,
+    name_input: &str,
+) -> bool {
+    let reopen = |app: &mut AppState, selected: usize, name_input: String| {
+        app.modal = Modal::InstallProfiles {
+            profiles: profiles.to_vec(),
+            selected,
+            name_input,
+        };
+    };
+    match ke.code {
+        KeyCode::Esc => {
+            app.modal = Modal::None;
+        }
+        KeyCode::Char('s') if ke.modifiers.contains(KeyModifiers::CONTROL) => {
+            let target = if name_input.trim().is_empty() {
+                profiles.get(selected).map_or("", String::as_str)
+            } else {
+                name_input
+            };
+            if target.is_empty() || !save_profile(app, target) {
+                reopen(app, selected, name_input.to_string());
+            }
+        }
+        KeyCode::Enter | KeyCode::Char('\n' | '\r') => {
+            let loaded = profiles
+                .get(selected)
+                .is_some_and(|name| load_profile(app, name));
+            if !loaded {
+                reopen(app, selected, name_input.to_string());
+            }
+        }
+        KeyCode::Up => reopen(app, selected.saturating_sub(1), name_input.to_string()),
+        KeyCode::Down => {
+            let max = profiles.len().saturating_sub(1);
+            reopen(
+                app,
+                selected.saturating_add(1).min(max),
+                name_input.to_string(),
+            );
+        }
+        KeyCode::Backspace => {
+            let mut buf = name_input.to_string();
+            buf.pop();
+            reopen(app, selected, buf);
+        }
+        KeyCode::Char(ch) if !ke.modifiers.contains(KeyModifiers::CONTROL) => {
+            let mut buf = name_input.to_string();
+            buf.push(ch);
+            reopen(app, selected, buf);
+        }
+        _ => return false,
+    }
+    true
+}
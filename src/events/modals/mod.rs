@@ -1,5 +1,10 @@
 //! Modal event handling module (excluding Preflight which is in preflight.rs).
 
+/// Key handling for the activity log browser.
+mod activity_log;
+pub(in crate::events) use activity_log::open_activity_log_modal;
+/// Key handling for the AUR helper missing guidance modal.
+mod aur_helper_missing;
 mod common;
 /// Key handling for the integrated TUI config editor modal.
 mod config_editor;
@@ -11,7 +16,12 @@ mod foreign_overlap;
 mod handlers;
 mod import;
 mod install;
+/// Key handling for the named install-list profile picker.
+mod install_profiles;
+pub(in crate::events) use install_profiles::open_install_profiles_modal;
 mod optional_deps;
+/// Key handling for the post-transaction `.pacnew`/`.pacsave` reminder modal.
+mod pacnew_reminder;
 mod password;
 mod repositories;
 pub use repositories::repositories_modal_wheel_step;
@@ -19,6 +29,7 @@ mod restore;
 mod scan;
 mod sudo_timestamp_setup;
 mod system_update;
+pub use system_update::start_update_execution;
 
 #[cfg(test)]
 mod tests;
@@ -70,6 +81,12 @@ pub(super) fn handle_modal_key(
         Modal::WarnAurRepoDuplicate { .. } => {
             handlers::handle_warn_aur_repo_duplicate_modal(ke, app, &modal)
         }
+        Modal::AurHelperMissing { .. } => {
+            handlers::handle_aur_helper_missing_modal(ke, app, &modal)
+        }
+        Modal::PacnewReminder { .. } => handlers::handle_pacnew_reminder_modal(ke, app, &modal),
+        Modal::ActivityLog { .. } => handlers::handle_activity_log_modal(ke, app, &modal),
+        Modal::InstallProfiles { .. } => handlers::handle_install_profiles_modal(ke, app, &modal),
         Modal::ForeignRepoOverlap { .. } => {
             handlers::handle_foreign_repo_overlap_modal(ke, app, &modal)
         }
@@ -99,6 +116,11 @@ pub(super) fn handle_modal_key(
             app.modal = modal;
             true // Consume key to prevent propagation
         }
+        Modal::CommandPalette { .. } => {
+            // The palette is routed from global.rs ahead of global chords
+            app.modal = modal;
+            true
+        }
         Modal::Preflight { .. } => {
             // Preflight is handled separately in preflight.rs
             // Restore it - we shouldn't have gotten here, but be safe
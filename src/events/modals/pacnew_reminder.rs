@@ -0,0 +1,108 @@
+//! Handlers for the post-transaction `.pacnew`/`.pacsave` reminder modal.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::state::{AppState, Modal};
+
+/// What: Close the reminder and continue any follow-up that was waiting behind it.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - None (mutates `app`).
+///
+/// Details:
+/// - Mirrors closing the post-transaction summary: startup setup steps first, then a pending
+///   Repositories modal resume.
+fn close_reminder(app: &mut AppState) {
+    app.modal = Modal::None;
+    if app.pending_startup_setup_steps.is_empty() {
+        super::repositories::reopen_repositories_modal_if_pending(app);
+    } else {
+        super::common::show_next_startup_setup_step(app);
+    }
+}
+
+/// What: Launch `pacdiff` in a terminal to review the new conflict files.
+///
+/// Inputs:
+/// - `app`: Application state (toast feedback).
+///
+/// Output:
+/// - `true` when `pacdiff` was launched, `false` when it is not installed.
+///
+/// Details:
+/// - `pacdiff` prompts interactively for every file, so it runs in a terminal window
+///   (the configured `preferred_terminal`) rather than the non-interactive executor log.
+fn launch_pacdiff(app: &mut AppState) -> bool {
+    if !crate::util::command::binary_available("pacdiff") {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.pacdiff_missing"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(4));
+        return false;
+    }
+    #[cfg(unix)]
+    let as_root = nix::unistd::Uid::effective().is_root();
+    #[cfg(not(unix))]
+    let as_root = false;
+    let cmd = crate::logic::pacnew::pacdiff_command(as_root);
+    tracing::info!(command = %cmd, "Launching pacdiff for new .pacnew/.pacsave files");
+    crate::install::spawn_shell_commands_in_terminal(&[cmd]);
+    app.toast_message = Some(crate::i18n::t(app, "app.toasts.pacdiff_started"));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+    true
+}
+
+/// What: Handle keys for [`Modal::PacnewReminder`].
+///
+/// Inputs:
+/// - `ke`: Key event.
+/// - `app`: Application state.
+/// - `paths`: Listed conflict files.
+/// - `scroll`: Current list scroll offset.
+///
+/// Output:
+/// - `true` when the event was consumed.
+///
+/// Details:
+/// - Enter/`d` launches `pacdiff` and closes; Esc/`q` dismisses; Up/Down scroll the list.
+pub(super) fn handle_pacnew_reminder_modal(
+    ke: KeyEvent,
+    app: &mut AppState,
+    paths: &[String],
+    scroll: u16,
+) -> bool {
+    let max_scroll = u16::try_from(paths.len().saturating_sub(1)).unwrap_or(u16::MAX);
+    match ke.code {
+        KeyCode::Esc | KeyCode::Char('q' | 'Q') => {
+            close_reminder(app);
+            true
+        }
+        KeyCode::Enter | KeyCode::Char('\n' | '\r' | 'd' | 'D') => {
+            if launch_pacdiff(app) {
+                close_reminder(app);
+            } else {
+                app.modal = Modal::PacnewReminder {
+                    paths: paths.to_vec(),
+                    scroll,
+                };
+            }
+            true
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.modal = Modal::PacnewReminder {
+                paths: paths.to_vec(),
+                scroll: scroll.saturating_sub(1),
+            };
+            true
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.modal = Modal::PacnewReminder {
+                paths: paths.to_vec(),
+                scroll: scroll.saturating_add(1).min(max_scroll),
+            };
+            true
+        }
+        _ => false,
+    }
+}
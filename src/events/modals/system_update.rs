@@ -301,6 +301,23 @@ fn handle_system_update_enter(
         return;
     }
 
+    start_update_execution(app, cmds);
+}
+
+/// What: Run system update commands through the executor, authenticating first when needed.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `cmds`: Shell commands to run in order (chained with `&&` by the executor)
+///
+/// Output:
+/// - Opens `PreflightExec` with an `ExecutorRequest::Update`, or stores the commands in
+///   `pending_update_commands` and opens the `PasswordPrompt` modal.
+///
+/// Details:
+/// - Interactive auth hands the terminal to the privilege tool first; passwordless-only auth
+///   skips the prompt; otherwise the password handler starts execution after validation.
+pub fn start_update_execution(app: &mut AppState, cmds: Vec<String>) {
     let settings = crate::theme::settings();
     if crate::logic::password::should_use_interactive_auth_handoff(&settings) {
         match crate::events::try_interactive_auth_handoff() {
@@ -315,6 +332,7 @@ fn handle_system_update_enter(
                     header_chips: crate::state::modal::PreflightHeaderChips::default(),
                     success: None,
                 };
+                app.pending_update_activity = (!app.dry_run).then(|| app.updates_list.clone());
                 app.pending_executor_request = Some(crate::install::ExecutorRequest::Update {
                     commands: cmds,
                     password: None,
@@ -347,6 +365,7 @@ fn handle_system_update_enter(
             header_chips: crate::state::modal::PreflightHeaderChips::default(),
             success: None,
         };
+        app.pending_update_activity = (!app.dry_run).then(|| app.updates_list.clone());
         app.pending_executor_request = Some(crate::install::ExecutorRequest::Update {
             commands: cmds,
             password: None,
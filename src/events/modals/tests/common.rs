@@ -85,6 +85,14 @@ pub(super) fn create_test_modals() -> Vec<(crate::state::Modal, &'static str)> {
             },
             "WarnAurRepoDuplicate",
         ),
+        (
+            crate::state::Modal::AurHelperMissing {
+                items: vec![],
+                header_chips: PreflightHeaderChips::default(),
+                password: None,
+            },
+            "AurHelperMissing",
+        ),
         (
             crate::state::Modal::ForeignRepoOverlap {
                 repo_name: "extra".to_string(),
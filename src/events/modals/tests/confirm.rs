@@ -42,6 +42,36 @@ fn confirm_install_esc_closes_modal() {
     assert!(matches!(app.modal, crate::state::Modal::None));
 }
 
+#[test]
+/// What: Verify Enter in dry-run logs the install plan instead of installing.
+///
+/// Inputs:
+/// - `ConfirmInstall` modal with one AUR package, dry-run enabled
+/// - Enter key event
+///
+/// Output:
+/// - Modal closes, nothing is tracked as pending, and a toast reports the plan
+///
+/// Details:
+/// - The AUR-only plan needs no privilege tool, so it always yields one command.
+fn confirm_install_enter_dry_run_plans_only() {
+    let mut app = new_app();
+    app.dry_run = true;
+    app.modal = crate::state::Modal::ConfirmInstall {
+        items: vec![PackageItem::test_item("test-pkg", "aur")],
+    };
+
+    let (add_tx, _add_rx) = mpsc::unbounded_channel::<PackageItem>();
+    let ke = key_event(KeyCode::Enter, KeyModifiers::empty());
+
+    handle_modal_key(ke, &mut app, &add_tx);
+
+    assert!(matches!(app.modal, crate::state::Modal::None));
+    assert!(app.pending_install_names.is_none());
+    assert!(app.refresh_installed_until.is_none());
+    assert!(app.toast_message.is_some());
+}
+
 #[test]
 /// What: Verify Esc key closes `ConfirmRemove` modal.
 ///
@@ -273,3 +303,86 @@ fn confirm_aur_vote_modal_message_includes_dry_run_hint() {
     }
     assert!(app.pending_aur_vote_request.is_none());
 }
+
+#[test]
+/// What: Verify `p` in `AurHelperMissing` installs only the official targets via pacman.
+///
+/// Inputs:
+/// - `AurHelperMissing` modal with one official and one AUR package (dry-run).
+/// - `p` key event.
+///
+/// Output:
+/// - Transitions to `PreflightExec` with an install request containing only the official package.
+///
+/// Details:
+/// - Dry-run keeps the pacman database lock guardrail out of the way.
+fn aur_helper_missing_p_installs_official_only() {
+    let mut app = new_app();
+    app.dry_run = true;
+    let official = PackageItem::test_item("ripgrep", "extra");
+    let aur = PackageItem::test_item("yay-bin", "aur");
+    app.modal = crate::state::Modal::AurHelperMissing {
+        items: vec![official, aur],
+        header_chips: crate::state::modal::PreflightHeaderChips::default(),
+        password: None,
+    };
+
+    let (add_tx, _add_rx) = mpsc::unbounded_channel::<PackageItem>();
+    handle_modal_key(
+        key_event(KeyCode::Char('p'), KeyModifiers::empty()),
+        &mut app,
+        &add_tx,
+    );
+
+    assert!(matches!(
+        app.modal,
+        crate::state::Modal::PreflightExec { .. }
+    ));
+    match &app.pending_executor_request {
+        Some(crate::install::ExecutorRequest::Install { items, .. }) => {
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].name, "ripgrep");
+        }
+        other => panic!("expected install request, got {other:?}"),
+    }
+}
+
+#[test]
+/// What: Verify Esc closes `AurHelperMissing` without starting an install.
+///
+/// Inputs:
+/// - `AurHelperMissing` modal with an AUR-only set.
+/// - Esc key event, after an ignored `p` (no official targets).
+///
+/// Output:
+/// - `p` keeps the modal open; Esc closes it and no executor request is queued.
+///
+/// Details:
+/// - AUR-only sets offer no pacman-only path.
+fn aur_helper_missing_esc_cancels_and_p_ignored_for_aur_only() {
+    let mut app = new_app();
+    app.modal = crate::state::Modal::AurHelperMissing {
+        items: vec![PackageItem::test_item("yay-bin", "aur")],
+        header_chips: crate::state::modal::PreflightHeaderChips::default(),
+        password: None,
+    };
+    let (add_tx, _add_rx) = mpsc::unbounded_channel::<PackageItem>();
+
+    handle_modal_key(
+        key_event(KeyCode::Char('p'), KeyModifiers::empty()),
+        &mut app,
+        &add_tx,
+    );
+    assert!(matches!(
+        app.modal,
+        crate::state::Modal::AurHelperMissing { .. }
+    ));
+
+    handle_modal_key(
+        key_event(KeyCode::Esc, KeyModifiers::empty()),
+        &mut app,
+        &add_tx,
+    );
+    assert!(matches!(app.modal, crate::state::Modal::None));
+    assert!(app.pending_executor_request.is_none());
+}
@@ -125,3 +125,64 @@ fn post_summary_numpad_enter_newline_closes_modal() {
     handle_modal_key(ke, &mut app, &add_tx);
     assert!(matches!(app.modal, crate::state::Modal::None));
 }
+
+#[test]
+/// What: Verify closing `PostSummary` opens the `.pacnew` reminder and Esc dismisses it.
+///
+/// Inputs:
+/// - `PostSummary` modal with two queued conflict files from a mocked post-scan.
+/// - Enter on the summary, Down and Esc on the reminder.
+///
+/// Output:
+/// - `PacnewReminder` lists both paths, Down scrolls by one, Esc closes without reopening.
+///
+/// Details:
+/// - The reminder drains the queue so it is shown only once per transaction.
+fn post_summary_close_shows_pacnew_reminder() {
+    let mut app = new_app();
+    app.pending_pacnew_reminder = vec![
+        "/etc/pacman.conf.pacnew".to_string(),
+        "/etc/locale.gen.pacnew".to_string(),
+    ];
+    app.modal = crate::state::Modal::PostSummary {
+        success: true,
+        changed_files: 3,
+        pacnew_count: 2,
+        pacsave_count: 0,
+        services_pending: vec![],
+        snapshot_label: None,
+    };
+    let (add_tx, _add_rx) = mpsc::unbounded_channel::<PackageItem>();
+
+    handle_modal_key(
+        key_event(KeyCode::Enter, KeyModifiers::empty()),
+        &mut app,
+        &add_tx,
+    );
+    match &app.modal {
+        crate::state::Modal::PacnewReminder { paths, scroll } => {
+            assert_eq!(paths.len(), 2);
+            assert_eq!(paths[0], "/etc/pacman.conf.pacnew");
+            assert_eq!(*scroll, 0);
+        }
+        other => panic!("expected PacnewReminder, got {other:?}"),
+    }
+    assert!(app.pending_pacnew_reminder.is_empty());
+
+    handle_modal_key(
+        key_event(KeyCode::Down, KeyModifiers::empty()),
+        &mut app,
+        &add_tx,
+    );
+    assert!(matches!(
+        app.modal,
+        crate::state::Modal::PacnewReminder { scroll: 1, .. }
+    ));
+
+    handle_modal_key(
+        key_event(KeyCode::Esc, KeyModifiers::empty()),
+        &mut app,
+        &add_tx,
+    );
+    assert!(matches!(app.modal, crate::state::Modal::None));
+}
@@ -103,6 +103,51 @@ fn handle_pkgb_toggle_click(
             let _ = pkgb_tx.send(item);
         }
     }
+    crate::logic::viewers::note_viewer_toggled(app);
+    true
+}
+
+/// Handle description expand/collapse toggle click.
+///
+/// What: Flips between the collapsed and full view of an over-long package description.
+///
+/// Inputs:
+/// - `mx`: Mouse X coordinate (column)
+/// - `my`: Mouse Y coordinate (row)
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` if the click was handled, `false` otherwise.
+const fn handle_description_toggle_click(mx: u16, my: u16, app: &mut AppState) -> bool {
+    if !is_point_in_rect(mx, my, app.details_description_toggle_rect) {
+        return false;
+    }
+    app.mouse_disabled_in_details = false;
+    app.details_description_expanded = !app.details_description_expanded;
+    true
+}
+
+/// Handle the copy name / copy details button clicks.
+///
+/// What: Copies the focused package's name or details block to the clipboard.
+///
+/// Inputs:
+/// - `mx`: Mouse X coordinate (column)
+/// - `my`: Mouse Y coordinate (row)
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` if the click was handled, `false` otherwise.
+fn handle_copy_package_click(mx: u16, my: u16, app: &mut AppState) -> bool {
+    let with_details = if is_point_in_rect(mx, my, app.details_copy_name_rect) {
+        false
+    } else if is_point_in_rect(mx, my, app.details_copy_details_rect) {
+        true
+    } else {
+        return false;
+    };
+    app.mouse_disabled_in_details = false;
+    crate::logic::package_copy::copy_focused_package(app, with_details);
     true
 }
 
@@ -142,7 +187,9 @@ fn handle_comments_toggle_click(
     if app.comments_visible {
         // Close if already open
         app.comments_visible = false;
+        crate::logic::viewers::note_viewer_toggled(app);
         app.comments.clear();
+        app.comments_hidden.clear();
         app.comments_package_name = None;
         app.comments_fetched_at = None;
         app.comments_scroll = 0;
@@ -152,6 +199,7 @@ fn handle_comments_toggle_click(
     } else {
         // Open and (re)load
         app.comments_visible = true;
+        crate::logic::viewers::note_viewer_toggled(app);
         app.comments_scroll = 0;
         app.comments_error = None;
         if let Some(item) = app.results.get(app.selected) {
@@ -167,6 +215,7 @@ fn handle_comments_toggle_click(
             }
             // Request new comments
             app.comments.clear();
+            app.comments_hidden.clear();
             app.comments_package_name = None;
             app.comments_fetched_at = None;
             app.comments_loading = true;
@@ -304,6 +353,28 @@ fn handle_reload_pkgb_click(mx: u16, my: u16, app: &mut AppState) -> bool {
     true
 }
 
+/// Handle PKGBUILD diff toggle click.
+///
+/// What: Switches the PKGBUILD viewer between the plain text and the diff against the
+/// last-viewed version.
+///
+/// Inputs:
+/// - `mx`: Mouse X coordinate (column)
+/// - `my`: Mouse Y coordinate (row)
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` if the click was handled, `false` otherwise.
+const fn handle_pkgb_diff_click(mx: u16, my: u16, app: &mut AppState) -> bool {
+    if !app.pkgb_visible || !is_point_in_rect(mx, my, app.pkgb_diff_button_rect) {
+        return false;
+    }
+    app.mouse_disabled_in_details = false;
+    app.pkgb_diff_visible = !app.pkgb_diff_visible;
+    app.pkgb_scroll = 0;
+    true
+}
+
 /// Handle run PKGBUILD checks button click.
 fn handle_run_pkgb_checks_click(
     mx: u16,
@@ -359,31 +430,32 @@ fn handle_run_pkgb_checks_click(
 ///
 /// Output:
 /// - `true` if the scroll was handled, `false` otherwise.
-#[allow(clippy::missing_const_for_fn)]
+///
+/// Details:
+/// - Moves by `mouse_scroll_lines`; package details clamp at their last line, while the news
+///   body is left to its renderer since its length depends on the fetched article.
 fn handle_details_scroll(m: MouseEvent, mx: u16, my: u16, app: &mut AppState) -> bool {
-    if !is_point_in_rect(mx, my, app.details_rect) {
+    if !is_point_in_rect(mx, my, app.details_rect)
+        || !matches!(
+            m.kind,
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+        )
+    {
         return false;
     }
-
-    match m.kind {
-        MouseEventKind::ScrollUp => {
-            if matches!(app.app_mode, crate::state::types::AppMode::News) {
-                app.news_content_scroll = app.news_content_scroll.saturating_sub(1);
-            } else {
-                app.details_scroll = app.details_scroll.saturating_sub(1);
-            }
-            true
-        }
-        MouseEventKind::ScrollDown => {
-            if matches!(app.app_mode, crate::state::types::AppMode::News) {
-                app.news_content_scroll = app.news_content_scroll.saturating_add(1);
-            } else {
-                app.details_scroll = app.details_scroll.saturating_add(1);
-            }
-            true
+    if matches!(app.app_mode, crate::state::types::AppMode::News) {
+        if let Some(offset) =
+            super::wheel_scroll_offset(app.news_content_scroll, m.kind, usize::MAX)
+        {
+            app.news_content_scroll = offset;
         }
-        _ => false,
+        return true;
+    }
+    let max = crate::ui::details_max_scroll(app);
+    if let Some(offset) = super::wheel_scroll_offset(app.details_scroll, m.kind, max) {
+        app.details_scroll = offset;
     }
+    true
 }
 
 /// Handle text selection blocking in details pane.
@@ -417,6 +489,41 @@ fn handle_text_selection_block(mx: u16, my: u16, app: &mut AppState) -> bool {
     true
 }
 
+/// Handle "load more" click in comments.
+///
+/// What: Reveals the next batch of comments hidden beyond the display cap, or queues the next
+/// AUR comments page once all fetched comments are shown.
+///
+/// Inputs:
+/// - `mx`: Mouse X coordinate (column)
+/// - `my`: Mouse Y coordinate (row)
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` if the load-more line was clicked, `false` otherwise.
+fn handle_comments_load_more_click(mx: u16, my: u16, app: &mut AppState) -> bool {
+    if !is_point_in_rect(mx, my, app.comments_load_more_rect) {
+        return false;
+    }
+    if app.comments_hidden.is_empty() {
+        // Everything fetched is shown: queue the next AUR page for the event loop to request
+        if let Some(offset) = app.comments_next_offset
+            && !app.comments_page_loading
+        {
+            app.comments_page_loading = true;
+            app.comments_page_requested = Some(offset);
+        }
+    } else {
+        let batch = crate::sources::load_more_batch(
+            app.comments_hidden.len(),
+            crate::theme::settings().comments_max_display,
+        );
+        app.comments.extend(app.comments_hidden.drain(..batch));
+    }
+    app.comments_load_more_rect = None;
+    true
+}
+
 /// Handle URL click in comments.
 ///
 /// What: Opens a URL from comments when clicked.
@@ -527,8 +634,10 @@ fn handle_comment_date_click(mx: u16, my: u16, app: &AppState) -> bool {
 /// - Comment URL clicks: Left click on URLs in comments opens them in the default browser.
 /// - Comment author clicks: Left click on author names in comments opens their AUR profile page.
 /// - Comment date clicks: Left click on dates in comments opens the associated URL.
+/// - Comment load-more clicks: Left click on the "load more" line reveals the next hidden batch.
 /// - PKGBUILD toggle: Left click on toggle button opens/closes the PKGBUILD viewer and requests content.
 /// - Comments toggle: Left click on toggle button opens/closes the comments viewer and requests content (AUR only).
+/// - Description toggle: Left click expands/collapses an over-long package description.
 /// - Copy PKGBUILD: Left click on copy button copies PKGBUILD to clipboard (wl-copy/xclip).
 /// - Reload PKGBUILD: Left click on reload button schedules a debounced reload.
 /// - Scroll: Mouse wheel scrolls the details content when within the details rectangle.
@@ -588,6 +697,9 @@ pub(super) fn handle_details_mouse(
     if is_left_down && handle_comment_date_click(mx, my, app) {
         return Some(false);
     }
+    if is_left_down && handle_comments_load_more_click(mx, my, app) {
+        return Some(false);
+    }
 
     // Handle button clicks
     if is_left_down {
@@ -597,6 +709,12 @@ pub(super) fn handle_details_mouse(
         if handle_comments_toggle_click(mx, my, app, comments_tx) {
             return Some(false);
         }
+        if handle_description_toggle_click(mx, my, app) {
+            return Some(false);
+        }
+        if handle_copy_package_click(mx, my, app) {
+            return Some(false);
+        }
         if handle_copy_pkgb_click(mx, my, app) {
             return Some(false);
         }
@@ -606,6 +724,9 @@ pub(super) fn handle_details_mouse(
         if handle_run_pkgb_checks_click(mx, my, app, pkgb_check_tx) {
             return Some(false);
         }
+        if handle_pkgb_diff_click(mx, my, app) {
+            return Some(false);
+        }
     }
 
     // Handle scroll events (before click blocking)
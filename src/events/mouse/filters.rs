@@ -54,6 +54,7 @@ where
     if is_point_in_rect(mx, my, rect) {
         toggle_fn(app);
         crate::logic::apply_filters_and_sort_preserve_selection(app);
+        app.record_current_view();
         true
     } else {
         false
@@ -91,6 +92,7 @@ fn toggle_all_artix_filters(app: &mut AppState) {
     app.results_filter_show_artix_system = new_state;
     app.results_filter_show_artix = new_state;
     crate::logic::apply_filters_and_sort_preserve_selection(app);
+    app.record_current_view();
 }
 
 /// Check if Artix-specific filters are hidden (dropdown mode).
@@ -285,6 +287,7 @@ fn handle_artix_dropdown_click(mx: u16, my: u16, app: &mut AppState) -> bool {
     }
 
     update_main_artix_filter_state(app);
+    app.record_current_view();
     true
 }
 
@@ -492,6 +495,18 @@ fn handle_simple_filter_toggles(mx: u16, my: u16, app: &mut AppState) -> bool {
         app.results_filter_manjaro_rect,
         |a| a.results_filter_show_manjaro = !a.results_filter_show_manjaro,
         app,
+    ) || try_toggle_simple_filter(
+        mx,
+        my,
+        app.results_filter_outofdate_rect,
+        |a| a.results_filter_only_outofdate = !a.results_filter_only_outofdate,
+        app,
+    ) || try_toggle_simple_filter(
+        mx,
+        my,
+        app.results_filter_orphaned_rect,
+        |a| a.results_filter_only_orphaned = !a.results_filter_only_orphaned,
+        app,
     )
 }
 
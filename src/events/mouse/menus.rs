@@ -84,10 +84,18 @@ pub fn handle_updates_button(app: &mut AppState) -> bool {
 ///
 /// Details:
 /// - Switches app mode to `News` and selects first result when available.
+/// - When entering from another mode, applies `mode_switch_search_input` to the news input.
 /// - Preserves existing `pending_news` data (no modal conversion/opening).
 /// - Shows "No News available" toast when there are no visible news entries.
 pub fn handle_news_button(app: &mut AppState) -> bool {
+    let entering = !matches!(app.app_mode, crate::state::types::AppMode::News);
     app.app_mode = crate::state::types::AppMode::News;
+    if entering {
+        crate::events::utils::restore_mode_search_input(
+            app,
+            &crate::theme::settings().mode_switch_search_input,
+        );
+    }
     crate::theme::save_app_start_mode(true);
     app.refresh_news_results();
     if app.news_results.is_empty() {
@@ -328,9 +336,14 @@ fn handle_sort_menu_click(
                     app.sort_mode = crate::state::SortMode::BestMatches;
                     crate::theme::save_sort_mode(app.sort_mode);
                 }
+                3 if app.installed_only_mode => {
+                    app.sort_mode = crate::state::SortMode::InstalledSize;
+                    crate::theme::save_sort_mode(app.sort_mode);
+                }
                 _ => return None,
             }
             crate::logic::sort_results_preserve_selection(app);
+            app.record_current_view();
             if app.results.is_empty() {
                 app.list_state.select(None);
             } else {
@@ -349,7 +362,7 @@ fn handle_sort_menu_click(
 
 /// Handle click inside options menu.
 ///
-/// What: Handles clicks on options menu items (installed-only, update, optional deps, repositories, mode toggle).
+/// What: Handles clicks on options menu items (installed-only, update, optional deps, repositories, mode toggle, activity log).
 ///
 /// Inputs:
 /// - `mx`: Mouse X coordinate
@@ -384,6 +397,10 @@ fn handle_options_menu_click(
                 2 => handle_repositories_option(app),
                 3 => {
                     app.app_mode = crate::state::types::AppMode::Package;
+                    crate::events::utils::restore_mode_search_input(
+                        app,
+                        &crate::theme::settings().mode_switch_search_input,
+                    );
                     if app.results.is_empty() {
                         app.list_state.select(None);
                     } else {
@@ -402,6 +419,7 @@ fn handle_options_menu_click(
                 2 => handle_optional_deps_option(app),
                 3 => handle_repositories_option(app),
                 4 => handle_mode_toggle(app, details_tx),
+                5 => crate::events::modals::open_activity_log_modal(app),
                 _ => return None,
             }
         }
@@ -467,7 +485,10 @@ fn handle_installed_only_toggle(
         }
         app.all_results = items;
         app.installed_only_mode = true;
-        app.right_pane_focus = crate::state::RightPaneFocus::Remove;
+        crate::events::utils::focus_installed_only_subpane(
+            app,
+            &crate::theme::settings().installed_only_default_focus,
+        );
         crate::logic::apply_filters_and_sort_preserve_selection(app);
         crate::events::utils::refresh_selected_details(app, details_tx);
 
@@ -484,12 +505,17 @@ fn handle_installed_only_toggle(
 /// Inputs:
 /// - `app`: Mutable application state
 /// - `details_tx`: Channel to request package details when switching back to package mode
+///
+/// Details:
+/// - The entered mode's search input is preserved or cleared per `mode_switch_search_input`.
 pub(in crate::events) fn handle_mode_toggle(
     app: &mut AppState,
     details_tx: &mpsc::UnboundedSender<PackageItem>,
 ) {
+    let input_pref = crate::theme::settings().mode_switch_search_input;
     if matches!(app.app_mode, crate::state::types::AppMode::News) {
         app.app_mode = crate::state::types::AppMode::Package;
+        crate::events::utils::restore_mode_search_input(app, &input_pref);
         if app.results.is_empty() {
             app.list_state.select(None);
         } else {
@@ -499,6 +525,7 @@ pub(in crate::events) fn handle_mode_toggle(
         }
     } else {
         app.app_mode = crate::state::types::AppMode::News;
+        crate::events::utils::restore_mode_search_input(app, &input_pref);
         if app.news_results.is_empty() {
             app.news_list_state.select(None);
             app.news_selected = 0;
@@ -1163,6 +1190,127 @@ mod tests {
         assert!(app.custom_repos_filter_menu_open);
     }
 
+    /// What: Run `f` with `HOME` pointing at a temp config that sets `mode_switch_search_input`.
+    ///
+    /// Inputs:
+    /// - `value`: Setting value written to the temp `settings.conf`.
+    /// - `f`: Test body executed while the override is active.
+    ///
+    /// Output:
+    /// - None; restores `HOME` afterwards.
+    ///
+    /// Details:
+    /// - Holds `theme::test_mutex` so concurrent tests do not observe the override.
+    fn with_mode_switch_setting(value: &str, f: impl FnOnce()) {
+        let _theme_guard = crate::theme::test_mutex()
+            .lock()
+            .expect("theme test mutex poisoned");
+        let orig_home = std::env::var_os("HOME");
+        let base = std::env::temp_dir().join(format!(
+            "pacsea_test_mode_switch_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("System time is before UNIX epoch")
+                .as_nanos()
+        ));
+        let cfg = base.join(".config").join("pacsea");
+        std::fs::create_dir_all(&cfg).expect("create test config dir");
+        unsafe {
+            std::env::set_var("HOME", base.display().to_string());
+        }
+        std::fs::write(
+            cfg.join("settings.conf"),
+            format!("mode_switch_search_input = {value}\n"),
+        )
+        .expect("write settings");
+
+        f();
+
+        unsafe {
+            if let Some(v) = orig_home {
+                std::env::set_var("HOME", v);
+            } else {
+                std::env::remove_var("HOME");
+            }
+        }
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    /// What: Verify switching to News and back restores each mode's last input under `preserve`.
+    ///
+    /// Inputs:
+    /// - Package input `ripgrep` (caret mid-word), then a News query typed after the first toggle.
+    ///
+    /// Output:
+    /// - Returning to Package restores `ripgrep` with its caret; returning to News restores `kernel`.
+    ///
+    /// Details:
+    /// - No re-query is scheduled because nothing was cleared.
+    fn handle_mode_toggle_preserves_each_mode_input() {
+        with_mode_switch_setting("preserve", || {
+            let (details_tx, _details_rx) = mpsc::unbounded_channel();
+            let mut app = crate::state::AppState {
+                input: "ripgrep".to_string(),
+                search_caret: 3,
+                search_select_anchor: Some(0),
+                ..crate::state::AppState::default()
+            };
+
+            handle_mode_toggle(&mut app, &details_tx);
+            assert!(matches!(app.app_mode, AppMode::News));
+            assert!(app.news_search_input.is_empty());
+            app.news_search_input = "kernel".to_string();
+            app.news_search_caret = 6;
+
+            handle_mode_toggle(&mut app, &details_tx);
+            assert!(matches!(app.app_mode, AppMode::Package));
+            assert_eq!(app.input, "ripgrep");
+            assert_eq!(app.search_caret, 3);
+            assert_eq!(app.search_select_anchor, None);
+            assert!(!app.search_requery_pending);
+
+            handle_mode_toggle(&mut app, &details_tx);
+            assert!(matches!(app.app_mode, AppMode::News));
+            assert_eq!(app.news_search_input, "kernel");
+            assert_eq!(app.news_search_caret, 6);
+        });
+    }
+
+    #[test]
+    /// What: Verify the `clear` setting empties the entered mode's input.
+    ///
+    /// Inputs:
+    /// - Package input and News input both set before toggling twice.
+    ///
+    /// Output:
+    /// - Entering News clears the news input; entering Package clears `input`, resets the caret,
+    ///   and schedules a re-query so results match the empty input.
+    fn handle_mode_toggle_clear_setting_empties_entered_input() {
+        with_mode_switch_setting("clear", || {
+            let (details_tx, _details_rx) = mpsc::unbounded_channel();
+            let mut app = crate::state::AppState {
+                input: "ripgrep".to_string(),
+                search_caret: 7,
+                news_search_input: "kernel".to_string(),
+                news_search_caret: 6,
+                ..crate::state::AppState::default()
+            };
+
+            handle_mode_toggle(&mut app, &details_tx);
+            assert!(matches!(app.app_mode, AppMode::News));
+            assert!(app.news_search_input.is_empty());
+            assert_eq!(app.news_search_caret, 0);
+
+            handle_mode_toggle(&mut app, &details_tx);
+            assert!(matches!(app.app_mode, AppMode::Package));
+            assert!(app.input.is_empty());
+            assert_eq!(app.search_caret, 0);
+            assert!(app.search_requery_pending);
+        });
+    }
+
     #[test]
     fn handle_mode_toggle_switches_from_config_editor_to_news() {
         let (details_tx, _details_rx) = mpsc::unbounded_channel();
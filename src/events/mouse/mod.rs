@@ -6,6 +6,7 @@
 //! - `menus`: Menu interactions (sort, options, config, panels, import/export)
 //! - `filters`: Filter toggle interactions
 //! - `panes`: Pane interactions (Results, Recent, Install/Remove/Downgrade, PKGBUILD viewer)
+//! - `resize`: Dragging the middle-row column borders
 
 use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use tokio::sync::mpsc;
@@ -18,6 +19,7 @@ pub mod menu_options;
 pub mod menus;
 mod modals;
 mod panes;
+mod resize;
 
 #[cfg(test)]
 mod tests;
@@ -53,6 +55,8 @@ mod tests;
 /// - Title bar: Sort/options/panels/config buttons toggle menus; filter toggles apply filters.
 /// - Results: Click selects; scroll wheel moves selection and triggers details fetch.
 /// - Recent/Install/Remove/Downgrade panes: Scroll moves selection; click focuses/sets selection.
+/// - Column borders: Left-drag between Recent/Search/Install resizes the columns and persists
+///   `layout_*_pct` on release.
 /// - Import/Export buttons: Import opens a system file picker to enqueue names; Export writes the
 ///   current Install list to a timestamped file and shows a toast.
 pub fn handle_mouse_event_with_pkgbuild_checks(
@@ -106,6 +110,11 @@ pub fn handle_mouse_event_with_pkgbuild_checks(
         return false;
     }
 
+    // Column border drags (press on a border, drag, release)
+    if let Some(handled) = resize::handle_column_resize_mouse(m, mx, my, app) {
+        return handled;
+    }
+
     // Details pane interactions (URL, PKGBUILD buttons, scroll)
     if let Some(handled) = details::handle_details_mouse(
         m,
@@ -150,6 +159,17 @@ pub fn handle_mouse_event_with_pkgbuild_checks(
         return false;
     }
 
+    // "Did you mean" suggestion below the results: re-run the search with it
+    if is_left_down
+        && let Some(idx) = app
+            .search_suggestion_rects
+            .iter()
+            .position(|&(x, y, w, h)| mx >= x && mx < x + w && my >= y && my < y + h)
+    {
+        crate::logic::apply_search_suggestion(app, idx, query_tx);
+        return false;
+    }
+
     // Pane interactions (Results, Recent, Install/Remove/Downgrade, PKGBUILD viewer)
     if let Some(handled) = panes::handle_panes_mouse(
         m,
@@ -169,3 +189,26 @@ pub fn handle_mouse_event_with_pkgbuild_checks(
 
 // Re-export for use in keyboard handlers
 pub use menus::{handle_news_button, handle_updates_button};
+
+/// What: Apply one mouse-wheel notch to a text pane's scroll offset.
+///
+/// Inputs:
+/// - `offset`: Current scroll offset (first visible line)
+/// - `kind`: Mouse event kind
+/// - `max`: Largest valid offset for the pane content
+///
+/// Output:
+/// - `Some(new_offset)` for `ScrollUp`/`ScrollDown`, `None` for other events.
+///
+/// Details:
+/// - Moves by the `mouse_scroll_lines` setting and clamps to `0..=max`; an offset already
+///   beyond `max` (e.g. a jump sentinel) is pulled back into range.
+fn wheel_scroll_offset(offset: u16, kind: MouseEventKind, max: usize) -> Option<u16> {
+    let step = crate::theme::settings().mouse_scroll_lines.max(1);
+    let max = u16::try_from(max).unwrap_or(u16::MAX);
+    match kind {
+        MouseEventKind::ScrollUp => Some(offset.min(max).saturating_sub(step)),
+        MouseEventKind::ScrollDown => Some(offset.saturating_add(step).min(max)),
+        _ => None,
+    }
+}
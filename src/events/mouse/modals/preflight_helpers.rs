@@ -20,6 +20,7 @@ use std::collections::{HashMap, HashSet};
 /// - Only includes dependencies when their parent package is expanded.
 /// - Always includes all packages, even if they have no dependencies.
 /// - Deduplicates dependencies by name within each package group.
+/// - Optional dependencies follow hard ones, preceded by an "Optional" group row.
 pub(super) fn build_deps_display_items(
     items: &[PackageItem],
     dependency_info: &[crate::state::modal::DependencyInfo],
@@ -41,11 +42,16 @@ pub(super) fn build_deps_display_items(
         if dep_tree_expanded.contains(pkg_name) {
             let mut seen_deps = HashSet::new();
             if let Some(pkg_deps) = grouped.get(pkg_name) {
+                let mut has_optional = false;
                 for dep in pkg_deps {
                     if seen_deps.insert(dep.name.as_str()) {
                         display_items.push((false, String::new()));
+                        has_optional |= dep.optional;
                     }
                 }
+                if has_optional {
+                    display_items.push((false, String::new()));
+                }
             }
         }
     }
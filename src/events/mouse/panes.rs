@@ -204,9 +204,8 @@ fn handle_install_click(
     } else {
         app.right_pane_focus = crate::state::RightPaneFocus::Install;
         let inds = crate::ui::helpers::filtered_install_indices(app);
-        if !inds.is_empty() {
-            let max = inds.len().saturating_sub(1);
-            let vis_idx = std::cmp::min(row, max);
+        let rows = crate::ui::helpers::install_pane_rows(app, &inds);
+        if let Some(vis_idx) = crate::ui::helpers::install_row_selection(&rows, row) {
             app.install_state.select(Some(vis_idx));
             crate::events::utils::refresh_install_details(app, details_tx);
         }
@@ -401,24 +400,21 @@ fn handle_downgrade_scroll(
 /// - `true` if event was handled, `false` otherwise
 ///
 /// Details:
-/// - Scroll wheel scrolls the PKGBUILD content.
-#[allow(clippy::missing_const_for_fn)]
+/// - Scroll wheel scrolls the PKGBUILD content by `mouse_scroll_lines`, clamped to its last line.
 fn handle_pkgbuild_scroll(m: MouseEvent, mx: u16, my: u16, app: &mut AppState) -> bool {
-    if !is_in_rect(mx, my, app.pkgb_rect) {
+    if !is_in_rect(mx, my, app.pkgb_rect)
+        || !matches!(
+            m.kind,
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+        )
+    {
         return false;
     }
-
-    match m.kind {
-        MouseEventKind::ScrollUp => {
-            app.pkgb_scroll = app.pkgb_scroll.saturating_sub(1);
-            true
-        }
-        MouseEventKind::ScrollDown => {
-            app.pkgb_scroll = app.pkgb_scroll.saturating_add(1);
-            true
-        }
-        _ => false,
+    let max = crate::ui::pkgbuild_max_scroll(app);
+    if let Some(offset) = super::wheel_scroll_offset(app.pkgb_scroll, m.kind, max) {
+        app.pkgb_scroll = offset;
     }
+    true
 }
 
 /// What: Handle comments viewer scroll interactions.
@@ -433,24 +429,18 @@ fn handle_pkgbuild_scroll(m: MouseEvent, mx: u16, my: u16, app: &mut AppState) -
 /// - `true` if event was handled, `false` otherwise
 ///
 /// Details:
-/// - Scroll wheel scrolls the comments content.
-#[allow(clippy::missing_const_for_fn)]
+/// - Scroll wheel scrolls the comments by `mouse_scroll_lines` comments, clamped so the last
+///   comment stays visible.
 fn handle_comments_scroll(m: MouseEvent, mx: u16, my: u16, app: &mut AppState) -> bool {
     if !is_in_rect(mx, my, app.comments_rect) {
         return false;
     }
-
-    match m.kind {
-        MouseEventKind::ScrollUp => {
-            app.comments_scroll = app.comments_scroll.saturating_sub(1);
-            true
-        }
-        MouseEventKind::ScrollDown => {
-            app.comments_scroll = app.comments_scroll.saturating_add(1);
-            true
-        }
-        _ => false,
-    }
+    let max = app.comments.len().saturating_sub(1);
+    let Some(offset) = super::wheel_scroll_offset(app.comments_scroll, m.kind, max) else {
+        return false;
+    };
+    app.comments_scroll = offset;
+    true
 }
 
 /// Handle mouse events for panes (Results, Recent, Install/Remove/Downgrade, PKGBUILD viewer).
@@ -475,7 +465,8 @@ fn handle_comments_scroll(m: MouseEvent, mx: u16, my: u16, app: &mut AppState) -
 /// - Recent pane: Scroll wheel moves selection and triggers preview fetch.
 /// - Install/Remove panes: Left click focuses pane and selects item; scroll wheel moves selection.
 /// - Downgrade pane: Left click focuses pane and selects item; scroll wheel moves selection.
-/// - PKGBUILD viewer: Scroll wheel scrolls the PKGBUILD content.
+/// - PKGBUILD and comments viewers: Scroll wheel scrolls the content (see
+///   [`super::wheel_scroll_offset`]).
 #[allow(clippy::too_many_arguments)]
 pub(super) fn handle_panes_mouse(
     m: MouseEvent,
@@ -0,0 +1,139 @@
+//! Drag-to-resize for the column borders of the middle row (Recent | Search | Install).
+//!
+//! Borders are located from the recorded inner pane rects (each pane has a one-cell border), and
+//! the pointer column is converted to a percentage of `middle_row_rect`.
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+use crate::state::AppState;
+use crate::state::types::ColumnBorder;
+
+/// Smallest width, in percent, any column can be dragged to.
+const MIN_COLUMN_PCT: u16 = 10;
+
+/// What: Find the column border under the pointer.
+///
+/// Inputs:
+/// - `mx`/`my`: Pointer position
+/// - `app`: Application state with the rects recorded by the last render
+///
+/// Output:
+/// - The border whose outline (either adjacent border cell) is under the pointer, or `None`.
+///
+/// Details:
+/// - Hidden panes have no rect, so their border cannot be dragged.
+/// - In installed-only mode the right column starts at the Downgrade subpane.
+fn border_at(mx: u16, my: u16, app: &AppState) -> Option<ColumnBorder> {
+    let (_, row_y, _, row_h) = app.middle_row_rect?;
+    if my < row_y || my >= row_y.saturating_add(row_h) {
+        return None;
+    }
+    if let Some((x, _, w, _)) = app.recent_rect {
+        // Recent's right border cell, then Search's left border cell
+        let edge = x.saturating_add(w);
+        if mx == edge || mx == edge.saturating_add(1) {
+            return Some(ColumnBorder::LeftCenter);
+        }
+    }
+    let right_inner = if app.installed_only_mode {
+        app.install_rect.and(app.downgrade_rect)
+    } else {
+        app.install_rect
+    };
+    if let Some((x, _, _, _)) = right_inner {
+        // Right column's left border cell, then Search's right border cell
+        let edge = x.saturating_sub(1);
+        if mx == edge || mx == edge.saturating_sub(1) {
+            return Some(ColumnBorder::CenterRight);
+        }
+    }
+    None
+}
+
+/// What: Move a column border to the pointer column.
+///
+/// Inputs:
+/// - `app`: Mutable application state (`layout_*_pct`, `middle_row_rect`)
+/// - `border`: Border being dragged
+/// - `mx`: Pointer column
+///
+/// Output:
+/// - Updates the three layout percentages so they sum to 100.
+///
+/// Details:
+/// - Every column keeps at least [`MIN_COLUMN_PCT`]; the column on the far side of the border
+///   keeps its width and the center column absorbs the change.
+/// - In installed-only mode the right column is drawn 1.5x wider, so the stored percentage is
+///   scaled back by 2/3.
+fn apply_column_drag(app: &mut AppState, border: ColumnBorder, mx: u16) {
+    let Some((row_x, _, row_w, _)) = app.middle_row_rect else {
+        return;
+    };
+    if row_w == 0 {
+        return;
+    }
+    let offset = u32::from(mx.saturating_sub(row_x).min(row_w));
+    let width = u32::from(row_w);
+    let pct = u16::try_from((offset * 100 + width / 2) / width).unwrap_or(100);
+    let max_fixed = 100 - 2 * MIN_COLUMN_PCT;
+    match border {
+        ColumnBorder::LeftCenter => {
+            let right = app.layout_right_pct.min(max_fixed);
+            app.layout_left_pct = pct.clamp(MIN_COLUMN_PCT, 100 - right - MIN_COLUMN_PCT);
+            app.layout_right_pct = right;
+        }
+        ColumnBorder::CenterRight => {
+            let left = app.layout_left_pct.min(max_fixed);
+            let mut right = 100 - pct;
+            if app.installed_only_mode {
+                right = right * 2 / 3;
+            }
+            app.layout_left_pct = left;
+            app.layout_right_pct = right.clamp(MIN_COLUMN_PCT, 100 - left - MIN_COLUMN_PCT);
+        }
+    }
+    app.layout_center_pct = 100 - app.layout_left_pct - app.layout_right_pct;
+}
+
+/// What: Handle pressing, dragging, and releasing a middle-row column border.
+///
+/// Inputs:
+/// - `m`: Mouse event
+/// - `mx`/`my`: Pointer position
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `Some(false)` when the event belongs to a border drag, `None` otherwise.
+///
+/// Details:
+/// - Left press on a border starts the drag; drag events resize live; release persists the
+///   percentages to `settings.conf` and ends the drag.
+pub(super) fn handle_column_resize_mouse(
+    m: MouseEvent,
+    mx: u16,
+    my: u16,
+    app: &mut AppState,
+) -> Option<bool> {
+    match (m.kind, app.column_drag) {
+        (MouseEventKind::Down(MouseButton::Left), _) => {
+            // A release swallowed elsewhere (e.g. by a modal) must not leave a drag behind
+            app.column_drag = border_at(mx, my, app);
+            app.column_drag.map(|_| false)
+        }
+        (MouseEventKind::Drag(MouseButton::Left), Some(border)) => {
+            apply_column_drag(app, border, mx);
+            Some(false)
+        }
+        (MouseEventKind::Up(MouseButton::Left), Some(border)) => {
+            apply_column_drag(app, border, mx);
+            app.column_drag = None;
+            crate::theme::save_layout_pcts(
+                app.layout_left_pct,
+                app.layout_center_pct,
+                app.layout_right_pct,
+            );
+            Some(false)
+        }
+        _ => None,
+    }
+}
@@ -354,3 +354,202 @@ fn click_artix_filter_toggles_dropdown() {
     );
     assert!(app.artix_filter_menu_open);
 }
+
+/// What: Send one mouse event at a position through the full mouse handler.
+///
+/// Inputs:
+/// - `app`: State under test
+/// - `kind`: Event kind (wheel, press, drag, release)
+/// - `column`/`row`: Pointer position
+fn send_mouse(app: &mut AppState, kind: MouseEventKind, column: u16, row: u16) {
+    let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+    let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+    let (atx, _arx) = mpsc::unbounded_channel::<PackageItem>();
+    let (pkgb_tx, _pkgb_rx) = mpsc::unbounded_channel::<PackageItem>();
+    let (comments_tx, _comments_rx) = mpsc::unbounded_channel::<String>();
+    let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+    let (pkgb_check_tx, _pkgb_check_rx) = mpsc::unbounded_channel::<PkgbuildCheckRequest>();
+    let ev = MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::empty(),
+    };
+    let _ = handle_mouse_event_with_pkgbuild_checks(
+        ev,
+        app,
+        &dtx,
+        &ptx,
+        &atx,
+        &pkgb_tx,
+        &comments_tx,
+        &qtx,
+        &pkgb_check_tx,
+    );
+}
+
+#[test]
+/// What: Wheel events over the details, PKGBUILD, and comments rects scroll and clamp.
+///
+/// Inputs:
+/// - Non-overlapping rects for the three panes, a 10-line PKGBUILD, and 4 comments.
+/// - One `ScrollDown`, then many `ScrollDown`s, then many `ScrollUp`s over each pane.
+///
+/// Output:
+/// - The first notch moves by `mouse_scroll_lines` (bounded by the content); repeated notches
+///   stop at the last line/comment and at `0`; other panes' offsets stay untouched.
+fn wheel_scrolls_text_panes_and_clamps() {
+    let mut app = new_app();
+    let step = crate::theme::settings().mouse_scroll_lines.max(1);
+    app.details_rect = Some((0, 0, 30, 4));
+    app.pkgb_rect = Some((40, 0, 30, 4));
+    app.comments_rect = Some((80, 0, 30, 4));
+    app.pkgb_visible = true;
+    app.pkgb_text = Some(
+        (1..=10)
+            .map(|i| format!("line{i}=1"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    app.comments = (0..4)
+        .map(|i| crate::state::types::AurComment {
+            id: None,
+            author: format!("user{i}"),
+            date: "2025-01-01".into(),
+            date_timestamp: None,
+            date_url: None,
+            content: "text".into(),
+            pinned: false,
+        })
+        .collect();
+
+    let details_max = u16::try_from(crate::ui::details_max_scroll(&app)).expect("fits u16");
+    let pkgb_max = u16::try_from(crate::ui::pkgbuild_max_scroll(&app)).expect("fits u16");
+    assert!(details_max > 0, "default details should span several lines");
+    assert_eq!(pkgb_max, 9);
+
+    send_mouse(&mut app, MouseEventKind::ScrollDown, 1, 1);
+    assert_eq!(app.details_scroll, step.min(details_max));
+    send_mouse(&mut app, MouseEventKind::ScrollDown, 41, 1);
+    assert_eq!(app.pkgb_scroll, step.min(pkgb_max));
+    send_mouse(&mut app, MouseEventKind::ScrollDown, 81, 1);
+    assert_eq!(app.comments_scroll, step.min(3));
+
+    for _ in 0..50 {
+        send_mouse(&mut app, MouseEventKind::ScrollDown, 1, 1);
+        send_mouse(&mut app, MouseEventKind::ScrollDown, 41, 1);
+        send_mouse(&mut app, MouseEventKind::ScrollDown, 81, 1);
+    }
+    assert_eq!(app.details_scroll, details_max);
+    assert_eq!(app.pkgb_scroll, pkgb_max);
+    assert_eq!(app.comments_scroll, 3);
+
+    for _ in 0..50 {
+        send_mouse(&mut app, MouseEventKind::ScrollUp, 41, 1);
+    }
+    assert_eq!(app.pkgb_scroll, 0);
+    assert_eq!(app.details_scroll, details_max);
+    assert_eq!(app.comments_scroll, 3);
+}
+
+#[test]
+/// What: Dragging the middle-row column borders resizes the columns with clamping and persists.
+///
+/// Inputs:
+/// - 100-column middle row split 20/60/20; Recent and Install inner rects as rendered.
+/// - Drags of the Recent|Search border to 35, 2, and 95, release, then the Search|Install border
+///   to 50 and 15.
+///
+/// Output:
+/// - Percentages follow the pointer, each column keeps at least 10%, all three sum to 100, and
+///   release writes them to `settings.conf`; a press away from borders starts no drag.
+fn drag_column_borders_resizes_and_clamps() {
+    let _guard = crate::theme::test_mutex()
+        .lock()
+        .expect("Test mutex poisoned");
+    let orig_home = std::env::var_os("HOME");
+    let orig_xdg = std::env::var_os("XDG_CONFIG_HOME");
+    let base = std::env::temp_dir().join(format!(
+        "pacsea_test_column_drag_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    let cfg = base.join(".config").join("pacsea");
+    std::fs::create_dir_all(&cfg).expect("create test config dir");
+    unsafe { std::env::set_var("HOME", base.display().to_string()) };
+    unsafe { std::env::remove_var("XDG_CONFIG_HOME") };
+
+    let mut app = new_app();
+    app.layout_left_pct = 20;
+    app.layout_center_pct = 60;
+    app.layout_right_pct = 20;
+    app.middle_row_rect = Some((0, 10, 100, 8));
+    app.recent_rect = Some((1, 11, 18, 6));
+    app.install_rect = Some((81, 11, 18, 6));
+    let pcts = |app: &AppState| {
+        (
+            app.layout_left_pct,
+            app.layout_center_pct,
+            app.layout_right_pct,
+        )
+    };
+
+    send_mouse(&mut app, MouseEventKind::Down(MouseButton::Left), 50, 12);
+    assert_eq!(app.column_drag, None);
+
+    send_mouse(&mut app, MouseEventKind::Down(MouseButton::Left), 19, 12);
+    assert_eq!(
+        app.column_drag,
+        Some(crate::state::types::ColumnBorder::LeftCenter)
+    );
+    send_mouse(&mut app, MouseEventKind::Drag(MouseButton::Left), 35, 12);
+    assert_eq!(pcts(&app), (35, 45, 20));
+    send_mouse(&mut app, MouseEventKind::Drag(MouseButton::Left), 2, 12);
+    assert_eq!(pcts(&app), (10, 70, 20));
+    send_mouse(&mut app, MouseEventKind::Drag(MouseButton::Left), 95, 12);
+    assert_eq!(pcts(&app), (70, 10, 20));
+    send_mouse(&mut app, MouseEventKind::Up(MouseButton::Left), 30, 12);
+    assert_eq!(app.column_drag, None);
+    assert_eq!(pcts(&app), (30, 50, 20));
+
+    let saved = std::fs::read_to_string(cfg.join("settings.conf")).expect("settings written");
+    let value = |key: &str| {
+        saved
+            .lines()
+            .find_map(|l| {
+                let (k, v) = l.split_once('=')?;
+                (k.trim() == key).then(|| v.trim().to_string())
+            })
+            .unwrap_or_default()
+    };
+    assert_eq!(value("layout_left_pct"), "30");
+    assert_eq!(value("layout_center_pct"), "50");
+    assert_eq!(value("layout_right_pct"), "20");
+
+    send_mouse(&mut app, MouseEventKind::Down(MouseButton::Left), 80, 12);
+    assert_eq!(
+        app.column_drag,
+        Some(crate::state::types::ColumnBorder::CenterRight)
+    );
+    send_mouse(&mut app, MouseEventKind::Drag(MouseButton::Left), 50, 12);
+    assert_eq!(pcts(&app), (30, 20, 50));
+    send_mouse(&mut app, MouseEventKind::Drag(MouseButton::Left), 15, 12);
+    assert_eq!(pcts(&app), (30, 10, 60));
+
+    unsafe {
+        if let Some(v) = orig_home {
+            std::env::set_var("HOME", v);
+        } else {
+            std::env::remove_var("HOME");
+        }
+        if let Some(v) = orig_xdg {
+            std::env::set_var("XDG_CONFIG_HOME", v);
+        } else {
+            std::env::remove_var("XDG_CONFIG_HOME");
+        }
+    }
+    let _ = std::fs::remove_dir_all(&base);
+}
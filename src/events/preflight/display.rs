@@ -18,6 +18,7 @@ use crate::state::PackageItem;
 /// - Mirrors the UI logic to keep keyboard navigation in sync with rendered rows.
 /// - Counts one header per package that has dependencies; only counts individual dependencies when
 ///   that package appears in `dep_tree_expanded` and deduplicates by dependency name.
+/// - Adds one "Optional" group row per expanded package that has optional dependencies.
 pub(super) fn compute_display_items_len(
     items: &[PackageItem],
     dependency_info: &[crate::state::modal::DependencyInfo],
@@ -43,11 +44,16 @@ pub(super) fn compute_display_items_len(
             && let Some(pkg_deps) = grouped.get(pkg_name)
         {
             let mut seen_deps = HashSet::new();
+            let mut has_optional = false;
             for dep in pkg_deps {
                 if seen_deps.insert(dep.name.as_str()) {
                     count += 1;
+                    has_optional |= dep.optional;
                 }
             }
+            if has_optional {
+                count += 1;
+            }
         }
     }
 
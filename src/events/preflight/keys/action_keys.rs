@@ -194,6 +194,38 @@ pub fn start_execution(
         return;
     }
 
+    // Guardrail: the AUR helper may have disappeared since startup; guide instead of failing opaquely
+    if matches!(action, crate::state::PreflightAction::Install)
+        && crate::events::install::try_open_aur_helper_missing_modal(
+            app,
+            items,
+            &header_chips,
+            password.as_ref(),
+            crate::events::install::aur_helper_available,
+        )
+    {
+        return;
+    }
+
+    // An explicitly chosen helper never silently falls back to the other one
+    let aur_helper = crate::install::selected_aur_helper(
+        app.install_aur_helper.as_deref(),
+        &crate::theme::settings().aur_helper,
+    );
+    if matches!(action, crate::state::PreflightAction::Install)
+        && crate::events::install::try_reject_unavailable_aur_helper(
+            app,
+            items,
+            aur_helper,
+            crate::util::command::binary_available,
+        )
+    {
+        if !matches!(app.modal, crate::state::Modal::Preflight { .. }) {
+            app.modal = crate::state::Modal::None;
+        }
+        return;
+    }
+
     tracing::debug!(
         action = ?action,
         item_count = items.len(),
@@ -220,6 +252,7 @@ pub fn start_execution(
             items: items.to_vec(),
             password,
             dry_run: app.dry_run,
+            aur_helper: aur_helper.map(str::to_string),
         },
         crate::state::PreflightAction::Remove => {
             let names: Vec<String> = items.iter().map(|p| p.name.clone()).collect();
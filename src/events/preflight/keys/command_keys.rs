@@ -179,6 +179,38 @@ pub(super) fn handle_m_key(app: &mut AppState) -> bool {
     false
 }
 
+/// What: Handle h key - cycle the AUR helper used for this install.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - Always returns `false`.
+///
+/// Details:
+/// - Only active for Install; cycles settings default -> `paru` -> `yay`.
+pub(super) fn handle_h_key(app: &mut AppState) -> bool {
+    if !matches!(
+        app.modal,
+        crate::state::Modal::Preflight {
+            action: crate::state::PreflightAction::Install,
+            ..
+        }
+    ) {
+        return false;
+    }
+    let message = crate::events::install::cycle_install_aur_helper(app).map_or_else(
+        || {
+            let setting = crate::theme::settings().aur_helper;
+            crate::i18n::t_fmt1(app, "app.toasts.aur_helper_follow_settings", setting)
+        },
+        |helper| crate::i18n::t_fmt1(app, "app.toasts.aur_helper_selected", helper),
+    );
+    app.toast_message = Some(message);
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(4));
+    false
+}
+
 /// What: Extract install targets from preflight modal.
 ///
 /// Inputs:
@@ -231,6 +263,19 @@ pub(super) fn handle_proceed_install(
     packages: Vec<PackageItem>,
     header_chips: crate::state::modal::PreflightHeaderChips,
 ) -> bool {
+    // An explicitly chosen AUR helper must exist; keep the modal open so another can be picked
+    let aur_helper = crate::install::selected_aur_helper(
+        app.install_aur_helper.as_deref(),
+        &crate::theme::settings().aur_helper,
+    );
+    if crate::events::install::try_reject_unavailable_aur_helper(
+        app,
+        &packages,
+        aur_helper,
+        crate::util::command::binary_available,
+    ) {
+        return false;
+    }
     if crate::events::install::try_open_warn_aur_repo_duplicate_modal(
         app,
         &packages,
@@ -242,51 +287,13 @@ pub(super) fn handle_proceed_install(
     // This check happens BEFORE password prompt
     // BUT exclude packages that have updates available (those should go through normal update flow)
     let installed_set = crate::logic::deps::get_installed_packages();
-    let provided_set = crate::logic::deps::get_provided_packages(&installed_set);
     let upgradable_set = crate::logic::deps::get_upgradable_packages();
 
-    let installed_packages: Vec<crate::state::PackageItem> = packages
-        .iter()
-        .filter(|item| {
-            // Check if package is installed or provided by an installed package
-            let is_installed = crate::logic::deps::is_package_installed_or_provided(
-                &item.name,
-                &installed_set,
-                &provided_set,
-            );
-
-            if !is_installed {
-                return false;
-            }
-
-            // Check if package has an update available
-            // For official packages: check if it's in upgradable_set OR version differs from installed
-            // For AUR packages: check if target version is different from installed version
-            let has_update = if upgradable_set.contains(&item.name) {
-                // Package is in upgradable set (pacman -Qu)
-                true
-            } else if !item.version.is_empty() {
-                // Normalize target version by removing revision suffix (same as installed version normalization)
-                let normalized_target_version =
-                    item.version.split('-').next().unwrap_or(&item.version);
-                // Compare normalized target version with normalized installed version
-                // This works for both official and AUR packages
-                crate::logic::deps::get_installed_version(&item.name)
-                    .is_ok_and(|installed_version| normalized_target_version != installed_version)
-            } else {
-                // No version info available, no update
-                false
-            };
-
-            // Only show reinstall confirmation if installed AND no update available
-            // If update is available, it should go through normal update flow
-            !has_update
-        })
-        .cloned()
-        .collect();
+    let installed_packages =
+        crate::logic::reinstall::reinstall_candidates(&packages, &installed_set, &upgradable_set);
 
-    if !installed_packages.is_empty() {
-        // Show reinstall confirmation modal (before password prompt)
+    if crate::logic::reinstall_confirm_required(&installed_packages) {
+        // Show reinstall confirmation modal (before password prompt, unless below the threshold)
         // Store both installed packages (for display) and all packages (for installation)
         app.modal = crate::state::Modal::ConfirmReinstall {
             items: installed_packages,
@@ -724,3 +731,51 @@ pub(super) fn handle_help_key(app: &mut AppState) -> bool {
     };
     false
 }
+
+/// What: Handle o key - toggle the raw command output subview.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - Always returns `false`.
+///
+/// Details:
+/// - Only available in expert mode; otherwise the key is ignored.
+pub(super) const fn handle_o_key(app: &mut AppState) -> bool {
+    if app.expert_mode {
+        app.preflight_raw_output_open = !app.preflight_raw_output_open;
+        app.preflight_raw_output_scroll = 0;
+    }
+    false
+}
+
+/// What: Handle keys while the raw command output subview is open.
+///
+/// Inputs:
+/// - `ke`: Key event
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` when the key scrolled or closed the subview; `false` to fall through to the
+///   regular preflight keys (e.g. proceed or close the modal).
+pub(super) const fn handle_raw_output_key(
+    ke: crossterm::event::KeyEvent,
+    app: &mut AppState,
+) -> bool {
+    use crossterm::event::KeyCode;
+    let scroll = &mut app.preflight_raw_output_scroll;
+    match ke.code {
+        KeyCode::Up => *scroll = scroll.saturating_sub(1),
+        KeyCode::Down => *scroll = scroll.saturating_add(1),
+        KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+        KeyCode::PageDown => *scroll = scroll.saturating_add(10),
+        KeyCode::Home => *scroll = 0,
+        KeyCode::Esc | KeyCode::Char('o') => {
+            app.preflight_raw_output_open = false;
+            app.preflight_raw_output_scroll = 0;
+        }
+        _ => return false,
+    }
+    true
+}
@@ -18,8 +18,8 @@ use action_keys::{
 // Re-export start_execution for use in other modules
 pub use action_keys::start_execution;
 use command_keys::{
-    handle_c_key, handle_dry_run_key, handle_f_key, handle_help_key, handle_m_key, handle_p_key,
-    handle_q_key, handle_s_key,
+    handle_c_key, handle_dry_run_key, handle_f_key, handle_h_key, handle_help_key, handle_m_key,
+    handle_o_key, handle_p_key, handle_q_key, handle_raw_output_key, handle_s_key,
 };
 use context::PreflightKeyContext;
 use navigation::{handle_down_key, handle_tab_switch, handle_up_key};
@@ -73,8 +73,10 @@ fn handle_keys_needing_app(ke: KeyEvent, app: &mut AppState) -> bool {
         KeyCode::Char('d') => handle_dry_run_key(app),
         // Only handle 'm' if no modifiers are present (to allow Ctrl+M for global keybinds)
         KeyCode::Char('m') if ke.modifiers.is_empty() => handle_m_key(app),
+        KeyCode::Char('h') => handle_h_key(app),
         KeyCode::Char('p') => handle_p_key(app),
         KeyCode::Char('c') => handle_c_key(app),
+        KeyCode::Char('o') => handle_o_key(app),
         KeyCode::Char('q') => handle_q_key(app),
         KeyCode::Char('?') => handle_help_key(app),
         _ => false,
@@ -91,12 +93,17 @@ fn handle_keys_needing_app(ke: KeyEvent, app: &mut AppState) -> bool {
 /// - Always returns `false` so the outer event loop continues processing.
 ///
 /// Details:
-/// - Supports tab switching, tree expansion, dependency/file navigation, scans, dry-run toggles, and
-///   command execution across install/remove flows.
+/// - Supports tab switching, tree expansion, dependency/file navigation, scans, dry-run toggles, the
+///   expert-mode raw output subview, and command execution across install/remove flows.
 /// - Mutates `app.modal` (and related cached fields) to close the modal, open nested dialogs, or
 ///   keep it updated with resolved dependency/file data.
 /// - Returns `false` so callers continue processing, matching existing event-loop expectations.
 pub fn handle_preflight_key(ke: KeyEvent, app: &mut AppState) -> bool {
+    // The expert-mode raw output subview captures scrolling and its own close keys
+    if app.preflight_raw_output_open && handle_raw_output_key(ke, app) {
+        return false;
+    }
+
     // First, handle keys that only need ctx (no app access required)
     // This avoids borrow checker conflicts
     {
@@ -223,6 +230,8 @@ mod tests {
             depends_on: vec![],
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         }];
         let mut expanded = HashSet::new();
         let mut selected_optdepends = HashMap::new();
@@ -36,11 +36,17 @@ pub(super) fn handle_deps_tab(ctx: &mut EnterOrSpaceContext<'_>) -> bool {
             && let Some(pkg_deps) = grouped.get(pkg_name)
         {
             let mut seen_deps = HashSet::new();
+            let mut has_optional = false;
             for dep in pkg_deps {
                 if seen_deps.insert(dep.name.as_str()) {
                     display_items.push((false, String::new()));
+                    has_optional |= dep.optional;
                 }
             }
+            // "Optional" group row; only headers are toggled, so its position is irrelevant
+            if has_optional {
+                display_items.push((false, String::new()));
+            }
         }
     }
 
@@ -85,6 +85,8 @@ pub(super) fn close_preflight_modal(
     app.preflight_files_items = None;
     app.preflight_services_items = None;
     app.preflight_sandbox_items = None;
+    app.preflight_raw_output_open = false;
+    app.preflight_raw_output_scroll = 0;
     app.modal = crate::state::Modal::None;
 }
 
@@ -66,6 +66,8 @@ fn dep(name: &str, required_by: &[&str]) -> DependencyInfo {
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }
 }
 
@@ -93,6 +95,8 @@ fn dep_with_status(name: &str, required_by: &[&str], status: DependencyStatus) -
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }
 }
 
@@ -197,6 +201,35 @@ fn deps_display_len_collapsed_counts_only_headers() {
     assert_eq!(len, 2);
 }
 
+#[test]
+/// What: Verify optional dependencies add one "Optional" group row per expanded package.
+///
+/// Inputs:
+/// - One hard and two optional dependencies for `app`, one optional for collapsed `tool`.
+///
+/// Output:
+/// - Two headers, three dependency rows and a single group row: six rows.
+///
+/// Details:
+/// - Collapsed packages contribute no group row even when they have optional dependencies.
+fn deps_display_len_adds_optional_group_row() {
+    let items = vec![pkg("app"), pkg("tool")];
+    let optional = |name: &str, required_by: &[&str]| DependencyInfo {
+        optional: true,
+        description: Some("extra feature".into()),
+        ..dep(name, required_by)
+    };
+    let deps = vec![
+        dep("libfoo", &["app"]),
+        optional("python", &["app"]),
+        optional("lua", &["app", "tool"]),
+    ];
+    let mut expanded = HashSet::new();
+    expanded.insert("app".to_string());
+    let len = compute_display_items_len(&items, &deps, &expanded);
+    assert_eq!(len, 6);
+}
+
 #[test]
 /// What: Confirm file display counts add child rows only for expanded entries.
 ///
@@ -1371,3 +1404,35 @@ fn test_keys_return_false_on_all_tabs() {
         );
     }
 }
+
+#[test]
+/// What: Verify the raw output toggle is only available in expert mode.
+///
+/// Inputs:
+/// - Preflight modal on the Summary tab; `o`, Down, and Esc key presses with and without expert mode.
+///
+/// Output:
+/// - Without expert mode `o` leaves the subview closed; with it `o` opens it, Down scrolls it, and
+///   Esc closes only the subview while the modal stays open.
+///
+/// Details:
+/// - Down must scroll the subview instead of moving the tab selection underneath.
+fn raw_output_toggle_requires_expert_mode() {
+    let mut app = setup_preflight_app(PreflightTab::Summary, Vec::new(), 0, HashSet::new());
+    let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+
+    handle_preflight_key(key(KeyCode::Char('o')), &mut app);
+    assert!(!app.preflight_raw_output_open);
+
+    app.expert_mode = true;
+    handle_preflight_key(key(KeyCode::Char('o')), &mut app);
+    assert!(app.preflight_raw_output_open);
+    handle_preflight_key(key(KeyCode::Down), &mut app);
+    handle_preflight_key(key(KeyCode::Down), &mut app);
+    assert_eq!(app.preflight_raw_output_scroll, 2);
+
+    handle_preflight_key(key(KeyCode::Esc), &mut app);
+    assert!(!app.preflight_raw_output_open);
+    assert_eq!(app.preflight_raw_output_scroll, 0);
+    assert!(matches!(app.modal, Modal::Preflight { .. }));
+}
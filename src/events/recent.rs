@@ -246,6 +246,7 @@ fn get_selected_recent_query(app: &AppState) -> Option<String> {
 ///
 /// Details:
 /// - Copies the selected recent query into Search, positions caret at end, and triggers a new search.
+/// - Outside News mode, restores the sort mode and filters saved with the query (defaults otherwise).
 fn handle_recent_enter(app: &mut AppState, query_tx: &mpsc::UnboundedSender<QueryInput>) -> bool {
     let Some(q) = get_selected_recent_query(app) else {
         return false;
@@ -259,6 +260,7 @@ fn handle_recent_enter(app: &mut AppState, query_tx: &mpsc::UnboundedSender<Quer
         app.news_search_select_anchor = None;
         app.refresh_news_results();
     } else {
+        app.restore_recent_view(&q);
         app.input = q;
         // Position caret at end and clear selection
         app.search_caret = char_count(&app.input);
@@ -311,7 +313,8 @@ fn handle_recent_space(app: &AppState, add_tx: &mpsc::UnboundedSender<PackageIte
 /// Details:
 /// - In-pane find: `/` enters find mode; typing edits the pattern; Enter jumps to next match;
 ///   Esc cancels. Matches are case-insensitive on recent query strings.
-/// - Navigation: `j/k` or `Down/Up` move selection within the filtered view and trigger preview.
+/// - Navigation: `j/k`, `Down/Up`, or configured `pane_down`/`pane_up` move selection within the
+///   filtered view and trigger preview; Left/Right (or `pane_left`/`pane_right`) move focus.
 /// - Use item: `Enter` copies the selected recent query into Search and triggers a new search.
 /// - Add item: Space resolves a best-effort match asynchronously and enqueues it to install list.
 /// - Removal: Configured keys (`recent_remove`/`recent_clear`) remove one/all entries.
@@ -347,6 +350,12 @@ pub fn handle_recent_key(
         KeyCode::Char('k') | KeyCode::Up => {
             move_recent_selection(app, false, preview_tx);
         }
+        _ if matches_any(&ke, &km.pane_down) => {
+            move_recent_selection(app, true, preview_tx);
+        }
+        _ if matches_any(&ke, &km.pane_up) => {
+            move_recent_selection(app, false, preview_tx);
+        }
         KeyCode::Char('/') => {
             app.pane_find = Some(String::new());
         }
@@ -356,10 +365,10 @@ pub fn handle_recent_key(
         code if matches_any(&ke, &km.pane_next) && code == ke.code => {
             transition_to_search(app, details_tx, false);
         }
-        KeyCode::Left => {
+        code if code == KeyCode::Left || matches_any(&ke, &km.pane_left) => {
             handle_recent_to_install_wrap(app, details_tx);
         }
-        KeyCode::Right => {
+        code if code == KeyCode::Right || matches_any(&ke, &km.pane_right) => {
             transition_to_search(app, details_tx, false);
         }
         code if matches_any(&ke, &km.recent_clear) && code == ke.code => {
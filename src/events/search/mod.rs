@@ -78,6 +78,26 @@ pub fn handle_search_key(
         return false;
     }
 
+    // Flip the selected result between its AUR and official variant (works in both modes)
+    if super::utils::matches_any(&ke, &km.search_toggle_variant) {
+        crate::logic::toggle_selected_variant(app, details_tx);
+        return false;
+    }
+
+    // Re-run the search with the first "did you mean" suggestion (both modes)
+    if !app.search_suggestions.is_empty()
+        && super::utils::matches_any(&ke, &km.search_accept_suggestion)
+    {
+        crate::logic::apply_search_suggestion(app, 0, query_tx);
+        return false;
+    }
+
+    // Quick install the selected result without adding it to the Install list (both modes)
+    if super::utils::matches_any(&ke, &km.search_quick_install) {
+        preflight_helpers::quick_install_selected(app);
+        return false;
+    }
+
     // Toggle Normal mode (configurable)
     if super::utils::matches_any(&ke, &km.search_normal_toggle) {
         app.search_normal_mode = !app.search_normal_mode;
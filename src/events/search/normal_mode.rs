@@ -504,7 +504,7 @@ fn handle_preflight_open(ke: &KeyEvent, app: &mut AppState) -> bool {
     false
 }
 
-/// What: Handle pane navigation (Left/Right arrows and `pane_next`).
+/// What: Handle pane navigation (Left/Right arrows, `pane_left`/`pane_right`, and `pane_next`).
 ///
 /// Inputs:
 /// - `ke`: Key event from terminal
@@ -516,7 +516,9 @@ fn handle_preflight_open(ke: &KeyEvent, app: &mut AppState) -> bool {
 /// - `true` if pane navigation was handled, `false` otherwise
 ///
 /// Details:
-/// - Handles Left/Right arrow keys and configured `pane_next` key.
+/// - Handles Left/Right arrow keys, configured `pane_left`/`pane_right`, and `pane_next`.
+/// - Runs after the normal-mode keymap actions, so chords shared with e.g. `h`/`l` selection
+///   keep their normal-mode meaning.
 /// - Switches focus between panes and updates details accordingly.
 fn handle_pane_navigation(
     ke: &KeyEvent,
@@ -525,11 +527,11 @@ fn handle_pane_navigation(
     preview_tx: &mpsc::UnboundedSender<PackageItem>,
 ) -> bool {
     match ke.code {
-        KeyCode::Right => {
+        code if code == KeyCode::Right || matches_any(ke, &app.keymap.pane_right) => {
             navigate_pane(app, "right", details_tx, preview_tx);
             true
         }
-        KeyCode::Left => {
+        code if code == KeyCode::Left || matches_any(ke, &app.keymap.pane_left) => {
             navigate_pane(app, "left", details_tx, preview_tx);
             true
         }
@@ -642,6 +644,27 @@ fn mark_news_feed_item(app: &mut AppState, mark_read: bool) -> bool {
     changed
 }
 
+/// What: Mark all visible News Feed items as read and report the count.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - None (mutates read state and shows a toast).
+///
+/// Details:
+/// - Delegates to `AppState::mark_visible_news_read`, so only items passing the active
+///   filters are marked.
+fn mark_all_news_feed_items(app: &mut AppState) {
+    let marked = app.mark_visible_news_read();
+    app.toast_message = Some(crate::i18n::t_fmt1(
+        app,
+        "app.toasts.news_marked_all_read",
+        marked,
+    ));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+}
+
 /// What: Toggle read/unread state for the selected News Feed item.
 ///
 /// Inputs:
@@ -668,6 +691,36 @@ fn toggle_news_feed_item(app: &mut AppState) -> bool {
     }
 }
 
+/// What: Copy the selected News Feed item to the clipboard as Markdown.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` if an item was selected and the copy was attempted, `false` otherwise
+///
+/// Details:
+/// - Includes the cached article content when available, otherwise only the metadata.
+/// - Shows a confirmation toast, or the clipboard error when no clipboard tool is installed.
+fn copy_news_item_markdown(app: &mut AppState) -> bool {
+    let Some(markdown) = crate::logic::news_export::selected_news_markdown(app) else {
+        return false;
+    };
+    match crate::util::clipboard::copy_plain_text_to_clipboard(&markdown) {
+        Ok(()) => {
+            app.toast_message = Some(crate::i18n::t(app, "app.toasts.news_markdown_copied"));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
+        Err(msg) => {
+            app.toast_message = Some(msg);
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
+        }
+    }
+    true
+}
+
 /// What: Handle news mode keybindings.
 ///
 /// Inputs:
@@ -678,13 +731,17 @@ fn toggle_news_feed_item(app: &mut AppState) -> bool {
 /// - `true` if a news keybinding was handled, `false` otherwise
 ///
 /// Details:
-/// - Handles mark read, mark unread, and toggle read keybindings in News mode.
+/// - Handles mark read, mark unread, toggle read, mark all read, and Markdown export
+///   keybindings in News mode.
 fn handle_news_mode_keybindings(ke: &KeyEvent, app: &mut AppState) -> bool {
     if !matches!(app.app_mode, crate::state::types::AppMode::News) {
         return false;
     }
 
-    if matches_any(ke, &app.keymap.news_mark_read_feed) {
+    if matches_any(ke, &app.keymap.news_mark_all_read_feed) {
+        mark_all_news_feed_items(app);
+        return true;
+    } else if matches_any(ke, &app.keymap.news_mark_read_feed) {
         if mark_news_feed_item(app, true) {
             return true;
         }
@@ -692,7 +749,9 @@ fn handle_news_mode_keybindings(ke: &KeyEvent, app: &mut AppState) -> bool {
         if mark_news_feed_item(app, false) {
             return true;
         }
-    } else if matches_any(ke, &app.keymap.news_toggle_read_feed) && toggle_news_feed_item(app) {
+    } else if (matches_any(ke, &app.keymap.news_toggle_read_feed) && toggle_news_feed_item(app))
+        || (matches_any(ke, &app.keymap.news_export_markdown_feed) && copy_news_item_markdown(app))
+    {
         return true;
     }
 
@@ -855,6 +914,90 @@ fn handle_aur_vote_action(ke: &KeyEvent, app: &mut AppState) -> bool {
     true
 }
 
+/// What: Handle the `:` numeric jump prompt that selects a result by its row number.
+///
+/// Inputs:
+/// - `ke`: Key event received from the terminal
+/// - `app`: Mutable application state
+/// - `details_tx`: Channel to request details for the focused item
+/// - `comments_tx`: Channel to request comments for the focused item
+///
+/// Output:
+/// - `true` if the key was consumed by the jump prompt; `false` otherwise
+///
+/// Details:
+/// - `:` starts the prompt in Package mode when results are present; digits accumulate.
+/// - Enter selects the 1-based row, clamped to the last result; Esc cancels.
+/// - Any other key cancels the prompt and is processed normally.
+fn handle_result_jump(
+    ke: &KeyEvent,
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+    comments_tx: &mpsc::UnboundedSender<String>,
+) -> bool {
+    let Some(mut digits) = app.result_jump_input.take() else {
+        let starts_jump = matches!(ke.code, KeyCode::Char(':'))
+            && (ke.modifiers.is_empty() || ke.modifiers == KeyModifiers::SHIFT)
+            && matches!(app.app_mode, crate::state::types::AppMode::Package)
+            && !app.results.is_empty();
+        if starts_jump {
+            app.result_jump_input = Some(String::new());
+            app.toast_message = Some(crate::i18n::t_fmt1(app, "app.toasts.jump_to_result", ""));
+            app.toast_expires_at = None;
+        }
+        return starts_jump;
+    };
+
+    match ke.code {
+        KeyCode::Char(ch) if ch.is_ascii_digit() => {
+            digits.push(ch);
+            app.toast_message = Some(crate::i18n::t_fmt1(
+                app,
+                "app.toasts.jump_to_result",
+                &digits,
+            ));
+            app.toast_expires_at = None;
+            app.result_jump_input = Some(digits);
+            true
+        }
+        KeyCode::Backspace => {
+            if digits.pop().is_some() {
+                app.toast_message = Some(crate::i18n::t_fmt1(
+                    app,
+                    "app.toasts.jump_to_result",
+                    &digits,
+                ));
+                app.result_jump_input = Some(digits);
+            } else {
+                app.toast_message = None;
+            }
+            true
+        }
+        KeyCode::Enter => {
+            app.toast_message = None;
+            if let Ok(number) = digits.parse::<usize>()
+                && !app.results.is_empty()
+            {
+                let target = number.saturating_sub(1).min(app.results.len() - 1);
+                let delta = isize::try_from(target).unwrap_or(isize::MAX)
+                    - isize::try_from(app.selected).unwrap_or(0);
+                if delta != 0 {
+                    move_sel_cached_with_vote_state(app, delta, details_tx, comments_tx);
+                }
+            }
+            true
+        }
+        KeyCode::Esc => {
+            app.toast_message = None;
+            true
+        }
+        _ => {
+            app.toast_message = None;
+            false
+        }
+    }
+}
+
 /// What: Handle key events in Normal mode for the Search pane.
 ///
 /// Inputs:
@@ -880,6 +1023,11 @@ pub fn handle_normal_mode(
     preview_tx: &mpsc::UnboundedSender<PackageItem>,
     comments_tx: &mpsc::UnboundedSender<String>,
 ) -> bool {
+    // Handle the `:` result jump prompt before digits reach the config menu
+    if handle_result_jump(&ke, app, details_tx, comments_tx) {
+        return false;
+    }
+
     // Handle numeric selection for config menu (1-9)
     if let KeyCode::Char(ch) = ke.code
         && ch.is_ascii_digit()
@@ -1045,4 +1193,75 @@ mod tests {
         assert!(!handled);
         assert!(app.news_read_ids.contains("three"));
     }
+
+    fn make_results(count: usize) -> Vec<PackageItem> {
+        (1..=count)
+            .map(|n| PackageItem::test_item(&format!("pkg-{n}"), "extra"))
+            .collect()
+    }
+
+    fn type_keys(app: &mut AppState, codes: &[KeyCode]) {
+        let (query_tx, _query_rx) = mpsc::unbounded_channel();
+        let (details_tx, _details_rx) = mpsc::unbounded_channel();
+        let (add_tx, _add_rx) = mpsc::unbounded_channel();
+        let (preview_tx, _preview_rx) = mpsc::unbounded_channel();
+        let (comments_tx, _comments_rx) = mpsc::unbounded_channel();
+        for code in codes {
+            handle_normal_mode(
+                key(*code),
+                app,
+                &query_tx,
+                &details_tx,
+                &add_tx,
+                &preview_tx,
+                &comments_tx,
+            );
+        }
+    }
+
+    #[test]
+    fn result_jump_selects_numbered_result() {
+        let mut app = AppState {
+            app_mode: AppMode::Package,
+            results: make_results(20),
+            ..AppState::default()
+        };
+
+        type_keys(
+            &mut app,
+            &[
+                KeyCode::Char(':'),
+                KeyCode::Char('1'),
+                KeyCode::Char('2'),
+                KeyCode::Enter,
+            ],
+        );
+
+        assert_eq!(app.selected, 11);
+        assert_eq!(app.results[app.selected].name, "pkg-12");
+        assert!(app.result_jump_input.is_none());
+        assert!(app.toast_message.is_none());
+    }
+
+    #[test]
+    fn result_jump_clamps_out_of_range_to_last_result() {
+        let mut app = AppState {
+            app_mode: AppMode::Package,
+            results: make_results(5),
+            ..AppState::default()
+        };
+
+        type_keys(
+            &mut app,
+            &[
+                KeyCode::Char(':'),
+                KeyCode::Char('9'),
+                KeyCode::Char('9'),
+                KeyCode::Enter,
+            ],
+        );
+
+        assert_eq!(app.selected, 4);
+        assert!(app.result_jump_input.is_none());
+    }
 }
@@ -198,7 +198,15 @@ fn create_preflight_modal_insert_mode(app: &mut AppState, items: Vec<PackageItem
 /// - If `use_cache` is true, checks cache and uses cached data if available, otherwise triggers background resolution.
 /// - If `use_cache` is false, always triggers background resolution (used in insert mode).
 /// - Sets up all preflight resolution flags and initializes the modal state.
+/// - Shows a blocking alert instead when pacman-only mode refuses AUR packages in `items`.
 pub fn open_preflight_modal(app: &mut AppState, items: Vec<PackageItem>, use_cache: bool) {
+    if crate::install::reject_aur_in_pacman_only(
+        app,
+        &items,
+        crate::install::command::pacman_only_mode(),
+    ) {
+        return;
+    }
     if crate::theme::settings().skip_preflight {
         if crate::events::install::try_open_warn_aur_repo_duplicate_modal(
             app,
@@ -211,52 +219,13 @@ pub fn open_preflight_modal(app: &mut AppState, items: Vec<PackageItem>, use_cac
         // First, check if we're installing packages that are already installed (reinstall scenario)
         // BUT exclude packages that have updates available (those should go through normal update flow)
         let installed_set = crate::logic::deps::get_installed_packages();
-        let provided_set = crate::logic::deps::get_provided_packages(&installed_set);
         let upgradable_set = crate::logic::deps::get_upgradable_packages();
 
-        let installed_packages: Vec<crate::state::PackageItem> = items
-            .iter()
-            .filter(|item| {
-                // Check if package is installed or provided by an installed package
-                let is_installed = crate::logic::deps::is_package_installed_or_provided(
-                    &item.name,
-                    &installed_set,
-                    &provided_set,
-                );
+        let installed_packages =
+            crate::logic::reinstall::reinstall_candidates(&items, &installed_set, &upgradable_set);
 
-                if !is_installed {
-                    return false;
-                }
-
-                // Check if package has an update available
-                // For official packages: check if it's in upgradable_set OR version differs from installed
-                // For AUR packages: check if target version is different from installed version
-                let has_update = if upgradable_set.contains(&item.name) {
-                    // Package is in upgradable set (pacman -Qu)
-                    true
-                } else if !item.version.is_empty() {
-                    // Normalize target version by removing revision suffix (same as installed version normalization)
-                    let normalized_target_version =
-                        item.version.split('-').next().unwrap_or(&item.version);
-                    // Compare normalized target version with normalized installed version
-                    // This works for both official and AUR packages
-                    crate::logic::deps::get_installed_version(&item.name).is_ok_and(
-                        |installed_version| normalized_target_version != installed_version,
-                    )
-                } else {
-                    // No version info available, no update
-                    false
-                };
-
-                // Only show reinstall confirmation if installed AND no update available
-                // If update is available, it should go through normal update flow
-                !has_update
-            })
-            .cloned()
-            .collect();
-
-        if !installed_packages.is_empty() {
-            // Show reinstall confirmation modal
+        if crate::logic::reinstall_confirm_required(&installed_packages) {
+            // Show reinstall confirmation modal (unless below the configured threshold)
             // Store both installed packages (for display) and all packages (for installation)
             app.modal = crate::state::Modal::ConfirmReinstall {
                 items: installed_packages,
@@ -309,10 +278,17 @@ pub fn open_preflight_modal(app: &mut AppState, items: Vec<PackageItem>, use_cac
             summary,
             header,
             reverse_deps_report: _,
-        } = crate::logic::preflight::compute_preflight_summary(
-            &items,
-            crate::state::PreflightAction::Install,
-        );
+        } = app
+            .cached_preflight_summary(&items, crate::state::PreflightAction::Install)
+            .unwrap_or_else(|| {
+                let action = crate::state::PreflightAction::Install;
+                let outcome = crate::logic::preflight::compute_preflight_summary(&items, action);
+                app.store_preflight_summary(
+                    crate::logic::preflight::summary_signature(&items, action),
+                    &outcome,
+                );
+                outcome
+            });
         app.pending_service_plan.clear();
 
         let item_names: std::collections::HashSet<String> =
@@ -350,6 +326,29 @@ pub fn open_preflight_modal(app: &mut AppState, items: Vec<PackageItem>, use_cac
     app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(2));
 }
 
+/// What: Install the selected result as a one-off transaction without touching the Install list.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+///
+/// Output:
+/// - `true` when a result was selected and handed to the install flow; `false` otherwise.
+///
+/// Details:
+/// - Routes the single package through [`open_preflight_modal`], so `skip_preflight`,
+///   reinstall/batch-update confirmations and `app.dry_run` apply exactly as for Enter.
+/// - Always installs, even in installed-only mode where Enter opens the remove flow.
+/// - Never sends on the add channel, so `app.install_list` stays unchanged.
+pub fn quick_install_selected(app: &mut AppState) -> bool {
+    let Some(item) = app.results.get(app.selected).cloned() else {
+        return false;
+    };
+    tracing::debug!("[Search] Quick install for package: {}", item.name);
+    let use_cache = app.search_normal_mode;
+    open_preflight_modal(app, vec![item], use_cache);
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -438,6 +437,8 @@ mod tests {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         }];
 
         trigger_background_resolution(&mut app, &items, &cached_deps, &[]);
@@ -502,6 +503,8 @@ mod tests {
                 depends_on: Vec::new(),
                 is_core: false,
                 is_system: false,
+                optional: false,
+                description: None,
             },
             crate::state::modal::DependencyInfo {
                 name: "dep-b".to_string(),
@@ -514,6 +517,8 @@ mod tests {
                 depends_on: Vec::new(),
                 is_core: false,
                 is_system: false,
+                optional: false,
+                description: None,
             },
         ];
 
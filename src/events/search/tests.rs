@@ -1052,3 +1052,182 @@ fn installed_only_enter_opens_remove_preflight_insert_mode() {
     }
     let _ = std::fs::remove_dir_all(&base);
 }
+
+/// What: Point `HOME` at a fresh temp config with the given `settings.conf` body.
+///
+/// Inputs:
+/// - `tag`: Short label used in the temp directory name
+/// - `settings`: Contents written to `settings.conf`
+///
+/// Output:
+/// - `(base_dir, original_home)` so the caller can restore `HOME` and clean up.
+///
+/// Details:
+/// - Caller must hold `theme::test_mutex` while the override is active.
+fn quick_install_test_home(
+    tag: &str,
+    settings: &str,
+) -> (std::path::PathBuf, Option<std::ffi::OsString>) {
+    let orig_home = std::env::var_os("HOME");
+    let base = std::env::temp_dir().join(format!(
+        "pacsea_test_quick_install_{tag}_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    let cfg = base.join(".config").join("pacsea");
+    std::fs::create_dir_all(&cfg).expect("create test config dir");
+    unsafe {
+        std::env::set_var("HOME", base.display().to_string());
+    }
+    std::fs::write(cfg.join("settings.conf"), settings).expect("write settings");
+    std::fs::write(cfg.join("keybinds.conf"), "").expect("write keybinds");
+    (base, orig_home)
+}
+
+/// What: Restore `HOME` and remove the temp config created by [`quick_install_test_home`].
+///
+/// Inputs:
+/// - `base`: Temp directory to remove
+/// - `orig_home`: Original `HOME` value to restore
+///
+/// Output:
+/// - None.
+fn restore_quick_install_home(base: &std::path::Path, orig_home: Option<std::ffi::OsString>) {
+    unsafe {
+        if let Some(v) = orig_home {
+            std::env::set_var("HOME", v);
+        } else {
+            std::env::remove_var("HOME");
+        }
+    }
+    let _ = std::fs::remove_dir_all(base);
+}
+
+#[test]
+/// What: Quick install with `skip_preflight` queues a single-item dry-run executor request.
+///
+/// Inputs:
+/// - `skip_preflight=true`, passwordless test override, `dry_run`, two results with the
+///   second selected and one unrelated package already in the Install list; Alt+Enter.
+///
+/// Output:
+/// - `pending_executor_request` is `Install` with only the selected package and `dry_run`,
+///   the Install list is unchanged and nothing is sent on the add channel.
+///
+/// Details:
+/// - Uses the integration-test auth overrides so no real privilege tool is probed.
+fn quick_install_queues_single_item_executor_request() {
+    let _guard = crate::global_test_mutex_lock();
+    let _theme_guard = crate::theme::test_mutex()
+        .lock()
+        .expect("theme test mutex poisoned");
+    let (base, orig_home) = quick_install_test_home("exec", "skip_preflight=true\n");
+    unsafe {
+        std::env::set_var("PACSEA_INTEGRATION_TEST", "1");
+        std::env::set_var("PACSEA_TEST_SUDO_PASSWORDLESS", "1");
+    }
+
+    let mut app = new_app();
+    app.dry_run = true;
+    app.focus = crate::state::Focus::Search;
+    app.results = vec![
+        PackageItem::test_item("first", "extra"),
+        PackageItem::test_item("picked", "extra"),
+    ];
+    app.selected = 1;
+    app.install_list = vec![PackageItem::test_item("already-listed", "extra")];
+
+    let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+    let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+    let (atx, mut arx) = mpsc::unbounded_channel::<PackageItem>();
+    let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+    let (comments_tx, _comments_rx) = mpsc::unbounded_channel::<String>();
+
+    let _ = handle_search_key(
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT),
+        &mut app,
+        &qtx,
+        &dtx,
+        &atx,
+        &ptx,
+        &comments_tx,
+    );
+
+    unsafe {
+        std::env::remove_var("PACSEA_TEST_SUDO_PASSWORDLESS");
+        std::env::remove_var("PACSEA_INTEGRATION_TEST");
+    }
+    restore_quick_install_home(&base, orig_home);
+
+    match &app.pending_executor_request {
+        Some(crate::install::ExecutorRequest::Install { items, dry_run, .. }) => {
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].name, "picked");
+            assert!(*dry_run);
+        }
+        other => panic!("expected single-item install request, got {other:?}"),
+    }
+    assert_eq!(app.install_list.len(), 1);
+    assert_eq!(app.install_list[0].name, "already-listed");
+    assert!(arx.try_recv().is_err());
+}
+
+#[test]
+/// What: Quick install in installed-only mode still opens an install preflight for the selection.
+///
+/// Inputs:
+/// - `skip_preflight=false`, installed-only normal mode, one selected result; Alt+Enter.
+///
+/// Output:
+/// - `Modal::Preflight` with `PreflightAction::Install` for that package; Install list stays empty.
+///
+/// Details:
+/// - Plain Enter would open the remove flow here; quick install always installs.
+fn quick_install_opens_install_preflight_without_touching_list() {
+    let _theme_guard = crate::theme::test_mutex()
+        .lock()
+        .expect("theme test mutex poisoned");
+    let (base, orig_home) = quick_install_test_home("preflight", "skip_preflight=false\n");
+
+    let mut app = new_app();
+    app.search_normal_mode = true;
+    app.installed_only_mode = true;
+    app.focus = crate::state::Focus::Search;
+    app.results = vec![PackageItem::test_item("quick-one", "extra")];
+    app.selected = 0;
+
+    let (qtx, _qrx) = mpsc::unbounded_channel::<QueryInput>();
+    let (dtx, _drx) = mpsc::unbounded_channel::<PackageItem>();
+    let (atx, mut arx) = mpsc::unbounded_channel::<PackageItem>();
+    let (ptx, _prx) = mpsc::unbounded_channel::<PackageItem>();
+    let (comments_tx, _comments_rx) = mpsc::unbounded_channel::<String>();
+
+    let _ = handle_search_key(
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT),
+        &mut app,
+        &qtx,
+        &dtx,
+        &atx,
+        &ptx,
+        &comments_tx,
+    );
+
+    restore_quick_install_home(&base, orig_home);
+
+    match &app.modal {
+        crate::state::Modal::Preflight {
+            action: crate::state::PreflightAction::Install,
+            items,
+            ..
+        } => {
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].name, "quick-one");
+        }
+        other => panic!("expected install preflight for quick-one, got {other:?}"),
+    }
+    assert!(app.install_list.is_empty());
+    assert!(arx.try_recv().is_err());
+}
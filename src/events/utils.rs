@@ -114,23 +114,55 @@ pub fn find_in_recent(app: &mut AppState, forward: bool) {
     }
 }
 
-/// What: Advance selection in the Install pane to the next/previous item matching the pane-find pattern.
+/// What: Advance selection in the focused right-column pane to the next/previous pane-find match.
 ///
 /// Input: `app` mutable application state; `forward` when true searches downward, else upward
-/// Output: No return value; updates `install_state` selection when a match is found
+/// Output: No return value; updates the Install, Remove, or Downgrade selection when a match is found
 ///
-/// Details: Operates on visible indices and tests case-insensitive matches against package name
-/// or description; wraps around the list.
+/// Details: Targets Remove/Downgrade in installed-only mode and Install otherwise. Matches are
+/// case-insensitive on the package name, plus the description for panes listed in
+/// `find_match_description`; wraps around the list.
 pub fn find_in_install(app: &mut AppState, forward: bool) {
+    let pref = crate::theme::settings().find_match_description;
+    find_in_right_pane(app, forward, &pref);
+}
+
+/// What: Pane-find implementation behind [`find_in_install`] with an explicit match preference.
+///
+/// Input: `app` mutable application state; `forward` search direction; `match_pref` the
+/// `find_match_description` value
+/// Output: No return value; updates the focused pane's selection when a match is found
+fn find_in_right_pane(app: &mut AppState, forward: bool, match_pref: &str) {
+    use crate::ui::helpers::filter::{description_match_enabled, pane_find_matches};
+
     let Some(pattern) = app.pane_find.clone() else {
         return;
     };
-    let inds = crate::ui::helpers::filtered_install_indices(app);
+    let pat = pattern.to_lowercase();
+    let focus = if app.installed_only_mode {
+        app.right_pane_focus
+    } else {
+        crate::state::RightPaneFocus::Install
+    };
+    let inds: Vec<usize> = match focus {
+        crate::state::RightPaneFocus::Remove => (0..app.remove_list.len()).collect(),
+        crate::state::RightPaneFocus::Downgrade => (0..app.downgrade_list.len()).collect(),
+        crate::state::RightPaneFocus::Install => crate::ui::helpers::filtered_install_indices(app),
+    };
     if inds.is_empty() {
         return;
     }
-    let start = app.install_state.selected().unwrap_or(0);
-    let mut vi = start;
+    let (pane, items, state) = match focus {
+        crate::state::RightPaneFocus::Remove => ("remove", &app.remove_list, &mut app.remove_state),
+        crate::state::RightPaneFocus::Downgrade => {
+            ("downgrade", &app.downgrade_list, &mut app.downgrade_state)
+        }
+        crate::state::RightPaneFocus::Install => {
+            ("install", &app.install_list, &mut app.install_state)
+        }
+    };
+    let match_description = description_match_enabled(match_pref, pane);
+    let mut vi = state.selected().unwrap_or(0);
     let n = inds.len();
     for _ in 0..n {
         vi = if forward {
@@ -140,19 +172,81 @@ pub fn find_in_install(app: &mut AppState, forward: bool) {
         } else {
             vi - 1
         };
-        let i = inds[vi];
-        if let Some(p) = app.install_list.get(i)
-            && (p.name.to_lowercase().contains(&pattern.to_lowercase())
-                || p.description
-                    .to_lowercase()
-                    .contains(&pattern.to_lowercase()))
+        if let Some(p) = items.get(inds[vi])
+            && pane_find_matches(p, &pat, match_description)
         {
-            app.install_state.select(Some(vi));
+            state.select(Some(vi));
             break;
         }
     }
 }
 
+/// What: Focus the configured right subpane when entering installed-only mode.
+///
+/// Input: `app` mutable application state; `preference` the `installed_only_default_focus` value
+/// Output: No return value; sets `right_pane_focus` and clamps that subpane's selection
+///
+/// Details: `"downgrade"` focuses the Downgrade subpane; anything else keeps the historical
+/// Remove focus. The focused list gets a valid selection (first row when none) or `None` when empty.
+pub fn focus_installed_only_subpane(app: &mut AppState, preference: &str) {
+    let (focus, len, state) = if preference.eq_ignore_ascii_case("downgrade") {
+        (
+            crate::state::RightPaneFocus::Downgrade,
+            app.downgrade_list.len(),
+            &mut app.downgrade_state,
+        )
+    } else {
+        (
+            crate::state::RightPaneFocus::Remove,
+            app.remove_list.len(),
+            &mut app.remove_state,
+        )
+    };
+    if len == 0 {
+        state.select(None);
+    } else {
+        state.select(Some(state.selected().unwrap_or(0).min(len - 1)));
+    }
+    app.right_pane_focus = focus;
+}
+
+/// What: Apply the configured search input handling after switching `app_mode`.
+///
+/// Input: `app` mutable application state (already in the new mode); `preference` the
+/// `mode_switch_search_input` value
+/// Output: No return value; restores or clears the input belonging to the entered mode
+///
+/// Details: `"clear"` empties the entered mode's input (news results are re-filtered, package
+/// results are re-queried on the next tick); anything else preserves the last input of that mode.
+/// In both cases the caret is clamped and any selection anchor is dropped so editing resumes
+/// predictably. Config editor mode keeps its own query and is left untouched.
+pub fn restore_mode_search_input(app: &mut AppState, preference: &str) {
+    let clear = preference.eq_ignore_ascii_case("clear");
+    match app.app_mode {
+        crate::state::types::AppMode::News => {
+            if clear && !app.news_search_input.is_empty() {
+                app.news_search_input.clear();
+                app.last_saved_value = None;
+                app.refresh_news_results();
+            }
+            app.news_search_caret = app
+                .news_search_caret
+                .min(char_count(&app.news_search_input));
+            app.news_search_select_anchor = None;
+        }
+        crate::state::types::AppMode::Package => {
+            if clear && !app.input.is_empty() {
+                app.input.clear();
+                app.last_saved_value = None;
+                app.search_requery_pending = true;
+            }
+            app.search_caret = app.search_caret.min(char_count(&app.input));
+            app.search_select_anchor = None;
+        }
+        crate::state::types::AppMode::ConfigEditor => {}
+    }
+}
+
 /// What: Ensure details reflect the currently selected result.
 ///
 /// Input: `app` mutable application state; `details_tx` channel for details requests
@@ -165,8 +259,9 @@ pub fn refresh_selected_details(
     details_tx: &mpsc::UnboundedSender<PackageItem>,
 ) {
     if let Some(item) = app.results.get(app.selected).cloned() {
-        // Reset scroll when package changes
+        // Reset scroll and description expansion when package changes
         app.details_scroll = 0;
+        app.details_description_expanded = false;
         if let Some(cached) = app.details_cache.get(&item.name).cloned() {
             app.details = cached;
         } else {
@@ -367,9 +462,25 @@ pub fn compute_updates_filtered_indices(
         .collect()
 }
 
+/// Store the current news content scroll offset for the item it belongs to.
+fn remember_news_scroll(app: &mut AppState) {
+    if let Some(id) = app.news_content_scroll_item.take() {
+        if app.news_content_scroll == 0 {
+            app.news_content_scroll_by_item.remove(&id);
+        } else {
+            app.news_content_scroll_by_item
+                .insert(id, app.news_content_scroll);
+        }
+    }
+}
+
 /// Synchronize details URL and content with currently selected news item.
+///
 /// Also triggers content fetching if channel is provided and content is not cached.
+/// The previous item's scroll offset is remembered and the new item's offset restored
+/// (when `news_remember_scroll` is enabled).
 pub fn update_news_url(app: &mut AppState) {
+    remember_news_scroll(app);
     if let Some(item) = app.news_results.get(app.news_selected)
         && let Some(url) = &item.url
     {
@@ -395,7 +506,15 @@ pub fn update_news_url(app: &mut AppState) {
             app.news_content_debounce_timer = Some(std::time::Instant::now());
             tracing::debug!(url, "news content not cached, setting debounce timer");
         }
-        app.news_content_scroll = 0;
+        app.news_content_scroll = if crate::theme::settings().news_remember_scroll {
+            app.news_content_scroll_by_item
+                .get(&item.id)
+                .copied()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        app.news_content_scroll_item = Some(item.id.clone());
     } else {
         app.details.url.clear();
         app.news_content = None;
@@ -521,8 +640,9 @@ pub fn refresh_install_details(
     }
     let i = inds[vsel];
     if let Some(item) = app.install_list.get(i).cloned() {
-        // Reset scroll when package changes
+        // Reset scroll and description expansion when package changes
         app.details_scroll = 0;
+        app.details_description_expanded = false;
         // Focus details on the install selection
         app.details_focus = Some(item.name.clone());
 
@@ -564,8 +684,9 @@ pub fn refresh_remove_details(app: &mut AppState, details_tx: &mpsc::UnboundedSe
         return;
     }
     if let Some(item) = app.remove_list.get(vsel).cloned() {
-        // Reset scroll when package changes
+        // Reset scroll and description expansion when package changes
         app.details_scroll = 0;
+        app.details_description_expanded = false;
         app.details_focus = Some(item.name.clone());
         app.details.name.clone_from(&item.name);
         app.details.version.clone_from(&item.version);
@@ -606,8 +727,9 @@ pub fn refresh_downgrade_details(
         return;
     }
     if let Some(item) = app.downgrade_list.get(vsel).cloned() {
-        // Reset scroll when package changes
+        // Reset scroll and description expansion when package changes
         app.details_scroll = 0;
+        app.details_description_expanded = false;
         app.details_focus = Some(item.name.clone());
         app.details.name.clone_from(&item.name);
         app.details.version.clone_from(&item.version);
@@ -648,6 +770,60 @@ mod tests {
         AppState::default()
     }
 
+    #[test]
+    /// What: Verify the Remove preference focuses Remove with a valid selection.
+    ///
+    /// Inputs:
+    /// - Remove list with two packages, a stale out-of-range selection, preference `remove`.
+    ///
+    /// Output:
+    /// - `right_pane_focus` is Remove and the selection is clamped to the last row.
+    ///
+    /// Details:
+    /// - An empty Remove list yields no selection instead of a dangling index.
+    fn focus_installed_only_subpane_remove_selects_valid_row() {
+        let mut app = new_app();
+        app.right_pane_focus = crate::state::RightPaneFocus::Install;
+        app.remove_list = vec![
+            PackageItem::test_item("a", "extra"),
+            PackageItem::test_item("b", "extra"),
+        ];
+        app.remove_state.select(Some(5));
+        focus_installed_only_subpane(&mut app, "remove");
+        assert_eq!(app.right_pane_focus, crate::state::RightPaneFocus::Remove);
+        assert_eq!(app.remove_state.selected(), Some(1));
+
+        app.remove_list.clear();
+        focus_installed_only_subpane(&mut app, "Remove");
+        assert_eq!(app.remove_state.selected(), None);
+    }
+
+    #[test]
+    /// What: Verify the default setting keeps Remove focus and `downgrade` switches subpanes.
+    ///
+    /// Inputs:
+    /// - Default `installed_only_default_focus`, then preference `downgrade` with one queued row.
+    ///
+    /// Output:
+    /// - Default lands on Remove; `downgrade` lands on Downgrade with the first row selected.
+    ///
+    /// Details:
+    /// - Guards the historical default behavior of the mode switch.
+    fn focus_installed_only_subpane_default_unchanged() {
+        let mut app = new_app();
+        let default_pref = crate::theme::Settings::default().installed_only_default_focus;
+        focus_installed_only_subpane(&mut app, &default_pref);
+        assert_eq!(app.right_pane_focus, crate::state::RightPaneFocus::Remove);
+
+        app.downgrade_list = vec![PackageItem::test_item("c", "extra")];
+        focus_installed_only_subpane(&mut app, "downgrade");
+        assert_eq!(
+            app.right_pane_focus,
+            crate::state::RightPaneFocus::Downgrade
+        );
+        assert_eq!(app.downgrade_state.selected(), Some(0));
+    }
+
     #[test]
     /// What: Ensure `char_count` returns the number of Unicode scalar values.
     ///
@@ -744,6 +920,52 @@ mod tests {
         assert_eq!(app.install_state.selected(), Some(0));
     }
 
+    #[test]
+    /// What: Verify Remove-pane find honours `find_match_description`.
+    ///
+    /// Inputs:
+    /// - Installed-only mode focused on Remove with `ripgrep` ("fast search") and `fd`
+    ///   ("find entries"), pattern `"search"`, selection on `fd`.
+    ///
+    /// Output:
+    /// - With `remove` enabled the selection moves to `ripgrep` via its description;
+    ///   with the default (`install`) the Remove pane matches names only and stays put.
+    ///
+    /// Details:
+    /// - Also checks the Install pane keeps description matching under the default.
+    fn find_in_remove_pane_respects_description_setting() {
+        let mut app = new_app();
+        app.installed_only_mode = true;
+        app.right_pane_focus = crate::state::RightPaneFocus::Remove;
+        let mut rg = PackageItem::test_item("ripgrep", "extra");
+        rg.description = "fast search".into();
+        let mut fd = PackageItem::test_item("fd", "extra");
+        fd.description = "find entries".into();
+        app.remove_list = vec![rg.clone(), fd.clone()];
+        app.pane_find = Some("search".into());
+
+        app.remove_state.select(Some(1));
+        find_in_right_pane(&mut app, true, "install, remove");
+        assert_eq!(app.remove_state.selected(), Some(0));
+
+        app.remove_state.select(Some(1));
+        find_in_right_pane(&mut app, true, "install");
+        assert_eq!(app.remove_state.selected(), Some(1));
+
+        app.installed_only_mode = false;
+        app.install_list = vec![fd, rg];
+        app.install_state.select(Some(0));
+        find_in_right_pane(&mut app, true, "install");
+        assert_eq!(app.install_state.selected(), Some(1));
+        assert!(crate::ui::helpers::filter::description_match_enabled(
+            "all",
+            "downgrade"
+        ));
+        assert!(!crate::ui::helpers::filter::description_match_enabled(
+            "none", "install"
+        ));
+    }
+
     #[test]
     /// What: Ensure `refresh_selected_details` dispatches a fetch when cache misses occur.
     ///
@@ -1002,4 +1224,45 @@ mod tests {
             assert!(crate::index::find_package_by_name(name).is_none());
         }
     }
+
+    #[test]
+    /// What: Verify the news content scroll offset is restored when an item is reselected.
+    ///
+    /// Inputs:
+    /// - Two cached news items; scroll the first, move to the second, then move back.
+    ///
+    /// Output:
+    /// - The second item starts at offset 0 and the first item gets its offset back.
+    ///
+    /// Details:
+    /// - Both items are cached so no content refresh interferes with the restore.
+    fn news_scroll_is_restored_when_returning_to_item() {
+        use crate::state::types::{NewsFeedItem, NewsFeedSource};
+        let item = |id: &str| NewsFeedItem {
+            id: id.to_string(),
+            date: "2024-01-01".to_string(),
+            title: id.to_string(),
+            summary: None,
+            url: Some(format!("https://example.com/{id}")),
+            source: NewsFeedSource::ArchNews,
+            severity: None,
+            packages: Vec::new(),
+        };
+        let mut app = new_app();
+        app.news_results = vec![item("a"), item("b")];
+        for id in ["a", "b"] {
+            app.news_content_cache
+                .insert(format!("https://example.com/{id}"), "body".to_string());
+        }
+        update_news_url(&mut app);
+        app.news_content_scroll = 12;
+
+        move_news_selection(&mut app, 1);
+        assert_eq!(app.news_selected, 1);
+        assert_eq!(app.news_content_scroll, 0);
+
+        move_news_selection(&mut app, -1);
+        assert_eq!(app.news_selected, 0);
+        assert_eq!(app.news_content_scroll, 12);
+    }
 }
@@ -7,9 +7,12 @@
 //! The i18n system supports:
 //! - **Locale Detection**: Auto-detects system locale from environment variables (`LANG`, `LC_ALL`, `LC_MESSAGES`)
 //! - **Locale Resolution**: Resolves locale with fallback chain (settings -> system -> default)
-//! - **Fallback Chain**: Supports locale fallbacks (e.g., `de-CH` -> `de-DE` -> `en-US`)
+//! - **Fallback Chain**: Supports locale fallbacks (e.g., `de-CH` -> `de-DE` -> `en-US`, or
+//!   `pt-BR` -> `pt` -> `en-US`); lookups walk every loaded locale of the chain
 //! - **Translation Loading**: Loads YAML locale files from `locales/` directory
 //! - **Translation Lookup**: Provides `t()`, `t_fmt()`, and `t_fmt1()` helpers for translation access
+//! - **Pluralization**: `tn()` picks a CLDR plural form (`one`, `few`, `other`, ...) stored under a key
+//! - **Text Direction**: `is_rtl()` reports right-to-left locales so the UI can mirror its layout
 //!
 //! # Locale Files
 //!
@@ -61,13 +64,15 @@
 
 mod detection;
 mod loader;
+pub mod plural;
 mod resolver;
 pub mod translations;
 
 pub use detection::detect_system_locale;
 pub use loader::{LocaleLoader, load_locale_file};
-pub use resolver::{LocaleResolver, resolve_locale};
-pub use translations::{TranslationMap, translate, translate_with_fallback};
+pub use plural::{PluralCategory, plural_category};
+pub use resolver::{LocaleResolver, fallback_chain, resolve_locale};
+pub use translations::{TranslationMap, translate, translate_with_chain, translate_with_fallback};
 
 use std::path::PathBuf;
 
@@ -149,14 +154,15 @@ pub fn find_locales_dir() -> Option<PathBuf> {
 /// - Translated string, or the key itself if translation not found
 ///
 /// Details:
-/// - Uses translations from `AppState`
-/// - Falls back to English if translation missing
+/// - Walks `translations`, then `translations_chain` (e.g. `pt` for `pt-BR`), then the English
+///   `translations_fallback`, returning the first hit
 #[must_use]
 pub fn t(app: &crate::state::AppState, key: &str) -> String {
-    crate::i18n::translations::translate_with_fallback(
+    crate::i18n::translations::translate_with_chain(
         key,
-        &app.translations,
-        &app.translations_fallback,
+        std::iter::once(&app.translations)
+            .chain(&app.translations_chain)
+            .chain(std::iter::once(&app.translations_fallback)),
     )
 }
 
@@ -213,3 +219,143 @@ pub fn t_fmt2<T1: std::fmt::Display, T2: std::fmt::Display>(
 ) -> String {
     t_fmt(app, key, &[&arg1, &arg2])
 }
+
+/// What: Get the plural form of a counted translation and insert the count.
+///
+/// Inputs:
+/// - `app`: `AppState` containing translation maps and the locale
+/// - `key`: Dot-notation key whose value is a map of plural forms (e.g. `one`/`other`)
+/// - `count`: Count selecting the form; replaces the first `{}` placeholder
+///
+/// Output:
+/// - Formatted translated string, or the key itself if no form is found
+///
+/// Details:
+/// - The category comes from [`plural_category`] for `app.locale`.
+/// - Each map of the lookup chain (primary, `translations_chain`, English) is tried in order for
+///   `key.<category>`, then `key.other`, then a plain string under `key` (not yet pluralized).
+#[must_use]
+pub fn tn(app: &crate::state::AppState, key: &str, count: usize) -> String {
+    let category = plural_category(&app.locale, count);
+    let candidates = [
+        format!("{key}.{}", category.as_key()),
+        format!("{key}.other"),
+        key.to_string(),
+    ];
+    let template = std::iter::once(&app.translations)
+        .chain(&app.translations_chain)
+        .chain(std::iter::once(&app.translations_fallback))
+        .find_map(|map| candidates.iter().find_map(|k| map.get(k)))
+        .map_or_else(|| key.to_string(), Clone::clone);
+    template.replacen("{}", &count.to_string(), 1)
+}
+
+/// Languages written right-to-left.
+const RTL_LANGUAGES: &[&str] = &["ar", "he", "fa", "ur", "yi", "ps", "sd", "ug", "dv", "ckb"];
+
+/// What: Decide whether a locale is written right-to-left.
+///
+/// Inputs:
+/// - `locale`: Locale code (e.g. `ar-SA`, `he_IL`); only the language subtag is used.
+///
+/// Output:
+/// - `true` for Arabic, Hebrew, Persian, Urdu, and other right-to-left languages.
+///
+/// Details:
+/// - UI builders check `is_rtl(&app.locale)` to mirror button rows and filter chips and to
+///   right-align block titles.
+#[must_use]
+pub fn is_rtl(locale: &str) -> bool {
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    RTL_LANGUAGES
+        .iter()
+        .any(|rtl| rtl.eq_ignore_ascii_case(language))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Build an `AppState` with the given locale and primary/English translations.
+    fn app_with(
+        locale: &str,
+        primary: &[(&str, &str)],
+        english: &[(&str, &str)],
+    ) -> crate::state::AppState {
+        let map = |pairs: &[(&str, &str)]| -> TranslationMap {
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect()
+        };
+        crate::state::AppState {
+            locale: locale.to_string(),
+            translations: map(primary),
+            translations_fallback: map(english),
+            ..crate::state::AppState::default()
+        }
+    }
+
+    #[test]
+    /// What: Verify English `one`/`other` selection and count substitution.
+    ///
+    /// Inputs:
+    /// - `en-US` with `one` and `other` forms; counts 1 and 3; a missing key.
+    ///
+    /// Output:
+    /// - Singular for 1, plural for 3, the key itself when missing.
+    fn tn_selects_english_one_and_other() {
+        let en = [
+            ("app.updates.one", "{} update"),
+            ("app.updates.other", "{} updates"),
+        ];
+        let app = app_with("en-US", &en, &en);
+        assert_eq!(tn(&app, "app.updates", 1), "1 update");
+        assert_eq!(tn(&app, "app.updates", 3), "3 updates");
+        assert_eq!(tn(&app, "app.missing", 2), "app.missing");
+    }
+
+    #[test]
+    /// What: Verify a language with `few`/`many` picks those forms and falls back to `other`.
+    ///
+    /// Inputs:
+    /// - `pl-PL` with `one`, `few`, `many` forms; English with `one`/`other` only.
+    ///
+    /// Output:
+    /// - Polish forms for 1, 3, 5; English `other` for a key Polish lacks.
+    fn tn_selects_few_and_many() {
+        let app = app_with(
+            "pl-PL",
+            &[
+                ("app.updates.one", "{} aktualizacja"),
+                ("app.updates.few", "{} aktualizacje"),
+                ("app.updates.many", "{} aktualizacji"),
+            ],
+            &[
+                ("app.files.one", "{} file"),
+                ("app.files.other", "{} files"),
+            ],
+        );
+        assert_eq!(tn(&app, "app.updates", 1), "1 aktualizacja");
+        assert_eq!(tn(&app, "app.updates", 3), "3 aktualizacje");
+        assert_eq!(tn(&app, "app.updates", 5), "5 aktualizacji");
+        assert_eq!(tn(&app, "app.files", 5), "5 files");
+    }
+
+    #[test]
+    /// What: Verify right-to-left detection uses the language subtag.
+    ///
+    /// Inputs:
+    /// - Arabic, Hebrew, and Persian locales in both separator styles; English and German.
+    ///
+    /// Output:
+    /// - `true` only for the right-to-left languages.
+    fn is_rtl_matches_language_subtag() {
+        for locale in ["ar-SA", "he_IL", "fa", "AR"] {
+            assert!(is_rtl(locale), "{locale} should be RTL");
+        }
+        for locale in ["en-US", "de-DE", "hu-HU", ""] {
+            assert!(!is_rtl(locale), "{locale} should be LTR");
+        }
+    }
+}
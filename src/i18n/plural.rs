@@ -0,0 +1,137 @@
+//! CLDR-style plural category selection for counted translations.
+//!
+//! Plural forms live under the translation key as sub-keys named after the category, e.g.
+//! `app.updates_button.available.one` and `app.updates_button.available.other`.
+
+/// What: CLDR plural category of a count in a given language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    /// Zero items (e.g. Arabic).
+    Zero,
+    /// Singular form.
+    One,
+    /// Dual form (e.g. Arabic).
+    Two,
+    /// Paucal form (e.g. Polish, Czech, Russian 2-4).
+    Few,
+    /// Form for larger counts in languages that distinguish it (e.g. Polish 5+).
+    Many,
+    /// General form; every pluralized key must provide it.
+    Other,
+}
+
+impl PluralCategory {
+    /// What: Sub-key name of the category (`zero`, `one`, `two`, `few`, `many`, `other`).
+    #[must_use]
+    pub const fn as_key(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// What: Select the plural category of an integer count for a locale.
+///
+/// Inputs:
+/// - `locale`: Locale code (e.g. `en-US`, `pl-PL`); only the language subtag is used.
+/// - `count`: Non-negative integer count.
+///
+/// Output:
+/// - The CLDR category for `count`.
+///
+/// Details:
+/// - Covers the integer rules of the languages in `i18n.yml` plus common Slavic ones; unknown
+///   languages use the English `one`/`other` rule. Add a match arm to support another language.
+#[must_use]
+pub fn plural_category(locale: &str, count: usize) -> PluralCategory {
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let (mod10, mod100) = (count % 10, count % 100);
+    match language.as_str() {
+        "fr" | "pt" if count <= 1 => PluralCategory::One,
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" | "fr" | "pt" => PluralCategory::Other,
+        "ru" | "uk" | "be" => match (mod10, mod100) {
+            (1, m) if m != 11 => PluralCategory::One,
+            (2..=4, m) if !(12..=14).contains(&m) => PluralCategory::Few,
+            _ => PluralCategory::Many,
+        },
+        "pl" => match (count, mod10, mod100) {
+            (1, _, _) => PluralCategory::One,
+            (_, 2..=4, m) if !(12..=14).contains(&m) => PluralCategory::Few,
+            _ => PluralCategory::Many,
+        },
+        "cs" | "sk" => match count {
+            1 => PluralCategory::One,
+            2..=4 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        },
+        "ar" => match (count, mod100) {
+            (0, _) => PluralCategory::Zero,
+            (1, _) => PluralCategory::One,
+            (2, _) => PluralCategory::Two,
+            (_, 3..=10) => PluralCategory::Few,
+            (_, 11..=99) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        },
+        _ if count == 1 => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify English, German, and Hungarian use `one` for 1 and `other` otherwise.
+    ///
+    /// Inputs:
+    /// - Counts 0, 1, 2, 21 for `en-US`, `de-DE`, and `hu-HU`.
+    ///
+    /// Output:
+    /// - Only 1 maps to `one`.
+    fn one_other_languages() {
+        for locale in ["en-US", "de-DE", "hu-HU"] {
+            assert_eq!(plural_category(locale, 0), PluralCategory::Other);
+            assert_eq!(plural_category(locale, 1), PluralCategory::One);
+            assert_eq!(plural_category(locale, 2), PluralCategory::Other);
+            assert_eq!(plural_category(locale, 21), PluralCategory::Other);
+        }
+    }
+
+    #[test]
+    /// What: Verify Polish and Russian select `few` and `many`.
+    ///
+    /// Inputs:
+    /// - Counts around the 2-4 and teen boundaries.
+    ///
+    /// Output:
+    /// - CLDR categories for each count.
+    fn few_many_languages() {
+        let pl = |n| plural_category("pl-PL", n);
+        assert_eq!(pl(1), PluralCategory::One);
+        assert_eq!(pl(3), PluralCategory::Few);
+        assert_eq!(pl(5), PluralCategory::Many);
+        assert_eq!(pl(12), PluralCategory::Many);
+        assert_eq!(pl(22), PluralCategory::Few);
+        assert_eq!(pl(21), PluralCategory::Many);
+
+        let ru = |n| plural_category("ru", n);
+        assert_eq!(ru(1), PluralCategory::One);
+        assert_eq!(ru(21), PluralCategory::One);
+        assert_eq!(ru(11), PluralCategory::Many);
+        assert_eq!(ru(24), PluralCategory::Few);
+        assert_eq!(ru(0), PluralCategory::Many);
+
+        assert_eq!(plural_category("ja-JP", 1), PluralCategory::Other);
+        assert_eq!(plural_category("ar-SA", 2), PluralCategory::Two);
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::hash::BuildHasher;
 use std::path::PathBuf;
 
 use crate::i18n::detection::detect_system_locale;
@@ -170,6 +171,46 @@ fn resolve_with_fallbacks(
     default_locale.to_string()
 }
 
+/// What: Build the ordered list of locales consulted for translations.
+///
+/// Inputs:
+/// - `locale`: Requested locale code (e.g., "pt-BR")
+/// - `fallbacks`: Map of locale -> fallback locale
+/// - `default_locale`: Ultimate fallback (usually "en-US")
+///
+/// Output:
+/// - Locales from most to least specific, ending with `default_locale`
+///   (e.g., `["pt-BR", "pt", "en-US"]`)
+///
+/// Details:
+/// - Each step follows the `i18n.yml` fallback of the current locale, or else drops the region
+///   (`pt-BR` -> `pt`); the walk stops at `default_locale`, a repeat, a bare language code, or
+///   after 10 steps, so the default locale never gains a language-only hop before itself.
+/// - Unlike [`resolve_with_fallbacks`], entries are not filtered by availability; callers skip
+///   locales without translation files.
+#[must_use]
+pub fn fallback_chain<S: BuildHasher>(
+    locale: &str,
+    fallbacks: &HashMap<String, String, S>,
+    default_locale: &str,
+) -> Vec<String> {
+    const MAX_CHAIN_LENGTH: usize = 10;
+    let mut chain: Vec<String> = Vec::new();
+    let mut current = Some(locale.to_string());
+    while let Some(loc) = current.take() {
+        if loc == default_locale || chain.len() >= MAX_CHAIN_LENGTH || chain.contains(&loc) {
+            break;
+        }
+        current = fallbacks.get(&loc).cloned().or_else(|| {
+            loc.rsplit_once('-')
+                .map(|(language, _)| language.to_string())
+        });
+        chain.push(loc);
+    }
+    chain.push(default_locale.to_string());
+    chain
+}
+
 /// What: Load fallback mappings from i18n.yml.
 ///
 /// Inputs:
@@ -275,16 +316,15 @@ impl LocaleResolver {
         }
     }
 
-    /// What: Resolve locale using cached fallback configuration.
+    /// What: Pick the requested locale before any fallback is applied.
     ///
     /// Inputs:
-    /// - `settings_locale`: Locale from settings.conf
+    /// - `settings_locale`: Locale from settings.conf (empty means auto-detect)
     ///
     /// Output:
-    /// - Resolved locale code
-    #[must_use]
-    pub fn resolve(&self, settings_locale: &str) -> String {
-        let initial_locale = if settings_locale.trim().is_empty() {
+    /// - The settings locale when valid, else the system locale, else the default locale
+    fn initial_locale(&self, settings_locale: &str) -> String {
+        if settings_locale.trim().is_empty() {
             detect_system_locale().unwrap_or_else(|| self.default_locale.clone())
         } else {
             let trimmed = settings_locale.trim().to_string();
@@ -298,8 +338,35 @@ impl LocaleResolver {
                 );
                 detect_system_locale().unwrap_or_else(|| self.default_locale.clone())
             }
-        };
+        }
+    }
 
+    /// What: Build the translation fallback chain for the requested locale.
+    ///
+    /// Inputs:
+    /// - `settings_locale`: Locale from settings.conf (empty means auto-detect)
+    ///
+    /// Output:
+    /// - Ordered locales ending with the default locale (see [`fallback_chain`])
+    #[must_use]
+    pub fn fallback_chain(&self, settings_locale: &str) -> Vec<String> {
+        fallback_chain(
+            &self.initial_locale(settings_locale),
+            &self.fallbacks,
+            &self.default_locale,
+        )
+    }
+
+    /// What: Resolve locale using cached fallback configuration.
+    ///
+    /// Inputs:
+    /// - `settings_locale`: Locale from settings.conf
+    ///
+    /// Output:
+    /// - Resolved locale code
+    #[must_use]
+    pub fn resolve(&self, settings_locale: &str) -> String {
+        let initial_locale = self.initial_locale(settings_locale);
         tracing::debug!(
             "Resolving locale '{}' with {} fallbacks available",
             initial_locale,
@@ -415,6 +482,30 @@ mod tests {
         assert_eq!(result, "en-US");
     }
 
+    #[test]
+    fn test_fallback_chain_region_language_default() {
+        let mut fallbacks = HashMap::new();
+        fallbacks.insert("de-CH".to_string(), "de-DE".to_string());
+        fallbacks.insert("de".to_string(), "de-DE".to_string());
+
+        // Region -> language -> default
+        assert_eq!(
+            fallback_chain("pt-BR", &fallbacks, "en-US"),
+            vec!["pt-BR", "pt", "en-US"]
+        );
+        // Configured fallbacks take precedence over dropping the region
+        assert_eq!(
+            fallback_chain("de-CH", &fallbacks, "en-US"),
+            vec!["de-CH", "de-DE", "de", "en-US"]
+        );
+        // The default locale keeps the two-level behavior
+        assert_eq!(fallback_chain("en-US", &fallbacks, "en-US"), vec!["en-US"]);
+        assert_eq!(
+            fallback_chain("en-GB", &fallbacks, "en-US"),
+            vec!["en-GB", "en", "en-US"]
+        );
+    }
+
     #[test]
     fn test_is_valid_locale_format() {
         // Valid formats
@@ -22,42 +22,36 @@ pub fn translate(key: &str, translations: &TranslationMap) -> Option<String> {
     translations.get(key).cloned()
 }
 
-/// What: Look up translation with fallback to English.
+/// What: Look up a translation by walking an ordered chain of translation maps.
 ///
 /// Inputs:
 /// - `key`: Dot-notation key
-/// - `translations`: Primary translation map
-/// - `fallback_translations`: Fallback translation map (usually English)
+/// - `chain`: Translation maps from most to least specific (e.g. `pt-BR`, `pt`, `en-US`)
 ///
 /// Output:
-/// - Translated string (from primary or fallback, or key itself if both missing)
+/// - The first translation found along the chain, or the key itself if every map misses it
 ///
 /// Details:
-/// - Tries primary translations first
-/// - Falls back to English if not found
-/// - Returns key itself if neither has translation (for debugging)
-/// - Logs warnings for missing keys (only once per key to avoid spam)
-pub fn translate_with_fallback(
+/// - Logs at debug level when a later map answers or when the key is missing everywhere
+pub fn translate_with_chain<'a>(
     key: &str,
-    translations: &TranslationMap,
-    fallback_translations: &TranslationMap,
+    chain: impl IntoIterator<Item = &'a TranslationMap>,
 ) -> String {
-    // Try primary translations first
-    if let Some(translation) = translations.get(key) {
-        return translation.clone();
-    }
-
-    // Try fallback translations
-    if let Some(translation) = fallback_translations.get(key) {
-        // Log that we're using fallback (only at debug level to avoid spam)
-        tracing::debug!(
-            "Translation key '{}' not found in primary locale, using fallback",
-            key
-        );
-        return translation.clone();
+    for (depth, translations) in chain.into_iter().enumerate() {
+        if let Some(translation) = translations.get(key) {
+            if depth > 0 {
+                // Only at debug level to avoid spam
+                tracing::debug!(
+                    "Translation key '{}' not found in primary locale, using fallback #{}",
+                    key,
+                    depth
+                );
+            }
+            return translation.clone();
+        }
     }
 
-    // Neither has the key - log warning and return key itself
+    // No map has the key - log and return key itself
     // Use debug level to avoid flooding logs, but make it discoverable
     tracing::debug!(
         "Missing translation key: '{}'. Returning key as-is. Please add this key to locale files.",
@@ -66,6 +60,27 @@ pub fn translate_with_fallback(
     key.to_string()
 }
 
+/// What: Look up translation with fallback to English.
+///
+/// Inputs:
+/// - `key`: Dot-notation key
+/// - `translations`: Primary translation map
+/// - `fallback_translations`: Fallback translation map (usually English)
+///
+/// Output:
+/// - Translated string (from primary or fallback, or key itself if both missing)
+///
+/// Details:
+/// - Two-level form of [`translate_with_chain`]: primary first, then the fallback
+#[must_use]
+pub fn translate_with_fallback(
+    key: &str,
+    translations: &TranslationMap,
+    fallback_translations: &TranslationMap,
+) -> String {
+    translate_with_chain(key, [translations, fallback_translations])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +119,36 @@ mod tests {
             "app.titles.missing"
         );
     }
+
+    #[test]
+    fn test_translate_with_chain_walks_region_language_english() {
+        let map = |pairs: &[(&str, &str)]| -> TranslationMap {
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect()
+        };
+        let pt_br = map(&[("app.titles.search", "Pesquisar (BR)")]);
+        let pt = map(&[
+            ("app.titles.search", "Pesquisar"),
+            ("app.titles.help", "Ajuda"),
+        ]);
+        let en = map(&[
+            ("app.titles.search", "Search"),
+            ("app.titles.help", "Help"),
+            ("app.titles.news", "News"),
+        ]);
+        let chain = [&pt_br, &pt, &en];
+
+        assert_eq!(
+            translate_with_chain("app.titles.search", chain),
+            "Pesquisar (BR)"
+        );
+        assert_eq!(translate_with_chain("app.titles.help", chain), "Ajuda");
+        assert_eq!(translate_with_chain("app.titles.news", chain), "News");
+        assert_eq!(
+            translate_with_chain("app.titles.missing", chain),
+            "app.titles.missing"
+        );
+    }
 }
@@ -0,0 +1,64 @@
+//! Host architecture resolution and architecture matching for official packages.
+
+use std::sync::OnceLock;
+
+/// Architecture value of packages that install on every architecture.
+pub const ARCH_ANY: &str = "any";
+
+/// Cached `uname -m` output.
+static UNAME_ARCH: OnceLock<String> = OnceLock::new();
+
+/// What: Machine architecture reported by `uname -m`.
+///
+/// Output:
+/// - Trimmed `uname -m` output (e.g. `x86_64`, `aarch64`), cached for the process.
+///
+/// Details:
+/// - Falls back to the architecture Pacsea was compiled for when `uname` is unavailable.
+#[must_use]
+pub fn uname_arch() -> &'static str {
+    UNAME_ARCH.get_or_init(|| {
+        std::process::Command::new("uname")
+            .arg("-m")
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+            .filter(|arch| !arch.is_empty())
+            .unwrap_or_else(|| std::env::consts::ARCH.to_string())
+    })
+}
+
+/// What: Architecture official packages are filtered and ranked for.
+///
+/// Output:
+/// - `search_default_arch` from settings, or [`uname_arch`] when it is empty or `auto`.
+#[must_use]
+pub fn host_arch() -> String {
+    let configured = crate::theme::settings().default_arch;
+    let configured = configured.trim();
+    if configured.is_empty() || configured.eq_ignore_ascii_case("auto") {
+        uname_arch().to_string()
+    } else {
+        configured.to_string()
+    }
+}
+
+/// What: Decide whether a package built for `pkg_arch` installs on `arch`.
+///
+/// Inputs:
+/// - `pkg_arch`: `OfficialPkg::arch` of the package.
+/// - `arch`: Target architecture.
+///
+/// Output:
+/// - `true` for an exact (case-insensitive) match, for [`ARCH_ANY`], and for an empty `pkg_arch`.
+///
+/// Details:
+/// - An empty architecture means the row was not enriched yet (`pacman -Sl` omits it); such rows
+///   come from the local sync databases and are kept rather than hidden.
+#[must_use]
+pub const fn arch_matches(pkg_arch: &str, arch: &str) -> bool {
+    pkg_arch.is_empty()
+        || pkg_arch.eq_ignore_ascii_case(ARCH_ANY)
+        || pkg_arch.eq_ignore_ascii_case(arch)
+}
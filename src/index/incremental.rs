@@ -0,0 +1,329 @@
+//! Incremental official index updates.
+//!
+//! Instead of replacing the whole package list, a fresh listing (sync databases or `pacman -Sl`) is diffed against the
+//! in-memory index by `(repo, name)`; only added, removed, and version-changed rows are touched and
+//! `name_to_idx` is patched in place. The sync database timestamp persisted with the index lets
+//! routine startups skip the listing entirely when nothing was synced.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::{OfficialIndex, OfficialPkg};
+
+/// Directory holding pacman's sync databases (`<repo>.db`).
+pub const SYNC_DB_DIR: &str = "/var/lib/pacman/sync";
+
+/// What: Resolve the sync database directory in use.
+///
+/// Output:
+/// - `PACSEA_TEST_SYNC_DB_DIR` when set (tests point it at a temp directory), else
+///   [`SYNC_DB_DIR`].
+#[must_use]
+pub fn sync_db_dir() -> PathBuf {
+    std::env::var_os("PACSEA_TEST_SYNC_DB_DIR")
+        .map_or_else(|| PathBuf::from(SYNC_DB_DIR), PathBuf::from)
+}
+
+/// What: Summary of applying a fresh package listing to the index.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexDiff {
+    /// Rows that were not in the index before.
+    pub added: usize,
+    /// Rows no longer present in the sync databases.
+    pub removed: usize,
+    /// Rows whose version changed or that gained missing metadata.
+    pub updated: usize,
+    /// Names of added and updated rows still missing metadata, which need `pacman -Si` enrichment.
+    pub to_enrich: Vec<String>,
+}
+
+impl IndexDiff {
+    /// What: Total number of index rows touched by the diff.
+    #[must_use]
+    pub const fn changed(&self) -> usize {
+        self.added + self.removed + self.updated
+    }
+}
+
+/// What: Compute a stamp identifying the current state of the pacman sync databases.
+///
+/// Inputs:
+/// - `dir`: Sync database directory (normally [`sync_db_dir`]).
+///
+/// Output:
+/// - Newest modification time of any `*.db` file, in nanoseconds since the epoch; `None` when the
+///   directory is missing or holds no databases.
+///
+/// Details:
+/// - `pacman -Sy` rewrites the database files, so any sync moves the stamp forward.
+#[must_use]
+pub fn sync_db_stamp(dir: &Path) -> Option<u64> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "db"))
+        .filter_map(|e| e.metadata().ok()?.modified().ok())
+        .filter_map(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .filter_map(|d| u64::try_from(d.as_nanos()).ok())
+        .max()
+}
+
+/// What: Key identifying an index row across listings.
+fn pkg_key(p: &OfficialPkg) -> (String, String) {
+    (p.repo.to_lowercase(), p.name.to_lowercase())
+}
+
+impl OfficialIndex {
+    /// What: Apply a fresh package listing to the index, touching only the rows that differ.
+    ///
+    /// Inputs:
+    /// - `fresh`: Current rows; `pacman -Sl` rows carry name, repo, and version only, sync database
+    ///   rows also carry description and architecture.
+    ///
+    /// Output:
+    /// - [`IndexDiff`] with added/removed/updated counts and the names that need enrichment.
+    ///
+    /// Details:
+    /// - Rows are matched by case-insensitive `(repo, name)`; unchanged rows keep their enriched
+    ///   description and architecture.
+    /// - A version change replaces description and architecture with the listing's (empty for
+    ///   `pacman -Sl`, so enrichment refreshes them); an unchanged version only fills empty fields.
+    /// - Removed rows are swap-removed and new rows appended; `name_to_idx` is patched for the
+    ///   affected names instead of being rebuilt.
+    pub fn apply_diff(&mut self, fresh: Vec<OfficialPkg>) -> IndexDiff {
+        let mut fresh_by_key: HashMap<(String, String), OfficialPkg> =
+            fresh.into_iter().map(|p| (pkg_key(&p), p)).collect();
+        let mut diff = IndexDiff::default();
+        let mut stale = Vec::new();
+        for (i, pkg) in self.pkgs.iter_mut().enumerate() {
+            match fresh_by_key.remove(&pkg_key(pkg)) {
+                None => stale.push(i),
+                Some(new) => {
+                    if new.version != pkg.version {
+                        pkg.version = new.version;
+                        pkg.arch = new.arch;
+                        pkg.description = new.description;
+                    } else if (pkg.arch.is_empty() && !new.arch.is_empty())
+                        || (pkg.description.is_empty() && !new.description.is_empty())
+                    {
+                        if pkg.arch.is_empty() {
+                            pkg.arch = new.arch;
+                        }
+                        if pkg.description.is_empty() {
+                            pkg.description = new.description;
+                        }
+                    } else {
+                        continue;
+                    }
+                    diff.updated += 1;
+                    if pkg.arch.is_empty() || pkg.description.is_empty() {
+                        diff.to_enrich.push(pkg.name.clone());
+                    }
+                }
+            }
+        }
+        diff.removed = stale.len();
+        // Descending order: each swap_remove only moves a row that is kept
+        for &i in stale.iter().rev() {
+            self.remove_row(i);
+        }
+        let mut added: Vec<OfficialPkg> = fresh_by_key.into_values().collect();
+        added.sort_by(|a, b| (&a.repo, &a.name).cmp(&(&b.repo, &b.name)));
+        diff.added = added.len();
+        for pkg in added {
+            self.name_to_idx
+                .insert(pkg.name.to_lowercase(), self.pkgs.len());
+            if pkg.arch.is_empty() || pkg.description.is_empty() {
+                diff.to_enrich.push(pkg.name.clone());
+            }
+            self.pkgs.push(pkg);
+        }
+        diff
+    }
+
+    /// What: Swap-remove the row at `i` and patch `name_to_idx` for the affected names.
+    ///
+    /// Details:
+    /// - The removed name falls back to another row with the same name (another repository), or is
+    ///   dropped; the row moved into `i` is re-pointed when the map referenced its old position.
+    fn remove_row(&mut self, i: usize) {
+        let removed = self.pkgs.swap_remove(i);
+        let old_last = self.pkgs.len();
+        let removed_key = removed.name.to_lowercase();
+        if self.name_to_idx.get(&removed_key) == Some(&i) {
+            match self
+                .pkgs
+                .iter()
+                .rposition(|p| p.name.eq_ignore_ascii_case(&removed.name))
+            {
+                Some(j) => {
+                    self.name_to_idx.insert(removed_key, j);
+                }
+                None => {
+                    self.name_to_idx.remove(&removed_key);
+                }
+            }
+        }
+        if let Some(moved) = self.pkgs.get(i) {
+            let moved_key = moved.name.to_lowercase();
+            if self.name_to_idx.get(&moved_key) == Some(&old_last) {
+                self.name_to_idx.insert(moved_key, i);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Build an index row for tests.
+    fn pkg(repo: &str, name: &str, version: &str, description: &str) -> OfficialPkg {
+        OfficialPkg {
+            name: name.to_string(),
+            repo: repo.to_string(),
+            arch: if description.is_empty() {
+                String::new()
+            } else {
+                "x86_64".to_string()
+            },
+            version: version.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    /// What: Verify a listing with an addition, a removal, and a version bump is applied in place.
+    ///
+    /// Inputs:
+    /// - Enriched index `alpha`, `beta`, `gamma`, `delta`; listing drops `beta`, bumps `gamma`, and
+    ///   adds `omega`.
+    ///
+    /// Output:
+    /// - Counts 1/1/1, `alpha` keeps its description, `gamma` is cleared for enrichment, and
+    ///   `name_to_idx` matches a full rebuild.
+    fn apply_diff_handles_add_remove_and_version_change() {
+        let mut index = OfficialIndex {
+            pkgs: vec![
+                pkg("core", "alpha", "1.0", "Alpha"),
+                pkg("core", "beta", "1.0", "Beta"),
+                pkg("extra", "gamma", "2.0", "Gamma"),
+                pkg("extra", "Delta", "3.0", "Delta"),
+            ],
+            ..OfficialIndex::default()
+        };
+        index.rebuild_name_index();
+
+        let diff = index.apply_diff(vec![
+            pkg("core", "alpha", "1.0", ""),
+            pkg("extra", "gamma", "2.1", ""),
+            pkg("extra", "Delta", "3.0", ""),
+            pkg("extra", "omega", "0.1", ""),
+        ]);
+
+        assert_eq!(
+            diff,
+            IndexDiff {
+                added: 1,
+                removed: 1,
+                updated: 1,
+                to_enrich: vec!["gamma".to_string(), "omega".to_string()],
+            }
+        );
+        assert_eq!(diff.changed(), 3);
+        assert_eq!(index.pkgs.len(), 4);
+        let by_name = |n: &str| &index.pkgs[index.name_to_idx[n]];
+        assert_eq!(by_name("alpha").description, "Alpha");
+        assert_eq!(by_name("delta").description, "Delta");
+        assert_eq!(by_name("gamma").version, "2.1");
+        assert!(by_name("gamma").description.is_empty());
+        assert_eq!(by_name("omega").repo, "extra");
+        assert!(!index.name_to_idx.contains_key("beta"));
+
+        let mut rebuilt = index.clone();
+        rebuilt.rebuild_name_index();
+        assert_eq!(index.name_to_idx, rebuilt.name_to_idx);
+    }
+
+    #[test]
+    /// What: Verify an identical listing leaves the index untouched.
+    ///
+    /// Inputs:
+    /// - Enriched two-row index and the same rows as an unenriched listing.
+    ///
+    /// Output:
+    /// - Empty diff and unchanged descriptions.
+    fn apply_diff_is_noop_for_identical_listing() {
+        let mut index = OfficialIndex {
+            pkgs: vec![
+                pkg("core", "alpha", "1.0", "Alpha"),
+                pkg("extra", "beta", "1.0", "Beta"),
+            ],
+            ..OfficialIndex::default()
+        };
+        index.rebuild_name_index();
+        let diff = index.apply_diff(vec![
+            pkg("extra", "beta", "1.0", ""),
+            pkg("core", "alpha", "1.0", ""),
+        ]);
+        assert_eq!(diff, IndexDiff::default());
+        assert_eq!(index.pkgs[index.name_to_idx["alpha"]].description, "Alpha");
+        assert_eq!(index.pkgs[index.name_to_idx["beta"]].description, "Beta");
+    }
+
+    #[test]
+    /// What: Verify sync database rows fill metadata without scheduling enrichment.
+    ///
+    /// Inputs:
+    /// - Index with an unenriched `alpha`; listing with `alpha` at the same version plus a new
+    ///   `beta`, both carrying description and architecture.
+    ///
+    /// Output:
+    /// - `alpha` gains its metadata, `beta` is added, and nothing needs enrichment.
+    fn apply_diff_fills_metadata_from_sync_db_rows() {
+        let mut index = OfficialIndex {
+            pkgs: vec![pkg("core", "alpha", "1.0", "")],
+            ..OfficialIndex::default()
+        };
+        index.rebuild_name_index();
+        let diff = index.apply_diff(vec![
+            pkg("core", "alpha", "1.0", "Alpha"),
+            pkg("core", "beta", "1.0", "Beta"),
+        ]);
+        assert_eq!((diff.added, diff.removed, diff.updated), (1, 0, 1));
+        assert!(diff.to_enrich.is_empty());
+        assert_eq!(index.pkgs[index.name_to_idx["alpha"]].description, "Alpha");
+        assert_eq!(index.pkgs[index.name_to_idx["alpha"]].arch, "x86_64");
+    }
+
+    #[test]
+    /// What: Verify the sync stamp tracks the newest `*.db` file and ignores other files.
+    ///
+    /// Inputs:
+    /// - Missing directory, then a temp directory with one database and one unrelated file.
+    ///
+    /// Output:
+    /// - `None` for the missing directory, the database mtime otherwise.
+    fn sync_db_stamp_uses_database_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "pacsea_test_sync_stamp_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("time after epoch")
+                .as_nanos()
+        ));
+        assert_eq!(sync_db_stamp(&dir), None);
+        std::fs::create_dir_all(&dir).expect("create temp sync dir");
+        std::fs::write(dir.join("notes.txt"), "x").expect("write unrelated file");
+        assert_eq!(sync_db_stamp(&dir), None);
+        std::fs::write(dir.join("core.db"), "db").expect("write fake database");
+        let expected = std::fs::metadata(dir.join("core.db"))
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .and_then(|d| u64::try_from(d.as_nanos()).ok());
+        assert_eq!(sync_db_stamp(&dir), expected);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
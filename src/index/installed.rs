@@ -1,27 +1,62 @@
-use super::installed_lock;
+use super::{
+    INSTALLED_SIZES_GENERATION, installed_lock, installed_sizes_lock, installed_versions_lock,
+};
+use std::sync::atomic::Ordering;
 
-/// What: Refresh the process-wide cache of installed package names using `pacman -Qq`.
+/// What: Refresh the process-wide caches of installed package names and versions using `pacman -Q`.
 ///
 /// Inputs:
 /// - None (spawns a blocking task to run pacman)
 ///
 /// Output:
-/// - Updates the global installed-name set; ignores errors.
+/// - Updates the global installed-name set and version map; ignores errors.
 ///
 /// Details:
-/// - Parses command stdout into a `HashSet` and swaps it into the shared cache under a write lock.
+/// - Parses `name version` lines and swaps both caches under their write locks.
+/// - Lines without a version still mark the name as installed.
 pub async fn refresh_installed_cache() {
     if let Ok(Ok(body)) =
-        tokio::task::spawn_blocking(|| crate::util::pacman::run_pacman(&["-Qq"])).await
+        tokio::task::spawn_blocking(|| crate::util::pacman::run_pacman(&["-Q"])).await
     {
-        let set: std::collections::HashSet<String> =
-            body.lines().map(|s| s.trim().to_string()).collect();
+        let mut set = std::collections::HashSet::new();
+        let mut versions = std::collections::HashMap::new();
+        for line in body.lines() {
+            let mut parts = line.split_whitespace();
+            let Some(name) = parts.next() else {
+                continue;
+            };
+            if let Some(version) = parts.next() {
+                versions.insert(name.to_string(), version.to_string());
+            }
+            set.insert(name.to_string());
+        }
         if let Ok(mut g) = installed_lock().write() {
             *g = set;
         }
+        if let Ok(mut g) = installed_versions_lock().write() {
+            *g = versions;
+        }
     }
 }
 
+/// What: Look up the cached installed version of a package.
+///
+/// Inputs:
+/// - `name`: Package name
+///
+/// Output:
+/// - `Some(version)` when the package is installed and its version is cached; `None` otherwise.
+///
+/// Details:
+/// - Reads the map filled by `refresh_installed_cache`; never spawns pacman.
+#[must_use]
+pub fn installed_version(name: &str) -> Option<String> {
+    installed_versions_lock()
+        .read()
+        .ok()
+        .and_then(|g| g.get(name).cloned())
+}
+
 /// What: Query whether `name` appears in the cached set of installed packages.
 ///
 /// Inputs:
@@ -40,6 +75,94 @@ pub fn is_installed(name: &str) -> bool {
         .is_some_and(|s| s.contains(name))
 }
 
+/// What: Request installed sizes for packages not yet in the installed-size cache.
+///
+/// Inputs:
+/// - `notify_tx`: Channel notified once new sizes are stored
+/// - `names`: Installed package names to size
+///
+/// Output:
+/// - Spawns a background task; returns immediately.
+///
+/// Details:
+/// - Names are marked as requested before the task runs so repeated calls (e.g. from the
+///   re-run query after the notification) never re-query the same package.
+/// - Uses batched `pacman -Qi` via `logic::preflight::fetch_installed_sizes_with_runner`.
+pub fn request_installed_sizes_for(
+    notify_tx: tokio::sync::mpsc::UnboundedSender<()>,
+    names: Vec<String>,
+) {
+    let pending: Vec<String> = {
+        let Ok(mut g) = installed_sizes_lock().write() else {
+            return;
+        };
+        names
+            .into_iter()
+            .filter(|n| {
+                if g.contains_key(n) {
+                    return false;
+                }
+                g.insert(n.clone(), None);
+                true
+            })
+            .collect()
+    };
+    if pending.is_empty() {
+        return;
+    }
+    tokio::spawn(async move {
+        let Ok(sizes) = tokio::task::spawn_blocking(move || {
+            crate::logic::preflight::fetch_installed_sizes_with_runner(
+                &crate::logic::preflight::SystemCommandRunner,
+                &pending,
+            )
+        })
+        .await
+        else {
+            return;
+        };
+        if sizes.is_empty() {
+            return;
+        }
+        if let Ok(mut g) = installed_sizes_lock().write() {
+            for (name, bytes) in sizes {
+                g.insert(name, Some(bytes));
+            }
+        }
+        INSTALLED_SIZES_GENERATION.fetch_add(1, Ordering::Relaxed);
+        let _ = notify_tx.send(());
+    });
+}
+
+/// What: Look up the cached installed size of a package.
+///
+/// Inputs:
+/// - `name`: Package name
+///
+/// Output:
+/// - `Some(bytes)` when the size has been fetched; `None` when unknown or not yet requested.
+#[must_use]
+pub fn installed_size(name: &str) -> Option<u64> {
+    installed_sizes_lock()
+        .read()
+        .ok()
+        .and_then(|g| g.get(name).copied().flatten())
+}
+
+/// What: Current generation of the installed-size cache.
+///
+/// Inputs: None
+///
+/// Output:
+/// - Counter that changes whenever fetched sizes are stored.
+///
+/// Details:
+/// - Keys the `InstalledSize` sort cache, so newly arrived sizes force a fresh sort.
+#[must_use]
+pub fn installed_sizes_generation() -> u64 {
+    INSTALLED_SIZES_GENERATION.load(Ordering::Relaxed)
+}
+
 #[cfg(test)]
 mod tests {
     /// What: Return false when the cache is empty or the package is missing.
@@ -92,10 +215,12 @@ mod tests {
     /// What: Populate the installed cache from pacman output.
     ///
     /// Inputs:
-    /// - Override PATH with a fake pacman that emits installed package names before invoking the refresh.
+    /// - Override PATH with a fake pacman that emits installed package names and versions before
+    ///   invoking the refresh.
     ///
     /// Output:
-    /// - Cache lookup succeeds for the emitted names after `refresh_installed_cache` completes.
+    /// - Name and version lookups succeed for the emitted packages after `refresh_installed_cache`
+    ///   completes.
     ///
     /// Details:
     /// - Exercises the async refresh path, ensures PATH is restored, and verifies cache contents via helper accessors.
@@ -138,9 +263,9 @@ mod tests {
         script.push("pacman");
         let body = r#"#!/usr/bin/env bash
 set -e
-if [[ "$1" == "-Qq" ]]; then
-  echo "alpha"
-  echo "beta"
+if [[ "$1" == "-Q" ]]; then
+  echo "alpha 1.0-1"
+  echo "beta 2:3.4-2"
   exit 0
 fi
 exit 1
@@ -168,5 +293,7 @@ exit 1
         assert!(super::is_installed("alpha"));
         assert!(super::is_installed("beta"));
         assert!(!super::is_installed("gamma"));
+        assert_eq!(super::installed_version("beta").as_deref(), Some("2:3.4-2"));
+        assert_eq!(super::installed_version("gamma"), None);
     }
 }
@@ -25,6 +25,10 @@ pub struct OfficialIndex {
     /// Skipped during serialization; rebuilt after deserialization via `rebuild_name_index()`.
     #[serde(skip)]
     pub name_to_idx: HashMap<String, usize>,
+    /// Pacman sync database stamp (see `sync_db_stamp`) the package list was last diffed against.
+    /// `None` for indexes written before incremental updates, which forces a full listing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_stamp: Option<u64>,
 }
 
 impl OfficialIndex {
@@ -66,7 +70,8 @@ pub struct OfficialPkg {
     /// Repository name (e.g., "core", "extra", "community").
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub repo: String,
-    /// Target architecture (e.g., `x86_64`, `any`).
+    /// Target architecture (e.g., `x86_64`, `aarch64`, `any`); the source of truth for arch filtering
+    /// and ranking (see `arch_matches`). Empty until the row is enriched.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub arch: String,
     /// Package version.
@@ -83,6 +88,13 @@ static OFFICIAL_INDEX: OnceLock<RwLock<OfficialIndex>> = OnceLock::new();
 static INSTALLED_SET: OnceLock<RwLock<HashSet<String>>> = OnceLock::new();
 /// Process-wide set of explicitly-installed package names (dependency-free set).
 static EXPLICIT_SET: OnceLock<RwLock<HashSet<String>>> = OnceLock::new();
+/// Process-wide installed versions keyed by package name.
+static INSTALLED_VERSIONS: OnceLock<RwLock<HashMap<String, String>>> = OnceLock::new();
+/// Process-wide installed sizes in bytes (`None` = requested but unknown).
+static INSTALLED_SIZES: OnceLock<RwLock<HashMap<String, Option<u64>>>> = OnceLock::new();
+/// Bumped whenever fetched sizes land in [`INSTALLED_SIZES`], so size-based sort caches expire.
+static INSTALLED_SIZES_GENERATION: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(0);
 
 mod distro;
 pub use distro::{
@@ -106,6 +118,7 @@ fn idx() -> &'static RwLock<OfficialIndex> {
         RwLock::new(OfficialIndex {
             pkgs: Vec::new(),
             name_to_idx: HashMap::new(),
+            sync_stamp: None,
         })
     })
 }
@@ -124,6 +137,20 @@ fn installed_lock() -> &'static RwLock<HashSet<String>> {
     INSTALLED_SET.get_or_init(|| RwLock::new(HashSet::new()))
 }
 
+/// What: Access the process-wide lock protecting the installed-version cache.
+///
+/// Inputs:
+/// - None (initializes the `OnceLock` on-demand)
+///
+/// Output:
+/// - `&'static RwLock<HashMap<String, String>>` mapping installed names to versions.
+///
+/// Details:
+/// - Filled together with the installed-name set by `refresh_installed_cache`.
+fn installed_versions_lock() -> &'static RwLock<HashMap<String, String>> {
+    INSTALLED_VERSIONS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
 /// What: Access the process-wide lock protecting the explicit-package name cache.
 ///
 /// Inputs:
@@ -138,18 +165,39 @@ fn explicit_lock() -> &'static RwLock<HashSet<String>> {
     EXPLICIT_SET.get_or_init(|| RwLock::new(HashSet::new()))
 }
 
+/// What: Access the process-wide lock protecting the installed-size cache.
+///
+/// Inputs:
+/// - None (initializes the `OnceLock` on-demand)
+///
+/// Output:
+/// - `&'static RwLock<HashMap<String, Option<u64>>>` keyed by package name.
+///
+/// Details:
+/// - A `None` value marks a name that was requested (or is in flight) but has no known size.
+fn installed_sizes_lock() -> &'static RwLock<HashMap<String, Option<u64>>> {
+    INSTALLED_SIZES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Host architecture and architecture matching.
+mod arch;
 /// Package index enrichment utilities.
 mod enrich;
 /// Explicit package tracking.
 mod explicit;
 /// Package index fetching.
 mod fetch;
+/// Incremental package index diffs.
+mod incremental;
 /// Installed package utilities.
 mod installed;
 /// Package index persistence.
 mod persist;
 /// Package query utilities.
 mod query;
+#[cfg(not(windows))]
+/// Sync database index backend.
+mod syncdb;
 
 #[cfg(windows)]
 /// Mirror configuration for Windows.
@@ -157,15 +205,19 @@ mod mirrors;
 /// Package index update utilities.
 mod update;
 
+pub use arch::{ARCH_ANY, arch_matches, host_arch, uname_arch};
 pub use enrich::*;
 pub use explicit::*;
+pub use incremental::{IndexDiff, SYNC_DB_DIR, sync_db_dir, sync_db_stamp};
 pub use installed::*;
 #[cfg(windows)]
 pub use mirrors::*;
 pub use persist::*;
 pub use query::*;
 #[cfg(not(windows))]
-pub use update::update_in_background;
+pub use syncdb::{fetch_official_pkgs_from_sync_dbs, parse_desc, read_sync_dbs};
+#[cfg(not(windows))]
+pub use update::{update_in_background, update_incremental};
 
 /// What: Find a package by name in the official index and return it as a `PackageItem`.
 ///
@@ -256,6 +308,7 @@ mod tests {
                 },
             ],
             name_to_idx: HashMap::new(),
+            sync_stamp: None,
         };
 
         index.rebuild_name_index();
@@ -66,6 +66,49 @@ pub fn search_official(query: &str, fuzzy: bool) -> Vec<(PackageItem, Option<i64
     items
 }
 
+/// What: Suggest official package names close to a (possibly typo'd) query.
+///
+/// Inputs:
+/// - `query`: Raw query string
+/// - `limit`: Maximum number of suggestions
+///
+/// Output:
+/// - Ranked package names; empty when the query exactly matches an official package.
+///
+/// Details:
+/// - Delegates ranking to [`crate::logic::suggest::suggest_similar_names`] under a read lock.
+#[must_use]
+pub fn official_name_suggestions(query: &str, limit: usize) -> Vec<String> {
+    idx().read().map_or_else(
+        |_| Vec::new(),
+        |g| {
+            crate::logic::suggest::suggest_similar_names(
+                query,
+                g.pkgs.iter().map(|p| p.name.as_str()),
+                limit,
+            )
+        },
+    )
+}
+
+/// What: Report whether the in-memory official index holds no packages.
+///
+/// Inputs:
+/// - None
+///
+/// Output:
+/// - `true` when the index is empty or its lock is poisoned.
+///
+/// Details:
+/// - Cheap check under a read lock; avoids cloning the index like [`all_official`].
+#[must_use]
+pub fn official_is_empty() -> bool {
+    let Ok(g) = idx().read() else {
+        return true;
+    };
+    g.pkgs.is_empty()
+}
+
 /// What: Return the entire official index as a list of `PackageItem`s.
 ///
 /// Inputs:
@@ -99,6 +142,25 @@ pub fn all_official() -> Vec<PackageItem> {
     items
 }
 
+/// What: Return the official packages that install on `arch`.
+///
+/// Inputs:
+/// - `arch`: Target architecture (e.g. [`super::host_arch`]).
+///
+/// Output:
+/// - Items whose `OfficialPkg::arch` matches `arch` per [`super::arch_matches`], including `any`
+///   packages and rows whose architecture is not known yet.
+#[must_use]
+pub fn all_official_for_arch(arch: &str) -> Vec<PackageItem> {
+    all_official()
+        .into_iter()
+        .filter(|item| match &item.source {
+            Source::Official { arch: pkg_arch, .. } => super::arch_matches(pkg_arch, arch),
+            Source::Aur => true,
+        })
+        .collect()
+}
+
 /// What: Return the entire official list; if empty, try to populate from disk and return it.
 ///
 /// Inputs:
@@ -228,6 +290,44 @@ mod tests {
         assert_eq!(names, vec!["aa", "zz"]);
     }
 
+    #[test]
+    /// What: Filter the official index by architecture.
+    ///
+    /// Inputs:
+    /// - Seed index with `x86_64`, `aarch64`, `any`, and not-yet-enriched packages.
+    ///
+    /// Output:
+    /// - Each architecture gets its own packages plus the `any` and unknown ones.
+    fn all_official_for_arch_keeps_any_packages() {
+        let _guard = crate::global_test_mutex_lock();
+        let pkg = |name: &str, arch: &str| crate::index::OfficialPkg {
+            name: name.to_string(),
+            repo: "extra".to_string(),
+            arch: arch.to_string(),
+            version: "1".to_string(),
+            description: String::new(),
+        };
+        if let Ok(mut g) = super::idx().write() {
+            g.pkgs = vec![
+                pkg("amd", "x86_64"),
+                pkg("arm", "aarch64"),
+                pkg("script", "any"),
+                pkg("fresh", ""),
+            ];
+        }
+        let names_for = |arch: &str| {
+            let mut names: Vec<String> = super::all_official_for_arch(arch)
+                .into_iter()
+                .map(|p| p.name)
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names_for("aarch64"), vec!["arm", "fresh", "script"]);
+        assert_eq!(names_for("X86_64"), vec!["amd", "fresh", "script"]);
+        assert_eq!(names_for("riscv64"), vec!["fresh", "script"]);
+    }
+
     #[tokio::test]
     /// What: Load packages from disk when the in-memory index is empty.
     ///
@@ -318,4 +418,36 @@ mod tests {
         let res_fuzzy2 = super::search_official("rip", true);
         assert_eq!(res_fuzzy2.len(), 1);
     }
+
+    #[test]
+    /// What: Suggest the intended package for a typo'd query from a seeded index.
+    ///
+    /// Inputs:
+    /// - Seed index with `ripgrep`, `grep`, and `rsync`; query `ripgrpe` and then `ripgrep`.
+    ///
+    /// Output:
+    /// - The typo suggests `ripgrep`; the exact name suggests nothing.
+    ///
+    /// Details:
+    /// - Holds the global test mutex because the index is process-wide.
+    fn official_name_suggestions_from_seeded_index() {
+        let _guard = crate::global_test_mutex_lock();
+        if let Ok(mut g) = super::idx().write() {
+            g.pkgs = ["ripgrep", "grep", "rsync"]
+                .into_iter()
+                .map(|name| crate::index::OfficialPkg {
+                    name: name.to_string(),
+                    repo: "extra".to_string(),
+                    arch: "x86_64".to_string(),
+                    version: "1".to_string(),
+                    description: String::new(),
+                })
+                .collect();
+        }
+        assert_eq!(
+            super::official_name_suggestions("ripgrpe", 3),
+            vec!["ripgrep"]
+        );
+        assert!(super::official_name_suggestions("ripgrep", 3).is_empty());
+    }
 }
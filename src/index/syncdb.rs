@@ -0,0 +1,355 @@
+//! Official index backend reading the pacman sync databases directly.
+//!
+//! Each `<repo>.db` is a gzip-compressed tar archive holding one `<name>-<version>/desc` file per
+//! package. Reading them yields name, version, description, and architecture in one pass, without
+//! spawning `pacman -Sl` per repository and without a later `pacman -Si` round-trip.
+
+use std::io::Read;
+use std::path::Path;
+
+use super::OfficialPkg;
+
+/// Tar block size in bytes.
+const BLOCK: usize = 512;
+
+/// What: Parse a sync database `desc` entry into an index row.
+///
+/// Inputs:
+/// - `repo`: Repository the database belongs to.
+/// - `text`: Contents of the `desc` file (`%FIELD%` headers followed by value lines).
+///
+/// Output:
+/// - `Some(OfficialPkg)` when a `%NAME%` is present, `None` otherwise.
+///
+/// Details:
+/// - Only the first value line of `%NAME%`, `%VERSION%`, `%DESC%`, and `%ARCH%` is used; other
+///   fields are ignored.
+#[must_use]
+pub fn parse_desc(repo: &str, text: &str) -> Option<OfficialPkg> {
+    let mut pkg = OfficialPkg {
+        name: String::new(),
+        repo: repo.to_string(),
+        arch: String::new(),
+        version: String::new(),
+        description: String::new(),
+    };
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let target = match line.trim() {
+            "%NAME%" => &mut pkg.name,
+            "%VERSION%" => &mut pkg.version,
+            "%DESC%" => &mut pkg.description,
+            "%ARCH%" => &mut pkg.arch,
+            _ => continue,
+        };
+        if let Some(value) = lines.next() {
+            value.trim().clone_into(target);
+        }
+    }
+    (!pkg.name.is_empty()).then_some(pkg)
+}
+
+/// What: Read an octal numeric tar header field.
+fn tar_octal(field: &[u8]) -> Option<usize> {
+    let digits = std::str::from_utf8(field)
+        .ok()?
+        .trim_matches(|c: char| c == '\0' || c == ' ');
+    if digits.is_empty() {
+        return Some(0);
+    }
+    usize::from_str_radix(digits, 8).ok()
+}
+
+/// What: Trim a fixed-width tar header field at its first NUL byte.
+fn tar_str(field: &[u8]) -> &[u8] {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    &field[..end]
+}
+
+/// What: Find the `path` record of a pax extended header.
+///
+/// Inputs:
+/// - `body`: Pax header body made of `<length> <key>=<value>\n` records.
+///
+/// Output:
+/// - The `path` value, or `None` when absent or the records are malformed.
+fn pax_path(body: &[u8]) -> Option<Vec<u8>> {
+    let mut rest = body;
+    let mut path = None;
+    while !rest.is_empty() {
+        let space = rest.iter().position(|&b| b == b' ')?;
+        let len: usize = std::str::from_utf8(&rest[..space]).ok()?.parse().ok()?;
+        if len <= space || len > rest.len() {
+            return None;
+        }
+        let record = rest[space + 1..len].strip_suffix(b"\n")?;
+        if let Some(value) = record.strip_prefix(b"path=") {
+            path = Some(value.to_vec());
+        }
+        rest = &rest[len..];
+    }
+    path
+}
+
+/// What: Extract every `desc` entry from an uncompressed tar archive.
+///
+/// Inputs:
+/// - `repo`: Repository the archive belongs to.
+/// - `tar`: Uncompressed tar bytes.
+///
+/// Output:
+/// - Parsed rows, or `None` when the archive is malformed.
+///
+/// Details:
+/// - Only regular files whose path ends in `/desc` are read; directories are skipped.
+///   Stops at the first all-zero header block.
+/// - Paths longer than the 100-byte name field are resolved from the ustar prefix, a
+///   preceding GNU `L` long-name entry, or a preceding pax `x` header's `path` record.
+fn parse_db_tar(repo: &str, tar: &[u8]) -> Option<Vec<OfficialPkg>> {
+    let mut pkgs = Vec::new();
+    let mut long_path: Option<Vec<u8>> = None;
+    let mut pos = 0;
+    while pos + BLOCK <= tar.len() {
+        let header = &tar[pos..pos + BLOCK];
+        if header.iter().all(|&b| b == 0) {
+            break;
+        }
+        let size = tar_octal(&header[124..136])?;
+        let body_start = pos + BLOCK;
+        let body_end = body_start.checked_add(size)?;
+        if body_end > tar.len() {
+            return None;
+        }
+        let body = &tar[body_start..body_end];
+        match header[156] {
+            b'L' => long_path = Some(tar_str(body).to_vec()),
+            b'x' => long_path = pax_path(body).or(long_path),
+            typeflag => {
+                let path = long_path.take().unwrap_or_else(|| {
+                    let name = tar_str(&header[..100]);
+                    let prefix = tar_str(&header[345..500]);
+                    if header[257..262] == *b"ustar" && !prefix.is_empty() {
+                        [prefix, b"/", name].concat()
+                    } else {
+                        name.to_vec()
+                    }
+                });
+                if matches!(typeflag, b'0' | 0) && path.ends_with(b"/desc") {
+                    let text = String::from_utf8_lossy(body);
+                    pkgs.extend(parse_desc(repo, &text));
+                }
+            }
+        }
+        pos = body_start + size.div_ceil(BLOCK) * BLOCK;
+    }
+    Some(pkgs)
+}
+
+/// What: Read one repository's sync database.
+///
+/// Inputs:
+/// - `path`: Path to `<repo>.db`.
+/// - `repo`: Repository name recorded on each row.
+///
+/// Output:
+/// - Parsed rows, or `None` when the file is unreadable, not gzip-compressed, or malformed.
+fn read_sync_db(path: &Path, repo: &str) -> Option<Vec<OfficialPkg>> {
+    let compressed = std::fs::read(path).ok()?;
+    // Only gzip is supported; other compressions fall back to `pacman -Sl`
+    if !compressed.starts_with(&[0x1f, 0x8b]) {
+        return None;
+    }
+    let mut tar = Vec::new();
+    flate2::read::MultiGzDecoder::new(compressed.as_slice())
+        .read_to_end(&mut tar)
+        .ok()?;
+    parse_db_tar(repo, &tar)
+}
+
+/// What: Build the official package list from the sync databases of the given repositories.
+///
+/// Inputs:
+/// - `dir`: Sync database directory (normally [`super::sync_db_dir`]).
+/// - `repos`: Repository names, each expected as `<dir>/<repo>.db`.
+///
+/// Output:
+/// - `Some(rows)` sorted and deduplicated by `(repo, name)` with all fields filled; `None` when
+///   `repos` is empty or any database cannot be read, so callers fall back to `pacman -Sl`.
+#[must_use]
+pub fn read_sync_dbs(dir: &Path, repos: &[String]) -> Option<Vec<OfficialPkg>> {
+    if repos.is_empty() {
+        return None;
+    }
+    let mut pkgs = Vec::new();
+    for repo in repos {
+        let Some(rows) = read_sync_db(&dir.join(format!("{repo}.db")), repo) else {
+            tracing::debug!(repo = %repo, "sync database unreadable; using pacman -Sl");
+            return None;
+        };
+        pkgs.extend(rows);
+    }
+    pkgs.sort_by(|a, b| a.repo.cmp(&b.repo).then(a.name.cmp(&b.name)));
+    pkgs.dedup_by(|a, b| a.repo == b.repo && a.name == b.name);
+    Some(pkgs)
+}
+
+/// What: Build the official package list from the sync databases of every active repository.
+///
+/// Inputs:
+/// - None (reads `/etc/pacman.conf` and the databases in [`super::sync_db_dir`]).
+///
+/// Output:
+/// - `Some(rows)` when every active repository's database is readable, else `None`.
+///
+/// Details:
+/// - Acts as the capability check for this backend: an unreadable `pacman.conf`, a missing
+///   database, or a non-gzip database yields `None`.
+#[must_use]
+pub fn fetch_official_pkgs_from_sync_dbs() -> Option<Vec<OfficialPkg>> {
+    let mut repos: Vec<String> = crate::logic::repos::scan_pacman_conf_path(Path::new(
+        crate::logic::repos::DEFAULT_MAIN_PACMAN_PATH,
+    ))
+    .active_repo_names_lower()
+    .into_iter()
+    .collect();
+    repos.sort();
+    read_sync_dbs(&super::sync_db_dir(), &repos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixture `desc` entry as written by `repo-add`.
+    const DESC: &str = "%FILENAME%\nripgrep-14.1.1-1-x86_64.pkg.tar.zst\n\n%NAME%\nripgrep\n\n\
+                        %BASE%\nripgrep\n\n%VERSION%\n14.1.1-1\n\n%DESC%\n\
+                        A search tool that combines the usability of ag with the raw speed of grep\n\n\
+                        %CSIZE%\n1234\n\n%ARCH%\nx86_64\n\n%DEPENDS%\ngcc-libs\npcre2\n\n";
+
+    /// What: Append one tar entry with the given name field, ustar prefix, and type flag.
+    fn push_entry(out: &mut Vec<u8>, name: &str, prefix: &str, typeflag: u8, body: &[u8]) {
+        let mut header = [0u8; BLOCK];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        let size = format!("{:011o}\0", body.len());
+        header[124..136].copy_from_slice(size.as_bytes());
+        header[156] = typeflag;
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+        out.extend_from_slice(&header);
+        out.extend_from_slice(body);
+        out.resize(out.len().div_ceil(BLOCK) * BLOCK, 0);
+    }
+
+    /// What: Build a tar archive with one regular file per `(path, body)` pair.
+    fn tar_of(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (path, body) in entries {
+            push_entry(&mut out, path, "", b'0', body.as_bytes());
+        }
+        out.resize(out.len() + 2 * BLOCK, 0);
+        out
+    }
+
+    #[test]
+    /// What: Verify a fixture `desc` blob fills every index field.
+    ///
+    /// Inputs:
+    /// - `repo-add` style `desc` for `ripgrep`, then a blob without `%NAME%`.
+    ///
+    /// Output:
+    /// - Name, repo, version, description, and arch set; `None` for the nameless blob.
+    fn parse_desc_fills_official_pkg() {
+        let pkg = parse_desc("extra", DESC).expect("desc has a name");
+        assert_eq!(pkg.name, "ripgrep");
+        assert_eq!(pkg.repo, "extra");
+        assert_eq!(pkg.version, "14.1.1-1");
+        assert_eq!(
+            pkg.description,
+            "A search tool that combines the usability of ag with the raw speed of grep"
+        );
+        assert_eq!(pkg.arch, "x86_64");
+        assert!(parse_desc("extra", "%VERSION%\n1.0\n").is_none());
+    }
+
+    #[test]
+    /// What: Verify only `desc` entries of the archive become rows.
+    ///
+    /// Inputs:
+    /// - Tar with a `desc` entry, a sibling `files` entry, and a truncated copy of the archive.
+    ///
+    /// Output:
+    /// - One `ripgrep` row; `None` for the truncated archive.
+    fn parse_db_tar_reads_desc_entries() {
+        let tar = tar_of(&[
+            ("ripgrep-14.1.1-1/desc", DESC),
+            ("ripgrep-14.1.1-1/files", "%FILES%\nusr/bin/rg\n"),
+        ]);
+        let pkgs = parse_db_tar("extra", &tar).expect("well-formed archive");
+        assert_eq!(pkgs.len(), 1);
+        assert_eq!(pkgs[0].name, "ripgrep");
+        assert!(parse_db_tar("extra", &tar[..BLOCK + 10]).is_none());
+    }
+
+    #[test]
+    /// What: Verify `desc` entries with paths over 100 bytes are found in every long-name form.
+    ///
+    /// Inputs:
+    /// - A 120-byte `<pkg>/desc` path stored via the ustar prefix, a GNU `L` entry, and a pax
+    ///   `x` header, each with the truncated name field a writer would leave behind.
+    ///
+    /// Output:
+    /// - Three `ripgrep` rows.
+    fn parse_db_tar_resolves_long_paths() {
+        let dir = format!("{}-14.1.1-1", "r".repeat(110));
+        let path = format!("{dir}/desc");
+        assert!(path.len() > 100);
+        let truncated = &path[..100];
+        let record = format!(" path={path}\n");
+        let pax_len = record.len() + (record.len() + 3).to_string().len();
+        let pax = format!("{pax_len}{record}");
+        assert_eq!(pax.len(), pax_len);
+
+        let mut tar = Vec::new();
+        push_entry(&mut tar, "desc", &dir, b'0', DESC.as_bytes());
+        push_entry(
+            &mut tar,
+            "././@LongLink",
+            "",
+            b'L',
+            format!("{path}\0").as_bytes(),
+        );
+        push_entry(&mut tar, truncated, "", b'0', DESC.as_bytes());
+        push_entry(&mut tar, "PaxHeaders/desc", "", b'x', pax.as_bytes());
+        push_entry(&mut tar, truncated, "", b'0', DESC.as_bytes());
+        tar.resize(tar.len() + 2 * BLOCK, 0);
+
+        let pkgs = parse_db_tar("extra", &tar).expect("well-formed archive");
+        assert_eq!(pkgs.len(), 3);
+        assert!(pkgs.iter().all(|p| p.name == "ripgrep"));
+    }
+
+    #[test]
+    /// What: Verify the backend reports itself unavailable when a database is missing.
+    ///
+    /// Inputs:
+    /// - Empty repository list, then a repository without a database in a temp directory.
+    ///
+    /// Output:
+    /// - `None` in both cases so callers fall back to `pacman -Sl`.
+    fn read_sync_dbs_falls_back_without_databases() {
+        let dir = std::env::temp_dir().join(format!(
+            "pacsea_test_syncdb_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("time after epoch")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).expect("create temp sync dir");
+        assert!(read_sync_dbs(&dir, &[]).is_none());
+        assert!(read_sync_dbs(&dir, &["core".to_string()]).is_none());
+        std::fs::write(dir.join("core.db"), "not gzip").expect("write fake database");
+        assert!(read_sync_dbs(&dir, &["core".to_string()]).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
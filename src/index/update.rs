@@ -1,22 +1,99 @@
 #[cfg(not(target_os = "windows"))]
 use super::fetch::fetch_official_pkg_names;
 #[cfg(not(target_os = "windows"))]
-use super::{OfficialPkg, idx, save_to_disk};
+use super::{
+    fetch_official_pkgs_from_sync_dbs, idx, request_enrich_for, save_to_disk, sync_db_dir,
+    sync_db_stamp,
+};
+
+/// What: Bring the official index up to date by applying only what changed since the last sync.
+///
+/// Inputs:
+/// - `persist_path`: File path to persist the updated index JSON
+/// - `notify_tx`: Channel to notify the UI when rows changed
+///
+/// Output:
+/// - `Ok(n)` with the number of added, removed, and version-changed rows (`0` when up to date);
+///   `Err` when the `pacman -Sl` listing fails.
+///
+/// # Errors
+/// - Returns `Err` when the package listing cannot be read from the sync databases or `pacman -Sl`
+///
+/// Details:
+/// - Skips the listing when the pacman sync database stamp matches the one stored with a
+///   non-empty index.
+/// - The listing is read from the sync databases when every active repository's database is
+///   readable (see `fetch_official_pkgs_from_sync_dbs`), and from `pacman -Sl` otherwise.
+/// - The listing is diffed into the index via `OfficialIndex::apply_diff`; the index is persisted
+///   with the new stamp and only added or updated packages still missing metadata are enriched.
+///   Enrichment is skipped when the index was empty, so a first generation stays lazy.
+#[cfg(not(target_os = "windows"))]
+pub async fn update_incremental(
+    persist_path: &std::path::Path,
+    notify_tx: &tokio::sync::mpsc::UnboundedSender<()>,
+) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+    let stamp = sync_db_stamp(&sync_db_dir());
+    let up_to_date = stamp.is_some()
+        && idx()
+            .read()
+            .is_ok_and(|g| !g.pkgs.is_empty() && g.sync_stamp == stamp);
+    if up_to_date {
+        tracing::info!("official index up-to-date (sync databases unchanged)");
+        return Ok(0);
+    }
+    let from_sync_dbs = tokio::task::spawn_blocking(fetch_official_pkgs_from_sync_dbs)
+        .await
+        .ok()
+        .flatten();
+    let fresh = match from_sync_dbs {
+        Some(pkgs) => pkgs,
+        None => fetch_official_pkg_names().await?,
+    };
+    let (diff, was_empty) = {
+        let Ok(mut g) = idx().write() else {
+            return Ok(0);
+        };
+        let was_empty = g.pkgs.is_empty();
+        let diff = g.apply_diff(fresh);
+        g.sync_stamp = stamp;
+        (diff, was_empty)
+    };
+    let changed = diff.changed();
+    if changed > 0 || stamp.is_some() {
+        save_to_disk(persist_path);
+    }
+    if changed > 0 {
+        let _ = notify_tx.send(());
+        if !was_empty && !diff.to_enrich.is_empty() {
+            request_enrich_for(
+                persist_path.to_path_buf(),
+                notify_tx.clone(),
+                diff.to_enrich,
+            );
+        }
+    }
+    tracing::info!(
+        added = diff.added,
+        removed = diff.removed,
+        updated = diff.updated,
+        "official index updated incrementally"
+    );
+    Ok(changed)
+}
 
 /// What: Spawn a background task to refresh the official index and notify on changes.
 ///
 /// Inputs:
 /// - `persist_path`: File path to persist the updated index JSON
 /// - `net_err_tx`: Channel to send human-readable errors on failure
-/// - `notify_tx`: Channel to notify the UI when the set of names changes
+/// - `notify_tx`: Channel to notify the UI when the index changes
 ///
 /// Output:
-/// - Launches a task that updates the in-memory index and persists to disk when the set of names
-///   changes; sends notifications/errors via the provided channels.
+/// - Launches a task that runs [`update_incremental`]; sends notifications/errors via the provided
+///   channels.
 ///
 /// Details:
-/// - Merges new names while preserving previously enriched fields (repo, arch, version, description)
-///   for still-existing packages.
+/// - Unchanged packages keep their enriched fields (arch, description); see [`update_incremental`].
 #[cfg(not(target_os = "windows"))]
 pub async fn update_in_background(
     persist_path: std::path::PathBuf,
@@ -25,60 +102,9 @@ pub async fn update_in_background(
 ) {
     tokio::spawn(async move {
         tracing::info!("refreshing official index in background");
-        match fetch_official_pkg_names().await {
-            Ok(new_pkgs) => {
-                let new_count = new_pkgs.len();
-                let (different, merged): (bool, Vec<OfficialPkg>) = {
-                    let guard = idx().read().ok();
-                    if let Some(g) = guard {
-                        use std::collections::{HashMap, HashSet};
-                        let pkg_key =
-                            |p: &OfficialPkg| (p.repo.to_lowercase(), p.name.to_lowercase());
-                        let old_keys: HashSet<(String, String)> =
-                            g.pkgs.iter().map(pkg_key).collect();
-                        let new_keys: HashSet<(String, String)> =
-                            new_pkgs.iter().map(pkg_key).collect();
-                        let different = old_keys != new_keys;
-                        // Merge: prefer old/enriched fields when the same (repo, name) exists
-                        let mut old_map: HashMap<(String, String), &OfficialPkg> = HashMap::new();
-                        for p in &g.pkgs {
-                            old_map.insert(pkg_key(p), p);
-                        }
-                        let mut merged = Vec::with_capacity(new_pkgs.len());
-                        for mut p in new_pkgs {
-                            if let Some(old) = old_map.get(&pkg_key(&p)) {
-                                // keep enriched data
-                                p.repo.clone_from(&old.repo);
-                                p.arch.clone_from(&old.arch);
-                                p.version.clone_from(&old.version);
-                                p.description.clone_from(&old.description);
-                            }
-                            merged.push(p);
-                        }
-                        (different, merged)
-                    } else {
-                        (true, new_pkgs)
-                    }
-                };
-                if different {
-                    if let Ok(mut g) = idx().write() {
-                        g.pkgs = merged;
-                        g.rebuild_name_index();
-                    }
-                    save_to_disk(&persist_path);
-                    let _ = notify_tx.send(());
-                    tracing::info!(count = new_count, "official index updated (names changed)");
-                } else {
-                    tracing::info!(
-                        count = new_count,
-                        "official index up-to-date (no name changes)"
-                    );
-                }
-            }
-            Err(e) => {
-                let _ = net_err_tx.send(format!("Failed to refresh official index: {e}"));
-                tracing::warn!(error = %e, "failed to refresh official index");
-            }
+        if let Err(e) = update_incremental(&persist_path, &notify_tx).await {
+            let _ = net_err_tx.send(format!("Failed to refresh official index: {e}"));
+            tracing::warn!(error = %e, "failed to refresh official index");
         }
     });
 }
@@ -132,13 +158,15 @@ mod tests {
     /// - Creates a test package "foo" with enriched fields.
     fn seed_enriched_index() {
         if let Ok(mut g) = super::idx().write() {
-            g.pkgs = vec![super::OfficialPkg {
+            g.pkgs = vec![crate::index::OfficialPkg {
                 name: "foo".to_string(),
                 repo: "core".to_string(),
                 arch: "x86_64".to_string(),
                 version: "0.9".to_string(),
                 description: "old".to_string(),
             }];
+            g.rebuild_name_index();
+            g.sync_stamp = None;
         }
     }
 
@@ -150,7 +178,8 @@ mod tests {
     /// - Returns (`old_path`, `root_dir`, `tmp_file`) for teardown.
     ///
     /// Details:
-    /// - Creates a temporary pacman script that returns test data.
+    /// - Creates a temporary pacman script that returns test data and points the sync database
+    ///   directory at an empty location.
     fn setup_fake_pacman_for_update() -> (String, std::path::PathBuf, std::path::PathBuf) {
         let old_path = std::env::var("PATH").unwrap_or_default();
         let mut root = std::env::temp_dir();
@@ -174,7 +203,7 @@ if [[ "$1" == "-Sl" ]]; then
   repo="$2"
   case "$repo" in
     core)
-      echo "core foo 1.0"
+      echo "core foo 0.9"
       ;;
     extra)
       echo "extra bar 2.0"
@@ -197,6 +226,8 @@ exit 0
         }
         let new_path = format!("{}:{old_path}", bin.to_string_lossy());
         unsafe { std::env::set_var("PATH", &new_path) };
+        // No readable sync databases: forces the `pacman -Sl` listing from the fake binary
+        unsafe { std::env::set_var("PACSEA_TEST_SYNC_DB_DIR", root.join("sync")) };
         let mut tmp = std::env::temp_dir();
         tmp.push("pacsea_update_merge.json");
         (old_path, root, tmp)
@@ -251,7 +282,7 @@ exit 0
             }
             crate::state::Source::Aur => panic!("expected official"),
         }
-        assert_eq!(foo.version, "0.9"); // preserved from enriched
+        assert_eq!(foo.version, "0.9"); // unchanged version keeps the enriched row
     }
 
     /// What: Cleanup test environment.
@@ -264,9 +295,10 @@ exit 0
     /// Output: None.
     ///
     /// Details:
-    /// - Restores PATH and removes temporary files.
+    /// - Restores PATH, clears the sync database override, and removes temporary files.
     fn teardown_test_env(old_path: &str, tmp: &std::path::PathBuf, root: &std::path::PathBuf) {
         unsafe { std::env::set_var("PATH", old_path) };
+        unsafe { std::env::remove_var("PACSEA_TEST_SYNC_DB_DIR") };
         let _ = std::fs::remove_file(tmp);
         let _ = std::fs::remove_dir_all(root);
     }
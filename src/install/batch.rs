@@ -8,7 +8,7 @@ use crate::state::PackageItem;
 #[cfg(not(target_os = "windows"))]
 use super::command::{aur_install_body, aur_install_helper_flags};
 #[cfg(not(target_os = "windows"))]
-use super::logging::log_installed;
+use super::logging::{log_installed, spawn_audited};
 #[cfg(not(target_os = "windows"))]
 use super::utils::{
     choose_terminal_index_prefer_path, command_on_path, shell_single_quote, validate_package_names,
@@ -28,7 +28,8 @@ use super::utils::{
 ///
 /// # Errors
 ///
-/// Returns `Err` when the configured privilege tool cannot be resolved for official paths.
+/// Returns `Err` when the configured privilege tool cannot be resolved for official paths,
+/// or when AUR packages are requested while pacman-only mode is active.
 ///
 /// Details:
 /// - Official packages are grouped into a single `pacman` invocation
@@ -42,6 +43,7 @@ fn build_batch_install_command(
 ) -> Result<String, String> {
     validate_package_names(official, "batch install command (official)")?;
     validate_package_names(aur, "batch install command (AUR)")?;
+    super::command::ensure_pacman_only_allows(items, super::command::pacman_only_mode())?;
     let official_quoted: Vec<String> = official
         .iter()
         .map(|name| shell_single_quote(name))
@@ -222,7 +224,199 @@ fn try_spawn_terminal(
         cmd.env("GSK_RENDERER", "cairo");
         cmd.env("LIBGL_ALWAYS_SOFTWARE", "1");
     }
-    cmd.spawn().map(|_| ()).map_err(|_| ())
+    spawn_audited(&mut cmd, None).map(|_| ()).map_err(|_| ())
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Terminal preference order for batch installs.
+///
+/// Input: None
+///
+/// Output:
+/// - `(terminal, args, needs_xfce_command)` entries; GNOME terminals come first under GNOME.
+fn batch_terminal_preferences() -> &'static [(&'static str, &'static [&'static str], bool)] {
+    let is_gnome = std::env::var("XDG_CURRENT_DESKTOP")
+        .ok()
+        .is_some_and(|v| v.to_uppercase().contains("GNOME"));
+    if is_gnome {
+        &[
+            ("gnome-terminal", &["--", "bash", "-lc"], false),
+            ("gnome-console", &["--", "bash", "-lc"], false),
+            ("kgx", &["--", "bash", "-lc"], false),
+            ("alacritty", &["-e", "bash", "-lc"], false),
+            ("kitty", &["bash", "-lc"], false),
+            ("konsole", &["-e", "bash", "-lc"], false),
+            ("xterm", &["-hold", "-e", "bash", "-lc"], false),
+            ("xfce4-terminal", &[], true),
+            ("tilix", &["--", "bash", "-lc"], false),
+            ("mate-terminal", &["--", "bash", "-lc"], false),
+        ]
+    } else {
+        &[
+            ("alacritty", &["-e", "bash", "-lc"], false),
+            ("kitty", &["bash", "-lc"], false),
+            ("konsole", &["-e", "bash", "-lc"], false),
+            ("gnome-terminal", &["--", "bash", "-lc"], false),
+            ("gnome-console", &["--", "bash", "-lc"], false),
+            ("kgx", &["--", "bash", "-lc"], false),
+            ("xterm", &["-hold", "-e", "bash", "-lc"], false),
+            ("xfce4-terminal", &[], true),
+            ("tilix", &["--", "bash", "-lc"], false),
+            ("mate-terminal", &["--", "bash", "-lc"], false),
+        ]
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Launch one install command in a terminal, falling back to plain `bash`.
+///
+/// Input:
+/// - `cmd_str`: Shell command to run
+/// - `names`: Space-separated package names (for logging only)
+/// - `dry_run`: Whether the command is a dry-run preview (for logging only)
+///
+/// Output:
+/// - None; launch failures are logged.
+///
+/// Details:
+/// - Tries the terminal found first on `PATH`, then every known terminal in preference order.
+fn launch_install_terminal(cmd_str: &str, names: &str, dry_run: bool) {
+    let terms = batch_terminal_preferences();
+    let mut launched = false;
+    if let Some(idx) = choose_terminal_index_prefer_path(terms) {
+        let (term, args, needs_xfce_command) = terms[idx];
+        match try_spawn_terminal(term, args, needs_xfce_command, cmd_str) {
+            Ok(()) => {
+                tracing::info!(terminal = %term, dry_run = dry_run, names = %names, "launched terminal for install");
+                launched = true;
+            }
+            Err(()) => {
+                tracing::warn!(terminal = %term, names = %names, "failed to spawn terminal, trying next");
+            }
+        }
+    }
+
+    if !launched {
+        for (term, args, needs_xfce_command) in terms {
+            if command_on_path(term) {
+                match try_spawn_terminal(term, args, *needs_xfce_command, cmd_str) {
+                    Ok(()) => {
+                        tracing::info!(terminal = %term, dry_run = dry_run, names = %names, "launched terminal for install");
+                        launched = true;
+                        break;
+                    }
+                    Err(()) => {
+                        tracing::warn!(terminal = %term, names = %names, "failed to spawn terminal, trying next");
+                    }
+                }
+            }
+        }
+    }
+    if !launched {
+        let res = spawn_audited(Command::new("bash").args(["-lc", cmd_str]), None);
+        if let Err(e) = res {
+            tracing::error!(error = %e, names = %names, "failed to spawn bash to run install command");
+        } else {
+            tracing::info!(dry_run = dry_run, names = %names, "launched bash for install");
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Split AUR targets into at most `jobs` groups for parallel helper invocations.
+///
+/// Input:
+/// - `aur`: AUR package names
+/// - `jobs`: Requested parallel job count (values below 1 are treated as 1)
+///
+/// Output:
+/// - Non-empty groups distributed round-robin; never more groups than packages.
+fn aur_job_groups(aur: &[String], jobs: usize) -> Vec<Vec<String>> {
+    let n = jobs.clamp(1, aur.len().max(1));
+    let mut groups = vec![Vec::new(); n];
+    for (i, name) in aur.iter().enumerate() {
+        groups[i % n].push(name.clone());
+    }
+    groups.retain(|g| !g.is_empty());
+    groups
+}
+
+#[cfg(not(target_os = "windows"))]
+/// Root-owned directory locked with `flock` to serialize pacman across parallel AUR jobs.
+///
+/// Locking an existing system directory avoids creating (or trusting) a lock file in a
+/// user-writable location from a privileged process.
+const PACMAN_DB_FLOCK: &str = "/var/lib/pacman";
+
+#[cfg(not(target_os = "windows"))]
+/// What: Build one shell command per parallel install job.
+///
+/// Input:
+/// - `official`: Official package names (installed by the first job before its AUR group)
+/// - `aur_groups`: AUR name groups from [`aur_job_groups`]
+/// - `aur_helper`: Explicit AUR helper, or `None` for the `paru`-then-`yay` fallback
+/// - `dry_run`: When `true`, each command only prints what it would run
+///
+/// Output:
+/// - `Ok(commands)` with one hold-tailed command per group.
+///
+/// # Errors
+///
+/// Returns `Err` when a package name is invalid or the privilege tool cannot be resolved.
+///
+/// Details:
+/// - Every privileged pacman call runs under `flock` on [`PACMAN_DB_FLOCK`], inline: the official
+///   step directly, the helpers' own via `--sudoflags` (so `sudo pacman ...` becomes
+///   `sudo flock ... pacman ...`). Jobs queue for the database instead of failing on `db.lck`;
+///   makepkg builds in per-package directories, so builds still overlap.
+fn build_parallel_install_commands(
+    official: &[String],
+    aur_groups: &[Vec<String>],
+    aur_helper: Option<&str>,
+    dry_run: bool,
+) -> Result<Vec<String>, String> {
+    validate_package_names(official, "parallel install command (official)")?;
+    let hold_tail = "; echo; echo 'Finished.'; echo 'Press any key to close...'; read -rn1 -s _ || (echo; echo 'Press Ctrl+C to close'; sleep infinity)";
+    let official_step = if official.is_empty() {
+        None
+    } else {
+        let tool = crate::logic::privilege::active_tool()?;
+        let names: Vec<String> = official.iter().map(|n| shell_single_quote(n)).collect();
+        Some(crate::logic::privilege::build_privilege_command(
+            tool,
+            &format!(
+                "flock {PACMAN_DB_FLOCK} pacman -S --needed --noconfirm {}",
+                names.join(" ")
+            ),
+        ))
+    };
+    let mut commands = Vec::with_capacity(aur_groups.len());
+    for (i, group) in aur_groups.iter().enumerate() {
+        validate_package_names(group, "parallel install command (AUR)")?;
+        let names: Vec<String> = group.iter().map(|n| shell_single_quote(n)).collect();
+        let reinstall = group.iter().any(|n| crate::index::is_installed(n));
+        let flags = format!(
+            "{} --sudoflags {}",
+            aur_install_helper_flags(reinstall),
+            shell_single_quote(&format!("flock {PACMAN_DB_FLOCK}"))
+        );
+        let n = names.join(" ");
+        let aur_cmd = match (aur_helper, dry_run) {
+            (Some(helper), _) => format!("{helper} {flags} {n}"),
+            (None, true) => format!("(paru {flags} {n} || yay {flags} {n})"),
+            (None, false) => aur_install_body(&flags, &n),
+        };
+        let cmd = match (&official_step, i) {
+            (Some(step), 0) => format!("{step} && {aur_cmd}{hold_tail}"),
+            _ => format!("{aur_cmd}{hold_tail}"),
+        };
+        commands.push(if dry_run {
+            format!("echo DRY RUN: {}", shell_single_quote(&cmd))
+        } else {
+            cmd
+        });
+    }
+    Ok(commands)
 }
 
 #[cfg(not(target_os = "windows"))]
@@ -238,10 +432,45 @@ fn try_spawn_terminal(
 /// Details:
 /// - Official packages are grouped into a single `pacman` invocation
 /// - AUR packages are installed via `paru`/`yay` (prompts to install a helper if missing)
+/// - With `install_aur_parallel_jobs` above 1, the AUR part is split across that many terminals
+///   (see [`spawn_install_all_with_jobs`])
 /// - Prefers common terminals (GNOME Console/Terminal, kitty, alacritty, xterm, xfce4-terminal, etc.); falls back to `bash`
 /// - Appends a "hold" tail so the terminal remains open after command completion
 /// - During tests, this is a no-op to avoid opening real terminal windows.
 pub fn spawn_install_all(items: &[PackageItem], dry_run: bool) {
+    let settings = crate::theme::settings();
+    let aur_helper = super::selected_aur_helper(None, &settings.aur_helper);
+    spawn_install_all_with_jobs(
+        items,
+        dry_run,
+        settings.install_aur_parallel_jobs,
+        aur_helper,
+    );
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Spawn batch install terminals with an explicit AUR job count.
+///
+/// Input:
+/// - `items`: Packages to install
+/// - `dry_run`: When `true`, prints commands instead of executing
+/// - `aur_jobs`: Maximum number of parallel AUR helper terminals
+/// - `aur_helper`: Explicit AUR helper for the parallel jobs, or `None` for the `paru`-then-`yay` fallback
+///
+/// Output:
+/// - Launches one terminal for serial installs, or one per AUR job group.
+///
+/// Details:
+/// - Parallelism only applies to independent packages: an AUR package that depends on another
+///   AUR package from the same batch may build before its dependency is installed and fail.
+/// - Callers track completion through `pending_install_names` with every batch name, so the
+///   installed-cache polling reports completion only once all jobs have finished.
+fn spawn_install_all_with_jobs(
+    items: &[PackageItem],
+    dry_run: bool,
+    aur_jobs: usize,
+    aur_helper: Option<&str>,
+) {
     // Skip actual spawning during tests unless PACSEA_TEST_OUT is set (indicates a test with fake terminal)
     #[cfg(test)]
     if std::env::var("PACSEA_TEST_OUT").is_err() {
@@ -261,98 +490,47 @@ pub fn spawn_install_all(items: &[PackageItem], dry_run: bool) {
         total = items.len(),
         aur_count = aur.len(),
         official_count = official.len(),
+        aur_jobs,
         dry_run = dry_run,
         names = %names_vec.join(" "),
         "spawning install"
     );
 
-    let cmd_str = match build_batch_install_command(items, &official, &aur, dry_run) {
-        Ok(s) => s,
-        Err(err) => {
-            tracing::error!(error = %err, "privilege tool resolution failed for batch install");
+    if aur_jobs > 1 && aur.len() > 1 {
+        if let Err(err) =
+            super::command::ensure_pacman_only_allows(items, super::command::pacman_only_mode())
+        {
+            tracing::error!(error = %err, "failed to build parallel install commands");
             return;
         }
-    };
-
-    // Prefer GNOME Terminal when running under GNOME desktop
-    let is_gnome = std::env::var("XDG_CURRENT_DESKTOP")
-        .ok()
-        .is_some_and(|v| v.to_uppercase().contains("GNOME"));
-    let terms_gnome_first: &[(&str, &[&str], bool)] = &[
-        ("gnome-terminal", &["--", "bash", "-lc"], false),
-        ("gnome-console", &["--", "bash", "-lc"], false),
-        ("kgx", &["--", "bash", "-lc"], false),
-        ("alacritty", &["-e", "bash", "-lc"], false),
-        ("kitty", &["bash", "-lc"], false),
-        ("konsole", &["-e", "bash", "-lc"], false),
-        ("xterm", &["-hold", "-e", "bash", "-lc"], false),
-        ("xfce4-terminal", &[], true),
-        ("tilix", &["--", "bash", "-lc"], false),
-        ("mate-terminal", &["--", "bash", "-lc"], false),
-    ];
-    let terms_default: &[(&str, &[&str], bool)] = &[
-        ("alacritty", &["-e", "bash", "-lc"], false),
-        ("kitty", &["bash", "-lc"], false),
-        ("konsole", &["-e", "bash", "-lc"], false),
-        ("gnome-terminal", &["--", "bash", "-lc"], false),
-        ("gnome-console", &["--", "bash", "-lc"], false),
-        ("kgx", &["--", "bash", "-lc"], false),
-        ("xterm", &["-hold", "-e", "bash", "-lc"], false),
-        ("xfce4-terminal", &[], true),
-        ("tilix", &["--", "bash", "-lc"], false),
-        ("mate-terminal", &["--", "bash", "-lc"], false),
-    ];
-    let terms = if is_gnome {
-        terms_gnome_first
-    } else {
-        terms_default
-    };
-    let mut launched = false;
-    if let Some(idx) = choose_terminal_index_prefer_path(terms) {
-        let (term, args, needs_xfce_command) = terms[idx];
-        match try_spawn_terminal(term, args, needs_xfce_command, &cmd_str) {
-            Ok(()) => {
-                tracing::info!(terminal = %term, total = items.len(), aur_count = aur.len(), official_count = official.len(), dry_run = dry_run, names = %names_vec.join(" "), "launched terminal for install");
-                launched = true;
+        let groups = aur_job_groups(&aur, aur_jobs);
+        match build_parallel_install_commands(&official, &groups, aur_helper, dry_run) {
+            Ok(commands) => {
+                for (cmd_str, group) in commands.iter().zip(&groups) {
+                    launch_install_terminal(cmd_str, &group.join(" "), dry_run);
+                }
             }
-            Err(()) => {
-                tracing::warn!(terminal = %term, names = %names_vec.join(" "), "failed to spawn terminal, trying next");
+            Err(err) => {
+                tracing::error!(error = %err, "failed to build parallel install commands");
+                return;
             }
         }
-    }
-
-    if !launched {
-        for (term, args, needs_xfce_command) in terms {
-            if command_on_path(term) {
-                match try_spawn_terminal(term, args, *needs_xfce_command, &cmd_str) {
-                    Ok(()) => {
-                        tracing::info!(terminal = %term, total = items.len(), aur_count = aur.len(), official_count = official.len(), dry_run = dry_run, names = %names_vec.join(" "), "launched terminal for install");
-                        launched = true;
-                        break;
-                    }
-                    Err(()) => {
-                        tracing::warn!(terminal = %term, names = %names_vec.join(" "), "failed to spawn terminal, trying next");
-                    }
-                }
+    } else {
+        let cmd_str = match build_batch_install_command(items, &official, &aur, dry_run) {
+            Ok(s) => s,
+            Err(err) => {
+                tracing::error!(error = %err, "failed to build batch install command");
+                return;
             }
-        }
-    }
-    if !launched {
-        let res = Command::new("bash").args(["-lc", &cmd_str]).spawn();
-        if let Err(e) = res {
-            tracing::error!(error = %e, names = %names_vec.join(" "), "failed to spawn bash to run install command");
-        } else {
-            tracing::info!(total = items.len(), aur_count = aur.len(), official_count = official.len(), dry_run = dry_run, names = %names_vec.join(" "), "launched bash for install");
-        }
+        };
+        launch_install_terminal(&cmd_str, &names_vec.join(" "), dry_run);
     }
 
-    if !dry_run {
-        let names: Vec<String> = items.iter().map(|p| p.name.clone()).collect();
-        if !names.is_empty()
-            && let Err(e) = log_installed(&names)
-        {
-            tracing::warn!(error = %e, count = names.len(), "failed to write install audit log");
-        }
+    if !dry_run
+        && !names_vec.is_empty()
+        && let Err(e) = log_installed(&names_vec)
+    {
+        tracing::warn!(error = %e, count = names_vec.len(), "failed to write install audit log");
     }
 }
 
@@ -375,6 +553,8 @@ mod tests {
         use std::os::unix::fs::PermissionsExt;
         use std::path::PathBuf;
 
+        let _guard = crate::global_test_mutex_lock();
+
         let mut dir: PathBuf = std::env::temp_dir();
         dir.push(format!(
             "pacsea_test_inst_batch_gnome_{}_{}",
@@ -449,6 +629,105 @@ mod tests {
             std::env::remove_var("PACSEA_TEST_OUT");
         }
     }
+
+    #[test]
+    /// What: Verify AUR packages are split into at most `jobs` round-robin groups.
+    ///
+    /// Inputs:
+    /// - Five AUR names with two jobs, and two names with eight jobs.
+    ///
+    /// Output:
+    /// - Two groups of three and two names; never more groups than packages.
+    fn aur_job_groups_round_robin() {
+        let names: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+        assert_eq!(
+            super::aur_job_groups(&names, 2),
+            vec![
+                vec!["a".to_string(), "c".into(), "e".into()],
+                vec!["b".to_string(), "d".into()],
+            ]
+        );
+        assert_eq!(super::aur_job_groups(&names[..2], 8).len(), 2);
+        assert_eq!(super::aur_job_groups(&names, 0).len(), 1);
+    }
+
+    #[test]
+    /// What: Verify N independent AUR packages with N jobs launch N terminals.
+    ///
+    /// Inputs:
+    /// - Shim `gnome-terminal` appending one line per launch to `PACSEA_TEST_OUT`.
+    /// - Three AUR packages installed in dry-run mode with three jobs and `yay` selected.
+    ///
+    /// Output:
+    /// - Three launches, each running exactly one package through the selected helper with pacman
+    ///   calls serialized by an inline `flock`.
+    ///
+    /// Details:
+    /// - Overrides `PATH` and environment variables, then restores them to avoid leaking state across tests.
+    fn install_batch_parallel_aur_spawns_one_terminal_per_job() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let _guard = crate::global_test_mutex_lock();
+        let dir = tempfile::tempdir().expect("tempdir");
+        let out_path = dir.path().join("launches.txt");
+        let term_path = dir.path().join("gnome-terminal");
+        let script = "#!/bin/sh\nprintf '%s\\n' \"$*\" >> \"$PACSEA_TEST_OUT\"\n";
+        fs::write(&term_path, script.as_bytes()).expect("Failed to write test terminal script");
+        fs::set_permissions(&term_path, fs::Permissions::from_mode(0o755))
+            .expect("Failed to set test terminal script permissions");
+
+        let orig_path = std::env::var_os("PATH");
+        unsafe {
+            std::env::set_var("PATH", dir.path().display().to_string());
+            std::env::set_var("PACSEA_TEST_OUT", out_path.display().to_string());
+        }
+
+        let names = ["aur-one", "aur-two", "aur-three"];
+        let items: Vec<crate::state::PackageItem> = names
+            .iter()
+            .map(|n| crate::state::PackageItem::test_item(n, "aur"))
+            .collect();
+        super::spawn_install_all_with_jobs(&items, true, names.len(), Some("yay"));
+
+        let mut launches: Vec<String> = Vec::new();
+        for _ in 0..40 {
+            launches = fs::read_to_string(&out_path)
+                .unwrap_or_default()
+                .lines()
+                .map(str::to_string)
+                .collect();
+            if launches.len() >= names.len() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+
+        unsafe {
+            if let Some(v) = orig_path {
+                std::env::set_var("PATH", v);
+            } else {
+                std::env::remove_var("PATH");
+            }
+            std::env::remove_var("PACSEA_TEST_OUT");
+        }
+
+        assert_eq!(launches.len(), names.len(), "launches: {launches:?}");
+        for name in names {
+            let hits = launches.iter().filter(|l| l.contains(name)).count();
+            assert_eq!(
+                hits, 1,
+                "{name} should run in exactly one terminal: {launches:?}"
+            );
+        }
+        assert!(
+            launches.iter().all(|l| l.contains("yay -S --aur")
+                && !l.contains("paru")
+                && l.contains("--sudoflags")
+                && l.contains("flock /var/lib/pacman")),
+            "every job should run the selected helper with inline flock: {launches:?}"
+        );
+    }
 }
 
 #[cfg(target_os = "windows")]
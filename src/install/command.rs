@@ -49,6 +49,52 @@ pub fn aur_install_body(flags: &str, n: &str) -> String {
     )
 }
 
+/// What: Whether the `install_mode = pacman_only` setting is active.
+///
+/// Inputs: None.
+///
+/// Output:
+/// - `true` when installs must never invoke an AUR helper.
+#[must_use]
+pub fn pacman_only_mode() -> bool {
+    crate::theme::settings().install_mode == "pacman_only"
+}
+
+/// What: Refuse AUR targets while pacman-only mode is active.
+///
+/// Inputs:
+/// - `items`: Packages about to be installed.
+/// - `pacman_only`: Whether pacman-only mode is active (see [`pacman_only_mode`]).
+///
+/// Output:
+/// - `Ok(())` when every target may be installed; `Err` naming the AUR targets otherwise.
+///
+/// # Errors
+///
+/// Returns `Err` when `pacman_only` is set and any item comes from the AUR.
+///
+/// Details:
+/// - AUR targets are refused as a whole rather than dropped, so an install never silently
+///   shrinks to its official subset.
+pub fn ensure_pacman_only_allows(items: &[PackageItem], pacman_only: bool) -> Result<(), String> {
+    if !pacman_only {
+        return Ok(());
+    }
+    let aur: Vec<&str> = items
+        .iter()
+        .filter(|item| matches!(item.source, Source::Aur))
+        .map(|item| item.name.as_str())
+        .collect();
+    if aur.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "install_mode = pacman_only refuses AUR packages: {}",
+            aur.join(", ")
+        ))
+    }
+}
+
 /// What: Build a shell command to install `item` and indicate whether `sudo` is used.
 ///
 /// Input:
@@ -61,7 +107,8 @@ pub fn aur_install_body(flags: &str, n: &str) -> String {
 ///
 /// # Errors
 ///
-/// Returns `Err` when the configured privilege tool cannot be resolved for official packages.
+/// Returns `Err` when the configured privilege tool cannot be resolved for official packages,
+/// or when `item` is an AUR package while pacman-only mode is active.
 ///
 /// Details:
 /// - Uses `--needed` flag for new installs, omits it for reinstalls.
@@ -75,6 +122,7 @@ pub fn build_install_command(
         std::slice::from_ref(&item.name),
         "install command construction",
     )?;
+    ensure_pacman_only_allows(std::slice::from_ref(item), pacman_only_mode())?;
     let quoted_name = shell_single_quote(&item.name);
     match &item.source {
         Source::Official { .. } => {
@@ -247,4 +295,22 @@ mod tests {
         assert!(cmd2.contains("paru -S --aur --needed --noconfirm"));
         assert!(cmd2.contains("yay-bin"));
     }
+
+    #[test]
+    /// What: Verify pacman-only mode rejects AUR targets and lets official targets proceed.
+    ///
+    /// Inputs:
+    /// - An official and an AUR package, checked with pacman-only mode on and off.
+    ///
+    /// Output:
+    /// - Only the on-mode check of a set containing the AUR package fails, naming it.
+    fn ensure_pacman_only_allows_rejects_aur_items() {
+        let official = PackageItem::test_item("ripgrep", "extra");
+        let aur = PackageItem::test_item("yay-bin", "aur");
+
+        assert!(ensure_pacman_only_allows(std::slice::from_ref(&official), true).is_ok());
+        assert!(ensure_pacman_only_allows(&[official.clone(), aur.clone()], false).is_ok());
+        let err = ensure_pacman_only_allows(&[official, aur], true).expect_err("AUR refused");
+        assert!(err.contains("yay-bin") && !err.contains("ripgrep"), "{err}");
+    }
 }
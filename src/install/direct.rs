@@ -18,6 +18,39 @@ fn maybe_show_long_run_auth_preflight(app: &mut AppState) {
     }
 }
 
+/// What: Block an install containing AUR packages while pacman-only mode is active.
+///
+/// Inputs:
+/// - `app`: Mutable application state (may set modal)
+/// - `items`: Packages about to be installed
+/// - `pacman_only`: Whether pacman-only mode is active (see `install::command::pacman_only_mode`)
+///
+/// Output:
+/// - `true` when a blocking alert naming the AUR packages was shown and the caller must stop.
+///
+/// Details:
+/// - The whole install is refused instead of dropping the AUR targets, so the user decides
+///   how to adjust the list.
+pub fn reject_aur_in_pacman_only(
+    app: &mut AppState,
+    items: &[PackageItem],
+    pacman_only: bool,
+) -> bool {
+    let Err(err) = super::command::ensure_pacman_only_allows(items, pacman_only) else {
+        return false;
+    };
+    tracing::warn!(error = %err, "[Install] Refusing AUR install in pacman-only mode");
+    let names: Vec<&str> = items
+        .iter()
+        .filter(|item| matches!(item.source, crate::state::Source::Aur))
+        .map(|item| item.name.as_str())
+        .collect();
+    app.modal = crate::state::Modal::Alert {
+        message: crate::i18n::t_fmt1(app, "app.errors.pacman_only_aur_blocked", names.join(", ")),
+    };
+    true
+}
+
 /// What: Start integrated install process for a single package (bypassing preflight).
 ///
 /// Inputs:
@@ -34,10 +67,18 @@ fn maybe_show_long_run_auth_preflight(app: &mut AppState) {
 /// - Both official packages (sudo pacman) and AUR packages (paru/yay need sudo for final step)
 ///   require sudo, but password may not be needed if passwordless sudo is configured.
 /// - Uses `ExecutorRequest::Install` for execution.
+/// - AUR packages are refused with an alert while pacman-only mode is active.
 pub fn start_integrated_install(app: &mut AppState, item: &PackageItem, dry_run: bool) {
     use crate::events::start_execution;
     use crate::state::modal::PreflightHeaderChips;
 
+    if reject_aur_in_pacman_only(
+        app,
+        std::slice::from_ref(item),
+        super::command::pacman_only_mode(),
+    ) {
+        return;
+    }
     app.dry_run = dry_run;
     maybe_show_long_run_auth_preflight(app);
     let items = vec![item.clone()];
@@ -112,10 +153,14 @@ pub fn start_integrated_install(app: &mut AppState, item: &PackageItem, dry_run:
 /// - Both official packages (sudo pacman) and AUR packages (paru/yay need sudo for final step)
 ///   require sudo, but password may not be needed if passwordless sudo is configured.
 /// - Uses `ExecutorRequest::Install` for execution.
+/// - AUR packages are refused with an alert while pacman-only mode is active.
 pub fn start_integrated_install_all(app: &mut AppState, items: &[PackageItem], dry_run: bool) {
     use crate::events::start_execution;
     use crate::state::modal::PreflightHeaderChips;
 
+    if reject_aur_in_pacman_only(app, items, super::command::pacman_only_mode()) {
+        return;
+    }
     app.dry_run = dry_run;
     maybe_show_long_run_auth_preflight(app);
     let items_vec = items.to_vec();
@@ -271,7 +316,8 @@ pub fn start_integrated_remove_all(
 
 #[cfg(test)]
 mod tests {
-    use super::maybe_show_long_run_auth_preflight;
+    use super::{maybe_show_long_run_auth_preflight, reject_aur_in_pacman_only};
+    use crate::state::PackageItem;
 
     #[test]
     fn preflight_warning_is_latched_once_per_session() {
@@ -296,4 +342,36 @@ mod tests {
             std::env::remove_var("PACSEA_INTEGRATION_TEST");
         }
     }
+
+    #[test]
+    /// What: Verify pacman-only mode blocks AUR installs with an alert and lets official ones proceed.
+    ///
+    /// Inputs:
+    /// - An official-only set and a set with an AUR package, with pacman-only mode on.
+    ///
+    /// Output:
+    /// - The official set passes untouched; the AUR set shows a blocking alert.
+    fn reject_aur_in_pacman_only_alerts_for_aur_items() {
+        let mut app = crate::state::AppState::default();
+        let official = PackageItem::test_item("ripgrep", "extra");
+        let aur = PackageItem::test_item("yay-bin", "aur");
+
+        assert!(!reject_aur_in_pacman_only(
+            &mut app,
+            std::slice::from_ref(&official),
+            true
+        ));
+        assert!(matches!(app.modal, crate::state::Modal::None));
+        assert!(!reject_aur_in_pacman_only(
+            &mut app,
+            std::slice::from_ref(&aur),
+            false
+        ));
+
+        assert!(reject_aur_in_pacman_only(&mut app, &[official, aur], true));
+        assert!(matches!(
+            app.modal,
+            crate::state::Modal::Alert { ref message } if message.contains("pacman_only_aur_blocked")
+        ));
+    }
 }
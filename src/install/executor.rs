@@ -23,6 +23,8 @@ pub enum ExecutorRequest {
         password: Option<SecureString>,
         /// Whether to run in dry-run mode.
         dry_run: bool,
+        /// AUR helper to run (`paru`/`yay`); `None` prefers `paru` and falls back to `yay`.
+        aur_helper: Option<String>,
     },
     /// Remove packages.
     Remove {
@@ -112,31 +114,69 @@ pub enum ExecutorOutput {
     Error(String),
 }
 
+/// What: Build the AUR step of an executor install and its dry-run preview.
+///
+/// Inputs:
+/// - `helper`: Explicit AUR helper, or `None` for the `paru`-then-`yay` fallback.
+/// - `s_flags`: Full helper flags (from `aur_install_helper_flags`).
+/// - `cli_suffix`: Flags shown after `-S --aur` in the preview.
+/// - `names`: Quoted, space-separated AUR package names.
+///
+/// Output:
+/// - `(command, preview)` where the preview names the helper that would run.
+fn aur_install_parts(
+    helper: Option<&str>,
+    s_flags: &str,
+    cli_suffix: &str,
+    names: &str,
+) -> (String, String) {
+    helper.map_or_else(
+        || {
+            (
+                super::command::aur_install_body(s_flags, names),
+                format!(
+                    "(paru -S --aur {cli_suffix} {names} || yay -S --aur {cli_suffix} {names})"
+                ),
+            )
+        },
+        |helper| {
+            (
+                format!("{helper} {s_flags} {names}"),
+                format!("{helper} -S --aur {cli_suffix} {names}"),
+            )
+        },
+    )
+}
+
 /// What: Build install command string without hold tail for `PTY` execution.
 ///
 /// Inputs:
 /// - `items`: Packages to install.
 /// - `_password`: Optional sudo password (unused - password is written to PTY stdin when sudo prompts).
 /// - `dry_run`: Whether to run in dry-run mode.
+/// - `aur_helper`: Explicit AUR helper; `None` keeps the `paru`-then-`yay` fallback.
 ///
 /// Output:
 /// - Command string ready for `PTY` execution (no hold tail).
 ///
 /// Details:
 /// - Groups official and `AUR` packages separately; mixed installs run `pacman` then `paru`/`yay` with `--aur` on **AUR-only** names.
+/// - An explicit helper is invoked directly (no fallback), and named in the dry-run preview.
 /// - Uses `--noconfirm` for non-interactive execution.
 /// - Always uses `sudo -S` for official packages (password written to PTY stdin when sudo prompts).
 /// - Removes hold tail since we're not spawning a terminal.
 ///
 /// # Errors
 ///
-/// Returns `Err` when the configured privilege tool cannot be resolved for official package paths.
+/// Returns `Err` when the configured privilege tool cannot be resolved for official package paths,
+/// or when AUR packages are requested while pacman-only mode is active.
 pub fn build_install_command_for_executor(
     items: &[PackageItem],
     password: Option<&str>,
     dry_run: bool,
+    aur_helper: Option<&str>,
 ) -> Result<String, String> {
-    use super::command::{aur_install_body, aur_install_helper_flags};
+    use super::command::aur_install_helper_flags;
     use super::utils::{shell_single_quote, validate_package_names};
     use crate::state::Source;
 
@@ -151,6 +191,7 @@ pub fn build_install_command_for_executor(
     }
     validate_package_names(&official, "executor install command (official)")?;
     validate_package_names(&aur, "executor install command (AUR)")?;
+    super::command::ensure_pacman_only_allows(items, super::command::pacman_only_mode())?;
     let official_quoted: Vec<String> = official
         .iter()
         .map(|name| shell_single_quote(name))
@@ -180,6 +221,8 @@ pub fn build_install_command_for_executor(
     };
 
     let aur_names = aur_quoted.join(" ");
+    let (aur_body, aur_preview) =
+        aur_install_parts(aur_helper, aur_s_flags, aur_cli_suffix, &aur_names);
 
     if dry_run {
         if !aur.is_empty() && !official.is_empty() {
@@ -188,17 +231,11 @@ pub fn build_install_command_for_executor(
                 tool,
                 &format!("pacman -S {pacman_flags} {}", official_quoted.join(" ")),
             );
-            let aur_cmd = format!(
-                "(paru -S --aur {aur_cli_suffix} {aur_names} || yay -S --aur {aur_cli_suffix} {aur_names})",
-            );
-            let combined = format!("{off_cmd} && {aur_cmd}");
+            let combined = format!("{off_cmd} && {aur_preview}");
             let quoted = shell_single_quote(&combined);
             Ok(format!("echo DRY RUN: {quoted}"))
         } else if !aur.is_empty() {
-            let aur_cmd = format!(
-                "(paru -S --aur {aur_cli_suffix} {aur_names} || yay -S --aur {aur_cli_suffix} {aur_names})",
-            );
-            let quoted = shell_single_quote(&aur_cmd);
+            let quoted = shell_single_quote(&aur_preview);
             Ok(format!("echo DRY RUN: {quoted}"))
         } else if !official.is_empty() {
             let tool = crate::logic::privilege::active_tool()?;
@@ -243,13 +280,9 @@ pub fn build_install_command_for_executor(
                 )
             },
         );
-        Ok(format!(
-            "{} && {}",
-            official_chain,
-            aur_install_body(aur_s_flags, &aur_names)
-        ))
+        Ok(format!("{official_chain} && {aur_body}"))
     } else if !aur.is_empty() {
-        Ok(aur_install_body(aur_s_flags, &aur_names))
+        Ok(aur_body)
     } else if !official.is_empty() {
         let tool = crate::logic::privilege::active_tool()?;
         let install_cmd = format!("pacman -S {pacman_flags} {}", official_quoted.join(" "));
@@ -633,9 +666,13 @@ mod tests {
             &installed_set,
             &provided_set,
         );
-        let cmd1 =
-            build_install_command_for_executor(std::slice::from_ref(&official_pkg), None, false)
-                .expect("build install");
+        let cmd1 = build_install_command_for_executor(
+            std::slice::from_ref(&official_pkg),
+            None,
+            false,
+            None,
+        )
+        .expect("build install");
         let quoted_name = crate::install::shell_single_quote("ripgrep");
         if is_installed {
             assert!(
@@ -658,6 +695,7 @@ mod tests {
             std::slice::from_ref(&official_pkg),
             Some("pass"),
             false,
+            None,
         )
         .expect("build install");
         if tool.capabilities().supports_stdin_password {
@@ -683,15 +721,16 @@ mod tests {
         }
 
         // AUR package
-        let cmd3 = build_install_command_for_executor(std::slice::from_ref(&aur_pkg), None, false)
-            .expect("build install");
+        let cmd3 =
+            build_install_command_for_executor(std::slice::from_ref(&aur_pkg), None, false, None)
+                .expect("build install");
         assert!(cmd3.contains("command -v paru"));
         assert!(cmd3.contains("paru -S --aur"));
         assert!(!cmd3.contains("Press any key to close"));
 
         // Dry run
-        let cmd4 =
-            build_install_command_for_executor(&[official_pkg], None, true).expect("build install");
+        let cmd4 = build_install_command_for_executor(&[official_pkg], None, true, None)
+            .expect("build install");
         assert!(cmd4.starts_with("echo DRY RUN:"));
     }
 
@@ -719,7 +758,7 @@ mod tests {
         );
         let aur_pkg = create_test_package("yay-bin", Source::Aur);
 
-        let cmd = build_install_command_for_executor(&[official_pkg, aur_pkg], None, false)
+        let cmd = build_install_command_for_executor(&[official_pkg, aur_pkg], None, false, None)
             .expect("build install");
         assert!(
             cmd.contains(&format!("{bin} pacman")),
@@ -739,6 +778,61 @@ mod tests {
         );
     }
 
+    #[test]
+    /// What: Verify an explicitly selected AUR helper is used without fallback.
+    ///
+    /// Inputs:
+    /// - AUR-only install with `yay` selected (real and dry-run).
+    /// - Mixed dry-run install with `paru` selected.
+    ///
+    /// Output:
+    /// - Commands and dry-run previews name only the selected helper.
+    ///
+    /// Details:
+    /// - The package name avoids helper names so substring checks stay meaningful.
+    fn executor_build_uses_selected_aur_helper() {
+        let official_pkg = create_test_package(
+            "ripgrep",
+            Source::Official {
+                repo: "extra".into(),
+                arch: "x86_64".into(),
+            },
+        );
+        let aur_pkg = create_test_package("visual-studio-code-bin", Source::Aur);
+
+        let cmd = build_install_command_for_executor(
+            std::slice::from_ref(&aur_pkg),
+            None,
+            false,
+            Some("yay"),
+        )
+        .expect("build install");
+        assert!(cmd.starts_with("yay -S --aur"), "expected yay: {cmd}");
+        assert!(!cmd.contains("paru"), "no paru fallback expected: {cmd}");
+        assert!(
+            !cmd.contains("command -v"),
+            "no helper probe expected: {cmd}"
+        );
+
+        let preview = build_install_command_for_executor(
+            std::slice::from_ref(&aur_pkg),
+            None,
+            true,
+            Some("yay"),
+        )
+        .expect("build install");
+        assert!(preview.starts_with("echo DRY RUN:"));
+        assert!(preview.contains("yay -S --aur"), "expected yay: {preview}");
+        assert!(!preview.contains("paru"), "unexpected paru: {preview}");
+
+        let mixed =
+            build_install_command_for_executor(&[official_pkg, aur_pkg], None, true, Some("paru"))
+                .expect("build install");
+        assert!(mixed.contains("pacman -S"), "expected pacman: {mixed}");
+        assert!(mixed.contains("paru -S --aur"), "expected paru: {mixed}");
+        assert!(!mixed.contains("yay"), "unexpected yay: {mixed}");
+    }
+
     #[test]
     /// What: Verify command builder handles empty package list.
     ///
@@ -751,7 +845,8 @@ mod tests {
     /// Details:
     /// - Empty list should produce a safe no-op command.
     fn executor_build_empty_list() {
-        let cmd = build_install_command_for_executor(&[], None, false).expect("build install");
+        let cmd =
+            build_install_command_for_executor(&[], None, false, None).expect("build install");
         assert!(cmd.contains("nothing to install") || cmd.is_empty());
     }
 
@@ -796,8 +891,8 @@ mod tests {
         );
         let has_reinstall = ripgrep_installed || fd_installed;
 
-        let cmd =
-            build_install_command_for_executor(&[pkg1, pkg2], None, false).expect("build install");
+        let cmd = build_install_command_for_executor(&[pkg1, pkg2], None, false, None)
+            .expect("build install");
         assert!(cmd.contains("ripgrep"));
         assert!(cmd.contains("fd"));
         let bin = crate::logic::privilege::active_tool()
@@ -837,7 +932,8 @@ mod tests {
             },
         );
 
-        let cmd = build_install_command_for_executor(&[pkg], None, true).expect("build install");
+        let cmd =
+            build_install_command_for_executor(&[pkg], None, true, None).expect("build install");
         assert!(cmd.starts_with("echo DRY RUN:"));
         // In dry-run mode, the command is wrapped in echo, so it may contain the original command text
         // The important thing is that it starts with "echo DRY RUN:" which prevents execution
@@ -865,7 +961,7 @@ mod tests {
         );
 
         let password = "pass'word\"with$special";
-        let cmd = build_install_command_for_executor(&[pkg], Some(password), false)
+        let cmd = build_install_command_for_executor(&[pkg], Some(password), false, None)
             .expect("build install");
         if tool.capabilities().supports_stdin_password {
             assert!(cmd.contains("printf"), "expected printf in: {cmd}");
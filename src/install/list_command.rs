@@ -0,0 +1,230 @@
+//! Copyable install command for the whole install list.
+//!
+//! Produces a single command line the user can paste into another terminal or script: the
+//! configured AUR helper when the list contains AUR packages (helpers install repo packages
+//! too), otherwise the privilege tool with `pacman -S`.
+
+use crate::state::{AppState, PackageItem, Source};
+
+use super::utils::{command_on_path, is_safe_package_name, shell_single_quote};
+
+/// What: Resolve the AUR helper named in the copied command.
+///
+/// Inputs:
+/// - `preference`: `aur_helper` setting (`auto`, `paru`, or `yay`).
+///
+/// Output:
+/// - `"paru"` or `"yay"`.
+///
+/// Details:
+/// - An explicit preference is used as-is, since the command may be run on another machine.
+/// - `auto` prefers an installed `paru`, then `yay`, and falls back to `paru`.
+#[must_use]
+pub fn resolve_aur_helper(preference: &str) -> &'static str {
+    match preference.trim().to_ascii_lowercase().as_str() {
+        "paru" => "paru",
+        "yay" => "yay",
+        _ if !command_on_path("paru") && command_on_path("yay") => "yay",
+        _ => "paru",
+    }
+}
+
+/// What: Resolve the AUR helper an install should run with.
+///
+/// Inputs:
+/// - `choice`: Helper picked in the install flow, if any.
+/// - `setting`: `aur_helper` setting (`auto`, `paru`, or `yay`).
+///
+/// Output:
+/// - `Some("paru")`/`Some("yay")` for an explicit helper, `None` for `auto`.
+///
+/// Details:
+/// - The in-flow choice wins; without one the setting is used, so `auto` keeps the
+///   `paru`-then-`yay` fallback.
+#[must_use]
+pub fn selected_aur_helper(choice: Option<&str>, setting: &str) -> Option<&'static str> {
+    match choice
+        .unwrap_or(setting)
+        .trim()
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "paru" => Some("paru"),
+        "yay" => Some("yay"),
+        _ => None,
+    }
+}
+
+/// What: Build the install command for a list of packages.
+///
+/// Inputs:
+/// - `items`: Install list entries.
+/// - `aur_helper`: Helper binary used when any AUR package is present.
+/// - `privilege`: Privilege tool prefix for official-only lists (e.g. `sudo`).
+///
+/// Output:
+/// - `Some("paru -S a b")` when AUR packages are present, `Some("sudo pacman -S a b")` for
+///   official-only lists, `None` for an empty list.
+///
+/// Details:
+/// - Official packages are listed before AUR packages, each group in list order.
+/// - Names outside the safe package-name alphabet are single-quoted.
+#[must_use]
+pub fn install_list_command(
+    items: &[PackageItem],
+    aur_helper: &str,
+    privilege: &str,
+) -> Option<String> {
+    if items.is_empty() {
+        return None;
+    }
+    let (aur, official): (Vec<&PackageItem>, Vec<&PackageItem>) = items
+        .iter()
+        .partition(|item| matches!(item.source, Source::Aur));
+    let names: Vec<String> = official
+        .iter()
+        .chain(aur.iter())
+        .map(|item| {
+            if is_safe_package_name(&item.name) {
+                item.name.clone()
+            } else {
+                shell_single_quote(&item.name)
+            }
+        })
+        .collect();
+    let names = names.join(" ");
+    if aur.is_empty() {
+        Some(format!("{privilege} pacman -S {names}"))
+    } else {
+        Some(format!("{aur_helper} -S {names}"))
+    }
+}
+
+/// What: Copy the install command for the current install list to the clipboard.
+///
+/// Inputs:
+/// - `app`: Application state (install list and toast fields).
+///
+/// Output:
+/// - `true` when a command was built (copied or not); `false` for an empty install list.
+///
+/// Details:
+/// - Honors the `aur_helper` setting and the active privilege tool (`sudo` when unresolved).
+/// - Shows a toast for success or clipboard failure.
+pub fn copy_install_list_command(app: &mut AppState) -> bool {
+    let helper = resolve_aur_helper(&crate::theme::settings().aur_helper);
+    let privilege = crate::logic::privilege::active_tool()
+        .map_or("sudo", crate::logic::privilege::PrivilegeTool::binary_name);
+    let Some(cmd) = install_list_command(&app.install_list, helper, privilege) else {
+        return false;
+    };
+    match crate::util::clipboard::copy_plain_text_to_clipboard(&cmd) {
+        Ok(()) => {
+            app.toast_message = Some(crate::i18n::t(app, "app.toasts.install_command_copied"));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
+        Err(msg) => {
+            app.toast_message = Some(msg);
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify official-only lists use the privilege tool with pacman.
+    ///
+    /// Inputs:
+    /// - Two official packages; `sudo` and `doas` prefixes; an empty list.
+    ///
+    /// Output:
+    /// - `sudo pacman -S ...` / `doas pacman -S ...`; `None` when empty.
+    fn install_list_command_official_only() {
+        let items = [
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("fd", "extra"),
+        ];
+        assert_eq!(
+            install_list_command(&items, "paru", "sudo").as_deref(),
+            Some("sudo pacman -S ripgrep fd")
+        );
+        assert_eq!(
+            install_list_command(&items, "paru", "doas").as_deref(),
+            Some("doas pacman -S ripgrep fd")
+        );
+        assert_eq!(install_list_command(&[], "paru", "sudo"), None);
+    }
+
+    #[test]
+    /// What: Verify AUR-only lists use the configured helper without a privilege prefix.
+    ///
+    /// Inputs:
+    /// - Two AUR packages with `yay` as helper.
+    ///
+    /// Output:
+    /// - `yay -S ...` in list order.
+    fn install_list_command_aur_only() {
+        let items = [
+            PackageItem::test_item("yay-bin", "aur"),
+            PackageItem::test_item("google-chrome", "aur"),
+        ];
+        assert_eq!(
+            install_list_command(&items, "yay", "sudo").as_deref(),
+            Some("yay -S yay-bin google-chrome")
+        );
+    }
+
+    #[test]
+    /// What: Verify mixed lists produce a single helper command with official packages first.
+    ///
+    /// Inputs:
+    /// - AUR, official, AUR, official packages, one with an unsafe name.
+    ///
+    /// Output:
+    /// - One `paru -S` command listing official then AUR packages; the unsafe name is quoted.
+    fn install_list_command_mixed() {
+        let items = [
+            PackageItem::test_item("yay-bin", "aur"),
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("Weird Name", "aur"),
+            PackageItem::test_item("fd", "extra"),
+        ];
+        assert_eq!(
+            install_list_command(&items, "paru", "sudo").as_deref(),
+            Some("paru -S ripgrep fd yay-bin 'Weird Name'")
+        );
+    }
+
+    #[test]
+    /// What: Verify explicit helper preferences are used verbatim.
+    ///
+    /// Inputs:
+    /// - `paru`, ` YAY `.
+    ///
+    /// Output:
+    /// - `paru` and `yay` regardless of what is installed.
+    fn resolve_aur_helper_honors_preference() {
+        assert_eq!(resolve_aur_helper("paru"), "paru");
+        assert_eq!(resolve_aur_helper(" YAY "), "yay");
+    }
+
+    #[test]
+    /// What: Verify the install-flow choice overrides the setting.
+    ///
+    /// Inputs:
+    /// - Choice/setting combinations including `auto` and mixed case.
+    ///
+    /// Output:
+    /// - The choice wins, explicit settings are normalised, and `auto` resolves to `None`.
+    fn selected_aur_helper_prefers_choice() {
+        assert_eq!(selected_aur_helper(None, "auto"), None);
+        assert_eq!(selected_aur_helper(None, "Yay"), Some("yay"));
+        assert_eq!(selected_aur_helper(Some("paru"), "yay"), Some("paru"));
+    }
+}
@@ -52,6 +52,120 @@ pub fn log_removed(names: &[String]) -> std::io::Result<()> {
     Ok(())
 }
 
+#[cfg(not(target_os = "windows"))]
+/// What: Format one audit log line for a spawned command.
+///
+/// Inputs:
+/// - `when`: Timestamp prefix (e.g. from `util::ts_to_date`).
+/// - `argv`: Resolved argument vector passed to the spawned process.
+///
+/// Output:
+/// - Single line (without trailing newline) of the form `<when> ["bash", "-c", "..."]`.
+///
+/// Details:
+/// - Every argument goes through the same password-pipe redaction as `last_command.log`,
+///   so `printf '%s\n' '<password>' | sudo -S` fragments never reach disk.
+#[must_use]
+pub fn format_audit_line(when: &str, argv: &[&str]) -> String {
+    let redacted: Vec<String> = argv
+        .iter()
+        .map(|arg| super::shell::redact_password_pipe_for_log(arg))
+        .collect();
+    format!("{when} {redacted:?}")
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Append a redacted audit line to the given log file.
+///
+/// Inputs:
+/// - `path`: Audit log destination.
+/// - `when`: Timestamp prefix.
+/// - `argv`: Resolved argument vector of the spawned process.
+///
+/// Output:
+/// - `Ok(())` on success; otherwise an I/O error.
+///
+/// # Errors
+/// - Returns `Err` when the log file cannot be opened or written to
+///
+/// Details:
+/// - Creates the file with mode `0o600` because command lines may reveal package choices
+///   and privileged operations even after redaction.
+pub fn append_audit_line(path: &std::path::Path, when: &str, argv: &[&str]) -> std::io::Result<()> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut f = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .mode(0o600)
+        .open(path)?;
+    writeln!(f, "{}", format_audit_line(when, argv))
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Record a spawned executor/terminal command in `logs_dir/audit.log` when enabled.
+///
+/// Inputs:
+/// - `argv`: Resolved argument vector of the process about to be spawned.
+///
+/// Output:
+/// - None (best-effort; failures are logged via `tracing`).
+///
+/// Details:
+/// - No-op unless `audit_log_commands` is enabled in `settings.conf`.
+/// - Called by the PTY executor and by [`spawn_audited`], which every terminal and `bash`
+///   fallback spawn goes through, so install, remove, update, and downgrade commands all land
+///   in one log.
+pub fn audit_log_spawn(argv: &[&str]) {
+    if !crate::theme::settings().audit_log_commands {
+        return;
+    }
+    let mut path = crate::theme::logs_dir();
+    path.push("audit.log");
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .and_then(|d| i64::try_from(d.as_secs()).ok());
+    let when = crate::util::ts_to_date(now);
+    if let Err(e) = append_audit_line(&path, &when, argv) {
+        tracing::warn!(path = %path.display(), error = %e, "audit: failed to append command line");
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+/// What: Spawn a prepared command and record its final argv in the audit log.
+///
+/// Inputs:
+/// - `cmd`: Fully configured command (program, arguments, environment).
+/// - `script`: Body of the temporary script the command runs, when the argv only references it.
+///
+/// Output:
+/// - The spawned child, or the spawn error.
+///
+/// # Errors
+/// - Returns `Err` when the process cannot be spawned; nothing is audited in that case.
+///
+/// Details:
+/// - The audited argv is read back from `cmd`, so it matches what was actually executed.
+/// - `script` is appended as a trailing `# script: ...` entry so script-based launches still
+///   record the commands they run.
+pub fn spawn_audited(
+    cmd: &mut std::process::Command,
+    script: Option<&str>,
+) -> std::io::Result<std::process::Child> {
+    let child = cmd.spawn()?;
+    let mut argv: Vec<String> = std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    if let Some(body) = script {
+        argv.push(format!("# script: {body}"));
+    }
+    let argv: Vec<&str> = argv.iter().map(String::as_str).collect();
+    audit_log_spawn(&argv);
+    Ok(child)
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -107,4 +221,70 @@ mod tests {
             }
         }
     }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    /// What: Ensure a dry-run install launched through a terminal lands in the audit log.
+    ///
+    /// Inputs:
+    /// - `HOME` redirected to a temp dir whose `settings.conf` enables `audit_log_commands`.
+    /// - Shim `gnome-terminal` first on `PATH`; `spawn_install` in dry-run mode with a password.
+    ///
+    /// Output:
+    /// - `logs/audit.log` holds one line with the terminal argv and the package name; the
+    ///   password never reaches disk, and a password pipe in the argv is redacted.
+    ///
+    /// Details:
+    /// - Holds `global_test_mutex_lock` because `HOME`, `PATH`, and settings are process-global.
+    fn audit_log_records_terminal_install_spawn() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let _guard = crate::global_test_mutex_lock();
+        let home = tempfile::tempdir().expect("tempdir");
+        let config = home.path().join(".config").join("pacsea");
+        std::fs::create_dir_all(&config).expect("create config dir");
+        std::fs::write(config.join("settings.conf"), "audit_log_commands = true\n")
+            .expect("write settings.conf");
+        let bin = home.path().join("bin");
+        std::fs::create_dir_all(&bin).expect("create bin dir");
+        let term = bin.join("gnome-terminal");
+        std::fs::write(&term, "#!/bin/sh\n: > \"$PACSEA_TEST_OUT\"\n").expect("write shim");
+        std::fs::set_permissions(&term, std::fs::Permissions::from_mode(0o755))
+            .expect("make shim executable");
+
+        let saved: Vec<_> = ["HOME", "PATH", "XDG_CONFIG_HOME", "PACSEA_TEST_OUT"]
+            .into_iter()
+            .map(|name| (name, std::env::var_os(name)))
+            .collect();
+        unsafe {
+            std::env::set_var("HOME", home.path());
+            std::env::set_var("PATH", &bin);
+            std::env::remove_var("XDG_CONFIG_HOME");
+            std::env::set_var("PACSEA_TEST_OUT", home.path().join("args.txt"));
+        }
+        let pkg = crate::state::PackageItem::test_item("ripgrep", "extra");
+        super::super::spawn_install(&pkg, Some("hunter2"), true);
+        let body = std::fs::read_to_string(crate::theme::logs_dir().join("audit.log"));
+        unsafe {
+            for (name, value) in saved {
+                match value {
+                    Some(v) => std::env::set_var(name, v),
+                    None => std::env::remove_var(name),
+                }
+            }
+        }
+
+        let body = body.expect("audit log written");
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 1, "expected one audit line, got: {body}");
+        assert!(lines[0].contains("[\"gnome-terminal\", \"--\", \"bash\", \"-lc\", "));
+        assert!(lines[0].contains("ripgrep"));
+        assert!(!body.contains("hunter2"));
+
+        let piped = "printf '%s\\n' 'hunter2' | sudo -S pacman -S --noconfirm 'ripgrep'";
+        let line = super::format_audit_line("2026-01-02 03:04:05", &["bash", "-c", piped]);
+        assert!(line.starts_with("2026-01-02 03:04:05 [\"bash\", \"-c\", "));
+        assert!(line.contains("[REDACTED]"));
+        assert!(!line.contains("hunter2"));
+    }
 }
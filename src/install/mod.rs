@@ -10,8 +10,12 @@ pub mod command;
 mod direct;
 /// Executor for package operations.
 mod executor;
+/// Copyable install command for the whole install list.
+mod list_command;
 /// Logging utilities for install operations.
 mod logging;
+/// Dry-run plans of install/remove/downgrade commands.
+mod plan;
 /// Package removal operations.
 mod remove;
 /// Security scanning operations.
@@ -20,10 +24,14 @@ mod scan;
 mod shell;
 /// Single package installation.
 mod single;
+/// Full system upgrade ("update all").
+mod upgrade;
 /// Utility functions for install operations.
 mod utils;
 
 pub use batch::spawn_install_all;
+#[cfg(not(target_os = "windows"))]
+pub use logging::audit_log_spawn;
 pub use logging::log_removed;
 mod patterns;
 pub use remove::{check_config_directories, spawn_remove_all};
@@ -84,7 +92,8 @@ pub fn spawn_aur_scan_for_with_config(
     );
 }
 pub use direct::{
-    start_integrated_install, start_integrated_install_all, start_integrated_remove_all,
+    reject_aur_in_pacman_only, start_integrated_install, start_integrated_install_all,
+    start_integrated_remove_all,
 };
 #[cfg(not(target_os = "windows"))]
 pub use executor::build_scan_command_for_executor;
@@ -93,8 +102,13 @@ pub use executor::{
     build_install_command_for_executor, build_remove_command_for_executor,
     build_update_command_for_executor,
 };
+pub use list_command::{
+    copy_install_list_command, install_list_command, resolve_aur_helper, selected_aur_helper,
+};
+pub use plan::{PlannedCommand, log_plan, plan_downgrade, plan_install, plan_remove};
 pub use shell::spawn_shell_commands_in_terminal;
 pub use single::spawn_install;
+pub use upgrade::{full_upgrade_commands, spawn_full_upgrade};
 #[cfg(not(target_os = "windows"))]
 pub use utils::editor_open_config_command;
 pub use utils::shell_single_quote;
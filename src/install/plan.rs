@@ -0,0 +1,330 @@
+//! Dry-run plans: the exact commands an install/remove/downgrade would run, as data.
+//!
+//! Builders here never spawn anything, so `--dry-run` sessions and scripts can log or emit
+//! the plan instead of opening a terminal that echoes it.
+
+use crate::state::modal::CascadeMode;
+use crate::state::{AppState, PackageItem, Source};
+
+use super::command::{ensure_pacman_only_allows, pacman_only_mode};
+use super::list_command::{resolve_aur_helper, selected_aur_helper};
+use super::utils::validate_package_names;
+
+/// One command of an operation plan, as an argv vector.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct PlannedCommand {
+    /// Program to execute (e.g. `sudo`, `paru`).
+    pub program: String,
+    /// Arguments passed to `program`, unquoted.
+    pub args: Vec<String>,
+}
+
+impl PlannedCommand {
+    /// What: Build a planned command from a program and its arguments.
+    #[must_use]
+    pub fn new<I, S>(program: &str, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            program: program.to_string(),
+            args: args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for PlannedCommand {
+    /// Space-joined argv; package names are validated before planning, so no quoting is needed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.program)?;
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+        Ok(())
+    }
+}
+
+/// What: Plan the commands installing `items` would run.
+///
+/// Inputs:
+/// - `app`: Application state; `install_aur_helper` selects the AUR helper.
+/// - `items`: Packages to install.
+///
+/// Output:
+/// - `Ok(commands)` in execution order; empty for an empty list.
+///
+/// # Errors
+///
+/// Returns `Err` for invalid package names, AUR targets in pacman-only mode, or an
+/// unresolvable privilege tool when official packages are present.
+///
+/// Details:
+/// - See [`plan_install_for`] for the command layout.
+pub fn plan_install(app: &AppState, items: &[PackageItem]) -> Result<Vec<PlannedCommand>, String> {
+    plan_install_for(items, app.install_aur_helper.as_deref())
+}
+
+/// What: Plan an install with an explicit in-flow helper choice.
+///
+/// Inputs:
+/// - `items`: Packages to install.
+/// - `helper_choice`: Helper picked in the install flow, or `None` to follow the `aur_helper` setting.
+///
+/// Output:
+/// - `Ok(commands)` in execution order.
+///
+/// # Errors
+///
+/// Same as [`plan_install`].
+///
+/// Details:
+/// - `auto` resolves to the helper the shell fallback would pick (`paru`, then `yay`).
+/// - Reinstall detection matches the executor: installed or provided packages drop `--needed`.
+pub(super) fn plan_install_for(
+    items: &[PackageItem],
+    helper_choice: Option<&str>,
+) -> Result<Vec<PlannedCommand>, String> {
+    let mut official: Vec<String> = Vec::new();
+    let mut aur: Vec<String> = Vec::new();
+    for item in items {
+        match item.source {
+            Source::Official { .. } => official.push(item.name.clone()),
+            Source::Aur => aur.push(item.name.clone()),
+        }
+    }
+    validate_package_names(&official, "install plan (official)")?;
+    validate_package_names(&aur, "install plan (AUR)")?;
+    ensure_pacman_only_allows(items, pacman_only_mode())?;
+    let privilege = if official.is_empty() {
+        ""
+    } else {
+        crate::logic::privilege::active_tool()?.binary_name()
+    };
+    let settings = crate::theme::settings();
+    let helper = selected_aur_helper(helper_choice, &settings.aur_helper)
+        .unwrap_or_else(|| resolve_aur_helper("auto"));
+    let installed = crate::logic::deps::get_installed_packages();
+    let provided = crate::logic::deps::get_provided_packages(&installed);
+    Ok(plan_install_with(items, privilege, helper, |name| {
+        crate::logic::deps::is_package_installed_or_provided(name, &installed, &provided)
+    }))
+}
+
+/// What: Lay out the install commands for already-validated items.
+///
+/// Inputs:
+/// - `items`: Packages to install.
+/// - `privilege`: Privilege tool binary (e.g. `sudo`) for the pacman steps.
+/// - `helper`: AUR helper binary for the AUR step.
+/// - `is_installed`: Whether a package name is already installed (or provided).
+///
+/// Output:
+/// - `privilege pacman -Sy`, `privilege pacman -S ... official`, then `helper -S --aur ... aur`;
+///   steps without targets are omitted.
+fn plan_install_with(
+    items: &[PackageItem],
+    privilege: &str,
+    helper: &str,
+    is_installed: impl Fn(&str) -> bool,
+) -> Vec<PlannedCommand> {
+    let (aur, official): (Vec<&PackageItem>, Vec<&PackageItem>) = items
+        .iter()
+        .partition(|item| matches!(item.source, Source::Aur));
+    let flags = |group: &[&PackageItem]| -> Vec<String> {
+        let reinstall = group.iter().any(|item| is_installed(&item.name));
+        let mut flags = vec!["-S".to_string()];
+        if !reinstall {
+            flags.push("--needed".to_string());
+        }
+        flags.push("--noconfirm".to_string());
+        flags
+    };
+    let mut plan = Vec::new();
+    if !official.is_empty() {
+        plan.push(PlannedCommand::new(privilege, ["pacman", "-Sy"]));
+        let mut args = vec!["pacman".to_string()];
+        args.extend(flags(&official));
+        args.extend(official.iter().map(|item| item.name.clone()));
+        plan.push(PlannedCommand::new(privilege, args));
+    }
+    if !aur.is_empty() {
+        let mut args = flags(&aur);
+        args.insert(1, "--aur".to_string());
+        args.extend(aur.iter().map(|item| item.name.clone()));
+        plan.push(PlannedCommand::new(helper, args));
+    }
+    plan
+}
+
+/// What: Plan the command removing `names` would run.
+///
+/// Inputs:
+/// - `names`: Packages to remove.
+/// - `cascade`: Removal mode (`-R`, `-Rs`, or `-Rns`).
+///
+/// Output:
+/// - `Ok([privilege pacman <flag> --noconfirm names...])`, or an empty plan for no names.
+///
+/// # Errors
+///
+/// Returns `Err` for invalid package names or an unresolvable privilege tool.
+pub fn plan_remove(names: &[String], cascade: CascadeMode) -> Result<Vec<PlannedCommand>, String> {
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+    validate_package_names(names, "remove plan")?;
+    let tool = crate::logic::privilege::active_tool()?;
+    let mut args = vec![
+        "pacman".to_string(),
+        cascade.flag().to_string(),
+        "--noconfirm".to_string(),
+    ];
+    args.extend(names.iter().cloned());
+    Ok(vec![PlannedCommand::new(tool.binary_name(), args)])
+}
+
+/// What: Plan the command downgrading `names` would run.
+///
+/// Inputs:
+/// - `names`: Packages to downgrade.
+///
+/// Output:
+/// - `Ok([privilege downgrade names...])`, or an empty plan for no names.
+///
+/// # Errors
+///
+/// Returns `Err` for invalid package names or an unresolvable privilege tool.
+pub fn plan_downgrade(names: &[String]) -> Result<Vec<PlannedCommand>, String> {
+    if names.is_empty() {
+        return Ok(Vec::new());
+    }
+    validate_package_names(names, "downgrade plan")?;
+    let tool = crate::logic::privilege::active_tool()?;
+    let mut args = vec!["downgrade".to_string()];
+    args.extend(names.iter().cloned());
+    Ok(vec![PlannedCommand::new(tool.binary_name(), args)])
+}
+
+/// What: Log a dry-run plan instead of executing it.
+///
+/// Inputs:
+/// - `operation`: Operation name for the log (`install`, `remove`, ...).
+/// - `plan`: Result of one of the `plan_*` builders.
+///
+/// Output:
+/// - None; one info line per planned command, or a warning when planning failed.
+pub fn log_plan(operation: &str, plan: &Result<Vec<PlannedCommand>, String>) {
+    match plan {
+        Ok(commands) if commands.is_empty() => {
+            tracing::info!(operation, "dry-run plan: nothing to do");
+        }
+        Ok(commands) => {
+            for (step, command) in commands.iter().enumerate() {
+                tracing::info!(operation, step = step + 1, command = %command, "dry-run plan");
+            }
+        }
+        Err(error) => tracing::warn!(operation, error = %error, "dry-run plan failed"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify a mixed official/AUR list plans sync, pacman install, then the AUR helper.
+    ///
+    /// Inputs:
+    /// - Two official and two AUR packages in interleaved order; one official package installed.
+    ///
+    /// Output:
+    /// - Three commands; the reinstall group drops `--needed`, the AUR step adds `--aur`.
+    fn plan_install_mixed_list() {
+        let items = [
+            PackageItem::test_item("yay-bin", "aur"),
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("google-chrome", "aur"),
+            PackageItem::test_item("fd", "extra"),
+        ];
+        let plan = plan_install_with(&items, "doas", "yay", |name| name == "fd");
+        assert_eq!(
+            plan,
+            [
+                PlannedCommand::new("doas", ["pacman", "-Sy"]),
+                PlannedCommand::new("doas", ["pacman", "-S", "--noconfirm", "ripgrep", "fd"]),
+                PlannedCommand::new(
+                    "yay",
+                    [
+                        "-S",
+                        "--aur",
+                        "--needed",
+                        "--noconfirm",
+                        "yay-bin",
+                        "google-chrome"
+                    ]
+                ),
+            ]
+        );
+        assert_eq!(
+            plan[2].to_string(),
+            "yay -S --aur --needed --noconfirm yay-bin google-chrome"
+        );
+
+        let aur_only = plan_install_with(&items[..1], "", "paru", |_| false);
+        assert_eq!(
+            aur_only,
+            [PlannedCommand::new(
+                "paru",
+                ["-S", "--aur", "--needed", "--noconfirm", "yay-bin"]
+            )]
+        );
+        assert!(plan_install_with(&[], "sudo", "paru", |_| false).is_empty());
+    }
+
+    #[test]
+    /// What: Verify the in-flow helper choice is honored and invalid names are refused.
+    ///
+    /// Inputs:
+    /// - An AUR-only list planned with `paru` chosen; an official package with an unsafe name.
+    ///
+    /// Output:
+    /// - The AUR step runs `paru`; the unsafe name yields `Err` without planning anything.
+    fn plan_install_for_uses_choice_and_validates() {
+        let plan = plan_install_for(
+            &[PackageItem::test_item("pacsea-plan-test-aur", "aur")],
+            Some("paru"),
+        )
+        .expect("AUR-only plan");
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].program, "paru");
+        assert!(plan_install_for(&[PackageItem::test_item("bad;name", "extra")], None).is_err());
+    }
+
+    #[test]
+    /// What: Verify remove and downgrade plans wrap pacman/downgrade in the privilege tool.
+    ///
+    /// Inputs:
+    /// - Two names with `CascadeWithConfigs`; one name for downgrade; empty lists.
+    ///
+    /// Output:
+    /// - `<tool> pacman -Rns --noconfirm a b`, `<tool> downgrade a`, and empty plans.
+    fn plan_remove_and_downgrade() {
+        let bin = crate::logic::privilege::active_tool()
+            .expect("privilege tool")
+            .binary_name();
+        let names = vec!["ripgrep".to_string(), "fd".to_string()];
+        let plan = plan_remove(&names, CascadeMode::CascadeWithConfigs).expect("remove plan");
+        assert_eq!(
+            plan,
+            [PlannedCommand::new(
+                bin,
+                ["pacman", "-Rns", "--noconfirm", "ripgrep", "fd"]
+            )]
+        );
+        let plan = plan_downgrade(&names[..1]).expect("downgrade plan");
+        assert_eq!(plan, [PlannedCommand::new(bin, ["downgrade", "ripgrep"])]);
+        assert_eq!(plan_remove(&[], CascadeMode::Basic), Ok(Vec::new()));
+        assert_eq!(plan_downgrade(&[]), Ok(Vec::new()));
+    }
+}
@@ -35,6 +35,8 @@ pub fn check_config_directories(package_name: &str, home: &str) -> Vec<std::path
     found_dirs
 }
 
+#[cfg(not(target_os = "windows"))]
+use super::logging::spawn_audited;
 #[cfg(not(target_os = "windows"))]
 use super::utils::{choose_terminal_index_prefer_path, command_on_path, shell_single_quote};
 
@@ -131,7 +133,7 @@ fn try_spawn_terminal(
     configure_test_env(&mut cmd);
     configure_terminal_env(&mut cmd, term);
 
-    match cmd.spawn() {
+    match spawn_audited(&mut cmd, None) {
         Ok(_) => {
             tracing::info!(
                 terminal = %term,
@@ -266,7 +268,7 @@ pub fn spawn_remove_all(names: &[String], dry_run: bool, cascade_mode: CascadeMo
     }
 
     if !launched {
-        let res = Command::new("bash").args(["-lc", &cmd_str]).spawn();
+        let res = spawn_audited(Command::new("bash").args(["-lc", &cmd_str]), None);
         if let Err(e) = res {
             tracing::error!(error = %e, names = %names_str, "failed to spawn bash to run removal command");
         } else {
@@ -1,5 +1,7 @@
 use std::process::Command;
 
+#[cfg(not(target_os = "windows"))]
+use super::logging::spawn_audited;
 #[cfg(not(target_os = "windows"))]
 use super::utils::{choose_terminal_index_prefer_path, command_on_path, shell_single_quote};
 
@@ -82,6 +84,19 @@ fn configure_terminal_env(cmd: &mut Command, term: &str, is_wayland: bool) {
     }
 }
 
+#[cfg(not(target_os = "windows"))]
+/// What: Script body to record in the audit log for a launch.
+///
+/// Input:
+/// - `script_exec`: What the terminal runs (`bash '<temp script>'` or the inline command).
+/// - `cmd_str`: The full command string.
+///
+/// Output:
+/// - `Some(cmd_str)` when a temp script is used; `None` when the command is already inline.
+fn script_body<'a>(script_exec: &str, cmd_str: &'a str) -> Option<&'a str> {
+    (script_exec != cmd_str).then_some(cmd_str)
+}
+
 #[cfg(not(target_os = "windows"))]
 /// What: Build and spawn a terminal command with logging.
 ///
@@ -126,7 +141,7 @@ fn try_spawn_terminal(
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null());
     }
-    let res = cmd.spawn();
+    let res = spawn_audited(&mut cmd, script_body(script_exec, cmd_str));
     match &res {
         Ok(child) => {
             log_to_terminal_log(&format!("spawn result: ok pid={}\n", child.id()));
@@ -285,7 +300,7 @@ fn persist_command_to_log_path(log_path: &std::path::Path, cmd_str: &str) {
 /// - Targets the exact prefix used by privilege builders: `printf '%s\n'`.
 /// - Keeps the surrounding command structure intact for debugging while removing sensitive material.
 #[must_use]
-fn redact_password_pipe_for_log(cmd_str: &str) -> String {
+pub(super) fn redact_password_pipe_for_log(cmd_str: &str) -> String {
     let marker = "printf '%s\\n' ";
     let mut out = String::with_capacity(cmd_str.len());
     let mut rest = cmd_str;
@@ -485,7 +500,10 @@ pub fn spawn_shell_commands_in_terminal_with_hold(cmds: &[String], hold: bool) {
             ["-lc"],
             cmd_str.len()
         ));
-        let res = Command::new("bash").args(["-lc", &script_exec]).spawn();
+        let res = spawn_audited(
+            Command::new("bash").args(["-lc", &script_exec]),
+            script_body(&script_exec, &cmd_str),
+        );
         match &res {
             Ok(child) => {
                 log_to_terminal_log(&format!("spawn result: ok pid={}\n", child.id()));
@@ -10,7 +10,7 @@ use super::command::build_install_command;
 #[cfg(all(target_os = "windows", not(test)))]
 use super::command::build_install_command;
 #[cfg(not(target_os = "windows"))]
-use super::logging::log_installed;
+use super::logging::{log_installed, spawn_audited};
 #[cfg(not(target_os = "windows"))]
 use super::utils::{choose_terminal_index_prefer_path, command_on_path, shell_single_quote};
 
@@ -53,7 +53,7 @@ fn try_spawn_terminal(
         }
         cmd.env("PACSEA_TEST_OUT", p);
     }
-    match cmd.spawn() {
+    match spawn_audited(&mut cmd, None) {
         Ok(_) => {
             tracing::info!(
                 terminal = %term,
@@ -144,7 +144,7 @@ pub fn spawn_install(item: &PackageItem, password: Option<&str>, dry_run: bool)
     let (cmd_str, uses_sudo) = match build_install_command(item, password, dry_run) {
         Ok(v) => v,
         Err(err) => {
-            tracing::error!(error = %err, names = %item.name, "privilege tool resolution failed for install");
+            tracing::error!(error = %err, names = %item.name, "failed to build install command");
             return;
         }
     };
@@ -200,7 +200,7 @@ pub fn spawn_install(item: &PackageItem, password: Option<&str>, dry_run: bool)
 
     // Final fallback: use bash directly
     if !launched {
-        let res = Command::new("bash").args(["-lc", &cmd_str]).spawn();
+        let res = spawn_audited(Command::new("bash").args(["-lc", &cmd_str]), None);
         if let Err(e) = res {
             tracing::error!(error = %e, names = %item.name, "failed to spawn bash to run install command");
         } else {
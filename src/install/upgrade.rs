@@ -0,0 +1,240 @@
+//! Full system upgrade ("update all"), independent of the install list.
+//!
+//! Builds `pacman -Syu` plus the AUR helper's `-Sua` (or the user's `upgrade_command`
+//! template) and runs it through the same executor pipeline as the system update modal.
+
+use crate::state::AppState;
+
+use super::list_command::selected_aur_helper;
+use super::utils::command_on_path;
+
+/// Placeholder in `upgrade_command` replaced by the privilege tool.
+const PRIVILEGE_PLACEHOLDER: &str = "{privilege}";
+/// Placeholder in `upgrade_command` replaced by the AUR helper.
+const AUR_HELPER_PLACEHOLDER: &str = "{aur_helper}";
+
+/// What: Resolve the AUR helper the full upgrade runs with.
+///
+/// Inputs:
+/// - `choice`: Helper picked in the install flow, if any.
+/// - `setting`: `aur_helper` setting (`auto`, `paru`, or `yay`).
+/// - `pacman_only`: Whether pacman-only mode is active.
+///
+/// Output:
+/// - The helper binary, or `None` when AUR packages are not upgraded.
+///
+/// Details:
+/// - An explicit helper is used even when missing so the failure is visible; `auto` picks an
+///   installed `paru`, then `yay`, and skips the AUR step when neither is installed.
+fn upgrade_aur_helper(
+    choice: Option<&str>,
+    setting: &str,
+    pacman_only: bool,
+) -> Option<&'static str> {
+    if pacman_only {
+        return None;
+    }
+    selected_aur_helper(choice, setting)
+        .or_else(|| ["paru", "yay"].into_iter().find(|h| command_on_path(h)))
+}
+
+/// What: Compose the commands of a full system upgrade.
+///
+/// Inputs:
+/// - `template`: `upgrade_command` setting; empty selects the built-in commands.
+/// - `privilege`: Privilege tool binary (e.g. `sudo`).
+/// - `aur_helper`: AUR helper binary, or `None` to skip the AUR step.
+///
+/// Output:
+/// - `Ok(commands)` to run in order, or `Err(message)` when the template needs an AUR helper
+///   that is not available.
+///
+/// # Errors
+/// - Returns `Err` when the template uses `{aur_helper}` and `aur_helper` is `None`
+///
+/// Details:
+/// - Built-in: `{privilege} pacman -Syu --noconfirm`, then `{aur_helper} -Sua --noconfirm`.
+/// - A template is a single command line with `{privilege}` and `{aur_helper}` substituted.
+pub fn full_upgrade_commands(
+    template: &str,
+    privilege: &str,
+    aur_helper: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let template = template.trim();
+    if template.is_empty() {
+        let mut cmds = vec![format!("{privilege} pacman -Syu --noconfirm")];
+        if let Some(helper) = aur_helper {
+            cmds.push(format!("{helper} -Sua --noconfirm"));
+        }
+        return Ok(cmds);
+    }
+    let mut cmd = template.replace(PRIVILEGE_PLACEHOLDER, privilege);
+    if cmd.contains(AUR_HELPER_PLACEHOLDER) {
+        let Some(helper) = aur_helper else {
+            return Err(format!(
+                "upgrade_command uses {AUR_HELPER_PLACEHOLDER}, but no AUR helper is available"
+            ));
+        };
+        cmd = cmd.replace(AUR_HELPER_PLACEHOLDER, helper);
+    }
+    Ok(vec![cmd])
+}
+
+/// What: Upgrade every installed package (official and AUR) in one operation.
+///
+/// Inputs:
+/// - `app`: Mutable application state
+/// - `dry_run`: Whether to only print the commands
+///
+/// Output:
+/// - Opens `PreflightExec` with an `ExecutorRequest::Update` (or the password prompt first)
+///   and sets `refresh_updates`; shows `Modal::Alert` when the commands cannot be built.
+///
+/// Details:
+/// - Commands come from [`full_upgrade_commands`] using the `upgrade_command` setting; the AUR
+///   step is skipped in pacman-only mode.
+/// - Dry runs execute nothing privileged, so they skip authentication.
+pub fn spawn_full_upgrade(app: &mut AppState, dry_run: bool) {
+    app.dry_run = dry_run;
+
+    // Guardrail: refuse to start while the pacman database is locked (dry-run executes nothing)
+    if !dry_run && let Some(message) = crate::events::guardrails::db_lock_alert_message(app) {
+        tracing::warn!("[Upgrade] Blocking full upgrade: pacman database is locked");
+        app.modal = crate::state::Modal::Alert { message };
+        return;
+    }
+
+    let settings = crate::theme::settings();
+    let tool = match crate::logic::privilege::active_tool() {
+        Ok(tool) => tool,
+        Err(message) => {
+            app.modal = crate::state::Modal::Alert { message };
+            return;
+        }
+    };
+    let aur_helper = upgrade_aur_helper(
+        app.install_aur_helper.as_deref(),
+        &settings.aur_helper,
+        super::command::pacman_only_mode(),
+    );
+    let cmds =
+        match full_upgrade_commands(&settings.upgrade_command, tool.binary_name(), aur_helper) {
+            Ok(cmds) => cmds,
+            Err(message) => {
+                app.modal = crate::state::Modal::Alert { message };
+                return;
+            }
+        };
+    tracing::info!(commands = ?cmds, dry_run, "[Upgrade] Starting full system upgrade");
+    app.refresh_updates = true;
+
+    if !dry_run {
+        crate::events::start_update_execution(app, cmds);
+        return;
+    }
+    app.modal = crate::state::Modal::PreflightExec {
+        items: Vec::new(),
+        action: crate::state::PreflightAction::Install,
+        tab: crate::state::PreflightTab::Summary,
+        verbose: false,
+        log_lines: Vec::new(),
+        abortable: false,
+        header_chips: crate::state::modal::PreflightHeaderChips::default(),
+        success: None,
+    };
+    app.pending_executor_request = Some(super::ExecutorRequest::Update {
+        commands: cmds,
+        password: None,
+        dry_run: true,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify built-in and template commands resolve their placeholders.
+    ///
+    /// Inputs:
+    /// - Empty template with and without a helper; a template using both placeholders.
+    ///
+    /// Output:
+    /// - Built-in pacman/AUR commands, the substituted template, and an error when the template
+    ///   needs a missing helper.
+    fn full_upgrade_commands_resolve_template() {
+        assert_eq!(
+            full_upgrade_commands("", "doas", Some("yay")).expect("built-in"),
+            vec!["doas pacman -Syu --noconfirm", "yay -Sua --noconfirm"]
+        );
+        assert_eq!(
+            full_upgrade_commands("  ", "sudo", None).expect("built-in"),
+            vec!["sudo pacman -Syu --noconfirm"]
+        );
+        let template = "{privilege} pacman -Syyu --noconfirm && {aur_helper} -Sua --devel";
+        assert_eq!(
+            full_upgrade_commands(template, "sudo", Some("paru")).expect("template"),
+            vec!["sudo pacman -Syyu --noconfirm && paru -Sua --devel"]
+        );
+        assert!(full_upgrade_commands(template, "sudo", None).is_err());
+    }
+
+    #[test]
+    /// What: Verify a dry-run full upgrade queues the resolved commands and refreshes updates.
+    ///
+    /// Inputs:
+    /// - Default settings, `PATH` without any AUR helper, `dry_run = true`.
+    ///
+    /// Output:
+    /// - `ExecutorRequest::Update` with the privilege tool's `pacman -Syu` only, `dry_run` set,
+    ///   `PreflightExec` open, and `refresh_updates` set.
+    fn spawn_full_upgrade_dry_run_queues_commands() {
+        let _guard = crate::global_test_mutex_lock();
+        let dir = std::env::temp_dir().join(format!(
+            "pacsea_test_full_upgrade_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("System time is before UNIX epoch")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).expect("create test dir");
+        let orig_path = std::env::var_os("PATH");
+        unsafe { std::env::set_var("PATH", &dir) };
+
+        let mut app = AppState::default();
+        spawn_full_upgrade(&mut app, true);
+        let privilege = crate::logic::privilege::active_tool()
+            .expect("privilege tool")
+            .binary_name();
+
+        unsafe {
+            if let Some(v) = orig_path {
+                std::env::set_var("PATH", v);
+            } else {
+                std::env::remove_var("PATH");
+            }
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(app.refresh_updates);
+        assert!(matches!(
+            app.modal,
+            crate::state::Modal::PreflightExec { .. }
+        ));
+        let Some(crate::install::ExecutorRequest::Update {
+            commands, dry_run, ..
+        }) = &app.pending_executor_request
+        else {
+            panic!(
+                "expected Update request, got {:?}",
+                app.pending_executor_request
+            );
+        };
+        assert!(*dry_run);
+        assert_eq!(
+            commands,
+            &vec![format!("{privilege} pacman -Syu --noconfirm")]
+        );
+    }
+}
@@ -52,7 +52,7 @@
 //! | [`app`] | `app::run`, `app::sandbox_cache`, `app::services_cache` | Terminal runtime orchestration, cache persistence, sandbox + service metadata. |
 //! | [`events`] | `events::search`, `events::recent` | Keyboard/mouse dispatchers that mutate `state::AppState`.
 //! | [`logic`] | `logic::send_query`, `logic::deps::resolve_dependencies` | Core business rules for querying indices, ranking, and dependency analysis. |
-//! | [`install`] | `install::command`, `install::spawn_install`, `install::spawn_remove_all` | Batch + single install orchestration, scan integrations, terminal helpers. |
+//! | [`install`] | `install::command`, `install::spawn_install`, `install::spawn_remove_all`, `install::plan_install` | Batch + single install orchestration, dry-run plans, scan integrations, terminal helpers. |
 //! | [`index`] | `index::load_from_disk`, `index::all_official`, `index::save_to_disk` | Persistent Arch index management and enrichment queues. |
 //! | [`state`] | `state::AppState`, `state::types::PackageItem` | Shared UI/runtime data model and domain structs. |
 //! | [`theme`] & [`ui`] | `theme::settings`, `ui::middle`, `ui::details` | Theme resolution, keymaps, and ratatui component tree. |
@@ -108,6 +108,7 @@
 //!             description: "fixture".into(),
 //!         }],
 //!         name_to_idx: HashMap::new(), // Skipped during serialization
+//!         sync_stamp: None,
 //!     };
 //!     std::fs::write(&tmp, serde_json::to_string(&snapshot).unwrap()).unwrap();
 //!     load_from_disk(&tmp);
@@ -0,0 +1,187 @@
+//! Correlation of security advisories with installed package versions.
+//!
+//! An advisory lists affected packages but not whether the installed version is still
+//! vulnerable. When the advisory text names a fixed version, the installed version is compared
+//! against it; otherwise any installed affected package is treated as potentially affected.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::logic::preflight::compare_versions;
+use crate::state::types::{NewsFeedItem, NewsFeedSource};
+
+/// Phrases that introduce the fixed version in advisory titles and summaries (lowercase).
+const FIXED_VERSION_MARKERS: &[&str] = &[
+    "fixed version:",
+    "fixed version",
+    "fixed in",
+    "fixed:",
+    "upgrade to",
+    "update to",
+];
+
+/// What: Extract the fixed version mentioned in advisory text.
+///
+/// Inputs:
+/// - `text`: Advisory title or summary.
+///
+/// Output:
+/// - `Some(version)` for the first version-like token after a known marker, `None` otherwise.
+///
+/// Details:
+/// - Markers are matched case-insensitively; the version token must start with a digit and
+///   may be preceded by a package name (`fixed in openssl 3.4.1-1`).
+/// - Trailing punctuation (`.`, `,`, `;`, `)`) is stripped from the token.
+#[must_use]
+pub fn fixed_version_hint(text: &str) -> Option<String> {
+    let lower = text.to_ascii_lowercase();
+    FIXED_VERSION_MARKERS.iter().find_map(|marker| {
+        let start = lower.find(marker)? + marker.len();
+        text.get(start..)?
+            .split_whitespace()
+            .take(2)
+            .map(|tok| tok.trim_end_matches(['.', ',', ';', ')']))
+            .find(|tok| tok.starts_with(|c: char| c.is_ascii_digit()))
+            .map(str::to_string)
+    })
+}
+
+/// What: Decide whether an advisory potentially affects installed packages.
+///
+/// Inputs:
+/// - `item`: News Feed item.
+/// - `installed_version`: Lookup returning the installed version of a package, if installed.
+///
+/// Output:
+/// - `true` when the item is a security advisory and at least one listed package is installed
+///   at a version that is not known to be fixed.
+///
+/// Details:
+/// - The fixed-version hint is taken from the summary, then the title.
+/// - With a hint, an installed version older than the fixed one is affected; equal or newer is
+///   not. Without a hint, any installed listed package counts as potentially affected.
+#[must_use]
+pub fn advisory_potentially_affected<F>(item: &NewsFeedItem, installed_version: F) -> bool
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if !matches!(item.source, NewsFeedSource::SecurityAdvisory) {
+        return false;
+    }
+    let fixed = item
+        .summary
+        .as_deref()
+        .and_then(fixed_version_hint)
+        .or_else(|| fixed_version_hint(&item.title));
+    item.packages.iter().any(|pkg| {
+        installed_version(pkg).is_some_and(|installed| {
+            fixed
+                .as_deref()
+                .is_none_or(|fixed| compare_versions(&installed, fixed) == Ordering::Less)
+        })
+    })
+}
+
+/// What: Collect the ids of advisories that potentially affect installed packages.
+///
+/// Inputs:
+/// - `items`: News Feed items.
+/// - `installed_version`: Lookup returning the installed version of a package, if installed.
+///
+/// Output:
+/// - Set of item ids flagged by [`advisory_potentially_affected`].
+#[must_use]
+pub fn affected_advisory_ids<F>(items: &[NewsFeedItem], installed_version: F) -> HashSet<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    items
+        .iter()
+        .filter(|item| advisory_potentially_affected(item, &installed_version))
+        .map(|item| item.id.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::types::AdvisorySeverity;
+
+    /// What: Build an advisory for `openssl` with the given summary.
+    ///
+    /// Inputs:
+    /// - `summary`: Advisory summary text.
+    ///
+    /// Output:
+    /// - Security advisory `NewsFeedItem` listing `openssl`.
+    fn openssl_advisory(summary: &str) -> NewsFeedItem {
+        NewsFeedItem {
+            id: "AVG-1".into(),
+            date: "2025-01-02".into(),
+            title: "openssl: multiple issues".into(),
+            summary: Some(summary.into()),
+            url: None,
+            source: NewsFeedSource::SecurityAdvisory,
+            severity: Some(AdvisorySeverity::High),
+            packages: vec!["openssl".into()],
+        }
+    }
+
+    #[test]
+    /// What: Verify fixed-version hints are extracted from common phrasings.
+    ///
+    /// Inputs:
+    /// - Summaries using "fixed in", "Fixed version:", "upgrade to" with a package name, and none.
+    ///
+    /// Output:
+    /// - The version token without trailing punctuation; `None` without a marker.
+    fn fixed_version_hint_parses_markers() {
+        assert_eq!(
+            fixed_version_hint("Issue fixed in 3.4.1-1.").as_deref(),
+            Some("3.4.1-1")
+        );
+        assert_eq!(
+            fixed_version_hint("Fixed version: 1:2.0-3, released today").as_deref(),
+            Some("1:2.0-3")
+        );
+        assert_eq!(
+            fixed_version_hint("Please upgrade to openssl 3.4.1").as_deref(),
+            Some("3.4.1")
+        );
+        assert_eq!(fixed_version_hint("Remote code execution"), None);
+    }
+
+    #[test]
+    /// What: Verify the affected flag for installed-older, installed-newer, and not-installed cases.
+    ///
+    /// Inputs:
+    /// - Advisory fixed in `3.4.1-1` with `openssl` installed at `3.4.0-1`, `3.4.2-1`, or absent.
+    /// - The same advisory without a fixed-version hint, and a non-advisory item.
+    ///
+    /// Output:
+    /// - Older installed version is affected; newer and not-installed are not. Without a hint any
+    ///   installed version is potentially affected; non-advisories are never flagged.
+    fn advisory_affected_compares_installed_versions() {
+        let item = openssl_advisory("Fixed in 3.4.1-1");
+        let installed =
+            |v: &'static str| move |name: &str| (name == "openssl").then(|| v.to_string());
+
+        assert!(advisory_potentially_affected(&item, installed("3.4.0-1")));
+        assert!(!advisory_potentially_affected(&item, installed("3.4.2-1")));
+        assert!(!advisory_potentially_affected(&item, installed("3.4.1-1")));
+        assert!(!advisory_potentially_affected(&item, |_: &str| None));
+
+        let no_hint = openssl_advisory("Remote code execution");
+        assert!(advisory_potentially_affected(
+            &no_hint,
+            installed("9.9.9-1")
+        ));
+
+        let mut news = openssl_advisory("Fixed in 3.4.1-1");
+        news.source = NewsFeedSource::ArchNews;
+        assert!(!advisory_potentially_affected(&news, installed("3.4.0-1")));
+
+        let ids = affected_advisory_ids(&[item, news], installed("3.4.0-1"));
+        assert_eq!(ids, HashSet::from(["AVG-1".to_string()]));
+    }
+}
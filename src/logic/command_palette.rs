@@ -0,0 +1,173 @@
+//! Action registry for the command palette (fuzzy action launcher).
+//!
+//! Each action has a stable id used for dispatch (`events::command_palette`) and an i18n key
+//! for its label; the palette ranks labels against the typed query with
+//! [`crate::util::fuzzy_match_rank`].
+
+use crate::state::AppState;
+use crate::state::types::AppMode;
+
+/// What: One entry of the command palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteAction {
+    /// Stable identifier used for dispatch (e.g. `filter.toggle_aur`).
+    pub id: &'static str,
+    /// i18n key of the displayed label.
+    pub label_key: &'static str,
+    /// Whether the action only applies in package mode (filters, installed-only, activity log).
+    pub package_mode_only: bool,
+}
+
+/// What: Build a registry entry.
+const fn action(
+    id: &'static str,
+    label_key: &'static str,
+    package_mode_only: bool,
+) -> PaletteAction {
+    PaletteAction {
+        id,
+        label_key,
+        package_mode_only,
+    }
+}
+
+/// Every palette action, in the order shown for an empty query.
+pub const PALETTE_ACTIONS: &[PaletteAction] = &[
+    action(
+        "help.open",
+        "app.modals.command_palette.actions.help_open",
+        false,
+    ),
+    action(
+        "options.system_update",
+        "app.modals.command_palette.actions.system_update",
+        false,
+    ),
+    action(
+        "mode.toggle_news",
+        "app.modals.command_palette.actions.toggle_news",
+        false,
+    ),
+    action(
+        "sort.cycle",
+        "app.modals.command_palette.actions.sort_cycle",
+        false,
+    ),
+    action(
+        "search.toggle_fuzzy",
+        "app.modals.command_palette.actions.toggle_fuzzy",
+        true,
+    ),
+    action(
+        "filter.toggle_aur",
+        "app.modals.command_palette.actions.filter_aur",
+        true,
+    ),
+    action(
+        "filter.toggle_core",
+        "app.modals.command_palette.actions.filter_core",
+        true,
+    ),
+    action(
+        "filter.toggle_extra",
+        "app.modals.command_palette.actions.filter_extra",
+        true,
+    ),
+    action(
+        "filter.toggle_multilib",
+        "app.modals.command_palette.actions.filter_multilib",
+        true,
+    ),
+    action(
+        "options.installed_only",
+        "app.modals.command_palette.actions.installed_only",
+        true,
+    ),
+    action(
+        "options.optional_deps",
+        "app.modals.command_palette.actions.optional_deps",
+        false,
+    ),
+    action(
+        "options.repositories",
+        "app.modals.command_palette.actions.repositories",
+        false,
+    ),
+    action(
+        "options.activity_log",
+        "app.modals.command_palette.actions.activity_log",
+        true,
+    ),
+    action(
+        "panels.toggle_footer",
+        "app.modals.command_palette.actions.toggle_footer",
+        false,
+    ),
+    action(
+        "pkgbuild.toggle",
+        "app.modals.command_palette.actions.toggle_pkgbuild",
+        true,
+    ),
+    action(
+        "comments.toggle",
+        "app.modals.command_palette.actions.toggle_comments",
+        true,
+    ),
+    action(
+        "config.reload",
+        "app.modals.command_palette.actions.reload_config",
+        false,
+    ),
+    action(
+        "fetch.retry_failed",
+        "app.modals.command_palette.actions.retry_failed",
+        false,
+    ),
+];
+
+/// What: Look up a palette action by id.
+///
+/// Inputs:
+/// - `id`: Stable action id.
+///
+/// Output:
+/// - The registry entry, or `None` for unknown ids.
+#[must_use]
+pub fn find_action(id: &str) -> Option<&'static PaletteAction> {
+    PALETTE_ACTIONS.iter().find(|a| a.id == id)
+}
+
+/// What: List the palette actions matching a query in the current mode.
+///
+/// Inputs:
+/// - `app`: Application state (mode and translations).
+/// - `query`: Text typed into the palette.
+///
+/// Output:
+/// - Matching actions, best match first.
+///
+/// Details:
+/// - A blank query lists every available action in registry order.
+/// - Otherwise the translated label and the id are both ranked with
+///   [`crate::util::fuzzy_match_rank`]; ties keep registry order.
+/// - Package-only actions are hidden in News mode.
+#[must_use]
+pub fn palette_matches(app: &AppState, query: &str) -> Vec<&'static PaletteAction> {
+    let news_mode = matches!(app.app_mode, AppMode::News);
+    let available = PALETTE_ACTIONS
+        .iter()
+        .filter(|a| !(news_mode && a.package_mode_only));
+    if query.trim().is_empty() {
+        return available.collect();
+    }
+    let mut ranked: Vec<(i64, &'static PaletteAction)> = available
+        .filter_map(|a| {
+            let label = crate::i18n::t(app, a.label_key);
+            let score = crate::util::fuzzy_match_rank(&label, query)
+                .max(crate::util::fuzzy_match_rank(a.id, query))?;
+            Some((score, a))
+        })
+        .collect();
+    ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    ranked.into_iter().map(|(_, a)| a).collect()
+}
@@ -13,19 +13,22 @@ mod utils;
 use crate::state::modal::{DependencyInfo, DependencyStatus};
 use crate::state::types::{PackageItem, Source};
 use parse::parse_dep_spec;
-use resolve::{batch_fetch_official_deps, fetch_package_conflicts, resolve_package_deps};
+use resolve::{
+    BatchedDeps, batch_fetch_official_deps, fetch_package_conflicts,
+    process_optional_dependency_specs, resolve_package_deps,
+};
 use source::{determine_dependency_source, is_system_package};
 use status::determine_status;
 use std::collections::{HashMap, HashSet};
 use utils::dependency_priority;
 
 pub use query::{
-    get_installed_packages, get_provided_packages, get_upgradable_packages,
-    is_package_installed_or_provided,
+    InstalledProvider, get_installed_packages, get_provided_packages, get_upgradable_packages,
+    is_package_installed_or_provided, resolve_installed_provider,
 };
 pub use reverse::{
     ReverseDependencyReport, get_installed_required_by, has_installed_required_by,
-    resolve_reverse_dependencies,
+    orphan_removal_preview, resolve_reverse_dependencies,
 };
 pub use status::{get_installed_version, version_satisfies};
 
@@ -109,6 +112,8 @@ fn process_conflicts(
                 depends_on: Vec::new(),
                 is_core,
                 is_system,
+                optional: false,
+                description: None,
             }
         });
 
@@ -145,6 +150,8 @@ fn process_conflicts(
                     depends_on: Vec::new(),
                     is_core,
                     is_system,
+                    optional: false,
+                    description: None,
                 }
             });
 
@@ -207,6 +214,8 @@ fn process_batched_dependencies(
             depends_on: Vec::new(),
             is_core,
             is_system,
+            optional: false,
+            description: None,
         });
     }
     deps
@@ -255,6 +264,8 @@ fn merge_dependency(
             depends_on: Vec::new(),
             is_core: dep.is_core,
             is_system: dep.is_system,
+            optional: false,
+            description: None,
         });
 
     // Update required_by (add the parent if not already present)
@@ -303,11 +314,51 @@ fn merge_dependency(
     }
 }
 
+/// What: Merge an optional dependency into the dependency map.
+///
+/// Inputs:
+/// - `dep`: Optional dependency record (`optional: true`).
+/// - `parent_name`: Name of the package that suggests this dependency.
+/// - `root_names`: Packages the user asked to install.
+/// - `deps`: Mutable reference to the dependency map to update.
+///
+/// Output:
+/// - Adds the entry or extends `required_by` of an existing optional entry.
+///
+/// Details:
+/// - Skips packages that are already hard dependencies or part of the install set; they are
+///   pulled in anyway, so listing them as optional would only duplicate rows.
+/// - Keeps the first non-empty description when several packages suggest the same dependency.
+fn merge_optional_dependency(
+    dep: DependencyInfo,
+    parent_name: &str,
+    root_names: &HashSet<String>,
+    deps: &mut HashMap<String, DependencyInfo>,
+) {
+    if root_names.contains(&dep.name) {
+        return;
+    }
+    match deps.get_mut(&dep.name) {
+        Some(entry) if !entry.optional => {}
+        Some(entry) => {
+            if !entry.required_by.iter().any(|r| r == parent_name) {
+                entry.required_by.push(parent_name.to_string());
+            }
+            if entry.description.is_none() {
+                entry.description = dep.description;
+            }
+        }
+        None => {
+            deps.insert(dep.name.clone(), dep);
+        }
+    }
+}
+
 /// What: Resolve dependencies for a single package.
 ///
 /// Inputs:
 /// - `item`: Package item to resolve dependencies for.
-/// - `batched_deps_cache`: Optional cache of batched dependencies for official packages.
+/// - `batched_deps_cache`: Optional cache of batched dependency specs for official packages.
 /// - `installed`: Set of installed package names.
 /// - `provided`: Map of provided packages.
 /// - `upgradable`: Set of upgradable package names.
@@ -319,7 +370,7 @@ fn merge_dependency(
 /// - Uses batched cache if available for official packages, otherwise calls `resolve_package_deps`.
 fn resolve_single_package_deps(
     item: &PackageItem,
-    batched_deps_cache: &HashMap<String, Vec<String>>,
+    batched_deps_cache: &HashMap<String, BatchedDeps>,
     installed: &HashSet<String>,
     provided: &HashSet<String>,
     upgradable: &HashSet<String>,
@@ -338,12 +389,19 @@ fn resolve_single_package_deps(
         && batched_deps_cache.contains_key(name.as_str());
 
     if use_batched {
-        // Use batched dependency list
-        let dep_names = batched_deps_cache
+        // Use batched dependency lists
+        let batched = batched_deps_cache
             .get(name.as_str())
             .cloned()
             .unwrap_or_default();
-        let deps = process_batched_dependencies(name, dep_names, installed, provided, upgradable);
+        let mut deps =
+            process_batched_dependencies(name, batched.depends, installed, provided, upgradable);
+        deps.extend(process_optional_dependency_specs(
+            batched.optdepends,
+            name,
+            installed,
+            provided,
+        ));
         Ok(deps)
     } else {
         resolve_package_deps(name, source, installed, provided, upgradable)
@@ -360,8 +418,11 @@ fn resolve_single_package_deps(
 ///
 /// Details:
 /// - Resolves ONLY direct dependencies (non-recursive) for each package in the list.
+/// - When `preflight_aur_dep_depth` is set, AUR dependencies are expanded up to that many levels.
 /// - Merges duplicates by name, retaining the most severe status across all requesters.
 /// - Populates `depends_on` and `required_by` relationships to reflect dependency relationships.
+/// - Optional dependencies (`optdepends`) are returned flagged `optional`, after all hard
+///   dependencies; names that are also hard dependencies or install targets are omitted.
 pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
     let _span = tracing::info_span!(
         "resolve_dependencies",
@@ -457,6 +518,8 @@ pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
 
     // Resolve ONLY direct dependencies (non-recursive)
     // This is faster and avoids resolving transitive dependencies which can be slow and error-prone
+    // Optional dependencies are held back until every hard dependency is known
+    let mut optional_deps: Vec<(DependencyInfo, String)> = Vec::new();
     for item in items {
         match resolve_single_package_deps(
             item,
@@ -473,6 +536,10 @@ pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
                 );
 
                 for dep in resolved_deps {
+                    if dep.optional {
+                        optional_deps.push((dep, item.name.clone()));
+                        continue;
+                    }
                     merge_dependency(
                         &dep,
                         &item.name,
@@ -492,15 +559,38 @@ pub fn resolve_dependencies(items: &[PackageItem]) -> Vec<DependencyInfo> {
         }
     }
 
+    // Optionally expand AUR dependencies of AUR dependencies (bounded, opt-in to limit AUR RPC calls)
+    let aur_depth = crate::theme::settings().preflight_aur_dep_depth;
+    if aur_depth > 0 {
+        aur::expand_aur_dependencies(
+            &mut deps,
+            &root_names,
+            aur_depth,
+            &installed,
+            &provided,
+            &upgradable,
+            |name| {
+                resolve_package_deps(name, &Source::Aur, &installed, &provided, &upgradable)
+                    .map(|nested| nested.into_iter().filter(|d| !d.optional).collect())
+            },
+        );
+    }
+
+    for (dep, parent_name) in optional_deps {
+        merge_optional_dependency(dep, &parent_name, &root_names, &mut deps);
+    }
+
     let mut result: Vec<DependencyInfo> = deps.into_values().collect();
     tracing::info!("Total unique dependencies found: {}", result.len());
 
-    // Sort dependencies: conflicts first, then missing, then to-install, then installed
+    // Sort dependencies: hard before optional, then conflicts first, then missing,
+    // then to-install, then installed
     result.sort_by(|a, b| {
         let priority_a = dependency_priority(&a.status);
         let priority_b = dependency_priority(&b.status);
-        priority_a
-            .cmp(&priority_b)
+        a.optional
+            .cmp(&b.optional)
+            .then_with(|| priority_a.cmp(&priority_b))
             .then_with(|| a.name.cmp(&b.name))
     });
 
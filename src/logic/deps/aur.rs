@@ -1 +1,277 @@
 //! AUR-specific dependency resolution.
+
+use super::merge_dependency;
+use crate::state::modal::{DependencyInfo, DependencySource, DependencyStatus};
+use std::collections::{HashMap, HashSet};
+
+/// Upper bound for nested AUR expansion regardless of the configured depth.
+pub(super) const MAX_AUR_DEP_DEPTH: usize = 5;
+
+/// What: Check whether a resolved dependency is an AUR package that still has to be built.
+///
+/// Inputs:
+/// - `dep`: Dependency record to inspect.
+///
+/// Output:
+/// - `true` for AUR dependencies that are missing or need an upgrade.
+///
+/// Details:
+/// - Installed AUR dependencies already satisfy their own dependencies, so they are not expanded.
+const fn needs_aur_build(dep: &DependencyInfo) -> bool {
+    matches!(dep.source, DependencySource::Aur)
+        && matches!(
+            dep.status,
+            DependencyStatus::ToInstall | DependencyStatus::ToUpgrade { .. }
+        )
+}
+
+/// What: Expand AUR dependencies of AUR dependencies up to a bounded depth.
+///
+/// Inputs:
+/// - `deps`: Dependency map produced by the direct (non-recursive) pass; updated in place.
+/// - `root_names`: Packages the user asked to install.
+/// - `max_depth`: Number of nested levels to expand (clamped to `MAX_AUR_DEP_DEPTH`).
+/// - `installed`: Set of installed package names.
+/// - `provided`: Map of provided packages.
+/// - `upgradable`: Set of upgradable package names.
+/// - `resolve`: Resolves the direct dependencies of one AUR package.
+///
+/// Output:
+/// - Adds nested dependencies to `deps` and fills `depends_on` for each expanded AUR package.
+///
+/// Details:
+/// - Each AUR package is resolved at most once, so dependency cycles terminate.
+/// - Nested dependencies are attributed both to their AUR parent and to the root packages
+///   that pulled the parent in, so they stay visible under the root in the Deps tab.
+/// - Every nested record keeps its own `source`, marking AUR vs official at each level.
+pub(super) fn expand_aur_dependencies<F>(
+    deps: &mut HashMap<String, DependencyInfo>,
+    root_names: &HashSet<String>,
+    max_depth: usize,
+    installed: &HashSet<String>,
+    provided: &HashSet<String>,
+    upgradable: &HashSet<String>,
+    mut resolve: F,
+) where
+    F: FnMut(&str) -> Result<Vec<DependencyInfo>, String>,
+{
+    let max_depth = max_depth.min(MAX_AUR_DEP_DEPTH);
+    if max_depth == 0 {
+        return;
+    }
+
+    // Frontier entries carry the root packages that (transitively) require them
+    let mut frontier: Vec<(String, Vec<String>)> = deps
+        .values()
+        .filter(|dep| needs_aur_build(dep) && !root_names.contains(&dep.name))
+        .map(|dep| {
+            let roots = dep
+                .required_by
+                .iter()
+                .filter(|r| root_names.contains(*r))
+                .cloned()
+                .collect();
+            (dep.name.clone(), roots)
+        })
+        .collect();
+    frontier.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut visited: HashSet<String> = root_names.clone();
+    visited.extend(frontier.iter().map(|(name, _)| name.clone()));
+
+    for depth in 1..=max_depth {
+        if frontier.is_empty() {
+            break;
+        }
+        tracing::debug!(
+            "Expanding {} AUR dependencies at depth {}",
+            frontier.len(),
+            depth
+        );
+        let mut next = Vec::new();
+        for (parent, roots) in frontier {
+            let children = match resolve(&parent) {
+                Ok(children) => children,
+                Err(e) => {
+                    tracing::warn!("  Failed to expand AUR dependency {}: {}", parent, e);
+                    continue;
+                }
+            };
+            for child in children {
+                merge_dependency(&child, &parent, installed, provided, upgradable, deps);
+                for root in &roots {
+                    merge_dependency(&child, root, installed, provided, upgradable, deps);
+                }
+                if let Some(entry) = deps.get_mut(&parent)
+                    && !entry.depends_on.contains(&child.name)
+                {
+                    entry.depends_on.push(child.name.clone());
+                }
+                if needs_aur_build(&child) && visited.insert(child.name.clone()) {
+                    next.push((child.name.clone(), roots.clone()));
+                }
+            }
+        }
+        frontier = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dep(name: &str, source: DependencySource, required_by: &str) -> DependencyInfo {
+        DependencyInfo {
+            name: name.into(),
+            version: String::new(),
+            status: DependencyStatus::ToInstall,
+            source,
+            required_by: vec![required_by.into()],
+            depends_on: Vec::new(),
+            is_core: false,
+            is_system: false,
+            optional: false,
+            description: None,
+        }
+    }
+
+    fn official(name: &str, required_by: &str) -> DependencyInfo {
+        dep(
+            name,
+            DependencySource::Official {
+                repo: "extra".into(),
+            },
+            required_by,
+        )
+    }
+
+    /// Mock AUR graph: `app` (root) -> `libfoo-git` -> `libbar-git` -> (`libfoo-git`, `libbaz-git`)
+    /// and `libbaz-git` -> `libqux-git`.
+    fn mock_graph(name: &str) -> Result<Vec<DependencyInfo>, String> {
+        Ok(match name {
+            "libfoo-git" => vec![
+                dep("libbar-git", DependencySource::Aur, name),
+                official("cmake", name),
+            ],
+            "libbar-git" => vec![
+                dep("libfoo-git", DependencySource::Aur, name),
+                dep("libbaz-git", DependencySource::Aur, name),
+            ],
+            "libbaz-git" => vec![dep("libqux-git", DependencySource::Aur, name)],
+            "broken-git" => return Err("rpc failed".into()),
+            _ => Vec::new(),
+        })
+    }
+
+    fn direct_pass() -> (HashMap<String, DependencyInfo>, HashSet<String>) {
+        let mut deps = HashMap::new();
+        deps.insert(
+            "libfoo-git".to_string(),
+            dep("libfoo-git", DependencySource::Aur, "app"),
+        );
+        let mut glibc = official("glibc", "app");
+        glibc.status = DependencyStatus::Installed {
+            version: "2.40".into(),
+        };
+        deps.insert("glibc".to_string(), glibc);
+        (deps, HashSet::from(["app".to_string()]))
+    }
+
+    fn expand(
+        deps: &mut HashMap<String, DependencyInfo>,
+        roots: &HashSet<String>,
+        depth: usize,
+    ) -> Vec<String> {
+        let empty = HashSet::new();
+        let mut calls = Vec::new();
+        expand_aur_dependencies(deps, roots, depth, &empty, &empty, &empty, |name| {
+            calls.push(name.to_string());
+            mock_graph(name)
+        });
+        calls
+    }
+
+    #[test]
+    /// What: Ensure a nested AUR dependency of an AUR dependency is surfaced with its source.
+    ///
+    /// Inputs:
+    /// - Direct pass containing `libfoo-git` (AUR) and `glibc` (official, installed) for root `app`.
+    ///
+    /// Output:
+    /// - `libbar-git` is added as AUR, required by both `libfoo-git` and `app`; `cmake` stays official.
+    ///
+    /// Details:
+    /// - Depth 1 expands only `libfoo-git`, so `libbaz-git` is not reached.
+    fn nested_aur_dependency_is_surfaced() {
+        let (mut deps, roots) = direct_pass();
+        let calls = expand(&mut deps, &roots, 1);
+
+        assert_eq!(calls, vec!["libfoo-git".to_string()]);
+        let bar = deps.get("libbar-git").expect("nested AUR dep surfaced");
+        assert!(matches!(bar.source, DependencySource::Aur));
+        assert!(bar.required_by.contains(&"libfoo-git".to_string()));
+        assert!(bar.required_by.contains(&"app".to_string()));
+        assert!(matches!(
+            deps["cmake"].source,
+            DependencySource::Official { .. }
+        ));
+        assert_eq!(
+            deps["libfoo-git"].depends_on,
+            vec!["libbar-git".to_string(), "cmake".to_string()]
+        );
+        assert!(!deps.contains_key("libbaz-git"));
+    }
+
+    #[test]
+    /// What: Verify the configured depth bounds expansion and cycles are resolved only once.
+    ///
+    /// Inputs:
+    /// - The mock graph where `libbar-git` depends back on `libfoo-git`.
+    ///
+    /// Output:
+    /// - Depth 2 stops before `libqux-git`; a huge depth is clamped and resolves each package once.
+    ///
+    /// Details:
+    /// - Depth 0 leaves the direct pass untouched without any resolver calls.
+    fn expansion_respects_depth_and_cycles() {
+        let (mut deps, roots) = direct_pass();
+        assert!(expand(&mut deps, &roots, 0).is_empty());
+        assert_eq!(deps.len(), 2);
+
+        let calls = expand(&mut deps, &roots, 2);
+        assert_eq!(calls, vec!["libfoo-git", "libbar-git"]);
+        assert!(deps.contains_key("libbaz-git"));
+        assert!(!deps.contains_key("libqux-git"));
+
+        let (mut deps, roots) = direct_pass();
+        let calls = expand(&mut deps, &roots, usize::MAX);
+        assert_eq!(
+            calls,
+            vec!["libfoo-git", "libbar-git", "libbaz-git", "libqux-git"]
+        );
+        assert_eq!(deps["libfoo-git"].required_by, vec!["app", "libbar-git"]);
+        assert!(deps["libqux-git"].required_by.contains(&"app".to_string()));
+    }
+
+    #[test]
+    /// What: Confirm resolver failures are skipped without aborting the expansion.
+    ///
+    /// Inputs:
+    /// - Direct pass with a failing `broken-git` AUR dependency next to `libfoo-git`.
+    ///
+    /// Output:
+    /// - `libfoo-git` is still expanded and `broken-git` keeps no `depends_on` entries.
+    ///
+    /// Details:
+    /// - Mirrors the direct pass, which logs and continues on per-package failures.
+    fn resolver_errors_are_skipped() {
+        let (mut deps, roots) = direct_pass();
+        deps.insert(
+            "broken-git".to_string(),
+            dep("broken-git", DependencySource::Aur, "app"),
+        );
+        let calls = expand(&mut deps, &roots, 1);
+        assert_eq!(calls, vec!["broken-git", "libfoo-git"]);
+        assert!(deps["broken-git"].depends_on.is_empty());
+        assert!(deps.contains_key("libbar-git"));
+    }
+}
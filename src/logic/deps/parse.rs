@@ -299,6 +299,76 @@ pub(super) fn parse_pacman_si_conflicts(text: &str) -> Vec<String> {
     Vec::new()
 }
 
+/// What: Extract optional dependency specifications from the `pacman -Si` "Optional Deps" field.
+///
+/// Inputs:
+/// - `text`: Raw stdout emitted by `pacman -Si`/`-Qi` or an AUR helper for one package.
+///
+/// Output:
+/// - Returns one `name[: description]` string per optional dependency, in output order.
+///
+/// Details:
+/// - pacman prints the first entry on the label line and each further entry on an indented
+///   continuation line, so entries are collected until the next unindented field.
+pub(super) fn parse_pacman_si_optdepends(text: &str) -> Vec<String> {
+    let none_labels = get_none_labels();
+    let mut specs = Vec::new();
+    let mut in_field = false;
+
+    for line in text.lines() {
+        let entry = if in_field && line.starts_with(char::is_whitespace) {
+            line.trim()
+        } else if line.starts_with("Optional Deps") || line.starts_with("Optionale Abhängigkeiten")
+        {
+            in_field = true;
+            line.split_once(':').map_or("", |(_, rest)| rest.trim())
+        } else if in_field {
+            break;
+        } else {
+            continue;
+        };
+        if entry.is_empty()
+            || none_labels
+                .iter()
+                .any(|label| entry.eq_ignore_ascii_case(label))
+        {
+            continue;
+        }
+        specs.push(entry.to_string());
+    }
+    specs
+}
+
+/// What: Split an optional dependency entry into name, version requirement and description.
+///
+/// Inputs:
+/// - `spec`: Entry from `optdepends` (e.g., `python-pyqt6: GUI support [installed]`).
+///
+/// Output:
+/// - Returns `(name, version_constraint, description)`; the description is `None` when absent.
+///
+/// Details:
+/// - Only `": "` (or a trailing `:`) separates the description, so epochs such as `foo>=1:2.0`
+///   stay part of the version constraint.
+/// - Drops the ` [installed]` marker that `pacman -Qi` appends to satisfied entries.
+pub(super) fn parse_optdepend_spec(spec: &str) -> (String, String, Option<String>) {
+    let spec = spec.trim();
+    let (dep, description) = match spec.split_once(": ") {
+        Some((dep, desc)) => (dep, Some(desc)),
+        None => (spec.strip_suffix(':').unwrap_or(spec), None),
+    };
+    let description = description
+        .map(|desc| {
+            desc.trim()
+                .trim_end_matches("[installed]")
+                .trim()
+                .to_string()
+        })
+        .filter(|desc| !desc.is_empty());
+    let (name, version) = parse_dep_spec(dep);
+    (name, version, description)
+}
+
 /// What: Split a dependency specification into name and version requirement components.
 ///
 /// Inputs:
@@ -430,4 +500,84 @@ mod tests {
         let conflicts = parse_pacman_si_conflicts(text);
         assert!(conflicts.is_empty());
     }
+
+    #[test]
+    /// What: Confirm optional dependency entries split with and without descriptions.
+    ///
+    /// Inputs:
+    /// - `optdepends` entries with a description, without one, with a version and epoch, and
+    ///   with the `[installed]` marker from `pacman -Qi`.
+    ///
+    /// Output:
+    /// - Names, constraints and trimmed descriptions; `None` when no description is given.
+    ///
+    /// Details:
+    /// - The epoch colon must not be mistaken for the description separator.
+    fn parse_optdepend_spec_with_and_without_description() {
+        assert_eq!(
+            parse_optdepend_spec("foo: does bar"),
+            (
+                "foo".to_string(),
+                String::new(),
+                Some("does bar".to_string())
+            )
+        );
+        assert_eq!(
+            parse_optdepend_spec("foo"),
+            ("foo".to_string(), String::new(), None)
+        );
+        assert_eq!(
+            parse_optdepend_spec("foo:"),
+            ("foo".to_string(), String::new(), None)
+        );
+        assert_eq!(
+            parse_optdepend_spec("qt6-base>=1:6.5: GUI support"),
+            (
+                "qt6-base".to_string(),
+                ">=1:6.5".to_string(),
+                Some("GUI support".to_string())
+            )
+        );
+        assert_eq!(
+            parse_optdepend_spec("python-pyqt6: GUI frontend [installed]"),
+            (
+                "python-pyqt6".to_string(),
+                String::new(),
+                Some("GUI frontend".to_string())
+            )
+        );
+    }
+
+    #[test]
+    /// What: Ensure the multi-line "Optional Deps" field is collected from pacman output.
+    ///
+    /// Inputs:
+    /// - `pacman -Si` output with three optional deps on continuation lines, and a package
+    ///   whose field is `None`.
+    ///
+    /// Output:
+    /// - All three raw entries in order; an empty list for `None`.
+    ///
+    /// Details:
+    /// - Parsing stops at the next unindented field.
+    fn parse_pacman_si_optdepends_multiline() {
+        let text = "\
+Name            : mpv
+Depends On      : ffmpeg libass
+Optional Deps   : yt-dlp: for video-sharing websites playback
+                  youtube-dl
+                  lua52: scripting support
+Required By     : None
+";
+        assert_eq!(
+            parse_pacman_si_optdepends(text),
+            vec![
+                "yt-dlp: for video-sharing websites playback".to_string(),
+                "youtube-dl".to_string(),
+                "lua52: scripting support".to_string(),
+            ]
+        );
+        let none = "Name            : ripgrep\nOptional Deps   : None\nRequired By     : None\n";
+        assert!(parse_pacman_si_optdepends(none).is_empty());
+    }
 }
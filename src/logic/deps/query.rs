@@ -1,9 +1,31 @@
 //! Package querying functions for dependency resolution.
 
+use super::status::get_installed_version;
 use std::collections::HashSet;
 use std::hash::BuildHasher;
 use std::process::{Command, Stdio};
 
+/// What: Installed package that satisfies a requested package name.
+///
+/// Inputs:
+/// - Produced by [`resolve_installed_provider`].
+///
+/// Output:
+/// - Names the concrete installed package and its version.
+///
+/// Details:
+/// - `via_provides` is set when the requested name is only a `provides` alias (e.g. `rust`
+///   satisfied by `rustup`), in which case `version` belongs to the providing package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledProvider {
+    /// Concrete installed package name.
+    pub package: String,
+    /// Installed version of `package` without the pkgrel suffix, when it could be read.
+    pub version: Option<String>,
+    /// Whether the requested name is satisfied only through a `provides` alias.
+    pub via_provides: bool,
+}
+
 /// What: Collect names of packages that have upgrades available via pacman.
 ///
 /// Inputs:
@@ -200,3 +222,140 @@ pub fn is_package_installed_or_provided<S: BuildHasher + Default>(
     // Lazy check if provided by any installed package (much faster than building full set upfront)
     check_if_provided(name, installed).is_some()
 }
+
+/// What: Map a requested package name to the installed package that satisfies it.
+///
+/// Inputs:
+/// - `name`: Package name as requested (may be a virtual/provides name).
+/// - `installed`: Set of directly installed package names.
+///
+/// Output:
+/// - Returns the concrete provider and its installed version, or `None` when nothing installed
+///   satisfies `name`.
+///
+/// Details:
+/// - Direct installs win; otherwise the providing package is looked up lazily like
+///   [`is_package_installed_or_provided`] does, and its version is read with `pacman -Q`.
+#[must_use]
+pub fn resolve_installed_provider<S: BuildHasher + Default>(
+    name: &str,
+    installed: &HashSet<String, S>,
+) -> Option<InstalledProvider> {
+    resolve_installed_provider_with(
+        name,
+        installed,
+        |n| check_if_provided(n, installed),
+        |pkg| get_installed_version(pkg).ok(),
+    )
+}
+
+/// What: Provider resolution with injectable lookups.
+///
+/// Inputs:
+/// - `name`: Requested package name.
+/// - `installed`: Set of directly installed package names.
+/// - `provider_of`: Returns the installed package providing a name, if any.
+/// - `version_of`: Returns the installed version of a concrete package.
+///
+/// Output:
+/// - Same as [`resolve_installed_provider`].
+///
+/// Details:
+/// - `provider_of` is only consulted when `name` is not installed directly.
+fn resolve_installed_provider_with<S, P, V>(
+    name: &str,
+    installed: &HashSet<String, S>,
+    provider_of: P,
+    version_of: V,
+) -> Option<InstalledProvider>
+where
+    S: BuildHasher,
+    P: FnOnce(&str) -> Option<String>,
+    V: FnOnce(&str) -> Option<String>,
+{
+    let package = if installed.contains(name) {
+        name.to_string()
+    } else {
+        provider_of(name)?
+    };
+    let version = version_of(&package);
+    Some(InstalledProvider {
+        via_provides: package != name,
+        package,
+        version,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What: Version lookup stub for a fixed set of installed packages.
+    fn versions(pkg: &str) -> Option<String> {
+        match pkg {
+            "ripgrep" => Some("14.1.0".to_string()),
+            "rustup" => Some("1.27.1".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    /// What: Verify a package that is neither installed nor provided resolves to nothing.
+    ///
+    /// Inputs:
+    /// - `fd` with an installed set lacking it and a provider lookup that finds nothing.
+    ///
+    /// Output:
+    /// - `None`, so the request is a plain install.
+    fn resolve_installed_provider_direct_install() {
+        let installed: HashSet<String> = HashSet::from(["ripgrep".to_string()]);
+        assert_eq!(
+            resolve_installed_provider_with("fd", &installed, |_| None, versions),
+            None
+        );
+    }
+
+    #[test]
+    /// What: Verify a name satisfied only through `provides` maps to the providing package.
+    ///
+    /// Inputs:
+    /// - `rust` requested while only `rustup` (which provides `rust`) is installed.
+    ///
+    /// Output:
+    /// - Provider `rustup` with its own version and `via_provides` set.
+    fn resolve_installed_provider_via_provides() {
+        let installed: HashSet<String> = HashSet::from(["rustup".to_string()]);
+        let provider = resolve_installed_provider_with(
+            "rust",
+            &installed,
+            |name| (name == "rust").then(|| "rustup".to_string()),
+            versions,
+        )
+        .expect("rust is provided by rustup");
+        assert_eq!(provider.package, "rustup");
+        assert_eq!(provider.version.as_deref(), Some("1.27.1"));
+        assert!(provider.via_provides);
+    }
+
+    #[test]
+    /// What: Verify a directly installed package resolves to itself without a provides lookup.
+    ///
+    /// Inputs:
+    /// - `ripgrep` in the installed set and a provider lookup that must not be called.
+    ///
+    /// Output:
+    /// - Provider `ripgrep` with its installed version and `via_provides` unset.
+    fn resolve_installed_provider_already_installed() {
+        let installed: HashSet<String> = HashSet::from(["ripgrep".to_string()]);
+        let provider = resolve_installed_provider_with(
+            "ripgrep",
+            &installed,
+            |_| panic!("provider lookup not needed for direct installs"),
+            versions,
+        )
+        .expect("ripgrep is installed");
+        assert_eq!(provider.package, "ripgrep");
+        assert_eq!(provider.version.as_deref(), Some("14.1.0"));
+        assert!(!provider.via_provides);
+    }
+}
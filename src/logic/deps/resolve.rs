@@ -1,9 +1,12 @@
 //! Core dependency resolution logic for individual packages.
 
-use super::parse::{parse_dep_spec, parse_pacman_si_conflicts, parse_pacman_si_deps};
+use super::parse::{
+    parse_dep_spec, parse_optdepend_spec, parse_pacman_si_conflicts, parse_pacman_si_deps,
+    parse_pacman_si_optdepends,
+};
 use super::source::{determine_dependency_source, is_system_package};
 use super::srcinfo::{fetch_srcinfo, parse_srcinfo_conflicts, parse_srcinfo_deps};
-use super::status::determine_status;
+use super::status::{determine_optional_status, determine_status};
 use crate::logic::files::get_pkgbuild_from_cache;
 use crate::logic::sandbox::{parse_pkgbuild_conflicts, parse_pkgbuild_deps};
 use crate::state::modal::DependencyInfo;
@@ -11,18 +14,27 @@ use crate::state::types::Source;
 use std::collections::{HashMap, HashSet};
 use std::process::{Command, Stdio};
 
+/// Hard and optional dependency specs of one official package from a batched `pacman -Si`.
+#[derive(Debug, Clone, Default)]
+pub(super) struct BatchedDeps {
+    /// Entries of the "Depends On" field.
+    pub(super) depends: Vec<String>,
+    /// Entries of the "Optional Deps" field (`name[: description]`).
+    pub(super) optdepends: Vec<String>,
+}
+
 /// What: Batch fetch dependency lists for multiple official packages using `pacman -Si`.
 ///
 /// Inputs:
 /// - `names`: Package names to query (must be official packages, not local).
 ///
 /// Output:
-/// - `HashMap` mapping package name to its dependency list (`Vec<String>`).
+/// - `HashMap` mapping package name to its hard and optional dependency specs.
 ///
 /// Details:
 /// - Batches queries into chunks of 50 to avoid command-line length limits.
 /// - Parses multi-package `pacman -Si` output (packages separated by blank lines).
-pub(super) fn batch_fetch_official_deps(names: &[&str]) -> HashMap<String, Vec<String>> {
+pub(super) fn batch_fetch_official_deps(names: &[&str]) -> HashMap<String, BatchedDeps> {
     const BATCH_SIZE: usize = 50;
     let mut result_map = HashMap::new();
 
@@ -60,14 +72,17 @@ pub(super) fn batch_fetch_official_deps(names: &[&str]) -> HashMap<String, Vec<S
 
                 // Parse each block to extract package name and dependencies
                 for block in package_blocks {
-                    let dep_names = parse_pacman_si_deps(&block);
+                    let batched = BatchedDeps {
+                        depends: parse_pacman_si_deps(&block),
+                        optdepends: parse_pacman_si_optdepends(&block),
+                    };
                     // Extract package name from block
                     if let Some(name_line) =
                         block.lines().find(|l| l.trim_start().starts_with("Name"))
                         && let Some((_, name)) = name_line.split_once(':')
                     {
                         let pkg_name = name.trim().to_string();
-                        result_map.insert(pkg_name, dep_names);
+                        result_map.insert(pkg_name, batched);
                     }
                 }
             }
@@ -162,6 +177,8 @@ fn process_dependency_spec(
         depends_on: Vec::new(),
         is_core,
         is_system,
+        optional: false,
+        description: None,
     })
 }
 
@@ -194,6 +211,52 @@ fn process_dependency_specs(
         .collect()
 }
 
+/// What: Convert `optdepends` entries into optional `DependencyInfo` records.
+///
+/// Inputs:
+/// - `opt_specs`: Optional dependency entries (`name[: description]`).
+/// - `parent_name`: Name of the package that suggests these dependencies.
+/// - `installed`: Set of locally installed packages.
+/// - `provided`: Set of package names provided by installed packages.
+///
+/// Output:
+/// - Returns records flagged `optional` with their descriptions, in input order.
+///
+/// Details:
+/// - Applies the same virtual-package and self-reference filtering as hard dependencies.
+/// - Installed or provided entries are reported as `Installed` (satisfied).
+pub(super) fn process_optional_dependency_specs(
+    opt_specs: Vec<String>,
+    parent_name: &str,
+    installed: &HashSet<String>,
+    provided: &HashSet<String>,
+) -> Vec<DependencyInfo> {
+    opt_specs
+        .into_iter()
+        .filter_map(|spec| {
+            let (pkg_name, version_req, description) = parse_optdepend_spec(&spec);
+            if pkg_name.is_empty() || should_filter_dependency(&pkg_name, parent_name) {
+                return None;
+            }
+            let status = determine_optional_status(&pkg_name, installed, provided);
+            let (source, is_core) = determine_dependency_source(&pkg_name, installed);
+            let is_system = is_core || is_system_package(&pkg_name);
+            Some(DependencyInfo {
+                name: pkg_name,
+                version: version_req,
+                status,
+                source,
+                required_by: vec![parent_name.to_string()],
+                depends_on: Vec::new(),
+                is_core,
+                is_system,
+                optional: true,
+                description,
+            })
+        })
+        .collect()
+}
+
 /// What: Resolve dependencies for a local package using pacman -Qi.
 ///
 /// Inputs:
@@ -240,6 +303,7 @@ fn resolve_local_package_deps(
 
     let text = String::from_utf8_lossy(&output.stdout);
     tracing::debug!("pacman -Qi {} output ({} bytes)", name, text.len());
+    crate::logic::raw_output::capture_raw_output(name, &format!("pacman -Qi {name}"), &text);
 
     let dep_names = parse_pacman_si_deps(&text);
     tracing::debug!(
@@ -247,9 +311,14 @@ fn resolve_local_package_deps(
         dep_names.len()
     );
 
-    Ok(process_dependency_specs(
-        dep_names, name, installed, provided, upgradable,
-    ))
+    let mut deps = process_dependency_specs(dep_names, name, installed, provided, upgradable);
+    deps.extend(process_optional_dependency_specs(
+        parse_pacman_si_optdepends(&text),
+        name,
+        installed,
+        provided,
+    ));
+    Ok(deps)
 }
 
 /// What: Resolve dependencies for an official package using pacman -Si.
@@ -300,6 +369,7 @@ fn resolve_official_package_deps(
 
     let text = String::from_utf8_lossy(&output.stdout);
     tracing::debug!("pacman -Si {} output ({} bytes)", name, text.len());
+    crate::logic::raw_output::capture_raw_output(name, &format!("pacman -Si {name}"), &text);
 
     let dep_names = parse_pacman_si_deps(&text);
     tracing::debug!(
@@ -307,9 +377,14 @@ fn resolve_official_package_deps(
         dep_names.len()
     );
 
-    Ok(process_dependency_specs(
-        dep_names, name, installed, provided, upgradable,
-    ))
+    let mut deps = process_dependency_specs(dep_names, name, installed, provided, upgradable);
+    deps.extend(process_optional_dependency_specs(
+        parse_pacman_si_optdepends(&text),
+        name,
+        installed,
+        provided,
+    ));
+    Ok(deps)
 }
 
 /// What: Try to resolve dependencies using an AUR helper (paru or yay).
@@ -357,6 +432,7 @@ fn try_helper_resolution(
 
     let text = String::from_utf8_lossy(&output.stdout);
     tracing::debug!("{} -Si {} output ({} bytes)", helper, name, text.len());
+    crate::logic::raw_output::capture_raw_output(name, &format!("{helper} -Si {name}"), &text);
     let dep_names = parse_pacman_si_deps(&text);
 
     if dep_names.is_empty() {
@@ -369,7 +445,13 @@ fn try_helper_resolution(
         name
     );
 
-    let deps = process_dependency_specs(dep_names, name, installed, provided, upgradable);
+    let mut deps = process_dependency_specs(dep_names, name, installed, provided, upgradable);
+    deps.extend(process_optional_dependency_specs(
+        parse_pacman_si_optdepends(&text),
+        name,
+        installed,
+        provided,
+    ));
     Some(deps)
 }
 
@@ -386,7 +468,7 @@ fn try_helper_resolution(
 /// - Returns the enhanced dependency list.
 ///
 /// Details:
-/// - Fetches and parses .SRCINFO to add missing depends entries.
+/// - Fetches and parses .SRCINFO to add missing depends and optdepends entries.
 fn enhance_with_srcinfo(
     name: &str,
     mut deps: Vec<DependencyInfo>,
@@ -428,6 +510,11 @@ fn enhance_with_srcinfo(
             })
             .filter(|dep_info| !existing_dep_names.contains(&dep_info.name)),
     );
+    deps.extend(
+        process_optional_dependency_specs(srcinfo_optdepends, name, installed, provided)
+            .into_iter()
+            .filter(|dep_info| !existing_dep_names.contains(&dep_info.name)),
+    );
 
     tracing::info!(
         "Enhanced dependency list with .SRCINFO data: total {} dependencies",
@@ -467,9 +554,16 @@ fn fallback_to_pkgbuild(
         "Using cached PKGBUILD for {} to resolve dependencies (offline fallback)",
         name
     );
-    let (pkgbuild_depends, _, _, _) = parse_pkgbuild_deps(&pkgbuild_text);
-
-    let deps = process_dependency_specs(pkgbuild_depends, name, installed, provided, upgradable);
+    let (pkgbuild_depends, _, _, pkgbuild_optdepends) = parse_pkgbuild_deps(&pkgbuild_text);
+
+    let mut deps =
+        process_dependency_specs(pkgbuild_depends, name, installed, provided, upgradable);
+    deps.extend(process_optional_dependency_specs(
+        pkgbuild_optdepends,
+        name,
+        installed,
+        provided,
+    ));
     tracing::info!(
         "Resolved {} dependencies from cached PKGBUILD for {}",
         deps.len(),
@@ -1,6 +1,9 @@
 //! Reverse dependency analysis for removal preflight checks.
 
-use crate::state::modal::{DependencyInfo, DependencySource, DependencyStatus, ReverseRootSummary};
+use super::parse::parse_dep_spec;
+use crate::state::modal::{
+    CascadeMode, DependencyInfo, DependencySource, DependencyStatus, ReverseRootSummary,
+};
 use crate::state::types::PackageItem;
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque, hash_map::Entry};
 use std::process::{Command, Stdio};
@@ -21,6 +24,8 @@ pub struct ReverseDependencyReport {
     pub dependencies: Vec<DependencyInfo>,
     /// Per-root summary statistics for the Summary tab.
     pub summaries: Vec<ReverseRootSummary>,
+    /// Installed dependencies that would be orphaned by the removal and dropped by `pacman -Rs`.
+    pub orphaned_dependencies: Vec<String>,
 }
 
 /// What: Internal working state used while traversing reverse dependencies.
@@ -146,6 +151,143 @@ impl ReverseResolverState {
             }
         }
     }
+
+    /// What: Walk the reverse dependency graph of one removal root breadth-first.
+    ///
+    /// Inputs:
+    /// - `root`: Removal target whose dependents should be aggregated.
+    ///
+    /// Output:
+    /// - Records every dependent reachable from `root` via `update_entry`.
+    ///
+    /// Details:
+    /// - Each package is visited at most once per root (visited set keyed by package name).
+    /// - Edges pointing back at the root or at an ancestor of the current package close a
+    ///   dependency cycle; they are skipped (and logged) so cycles neither loop nor add
+    ///   spurious parents to packages already on the path.
+    fn walk_root(&mut self, root: &str) {
+        // BFS tree: package -> package it was first reached from
+        let mut reached_from: HashMap<String, String> = HashMap::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        visited.insert(root.to_string());
+
+        let mut queue: VecDeque<(String, usize)> = VecDeque::new();
+        queue.push_back((root.to_string(), 0));
+
+        while let Some((current, depth)) = queue.pop_front() {
+            let Some(info) = self.pkg_info(&current) else {
+                continue;
+            };
+
+            for dependent in info.required_by.iter().filter(|name| !name.is_empty()) {
+                if is_on_path(&reached_from, &current, dependent) {
+                    tracing::debug!(
+                        "Skipping reverse dependency cycle edge {} -> {} (root {})",
+                        current,
+                        dependent,
+                        root
+                    );
+                    continue;
+                }
+
+                self.update_entry(dependent, &current, root, depth + 1);
+
+                if visited.insert(dependent.clone()) {
+                    reached_from.insert(dependent.clone(), current.clone());
+                    queue.push_back((dependent.clone(), depth + 1));
+                }
+            }
+        }
+    }
+
+    /// What: Collect dependencies that become orphans once the targets are removed.
+    ///
+    /// Inputs:
+    /// - `targets`: Packages selected for removal.
+    ///
+    /// Output:
+    /// - Returns sorted canonical names of dependencies `pacman -Rs` would remove alongside the targets.
+    ///
+    /// Details:
+    /// - A dependency is orphaned when it was not explicitly installed and every package requiring
+    ///   it is itself being removed; the walk repeats until no further dependency qualifies so
+    ///   chains of orphans are followed.
+    fn collect_orphaned_dependencies(&mut self, targets: &[PackageItem]) -> Vec<String> {
+        let mut removal: HashSet<String> = HashSet::new();
+        let mut pending: Vec<String> = Vec::new();
+        for target in targets {
+            let root = target.name.trim();
+            if root.is_empty() {
+                continue;
+            }
+            removal.insert(root.to_string());
+            if let Some(info) = self.pkg_info(root) {
+                removal.insert(info.name.clone());
+                pending.extend(info.depends_on);
+            }
+        }
+
+        let mut orphans: Vec<String> = Vec::new();
+        loop {
+            pending.sort();
+            pending.dedup();
+            let mut progressed = false;
+            let mut still_pending: Vec<String> = Vec::new();
+            for dep in std::mem::take(&mut pending) {
+                if removal.contains(&dep) {
+                    continue;
+                }
+                let Some(info) = self.pkg_info(&dep) else {
+                    continue;
+                };
+                if info.explicit || removal.contains(&info.name) {
+                    continue;
+                }
+                if info.required_by.iter().all(|req| removal.contains(req)) {
+                    removal.insert(dep);
+                    removal.insert(info.name.clone());
+                    still_pending.extend(info.depends_on);
+                    orphans.push(info.name);
+                    progressed = true;
+                } else {
+                    still_pending.push(dep);
+                }
+            }
+            pending = still_pending;
+            if !progressed {
+                break;
+            }
+        }
+
+        orphans.sort();
+        orphans.dedup();
+        orphans
+    }
+}
+
+/// What: Check whether a package lies on the BFS path from the root to `current`.
+///
+/// Inputs:
+/// - `reached_from`: BFS tree mapping each visited package to the package it was reached from.
+/// - `current`: Package whose dependents are being expanded.
+/// - `candidate`: Dependent about to be recorded.
+///
+/// Output:
+/// - `true` when `candidate` is `current`, the root, or another ancestor of `current`.
+///
+/// Details:
+/// - Such an edge means `candidate` (transitively) depends on itself, i.e. a dependency cycle.
+fn is_on_path(reached_from: &HashMap<String, String>, current: &str, candidate: &str) -> bool {
+    let mut node = current;
+    loop {
+        if node == candidate {
+            return true;
+        }
+        match reached_from.get(node) {
+            Some(parent) => node = parent,
+            None => return false,
+        }
+    }
 }
 
 /// What: Snapshot of metadata retrieved from pacman's local database for traversal decisions.
@@ -170,6 +312,8 @@ struct PkgInfo {
     groups: Vec<String>,
     /// Packages that require this package.
     required_by: Vec<String>,
+    /// Packages this package depends on (version constraints stripped).
+    depends_on: Vec<String>,
     /// Whether package was explicitly installed.
     explicit: bool,
 }
@@ -275,6 +419,7 @@ impl RootRelation {
 ///
 /// Details:
 /// - Performs a breadth-first search using `pacman -Qi` metadata, aggregating per-root relationships.
+/// - Dependency cycles are cut, so each package appears at most once in `dependencies`.
 pub fn resolve_reverse_dependencies(targets: &[PackageItem]) -> ReverseDependencyReport {
     tracing::info!(
         "Starting reverse dependency resolution for {} target(s)",
@@ -285,8 +430,24 @@ pub fn resolve_reverse_dependencies(targets: &[PackageItem]) -> ReverseDependenc
         return ReverseDependencyReport::default();
     }
 
-    let mut state = ReverseResolverState::new(targets);
+    build_report(ReverseResolverState::new(targets), targets)
+}
 
+/// What: Walk all removal roots and assemble the reverse dependency report.
+///
+/// Inputs:
+/// - `state`: Resolver state seeded with the targets (its cache may already hold metadata).
+/// - `targets`: Packages the user intends to uninstall.
+///
+/// Output:
+/// - Returns the aggregated `ReverseDependencyReport`.
+///
+/// Details:
+/// - Split from `resolve_reverse_dependencies` so tests can supply a mocked package graph.
+fn build_report(
+    mut state: ReverseResolverState,
+    targets: &[PackageItem],
+) -> ReverseDependencyReport {
     for target in targets {
         let root = target.name.trim();
         if root.is_empty() {
@@ -301,27 +462,10 @@ pub fn resolve_reverse_dependencies(targets: &[PackageItem]) -> ReverseDependenc
             continue;
         }
 
-        let mut visited: HashSet<String> = HashSet::new();
-        visited.insert(root.to_string());
-
-        let mut queue: VecDeque<(String, usize)> = VecDeque::new();
-        queue.push_back((root.to_string(), 0));
-
-        while let Some((current, depth)) = queue.pop_front() {
-            let Some(info) = state.pkg_info(&current) else {
-                continue;
-            };
-
-            for dependent in info.required_by.iter().filter(|name| !name.is_empty()) {
-                state.update_entry(dependent, &current, root, depth + 1);
-
-                if visited.insert(dependent.clone()) {
-                    queue.push_back((dependent.clone(), depth + 1));
-                }
-            }
-        }
+        state.walk_root(root);
     }
 
+    let orphaned_dependencies = state.collect_orphaned_dependencies(targets);
     let ReverseResolverState { aggregated, .. } = state;
 
     let mut summary_map: HashMap<String, ReverseRootSummary> = HashMap::new();
@@ -362,13 +506,38 @@ pub fn resolve_reverse_dependencies(targets: &[PackageItem]) -> ReverseDependenc
     dependencies.sort_by(|a, b| a.name.cmp(&b.name));
 
     tracing::info!(
-        "Reverse dependency resolution complete ({} impacted packages)",
-        dependencies.len()
+        "Reverse dependency resolution complete ({} impacted packages, {} orphaned dependencies)",
+        dependencies.len(),
+        orphaned_dependencies.len()
     );
 
     ReverseDependencyReport {
         dependencies,
         summaries,
+        orphaned_dependencies,
+    }
+}
+
+/// What: Select the orphaned dependencies a removal would actually drop for a cascade mode.
+///
+/// Inputs:
+/// - `orphaned_dependencies`: Orphans computed by `resolve_reverse_dependencies`.
+/// - `cascade_mode`: Removal strategy chosen in the preflight modal.
+///
+/// Output:
+/// - Returns the orphan list for `-Rs`/`-Rns`, or an empty slice for plain `-R`.
+///
+/// Details:
+/// - Orphans are computed once per preflight so toggling the cascade mode only changes this view.
+#[must_use]
+pub const fn orphan_removal_preview(
+    orphaned_dependencies: &[String],
+    cascade_mode: CascadeMode,
+) -> &[String] {
+    if cascade_mode.removes_orphaned_dependencies() {
+        orphaned_dependencies
+    } else {
+        &[]
     }
 }
 
@@ -396,6 +565,7 @@ fn convert_entry(name: String, entry: AggregatedEntry) -> DependencyInfo {
         repo,
         groups,
         required_by: _,
+        depends_on: _,
         explicit,
     } = info;
 
@@ -469,6 +639,8 @@ fn convert_entry(name: String, entry: AggregatedEntry) -> DependencyInfo {
         depends_on,
         is_core,
         is_system,
+        optional: false,
+        description: None,
     }
 }
 
@@ -560,6 +732,11 @@ fn fetch_pkg_info(name: &str) -> Result<PkgInfo, String> {
     let map = parse_key_value_output(&text);
 
     let required_by = split_ws_or_none(map.get("Required By"));
+    let depends_on = split_ws_or_none(map.get("Depends On"))
+        .iter()
+        .map(|spec| parse_dep_spec(spec).0)
+        .filter(|name| !name.is_empty())
+        .collect();
     let groups = split_ws_or_none(map.get("Groups"));
     let version = map.get("Version").cloned().unwrap_or_default();
     let repo = map.get("Repository").cloned();
@@ -576,6 +753,7 @@ fn fetch_pkg_info(name: &str) -> Result<PkgInfo, String> {
         repo,
         groups,
         required_by,
+        depends_on,
         explicit,
     })
 }
@@ -670,6 +848,7 @@ mod tests {
             repo: Some("extra".into()),
             groups: Vec::new(),
             required_by: Vec::new(),
+            depends_on: Vec::new(),
             explicit: false,
         }
     }
@@ -734,6 +913,7 @@ mod tests {
                 repo: Some("core".into()),
                 groups: vec!["base".into()],
                 required_by: Vec::new(),
+                depends_on: Vec::new(),
                 explicit: true,
             },
             per_root: HashMap::from([("root".into(), relation_a), ("other".into(), relation_b)]),
@@ -755,6 +935,140 @@ mod tests {
         assert_eq!(info.name, "dep_alias");
     }
 
+    /// What: Build a resolver state whose cache holds a mock installed dependency graph.
+    ///
+    /// Inputs:
+    /// - `targets`: Removal targets seeding the resolver.
+    /// - `graph`: `(name, depends_on, required_by, explicit)` tuples for installed packages.
+    ///
+    /// Output:
+    /// - Returns a `ReverseResolverState` that never needs to query pacman for the graph.
+    fn mock_graph_state(
+        targets: &[PackageItem],
+        graph: &[(&str, &[&str], &[&str], bool)],
+    ) -> ReverseResolverState {
+        let mut state = ReverseResolverState::new(targets);
+        for (name, depends_on, required_by, explicit) in graph {
+            let mut info = pkg_info_stub(name);
+            info.depends_on = depends_on.iter().map(ToString::to_string).collect();
+            info.required_by = required_by.iter().map(ToString::to_string).collect();
+            info.explicit = *explicit;
+            state.cache.insert((*name).to_string(), info);
+        }
+        state
+    }
+
+    #[test]
+    /// What: Verify orphaned dependencies appear in the preview only for cascade removal modes.
+    ///
+    /// Inputs:
+    /// - Mock graph: `app` depends on `libfoo` (only needed by `app`), which depends on `libbar`;
+    ///   `libshared` is also required by `other`, and `tool` is explicitly installed.
+    ///
+    /// Output:
+    /// - `libfoo` and the chained `libbar` are orphaned; `libshared` and `tool` are kept.
+    /// - The preview lists the orphans under `Cascade`/`CascadeWithConfigs` and nothing under `Basic`.
+    ///
+    /// Details:
+    /// - Exercises the fixpoint walk so orphans of orphans are included.
+    fn orphaned_dependencies_previewed_only_for_cascade_modes() {
+        let targets = vec![pkg_item("app")];
+        let mut state = mock_graph_state(
+            &targets,
+            &[
+                ("app", &["libfoo", "libshared", "tool"], &[], true),
+                ("libfoo", &["libbar"], &["app"], false),
+                ("libbar", &[], &["libfoo"], false),
+                ("libshared", &[], &["app", "other"], false),
+                ("tool", &[], &["app"], true),
+                ("other", &["libshared"], &[], true),
+            ],
+        );
+
+        let orphans = state.collect_orphaned_dependencies(&targets);
+        assert_eq!(orphans, vec!["libbar", "libfoo"]);
+
+        let cascade = orphan_removal_preview(&orphans, CascadeMode::Cascade);
+        assert!(cascade.contains(&"libfoo".to_string()));
+        assert_eq!(
+            orphan_removal_preview(&orphans, CascadeMode::CascadeWithConfigs),
+            cascade
+        );
+        assert!(orphan_removal_preview(&orphans, CascadeMode::Basic).is_empty());
+    }
+
+    #[test]
+    /// What: Ensure a dependency shared by another removal target is orphaned only when both go.
+    ///
+    /// Inputs:
+    /// - Mock graph where `libcommon` is required by `a` and `b`.
+    ///
+    /// Output:
+    /// - Removing `a` alone orphans nothing; removing `a` and `b` orphans `libcommon`.
+    fn orphaned_dependencies_account_for_all_removal_targets() {
+        let graph: &[(&str, &[&str], &[&str], bool)] = &[
+            ("a", &["libcommon"], &[], true),
+            ("b", &["libcommon"], &[], true),
+            ("libcommon", &[], &["a", "b"], false),
+        ];
+
+        let single = vec![pkg_item("a")];
+        let mut state = mock_graph_state(&single, graph);
+        assert!(state.collect_orphaned_dependencies(&single).is_empty());
+
+        let both = vec![pkg_item("a"), pkg_item("b")];
+        let mut state = mock_graph_state(&both, graph);
+        assert_eq!(
+            state.collect_orphaned_dependencies(&both),
+            vec!["libcommon"]
+        );
+    }
+
+    #[test]
+    /// What: Verify dependency cycles are cut during the reverse dependency walk.
+    ///
+    /// Inputs:
+    /// - Mock graph with an `a`↔`b` cycle and a `b`↔`c` cycle; removal of `a` alone, then of
+    ///   `a` and `b` together.
+    ///
+    /// Output:
+    /// - Every package appears once in `dependencies`; `b` keeps only `a` as parent and `a`
+    ///   counts `b` as direct and `c` as transitive dependent.
+    ///
+    /// Details:
+    /// - Without cycle detection the `c -> b` back edge would record `c` as a parent of `b`.
+    fn reverse_walk_skips_dependency_cycles() {
+        let graph: &[(&str, &[&str], &[&str], bool)] = &[
+            ("a", &["b"], &["b"], true),
+            ("b", &["a", "c"], &["a", "c"], false),
+            ("c", &["b"], &["b"], false),
+        ];
+
+        let single = vec![pkg_item("a")];
+        let report = build_report(mock_graph_state(&single, graph), &single);
+        let names: Vec<&str> = report
+            .dependencies
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["b", "c"]);
+        let b = &report.dependencies[0];
+        assert_eq!(b.depends_on, vec!["a"]);
+        let summary = &report.summaries[0];
+        assert_eq!(summary.package, "a");
+        assert_eq!(summary.direct_dependents, 1);
+        assert_eq!(summary.transitive_dependents, 1);
+
+        let both = vec![pkg_item("a"), pkg_item("b")];
+        let report = build_report(mock_graph_state(&both, graph), &both);
+        let names: Vec<&str> = report
+            .dependencies
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
+
     #[test]
     /// What: Ensure pacman-style key/value parsing merges wrapped descriptions.
     ///
@@ -89,6 +89,32 @@ pub(super) fn determine_status(
     )
 }
 
+/// What: Evaluate whether an optional dependency is already satisfied.
+///
+/// Inputs:
+/// - `name`: Optional dependency package identifier.
+/// - `installed`: Set of names currently installed on the system.
+/// - `provided`: Set of names provided by installed packages.
+///
+/// Output:
+/// - `Installed` when the package is installed or provided, otherwise `ToInstall`.
+///
+/// Details:
+/// - Optional dependencies are never upgraded or installed as part of the transaction, so
+///   version constraints and pending upgrades are not reported.
+pub(super) fn determine_optional_status(
+    name: &str,
+    installed: &HashSet<String>,
+    provided: &HashSet<String>,
+) -> DependencyStatus {
+    if !is_package_installed_or_provided(name, installed, provided) {
+        return DependencyStatus::ToInstall;
+    }
+    DependencyStatus::Installed {
+        version: get_installed_version(name).unwrap_or_else(|_| "installed".to_string()),
+    }
+}
+
 /// What: Query the repositories for the latest available version of a package.
 ///
 /// Inputs:
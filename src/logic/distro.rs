@@ -111,6 +111,45 @@ pub fn label_for_official(repo: &str, name: &str, owner: &str) -> String {
     }
 }
 
+/// What: Render a repository label in the configured `results_repo_label_style`.
+///
+/// Inputs:
+/// - `label`: Full label as produced by [`label_for_official`] (or `AUR`)
+/// - `style`: One of `full`, `short`, or `icon`
+///
+/// Output:
+/// - Label text to display in the results list and details pane
+///
+/// Details:
+/// - `full` (and any unknown style) returns the label unchanged.
+/// - `short` maps `core`/`extra`/`multilib`/`AUR` to `C`/`E`/`M`/`A` (testing repos get a `T` suffix)
+///   and abbreviates other labels to their first three characters in upper case.
+/// - `icon` maps the same well-known repos to glyphs and falls back to the `short` form.
+#[must_use]
+pub fn styled_repo_label(label: &str, style: &str) -> String {
+    let short = || match label.to_lowercase().as_str() {
+        "core" => "C".to_string(),
+        "extra" => "E".to_string(),
+        "multilib" => "M".to_string(),
+        "core-testing" => "CT".to_string(),
+        "extra-testing" => "ET".to_string(),
+        "multilib-testing" => "MT".to_string(),
+        "aur" => "A".to_string(),
+        _ => label.chars().take(3).collect::<String>().to_uppercase(),
+    };
+    match style {
+        "short" => short(),
+        "icon" => match label.to_lowercase().as_str() {
+            "core" => "●".to_string(),
+            "extra" => "◆".to_string(),
+            "multilib" => "◇".to_string(),
+            "aur" => "▲".to_string(),
+            _ => short(),
+        },
+        _ => label.to_string(),
+    }
+}
+
 /// What: Build a shell snippet to refresh pacman mirrors depending on the detected distro.
 ///
 /// Inputs:
@@ -324,6 +363,33 @@ mod tests {
         assert_eq!(label_for_official("core", "glibc", ""), "core");
     }
 
+    #[test]
+    /// What: Confirm repo label styles abbreviate well-known repositories and leave `full` untouched.
+    ///
+    /// Inputs:
+    /// - Labels `core`, `extra`, `multilib`, `AUR`, and `EOS` rendered in each style.
+    ///
+    /// Output:
+    /// - `short` yields `C`/`E`/`M`/`A`, `icon` yields glyphs, and `full` returns the input.
+    ///
+    /// Details:
+    /// - Unrecognised labels fall back to a three-character abbreviation in both compact styles.
+    fn styled_repo_label_maps_each_style() {
+        for label in ["core", "extra", "multilib", "AUR", "EOS"] {
+            assert_eq!(styled_repo_label(label, "full"), label);
+        }
+        assert_eq!(styled_repo_label("core", "short"), "C");
+        assert_eq!(styled_repo_label("extra", "short"), "E");
+        assert_eq!(styled_repo_label("multilib", "short"), "M");
+        assert_eq!(styled_repo_label("AUR", "short"), "A");
+        assert_eq!(styled_repo_label("CachyOS", "short"), "CAC");
+        assert_eq!(styled_repo_label("core", "icon"), "●");
+        assert_eq!(styled_repo_label("extra", "icon"), "◆");
+        assert_eq!(styled_repo_label("multilib", "icon"), "◇");
+        assert_eq!(styled_repo_label("AUR", "icon"), "▲");
+        assert_eq!(styled_repo_label("EOS", "icon"), "EOS");
+    }
+
     #[test]
     /// What: Validate `BlackArch` toggle routes through its dedicated flag.
     ///
@@ -0,0 +1,235 @@
+//! Live-follow state of the `PreflightExec` log pane.
+//!
+//! The integrated executor streams transaction output into the log pane line by line. By
+//! default the pane follows the newest line; pressing `f` or scrolling back pauses following,
+//! after which the view stays on the same lines while output keeps arriving below.
+
+use crate::state::AppState;
+
+/// Maximum log lines kept in the pane; older lines are dropped first.
+pub const MAX_EXEC_LOG_LINES: usize = 1000;
+/// Maximum executor messages applied between two redraws.
+///
+/// A chatty build can emit thousands of lines per second; applying them in bounded batches
+/// keeps redraws (and key handling) responsive without letting the channel grow unchecked.
+pub const MAX_EXEC_OUTPUTS_PER_FRAME: usize = 256;
+/// Lines moved by `PgUp`/`PgDn` in the log pane.
+const PAGE_LINES: usize = 10;
+
+/// What: Reset the log view for a new executor run.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Following follows the `exec_log_follow` setting and the scroll-back offset is cleared.
+pub fn reset_exec_log_view(app: &mut AppState) {
+    app.exec_log_follow = crate::theme::settings().exec_log_follow;
+    app.exec_log_from_bottom = 0;
+}
+
+/// What: Append one streamed line to the log pane.
+///
+/// Inputs:
+/// - `log_lines`: Log buffer of the `PreflightExec` modal.
+/// - `line`: New output line.
+/// - `follow`: Whether the pane follows the newest output.
+/// - `from_bottom`: Scroll-back offset of a paused view.
+///
+/// Output:
+/// - Pushes `line`, trimming the buffer to [`MAX_EXEC_LOG_LINES`].
+///
+/// Details:
+/// - A paused view moves its offset along with the new line so the visible lines stay put.
+pub fn push_exec_log_line(
+    log_lines: &mut Vec<String>,
+    line: String,
+    follow: bool,
+    from_bottom: &mut usize,
+) {
+    log_lines.push(line);
+    if log_lines.len() > MAX_EXEC_LOG_LINES {
+        log_lines.remove(0);
+    }
+    if !follow {
+        *from_bottom = (*from_bottom + 1).min(log_lines.len());
+    }
+}
+
+/// What: Compute the first visible log line.
+///
+/// Inputs:
+/// - `len`: Number of buffered log lines.
+/// - `rows`: Rows available for log lines.
+/// - `follow`: Whether the pane follows the newest output.
+/// - `from_bottom`: Scroll-back offset of a paused view.
+///
+/// Output:
+/// - Index of the first line to draw; clamped so the pane never scrolls past the top.
+#[must_use]
+pub const fn exec_log_window_start(
+    len: usize,
+    rows: usize,
+    follow: bool,
+    from_bottom: usize,
+) -> usize {
+    let bottom = len.saturating_sub(rows);
+    if follow {
+        bottom
+    } else {
+        bottom.saturating_sub(from_bottom)
+    }
+}
+
+/// What: Toggle following the newest output.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Flips `exec_log_follow`; pausing keeps the current view, resuming jumps to the bottom.
+pub fn toggle_exec_log_follow(app: &mut AppState) {
+    app.exec_log_follow = !app.exec_log_follow;
+    app.exec_log_from_bottom = 0;
+    let key = if app.exec_log_follow {
+        "app.toasts.exec_log_follow_resumed"
+    } else {
+        "app.toasts.exec_log_follow_paused"
+    };
+    app.toast_message = Some(crate::i18n::t(app, key));
+    app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(2));
+}
+
+/// What: Scroll the log pane back towards older output.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `lines`: Lines to scroll.
+/// - `len`: Number of buffered log lines.
+///
+/// Output:
+/// - Pauses following and raises the scroll-back offset (capped at `len`).
+pub fn scroll_exec_log_up(app: &mut AppState, lines: usize, len: usize) {
+    if app.exec_log_follow {
+        app.exec_log_follow = false;
+        app.exec_log_from_bottom = 0;
+    }
+    app.exec_log_from_bottom = app.exec_log_from_bottom.saturating_add(lines).min(len);
+}
+
+/// What: Scroll a paused log pane towards newer output.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `lines`: Lines to scroll.
+///
+/// Output:
+/// - Lowers the scroll-back offset; following stays paused until `f` or `End`.
+pub const fn scroll_exec_log_down(app: &mut AppState, lines: usize) {
+    app.exec_log_from_bottom = app.exec_log_from_bottom.saturating_sub(lines);
+}
+
+/// What: Handle log scrolling keys in the `PreflightExec` modal.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `code`: Pressed key.
+/// - `len`: Number of buffered log lines.
+///
+/// Output:
+/// - `true` when the key was a log pane key.
+///
+/// Details:
+/// - `f` toggles following, Up/`k`/`PgUp` scroll back (pausing), Down/`j`/`PgDn` scroll forward,
+///   and End/`G` resume following.
+pub fn handle_exec_log_key(
+    app: &mut AppState,
+    code: crossterm::event::KeyCode,
+    len: usize,
+) -> bool {
+    use crossterm::event::KeyCode;
+    match code {
+        KeyCode::Char('f') => toggle_exec_log_follow(app),
+        KeyCode::Up | KeyCode::Char('k') => scroll_exec_log_up(app, 1, len),
+        KeyCode::PageUp => scroll_exec_log_up(app, PAGE_LINES, len),
+        KeyCode::Down | KeyCode::Char('j') => scroll_exec_log_down(app, 1),
+        KeyCode::PageDown => scroll_exec_log_down(app, PAGE_LINES),
+        KeyCode::End | KeyCode::Char('G') => {
+            app.exec_log_follow = true;
+            app.exec_log_from_bottom = 0;
+        }
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify a paused view stays on the same lines while output keeps streaming.
+    ///
+    /// Inputs:
+    /// - 20 buffered lines in a 5-row pane; following paused, then 3 more lines arrive.
+    ///
+    /// Output:
+    /// - The window start does not move while paused; resuming jumps to the newest lines.
+    fn paused_view_stops_following() {
+        let mut lines: Vec<String> = (0..20).map(|i| format!("line {i}")).collect();
+        let mut from_bottom = 0;
+        assert_eq!(exec_log_window_start(lines.len(), 5, true, from_bottom), 15);
+
+        for i in 20..23 {
+            push_exec_log_line(&mut lines, format!("line {i}"), false, &mut from_bottom);
+        }
+        assert_eq!(from_bottom, 3);
+        assert_eq!(
+            exec_log_window_start(lines.len(), 5, false, from_bottom),
+            15
+        );
+        assert_eq!(exec_log_window_start(lines.len(), 5, true, from_bottom), 18);
+    }
+
+    #[test]
+    /// What: Verify scrolling back pauses following and `End` resumes it.
+    ///
+    /// Inputs:
+    /// - 30-line log; `PgUp`, Down, then End.
+    ///
+    /// Output:
+    /// - Offsets 10 then 9 while paused; End re-enables following at offset 0.
+    fn scroll_keys_pause_and_resume() {
+        use crossterm::event::KeyCode;
+        let mut app = AppState::default();
+        assert!(app.exec_log_follow);
+        assert!(handle_exec_log_key(&mut app, KeyCode::PageUp, 30));
+        assert!(!app.exec_log_follow);
+        assert_eq!(app.exec_log_from_bottom, 10);
+        assert!(handle_exec_log_key(&mut app, KeyCode::Down, 30));
+        assert_eq!(app.exec_log_from_bottom, 9);
+        assert!(handle_exec_log_key(&mut app, KeyCode::End, 30));
+        assert!(app.exec_log_follow);
+        assert_eq!(app.exec_log_from_bottom, 0);
+        assert!(!handle_exec_log_key(&mut app, KeyCode::Char('x'), 30));
+    }
+
+    #[test]
+    /// What: Verify the buffer cap drops the oldest lines.
+    ///
+    /// Inputs:
+    /// - `MAX_EXEC_LOG_LINES + 5` lines pushed while following.
+    ///
+    /// Output:
+    /// - Buffer holds exactly the newest `MAX_EXEC_LOG_LINES` lines.
+    fn log_buffer_is_capped() {
+        let mut lines = Vec::new();
+        let mut from_bottom = 0;
+        for i in 0..MAX_EXEC_LOG_LINES + 5 {
+            push_exec_log_line(&mut lines, format!("{i}"), true, &mut from_bottom);
+        }
+        assert_eq!(lines.len(), MAX_EXEC_LOG_LINES);
+        assert_eq!(lines[0], "5");
+        assert_eq!(from_bottom, 0);
+    }
+}
@@ -1,6 +1,6 @@
 //! PKGBUILD fetching functions.
 
-use crate::util::{curl_args, percent_encode};
+use crate::util::{NetworkSource, curl_args_for, percent_encode};
 use std::process::Command;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
@@ -292,7 +292,7 @@ pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching PKGBUILD from AUR: {}", url_aur);
 
-    let args = curl_args(&url_aur, &[]);
+    let args = curl_args_for(NetworkSource::Pkgbuild, &url_aur, &[]);
     let output = Command::new("curl").args(&args).output();
 
     let aur_failed_http_error = match &output {
@@ -328,7 +328,7 @@ pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching PKGBUILD from GitLab main: {}", url_main);
 
-    let args = curl_args(&url_main, &[]);
+    let args = curl_args_for(NetworkSource::Pkgbuild, &url_main, &[]);
     let output = Command::new("curl").args(&args).output();
 
     match output {
@@ -357,7 +357,7 @@ pub fn fetch_pkgbuild_sync(name: &str) -> Result<String, String> {
     );
     tracing::debug!("Fetching PKGBUILD from GitLab master: {}", url_master);
 
-    let args = curl_args(&url_master, &[]);
+    let args = curl_args_for(NetworkSource::Pkgbuild, &url_master, &[]);
     let output = Command::new("curl")
         .args(&args)
         .output()
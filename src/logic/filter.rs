@@ -21,6 +21,19 @@ fn return_if_true(cond: bool, it: PackageItem, out: &mut Vec<PackageItem>) {
     }
 }
 
+/// What: Check a package against the out-of-date/orphaned status toggles.
+///
+/// Inputs:
+/// - `app`: Application state with `results_filter_only_outofdate` / `results_filter_only_orphaned`.
+/// - `it`: Candidate package.
+///
+/// Output:
+/// - `true` when the package satisfies every enabled status toggle (always `true` when both are off).
+const fn passes_status_filters(app: &AppState, it: &PackageItem) -> bool {
+    (!app.results_filter_only_outofdate || it.out_of_date.is_some())
+        && (!app.results_filter_only_orphaned || it.orphaned)
+}
+
 /// What: Apply current repo/AUR filters to `app.all_results`, write into `app.results`, then sort.
 ///
 /// Inputs:
@@ -31,6 +44,7 @@ fn return_if_true(cond: bool, it: PackageItem, out: &mut Vec<PackageItem>) {
 ///
 /// Details:
 /// - Unknown official repos are included only when all official filters are enabled.
+/// - Out-of-date/orphaned toggles narrow the repo-filtered set further (AND semantics).
 /// - Selection is restored by name when present; otherwise clamped or cleared if list is empty.
 pub fn apply_filters_and_sort_preserve_selection(app: &mut AppState) {
     // Capture previous selected name to preserve when possible
@@ -39,6 +53,9 @@ pub fn apply_filters_and_sort_preserve_selection(app: &mut AppState) {
     // Filter from all_results into results based on toggles
     let mut filtered: Vec<PackageItem> = Vec::with_capacity(app.all_results.len());
     for it in app.all_results.iter().cloned() {
+        if !passes_status_filters(app, &it) {
+            continue;
+        }
         let include = match &it.source {
             Source::Aur => app.results_filter_show_aur,
             Source::Official { repo, .. } => {
@@ -253,4 +270,114 @@ mod tests {
             Source::Aur => false,
         }));
     }
+
+    /// What: Build a package with the given status flags.
+    ///
+    /// Inputs:
+    /// - `name`: Package name.
+    /// - `source`: Package source.
+    /// - `out_of_date`: Whether the package is flagged out-of-date.
+    /// - `orphaned`: Whether the package is orphaned.
+    ///
+    /// Output:
+    /// - `PackageItem` with the requested flags.
+    fn item_status(name: &str, source: Source, out_of_date: bool, orphaned: bool) -> PackageItem {
+        PackageItem {
+            source,
+            out_of_date: out_of_date.then_some(1_700_000_000),
+            orphaned,
+            ..PackageItem::test_item(name, "aur")
+        }
+    }
+
+    /// What: Build an app with status-flagged results and all repo filters enabled.
+    ///
+    /// Inputs:
+    /// - None.
+    ///
+    /// Output:
+    /// - `AppState` containing stale, orphan, stale+orphan, healthy AUR, and a stale core package.
+    fn status_filter_app() -> AppState {
+        AppState {
+            all_results: vec![
+                item_status("stale", Source::Aur, true, false),
+                item_status("orphan", Source::Aur, false, true),
+                item_status("both", Source::Aur, true, true),
+                item_status("healthy", Source::Aur, false, false),
+                item_status(
+                    "core-stale",
+                    Source::Official {
+                        repo: "core".into(),
+                        arch: "x86_64".into(),
+                    },
+                    true,
+                    false,
+                ),
+            ],
+            results_filter_show_aur: true,
+            results_filter_show_core: true,
+            ..Default::default()
+        }
+    }
+
+    /// What: Collect sorted result names for order-independent assertions.
+    ///
+    /// Inputs:
+    /// - `app`: Application state after filtering.
+    ///
+    /// Output:
+    /// - Sorted package names in `app.results`.
+    fn result_names(app: &AppState) -> Vec<String> {
+        let mut names: Vec<String> = app.results.iter().map(|p| p.name.clone()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    /// What: Verify each status toggle keeps only matching packages and resets cleanly.
+    ///
+    /// Inputs:
+    /// - Mixed results; enable only out-of-date, then only orphaned, then neither.
+    ///
+    /// Output:
+    /// - Out-of-date keeps `both`, `core-stale`, `stale`; orphaned keeps `both`, `orphan`;
+    ///   turning both off restores all five packages.
+    fn status_filters_keep_only_matching_items() {
+        let mut app = status_filter_app();
+        app.results_filter_only_outofdate = true;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert!(app.results.iter().all(|p| p.out_of_date.is_some()));
+        assert_eq!(result_names(&app), ["both", "core-stale", "stale"]);
+
+        app.results_filter_only_outofdate = false;
+        app.results_filter_only_orphaned = true;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert!(app.results.iter().all(|p| p.orphaned));
+        assert_eq!(result_names(&app), ["both", "orphan"]);
+
+        app.results_filter_only_orphaned = false;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(app.results.len(), 5);
+    }
+
+    #[test]
+    /// What: Verify both status toggles intersect with each other and with repo filters.
+    ///
+    /// Inputs:
+    /// - Both toggles on; then out-of-date only with the AUR filter disabled.
+    ///
+    /// Output:
+    /// - Only `both` survives the intersection; with AUR hidden only `core-stale` remains.
+    fn status_filters_intersect_with_each_other_and_repos() {
+        let mut app = status_filter_app();
+        app.results_filter_only_outofdate = true;
+        app.results_filter_only_orphaned = true;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(result_names(&app), ["both"]);
+
+        app.results_filter_only_orphaned = false;
+        app.results_filter_show_aur = false;
+        apply_filters_and_sort_preserve_selection(&mut app);
+        assert_eq!(result_names(&app), ["core-stale"]);
+    }
 }
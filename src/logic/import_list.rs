@@ -0,0 +1,134 @@
+//! Plain-text package list import for the Install list.
+//!
+//! Accepts files produced by scripts (`pacman -Qqe > list.txt`, hand-written lists): package
+//! names separated by whitespace or newlines, with `#` comments. Names found in the official
+//! index keep their repository; everything else is queued as an AUR package.
+
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+use crate::state::{PackageItem, Source};
+
+/// Outcome of resolving imported names against the official index.
+#[derive(Debug, Default)]
+pub struct ImportResolution {
+    /// Packages to append to the install list.
+    pub items: Vec<PackageItem>,
+    /// Number of queued packages not found in the official index (added as AUR).
+    pub unresolved: usize,
+    /// Number of names skipped because they are already in the install list.
+    pub skipped: usize,
+}
+
+/// What: Parse a plain-text package list into names.
+///
+/// Inputs:
+/// - `body`: File contents.
+///
+/// Output:
+/// - Package names in file order, without duplicates (compared case-insensitively).
+///
+/// Details:
+/// - Everything after `#` on a line is a comment; blank lines are ignored.
+/// - Names may be separated by any whitespace, so `a b\nc` yields three names.
+#[must_use]
+pub fn parse_package_list(body: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    body.lines()
+        .map(|line| line.split_once('#').map_or(line, |(code, _)| code))
+        .flat_map(str::split_whitespace)
+        .filter(|name| seen.insert(name.to_lowercase()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// What: Resolve imported names to install list entries.
+///
+/// Inputs:
+/// - `names`: Parsed package names.
+/// - `existing`: Lowercased names already in the install list (`install_list_names`).
+/// - `lookup`: Official index lookup returning a fully populated item.
+///
+/// Output:
+/// - [`ImportResolution`] with new items, the unresolved count, and the skipped count.
+///
+/// Details:
+/// - Unknown names become AUR items with an empty version, to be resolved when details load.
+pub fn resolve_import_names<F, S: BuildHasher>(
+    names: &[String],
+    existing: &HashSet<String, S>,
+    lookup: F,
+) -> ImportResolution
+where
+    F: Fn(&str) -> Option<PackageItem>,
+{
+    let mut out = ImportResolution::default();
+    for name in names {
+        if existing.contains(&name.to_lowercase()) {
+            out.skipped += 1;
+            continue;
+        }
+        let item = lookup(name).unwrap_or_else(|| {
+            out.unresolved += 1;
+            PackageItem {
+                name: name.clone(),
+                version: String::new(),
+                description: String::new(),
+                source: Source::Aur,
+                popularity: None,
+                out_of_date: None,
+                orphaned: false,
+            }
+        });
+        out.items.push(item);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify comments, blank lines, and mixed separators are handled.
+    ///
+    /// Inputs:
+    /// - List with a comment line, a trailing comment, blank lines, tabs, and a duplicate.
+    ///
+    /// Output:
+    /// - Names in file order without comments or the repeated entry.
+    fn parse_package_list_skips_comments_and_blanks() {
+        let body = "# my tools\n\nripgrep fd\n\tbat   # cat clone\n   \n#fzf\nFD\nyay-bin\n";
+        assert_eq!(
+            parse_package_list(body),
+            vec!["ripgrep", "fd", "bat", "yay-bin"]
+        );
+        assert!(parse_package_list("# only comments\n\n   \n").is_empty());
+    }
+
+    #[test]
+    /// What: Verify resolution keeps official sources, queues unknown names as AUR, and skips
+    /// names already in the install list.
+    ///
+    /// Inputs:
+    /// - Names `ripgrep` (official), `yay-bin` (unknown), `Fd` (already listed as `fd`).
+    ///
+    /// Output:
+    /// - Two items (`ripgrep` from `extra`, `yay-bin` as AUR), one unresolved, one skipped.
+    fn resolve_import_names_partitions_sources() {
+        let names = vec!["ripgrep".to_string(), "yay-bin".into(), "Fd".into()];
+        let existing = HashSet::from(["fd".to_string()]);
+        let lookup =
+            |name: &str| (name == "ripgrep").then(|| PackageItem::test_item(name, "extra"));
+        let res = resolve_import_names(&names, &existing, lookup);
+        assert_eq!(res.items.len(), 2);
+        assert!(matches!(
+            &res.items[0].source,
+            Source::Official { repo, .. } if repo == "extra"
+        ));
+        assert_eq!(res.items[1].name, "yay-bin");
+        assert!(matches!(res.items[1].source, Source::Aur));
+        assert_eq!(res.unresolved, 1);
+        assert_eq!(res.skipped, 1);
+    }
+}
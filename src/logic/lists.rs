@@ -23,6 +23,7 @@ pub fn add_to_install_list(app: &mut AppState, item: PackageItem) {
     let prev_len = app.install_list.len();
     app.install_list.insert(0, item);
     app.install_dirty = true;
+    app.invalidate_preflight_summary_cache();
     app.last_install_change = Some(std::time::Instant::now());
     // Always keep cursor on top after adding
     app.install_state.select(Some(0));
@@ -53,6 +54,7 @@ pub fn add_to_remove_list(app: &mut AppState, item: PackageItem) {
     }
     let prev_len = app.remove_list.len();
     app.remove_list.insert(0, item);
+    app.invalidate_preflight_summary_cache();
     app.remove_state.select(Some(0));
     debug!(
         new_len = app.remove_list.len(),
@@ -81,6 +83,7 @@ pub fn add_to_downgrade_list(app: &mut AppState, item: PackageItem) {
     }
     let prev_len = app.downgrade_list.len();
     app.downgrade_list.insert(0, item);
+    app.invalidate_preflight_summary_cache();
     app.downgrade_state.select(Some(0));
     debug!(
         new_len = app.downgrade_list.len(),
@@ -1,21 +1,31 @@
 //! Core non-UI logic split into modular submodules.
 
+pub mod advisory_match;
 pub mod aur_official_catalog_dup;
+pub mod command_palette;
 pub mod deps;
 pub mod distro;
 pub mod doas_persist_setup;
+pub mod exec_log;
 pub mod faillock;
 pub mod files;
 pub mod filter;
 pub mod gating;
+pub mod import_list;
 pub mod lists;
 pub mod long_run_auth;
+pub mod news_export;
+pub mod package_copy;
+pub mod pacnew;
 pub mod password;
 pub mod pkgbuild_checks;
+pub mod pkgbuild_diff;
 pub mod prefetch;
 pub mod preflight;
 pub mod privilege;
 pub mod query;
+pub mod raw_output;
+pub mod reinstall;
 pub mod repos;
 pub mod sandbox;
 pub mod selection;
@@ -23,7 +33,10 @@ pub mod services;
 pub mod sort;
 pub mod ssh_setup;
 pub mod sudo_timestamp_setup;
+pub mod suggest;
 pub mod summary;
+pub mod variant;
+pub mod viewers;
 
 // Re-export public APIs to preserve existing import paths (crate::logic::...)
 pub use aur_official_catalog_dup::aur_pkgnames_also_in_official_catalog;
@@ -34,8 +47,11 @@ pub use pkgbuild_checks::{
     clear_stale_pkgbuild_checks_for_selection, pkgbuild_check_response_matches_selection,
 };
 pub use prefetch::ring_prefetch_from_selected;
-pub use query::send_query;
+pub use query::{ParsedQuery, normalize_query, parse_query_operators, send_query};
+pub use reinstall::reinstall_confirm_required;
 pub use selection::move_sel_cached;
 pub use services::resolve_service_impacts;
 pub use sort::{invalidate_sort_caches, sort_results_preserve_selection};
+pub use suggest::{apply_search_suggestion, update_search_suggestions};
 pub use summary::compute_post_summary;
+pub use variant::toggle_selected_variant;
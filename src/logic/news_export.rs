@@ -0,0 +1,165 @@
+//! Markdown export of News Feed items.
+//!
+//! The News mode export action copies the selected item as Markdown (title heading, metadata
+//! list, cached article body) so it can be pasted into change logs or tickets.
+
+use std::fmt::Write as _;
+
+use crate::state::AppState;
+use crate::state::types::{NewsFeedItem, NewsFeedSource};
+
+/// What: Human-readable label for a news item source.
+///
+/// Inputs:
+/// - `source`: Item source.
+///
+/// Output:
+/// - Short label used in the exported metadata list.
+const fn source_label(source: NewsFeedSource) -> &'static str {
+    match source {
+        NewsFeedSource::ArchNews => "Arch Linux news",
+        NewsFeedSource::SecurityAdvisory => "Security advisory",
+        NewsFeedSource::InstalledPackageUpdate => "Package update",
+        NewsFeedSource::AurPackageUpdate => "AUR package update",
+        NewsFeedSource::AurComment => "AUR comment",
+    }
+}
+
+/// What: Render a news item as a Markdown document.
+///
+/// Inputs:
+/// - `item`: News Feed item to export.
+/// - `content`: Cached article text, if it has been fetched.
+///
+/// Output:
+/// - Markdown with the title as H1, a metadata list, and the body.
+///
+/// Details:
+/// - Only metadata is exported while the article content has not been loaded yet.
+/// - Line breaks in the title are flattened so the heading stays on one line.
+#[must_use]
+pub fn news_item_markdown(item: &NewsFeedItem, content: Option<&str>) -> String {
+    let title = item.title.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut out = format!("# {title}\n\n");
+    if !item.date.trim().is_empty() {
+        let _ = writeln!(out, "- **Date:** {}", item.date.trim());
+    }
+    let _ = writeln!(out, "- **Source:** {}", source_label(item.source));
+    if let Some(severity) = item.severity {
+        let _ = writeln!(out, "- **Severity:** {severity:?}");
+    }
+    if !item.packages.is_empty() {
+        let packages: Vec<String> = item.packages.iter().map(|p| format!("`{p}`")).collect();
+        let _ = writeln!(out, "- **Packages:** {}", packages.join(", "));
+    }
+    if let Some(url) = item.url.as_deref().filter(|u| !u.trim().is_empty()) {
+        let _ = writeln!(out, "- **Link:** <{}>", url.trim());
+    }
+    if let Some(body) = content.map(str::trim).filter(|c| !c.is_empty()) {
+        out.push('\n');
+        out.push_str(body);
+        out.push('\n');
+    }
+    out
+}
+
+/// What: Render the selected News Feed item as Markdown.
+///
+/// Inputs:
+/// - `app`: Application state (selection and `news_content_cache`).
+///
+/// Output:
+/// - `Some(markdown)` for the selected item, `None` when nothing is selected.
+///
+/// Details:
+/// - Uses the cached content for the item's URL; otherwise exports metadata only.
+#[must_use]
+pub fn selected_news_markdown(app: &AppState) -> Option<String> {
+    let item = app.news_results.get(app.news_selected)?;
+    let content = item
+        .url
+        .as_ref()
+        .and_then(|url| app.news_content_cache.get(url))
+        .map(String::as_str);
+    Some(news_item_markdown(item, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::types::AdvisorySeverity;
+
+    /// What: Build a populated advisory item.
+    ///
+    /// Inputs:
+    /// - None.
+    ///
+    /// Output:
+    /// - Advisory `NewsFeedItem` with severity, packages, and URL.
+    fn advisory() -> NewsFeedItem {
+        NewsFeedItem {
+            id: "AVG-2843".into(),
+            date: "2025-01-02".into(),
+            title: "openssl: multiple issues".into(),
+            summary: Some("Remote code execution".into()),
+            url: Some("https://security.archlinux.org/AVG-2843".into()),
+            source: NewsFeedSource::SecurityAdvisory,
+            severity: Some(AdvisorySeverity::High),
+            packages: vec!["openssl".into(), "lib32-openssl".into()],
+        }
+    }
+
+    #[test]
+    /// What: Verify Markdown export of a populated item with and without cached content.
+    ///
+    /// Inputs:
+    /// - Advisory item; cached body text, then no content.
+    ///
+    /// Output:
+    /// - H1 title, metadata list, and body; metadata only when content is missing.
+    fn news_item_markdown_includes_metadata_and_body() {
+        let item = advisory();
+        let md = news_item_markdown(&item, Some("  Upgrade to 3.4.1.\n\nDetails follow.\n"));
+        assert_eq!(
+            md,
+            "# openssl: multiple issues\n\n\
+             - **Date:** 2025-01-02\n\
+             - **Source:** Security advisory\n\
+             - **Severity:** High\n\
+             - **Packages:** `openssl`, `lib32-openssl`\n\
+             - **Link:** <https://security.archlinux.org/AVG-2843>\n\
+             \n\
+             Upgrade to 3.4.1.\n\nDetails follow.\n"
+        );
+
+        let metadata_only = news_item_markdown(&item, None);
+        assert!(metadata_only.ends_with("- **Link:** <https://security.archlinux.org/AVG-2843>\n"));
+        assert_eq!(news_item_markdown(&item, Some("   ")), metadata_only);
+    }
+
+    #[test]
+    /// What: Verify the selected item's cached content is used for export.
+    ///
+    /// Inputs:
+    /// - App with one selected item and its content cached by URL; then an empty selection.
+    ///
+    /// Output:
+    /// - Markdown ends with the cached body; `None` without a selection.
+    fn selected_news_markdown_uses_content_cache() {
+        let item = advisory();
+        let mut app = AppState {
+            news_results: vec![item],
+            news_selected: 0,
+            ..AppState::default()
+        };
+        app.news_content_cache.insert(
+            "https://security.archlinux.org/AVG-2843".into(),
+            "Cached body".into(),
+        );
+        let md = selected_news_markdown(&app).expect("selected item");
+        assert!(md.ends_with("\nCached body\n"));
+
+        app.news_results.clear();
+        assert!(selected_news_markdown(&app).is_none());
+    }
+}
@@ -0,0 +1,189 @@
+//! Copy the focused package's name or a short details block to the clipboard.
+
+use crate::state::types::PackageDetails;
+use crate::state::{AppState, Focus, PackageItem, RightPaneFocus, Source};
+
+/// What: Resolve the package the user is looking at, based on pane focus.
+///
+/// Inputs:
+/// - `app`: Application state (focus, selections, lists).
+///
+/// Output:
+/// - The selected Install, Remove, or Downgrade entry when the Install column is focused,
+///   otherwise the selected Results entry; `None` when nothing is selected.
+#[must_use]
+pub fn focused_package(app: &AppState) -> Option<PackageItem> {
+    if !matches!(app.focus, Focus::Install) {
+        return app.results.get(app.selected).cloned();
+    }
+    match app.right_pane_focus {
+        RightPaneFocus::Install => {
+            let vsel = app.install_state.selected()?;
+            let idx = *crate::ui::helpers::filtered_install_indices(app).get(vsel)?;
+            app.install_list.get(idx).cloned()
+        }
+        RightPaneFocus::Remove => app.remove_list.get(app.remove_state.selected()?).cloned(),
+        RightPaneFocus::Downgrade => app
+            .downgrade_list
+            .get(app.downgrade_state.selected()?)
+            .cloned(),
+    }
+}
+
+/// What: Format package details as a plain-text block for pasting elsewhere.
+///
+/// Inputs:
+/// - `details`: Package details to serialize.
+///
+/// Output:
+/// - `Name`, `Version`, `Repository`, `Description`, and `URL` lines; empty fields are omitted
+///   (the name line is always present).
+#[must_use]
+pub fn format_details_block(details: &PackageDetails) -> String {
+    let mut lines = vec![format!("Name: {}", details.name)];
+    for (label, value) in [
+        ("Version", &details.version),
+        ("Repository", &details.repository),
+        ("Description", &details.description),
+        ("URL", &details.url),
+    ] {
+        let value = value.trim();
+        if !value.is_empty() {
+            lines.push(format!("{label}: {value}"));
+        }
+    }
+    lines.join("\n")
+}
+
+/// What: Best known details for a package.
+///
+/// Inputs:
+/// - `app`: Application state (details cache and the details pane).
+/// - `item`: Package to describe.
+///
+/// Output:
+/// - Cached or displayed details for `item`, or details built from the list entry itself.
+fn details_for(app: &AppState, item: &PackageItem) -> PackageDetails {
+    if let Some(cached) = app.details_cache.get(&item.name) {
+        return cached.clone();
+    }
+    if app.details.name == item.name {
+        return app.details.clone();
+    }
+    PackageDetails {
+        name: item.name.clone(),
+        version: item.version.clone(),
+        description: item.description.clone(),
+        repository: match &item.source {
+            Source::Official { repo, .. } => repo.clone(),
+            Source::Aur => "AUR".to_string(),
+        },
+        ..PackageDetails::default()
+    }
+}
+
+/// What: Copy the focused package's name, or its details block, to the clipboard.
+///
+/// Inputs:
+/// - `app`: Mutable application state (toast).
+/// - `with_details`: `false` copies the name, `true` the [`format_details_block`] text.
+///
+/// Output:
+/// - `true` when a package was focused and a copy was attempted.
+///
+/// Details:
+/// - Shows a confirmation toast, the clipboard tool error, or a "nothing selected" toast.
+pub fn copy_focused_package(app: &mut AppState, with_details: bool) -> bool {
+    let Some(item) = focused_package(app) else {
+        app.toast_message = Some(crate::i18n::t(app, "app.toasts.no_package_selected"));
+        app.toast_expires_at = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        return false;
+    };
+    let text = if with_details {
+        format_details_block(&details_for(app, &item))
+    } else {
+        item.name.clone()
+    };
+    match crate::util::clipboard::copy_plain_text_to_clipboard(&text) {
+        Ok(()) => {
+            let key = if with_details {
+                "app.toasts.package_details_copied"
+            } else {
+                "app.toasts.package_name_copied"
+            };
+            app.toast_message = Some(crate::i18n::t_fmt1(app, key, &item.name));
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+        }
+        Err(msg) => {
+            app.toast_message = Some(msg);
+            app.toast_expires_at =
+                Some(std::time::Instant::now() + std::time::Duration::from_secs(5));
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify the details block lists name, version, repo, description, and URL.
+    ///
+    /// Inputs:
+    /// - Populated `PackageDetails` for `ripgrep`, then the same with an empty URL.
+    ///
+    /// Output:
+    /// - Five labeled lines in order; the URL line is dropped when empty.
+    fn format_details_block_serializes_core_fields() {
+        let details = PackageDetails {
+            repository: "extra".into(),
+            name: "ripgrep".into(),
+            version: "14.1.1-1".into(),
+            description: "A search tool ".into(),
+            architecture: "x86_64".into(),
+            url: "https://github.com/BurntSushi/ripgrep".into(),
+            ..PackageDetails::default()
+        };
+        assert_eq!(
+            format_details_block(&details),
+            "Name: ripgrep\nVersion: 14.1.1-1\nRepository: extra\n\
+             Description: A search tool\nURL: https://github.com/BurntSushi/ripgrep"
+        );
+
+        let no_url = PackageDetails {
+            url: String::new(),
+            ..details
+        };
+        assert!(!format_details_block(&no_url).contains("URL:"));
+    }
+
+    #[test]
+    /// What: Verify the focused package follows pane focus.
+    ///
+    /// Inputs:
+    /// - One result and one Install entry; Search focus, then Install focus.
+    ///
+    /// Output:
+    /// - The Results selection, then the Install selection.
+    fn focused_package_follows_focus() {
+        let pkg = |name: &str| PackageItem::test_item(name, "aur");
+        let mut app = AppState {
+            results: vec![pkg("from-results")],
+            install_list: vec![pkg("from-install")],
+            ..AppState::default()
+        };
+        app.install_state.select(Some(0));
+        app.focus = Focus::Search;
+        assert_eq!(
+            focused_package(&app).map(|p| p.name),
+            Some("from-results".to_string())
+        );
+        app.focus = Focus::Install;
+        assert_eq!(
+            focused_package(&app).map(|p| p.name),
+            Some("from-install".to_string())
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Follow-up for `.pacnew`/`.pacsave` files created by a transaction.
+//!
+//! Before an executor request runs, Pacsea snapshots the existing `.pacnew`/`.pacsave`
+//! paths below `/etc`. When the transaction finishes the scan is repeated; paths that were
+//! not there before are queued and shown in a reminder modal offering to run `pacdiff`.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::state::{AppState, Modal};
+
+/// Root directory scanned for configuration conflicts.
+pub const PAC_FILES_ROOT: &str = "/etc";
+/// Maximum directory depth below the scan root; deeper trees are skipped.
+const MAX_SCAN_DEPTH: usize = 12;
+
+/// What: Collect `.pacnew` and `.pacsave` files below a directory.
+///
+/// Inputs:
+/// - `root`: Directory to walk (normally [`PAC_FILES_ROOT`]).
+///
+/// Output:
+/// - Sorted set of absolute paths ending in `.pacnew` or `.pacsave`.
+///
+/// Details:
+/// - Symlinked directories are not followed and unreadable directories are skipped.
+/// - Stops descending after `MAX_SCAN_DEPTH` levels to avoid pathological trees.
+#[must_use]
+pub fn scan_pac_files(root: &Path) -> BTreeSet<String> {
+    fn walk(dir: &Path, depth: usize, out: &mut BTreeSet<String>) {
+        let Ok(rd) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in rd.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let path = entry.path();
+            if file_type.is_dir() {
+                if depth < MAX_SCAN_DEPTH {
+                    walk(&path, depth + 1, out);
+                }
+            } else if path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e == "pacnew" || e == "pacsave")
+            {
+                out.insert(path.to_string_lossy().into_owned());
+            }
+        }
+    }
+    let mut out = BTreeSet::new();
+    walk(root, 0, &mut out);
+    out
+}
+
+/// What: Build the shell command that reviews configuration conflicts with `pacdiff`.
+///
+/// Inputs:
+/// - `as_root`: Whether Pacsea itself runs as root.
+///
+/// Output:
+/// - `pacdiff` command line; non-root sessions add `--sudo` so merges can write to `/etc`.
+///
+/// Details:
+/// - `pacdiff` (from `pacman-contrib`) picks `sudo` or `doas` itself and honours `DIFFPROG`.
+#[must_use]
+pub fn pacdiff_command(as_root: bool) -> String {
+    if as_root {
+        "pacdiff".to_string()
+    } else {
+        "pacdiff --sudo".to_string()
+    }
+}
+
+/// What: Snapshot existing conflict files before a transaction starts.
+///
+/// Inputs:
+/// - `app`: Application state receiving the baseline.
+/// - `scan`: Scanner returning the current conflict files.
+///
+/// Output:
+/// - Sets `app.pac_files_baseline` when the `pacnew_reminder` setting is enabled.
+pub fn begin_pac_files_check_with<F>(app: &mut AppState, scan: F)
+where
+    F: FnOnce() -> BTreeSet<String>,
+{
+    app.pac_files_baseline = crate::theme::settings().pacnew_reminder.then(scan);
+}
+
+/// What: Compare conflict files after a transaction with the pre-transaction snapshot.
+///
+/// Inputs:
+/// - `app`: Application state holding the baseline.
+/// - `scan`: Scanner returning the current conflict files.
+///
+/// Output:
+/// - Appends newly created paths to `app.pending_pacnew_reminder` and clears the baseline.
+///
+/// Details:
+/// - No-op (and no scan) when no baseline was taken, e.g. with the reminder disabled.
+pub fn finish_pac_files_check_with<F>(app: &mut AppState, scan: F)
+where
+    F: FnOnce() -> BTreeSet<String>,
+{
+    let Some(before) = app.pac_files_baseline.take() else {
+        return;
+    };
+    for path in scan().difference(&before) {
+        if !app.pending_pacnew_reminder.contains(path) {
+            app.pending_pacnew_reminder.push(path.clone());
+        }
+    }
+}
+
+/// What: Open the reminder modal when the last transaction created conflict files.
+///
+/// Inputs:
+/// - `app`: Application state with `pending_pacnew_reminder`.
+///
+/// Output:
+/// - `true` when the reminder modal was opened (the queue is drained), otherwise `false`.
+pub fn show_pacnew_reminder_if_pending(app: &mut AppState) -> bool {
+    if app.pending_pacnew_reminder.is_empty() {
+        return false;
+    }
+    app.modal = Modal::PacnewReminder {
+        paths: std::mem::take(&mut app.pending_pacnew_reminder),
+        scroll: 0,
+    };
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Ensure the scan finds nested `.pacnew`/`.pacsave` files only.
+    ///
+    /// Inputs:
+    /// - Temp tree with a nested `.pacnew`, a top-level `.pacsave`, and an unrelated file.
+    ///
+    /// Output:
+    /// - Exactly the two conflict paths are returned.
+    ///
+    /// Details:
+    /// - Uses a temporary directory instead of the real `/etc`.
+    fn scan_collects_conflict_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "pacsea_pacnew_scan_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(dir.join("pacman.d")).expect("mkdir");
+        std::fs::write(dir.join("pacman.d/mirrorlist.pacnew"), "").expect("write");
+        std::fs::write(dir.join("fstab.pacsave"), "").expect("write");
+        std::fs::write(dir.join("hosts"), "").expect("write");
+
+        let found = scan_pac_files(&dir);
+        let expected: BTreeSet<String> = [
+            dir.join("fstab.pacsave"),
+            dir.join("pacman.d/mirrorlist.pacnew"),
+        ]
+        .iter()
+        .map(|p| p.to_string_lossy().into_owned())
+        .collect();
+        assert_eq!(found, expected);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    /// What: Verify only files created during the transaction reach the reminder modal.
+    ///
+    /// Inputs:
+    /// - Baseline with an old `.pacnew`; mock post-scan adding two new conflict files.
+    ///
+    /// Output:
+    /// - `PacnewReminder` lists the two new paths (sorted) and the queue is drained.
+    ///
+    /// Details:
+    /// - Without a baseline the post-scan is skipped and no modal opens.
+    fn reminder_lists_new_conflict_files() {
+        let mut app = AppState::default();
+        finish_pac_files_check_with(&mut app, || panic!("no baseline, no scan"));
+        assert!(!show_pacnew_reminder_if_pending(&mut app));
+
+        app.pac_files_baseline = Some(BTreeSet::from(["/etc/old.conf.pacnew".to_string()]));
+        finish_pac_files_check_with(&mut app, || {
+            BTreeSet::from([
+                "/etc/old.conf.pacnew".to_string(),
+                "/etc/pacman.conf.pacnew".to_string(),
+                "/etc/makepkg.conf.pacsave".to_string(),
+            ])
+        });
+        assert!(app.pac_files_baseline.is_none());
+        assert!(show_pacnew_reminder_if_pending(&mut app));
+        match &app.modal {
+            Modal::PacnewReminder { paths, scroll } => {
+                assert_eq!(
+                    paths,
+                    &vec![
+                        "/etc/makepkg.conf.pacsave".to_string(),
+                        "/etc/pacman.conf.pacnew".to_string()
+                    ]
+                );
+                assert_eq!(*scroll, 0);
+            }
+            other => panic!("expected PacnewReminder, got {other:?}"),
+        }
+        assert!(app.pending_pacnew_reminder.is_empty());
+    }
+
+    #[test]
+    /// What: Check the `pacdiff` launch command for root and non-root sessions.
+    ///
+    /// Inputs:
+    /// - `as_root` true and false.
+    ///
+    /// Output:
+    /// - `pacdiff` as root, `pacdiff --sudo` otherwise.
+    ///
+    /// Details:
+    /// - `--sudo` lets pacdiff elevate only the merge/remove steps.
+    fn pacdiff_command_adds_sudo_for_non_root() {
+        assert_eq!(pacdiff_command(true), "pacdiff");
+        assert_eq!(pacdiff_command(false), "pacdiff --sudo");
+    }
+}
@@ -0,0 +1,192 @@
+//! Line diff between the last-viewed and freshly fetched PKGBUILD of a package.
+//!
+//! The last-viewed text is stored per package in `pkgbuild_history/` next to the details cache,
+//! so a change is visible on the next visit even across restarts.
+
+use std::path::{Path, PathBuf};
+
+/// Directory (next to the details cache) holding the last-viewed PKGBUILD per package.
+pub const HISTORY_DIR: &str = "pkgbuild_history";
+
+/// Largest `old * new` line product diffed with LCS; beyond it the diff degrades to replace-all.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// What: One line of a unified line diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    /// Line present only in the fresh text.
+    Added(String),
+    /// Line present only in the previous text.
+    Removed(String),
+    /// Line present in both.
+    Unchanged(String),
+}
+
+/// What: Compute a unified line diff between two texts.
+///
+/// Inputs:
+/// - `old`: Previously viewed text.
+/// - `new`: Freshly fetched text.
+///
+/// Output:
+/// - Lines in reading order; removals precede additions within a changed block.
+///
+/// Details:
+/// - Uses a longest-common-subsequence table over lines; inputs too large for the table are
+///   reported as all removed followed by all added.
+#[must_use]
+pub fn line_diff(old: &str, new: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    if a.len().saturating_mul(b.len()) > MAX_LCS_CELLS {
+        return a
+            .iter()
+            .map(|l| DiffLine::Removed((*l).to_string()))
+            .chain(b.iter().map(|l| DiffLine::Added((*l).to_string())))
+            .collect();
+    }
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::with_capacity(a.len().max(b.len()));
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            out.push(DiffLine::Unchanged(a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(DiffLine::Removed(a[i].to_string()));
+            i += 1;
+        } else {
+            out.push(DiffLine::Added(b[j].to_string()));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|l| DiffLine::Removed((*l).to_string())));
+    out.extend(b[j..].iter().map(|l| DiffLine::Added((*l).to_string())));
+    out
+}
+
+/// What: Path of the stored last-viewed PKGBUILD for a package.
+///
+/// Inputs:
+/// - `cache_path`: Details cache file (`AppState::cache_path`); its directory hosts the history.
+/// - `pkgname`: Package name.
+///
+/// Output:
+/// - `<cache dir>/pkgbuild_history/<pkgname>.PKGBUILD`, with path separators replaced.
+#[must_use]
+pub fn history_path(cache_path: &Path, pkgname: &str) -> PathBuf {
+    let safe: String = pkgname
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+        .collect();
+    cache_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(HISTORY_DIR)
+        .join(format!("{safe}.PKGBUILD"))
+}
+
+/// What: Record a freshly fetched PKGBUILD and return the previously viewed one.
+///
+/// Inputs:
+/// - `cache_path`: Details cache file (`AppState::cache_path`).
+/// - `pkgname`: Package name.
+/// - `text`: Freshly fetched PKGBUILD.
+///
+/// Output:
+/// - The stored text from the previous visit, or `None` when nothing was cached.
+///
+/// Details:
+/// - The stored copy is only rewritten when the text changed; write failures are logged.
+pub fn remember_pkgbuild(cache_path: &Path, pkgname: &str, text: &str) -> Option<String> {
+    let path = history_path(cache_path, pkgname);
+    let previous = std::fs::read_to_string(&path).ok();
+    if previous.as_deref() != Some(text) {
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&path, text));
+        if let Err(e) = written {
+            tracing::warn!(path = %path.display(), error = %e, "failed to store PKGBUILD history");
+        }
+    }
+    previous
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify the diff classifies added, removed, and unchanged lines in order.
+    ///
+    /// Inputs:
+    /// - A PKGBUILD whose `pkgver` changed and which gained a `depends` line.
+    ///
+    /// Output:
+    /// - Unchanged name, removed/added `pkgver`, unchanged `arch`, added `depends`.
+    fn line_diff_classifies_lines() {
+        let old = "pkgname=demo\npkgver=1.0\narch=(any)\n";
+        let new = "pkgname=demo\npkgver=1.1\narch=(any)\ndepends=(glibc)\n";
+        assert_eq!(
+            line_diff(old, new),
+            vec![
+                DiffLine::Unchanged("pkgname=demo".into()),
+                DiffLine::Removed("pkgver=1.0".into()),
+                DiffLine::Added("pkgver=1.1".into()),
+                DiffLine::Unchanged("arch=(any)".into()),
+                DiffLine::Added("depends=(glibc)".into()),
+            ]
+        );
+        assert!(
+            line_diff(old, old)
+                .iter()
+                .all(|l| matches!(l, DiffLine::Unchanged(_)))
+        );
+        assert_eq!(
+            line_diff("a\nb\n", ""),
+            vec![DiffLine::Removed("a".into()), DiffLine::Removed("b".into())]
+        );
+    }
+
+    #[test]
+    /// What: Verify the history store returns the previous visit and then the latest text.
+    ///
+    /// Inputs:
+    /// - Temp cache dir; two visits of `demo` with different text.
+    ///
+    /// Output:
+    /// - `None` on the first visit, the first text on the second.
+    fn remember_pkgbuild_returns_previous_visit() {
+        let dir = std::env::temp_dir().join(format!(
+            "pacsea_test_pkgb_history_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("time after epoch")
+                .as_nanos()
+        ));
+        let cache_path = dir.join("details_cache.json");
+        assert_eq!(remember_pkgbuild(&cache_path, "demo", "v1"), None);
+        assert_eq!(
+            remember_pkgbuild(&cache_path, "demo", "v2").as_deref(),
+            Some("v1")
+        );
+        assert_eq!(
+            std::fs::read_to_string(history_path(&cache_path, "demo")).ok(),
+            Some("v2".to_string())
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
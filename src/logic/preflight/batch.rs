@@ -1,13 +1,48 @@
 //! Batch fetching utilities for package metadata.
 //!
-//! This module provides functions to efficiently fetch installed versions and
-//! sizes for multiple packages in batches.
+//! This module provides functions to efficiently fetch installed versions,
+//! sizes, and official repository metadata for multiple packages in batches.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use super::command::{CommandError, CommandRunner};
 use super::metadata::{
-    fetch_installed_size, fetch_installed_version, parse_pacman_key_values, parse_size_to_bytes,
+    OfficialMetadata, fetch_installed_size, fetch_installed_version, fetch_mirror_download_size,
+    fetch_modified_backup_files, fetch_official_metadata, official_metadata_from_fields,
+    parse_modified_backup_files, parse_pacman_key_values, parse_size_to_bytes,
 };
-use crate::state::types::PackageItem;
+use crate::state::types::{PackageItem, Source};
+
+/// What: Split multi-package `pacman -Qi`/`-Si` output into per-package blocks.
+///
+/// Inputs:
+/// - `output`: Raw stdout listing several packages.
+///
+/// Output:
+/// - One string per package, each ending with a newline.
+///
+/// Details:
+/// - pacman separates packages with blank lines; empty blocks are dropped.
+pub(super) fn split_package_blocks(output: &str) -> Vec<String> {
+    let mut package_blocks = Vec::new();
+    let mut current_block = String::new();
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            if !current_block.is_empty() {
+                package_blocks.push(std::mem::take(&mut current_block));
+            }
+        } else {
+            current_block.push_str(line);
+            current_block.push('\n');
+        }
+    }
+    if !current_block.is_empty() {
+        package_blocks.push(current_block);
+    }
+    package_blocks
+}
 
 /// What: Batch fetch installed versions for multiple packages using `pacman -Q`.
 ///
@@ -93,27 +128,9 @@ pub(super) fn batch_fetch_installed_sizes<R: CommandRunner>(
         args.extend(names.iter().copied());
         match runner.run("pacman", &args) {
             Ok(output) => {
-                // Parse multi-package output: packages are separated by blank lines
-                let mut package_blocks = Vec::new();
-                let mut current_block = String::new();
-                for line in output.lines() {
-                    if line.trim().is_empty() {
-                        if !current_block.is_empty() {
-                            package_blocks.push(current_block.clone());
-                            current_block.clear();
-                        }
-                    } else {
-                        current_block.push_str(line);
-                        current_block.push('\n');
-                    }
-                }
-                if !current_block.is_empty() {
-                    package_blocks.push(current_block);
-                }
-
                 // Parse each block to extract package name and size
                 let mut size_map = std::collections::HashMap::new();
-                for block in package_blocks {
+                for block in split_package_blocks(&output) {
                     let block_fields = parse_pacman_key_values(&block);
                     if let (Some(name), Some(size_str)) = (
                         block_fields.get("Name").map(|s| s.trim()),
@@ -149,3 +166,288 @@ pub(super) fn batch_fetch_installed_sizes<R: CommandRunner>(
     }
     results
 }
+
+/// What: Batch fetch locally modified backup files for installed packages using `pacman -Qii`.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `names`: Installed package names to query.
+///
+/// Output:
+/// - Map from package name to its modified backup file paths; packages without modified
+///   backup files (or whose lookup failed) are omitted.
+///
+/// Details:
+/// - Batches queries into chunks of 50 to avoid command-line length limits.
+/// - Falls back to per-package queries when a batched call fails.
+pub(super) fn batch_fetch_modified_backups<R: CommandRunner>(
+    runner: &R,
+    names: &[&str],
+) -> HashMap<String, Vec<String>> {
+    const BATCH_SIZE: usize = 50;
+    let mut out = HashMap::new();
+
+    for chunk in names.chunks(BATCH_SIZE) {
+        let mut args = vec!["-Qii"];
+        args.extend(chunk.iter().copied());
+        if let Ok(output) = runner.run("pacman", &args) {
+            for block in split_package_blocks(&output) {
+                let files = parse_modified_backup_files(&block);
+                if let Some(name) = parse_pacman_key_values(&block).get("Name")
+                    && !files.is_empty()
+                {
+                    out.insert(name.trim().to_string(), files);
+                }
+            }
+        } else {
+            for name in chunk {
+                if let Ok(files) = fetch_modified_backup_files(runner, name)
+                    && !files.is_empty()
+                {
+                    out.insert((*name).to_string(), files);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// What: Fetch `pacman -Si` metadata for one chunk of official packages.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `chunk`: Official packages to query in a single invocation.
+///
+/// Output:
+/// - `(name, metadata)` pairs for every package whose metadata could be read.
+///
+/// Details:
+/// - Packages are queried as `repo/name`; output blocks are matched back by `Name`.
+/// - If the batched call fails (e.g. one package is missing from the sync db), each
+///   package is retried individually so the others still receive metadata.
+fn fetch_official_metadata_chunk<R: CommandRunner>(
+    runner: &R,
+    chunk: &[&PackageItem],
+) -> Vec<(String, OfficialMetadata)> {
+    let specs: Vec<(&PackageItem, &str, String)> = chunk
+        .iter()
+        .filter_map(|item| match &item.source {
+            Source::Official { repo, .. } => {
+                Some((*item, repo.as_str(), format!("{repo}/{}", item.name)))
+            }
+            Source::Aur => None,
+        })
+        .collect();
+    let mut args = vec!["-Si"];
+    args.extend(specs.iter().map(|(_, _, spec)| spec.as_str()));
+    match runner.run("pacman", &args) {
+        Ok(output) => {
+            let blocks: HashMap<String, (String, HashMap<String, String>)> =
+                split_package_blocks(&output)
+                    .into_iter()
+                    .filter_map(|block| {
+                        let fields = parse_pacman_key_values(&block);
+                        let name = fields.get("Name")?.trim().to_string();
+                        Some((name, (block, fields)))
+                    })
+                    .collect();
+            specs
+                .iter()
+                .filter_map(|(item, _, spec)| {
+                    let (block, fields) = blocks.get(&item.name)?;
+                    crate::logic::raw_output::capture_raw_output(
+                        &item.name,
+                        &format!("pacman -Si {spec}"),
+                        block,
+                    );
+                    Some((
+                        item.name.clone(),
+                        official_metadata_from_fields(fields, spec, &item.version),
+                    ))
+                })
+                .collect()
+        }
+        Err(err) => {
+            tracing::debug!(
+                "Preflight summary: batched pacman -Si for {} packages failed ({err}); retrying individually",
+                specs.len()
+            );
+            specs
+                .iter()
+                .filter_map(|(item, repo, _)| {
+                    match fetch_official_metadata(runner, repo, &item.name, &item.version) {
+                        Ok(meta) => Some((item.name.clone(), meta)),
+                        Err(err) => {
+                            tracing::debug!(
+                                "Preflight summary: failed to fetch metadata for {repo}/{pkg}: {err}",
+                                pkg = item.name
+                            );
+                            None
+                        }
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// What: Batch fetch `pacman -Si` metadata for the official packages in `items`.
+///
+/// Inputs:
+/// - `runner`: Command executor (shared across worker threads).
+/// - `items`: Packages to query; AUR packages are skipped.
+/// - `chunk_size`: Maximum packages per `pacman -Si` invocation (`0` is treated as `1`).
+/// - `max_parallel`: Maximum chunks queried at the same time (`0` is treated as `1`).
+///
+/// Output:
+/// - Map from package name to its metadata; packages without metadata are omitted.
+///
+/// Details:
+/// - Chunking keeps each command line well below argv length limits for huge install lists.
+/// - Chunks are handed out to at most `max_parallel` scoped worker threads; a single chunk
+///   runs on the calling thread.
+pub(super) fn batch_fetch_official_metadata<R: CommandRunner + Sync>(
+    runner: &R,
+    items: &[PackageItem],
+    chunk_size: usize,
+    max_parallel: usize,
+) -> HashMap<String, OfficialMetadata> {
+    let official: Vec<&PackageItem> = items
+        .iter()
+        .filter(|item| matches!(item.source, Source::Official { .. }))
+        .collect();
+    let chunks: Vec<&[&PackageItem]> = official.chunks(chunk_size.max(1)).collect();
+    let workers = max_parallel.max(1).min(chunks.len());
+    if workers <= 1 {
+        return chunks
+            .iter()
+            .flat_map(|chunk| fetch_official_metadata_chunk(runner, chunk))
+            .collect();
+    }
+
+    let next_chunk = AtomicUsize::new(0);
+    let merged = Mutex::new(HashMap::with_capacity(official.len()));
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some(chunk) = chunks.get(next_chunk.fetch_add(1, Ordering::Relaxed)) {
+                    let part = fetch_official_metadata_chunk(runner, chunk);
+                    merged
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .extend(part);
+                }
+            });
+        }
+    });
+    merged
+        .into_inner()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// What: Run a per-package fetch on a bounded worker pool, keeping results in input order.
+///
+/// Inputs:
+/// - `items`: Packages to fetch for.
+/// - `max_parallel`: Maximum number of concurrent workers.
+/// - `cancelled`: Flag checked before each fetch; once set, remaining packages are skipped.
+/// - `fetch`: Per-package fetch, typically wrapping a [`CommandRunner`].
+///
+/// Output:
+/// - One entry per item at the item's index; `None` for packages skipped after cancellation.
+///
+/// Details:
+/// - Workers pull the next index from a shared counter, so slow packages do not hold up others.
+/// - Runs inline when only one worker is needed.
+pub(super) fn fetch_in_input_order<T, F>(
+    items: &[PackageItem],
+    max_parallel: usize,
+    cancelled: &AtomicBool,
+    fetch: F,
+) -> Vec<Option<T>>
+where
+    T: Send,
+    F: Fn(&PackageItem) -> T + Sync,
+{
+    let slots: Vec<Mutex<Option<T>>> = items.iter().map(|_| Mutex::new(None)).collect();
+    let next_item = AtomicUsize::new(0);
+    let work = || {
+        while !cancelled.load(Ordering::Relaxed) {
+            let idx = next_item.fetch_add(1, Ordering::Relaxed);
+            let Some(item) = items.get(idx) else {
+                break;
+            };
+            let value = fetch(item);
+            *slots[idx]
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(value);
+        }
+    };
+    let workers = max_parallel.max(1).min(items.len());
+    if workers <= 1 {
+        work();
+    } else {
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(work);
+            }
+        });
+    }
+    slots
+        .into_iter()
+        .map(|slot| {
+            slot.into_inner()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+        })
+        .collect()
+}
+
+/// What: Fill missing official download sizes from the package files on a mirror.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `items`: Packages under review.
+/// - `metadata`: Batched `pacman -Si` metadata, updated in place.
+/// - `server`: `Server =` URL template from the mirror list.
+/// - `max_parallel`: Maximum number of concurrent `HEAD` requests.
+/// - `cancelled`: Preflight cancellation flag.
+///
+/// Output:
+/// - Adds `download_size` for official packages that `pacman -Si` gave none for.
+///
+/// Details:
+/// - Packages with a known download size never reach the network.
+/// - Failed lookups are skipped silently; the summary total is then just understated.
+pub(super) fn fill_mirror_download_sizes<R: CommandRunner + Sync>(
+    runner: &R,
+    items: &[PackageItem],
+    metadata: &mut HashMap<String, OfficialMetadata>,
+    server: &str,
+    max_parallel: usize,
+    cancelled: &AtomicBool,
+) {
+    let missing: Vec<PackageItem> = items
+        .iter()
+        .filter(|item| {
+            matches!(item.source, Source::Official { .. })
+                && metadata
+                    .get(&item.name)
+                    .is_none_or(|meta| meta.download_size.is_none())
+        })
+        .cloned()
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+    let sizes = fetch_in_input_order(&missing, max_parallel, cancelled, |item| {
+        let Source::Official { repo, arch } = &item.source else {
+            return None;
+        };
+        fetch_mirror_download_size(runner, server, repo, arch, &item.name, &item.version)
+    });
+    for (item, size) in missing.into_iter().zip(sizes) {
+        if let Some(size) = size.flatten() {
+            metadata.entry(item.name).or_default().download_size = Some(size);
+        }
+    }
+}
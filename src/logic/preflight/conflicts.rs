@@ -0,0 +1,245 @@
+//! Dry-run conflict detection for installs.
+//!
+//! `pacman -S --print` resolves the transaction without downloading or committing anything,
+//! so package conflicts pacman would ask about surface before the real install. The check
+//! runs through a small shell wrapper because pacman reports conflicts on stderr and exits
+//! non-zero, which [`CommandRunner`] would otherwise turn into an output-less error.
+
+use super::command::CommandRunner;
+use crate::state::modal::{ConflictInfo, ConflictKind};
+use crate::state::types::{PackageItem, Source};
+
+/// Shell wrapper: exits 127 without pacman, otherwise merges stderr and always succeeds.
+pub(super) const DRY_RUN_SCRIPT: &str = "command -v pacman >/dev/null 2>&1 || exit 127; pacman -S --print --noconfirm -- \"$@\" 2>&1; exit 0";
+
+/// What: Result of the dry-run conflict check.
+///
+/// Inputs: Produced by [`check_install_conflicts`].
+///
+/// Output: Conflicts to render, or a marker that the check could not run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictCheck {
+    /// The check ran; an empty list means pacman reported no conflicts.
+    Checked(Vec<ConflictInfo>),
+    /// pacman is not available or the wrapper could not be started.
+    Unavailable,
+}
+
+/// What: Strip a `-pkgver-pkgrel` suffix from a pacman package label.
+///
+/// Inputs:
+/// - `label`: `name` or `name-1:2.0-1` as printed by pacman.
+///
+/// Output:
+/// - The package name.
+///
+/// Details:
+/// - Only strips when the last segment looks like a pkgrel and the one before like a version,
+///   so names such as `python-foo` or `lib32-glibc` are kept intact.
+fn package_name(label: &str) -> &str {
+    let mut parts = label.rsplitn(3, '-');
+    let (Some(rel), Some(ver), Some(name)) = (parts.next(), parts.next(), parts.next()) else {
+        return label;
+    };
+    let looks_like_rel = !rel.is_empty() && rel.chars().all(|c| c.is_ascii_digit() || c == '.');
+    let looks_like_ver = ver.starts_with(|c: char| c.is_ascii_digit());
+    if looks_like_rel && looks_like_ver {
+        name
+    } else {
+        label
+    }
+}
+
+/// What: Parse one `A and B are in conflict` line.
+///
+/// Inputs:
+/// - `line`: Line with the leading `::` already removed.
+///
+/// Output:
+/// - Package conflict, or `None` when the line has a different shape.
+///
+/// Details:
+/// - Accepts the prompt form (`... are in conflict (reason). Remove b? [y/N]`) and the error
+///   listing form (`a-1.0-1 and b-2.0-1 are in conflict`).
+fn parse_package_conflict(line: &str) -> Option<ConflictInfo> {
+    let (pair, rest) = line.split_once(" are in conflict")?;
+    let (a, b) = pair.split_once(" and ")?;
+    let detail = rest
+        .trim_start()
+        .strip_prefix('(')
+        .and_then(|r| r.split_once(')'))
+        .map(|(reason, _)| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty());
+    Some(ConflictInfo {
+        kind: ConflictKind::Package,
+        package: package_name(a.trim()).to_string(),
+        conflicts_with: package_name(b.trim()).to_string(),
+        detail,
+    })
+}
+
+/// What: Parse one file conflict line.
+///
+/// Inputs:
+/// - `line`: `pkg: /path exists in filesystem` or `/path exists in both 'a' and 'b'`.
+///
+/// Output:
+/// - File conflict, or `None` when the line has a different shape.
+fn parse_file_conflict(line: &str) -> Option<ConflictInfo> {
+    if let Some(head) = line.strip_suffix(" exists in filesystem") {
+        let (pkg, path) = head.split_once(": ")?;
+        return Some(ConflictInfo {
+            kind: ConflictKind::File,
+            package: pkg.trim().to_string(),
+            conflicts_with: "filesystem".to_string(),
+            detail: Some(path.trim().to_string()),
+        });
+    }
+    let (path, owners) = line.split_once(" exists in both ")?;
+    let (a, b) = owners.split_once(" and ")?;
+    let unquote = |s: &str| s.trim().trim_matches('\'').to_string();
+    Some(ConflictInfo {
+        kind: ConflictKind::File,
+        package: unquote(a),
+        conflicts_with: unquote(b),
+        detail: Some(path.trim().to_string()),
+    })
+}
+
+/// What: Parse conflicts from pacman transaction output.
+///
+/// Inputs:
+/// - `output`: Combined stdout/stderr of `pacman -S --print` (or a real transaction).
+///
+/// Output:
+/// - Package and file conflicts in output order, without duplicates.
+///
+/// Details:
+/// - pacman prints each package conflict twice (prompt and error summary); both collapse
+///   into one entry. Unrelated lines (URLs, `error:` headers) are ignored.
+#[must_use]
+pub fn parse_conflicts(output: &str) -> Vec<ConflictInfo> {
+    let mut conflicts: Vec<ConflictInfo> = Vec::new();
+    for line in output.lines() {
+        let line = line.trim().trim_start_matches("::").trim();
+        let Some(conflict) = parse_package_conflict(line).or_else(|| parse_file_conflict(line))
+        else {
+            continue;
+        };
+        let duplicate = conflicts.iter().any(|c| {
+            c.kind == conflict.kind
+                && c.package == conflict.package
+                && c.conflicts_with == conflict.conflicts_with
+                && (c.kind == ConflictKind::Package || c.detail == conflict.detail)
+        });
+        if !duplicate {
+            conflicts.push(conflict);
+        }
+    }
+    conflicts
+}
+
+/// What: Run the dry-run conflict check for official install targets.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `items`: Packages being installed.
+///
+/// Output:
+/// - [`ConflictCheck::Checked`] with parsed conflicts (empty when there are none or no
+///   official targets), or [`ConflictCheck::Unavailable`] when the check cannot run.
+///
+/// Details:
+/// - AUR packages are skipped; pacman cannot resolve them from the sync databases.
+pub fn check_install_conflicts<R: CommandRunner>(
+    runner: &R,
+    items: &[PackageItem],
+) -> ConflictCheck {
+    let targets: Vec<&str> = items
+        .iter()
+        .filter(|item| matches!(item.source, Source::Official { .. }))
+        .map(|item| item.name.as_str())
+        .collect();
+    if targets.is_empty() {
+        return ConflictCheck::Checked(Vec::new());
+    }
+    let mut args = vec!["-c", DRY_RUN_SCRIPT, "sh"];
+    args.extend(targets);
+    match runner.run("sh", &args) {
+        Ok(output) => ConflictCheck::Checked(parse_conflicts(&output)),
+        Err(err) => {
+            tracing::debug!("Preflight summary: conflict check unavailable: {err}");
+            ConflictCheck::Unavailable
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify package conflicts are parsed from prompt and error output.
+    ///
+    /// Inputs:
+    /// - pacman output with a prompt carrying a reason, the repeated error listing with
+    ///   versions, and a second conflict without a reason.
+    ///
+    /// Output:
+    /// - Two package conflicts with names stripped of versions; the repeat is dropped.
+    fn parse_conflicts_reads_package_conflicts() {
+        let output = "\
+resolving dependencies...
+looking for conflicting packages...
+:: iptables-nft-1:1.8.10-2 and iptables-1:1.8.10-2 are in conflict. Remove iptables? [y/N]
+:: python-foo and lib32-glibc are in conflict (glibc). Remove lib32-glibc? [y/N]
+error: unresolvable package conflicts detected
+error: failed to prepare transaction (conflicting dependencies)
+:: iptables-nft-1:1.8.10-2 and iptables-1:1.8.10-2 are in conflict
+";
+        let conflicts = parse_conflicts(output);
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].package, "iptables-nft");
+        assert_eq!(conflicts[0].conflicts_with, "iptables");
+        assert_eq!(conflicts[0].detail, None);
+        assert_eq!(conflicts[1].package, "python-foo");
+        assert_eq!(conflicts[1].conflicts_with, "lib32-glibc");
+        assert_eq!(conflicts[1].detail.as_deref(), Some("glibc"));
+        assert_eq!(
+            conflicts[1].warning(),
+            "python-foo conflicts with lib32-glibc (glibc)"
+        );
+    }
+
+    #[test]
+    /// What: Verify file conflicts and clean output are handled.
+    ///
+    /// Inputs:
+    /// - pacman commit errors for a file on disk and a file owned by two packages.
+    /// - `--print` output listing only download URLs.
+    ///
+    /// Output:
+    /// - Two file conflicts with their paths; no conflicts for the clean output.
+    fn parse_conflicts_reads_file_conflicts_and_clean_output() {
+        let output = "\
+error: failed to commit transaction (conflicting files)
+foo: /usr/bin/foo exists in filesystem
+/usr/lib/libbar.so exists in both 'foo' and 'bar'
+Errors occurred, no packages were upgraded.
+";
+        let conflicts = parse_conflicts(output);
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].kind, ConflictKind::File);
+        assert_eq!(conflicts[0].conflicts_with, "filesystem");
+        assert_eq!(
+            conflicts[0].warning(),
+            "foo: /usr/bin/foo exists in filesystem"
+        );
+        assert_eq!(conflicts[1].package, "foo");
+        assert_eq!(conflicts[1].conflicts_with, "bar");
+        assert_eq!(conflicts[1].detail.as_deref(), Some("/usr/lib/libbar.so"));
+
+        let clean = "https://mirror.example/extra/os/x86_64/ripgrep-14.1.0-1-x86_64.pkg.tar.zst\n";
+        assert!(parse_conflicts(clean).is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+//! Version-lock conflict detection for downgrades.
+//!
+//! Installed packages may depend on a minimum version of a package (e.g. `foo>=2.0`).
+//! Downgrading `foo` below that version leaves the dependent with an unsatisfied
+//! requirement, so the preflight summary reads the dependents' `Depends On` entries and
+//! flags every requirement the downgrade target would violate.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::batch::split_package_blocks;
+use super::command::CommandRunner;
+use super::metadata::parse_pacman_key_values;
+use super::version::compare_versions;
+use crate::state::types::PackageItem;
+
+/// Maximum packages per `pacman -Qi` call, matching the other batched lookups.
+const BATCH_SIZE: usize = 50;
+
+/// What: An installed package whose versioned dependency a downgrade would break.
+///
+/// Inputs: Produced by [`find_downgrade_conflicts`].
+///
+/// Output: Rendered as package notes and summary warnings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DowngradeConflict {
+    /// Package being downgraded.
+    pub target: String,
+    /// Version the package is downgraded to.
+    pub target_version: String,
+    /// Installed package that depends on `target`.
+    pub dependent: String,
+    /// Dependency entry of `dependent` that `target_version` violates (e.g. `foo>=2.0`).
+    pub requirement: String,
+}
+
+impl DowngradeConflict {
+    /// What: Human-readable warning for the Summary tab.
+    ///
+    /// Output: Sentence naming the dependent, its requirement, and the downgrade version.
+    #[must_use]
+    pub fn warning(&self) -> String {
+        format!(
+            "{} requires {}; downgrading {} to {} breaks it",
+            self.dependent, self.requirement, self.target, self.target_version
+        )
+    }
+}
+
+/// What: Split a dependency entry into name, comparison operator, and version.
+///
+/// Inputs:
+/// - `spec`: Entry such as `foo>=1.2`, `foo=1.2-3`, or `foo`.
+///
+/// Output:
+/// - `(name, Some((operator, version)))` for versioned entries, `(name, None)` otherwise.
+fn split_versioned_dep(spec: &str) -> (&str, Option<(&str, &str)>) {
+    let Some(pos) = spec.find(['<', '>', '=']) else {
+        return (spec, None);
+    };
+    let rest = &spec[pos..];
+    let op_len = if rest.starts_with(">=") || rest.starts_with("<=") {
+        2
+    } else {
+        1
+    };
+    (&spec[..pos], Some((&rest[..op_len], &rest[op_len..])))
+}
+
+/// What: Check whether a version satisfies a pacman dependency constraint.
+///
+/// Inputs:
+/// - `version`: Candidate version (e.g. `1.2.3-1`).
+/// - `op`: One of `>=`, `<=`, `>`, `<`, `=`.
+/// - `required`: Version from the dependency entry.
+///
+/// Output:
+/// - `true` when the constraint holds or the operator is unknown.
+///
+/// Details:
+/// - Like pacman, a requirement without a `pkgrel` ignores the candidate's `pkgrel`.
+fn constraint_satisfied(version: &str, op: &str, required: &str) -> bool {
+    let version = if required.contains('-') {
+        version
+    } else {
+        version
+            .rsplit_once('-')
+            .map_or(version, |(pkgver, _)| pkgver)
+    };
+    let ord = compare_versions(version, required);
+    match op {
+        ">=" => ord != Ordering::Less,
+        "<=" => ord != Ordering::Greater,
+        ">" => ord == Ordering::Greater,
+        "<" => ord == Ordering::Less,
+        "=" => ord == Ordering::Equal,
+        _ => true,
+    }
+}
+
+/// What: Query `pacman -Qi` for several packages and return one field per package.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `names`: Installed packages to query.
+/// - `field`: Whitespace-separated list field to read (e.g. `Required By`).
+///
+/// Output:
+/// - Map from package name to the field's entries; `None` values are dropped.
+///
+/// Details:
+/// - Failed batches are logged and skipped; the check is best-effort.
+fn query_list_field<R: CommandRunner>(
+    runner: &R,
+    names: &[&str],
+    field: &str,
+) -> HashMap<String, Vec<String>> {
+    let mut out = HashMap::new();
+    for chunk in names.chunks(BATCH_SIZE) {
+        let mut args = vec!["-Qi"];
+        args.extend(chunk.iter().copied());
+        let output = match runner.run("pacman", &args) {
+            Ok(output) => output,
+            Err(err) => {
+                tracing::debug!(error = %err, field, "Downgrade check: pacman -Qi failed");
+                continue;
+            }
+        };
+        for block in split_package_blocks(&output) {
+            let fields = parse_pacman_key_values(&block);
+            let Some(name) = fields.get("Name") else {
+                continue;
+            };
+            let entries = fields
+                .get(field)
+                .map(|value| {
+                    value
+                        .split_whitespace()
+                        .filter(|entry| *entry != "None")
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            out.insert(name.trim().to_string(), entries);
+        }
+    }
+    out
+}
+
+/// What: Find installed dependents whose versioned requirements a downgrade would violate.
+///
+/// Inputs:
+/// - `runner`: Command executor (mockable).
+/// - `items`: Packages being downgraded; `version` holds the downgrade target version.
+///
+/// Output:
+/// - One [`DowngradeConflict`] per violated requirement, ordered by target then dependent.
+///
+/// Details:
+/// - Reads each target's `Required By` list, then the dependents' `Depends On` entries, with
+///   one batched `pacman -Qi` per step.
+/// - Unversioned dependencies and entries for other packages are ignored.
+pub(super) fn find_downgrade_conflicts<R: CommandRunner>(
+    runner: &R,
+    items: &[PackageItem],
+) -> Vec<DowngradeConflict> {
+    let targets: Vec<&PackageItem> = items.iter().filter(|i| !i.version.is_empty()).collect();
+    if targets.is_empty() {
+        return Vec::new();
+    }
+    let target_names: Vec<&str> = targets.iter().map(|i| i.name.as_str()).collect();
+    let required_by = query_list_field(runner, &target_names, "Required By");
+
+    let mut dependents: Vec<&str> = required_by.values().flatten().map(String::as_str).collect();
+    dependents.sort_unstable();
+    dependents.dedup();
+    if dependents.is_empty() {
+        return Vec::new();
+    }
+    let depends_on = query_list_field(runner, &dependents, "Depends On");
+
+    let mut conflicts = Vec::new();
+    for target in targets {
+        let Some(users) = required_by.get(&target.name) else {
+            continue;
+        };
+        let mut users: Vec<&String> = users.iter().collect();
+        users.sort_unstable();
+        for dependent in users {
+            let Some(specs) = depends_on.get(dependent) else {
+                continue;
+            };
+            for spec in specs {
+                let (name, constraint) = split_versioned_dep(spec);
+                if name != target.name {
+                    continue;
+                }
+                if let Some((op, required)) = constraint
+                    && !constraint_satisfied(&target.version, op, required)
+                {
+                    conflicts.push(DowngradeConflict {
+                        target: target.name.clone(),
+                        target_version: target.version.clone(),
+                        dependent: dependent.clone(),
+                        requirement: spec.clone(),
+                    });
+                }
+            }
+        }
+    }
+    conflicts
+}
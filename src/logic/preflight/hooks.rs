@@ -0,0 +1,568 @@
+//! Detection of alpm hooks a transaction will trigger.
+//!
+//! pacman runs hooks from `/usr/share/libalpm/hooks` and `/etc/pacman.d/hooks`; a hook in the
+//! latter replaces one with the same file name, and an empty replacement (such as a symlink to
+//! `/dev/null`) disables it. Hook files are read through [`HookSource`] so tests can inject
+//! definitions instead of touching the real directories.
+
+use std::collections::HashMap;
+
+use super::command::CommandRunner;
+use crate::state::modal::{PreflightAction, PreflightPackageSummary};
+use crate::state::types::{PackageItem, Source};
+
+/// Hook directories in override order (later entries replace earlier ones by file name).
+pub const HOOK_DIRS: &[&str] = &["/usr/share/libalpm/hooks", "/etc/pacman.d/hooks"];
+
+/// What: Source of alpm hook definitions.
+///
+/// Inputs: Implemented by [`SystemHookSource`] and by test doubles.
+///
+/// Output: Hook files per directory.
+pub trait HookSource {
+    /// What: List the hook files of one directory.
+    ///
+    /// Inputs:
+    /// - `dir`: Hook directory (one of [`HOOK_DIRS`]).
+    ///
+    /// Output:
+    /// - `(file name, contents)` for every `*.hook` file; empty when the directory is missing.
+    fn read_hooks(&self, dir: &str) -> Vec<(String, String)>;
+}
+
+/// Reads hook files from the filesystem.
+pub struct SystemHookSource;
+
+impl HookSource for SystemHookSource {
+    fn read_hooks(&self, dir: &str) -> Vec<(String, String)> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                if !path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("hook"))
+                {
+                    return None;
+                }
+                let name = entry.file_name().to_string_lossy().into_owned();
+                // A symlink to /dev/null reads as empty, which disables the hook.
+                let content = std::fs::read_to_string(&path).unwrap_or_default();
+                Some((name, content))
+            })
+            .collect()
+    }
+}
+
+/// Transaction operation a hook trigger listens for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookOperation {
+    /// Package installed for the first time.
+    Install,
+    /// Installed package replaced by another version.
+    Upgrade,
+    /// Package removed.
+    Remove,
+}
+
+/// What a trigger's `Target` values are matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TriggerKind {
+    /// Package names.
+    Package,
+    /// File paths (relative to `/`) owned by the packages.
+    Path,
+}
+
+/// One `[Trigger]` section of a hook file.
+#[derive(Debug, Clone)]
+struct Trigger {
+    /// Operations the trigger listens for.
+    operations: Vec<HookOperation>,
+    /// Whether targets name packages or paths.
+    kind: TriggerKind,
+    /// Glob targets in file order; a leading `!` negates.
+    targets: Vec<String>,
+}
+
+/// A parsed hook file.
+#[derive(Debug, Clone)]
+struct AlpmHook {
+    /// File name, e.g. `90-mkinitcpio-install.hook`.
+    name: String,
+    /// `Description` from the `[Action]` section, if any.
+    description: Option<String>,
+    /// Trigger sections; the hook fires when any of them matches.
+    triggers: Vec<Trigger>,
+}
+
+/// A package as seen by hook matching.
+#[derive(Debug, Clone, Copy)]
+pub struct HookPackage<'a> {
+    /// Package name.
+    pub name: &'a str,
+    /// Operation the transaction performs on the package.
+    pub operation: HookOperation,
+    /// Files owned by the package, with or without a leading `/`.
+    pub files: &'a [String],
+}
+
+/// A hook that will fire, with the packages that trigger it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriggeredHook {
+    /// Hook file name.
+    pub name: String,
+    /// Hook description, if the hook declares one.
+    pub description: Option<String>,
+    /// Triggering package names in transaction order.
+    pub packages: Vec<String>,
+}
+
+impl TriggeredHook {
+    /// What: Summary-tab note for the hook.
+    ///
+    /// Inputs: None.
+    ///
+    /// Output:
+    /// - e.g. `Hook: Updating linux initcpios... (90-mkinitcpio-install.hook; linux)`.
+    #[must_use]
+    pub fn note(&self) -> String {
+        let label = self.description.as_deref().unwrap_or(&self.name);
+        format!(
+            "Hook: {label} ({}; {})",
+            self.name,
+            self.packages.join(", ")
+        )
+    }
+}
+
+/// What: Match `text` against an `fnmatch`-style glob.
+///
+/// Inputs:
+/// - `pattern`: Glob with `*` and `?` wildcards.
+/// - `text`: Candidate string.
+///
+/// Output:
+/// - `true` when the whole of `text` matches.
+///
+/// Details:
+/// - Like alpm's `fnmatch` without flags, `*` also matches `/`.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            backtrack = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star, matched)) = backtrack {
+            pi = star + 1;
+            ti = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+/// What: Apply a trigger's target list to one candidate.
+///
+/// Inputs:
+/// - `targets`: Targets in file order.
+/// - `candidate`: Package name or path.
+///
+/// Output:
+/// - `true` when the candidate is targeted.
+///
+/// Details:
+/// - Mirrors alpm: the last matching target decides, and a negated (`!`) match excludes.
+fn targets_match(targets: &[String], candidate: &str) -> bool {
+    for target in targets.iter().rev() {
+        if let Some(negated) = target.strip_prefix('!') {
+            if glob_match(negated, candidate) {
+                return false;
+            }
+        } else if glob_match(target, candidate) {
+            return true;
+        }
+    }
+    false
+}
+
+/// What: Parse a hook file.
+///
+/// Inputs:
+/// - `name`: File name.
+/// - `content`: File contents.
+///
+/// Output:
+/// - The hook, or `None` when it is empty (disabled) or has no usable trigger.
+///
+/// Details:
+/// - `Type = File` is accepted as the deprecated spelling of `Path`.
+fn parse_hook(name: &str, content: &str) -> Option<AlpmHook> {
+    let mut hook = AlpmHook {
+        name: name.to_string(),
+        description: None,
+        triggers: Vec::new(),
+    };
+    let mut section = String::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = header.trim().to_string();
+            if section == "Trigger" {
+                hook.triggers.push(Trigger {
+                    operations: Vec::new(),
+                    kind: TriggerKind::Package,
+                    targets: Vec::new(),
+                });
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        match (section.as_str(), key) {
+            ("Trigger", "Operation") => {
+                let op = match value {
+                    "Install" => HookOperation::Install,
+                    "Upgrade" => HookOperation::Upgrade,
+                    "Remove" => HookOperation::Remove,
+                    _ => continue,
+                };
+                if let Some(trigger) = hook.triggers.last_mut() {
+                    trigger.operations.push(op);
+                }
+            }
+            ("Trigger", "Type") => {
+                if let Some(trigger) = hook.triggers.last_mut() {
+                    trigger.kind = if matches!(value, "Path" | "File") {
+                        TriggerKind::Path
+                    } else {
+                        TriggerKind::Package
+                    };
+                }
+            }
+            ("Trigger", "Target") => {
+                if let Some(trigger) = hook.triggers.last_mut() {
+                    trigger
+                        .targets
+                        .push(value.trim_start_matches('/').to_string());
+                }
+            }
+            ("Action", "Description") => hook.description = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    hook.triggers
+        .retain(|t| !t.operations.is_empty() && !t.targets.is_empty());
+    (!hook.triggers.is_empty()).then_some(hook)
+}
+
+/// What: Check whether a trigger fires for a package.
+///
+/// Inputs:
+/// - `trigger`: Parsed trigger.
+/// - `pkg`: Package and its operation/files.
+///
+/// Output:
+/// - `true` when the operation matches and the package name or one of its files is targeted.
+fn trigger_fires(trigger: &Trigger, pkg: &HookPackage<'_>) -> bool {
+    if !trigger.operations.contains(&pkg.operation) {
+        return false;
+    }
+    match trigger.kind {
+        TriggerKind::Package => targets_match(&trigger.targets, pkg.name),
+        TriggerKind::Path => pkg
+            .files
+            .iter()
+            .any(|f| targets_match(&trigger.targets, f.trim_start_matches('/'))),
+    }
+}
+
+/// What: Find the hooks a transaction will trigger.
+///
+/// Inputs:
+/// - `source`: Hook definitions (use [`SystemHookSource`] outside tests).
+/// - `packages`: Packages in the transaction.
+///
+/// Output:
+/// - Triggered hooks sorted by file name (pacman's run order), each with its packages.
+///
+/// Details:
+/// - Hooks from later [`HOOK_DIRS`] replace same-named earlier ones; empty files disable them.
+#[must_use]
+pub fn triggered_hooks<S: HookSource>(
+    source: &S,
+    packages: &[HookPackage<'_>],
+) -> Vec<TriggeredHook> {
+    let mut by_name: std::collections::BTreeMap<String, String> = std::collections::BTreeMap::new();
+    for dir in HOOK_DIRS {
+        for (name, content) in source.read_hooks(dir) {
+            by_name.insert(name, content);
+        }
+    }
+    by_name
+        .iter()
+        .filter_map(|(name, content)| parse_hook(name, content))
+        .filter_map(|hook| {
+            let packages: Vec<String> = packages
+                .iter()
+                .filter(|pkg| hook.triggers.iter().any(|t| trigger_fires(t, pkg)))
+                .map(|pkg| pkg.name.to_string())
+                .collect();
+            if packages.is_empty() {
+                return None;
+            }
+            Some(TriggeredHook {
+                name: hook.name,
+                description: hook.description,
+                packages,
+            })
+        })
+        .collect()
+}
+
+/// What: Fetch file lists for hook path matching.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `items`: Packages in the transaction.
+/// - `action`: Install, remove, or downgrade.
+///
+/// Output:
+/// - Map of package name to its files (as printed by pacman, no leading `/` for `-Fl`).
+///
+/// Details:
+/// - Removals list installed files (`pacman -Ql`); other actions query the sync files
+///   database (`pacman -Fl`) for official packages. AUR packages have no file list before they
+///   are built, so only their package-name triggers are checked.
+/// - A failed query (e.g. files database not synced) yields no files instead of an error.
+fn fetch_file_lists<R: CommandRunner>(
+    runner: &R,
+    items: &[PackageItem],
+    action: PreflightAction,
+) -> HashMap<String, Vec<String>> {
+    let (flag, names): (&str, Vec<&str>) = if matches!(action, PreflightAction::Remove) {
+        ("-Ql", items.iter().map(|i| i.name.as_str()).collect())
+    } else {
+        (
+            "-Fl",
+            items
+                .iter()
+                .filter(|i| matches!(i.source, Source::Official { .. }))
+                .map(|i| i.name.as_str())
+                .collect(),
+        )
+    };
+    let mut files: HashMap<String, Vec<String>> = HashMap::new();
+    if names.is_empty() {
+        return files;
+    }
+    let mut args = vec![flag];
+    args.extend(names);
+    match runner.run("pacman", &args) {
+        Ok(output) => {
+            for (pkg, path) in output.lines().filter_map(|l| l.split_once(' ')) {
+                files
+                    .entry(pkg.to_string())
+                    .or_default()
+                    .push(path.trim().to_string());
+            }
+        }
+        Err(err) => tracing::debug!("Preflight summary: hook file lists unavailable: {err}"),
+    }
+    files
+}
+
+/// What: Build Summary-tab notes for the alpm hooks a transaction will trigger.
+///
+/// Inputs:
+/// - `runner`: Command executor for file lists.
+/// - `source`: Hook definitions (use [`SystemHookSource`] outside tests).
+/// - `items`: Packages in the transaction.
+/// - `action`: Install, remove, or downgrade.
+/// - `summaries`: Per-package summaries (installed versions pick Install vs. Upgrade).
+///
+/// Output:
+/// - One note per triggered hook (see [`TriggeredHook::note`]).
+pub fn transaction_hook_notes<R: CommandRunner, S: HookSource>(
+    runner: &R,
+    source: &S,
+    items: &[PackageItem],
+    action: PreflightAction,
+    summaries: &[PreflightPackageSummary],
+) -> Vec<String> {
+    let files = fetch_file_lists(runner, items, action);
+    let no_files: Vec<String> = Vec::new();
+    let packages: Vec<HookPackage<'_>> = items
+        .iter()
+        .map(|item| {
+            let installed = summaries
+                .iter()
+                .any(|s| s.name == item.name && s.installed_version.is_some());
+            let operation = match action {
+                PreflightAction::Remove => HookOperation::Remove,
+                _ if installed => HookOperation::Upgrade,
+                _ => HookOperation::Install,
+            };
+            HookPackage {
+                name: &item.name,
+                operation,
+                files: files.get(&item.name).unwrap_or(&no_files),
+            }
+        })
+        .collect();
+    triggered_hooks(source, &packages)
+        .iter()
+        .map(TriggeredHook::note)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hook source backed by in-memory definitions per directory.
+    #[derive(Default)]
+    struct FakeHooks(HashMap<&'static str, Vec<(String, String)>>);
+
+    impl FakeHooks {
+        /// What: Add a hook definition.
+        ///
+        /// Inputs:
+        /// - `dir`: Hook directory.
+        /// - `name`: File name.
+        /// - `content`: File contents.
+        ///
+        /// Output:
+        /// - `self` for chaining.
+        fn with(mut self, dir: &'static str, name: &str, content: &str) -> Self {
+            self.0
+                .entry(dir)
+                .or_default()
+                .push((name.to_string(), content.to_string()));
+            self
+        }
+    }
+
+    impl HookSource for FakeHooks {
+        fn read_hooks(&self, dir: &str) -> Vec<(String, String)> {
+            self.0.get(dir).cloned().unwrap_or_default()
+        }
+    }
+
+    const GRUB_HOOK: &str = "\
+[Trigger]
+Operation = Install
+Operation = Upgrade
+Type = Package
+Target = grub
+
+[Action]
+Description = Updating GRUB configuration...
+When = PostTransaction
+Exec = /usr/bin/grub-mkconfig -o /boot/grub/grub.cfg
+";
+
+    const INITCPIO_HOOK: &str = "\
+[Trigger]
+Type = Path
+Operation = Install
+Operation = Upgrade
+Target = usr/lib/modules/*/vmlinuz
+Target = usr/lib/initcpio/*
+Target = !usr/lib/initcpio/ignored
+
+[Action]
+Description = Updating linux initcpios...
+When = PostTransaction
+Exec = /usr/share/libalpm/scripts/mkinitcpio install
+";
+
+    #[test]
+    /// What: Verify a package-type trigger matches by package name and operation.
+    ///
+    /// Inputs:
+    /// - A `grub` hook for Install/Upgrade; `grub` installed, `grub` removed, `ripgrep` installed.
+    ///
+    /// Output:
+    /// - Only the install of `grub` triggers it; the note names description, file, and package.
+    fn hook_matches_by_package_name() {
+        let source = FakeHooks::default().with(HOOK_DIRS[0], "90-grub.hook", GRUB_HOOK);
+        let no_files: Vec<String> = Vec::new();
+        let pkg = |name, operation| HookPackage {
+            name,
+            operation,
+            files: &no_files,
+        };
+
+        let hooks = triggered_hooks(
+            &source,
+            &[
+                pkg("grub", HookOperation::Install),
+                pkg("ripgrep", HookOperation::Install),
+            ],
+        );
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0].packages, vec!["grub".to_string()]);
+        assert_eq!(
+            hooks[0].note(),
+            "Hook: Updating GRUB configuration... (90-grub.hook; grub)"
+        );
+
+        assert!(triggered_hooks(&source, &[pkg("grub", HookOperation::Remove)]).is_empty());
+    }
+
+    #[test]
+    /// What: Verify a path-type trigger matches package files, honouring negation and overrides.
+    ///
+    /// Inputs:
+    /// - The initcpio hook; `linux` ships a kernel image, `mkinitcpio-extra` only an ignored file.
+    /// - The same hook disabled by an empty `/etc/pacman.d/hooks` override.
+    ///
+    /// Output:
+    /// - Only `linux` triggers the hook; the disabled override yields no hooks.
+    fn hook_matches_by_file_path() {
+        let linux_files = vec![
+            "/usr/lib/modules/6.9.1-arch1-1/vmlinuz".to_string(),
+            "/usr/share/doc/linux/README".to_string(),
+        ];
+        let extra_files = vec!["usr/lib/initcpio/ignored".to_string()];
+        let packages = [
+            HookPackage {
+                name: "linux",
+                operation: HookOperation::Upgrade,
+                files: &linux_files,
+            },
+            HookPackage {
+                name: "mkinitcpio-extra",
+                operation: HookOperation::Install,
+                files: &extra_files,
+            },
+        ];
+
+        let source =
+            FakeHooks::default().with(HOOK_DIRS[0], "90-mkinitcpio-install.hook", INITCPIO_HOOK);
+        let hooks = triggered_hooks(&source, &packages);
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0].name, "90-mkinitcpio-install.hook");
+        assert_eq!(hooks[0].packages, vec!["linux".to_string()]);
+
+        let disabled = source.with(HOOK_DIRS[1], "90-mkinitcpio-install.hook", "");
+        assert!(triggered_hooks(&disabled, &packages).is_empty());
+    }
+}
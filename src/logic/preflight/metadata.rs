@@ -7,6 +7,144 @@ use super::command::{CommandError, CommandRunner};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Pacman mirror list consulted for the mirror download-size fallback.
+pub(super) const MIRRORLIST_PATH: &str = "/etc/pacman.d/mirrorlist";
+
+/// Mirror download sizes keyed by `repo/name-version`; `None` records a failed lookup.
+static MIRROR_SIZE_CACHE: OnceLock<Mutex<HashMap<String, Option<u64>>>> = OnceLock::new();
+
+/// What: Find the first active server in a pacman mirror list.
+///
+/// Inputs:
+/// - `mirrorlist`: Contents of a `mirrorlist` file.
+///
+/// Output:
+/// - The `Server =` URL template (still containing `$repo`/`$arch`), or `None`.
+///
+/// Details:
+/// - Commented-out servers are skipped.
+pub(super) fn first_mirror_server(mirrorlist: &str) -> Option<String> {
+    mirrorlist.lines().find_map(|line| {
+        let (key, value) = line.trim().split_once('=')?;
+        (key.trim() == "Server")
+            .then(|| value.trim().to_string())
+            .filter(|url| !url.is_empty())
+    })
+}
+
+/// What: Build the mirror URL of a package file.
+///
+/// Inputs:
+/// - `server`: `Server =` URL template from the mirror list.
+/// - `repo`: Repository name.
+/// - `name`: Package name.
+/// - `version`: Full package version (`epoch:pkgver-pkgrel`).
+/// - `pkg_arch`: Package architecture used in the file name (`x86_64`, `any`).
+///
+/// Output:
+/// - URL of `{name}-{version}-{pkg_arch}.pkg.tar.zst` in the repository directory.
+///
+/// Details:
+/// - `$arch` in the template is the machine architecture, not the package's.
+pub(super) fn mirror_package_url(
+    server: &str,
+    repo: &str,
+    name: &str,
+    version: &str,
+    pkg_arch: &str,
+) -> String {
+    let base = server
+        .replace("$repo", repo)
+        .replace("$arch", std::env::consts::ARCH);
+    format!(
+        "{}/{name}-{version}-{pkg_arch}.pkg.tar.zst",
+        base.trim_end_matches('/')
+    )
+}
+
+/// What: Read the size from HTTP response headers.
+///
+/// Inputs:
+/// - `headers`: Output of `curl -I`, possibly covering several redirects.
+///
+/// Output:
+/// - The last `Content-Length` value, or `None` when absent or zero.
+///
+/// Details:
+/// - Header names are matched case-insensitively; the last response is the final one after
+///   redirects.
+pub(super) fn parse_content_length(headers: &str) -> Option<u64> {
+    headers
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            if key.trim().eq_ignore_ascii_case("content-length") {
+                value.trim().parse::<u64>().ok()
+            } else {
+                None
+            }
+        })
+        .next_back()
+        .filter(|&len| len > 0)
+}
+
+/// What: Look up a package's download size from its file on a mirror.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `server`: `Server =` URL template from the mirror list.
+/// - `repo`: Repository name.
+/// - `pkg_arch`: Package architecture from the index.
+/// - `name`: Package name.
+/// - `version`: Target version.
+///
+/// Output:
+/// - `Some(bytes)` from the `Content-Length` of a `HEAD` request, `None` on any failure.
+///
+/// Details:
+/// - Tries the `pkg_arch` file name first, then `any`.
+/// - Results, including failures, are cached per `repo/name-version` for the session.
+pub(super) fn fetch_mirror_download_size<R: CommandRunner>(
+    runner: &R,
+    server: &str,
+    repo: &str,
+    pkg_arch: &str,
+    name: &str,
+    version: &str,
+) -> Option<u64> {
+    let key = format!("{repo}/{name}-{version}");
+    let cache = MIRROR_SIZE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(cached) = cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&key)
+    {
+        return *cached;
+    }
+    let mut arches = vec![pkg_arch];
+    if pkg_arch != "any" {
+        arches.push("any");
+    }
+    let size = arches.into_iter().find_map(|arch| {
+        let url = mirror_package_url(server, repo, name, version, arch);
+        let args = crate::util::curl_args(&url, &["-I"]);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        match runner.run("curl", &args) {
+            Ok(headers) => parse_content_length(&headers),
+            Err(err) => {
+                tracing::debug!("Preflight summary: mirror HEAD for {url} failed: {err}");
+                None
+            }
+        }
+    });
+    cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(key, size);
+    size
+}
 
 /// What: Extract remote download/install sizes for an official package via
 /// `pacman -Si`.
@@ -32,8 +170,32 @@ pub(super) fn fetch_official_metadata<R: CommandRunner>(
 ) -> Result<OfficialMetadata, CommandError> {
     let spec = format!("{repo}/{name}");
     let output = runner.run("pacman", &["-Si", &spec])?;
+    crate::logic::raw_output::capture_raw_output(name, &format!("pacman -Si {spec}"), &output);
     let fields = parse_pacman_key_values(&output);
+    Ok(official_metadata_from_fields(
+        &fields,
+        &spec,
+        expected_version,
+    ))
+}
 
+/// What: Build [`OfficialMetadata`] from parsed `pacman -Si` fields of one package.
+///
+/// Inputs:
+/// - `fields`: Key-value pairs of a single package block.
+/// - `spec`: `repo/name` spec used in diagnostics.
+/// - `expected_version`: Version string to cross-check.
+///
+/// Output:
+/// - Metadata with the parsed download and install sizes.
+///
+/// Details:
+/// - Shared by the single-package and batched lookups; version mismatches are only logged.
+pub(super) fn official_metadata_from_fields(
+    fields: &HashMap<String, String>,
+    spec: &str,
+    expected_version: &str,
+) -> OfficialMetadata {
     if let Some(version) = fields.get("Version")
         && version.trim() != expected_version
     {
@@ -52,10 +214,10 @@ pub(super) fn fetch_official_metadata<R: CommandRunner>(
         .get("Installed Size")
         .and_then(|raw| parse_size_to_bytes(raw));
 
-    Ok(OfficialMetadata {
+    OfficialMetadata {
         download_size,
         install_size,
-    })
+    }
 }
 
 /// What: Retrieve installed package version via `pacman -Q`.
@@ -113,6 +275,65 @@ pub(super) fn fetch_installed_size<R: CommandRunner>(
         })
 }
 
+/// What: Extract locally modified backup (config) files from `pacman -Qii` output.
+///
+/// Inputs:
+/// - `output`: `pacman -Qii` output for a single package.
+///
+/// Output:
+/// - Paths of backup files reported as `[modified]`, in output order.
+///
+/// Details:
+/// - Reads the `Backup Files` field and its indented continuation lines.
+/// - Accepts both `path [status]` and `[status]<TAB>path` entry layouts.
+/// - `[unmodified]` files are replaced silently and `[missing]` files do not exist on disk, so
+///   only modified files can produce `.pacnew`/`.pacsave` files.
+pub(super) fn parse_modified_backup_files(output: &str) -> Vec<String> {
+    let mut modified = Vec::new();
+    let mut in_backup = false;
+    for line in output.lines() {
+        let entry = if let Some(rest) = line.strip_prefix("Backup Files") {
+            in_backup = true;
+            rest.trim_start().strip_prefix(':').unwrap_or(rest)
+        } else if in_backup && line.starts_with(char::is_whitespace) {
+            line
+        } else {
+            in_backup = false;
+            continue;
+        };
+        let mut path = None;
+        let mut is_modified = false;
+        for token in entry.split_whitespace() {
+            if token.starts_with('/') {
+                path = Some(token);
+            } else if token.eq_ignore_ascii_case("[modified]") {
+                is_modified = true;
+            }
+        }
+        if is_modified && let Some(path) = path {
+            modified.push(path.to_string());
+        }
+    }
+    modified
+}
+
+/// What: Retrieve locally modified backup files of an installed package via `pacman -Qii`.
+///
+/// Inputs:
+/// - `runner`: Command executor.
+/// - `name`: Package identifier.
+///
+/// Output:
+/// - `Ok(paths)` of modified backup files (possibly empty).
+/// - `Err(CommandError)` when the package is not installed or pacman fails.
+pub(super) fn fetch_modified_backup_files<R: CommandRunner>(
+    runner: &R,
+    name: &str,
+) -> Result<Vec<String>, CommandError> {
+    let output = runner.run("pacman", &["-Qii", name])?;
+    Ok(parse_modified_backup_files(&output))
+}
+
 /// What: Metadata extracted from `pacman -Si` to inform download/install
 /// calculations.
 ///
@@ -7,20 +7,34 @@
 
 mod batch;
 mod command;
+mod conflicts;
+mod downgrade;
 pub mod guardrails;
+mod hooks;
 mod metadata;
 mod version;
 
 use crate::state::modal::{
-    PreflightAction, PreflightHeaderChips, PreflightPackageSummary, PreflightSummaryData, RiskLevel,
+    ConflictInfo, PreflightAction, PreflightHeaderChips, PreflightPackageSummary,
+    PreflightSummaryData, RiskLevel,
 };
 use crate::state::types::{PackageItem, Source};
+use crate::theme::RiskWeights;
 use std::cmp::Ordering;
+use std::sync::atomic::AtomicBool;
 
 pub use command::{CommandError, CommandRunner, SystemCommandRunner};
 
-use batch::{batch_fetch_installed_sizes, batch_fetch_installed_versions};
-use version::{compare_versions, is_major_version_bump};
+use batch::{
+    batch_fetch_installed_sizes, batch_fetch_installed_versions, batch_fetch_modified_backups,
+    batch_fetch_official_metadata, fetch_in_input_order, fill_mirror_download_sizes,
+};
+use conflicts::{ConflictCheck, check_install_conflicts};
+use downgrade::{DowngradeConflict, find_downgrade_conflicts};
+use hooks::{SystemHookSource, transaction_hook_notes};
+use metadata::OfficialMetadata;
+pub use version::compare_versions;
+use version::is_major_version_bump;
 
 /// Packages that contribute additional risk when present in a transaction.
 const CORE_CRITICAL_PACKAGES: &[&str] = &[
@@ -83,6 +97,69 @@ pub fn compute_preflight_summary(
     compute_preflight_summary_with_runner(items, action, &runner)
 }
 
+/// What: Compute the cache key for a preflight summary request.
+///
+/// Inputs:
+/// - `items`: Packages under review.
+/// - `action`: Install vs. remove context.
+///
+/// Output:
+/// - Hash of the action and every package's name and version.
+///
+/// Details:
+/// - Order-insensitive: the same packages in a different list order share a signature.
+#[must_use]
+pub fn summary_signature(items: &[PackageItem], action: PreflightAction) -> u64 {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    let mut entries: Vec<(&str, &str)> = items
+        .iter()
+        .map(|item| (item.name.as_str(), item.version.as_str()))
+        .collect();
+    entries.sort_unstable();
+    let mut hasher = DefaultHasher::new();
+    std::mem::discriminant(&action).hash(&mut hasher);
+    for entry in &entries {
+        entry.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// What: Fetch on-disk installed sizes for installed packages.
+///
+/// Inputs:
+/// - `runner`: Command executor (use [`SystemCommandRunner`] outside tests).
+/// - `names`: Installed package names to query.
+///
+/// Output:
+/// - Map from package name to `Installed Size` in bytes; packages whose size
+///   could not be determined are omitted.
+///
+/// Details:
+/// - Reuses the batched `pacman -Qi` lookup from the preflight summary.
+#[must_use]
+pub fn fetch_installed_sizes_with_runner<R: CommandRunner>(
+    runner: &R,
+    names: &[String],
+) -> std::collections::HashMap<String, u64> {
+    let items: Vec<PackageItem> = names
+        .iter()
+        .map(|name| PackageItem {
+            name: name.clone(),
+            version: String::new(),
+            description: String::new(),
+            source: Source::Aur,
+            popularity: None,
+            out_of_date: None,
+            orphaned: false,
+        })
+        .collect();
+    names
+        .iter()
+        .zip(batch_fetch_installed_sizes(runner, &items))
+        .filter_map(|(name, size)| size.ok().map(|bytes| (name.clone(), bytes)))
+        .collect()
+}
+
 /// What: Intermediate state accumulated during package processing.
 ///
 /// Inputs: Built incrementally while iterating packages.
@@ -109,6 +186,18 @@ struct ProcessingState {
     any_core_update: bool,
     /// Whether any AUR package is included.
     any_aur: bool,
+    /// Installed dependents whose versioned requirements a downgrade would break.
+    downgrade_conflicts: Vec<DowngradeConflict>,
+    /// Outcome of the dry-run conflict check; `None` until it runs.
+    conflict_check: Option<ConflictCheck>,
+    /// User-configured critical package names (lowercase), checked after `CORE_CRITICAL_PACKAGES`.
+    extra_critical_packages: Vec<String>,
+    /// Locally modified config files an install/upgrade may leave as `.pacnew`.
+    pacnew_candidates: usize,
+    /// Locally modified config files a removal may leave as `.pacsave`.
+    pacsave_candidates: usize,
+    /// Packages owning those modified config files.
+    config_warning_packages: Vec<String>,
 }
 
 impl ProcessingState {
@@ -131,6 +220,22 @@ impl ProcessingState {
             any_major_bump: false,
             any_core_update: false,
             any_aur: false,
+            downgrade_conflicts: Vec::new(),
+            conflict_check: None,
+            extra_critical_packages: Vec::new(),
+            pacnew_candidates: 0,
+            pacsave_candidates: 0,
+            config_warning_packages: Vec::new(),
+        }
+    }
+
+    /// What: Conflicts reported by the dry-run conflict check.
+    ///
+    /// Output: Reported conflicts; empty when the check found none, has not run, or was unavailable.
+    fn conflicts(&self) -> &[ConflictInfo] {
+        match &self.conflict_check {
+            Some(ConflictCheck::Checked(conflicts)) => conflicts,
+            _ => &[],
         }
     }
 }
@@ -140,23 +245,22 @@ impl ProcessingState {
 /// Inputs:
 /// - `item`: Package to process.
 /// - `action`: Install vs. remove context.
-/// - `runner`: Command execution abstraction.
 /// - `installed_version`: Previously fetched installed version (if any).
 /// - `installed_size`: Previously fetched installed size (if any).
+/// - `metadata`: Previously fetched (`download_bytes`, `install_size_target`).
 /// - `state`: Mutable state accumulator.
 ///
 /// Output: Updates `state` in place.
 ///
 /// Details:
-/// - Fetches metadata for official packages.
 /// - Computes version comparisons and notes.
 /// - Detects core packages and major version bumps.
-fn process_package_item<R: CommandRunner>(
+fn process_package_item(
     item: &PackageItem,
     action: PreflightAction,
-    runner: &R,
     installed_version: Option<String>,
     installed_size: Option<u64>,
+    metadata: (Option<u64>, Option<u64>),
     state: &mut ProcessingState,
 ) {
     if matches!(item.source, Source::Aur) {
@@ -177,7 +281,7 @@ fn process_package_item<R: CommandRunner>(
         );
     }
 
-    let (download_bytes, install_size_target) = fetch_package_metadata(runner, item);
+    let (download_bytes, install_size_target) = metadata;
 
     let install_delta_bytes = calculate_install_delta(action, install_size_target, installed_size);
 
@@ -200,6 +304,7 @@ fn process_package_item<R: CommandRunner>(
     let core_note = check_core_package(
         item,
         action,
+        &state.extra_critical_packages,
         &mut state.core_system_updates,
         &mut state.any_core_update,
     );
@@ -243,30 +348,29 @@ fn process_package_item<R: CommandRunner>(
 /// Inputs:
 /// - `runner`: Command execution abstraction.
 /// - `item`: Package item to fetch metadata for.
+/// - `official_metadata`: Batched `pacman -Si` metadata keyed by package name.
 ///
 /// Output: Tuple of (`download_bytes`, `install_size_target`), both `Option`.
 ///
 /// Details:
-/// - For official packages: uses `pacman -Si`.
+/// - For official packages: reads the batched `pacman -Si` results.
 /// - For AUR packages: checks local caches (pacman cache, AUR helper caches) for built package files.
 fn fetch_package_metadata<R: CommandRunner>(
     runner: &R,
     item: &PackageItem,
+    official_metadata: &std::collections::HashMap<String, OfficialMetadata>,
 ) -> (Option<u64>, Option<u64>) {
     match &item.source {
-        Source::Official { repo, .. } => {
-            match metadata::fetch_official_metadata(runner, repo, &item.name, item.version.as_str())
-            {
-                Ok(meta) => (meta.download_size, meta.install_size),
-                Err(err) => {
-                    tracing::debug!(
-                        "Preflight summary: failed to fetch metadata for {repo}/{pkg}: {err}",
-                        pkg = item.name
-                    );
-                    (None, None)
-                }
-            }
-        }
+        Source::Official { repo, .. } => official_metadata.get(&item.name).map_or_else(
+            || {
+                tracing::debug!(
+                    "Preflight summary: no metadata for {repo}/{pkg}",
+                    pkg = item.name
+                );
+                (None, None)
+            },
+            |meta| (meta.download_size, meta.install_size),
+        ),
         Source::Aur => {
             let meta =
                 metadata::fetch_aur_metadata(runner, &item.name, Some(item.version.as_str()));
@@ -373,22 +477,27 @@ fn analyze_version_changes(
 /// Inputs:
 /// - `item`: Package item to check.
 /// - `action`: Install vs. remove context.
+/// - `extra_critical`: User-configured critical package names (lowercase).
 /// - `core_system_updates`: Mutable list to append to if core package.
 /// - `any_core_update`: Mutable flag to set if core package.
 ///
 /// Output: Optional note string if core package detected.
 ///
-/// Details: Normalizes package name for comparison against critical packages list.
+/// Details: Normalizes package name for comparison against the built-in critical packages list
+/// merged with `extra_critical`.
 fn check_core_package(
     item: &PackageItem,
     action: PreflightAction,
+    extra_critical: &[String],
     core_system_updates: &mut Vec<String>,
     any_core_update: &mut bool,
 ) -> Option<String> {
     let normalized_name = item.name.to_ascii_lowercase();
     if CORE_CRITICAL_PACKAGES
         .iter()
-        .any(|candidate| normalized_name == *candidate)
+        .copied()
+        .chain(extra_critical.iter().map(String::as_str))
+        .any(|candidate| normalized_name == candidate)
     {
         *any_core_update = true;
         core_system_updates.push(item.name.clone());
@@ -402,6 +511,89 @@ fn check_core_package(
     }
 }
 
+/// What: Record locally modified config files as `.pacnew`/`.pacsave` candidates.
+///
+/// Inputs:
+/// - `state`: Processing state whose `packages` were already populated.
+/// - `action`: Install/downgrade (`.pacnew`) vs. remove (`.pacsave`) context.
+/// - `modified_backups`: Modified backup files keyed by installed package name.
+///
+/// Output: Updates `state` in place.
+///
+/// Details: Adds a per-package note and lists the package in `config_warning_packages`.
+fn record_config_candidates(
+    state: &mut ProcessingState,
+    action: PreflightAction,
+    modified_backups: &std::collections::HashMap<String, Vec<String>>,
+) {
+    let removing = matches!(action, PreflightAction::Remove);
+    let suffix = if removing { ".pacsave" } else { ".pacnew" };
+    for pkg in &mut state.packages {
+        let Some(files) = modified_backups.get(&pkg.name) else {
+            continue;
+        };
+        if removing {
+            state.pacsave_candidates += files.len();
+        } else {
+            state.pacnew_candidates += files.len();
+        }
+        pkg.notes.push(format!(
+            "{} modified config file(s) may be kept as {suffix}",
+            files.len()
+        ));
+        state.config_warning_packages.push(pkg.name.clone());
+    }
+}
+
+/// What: Record downgrade version-lock conflicts on the affected packages.
+///
+/// Inputs:
+/// - `state`: Processing state whose `packages` were already populated.
+/// - `conflicts`: Violated requirements from [`find_downgrade_conflicts`].
+///
+/// Output: Updates `state` in place.
+///
+/// Details: Adds a note per violated requirement to the downgraded package's summary.
+fn record_downgrade_conflicts(state: &mut ProcessingState, conflicts: Vec<DowngradeConflict>) {
+    for conflict in &conflicts {
+        if let Some(pkg) = state
+            .packages
+            .iter_mut()
+            .find(|p| p.name == conflict.target)
+        {
+            pkg.notes.push(format!(
+                "Required by {} ({})",
+                conflict.dependent, conflict.requirement
+            ));
+        }
+    }
+    state.downgrade_conflicts = conflicts;
+}
+
+/// What: Record the outcome of the dry-run conflict check.
+///
+/// Inputs:
+/// - `state`: Processing state whose `packages` were already populated.
+/// - `check`: Result of [`check_install_conflicts`].
+///
+/// Output: Updates `state` in place.
+///
+/// Details: Adds a note to each installed-side package named in a conflict.
+fn record_conflict_check(state: &mut ProcessingState, check: ConflictCheck) {
+    if let ConflictCheck::Checked(conflicts) = &check {
+        for conflict in conflicts {
+            if let Some(pkg) = state
+                .packages
+                .iter_mut()
+                .find(|p| p.name == conflict.package)
+            {
+                pkg.notes.push(conflict.warning());
+            }
+        }
+    }
+    state.conflict_check = Some(check);
+}
+
 /// What: Calculate risk reasons and score from processing state.
 ///
 /// Inputs:
@@ -410,40 +602,57 @@ fn check_core_package(
 /// - `service_restart_units`: List of services that need restart.
 /// - `action`: Preflight action (Install vs Remove).
 /// - `dependent_count`: Number of packages that depend on packages being removed (for Remove actions).
+/// - `weights`: Risk points per factor and the High cutoff (`preflight_risk_*` settings).
 ///
 /// Output: Tuple of (`risk_reasons`, `risk_score`, `risk_level`).
 ///
-/// Details: Applies the risk heuristic scoring system.
+/// Details:
+/// - Applies the risk heuristic scoring system; reasons show the effective weight.
+/// - Factors weighted 0 add neither points nor a reason.
+/// - The tiered Remove-dependents points are not configurable.
 fn calculate_risk_metrics(
     state: &ProcessingState,
     pacnew_candidates: usize,
     service_restart_units: &[String],
     action: PreflightAction,
     dependent_count: usize,
+    weights: &RiskWeights,
 ) -> (Vec<String>, u8, RiskLevel) {
     let mut risk_reasons = Vec::new();
     let mut risk_score: u8 = 0;
+    let mut add = |applies: bool, weight: u8, reason: &str| {
+        if applies && weight > 0 {
+            risk_reasons.push(format!("{reason} (+{weight})"));
+            risk_score = risk_score.saturating_add(weight);
+        }
+    };
 
-    if state.any_core_update {
-        risk_reasons.push("Core/system packages involved (+3)".to_string());
-        risk_score = risk_score.saturating_add(3);
-    }
-    if state.any_major_bump {
-        risk_reasons.push("Major version bump detected (+2)".to_string());
-        risk_score = risk_score.saturating_add(2);
-    }
-    if state.any_aur {
-        risk_reasons.push("AUR packages included (+2)".to_string());
-        risk_score = risk_score.saturating_add(2);
-    }
-    if pacnew_candidates > 0 {
-        risk_reasons.push("Configuration files may produce .pacnew (+1)".to_string());
-        risk_score = risk_score.saturating_add(1);
-    }
-    if !service_restart_units.is_empty() {
-        risk_reasons.push("Services likely require restart (+1)".to_string());
-        risk_score = risk_score.saturating_add(1);
-    }
+    add(
+        state.any_core_update,
+        weights.core,
+        "Core/system packages involved",
+    );
+    add(
+        state.any_major_bump,
+        weights.major_bump,
+        "Major version bump detected",
+    );
+    add(state.any_aur, weights.aur, "AUR packages included");
+    add(
+        pacnew_candidates > 0,
+        weights.pacnew,
+        "Configuration files may produce .pacnew",
+    );
+    add(
+        !service_restart_units.is_empty(),
+        weights.services,
+        "Services likely require restart",
+    );
+    add(
+        !state.conflicts().is_empty(),
+        weights.conflict,
+        "Package or file conflicts reported by pacman",
+    );
     // For Remove actions, add risk when removing packages with dependencies
     if matches!(action, PreflightAction::Remove) && dependent_count > 0 {
         let risk_points = if dependent_count >= 5 {
@@ -459,23 +668,114 @@ fn calculate_risk_metrics(
         risk_score = risk_score.saturating_add(risk_points);
     }
     // For Install actions, add risk when updating packages with installed dependents
-    // Add +2 risk points for each installed package that depends on packages being updated
-    if matches!(action, PreflightAction::Install) && dependent_count > 0 {
-        let risk_points = dependent_count.saturating_mul(2).min(255); // +2 per dependent package, cap at u8::MAX
+    // Add `weights.dependent` risk points for each installed package that depends on packages being updated
+    if matches!(action, PreflightAction::Install) && dependent_count > 0 && weights.dependent > 0 {
+        let risk_points = dependent_count
+            .saturating_mul(usize::from(weights.dependent))
+            .min(255); // per dependent package, cap at u8::MAX
         let risk_points_u8 = u8::try_from(risk_points).unwrap_or(255);
         risk_reasons.push(format!(
             "{dependent_count} installed package(s) depend on packages being updated (+{risk_points_u8})"
         ));
         risk_score = risk_score.saturating_add(risk_points_u8);
     }
+    // For Downgrade actions, add risk when dependents require a newer version
+    if !state.downgrade_conflicts.is_empty() && weights.downgrade_conflict > 0 {
+        let mut dependents: Vec<&str> = state
+            .downgrade_conflicts
+            .iter()
+            .map(|c| c.dependent.as_str())
+            .collect();
+        dependents.sort_unstable();
+        dependents.dedup();
+        risk_reasons.push(format!(
+            "Downgrade breaks version requirements of {} installed package(s) (+{})",
+            dependents.len(),
+            weights.downgrade_conflict
+        ));
+        risk_score = risk_score.saturating_add(weights.downgrade_conflict);
+    }
 
-    let risk_level = match risk_score {
-        0 => RiskLevel::Low,
-        1..=4 => RiskLevel::Medium,
-        _ => RiskLevel::High,
-    };
+    (
+        risk_reasons,
+        risk_score,
+        risk_level_for(risk_score, weights),
+    )
+}
+
+/// Prefix of the risk reason added by [`apply_sandbox_risk`].
+const SANDBOX_RISK_REASON: &str = "AUR packages need high-risk capabilities";
 
-    (risk_reasons, risk_score, risk_level)
+/// What: Add the sandbox analysis to an already computed summary's risk.
+///
+/// Inputs:
+/// - `summary`: Preflight summary to update.
+/// - `header`: Header chips mirroring the summary's risk.
+/// - `sandbox`: Sandbox analysis of the transaction's AUR packages.
+/// - `weights`: Risk weights (`weights.sandbox` points are added).
+///
+/// Output:
+/// - `true` when a risk reason was added.
+///
+/// Details:
+/// - Sandbox data arrives after the summary, so this runs whenever either side lands; the
+///   reason is added at most once.
+/// - Only batches with a high-risk package (see [`crate::logic::sandbox::summarize`]) count.
+pub fn apply_sandbox_risk(
+    summary: &mut PreflightSummaryData,
+    header: &mut PreflightHeaderChips,
+    sandbox: &[crate::logic::sandbox::SandboxInfo],
+    weights: &RiskWeights,
+) -> bool {
+    if weights.sandbox == 0
+        || summary
+            .risk_reasons
+            .iter()
+            .any(|r| r.starts_with(SANDBOX_RISK_REASON))
+    {
+        return false;
+    }
+    let sandbox_summary = crate::logic::sandbox::summarize(sandbox);
+    if !sandbox_summary.has_high_risk() {
+        return false;
+    }
+    let capabilities: Vec<&str> = sandbox_summary
+        .capabilities
+        .iter()
+        .map(|cap| cap.label())
+        .collect();
+    let reason = format!(
+        "{SANDBOX_RISK_REASON} ({}): {} (+{})",
+        sandbox_summary.high_risk_packages.join(", "),
+        capabilities.join(", "),
+        weights.sandbox
+    );
+    summary.risk_reasons.push(reason.clone());
+    summary.summary_warnings.push(reason);
+    summary.risk_score = summary.risk_score.saturating_add(weights.sandbox);
+    summary.risk_level = risk_level_for(summary.risk_score, weights);
+    header.risk_score = summary.risk_score;
+    header.risk_level = summary.risk_level;
+    true
+}
+
+/// What: Map a risk score to its level.
+///
+/// Inputs:
+/// - `score`: Preflight risk score.
+/// - `weights`: Risk weights providing the High cutoff.
+///
+/// Output:
+/// - `Low` for 0, `High` at or above `weights.high_threshold`, `Medium` in between.
+#[must_use]
+pub const fn risk_level_for(score: u8, weights: &RiskWeights) -> RiskLevel {
+    if score == 0 {
+        RiskLevel::Low
+    } else if score >= weights.high_threshold {
+        RiskLevel::High
+    } else {
+        RiskLevel::Medium
+    }
 }
 
 /// What: Build summary notes from processing state.
@@ -497,6 +797,9 @@ fn build_summary_notes(state: &ProcessingState) -> Vec<String> {
     if state.any_aur {
         notes.push("AUR packages present; build steps may vary.".to_string());
     }
+    if state.conflict_check == Some(ConflictCheck::Unavailable) {
+        notes.push("Conflict check unavailable; pacman may still report conflicts.".to_string());
+    }
     notes
 }
 
@@ -506,21 +809,65 @@ fn build_summary_notes(state: &ProcessingState) -> Vec<String> {
 /// - `items`: Packages to process.
 /// - `action`: Install vs. remove context.
 /// - `runner`: Command execution abstraction.
+/// - `cancelled`: Preflight cancellation flag.
 /// - `state`: Mutable state accumulator.
 ///
 /// Output: Updates `state` in place.
 ///
-/// Details: Batch fetches installed versions/sizes and processes each package.
-fn process_all_packages<R: CommandRunner>(
+/// Details:
+/// - Batch fetches installed versions/sizes and official `pacman -Si` metadata, then
+///   fetches per-package metadata on a bounded worker pool and processes packages in input order.
+/// - The metadata fetches use the `preflight_metadata_chunk_size` and
+///   `preflight_metadata_parallelism` settings.
+/// - Official packages without a `pacman -Si` download size fall back to a `HEAD` request
+///   against the first server in the pacman mirror list.
+/// - Stops early, leaving `state` partially filled, once `cancelled` is set.
+/// - `preflight_extra_critical_packages` extends the core/system package check.
+/// - Installed packages are checked for locally modified backup files (`pacman -Qii`).
+fn process_all_packages<R: CommandRunner + Sync>(
     items: &[PackageItem],
     action: PreflightAction,
     runner: &R,
+    cancelled: &AtomicBool,
     state: &mut ProcessingState,
 ) {
     let installed_versions = batch_fetch_installed_versions(runner, items);
     let installed_sizes = batch_fetch_installed_sizes(runner, items);
+    let prefs = crate::theme::settings();
+    state
+        .extra_critical_packages
+        .clone_from(&prefs.preflight_extra_critical_packages);
+    let mut official_metadata = batch_fetch_official_metadata(
+        runner,
+        items,
+        prefs.preflight_metadata_chunk_size,
+        prefs.preflight_metadata_parallelism,
+    );
+    if let Some(server) = std::fs::read_to_string(metadata::MIRRORLIST_PATH)
+        .ok()
+        .and_then(|body| metadata::first_mirror_server(&body))
+    {
+        fill_mirror_download_sizes(
+            runner,
+            items,
+            &mut official_metadata,
+            &server,
+            prefs.preflight_metadata_parallelism,
+            cancelled,
+        );
+    }
+    let package_metadata = fetch_in_input_order(
+        items,
+        prefs.preflight_metadata_parallelism,
+        cancelled,
+        |item| fetch_package_metadata(runner, item, &official_metadata),
+    );
+    if cancelled.load(std::sync::atomic::Ordering::Relaxed) {
+        tracing::debug!("Preflight summary: cancelled during metadata fetch");
+        return;
+    }
 
-    for (idx, item) in items.iter().enumerate() {
+    for (idx, (item, metadata)) in items.iter().zip(package_metadata).enumerate() {
         let installed_version = installed_versions
             .get(idx)
             .and_then(|v| v.as_ref().ok())
@@ -533,12 +880,23 @@ fn process_all_packages<R: CommandRunner>(
         process_package_item(
             item,
             action,
-            runner,
             installed_version,
             installed_size,
+            metadata.unwrap_or_default(),
             state,
         );
     }
+
+    let installed_names: Vec<&str> = items
+        .iter()
+        .zip(&installed_versions)
+        .filter(|(_, version)| version.is_ok())
+        .map(|(item, _)| item.name.as_str())
+        .collect();
+    if !installed_names.is_empty() {
+        let modified_backups = batch_fetch_modified_backups(runner, &installed_names);
+        record_config_candidates(state, action, &modified_backups);
+    }
 }
 
 /// What: Resolve reverse dependencies for Remove actions and count installed dependents for Install actions.
@@ -599,6 +957,13 @@ fn build_summary_data(
     if summary_warnings.is_empty() {
         summary_warnings.extend(risk_reasons.iter().cloned());
     }
+    summary_warnings.extend(
+        state
+            .downgrade_conflicts
+            .iter()
+            .map(DowngradeConflict::warning),
+    );
+    summary_warnings.extend(state.conflicts().iter().map(ConflictInfo::warning));
 
     PreflightSummaryData {
         packages: state.packages,
@@ -611,12 +976,18 @@ fn build_summary_data(
         risk_reasons: risk_reasons.to_vec(),
         major_bump_packages: state.major_bump_packages,
         core_system_updates: state.core_system_updates,
-        pacnew_candidates: 0,
-        pacsave_candidates: 0,
-        config_warning_packages: Vec::new(),
+        pacnew_candidates: state.pacnew_candidates,
+        pacsave_candidates: state.pacsave_candidates,
+        config_warning_packages: state.config_warning_packages,
         service_restart_units: Vec::new(),
         summary_warnings,
         summary_notes,
+        orphaned_dependencies: Vec::new(),
+        conflicts: match state.conflict_check {
+            Some(ConflictCheck::Checked(conflicts)) => conflicts,
+            _ => Vec::new(),
+        },
+        hook_notes: Vec::new(),
     }
 }
 
@@ -665,11 +1036,39 @@ const fn build_header_chips(
 /// Details:
 /// - Fetches installed versions/sizes via `pacman` when possible.
 /// - Applies the initial risk heuristic outlined in the specification.
+/// - For downgrades, checks installed dependents' versioned requirements when the
+///   `downgrade_conflict_check` setting is enabled.
+/// - For installs, runs a dry-run `pacman -S --print` conflict check on official packages.
+/// - Lists alpm hooks (e.g. mkinitcpio, grub) the transaction will trigger as hook notes.
 /// - Gracefully degrades metrics when metadata is unavailable.
-pub fn compute_preflight_summary_with_runner<R: CommandRunner>(
+pub fn compute_preflight_summary_with_runner<R: CommandRunner + Sync>(
+    items: &[PackageItem],
+    action: PreflightAction,
+    runner: &R,
+) -> PreflightSummaryOutcome {
+    compute_preflight_summary_with_cancel(items, action, runner, &AtomicBool::new(false))
+}
+
+/// What: Compute preflight summary data, stopping early when the preflight is cancelled.
+///
+/// Inputs:
+/// - `items`: Packages under review.
+/// - `action`: Install vs. remove context.
+/// - `runner`: Command executor (use [`SystemCommandRunner`] outside tests).
+/// - `cancelled`: Preflight cancellation flag (`AppState::preflight_cancelled`).
+///
+/// Output:
+/// - [`PreflightSummaryOutcome`]; after cancellation it only covers the packages processed so
+///   far and is expected to be discarded.
+///
+/// Details:
+/// - Same computation as [`compute_preflight_summary_with_runner`]; the flag is checked
+///   between per-package metadata fetches.
+pub fn compute_preflight_summary_with_cancel<R: CommandRunner + Sync>(
     items: &[PackageItem],
     action: PreflightAction,
     runner: &R,
+    cancelled: &AtomicBool,
 ) -> PreflightSummaryOutcome {
     let _span = tracing::info_span!(
         "compute_preflight_summary",
@@ -680,12 +1079,38 @@ pub fn compute_preflight_summary_with_runner<R: CommandRunner>(
     let start_time = std::time::Instant::now();
 
     let mut state = ProcessingState::new(items.len());
-    process_all_packages(items, action, runner, &mut state);
+    process_all_packages(items, action, runner, cancelled, &mut state);
+    let was_cancelled = cancelled.load(std::sync::atomic::Ordering::Relaxed);
+    if !was_cancelled
+        && matches!(action, PreflightAction::Downgrade)
+        && crate::theme::settings().downgrade_conflict_check
+    {
+        let conflicts = find_downgrade_conflicts(runner, items);
+        record_downgrade_conflicts(&mut state, conflicts);
+    }
+    if !was_cancelled && matches!(action, PreflightAction::Install) {
+        record_conflict_check(&mut state, check_install_conflicts(runner, items));
+    }
+    let hook_notes = if was_cancelled {
+        Vec::new()
+    } else {
+        transaction_hook_notes(runner, &SystemHookSource, items, action, &state.packages)
+    };
 
-    let (dependent_count, reverse_deps_report) = resolve_reverse_deps(items, action);
+    let (dependent_count, reverse_deps_report) = if was_cancelled {
+        (0, None)
+    } else {
+        resolve_reverse_deps(items, action)
+    };
 
-    let (risk_reasons, risk_score, risk_level) =
-        calculate_risk_metrics(&state, 0, &[], action, dependent_count);
+    let (risk_reasons, risk_score, risk_level) = calculate_risk_metrics(
+        &state,
+        state.pacnew_candidates,
+        &[],
+        action,
+        dependent_count,
+        &crate::theme::settings().preflight_risk_weights,
+    );
 
     let header = build_header_chips(
         items.len(),
@@ -696,7 +1121,13 @@ pub fn compute_preflight_summary_with_runner<R: CommandRunner>(
         risk_level,
     );
 
-    let summary = build_summary_data(state, items, &risk_reasons, risk_score, risk_level);
+    let mut summary = build_summary_data(state, items, &risk_reasons, risk_score, risk_level);
+    summary.hook_notes = hook_notes;
+    if let Some(report) = reverse_deps_report.as_ref() {
+        summary
+            .orphaned_dependencies
+            .clone_from(&report.orphaned_dependencies);
+    }
 
     let elapsed = start_time.elapsed();
     let duration_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
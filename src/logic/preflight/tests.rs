@@ -158,3 +158,759 @@ fn summary_handles_aur_without_metadata() {
     );
     assert_eq!(outcome.header.aur_count, 1);
 }
+
+#[test]
+/// What: Ensure installed sizes populate for installed packages from one batched `pacman -Qi`.
+///
+/// Inputs:
+/// - Two installed packages plus one name `pacman -Qi` does not report.
+///
+/// Output:
+/// - Sizes in bytes for the reported packages; the unreported name is omitted.
+fn fetch_installed_sizes_populates_installed_packages() {
+    let mut responses = HashMap::new();
+    responses.insert(
+        (
+            "pacman".into(),
+            vec![
+                "-Qi".into(),
+                "ripgrep".into(),
+                "linux-firmware".into(),
+                "ghost".into(),
+            ],
+        ),
+        Ok("Name            : ripgrep\nInstalled Size  : 4.00 MiB\n\nName            : linux-firmware\nInstalled Size  : 512.00 MiB\n".to_string()),
+    );
+    let runner = MockRunner::with(responses);
+    let names = vec![
+        "ripgrep".to_string(),
+        "linux-firmware".to_string(),
+        "ghost".to_string(),
+    ];
+
+    let sizes = fetch_installed_sizes_with_runner(&runner, &names);
+
+    assert_eq!(sizes.get("ripgrep"), Some(&(4 * 1024 * 1024)));
+    assert_eq!(sizes.get("linux-firmware"), Some(&(512 * 1024 * 1024)));
+    assert!(!sizes.contains_key("ghost"));
+}
+
+/// Runner answering every `pacman -Si repo/name ...` call with one block per spec.
+#[derive(Default)]
+struct SiEchoRunner {
+    /// Number of specs passed to each `-Si` invocation.
+    chunk_lens: Mutex<Vec<usize>>,
+}
+
+impl CommandRunner for SiEchoRunner {
+    fn run(&self, program: &str, args: &[&str]) -> Result<String, CommandError> {
+        assert_eq!(program, "pacman");
+        assert_eq!(args.first(), Some(&"-Si"));
+        let specs = &args[1..];
+        self.chunk_lens
+            .lock()
+            .expect("poisoned chunk_lens mutex")
+            .push(specs.len());
+        Ok(specs
+            .iter()
+            .map(|spec| {
+                let name = spec.split_once('/').map_or(*spec, |(_, n)| n);
+                format!(
+                    "Repository      : extra\nName            : {name}\nVersion         : 1.0\nDownload Size   : 1.00 KiB\nInstalled Size  : 2.00 KiB\n"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+#[test]
+/// What: Ensure the batched `pacman -Si` fetch is split into bounded chunks.
+///
+/// Inputs:
+/// - 25 official packages plus one AUR package, chunk size 10, up to 3 parallel chunks.
+///
+/// Output:
+/// - Three `-Si` calls (10 + 10 + 5 specs) and metadata for every official package.
+///
+/// Details:
+/// - AUR packages never reach `pacman -Si`; chunk completion order is not asserted.
+fn official_metadata_fetch_is_chunked() {
+    let mut items: Vec<PackageItem> = (0..25)
+        .map(|i| PackageItem::test_item(&format!("pkg{i}"), "extra"))
+        .collect();
+    items.push(PackageItem::test_item("aur-only", "aur"));
+    let runner = SiEchoRunner::default();
+
+    let metadata = batch_fetch_official_metadata(&runner, &items, 10, 3);
+
+    let mut chunk_lens = runner
+        .chunk_lens
+        .lock()
+        .expect("poisoned chunk_lens mutex")
+        .clone();
+    chunk_lens.sort_unstable();
+    assert_eq!(chunk_lens, vec![5, 10, 10]);
+    assert_eq!(metadata.len(), 25);
+    for i in 0..25 {
+        let meta = metadata.get(&format!("pkg{i}")).expect("metadata for pkg");
+        assert_eq!(meta.download_size, Some(1024));
+        assert_eq!(meta.install_size, Some(2048));
+    }
+    assert!(!metadata.contains_key("aur-only"));
+}
+
+#[test]
+/// What: Ensure official packages without `pacman -Si` sizes fall back to a mirror `HEAD`.
+///
+/// Inputs:
+/// - Mirror list with a commented and an active server.
+/// - `mirror-fallback` with no `-Si` metadata and a mocked `curl -I` answer; `mirror-missing`
+///   whose `HEAD` request fails; `mirror-known` that already has a download size.
+///
+/// Output:
+/// - `mirror-fallback` gets the final `Content-Length`; `mirror-missing` stays absent and
+///   `mirror-known` keeps its `-Si` value.
+/// - A second lookup is served from the cache without any mocked responses.
+fn mirror_download_size_fills_missing_metadata() {
+    let server = metadata::first_mirror_server(
+        "# Server = https://old.example/$repo/os/$arch\nServer = https://mirror.example/$repo/os/$arch\n",
+    )
+    .expect("active server");
+    assert_eq!(server, "https://mirror.example/$repo/os/$arch");
+    let official = |name: &str| PackageItem {
+        version: "1:2.0-1".into(),
+        ..PackageItem::test_item(name, "extra")
+    };
+    let items = vec![
+        official("mirror-fallback"),
+        official("mirror-missing"),
+        official("mirror-known"),
+    ];
+    let url =
+        metadata::mirror_package_url(&server, "extra", "mirror-fallback", "1:2.0-1", "x86_64");
+    assert!(url.starts_with("https://mirror.example/extra/os/"));
+    assert!(url.ends_with("/mirror-fallback-1:2.0-1-x86_64.pkg.tar.zst"));
+    let mut responses = HashMap::new();
+    responses.insert(
+        ("curl".into(), crate::util::curl_args(&url, &["-I"])),
+        Ok(
+            "HTTP/2 302\r\ncontent-length: 0\r\n\r\nHTTP/2 200\r\nContent-Length: 4096\r\n\r\n"
+                .to_string(),
+        ),
+    );
+    let runner = MockRunner::with(responses);
+    let mut official_metadata = HashMap::from([(
+        "mirror-known".to_string(),
+        OfficialMetadata {
+            download_size: Some(10),
+            install_size: Some(20),
+        },
+    )]);
+
+    fill_mirror_download_sizes(
+        &runner,
+        &items,
+        &mut official_metadata,
+        &server,
+        2,
+        &AtomicBool::new(false),
+    );
+
+    assert_eq!(
+        official_metadata
+            .get("mirror-fallback")
+            .and_then(|m| m.download_size),
+        Some(4096)
+    );
+    assert!(!official_metadata.contains_key("mirror-missing"));
+    assert_eq!(
+        official_metadata
+            .get("mirror-known")
+            .and_then(|m| m.download_size),
+        Some(10)
+    );
+
+    let mut again = HashMap::new();
+    fill_mirror_download_sizes(
+        &MockRunner::default(),
+        &items[..1],
+        &mut again,
+        &server,
+        1,
+        &AtomicBool::new(false),
+    );
+    assert_eq!(
+        again.get("mirror-fallback").and_then(|m| m.download_size),
+        Some(4096)
+    );
+}
+
+/// Runner that records how many calls are in flight at once and echoes the last argument.
+#[derive(Default)]
+struct ConcurrencyRunner {
+    /// Calls currently running.
+    in_flight: std::sync::atomic::AtomicUsize,
+    /// Highest number of simultaneous calls observed.
+    peak: std::sync::atomic::AtomicUsize,
+}
+
+impl CommandRunner for ConcurrencyRunner {
+    fn run(&self, _program: &str, args: &[&str]) -> Result<String, CommandError> {
+        use std::sync::atomic::Ordering::SeqCst;
+        let now = self.in_flight.fetch_add(1, SeqCst) + 1;
+        self.peak.fetch_max(now, SeqCst);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        self.in_flight.fetch_sub(1, SeqCst);
+        Ok(args.last().copied().unwrap_or_default().to_string())
+    }
+}
+
+#[test]
+/// What: Ensure per-package metadata fetches run concurrently but come back in input order.
+///
+/// Inputs:
+/// - 12 AUR packages fetched through a recording runner with at most 4 workers.
+/// - The same packages with the cancellation flag set before and during the fetch.
+///
+/// Output:
+/// - Results match input order; more than one and at most 4 calls ran at once.
+/// - Pre-cancelled fetch runs nothing; cancelling after the first call skips the rest.
+fn package_metadata_fetch_is_bounded_and_ordered() {
+    use std::sync::atomic::Ordering::SeqCst;
+    let items: Vec<PackageItem> = (0..12)
+        .map(|i| PackageItem::test_item(&format!("pkg{i:02}"), "aur"))
+        .collect();
+    let runner = ConcurrencyRunner::default();
+    let not_cancelled = AtomicBool::new(false);
+
+    let results = fetch_in_input_order(&items, 4, &not_cancelled, |item| {
+        runner.run("pacman", &["-Si", &item.name]).ok()
+    });
+
+    let names: Vec<String> = results.into_iter().flatten().flatten().collect();
+    let expected: Vec<String> = items.iter().map(|item| item.name.clone()).collect();
+    assert_eq!(names, expected);
+    let peak = runner.peak.load(SeqCst);
+    assert!(peak > 1, "expected concurrent fetches, peak was {peak}");
+    assert!(peak <= 4, "worker limit exceeded, peak was {peak}");
+
+    let cancelled = AtomicBool::new(true);
+    let skipped = fetch_in_input_order(&items, 4, &cancelled, |item| item.name.clone());
+    assert!(skipped.iter().all(Option::is_none));
+
+    let cancel_midway = AtomicBool::new(false);
+    let partial = fetch_in_input_order(&items, 1, &cancel_midway, |item| {
+        cancel_midway.store(true, SeqCst);
+        item.name.clone()
+    });
+    assert_eq!(partial[0].as_deref(), Some("pkg00"));
+    assert!(partial[1..].iter().all(Option::is_none));
+}
+
+#[test]
+/// What: Ensure a failed batched `pacman -Si` falls back to per-package queries.
+///
+/// Inputs:
+/// - Two official packages; only the single-package query for `present` succeeds.
+///
+/// Output:
+/// - Metadata for `present`; `missing` is omitted instead of failing the whole chunk.
+fn official_metadata_fetch_falls_back_per_package() {
+    let mut responses = HashMap::new();
+    responses.insert(
+        ("pacman".into(), vec!["-Si".into(), "extra/present".into()]),
+        Ok("Name            : present\nDownload Size   : 3.00 KiB\n".to_string()),
+    );
+    let runner = MockRunner::with(responses);
+    let items: Vec<PackageItem> = ["present", "missing"]
+        .iter()
+        .map(|name| PackageItem::test_item(name, "extra"))
+        .collect();
+
+    let metadata = batch_fetch_official_metadata(&runner, &items, 50, 4);
+
+    assert_eq!(
+        metadata.get("present").and_then(|m| m.download_size),
+        Some(3 * 1024)
+    );
+    assert!(!metadata.contains_key("missing"));
+}
+
+/// What: Build a mock dependency graph for the downgrade conflict check.
+///
+/// Inputs: None.
+///
+/// Output:
+/// - Runner where `libfoo` is required by `app-a` (`libfoo>=2.0`) and `app-b`
+///   (`libfoo>=1.5`, plus an unversioned `glibc`).
+fn downgrade_graph_runner() -> MockRunner {
+    let mut responses = HashMap::new();
+    responses.insert(
+        ("pacman".into(), vec!["-Qi".into(), "libfoo".into()]),
+        Ok(
+            "Name            : libfoo\nVersion         : 2.1-1\nRequired By     : app-a  app-b\n"
+                .to_string(),
+        ),
+    );
+    responses.insert(
+        (
+            "pacman".into(),
+            vec!["-Qi".into(), "app-a".into(), "app-b".into()],
+        ),
+        Ok("Name            : app-a\nDepends On      : glibc  libfoo>=2.0\n\nName            : app-b\nDepends On      : glibc  libfoo>=1.5\n".to_string()),
+    );
+    MockRunner::with(responses)
+}
+
+/// What: Build a downgrade item for `libfoo`.
+///
+/// Inputs:
+/// - `version`: Downgrade target version.
+///
+/// Output:
+/// - Official `libfoo` package item.
+fn libfoo_downgrade(version: &str) -> PackageItem {
+    PackageItem {
+        version: version.into(),
+        ..PackageItem::test_item("libfoo", "extra")
+    }
+}
+
+#[test]
+/// What: Ensure a downgrade below a dependent's minimum version is reported.
+///
+/// Inputs:
+/// - `libfoo` downgraded to `1.9-1`; `app-a` requires `>=2.0`, `app-b` requires `>=1.5`.
+///
+/// Output:
+/// - One conflict for `app-a`, a package note, a warning, and +3 risk.
+fn downgrade_conflict_detected_for_min_version() {
+    let runner = downgrade_graph_runner();
+    let item = libfoo_downgrade("1.9-1");
+
+    let conflicts = find_downgrade_conflicts(&runner, std::slice::from_ref(&item));
+    assert_eq!(
+        conflicts,
+        vec![DowngradeConflict {
+            target: "libfoo".into(),
+            target_version: "1.9-1".into(),
+            dependent: "app-a".into(),
+            requirement: "libfoo>=2.0".into(),
+        }]
+    );
+
+    let mut state = ProcessingState::new(1);
+    state.packages.push(PreflightPackageSummary {
+        name: item.name.clone(),
+        source: item.source.clone(),
+        installed_version: Some("2.1-1".into()),
+        target_version: item.version.clone(),
+        is_downgrade: true,
+        is_major_bump: false,
+        download_bytes: None,
+        install_delta_bytes: None,
+        notes: Vec::new(),
+    });
+    record_downgrade_conflicts(&mut state, conflicts);
+    assert_eq!(
+        state.packages[0].notes,
+        vec!["Required by app-a (libfoo>=2.0)"]
+    );
+
+    let (reasons, score, level) = calculate_risk_metrics(
+        &state,
+        0,
+        &[],
+        PreflightAction::Downgrade,
+        0,
+        &RiskWeights::default(),
+    );
+    assert_eq!(score, 3);
+    assert_eq!(level, RiskLevel::Medium);
+    assert!(reasons.iter().any(|r| r.contains("version requirements")));
+
+    let summary = build_summary_data(state, &[item], &reasons, score, level);
+    assert!(
+        summary
+            .summary_warnings
+            .iter()
+            .any(|w| w == "app-a requires libfoo>=2.0; downgrading libfoo to 1.9-1 breaks it")
+    );
+}
+
+#[test]
+/// What: Ensure downgrades that keep every requirement satisfied raise no conflict.
+///
+/// Inputs:
+/// - `libfoo` downgraded to `2.0-3`, which satisfies both `>=2.0` and `>=1.5`.
+///
+/// Output:
+/// - No conflicts and no added risk.
+///
+/// Details:
+/// - The requirement has no `pkgrel`, so `2.0-3` compares as `2.0`.
+fn downgrade_conflict_absent_when_satisfied() {
+    let runner = downgrade_graph_runner();
+
+    let conflicts = find_downgrade_conflicts(&runner, &[libfoo_downgrade("2.0-3")]);
+    assert!(conflicts.is_empty());
+
+    let mut state = ProcessingState::new(1);
+    record_downgrade_conflicts(&mut state, conflicts);
+    let (reasons, score, _) = calculate_risk_metrics(
+        &state,
+        0,
+        &[],
+        PreflightAction::Downgrade,
+        0,
+        &RiskWeights::default(),
+    );
+    assert_eq!(score, 0);
+    assert!(reasons.is_empty());
+}
+
+#[test]
+/// What: Ensure user-configured critical packages are treated like built-in core packages.
+///
+/// Inputs:
+/// - `NVIDIA` (official, mixed case) processed without and with `nvidia` in the extra list.
+///
+/// Output:
+/// - Without the extra list no core note is added and the score stays 0; with it the package
+///   lands in `core_system_updates`, gets the core note, and adds the core risk points.
+fn extra_critical_packages_raise_core_risk() {
+    let item = PackageItem {
+        version: "560.35.03-1".into(),
+        ..PackageItem::test_item("NVIDIA", "extra")
+    };
+    let mut plain = ProcessingState::new(1);
+    process_package_item(
+        &item,
+        PreflightAction::Install,
+        None,
+        None,
+        (None, None),
+        &mut plain,
+    );
+    assert!(plain.core_system_updates.is_empty());
+    let (_, plain_score, _) = calculate_risk_metrics(
+        &plain,
+        0,
+        &[],
+        PreflightAction::Install,
+        0,
+        &RiskWeights::default(),
+    );
+    assert_eq!(plain_score, 0);
+
+    let mut state = ProcessingState::new(1);
+    state.extra_critical_packages = vec!["grub".into(), "nvidia".into()];
+    process_package_item(
+        &item,
+        PreflightAction::Install,
+        None,
+        None,
+        (None, None),
+        &mut state,
+    );
+    assert_eq!(state.core_system_updates, vec!["NVIDIA".to_string()]);
+    assert!(
+        state.packages[0]
+            .notes
+            .iter()
+            .any(|n| n == "Core/system package update")
+    );
+    let (reasons, score, _) = calculate_risk_metrics(
+        &state,
+        0,
+        &[],
+        PreflightAction::Install,
+        0,
+        &RiskWeights::default(),
+    );
+    assert_eq!(score, plain_score + 3);
+    assert!(reasons.iter().any(|r| r.contains("Core/system")));
+}
+
+#[test]
+/// What: Ensure custom risk weights change both the score and the reason text.
+///
+/// Inputs:
+/// - State with an AUR package and a major bump, scored with default weights and with
+///   `aur = 0`, `major_bump = 6`, `high_threshold = 6`.
+///
+/// Output:
+/// - Defaults: score 4 (Medium) with "(+2)" reasons. Custom: score 6 (High), the AUR reason is
+///   dropped, and the major bump reason reads "(+6)".
+fn custom_risk_weights_change_score_and_reasons() {
+    let mut state = ProcessingState::new(1);
+    state.any_aur = true;
+    state.any_major_bump = true;
+
+    let (reasons, score, level) = calculate_risk_metrics(
+        &state,
+        0,
+        &[],
+        PreflightAction::Install,
+        0,
+        &RiskWeights::default(),
+    );
+    assert_eq!(score, 4);
+    assert_eq!(level, RiskLevel::Medium);
+    assert_eq!(
+        reasons,
+        vec![
+            "Major version bump detected (+2)",
+            "AUR packages included (+2)"
+        ]
+    );
+
+    let weights = RiskWeights {
+        aur: 0,
+        major_bump: 6,
+        high_threshold: 6,
+        ..RiskWeights::default()
+    };
+    let (reasons, score, level) =
+        calculate_risk_metrics(&state, 0, &[], PreflightAction::Install, 0, &weights);
+    assert_eq!(score, 6);
+    assert_eq!(level, RiskLevel::High);
+    assert_eq!(reasons, vec!["Major version bump detected (+6)"]);
+
+    let (reasons, score, _) =
+        calculate_risk_metrics(&state, 0, &[], PreflightAction::Install, 3, &weights);
+    assert_eq!(score, 12);
+    assert!(reasons.iter().any(|r| r.ends_with("being updated (+6)")));
+}
+
+#[test]
+/// What: Verify a high-risk sandbox analysis raises the summary risk exactly once.
+///
+/// Inputs:
+/// - Empty summary (score 0), sandbox info for a DKMS package; applied twice.
+///
+/// Output:
+/// - One reason naming the package and capability, score 3 on summary and header.
+fn apply_sandbox_risk_adds_reason_once() {
+    let item = PackageItem::test_item("foo-dkms", "aur");
+    let mut summary = build_summary_data(
+        ProcessingState::new(1),
+        std::slice::from_ref(&item),
+        &[],
+        0,
+        RiskLevel::Low,
+    );
+    let mut header = PreflightHeaderChips::default();
+    let sandbox = vec![crate::logic::sandbox::SandboxInfo {
+        package_name: "foo-dkms".into(),
+        depends: vec![crate::logic::sandbox::DependencyDelta {
+            name: "dkms".into(),
+            is_installed: true,
+            installed_version: None,
+            version_satisfied: true,
+        }],
+        makedepends: Vec::new(),
+        checkdepends: Vec::new(),
+        optdepends: Vec::new(),
+    }];
+    let weights = RiskWeights::default();
+
+    assert!(apply_sandbox_risk(
+        &mut summary,
+        &mut header,
+        &sandbox,
+        &weights
+    ));
+    assert!(!apply_sandbox_risk(
+        &mut summary,
+        &mut header,
+        &sandbox,
+        &weights
+    ));
+    assert_eq!(
+        summary.risk_reasons,
+        vec!["AUR packages need high-risk capabilities (foo-dkms): filesystem-root (+3)"]
+    );
+    assert_eq!((summary.risk_score, header.risk_score), (3, 3));
+    assert_eq!(header.risk_level, risk_level_for(3, &weights));
+}
+
+/// What: Build an installed official package upgrade and its mocked `pacman -Q`/`-Qii` output.
+///
+/// Inputs:
+/// - `name`: Package name.
+/// - `backup_field`: Value lines of the `Backup Files` field.
+///
+/// Output:
+/// - The package item and a runner answering `pacman -Q` and `pacman -Qii` for it.
+fn installed_with_backups(name: &str, backup_field: &str) -> (PackageItem, MockRunner) {
+    let mut responses = HashMap::new();
+    responses.insert(
+        ("pacman".into(), vec!["-Q".into(), name.into()]),
+        Ok(format!("{name} 1.26.0-1\n")),
+    );
+    responses.insert(
+        ("pacman".into(), vec!["-Qii".into(), name.into()]),
+        Ok(format!(
+            "Name            : {name}\nVersion         : 1.26.0-1\n\
+             Backup Files    : {backup_field}\n\
+             Validated By    : Signature\n"
+        )),
+    );
+    let item = PackageItem {
+        version: "1.26.1-1".into(),
+        ..PackageItem::test_item(name, "extra")
+    };
+    (item, MockRunner::with(responses))
+}
+
+#[test]
+/// What: Ensure modified backup files become `.pacnew` candidates and fire the risk reason.
+///
+/// Inputs:
+/// - Installed `nginx` upgrade whose `pacman -Qii` lists one modified, one unmodified, and one
+///   missing backup file (both entry layouts).
+///
+/// Output:
+/// - One `.pacnew` candidate, `nginx` in `config_warning_packages`, a package note, and the
+///   `.pacnew` risk reason.
+fn pacnew_candidates_counted_from_modified_backups() {
+    let (item, runner) = installed_with_backups(
+        "nginx",
+        "/etc/nginx/nginx.conf [modified]\n\
+         \x20                 [unmodified]\t/etc/nginx/mime.types\n\
+         \x20                 /etc/logrotate.d/nginx [missing]",
+    );
+
+    let outcome = compute_preflight_summary_with_runner(&[item], PreflightAction::Install, &runner);
+
+    assert_eq!(outcome.summary.pacnew_candidates, 1);
+    assert_eq!(outcome.summary.pacsave_candidates, 0);
+    assert_eq!(outcome.summary.config_warning_packages, vec!["nginx"]);
+    assert!(
+        outcome.summary.packages[0]
+            .notes
+            .iter()
+            .any(|n| n.contains(".pacnew"))
+    );
+    assert!(
+        outcome
+            .summary
+            .risk_reasons
+            .iter()
+            .any(|r| r.starts_with("Configuration files may produce .pacnew"))
+    );
+}
+
+#[test]
+/// What: Ensure packages without modified backup files add no candidates or risk.
+///
+/// Inputs:
+/// - Installed package with `Backup Files : None`; a package with a modified file being removed.
+///
+/// Output:
+/// - No candidates, warnings, or `.pacnew` reason; removal counts a `.pacsave` candidate instead.
+fn config_candidates_absent_without_backups() {
+    let (item, runner) = installed_with_backups("ripgrep", "None");
+
+    let outcome = compute_preflight_summary_with_runner(&[item], PreflightAction::Install, &runner);
+
+    assert_eq!(outcome.summary.pacnew_candidates, 0);
+    assert!(outcome.summary.config_warning_packages.is_empty());
+    assert!(
+        !outcome
+            .summary
+            .risk_reasons
+            .iter()
+            .any(|r| r.contains(".pacnew"))
+    );
+
+    let (item, runner) = installed_with_backups("nginx", "/etc/nginx/nginx.conf [modified]");
+    let mut state = ProcessingState::new(1);
+    process_all_packages(
+        &[item],
+        PreflightAction::Remove,
+        &runner,
+        &AtomicBool::new(false),
+        &mut state,
+    );
+    assert_eq!(state.pacsave_candidates, 1);
+    assert_eq!(state.pacnew_candidates, 0);
+    assert_eq!(state.config_warning_packages, vec!["nginx"]);
+}
+
+#[test]
+/// What: Ensure dry-run conflicts reach the summary and raise risk, and a missing tool degrades.
+///
+/// Inputs:
+/// - Official `iptables-nft` install whose dry run reports a conflict with `iptables`.
+/// - The same install with the dry-run command failing.
+///
+/// Output:
+/// - One conflict, a warning, and the conflict risk reason; without the tool no conflicts,
+///   no conflict risk, and an "unavailable" note.
+fn install_conflicts_recorded_in_summary() {
+    let item = PackageItem {
+        version: "1:1.8.10-2".into(),
+        ..PackageItem::test_item("iptables-nft", "core")
+    };
+    let mut responses = HashMap::new();
+    responses.insert(
+        (
+            "sh".into(),
+            vec![
+                "-c".into(),
+                conflicts::DRY_RUN_SCRIPT.into(),
+                "sh".into(),
+                "iptables-nft".into(),
+            ],
+        ),
+        Ok(":: iptables-nft-1:1.8.10-2 and iptables-1:1.8.10-2 are in conflict. Remove iptables? [y/N]\n\
+            error: unresolvable package conflicts detected\n"
+            .to_string()),
+    );
+    let runner = MockRunner::with(responses);
+
+    let outcome = compute_preflight_summary_with_runner(
+        std::slice::from_ref(&item),
+        PreflightAction::Install,
+        &runner,
+    );
+    let summary = outcome.summary;
+    assert_eq!(summary.conflicts.len(), 1);
+    assert_eq!(summary.conflicts[0].conflicts_with, "iptables");
+    assert!(
+        summary
+            .summary_warnings
+            .iter()
+            .any(|w| w == "iptables-nft conflicts with iptables")
+    );
+    assert!(
+        summary
+            .risk_reasons
+            .iter()
+            .any(|r| r == "Package or file conflicts reported by pacman (+3)")
+    );
+
+    let outcome = compute_preflight_summary_with_runner(
+        &[item],
+        PreflightAction::Install,
+        &MockRunner::default(),
+    );
+    assert!(outcome.summary.conflicts.is_empty());
+    assert!(
+        !outcome
+            .summary
+            .risk_reasons
+            .iter()
+            .any(|r| r.starts_with("Package or file conflicts"))
+    );
+    assert!(
+        outcome
+            .summary
+            .summary_notes
+            .iter()
+            .any(|n| n.starts_with("Conflict check unavailable"))
+    );
+}
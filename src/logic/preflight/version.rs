@@ -17,7 +17,8 @@ use std::cmp::Ordering;
 /// Details:
 /// - Splits on `.` and `-`, comparing numeric segments when possible and
 ///   falling back to lexicographical comparison.
-pub(super) fn compare_versions(a: &str, b: &str) -> Ordering {
+#[must_use]
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
     let a_parts: Vec<&str> = a.split(['.', '-']).collect();
     let b_parts: Vec<&str> = b.split(['.', '-']).collect();
     let len = a_parts.len().max(b_parts.len());
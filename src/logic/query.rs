@@ -2,7 +2,120 @@
 
 use tokio::sync::mpsc;
 
-use crate::state::AppState;
+use crate::state::{AppState, PackageItem, Source};
+
+/// What: Normalize query text according to the `search_normalize` setting.
+///
+/// Inputs:
+/// - `text`: Raw query text as typed
+/// - `mode`: Normalization mode (`"trim"`, `"collapse"`, or `"off"`)
+///
+/// Output:
+/// - Normalized query used for ranking and search-cache comparison.
+///
+/// Details:
+/// - `trim` trims surrounding whitespace and lowercases; `collapse` additionally collapses
+///   internal whitespace runs to a single space. `off` (or unknown values handled as `off`)
+///   returns the text unchanged.
+#[must_use]
+pub fn normalize_query(text: &str, mode: &str) -> String {
+    match mode {
+        "trim" => text.trim().to_lowercase(),
+        "collapse" => text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase(),
+        _ => text.to_string(),
+    }
+}
+
+/// Search text split into plain terms and operators.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedQuery {
+    /// Remaining text used for matching and ranking, space-joined.
+    pub terms: String,
+    /// Lowercased words whose presence in the name or description excludes a result (`-word`).
+    pub exclude: Vec<String>,
+    /// Lowercased repositories results are restricted to (`repo:extra`); `aur` selects AUR.
+    pub repos: Vec<String>,
+}
+
+impl ParsedQuery {
+    /// What: Whether the query carries any operator.
+    #[must_use]
+    pub const fn has_operators(&self) -> bool {
+        !self.exclude.is_empty() || !self.repos.is_empty()
+    }
+
+    /// What: Whether results from the AUR can satisfy the repo restriction.
+    #[must_use]
+    pub fn allows_aur(&self) -> bool {
+        self.repos.is_empty() || self.repos.iter().any(|r| r == "aur")
+    }
+
+    /// What: Decide whether a result passes the exclusion and repo operators.
+    ///
+    /// Inputs:
+    /// - `item`: Candidate result.
+    ///
+    /// Output:
+    /// - `false` when the name or description contains an excluded word, or when a repo
+    ///   restriction is set and the item comes from another repo.
+    #[must_use]
+    pub fn matches(&self, item: &PackageItem) -> bool {
+        if !self.repos.is_empty() {
+            let repo = match &item.source {
+                Source::Official { repo, .. } => repo.to_lowercase(),
+                Source::Aur => "aur".to_string(),
+            };
+            if !self.repos.contains(&repo) {
+                return false;
+            }
+        }
+        if self.exclude.is_empty() {
+            return true;
+        }
+        let name = item.name.to_lowercase();
+        let description = item.description.to_lowercase();
+        !self
+            .exclude
+            .iter()
+            .any(|word| name.contains(word.as_str()) || description.contains(word.as_str()))
+    }
+}
+
+/// What: Split query text into terms, `-word` exclusions, and `repo:name` restrictions.
+///
+/// Inputs:
+/// - `text`: Query as typed or normalized.
+///
+/// Output:
+/// - [`ParsedQuery`]; `terms` keeps every token that is not an operator, in order.
+///
+/// Details:
+/// - A bare `-`, a bare `repo:`, and any other `prefix:` token are literal text.
+/// - Several `repo:` tokens widen the restriction (results from any of them match).
+#[must_use]
+pub fn parse_query_operators(text: &str) -> ParsedQuery {
+    let mut parsed = ParsedQuery::default();
+    let mut terms: Vec<&str> = Vec::new();
+    for token in text.split_whitespace() {
+        if let Some(word) = token.strip_prefix('-')
+            && !word.is_empty()
+        {
+            parsed.exclude.push(word.to_lowercase());
+        } else if let Some(repo) = token.strip_prefix("repo:")
+            && !repo.is_empty()
+        {
+            parsed.repos.push(repo.to_lowercase());
+        } else {
+            terms.push(token);
+        }
+    }
+    parsed.terms = terms.join(" ");
+    parsed
+}
 
 /// What: Send the current query text over the search channel with a fresh id.
 ///
@@ -15,6 +128,7 @@ use crate::state::AppState;
 ///
 /// Details:
 /// - The id allows correlating responses so the UI can discard stale results.
+/// - The text is normalized per `search_normalize` so whitespace/case variants query alike.
 /// - Cache checking happens in `handle_search_results` to avoid architectural changes.
 pub fn send_query(app: &mut AppState, query_tx: &mpsc::UnboundedSender<crate::state::QueryInput>) {
     let id = app.next_query_id;
@@ -22,7 +136,7 @@ pub fn send_query(app: &mut AppState, query_tx: &mpsc::UnboundedSender<crate::st
     app.latest_query_id = id;
     let _ = query_tx.send(crate::state::QueryInput {
         id,
-        text: app.input.clone(),
+        text: normalize_query(&app.input, &crate::theme::settings().search_normalize),
         fuzzy: app.fuzzy_search_enabled,
     });
 }
@@ -59,4 +173,105 @@ mod tests {
         assert_eq!(q.text, "hello");
         assert!(!q.fuzzy); // Default is false
     }
+
+    #[test]
+    /// What: Ensure `normalize_query` applies each normalization mode.
+    ///
+    /// Inputs:
+    /// - Whitespace- and case-varied queries under `trim`, `collapse`, and `off`.
+    ///
+    /// Output:
+    /// - `trim` and `collapse` fold trailing-space/case variants together; `off` keeps text as typed.
+    ///
+    /// Details:
+    /// - Only `collapse` merges internal whitespace runs.
+    fn normalize_query_modes() {
+        assert_eq!(normalize_query("ripgrep ", "trim"), "ripgrep");
+        assert_eq!(normalize_query(" RipGrep", "trim"), "ripgrep");
+        assert_eq!(
+            normalize_query("python  requests", "trim"),
+            "python  requests"
+        );
+        assert_eq!(
+            normalize_query(" Python \t requests ", "collapse"),
+            "python requests"
+        );
+        assert_eq!(normalize_query("RipGrep ", "off"), "RipGrep ");
+    }
+
+    /// What: Build a result from `repo` (`aur` for the AUR) with a description.
+    fn item(name: &str, repo: &str, description: &str) -> PackageItem {
+        PackageItem {
+            description: description.into(),
+            ..PackageItem::test_item(name, repo)
+        }
+    }
+
+    #[test]
+    /// What: Ensure `-word` excludes results by name or description.
+    ///
+    /// Inputs:
+    /// - `python -django`, a bare `-`, and three candidates.
+    ///
+    /// Output:
+    /// - Terms keep `python`; name and description hits are dropped; a bare `-` stays literal.
+    fn parse_query_operators_exclusion() {
+        let q = parse_query_operators("python -Django");
+        assert_eq!(q.terms, "python");
+        assert_eq!(q.exclude, ["django"]);
+        assert!(q.matches(&item("python-requests", "extra", "HTTP library")));
+        assert!(!q.matches(&item("python-django", "extra", "Web framework")));
+        assert!(!q.matches(&item("python-drf", "aur", "REST for Django")));
+
+        let literal = parse_query_operators("c - d");
+        assert_eq!(literal.terms, "c - d");
+        assert!(!literal.has_operators());
+    }
+
+    #[test]
+    /// What: Ensure `repo:name` restricts results and gates the AUR.
+    ///
+    /// Inputs:
+    /// - `repo:extra term`, `repo:aur term`, and literal `repo:` / `foo:bar` tokens.
+    ///
+    /// Output:
+    /// - Only matching repos pass; AUR is allowed only for `repo:aur` or no restriction.
+    fn parse_query_operators_repo_scope() {
+        let q = parse_query_operators("repo:Extra term");
+        assert_eq!(q.terms, "term");
+        assert_eq!(q.repos, ["extra"]);
+        assert!(q.matches(&item("term", "extra", "")));
+        assert!(!q.matches(&item("term", "core", "")));
+        assert!(!q.matches(&item("term", "aur", "")));
+        assert!(!q.allows_aur());
+
+        let aur = parse_query_operators("repo:aur term");
+        assert!(aur.allows_aur());
+        assert!(aur.matches(&item("term", "aur", "")));
+        assert!(!aur.matches(&item("term", "extra", "")));
+
+        let literal = parse_query_operators("repo: foo:bar");
+        assert_eq!(literal.terms, "repo: foo:bar");
+        assert!(!literal.has_operators());
+        assert!(literal.allows_aur());
+    }
+
+    #[test]
+    /// What: Ensure exclusion and repo operators combine.
+    ///
+    /// Inputs:
+    /// - `repo:extra repo:core -git editor`.
+    ///
+    /// Output:
+    /// - Results must be in `core` or `extra` and not mention `git`.
+    fn parse_query_operators_combined() {
+        let q = parse_query_operators("repo:extra repo:core -git editor");
+        assert_eq!(q.terms, "editor");
+        assert_eq!(q.repos, ["extra", "core"]);
+        assert_eq!(q.exclude, ["git"]);
+        assert!(q.matches(&item("vim", "extra", "Vi improved editor")));
+        assert!(q.matches(&item("nano", "core", "Pico editor clone")));
+        assert!(!q.matches(&item("gitui", "extra", "Terminal UI for git")));
+        assert!(!q.matches(&item("micro", "aur", "Modern editor")));
+    }
 }
@@ -0,0 +1,161 @@
+//! Raw command output captured for expert mode.
+//!
+//! Preflight summarizes `pacman -Si`/`-Qi` and AUR helper output into metadata and
+//! dependency lists. When `expert_mode` is enabled, the resolvers also record the
+//! unparsed stdout here (keyed by package and command) so the preflight modal can
+//! reveal it in a scrollable subview.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, OnceLock};
+
+/// Upper bound on stored captures; the oldest package entries are dropped first.
+const MAX_RAW_OUTPUT_ENTRIES: usize = 256;
+
+/// One captured command invocation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawOutputEntry {
+    /// Package the command was run for.
+    pub package: String,
+    /// Command line as displayed (e.g. `pacman -Si core/bash`).
+    pub command: String,
+    /// Captured stdout, unmodified.
+    pub output: String,
+}
+
+/// Captures keyed by `(package, command)` with a monotonically increasing insertion stamp.
+type RawOutputStore = BTreeMap<(String, String), (u64, String)>;
+
+/// Global capture store shared by the background resolvers and the UI.
+static RAW_OUTPUT: OnceLock<Mutex<(u64, RawOutputStore)>> = OnceLock::new();
+
+/// What: Record raw command output when expert mode is enabled.
+///
+/// Inputs:
+/// - `package`: Package the command was run for.
+/// - `command`: Command line as displayed.
+/// - `output`: Captured stdout.
+///
+/// Output:
+/// - None; the capture is stored for [`raw_output_for`].
+///
+/// Details:
+/// - No-op unless the `expert_mode` setting is enabled, so normal sessions keep nothing.
+pub fn capture_raw_output(package: &str, command: &str, output: &str) {
+    if crate::theme::settings().expert_mode {
+        store_raw_output(package, command, output);
+    }
+}
+
+/// What: Store raw command output unconditionally.
+///
+/// Inputs:
+/// - `package`: Package the command was run for.
+/// - `command`: Command line as displayed.
+/// - `output`: Captured stdout.
+///
+/// Output:
+/// - None; replaces any previous capture of the same package and command.
+///
+/// Details:
+/// - Evicts the oldest capture once `MAX_RAW_OUTPUT_ENTRIES` is exceeded.
+pub fn store_raw_output(package: &str, command: &str, output: &str) {
+    let Ok(mut guard) = RAW_OUTPUT
+        .get_or_init(|| Mutex::new((0, BTreeMap::new())))
+        .lock()
+    else {
+        return;
+    };
+    let (stamp, store) = &mut *guard;
+    *stamp += 1;
+    store.insert(
+        (package.to_string(), command.to_string()),
+        (*stamp, output.to_string()),
+    );
+    if store.len() > MAX_RAW_OUTPUT_ENTRIES
+        && let Some(oldest) = store
+            .iter()
+            .min_by_key(|(_, (s, _))| *s)
+            .map(|(k, _)| k.clone())
+    {
+        store.remove(&oldest);
+    }
+}
+
+/// What: Collect captured output for a set of packages.
+///
+/// Inputs:
+/// - `packages`: Package names to look up (e.g. preflight items and their dependencies).
+///
+/// Output:
+/// - Captures ordered by the position of their package in `packages`, then by command.
+///
+/// Details:
+/// - Packages without captures are skipped; duplicates in `packages` are listed once.
+#[must_use]
+pub fn raw_output_for<'a, I>(packages: I) -> Vec<RawOutputEntry>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let Some(lock) = RAW_OUTPUT.get() else {
+        return Vec::new();
+    };
+    let Ok(guard) = lock.lock() else {
+        return Vec::new();
+    };
+    let mut seen = std::collections::HashSet::new();
+    let mut out = Vec::new();
+    for package in packages {
+        if !seen.insert(package) {
+            continue;
+        }
+        let start = (package.to_string(), String::new());
+        out.extend(
+            guard
+                .1
+                .range(start..)
+                .take_while(|((p, _), _)| p == package)
+                .map(|((p, c), (_, o))| RawOutputEntry {
+                    package: p.clone(),
+                    command: c.clone(),
+                    output: o.clone(),
+                }),
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify captures are returned per package in request order and replaced on re-capture.
+    ///
+    /// Inputs:
+    /// - Two captures for `raw-test-a` (one re-captured) and one for `raw-test-b`.
+    ///
+    /// Output:
+    /// - `raw-test-b` first when requested first; the re-captured output wins; unknown names are skipped.
+    ///
+    /// Details:
+    /// - Uses unique package names because the store is process-global.
+    fn raw_output_is_grouped_by_package() {
+        store_raw_output("raw-test-a", "pacman -Si raw-test-a", "old");
+        store_raw_output("raw-test-a", "pacman -Si raw-test-a", "Name : raw-test-a");
+        store_raw_output("raw-test-a", "pacman -Qi raw-test-a", "local");
+        store_raw_output("raw-test-b", "paru -Si raw-test-b", "Name : raw-test-b");
+
+        let entries =
+            raw_output_for(["raw-test-b", "raw-test-missing", "raw-test-a", "raw-test-b"]);
+        let commands: Vec<&str> = entries.iter().map(|e| e.command.as_str()).collect();
+        assert_eq!(
+            commands,
+            vec![
+                "paru -Si raw-test-b",
+                "pacman -Qi raw-test-a",
+                "pacman -Si raw-test-a"
+            ]
+        );
+        assert_eq!(entries[2].output, "Name : raw-test-a");
+    }
+}
@@ -0,0 +1,244 @@
+//! Decide whether re-adding already installed packages needs the reinstall confirmation.
+
+use crate::logic::deps::InstalledProvider;
+use crate::state::types::{PackageItem, Source};
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+/// What: Check whether a package's source is listed in a skip-sources setting value.
+///
+/// Inputs:
+/// - `item`: Package being reinstalled.
+/// - `skip_sources`: Comma list of `aur`, `official`, or repository names.
+///
+/// Output:
+/// - `true` when the package's source matches one of the listed entries.
+fn source_is_skipped(item: &PackageItem, skip_sources: &str) -> bool {
+    skip_sources
+        .split(',')
+        .map(|s| s.trim().to_ascii_lowercase())
+        .filter(|s| !s.is_empty())
+        .any(|s| match &item.source {
+            Source::Aur => s == "aur",
+            Source::Official { repo, .. } => s == "official" || repo.eq_ignore_ascii_case(&s),
+        })
+}
+
+/// What: Decide whether a reinstall set requires confirmation under explicit settings.
+///
+/// Inputs:
+/// - `reinstalls`: Packages already installed with no update available (true reinstalls).
+/// - `threshold`: Minimum counted reinstalls that trigger the confirmation (`0` behaves as `1`).
+/// - `skip_sources`: Comma list of sources whose reinstalls are never counted.
+///
+/// Output:
+/// - `true` when the reinstall confirmation should be shown.
+///
+/// Details:
+/// - Callers already exclude packages with pending updates, so updates never count here.
+#[must_use]
+pub fn reinstall_confirm_required_with(
+    reinstalls: &[PackageItem],
+    threshold: usize,
+    skip_sources: &str,
+) -> bool {
+    let counted = reinstalls
+        .iter()
+        .filter(|item| !source_is_skipped(item, skip_sources))
+        .count();
+    counted > 0 && counted >= threshold.max(1)
+}
+
+/// What: Decide whether a reinstall set requires confirmation using the current settings.
+///
+/// Inputs:
+/// - `reinstalls`: Packages already installed with no update available.
+///
+/// Output:
+/// - `true` when the `ConfirmReinstall` modal should be shown.
+///
+/// Details:
+/// - Reads `reinstall_confirm_threshold` and `reinstall_confirm_skip_sources`.
+#[must_use]
+pub fn reinstall_confirm_required(reinstalls: &[PackageItem]) -> bool {
+    let prefs = crate::theme::settings();
+    reinstall_confirm_required_with(
+        reinstalls,
+        prefs.reinstall_confirm_threshold,
+        &prefs.reinstall_confirm_skip_sources,
+    )
+}
+
+/// What: Decide whether installing `item` would only reinstall what is already present.
+///
+/// Inputs:
+/// - `item`: Package requested for install.
+/// - `provider`: Installed package satisfying `item.name`, if any.
+/// - `upgradable`: Names reported as upgradable by `pacman -Qu`.
+///
+/// Output:
+/// - `true` when the exact package is installed and no update is pending.
+///
+/// Details:
+/// - A name satisfied only through `provides` (e.g. `rust` by `rustup`) installs a different
+///   package, so it is never a reinstall even though it looks installed.
+/// - Versions are compared without the pkgrel suffix; an unknown installed version counts as
+///   the same version.
+#[must_use]
+pub fn is_reinstall_candidate<S: BuildHasher>(
+    item: &PackageItem,
+    provider: Option<&InstalledProvider>,
+    upgradable: &HashSet<String, S>,
+) -> bool {
+    let Some(provider) = provider else {
+        return false;
+    };
+    if provider.via_provides || upgradable.contains(&item.name) {
+        return false;
+    }
+    if item.version.is_empty() {
+        return true;
+    }
+    let normalized_target_version = item.version.split('-').next().unwrap_or(&item.version);
+    provider
+        .version
+        .as_deref()
+        .is_none_or(|installed| installed == normalized_target_version)
+}
+
+/// What: Collect the packages of an install set that would only be reinstalled.
+///
+/// Inputs:
+/// - `items`: Packages requested for install.
+/// - `installed`: Directly installed package names (`pacman -Qq`).
+/// - `upgradable`: Names reported as upgradable by `pacman -Qu`.
+///
+/// Output:
+/// - Cloned items that are already installed with no update available.
+///
+/// Details:
+/// - Resolves `provides` aliases to the concrete installed package before comparing versions.
+#[must_use]
+pub fn reinstall_candidates<S: BuildHasher + Default, U: BuildHasher>(
+    items: &[PackageItem],
+    installed: &HashSet<String, S>,
+    upgradable: &HashSet<String, U>,
+) -> Vec<PackageItem> {
+    items
+        .iter()
+        .filter(|item| {
+            let provider = crate::logic::deps::resolve_installed_provider(&item.name, installed);
+            is_reinstall_candidate(item, provider.as_ref(), upgradable)
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_reinstall_below_threshold_skips_confirm() {
+        assert!(!reinstall_confirm_required_with(
+            &[PackageItem::test_item("ripgrep", "extra")],
+            3,
+            ""
+        ));
+    }
+
+    #[test]
+    fn larger_reinstall_set_still_prompts() {
+        let set = vec![
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("fd", "extra"),
+            PackageItem::test_item("paru", "aur"),
+        ];
+        assert!(reinstall_confirm_required_with(&set, 3, ""));
+    }
+
+    #[test]
+    fn default_threshold_always_prompts_and_empty_never_does() {
+        assert!(reinstall_confirm_required_with(
+            &[PackageItem::test_item("paru", "aur")],
+            1,
+            ""
+        ));
+        assert!(reinstall_confirm_required_with(
+            &[PackageItem::test_item("paru", "aur")],
+            0,
+            ""
+        ));
+        assert!(!reinstall_confirm_required_with(&[], 0, ""));
+    }
+
+    #[test]
+    fn skipped_sources_do_not_count() {
+        let set = vec![
+            PackageItem::test_item("paru", "aur"),
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("linux", "core"),
+        ];
+        assert!(!reinstall_confirm_required_with(&set, 1, "aur, official"));
+        assert!(!reinstall_confirm_required_with(&set, 2, "aur,EXTRA"));
+        assert!(reinstall_confirm_required_with(&set, 1, "aur,extra"));
+    }
+
+    fn provider(package: &str, version: &str, via_provides: bool) -> InstalledProvider {
+        InstalledProvider {
+            package: package.into(),
+            version: Some(version.into()),
+            via_provides,
+        }
+    }
+
+    #[test]
+    fn not_installed_package_is_plain_install() {
+        let mut item = PackageItem::test_item("fd", "extra");
+        item.version = "10.2.0-1".into();
+        assert!(!is_reinstall_candidate(&item, None, &HashSet::new()));
+    }
+
+    #[test]
+    fn provides_satisfied_name_is_not_reinstall() {
+        let mut item = PackageItem::test_item("rust", "extra");
+        item.version = "1:1.83.0-1".into();
+        let rustup = provider("rustup", "1.27.1", true);
+        assert!(!is_reinstall_candidate(
+            &item,
+            Some(&rustup),
+            &HashSet::new()
+        ));
+        item.version = "1.27.1-1".into();
+        assert!(!is_reinstall_candidate(
+            &item,
+            Some(&rustup),
+            &HashSet::new()
+        ));
+    }
+
+    #[test]
+    fn installed_package_with_same_version_is_reinstall() {
+        let mut item = PackageItem::test_item("ripgrep", "extra");
+        item.version = "14.1.0-1".into();
+        let installed = provider("ripgrep", "14.1.0", false);
+        assert!(is_reinstall_candidate(
+            &item,
+            Some(&installed),
+            &HashSet::new()
+        ));
+
+        let upgradable = HashSet::from(["ripgrep".to_string()]);
+        assert!(!is_reinstall_candidate(
+            &item,
+            Some(&installed),
+            &upgradable
+        ));
+        item.version = "14.2.0-1".into();
+        assert!(!is_reinstall_candidate(
+            &item,
+            Some(&installed),
+            &HashSet::new()
+        ));
+    }
+}
@@ -95,6 +95,7 @@ pub fn persist_dynamic_filter_toggle_and_refresh(
     }
     crate::theme::save_results_filter_show_canonical(canonical_id, new_visible);
     crate::logic::apply_filters_and_sort_preserve_selection(app);
+    app.record_current_view();
 }
 
 /// What: Turn every dynamic repo filter on or off together, persist, and re-filter.
@@ -120,6 +121,7 @@ pub fn persist_dynamic_filters_set_all(app: &mut AppState, new_visible: bool) {
         crate::theme::save_results_filter_show_canonical(id, new_visible);
     }
     crate::logic::apply_filters_and_sort_preserve_selection(app);
+    app.record_current_view();
 }
 
 /// What: Whether Arch-style repository tooling (pacman paths, privileged apply) is available.
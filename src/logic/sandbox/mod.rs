@@ -3,6 +3,7 @@
 mod analyze;
 mod fetch;
 mod parse;
+mod summary;
 mod types;
 
 #[cfg(test)]
@@ -10,6 +11,9 @@ mod tests;
 
 pub use analyze::extract_package_name;
 pub use parse::{parse_pkgbuild_conflicts, parse_pkgbuild_deps};
+pub use summary::{
+    SandboxCapability, SandboxSeverity, SandboxSummary, capabilities, severity, summarize,
+};
 pub use types::{DependencyDelta, SandboxInfo};
 
 use crate::logic::sandbox::analyze::{
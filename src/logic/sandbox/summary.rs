@@ -0,0 +1,166 @@
+//! Severity summary over sandbox analysis results.
+//!
+//! `SandboxInfo` only carries dependency data, so capabilities are inferred from the
+//! runtime and build dependencies a package pulls in (e.g. `dkms` implies writing into
+//! `/usr/lib/modules` as root).
+
+use crate::logic::sandbox::analyze::extract_package_name;
+use crate::logic::sandbox::types::SandboxInfo;
+
+/// What: Sensitive capability inferred from a package's dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SandboxCapability {
+    /// Network clients, servers, or firewall tooling.
+    Network,
+    /// Writes outside the package payload as root (kernel modules, initramfs, bootloader).
+    FilesystemRoot,
+    /// Setuid helpers or privilege escalation frameworks.
+    Suid,
+}
+
+impl SandboxCapability {
+    /// What: Short label used in risk reasons.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Network => "network",
+            Self::FilesystemRoot => "filesystem-root",
+            Self::Suid => "suid",
+        }
+    }
+
+    /// What: Whether the capability alone makes a package high risk.
+    const fn is_high_risk(self) -> bool {
+        matches!(self, Self::FilesystemRoot | Self::Suid)
+    }
+}
+
+/// What: Severity of a single package's sandbox analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SandboxSeverity {
+    /// No sensitive capabilities and every runtime dependency is present.
+    Low,
+    /// Network capability or runtime dependencies missing from the host.
+    Medium,
+    /// Filesystem-root or setuid capability.
+    High,
+}
+
+/// What: Aggregated sandbox severity for a batch of AUR packages.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SandboxSummary {
+    /// Packages rated [`SandboxSeverity::Low`].
+    pub low: usize,
+    /// Packages rated [`SandboxSeverity::Medium`].
+    pub medium: usize,
+    /// Packages rated [`SandboxSeverity::High`].
+    pub high: usize,
+    /// Capabilities flagged by any package, sorted and deduplicated.
+    pub capabilities: Vec<SandboxCapability>,
+    /// Names of the packages rated high risk, in input order.
+    pub high_risk_packages: Vec<String>,
+}
+
+impl SandboxSummary {
+    /// What: Whether any package in the batch is rated high risk.
+    #[must_use]
+    pub const fn has_high_risk(&self) -> bool {
+        self.high > 0
+    }
+}
+
+/// What: Map a dependency name to the capability it implies.
+///
+/// Inputs:
+/// - `name`: Bare dependency name (no version requirement).
+///
+/// Output:
+/// - `Some(capability)` for known sensitive dependencies, `None` otherwise.
+fn capability_for_dependency(name: &str) -> Option<SandboxCapability> {
+    match name {
+        "curl" | "wget" | "openssh" | "networkmanager" | "iptables" | "iptables-nft"
+        | "nftables" | "openvpn" | "wireguard-tools" | "socat" | "nmap" => {
+            Some(SandboxCapability::Network)
+        }
+        "dkms"
+        | "linux-headers"
+        | "linux-lts-headers"
+        | "linux-zen-headers"
+        | "linux-hardened-headers"
+        | "mkinitcpio"
+        | "dracut"
+        | "grub"
+        | "efibootmgr" => Some(SandboxCapability::FilesystemRoot),
+        "sudo" | "opendoas" | "polkit" | "libcap" => Some(SandboxCapability::Suid),
+        _ => None,
+    }
+}
+
+/// What: Infer the capabilities a package needs from its dependencies.
+///
+/// Inputs:
+/// - `info`: Sandbox analysis for one package.
+///
+/// Output:
+/// - Sorted, deduplicated capabilities from `depends` and `makedepends`.
+///
+/// Details:
+/// - Optional and check dependencies are ignored since they are not pulled in by default.
+#[must_use]
+pub fn capabilities(info: &SandboxInfo) -> Vec<SandboxCapability> {
+    let mut caps: Vec<SandboxCapability> = info
+        .depends
+        .iter()
+        .chain(&info.makedepends)
+        .filter_map(|dep| capability_for_dependency(&extract_package_name(&dep.name)))
+        .collect();
+    caps.sort_unstable();
+    caps.dedup();
+    caps
+}
+
+/// What: Rate one package's sandbox analysis.
+///
+/// Inputs:
+/// - `info`: Sandbox analysis for one package.
+///
+/// Output:
+/// - `High` for filesystem-root/suid, `Medium` for network or missing runtime dependencies,
+///   `Low` otherwise.
+#[must_use]
+pub fn severity(info: &SandboxInfo) -> SandboxSeverity {
+    let caps = capabilities(info);
+    if caps.iter().any(|cap| cap.is_high_risk()) {
+        SandboxSeverity::High
+    } else if !caps.is_empty() || info.depends.iter().any(|dep| !dep.is_installed) {
+        SandboxSeverity::Medium
+    } else {
+        SandboxSeverity::Low
+    }
+}
+
+/// What: Summarize sandbox analysis for a batch of packages.
+///
+/// Inputs:
+/// - `infos`: Sandbox analysis results (e.g. `install_list_sandbox`).
+///
+/// Output:
+/// - Counts per severity and the union of flagged capabilities; empty input yields the default.
+#[must_use]
+pub fn summarize(infos: &[SandboxInfo]) -> SandboxSummary {
+    let mut summary = SandboxSummary::default();
+    for info in infos {
+        match severity(info) {
+            SandboxSeverity::Low => summary.low += 1,
+            SandboxSeverity::Medium => summary.medium += 1,
+            SandboxSeverity::High => {
+                summary.high += 1;
+                summary.high_risk_packages.push(info.package_name.clone());
+            }
+        }
+        summary.capabilities.extend(capabilities(info));
+    }
+    summary.capabilities.sort_unstable();
+    summary.capabilities.dedup();
+    summary
+}
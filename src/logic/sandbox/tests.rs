@@ -1,6 +1,34 @@
-//! Unit tests for sandbox parsing functions.
+//! Unit tests for sandbox parsing and severity summary functions.
 
 use crate::logic::sandbox::parse::{parse_pkgbuild_conflicts, parse_pkgbuild_deps};
+use crate::logic::sandbox::{
+    DependencyDelta, SandboxCapability, SandboxInfo, SandboxSummary, summarize,
+};
+
+/// What: Build a synthetic `SandboxInfo` for summary tests.
+///
+/// Inputs:
+/// - `name`: Package name.
+/// - `depends`: Runtime dependencies as `(spec, installed)` pairs.
+/// - `makedepends`: Build dependency specs (all installed).
+///
+/// Output:
+/// - `SandboxInfo` with empty check/optional dependencies.
+fn sandbox_info(name: &str, depends: &[(&str, bool)], makedepends: &[&str]) -> SandboxInfo {
+    let delta = |spec: &str, installed: bool| DependencyDelta {
+        name: spec.to_string(),
+        is_installed: installed,
+        installed_version: None,
+        version_satisfied: installed,
+    };
+    SandboxInfo {
+        package_name: name.to_string(),
+        depends: depends.iter().map(|(d, i)| delta(d, *i)).collect(),
+        makedepends: makedepends.iter().map(|d| delta(d, true)).collect(),
+        checkdepends: Vec::new(),
+        optdepends: Vec::new(),
+    }
+}
 
 #[test]
 /// What: Test parsing dependencies from PKGBUILD with depends= syntax.
@@ -391,3 +419,52 @@ conflicts=('foo' 'libcairo.so' 'bar' 'libdbus-1.so=1-64')
     assert!(conflicts.contains(&"foo".to_string()));
     assert!(conflicts.contains(&"bar".to_string()));
 }
+
+#[test]
+/// What: Verify summaries of empty and benign batches carry no high risk.
+///
+/// Inputs:
+/// - No packages; then packages with plain installed deps and a network client dependency.
+///
+/// Output:
+/// - Empty batch is the default summary; benign batch counts Low/Medium and flags only network.
+fn test_summarize_empty_and_benign_batches() {
+    let empty = summarize(&[]);
+    assert_eq!(empty, SandboxSummary::default());
+    assert!(!empty.has_high_risk());
+
+    let benign = summarize(&[
+        sandbox_info("plain-bin", &[("glibc", true)], &["cmake"]),
+        sandbox_info("sync-tool", &[("curl>=8.0", true)], &[]),
+    ]);
+    assert_eq!((benign.low, benign.medium, benign.high), (1, 1, 0));
+    assert_eq!(benign.capabilities, vec![SandboxCapability::Network]);
+    assert!(!benign.has_high_risk());
+}
+
+#[test]
+/// What: Verify root-filesystem and setuid dependencies mark a batch high risk.
+///
+/// Inputs:
+/// - A DKMS module (build dependency on `linux-headers`), a tool needing `sudo`, a benign package.
+///
+/// Output:
+/// - Two High packages named in input order; capabilities sorted and deduplicated.
+fn test_summarize_high_risk_batch() {
+    let summary = summarize(&[
+        sandbox_info("foo-dkms", &[("dkms", true)], &["linux-headers"]),
+        sandbox_info("plain-bin", &[("glibc", true)], &[]),
+        sandbox_info("admin-tool", &[("sudo", true), ("wget", false)], &[]),
+    ]);
+    assert_eq!((summary.low, summary.medium, summary.high), (1, 0, 2));
+    assert!(summary.has_high_risk());
+    assert_eq!(summary.high_risk_packages, vec!["foo-dkms", "admin-tool"]);
+    assert_eq!(
+        summary.capabilities,
+        vec![
+            SandboxCapability::Network,
+            SandboxCapability::FilesystemRoot,
+            SandboxCapability::Suid,
+        ]
+    );
+}
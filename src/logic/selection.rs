@@ -23,6 +23,7 @@ use crate::state::{AppState, PackageItem};
 /// - Clamps the selection to valid bounds, refreshes placeholder metadata, and reuses cached entries.
 /// - Schedules PKGBUILD reloads when necessary and tracks scroll velocity to throttle prefetching.
 /// - Updates comments when package changes and comments are visible (only for AUR packages).
+/// - Opens startup viewers still queued by the `pkgb_visible`/`comments_visible` settings.
 /// - Switches between selected-only gating during fast scrolls and wide ring prefetch for slower navigation.
 pub fn move_sel_cached(
     app: &mut AppState,
@@ -105,6 +106,7 @@ pub fn move_sel_cached(
                 } else {
                     // Request new comments
                     app.comments.clear();
+                    app.comments_hidden.clear();
                     app.comments_package_name = None;
                     app.comments_fetched_at = None;
                     app.comments_scroll = 0;
@@ -114,6 +116,9 @@ pub fn move_sel_cached(
                 }
             }
         }
+
+        // Startup viewers still queued (e.g. comments waiting for an AUR row) open here
+        crate::logic::viewers::apply_startup_viewers(app, comments_tx);
     }
 
     // Debounce ring prefetch when scrolling fast (>5 items cumulatively)
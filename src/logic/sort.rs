@@ -84,8 +84,12 @@ fn reorder_from_indices(results: &mut Vec<PackageItem>, indices: &[usize]) {
 ///
 /// Details:
 /// - Used for `BestMatches` sort mode. Query-dependent, so cannot be cached.
+/// - The query is normalized per `search_normalize` before ranking.
 fn sort_best_matches(results: &mut [PackageItem], query: &str) {
-    let ql = query.trim().to_lowercase();
+    let settings = crate::theme::settings();
+    let normalized = crate::logic::normalize_query(query, &settings.search_normalize);
+    let ql = normalized.trim().to_lowercase();
+    let priority = settings.repo_priority;
     results.sort_by(|a, b| {
         let ra = crate::util::match_rank(&a.name, &ql);
         let rb = crate::util::match_rank(&b.name, &ql);
@@ -93,8 +97,8 @@ fn sort_best_matches(results: &mut [PackageItem], query: &str) {
             return ra.cmp(&rb);
         }
         // Tiebreak: keep pacman repo order first to keep layout familiar
-        let oa = crate::util::repo_order(&a.source);
-        let ob = crate::util::repo_order(&b.source);
+        let oa = crate::util::repo_order_with(&a.source, &priority);
+        let ob = crate::util::repo_order_with(&b.source, &priority);
         if oa != ob {
             return oa.cmp(&ob);
         }
@@ -102,6 +106,30 @@ fn sort_best_matches(results: &mut [PackageItem], query: &str) {
     });
 }
 
+/// What: Compute sort order indices for installed-size sorting, largest first.
+///
+/// Inputs:
+/// - `results`: Slice of package items.
+/// - `size_of`: Lookup returning the installed size in bytes for a package name.
+///
+/// Output:
+/// - Returns vector of indices representing sorted order.
+///
+/// Details:
+/// - Used for `InstalledSize` sort mode; packages without a known size go last and ties fall
+///   back to case-insensitive name order.
+fn compute_installed_size_indices(
+    results: &[PackageItem],
+    size_of: impl Fn(&str) -> Option<u64>,
+) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..results.len()).collect();
+    indices.sort_by_cached_key(|&i| {
+        let p = &results[i];
+        (std::cmp::Reverse(size_of(&p.name)), p.name.to_lowercase())
+    });
+    indices
+}
+
 /// What: Compute sort order indices for repo-then-name sorting.
 ///
 /// Inputs:
@@ -116,12 +144,13 @@ fn compute_repo_then_name_indices(results: &[PackageItem]) -> Vec<usize> {
     #[cfg(test)]
     COMPUTE_REPO_INDICES_CALLS.fetch_add(1, Ordering::Relaxed);
 
+    let priority = crate::theme::settings().repo_priority;
     let mut indices: Vec<usize> = (0..results.len()).collect();
     indices.sort_by(|&i, &j| {
         let a = &results[i];
         let b = &results[j];
-        let oa = crate::util::repo_order(&a.source);
-        let ob = crate::util::repo_order(&b.source);
+        let oa = crate::util::repo_order_with(&a.source, &priority);
+        let ob = crate::util::repo_order_with(&b.source, &priority);
         if oa != ob {
             return oa.cmp(&ob);
         }
@@ -144,6 +173,7 @@ fn compute_aur_popularity_then_official_indices(results: &[PackageItem]) -> Vec<
     #[cfg(test)]
     COMPUTE_AUR_INDICES_CALLS.fetch_add(1, Ordering::Relaxed);
 
+    let priority = crate::theme::settings().repo_priority;
     let mut indices: Vec<usize> = (0..results.len()).collect();
     indices.sort_by(|&i, &j| {
         let a = &results[i];
@@ -163,8 +193,8 @@ fn compute_aur_popularity_then_official_indices(results: &[PackageItem]) -> Vec<
             }
         } else {
             // Both official: keep pacman order (repo_order), then name
-            let oa = crate::util::repo_order(&a.source);
-            let ob = crate::util::repo_order(&b.source);
+            let oa = crate::util::repo_order_with(&a.source, &priority);
+            let ob = crate::util::repo_order_with(&b.source, &priority);
             if oa != ob {
                 return oa.cmp(&ob);
             }
@@ -183,10 +213,55 @@ fn compute_aur_popularity_then_official_indices(results: &[PackageItem]) -> Vec<
 /// - Sorts `app.results` and preserves selection by name when possible; otherwise clamps index.
 ///
 /// Details:
-/// - Uses cache-based O(n) reordering when switching between cacheable modes (`RepoThenName` and `AurPopularityThenOfficial`).
+/// - Uses cache-based O(n) reordering when switching between cacheable modes (`RepoThenName`, `AurPopularityThenOfficial`, and `InstalledSize`).
 /// - Performs full O(n log n) sort when cache is invalid or for `BestMatches` mode.
 /// - Populates both cache orders eagerly after full sort to enable instant mode switching.
+/// - The `InstalledSize` cache is additionally keyed by the installed-size cache generation,
+///   since sizes arrive asynchronously; it is only re-anchored once that mode has been used.
 pub fn sort_results_preserve_selection(app: &mut AppState) {
+    sort_results_with_sizes(
+        app,
+        crate::index::installed_size,
+        crate::index::installed_sizes_generation(),
+    );
+}
+
+/// What: Re-anchor the `InstalledSize` cache to the current results order.
+///
+/// Inputs:
+/// - `app`: Mutable application state.
+/// - `size_of`: Lookup returning the installed size in bytes for a package name.
+/// - `generation`: Installed-size cache generation the sizes belong to.
+///
+/// Output:
+/// - Recomputes the cached order when one exists; leaves an empty cache untouched.
+fn reanchor_installed_size_cache(
+    app: &mut AppState,
+    size_of: impl Fn(&str) -> Option<u64>,
+    generation: u64,
+) {
+    if app.sort_cache_installed_size.is_some() {
+        app.sort_cache_installed_size = Some((
+            generation,
+            compute_installed_size_indices(&app.results, size_of),
+        ));
+    }
+}
+
+/// What: Sort `app.results` for the current mode with an injectable installed-size source.
+///
+/// Inputs:
+/// - `app`: Mutable application state.
+/// - `size_of`: Lookup returning the installed size in bytes for a package name.
+/// - `sizes_generation`: Generation of the sizes returned by `size_of`.
+///
+/// Output:
+/// - Same as [`sort_results_preserve_selection`].
+fn sort_results_with_sizes(
+    app: &mut AppState,
+    size_of: impl Fn(&str) -> Option<u64>,
+    sizes_generation: u64,
+) {
     if app.results.is_empty() {
         return;
     }
@@ -218,6 +293,7 @@ pub fn sort_results_preserve_selection(app: &mut AppState) {
             app.sort_cache_repo_name = Some((0..app.results.len()).collect());
             app.sort_cache_aur_popularity =
                 Some(compute_aur_popularity_then_official_indices(&app.results));
+            reanchor_installed_size_cache(app, &size_of, sizes_generation);
             app.sort_cache_signature = Some(current_sig);
         }
         SortMode::AurPopularityThenOfficial => {
@@ -238,16 +314,44 @@ pub fn sort_results_preserve_selection(app: &mut AppState) {
             // Re-anchor caches to current order to keep future switches correct.
             app.sort_cache_repo_name = Some(compute_repo_then_name_indices(&app.results));
             app.sort_cache_aur_popularity = Some((0..app.results.len()).collect());
+            reanchor_installed_size_cache(app, &size_of, sizes_generation);
             app.sort_cache_signature = Some(current_sig);
         }
         SortMode::BestMatches => {
             // BestMatches is query-dependent, always do full sort and don't cache
-            sort_best_matches(&mut app.results, &app.input);
+            sort_best_matches(
+                &mut app.results,
+                &crate::logic::parse_query_operators(&app.input).terms,
+            );
             // Clear mode-specific caches since BestMatches can't use them
             app.sort_cache_repo_name = None;
             app.sort_cache_aur_popularity = None;
+            app.sort_cache_installed_size = None;
             app.sort_cache_signature = None;
         }
+        SortMode::InstalledSize => {
+            // Sizes arrive asynchronously, so the cache must also match the size generation
+            let cached = app
+                .sort_cache_installed_size
+                .as_ref()
+                .filter(|(generation, _)| cache_valid && *generation == sizes_generation);
+            if let Some((_, indices)) = cached {
+                // Cache hit: O(n) reorder
+                let indices = indices.clone();
+                reorder_from_indices(&mut app.results, &indices);
+            } else {
+                // Cache invalid or sizes changed: compute indices from current state, then reorder
+                let indices = compute_installed_size_indices(&app.results, &size_of);
+                reorder_from_indices(&mut app.results, &indices);
+            }
+            // Re-anchor caches to current order to keep future switches correct.
+            app.sort_cache_repo_name = Some(compute_repo_then_name_indices(&app.results));
+            app.sort_cache_aur_popularity =
+                Some(compute_aur_popularity_then_official_indices(&app.results));
+            app.sort_cache_installed_size =
+                Some((sizes_generation, (0..app.results.len()).collect()));
+            app.sort_cache_signature = Some(current_sig);
+        }
     }
 
     // Restore selection by name
@@ -275,6 +379,7 @@ pub fn sort_results_preserve_selection(app: &mut AppState) {
 pub fn invalidate_sort_caches(app: &mut AppState) {
     app.sort_cache_repo_name = None;
     app.sort_cache_aur_popularity = None;
+    app.sort_cache_installed_size = None;
     app.sort_cache_signature = None;
 }
 
@@ -681,4 +786,95 @@ mod tests {
         let repo_order_again: Vec<String> = app.results.iter().map(|p| p.name.clone()).collect();
         assert_eq!(repo_order, repo_order_again);
     }
+
+    #[test]
+    /// What: Verify installed-size sorting orders largest-first with unknown sizes last.
+    ///
+    /// Inputs:
+    /// - Four packages with sizes 2 MiB, 300 MiB, unknown, and 2 MiB.
+    ///
+    /// Output:
+    /// - Order is 300 MiB, then the two 2 MiB packages by name, then the unknown one.
+    ///
+    /// Details:
+    /// - Uses an injected lookup so the global installed-size cache is not touched.
+    fn sort_installed_size_orders_largest_first() {
+        let results = vec![
+            item_official("zlib", "core"),
+            item_official("linux-firmware", "core"),
+            item_aur("mystery", None),
+            item_official("bash", "core"),
+        ];
+        let sizes: std::collections::HashMap<&str, u64> = [
+            ("zlib", 2 * 1024 * 1024),
+            ("linux-firmware", 300 * 1024 * 1024),
+            ("bash", 2 * 1024 * 1024),
+        ]
+        .into_iter()
+        .collect();
+        let indices = compute_installed_size_indices(&results, |name| sizes.get(name).copied());
+        let names: Vec<&str> = indices.iter().map(|&i| results[i].name.as_str()).collect();
+        assert_eq!(names, vec!["linux-firmware", "bash", "zlib", "mystery"]);
+    }
+
+    #[test]
+    /// What: Verify the `InstalledSize` sort mode orders app results and caches per size generation.
+    ///
+    /// Inputs:
+    /// - Results seeded with 10 KiB, 500 MiB, 10 KiB, and unknown sizes; the unknown entry is
+    ///   selected. Sorted twice with the same generation, then again after a new size arrives.
+    ///
+    /// Output:
+    /// - Largest first, equal sizes by name, unknown last; the selection follows the package.
+    /// - The repeat sort reuses the cache (no size lookups); a new generation re-sorts.
+    fn installed_size_sort_mode_orders_app_results() {
+        use std::cell::{Cell, RefCell};
+        let pkg = |name: &str| PackageItem::test_item(name, "extra");
+        let mut app = AppState {
+            results: vec![pkg("small-b"), pkg("unknown"), pkg("huge"), pkg("small-a")],
+            selected: 1,
+            sort_mode: SortMode::InstalledSize,
+            ..AppState::default()
+        };
+        let sizes: RefCell<std::collections::HashMap<&str, u64>> = RefCell::new(
+            [
+                ("small-b", 10 * 1024),
+                ("huge", 500 * 1024 * 1024),
+                ("small-a", 10 * 1024),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let lookups = Cell::new(0_usize);
+        let size_of = |name: &str| {
+            lookups.set(lookups.get() + 1);
+            sizes.borrow().get(name).copied()
+        };
+        let names = |app: &AppState| -> Vec<String> {
+            app.results.iter().map(|p| p.name.clone()).collect()
+        };
+
+        sort_results_with_sizes(&mut app, size_of, 1);
+        assert_eq!(names(&app), vec!["huge", "small-a", "small-b", "unknown"]);
+        assert_eq!(app.selected, 3);
+        assert!(app.sort_cache_signature.is_some());
+        assert_eq!(
+            app.sort_cache_installed_size.as_ref().map(|(g, _)| *g),
+            Some(1)
+        );
+
+        lookups.set(0);
+        sort_results_with_sizes(&mut app, size_of, 1);
+        assert_eq!(
+            lookups.get(),
+            0,
+            "same generation should reuse the cached order"
+        );
+        assert_eq!(names(&app), vec!["huge", "small-a", "small-b", "unknown"]);
+
+        sizes.borrow_mut().insert("unknown", 1024 * 1024 * 1024);
+        sort_results_with_sizes(&mut app, size_of, 2);
+        assert_eq!(names(&app), vec!["unknown", "huge", "small-a", "small-b"]);
+        assert_eq!(app.selected, 0);
+    }
 }
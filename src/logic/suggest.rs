@@ -0,0 +1,161 @@
+//! "Did you mean" suggestions for queries that return few or no results.
+
+use std::cmp::Reverse;
+
+use tokio::sync::mpsc;
+
+use crate::state::{AppState, QueryInput};
+
+/// Show suggestions only when the current query has at most this many results.
+pub const SUGGESTION_RESULT_THRESHOLD: usize = 3;
+/// Maximum number of suggestions shown below the results.
+pub const SUGGESTION_LIMIT: usize = 3;
+
+/// What: Rank package names that are plausible corrections for a (typo'd) query.
+///
+/// Inputs:
+/// - `query`: Search text as typed.
+/// - `names`: Candidate package names (e.g. the official index).
+/// - `limit`: Maximum number of suggestions to return.
+///
+/// Output:
+/// - Up to `limit` names ordered by edit distance, then fuzzy score, then name.
+/// - Empty when the query is blank or exactly matches a candidate (case-insensitive).
+///
+/// Details:
+/// - Candidates must be within an edit distance of a third of the query length (at least 1).
+/// - Ties are broken with the fzf-style fuzzy matcher used by fuzzy search.
+#[must_use]
+pub fn suggest_similar_names<'a, I>(query: &str, names: I, limit: usize) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let q = query.trim().to_lowercase();
+    if q.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+    let q_len = q.chars().count();
+    let max_dist = (q_len / 3).max(1);
+    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+    let mut scored: Vec<(usize, Reverse<i64>, &str)> = Vec::new();
+    for name in names {
+        let nl = name.to_lowercase();
+        if nl == q {
+            return Vec::new();
+        }
+        // Cheap length prefilter before the quadratic edit distance
+        if nl.chars().count().abs_diff(q_len) > max_dist {
+            continue;
+        }
+        let dist = crate::theme::levenshtein(&q, &nl);
+        if dist > max_dist {
+            continue;
+        }
+        let fuzzy = crate::util::fuzzy_match_rank_with_matcher(&nl, &q, &matcher).unwrap_or(0);
+        scored.push((dist, Reverse(fuzzy), name));
+    }
+    scored.sort_unstable();
+    scored.dedup_by(|a, b| a.2 == b.2);
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, _, name)| name.to_string())
+        .collect()
+}
+
+/// What: Refresh `app.search_suggestions` after new results arrived.
+///
+/// Inputs:
+/// - `app`: Application state with the current input and filtered results.
+///
+/// Output:
+/// - Sets or clears `app.search_suggestions`.
+///
+/// Details:
+/// - Only active when `search_suggestions` is enabled, outside installed-only mode, and when the
+///   query has at most `SUGGESTION_RESULT_THRESHOLD` results.
+pub fn update_search_suggestions(app: &mut AppState) {
+    let parsed = crate::logic::parse_query_operators(&app.input);
+    let query = parsed.terms.as_str();
+    app.search_suggestions = if crate::theme::settings().search_suggestions
+        && !app.installed_only_mode
+        && !query.is_empty()
+        && app.results.len() <= SUGGESTION_RESULT_THRESHOLD
+    {
+        crate::index::official_name_suggestions(query, SUGGESTION_LIMIT)
+    } else {
+        Vec::new()
+    };
+}
+
+/// What: Replace the search input with a suggestion and re-run the query.
+///
+/// Inputs:
+/// - `app`: Mutable application state.
+/// - `index`: Position of the suggestion in `app.search_suggestions`.
+/// - `query_tx`: Channel to send the new query.
+///
+/// Output:
+/// - `true` when a suggestion was applied, `false` when `index` is out of range.
+///
+/// Details:
+/// - Moves the caret to the end and clears the selection like using a recent query.
+pub fn apply_search_suggestion(
+    app: &mut AppState,
+    index: usize,
+    query_tx: &mpsc::UnboundedSender<QueryInput>,
+) -> bool {
+    let Some(suggestion) = app.search_suggestions.get(index).cloned() else {
+        return false;
+    };
+    app.input = suggestion;
+    app.search_caret = app.input.chars().count();
+    app.search_select_anchor = None;
+    app.last_input_change = std::time::Instant::now();
+    app.last_saved_value = None;
+    app.search_suggestions.clear();
+    super::send_query(app, query_tx);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: [&str; 6] = ["ripgrep", "ripgrep-all", "grep", "firefox", "fd", "rsync"];
+
+    #[test]
+    /// What: Verify a typo'd query yields the plausible package first.
+    ///
+    /// Inputs:
+    /// - Typos `ripgerp` and `firefx` against a small candidate list.
+    ///
+    /// Output:
+    /// - `ripgrep` and `firefox` are the top suggestions; unrelated names are excluded.
+    ///
+    /// Details:
+    /// - The edit-distance budget scales with query length.
+    fn typo_query_suggests_close_names() {
+        let s = suggest_similar_names("ripgerp", NAMES, 3);
+        assert_eq!(s.first().map(String::as_str), Some("ripgrep"));
+        assert!(!s.iter().any(|n| n == "rsync" || n == "fd"));
+        assert_eq!(suggest_similar_names("Firefx", NAMES, 3), vec!["firefox"]);
+    }
+
+    #[test]
+    /// What: Ensure exact matches and blank queries produce no suggestions.
+    ///
+    /// Inputs:
+    /// - Exact (case-insensitive) package name, blank query, and a zero limit.
+    ///
+    /// Output:
+    /// - Empty suggestion lists.
+    ///
+    /// Details:
+    /// - An exact hit short-circuits even when other names fit the edit budget.
+    fn exact_or_blank_query_has_no_suggestions() {
+        assert!(suggest_similar_names("GREP", NAMES, 3).is_empty());
+        assert!(suggest_similar_names("   ", NAMES, 3).is_empty());
+        assert!(suggest_similar_names("ripgerp", NAMES, 0).is_empty());
+    }
+}
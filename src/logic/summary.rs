@@ -75,35 +75,11 @@ fn count_changed_files_and_services(names: &[String]) -> (usize, Vec<String>) {
 /// - Returns counts of `.pacnew` and `.pacsave` files found beneath `/etc`.
 ///
 /// Details:
-/// - Ignores very deep directory structures to avoid pathological traversal scenarios.
+/// - Shares the walker with the post-transaction `.pacnew` reminder (`logic::pacnew`).
 fn count_pac_conflicts_in_etc() -> (usize, usize) {
-    fn walk(dir: &std::path::Path, pacnew: &mut usize, pacsave: &mut usize) {
-        if let Ok(rd) = std::fs::read_dir(dir) {
-            for entry in rd.flatten() {
-                let p = entry.path();
-                if p.is_dir() {
-                    // Limit to reasonable depth to avoid cycles (symlinks ignored)
-                    if p.strip_prefix("/etc")
-                        .is_ok_and(|stripped| stripped.components().count() > 12)
-                    {
-                        continue;
-                    }
-                    walk(&p, pacnew, pacsave);
-                } else if let Some(name) = p.file_name().and_then(|s| s.to_str()) {
-                    if name.ends_with(".pacnew") {
-                        *pacnew += 1;
-                    }
-                    if name.ends_with(".pacsave") {
-                        *pacsave += 1;
-                    }
-                }
-            }
-        }
-    }
-    let mut pn = 0usize;
-    let mut ps = 0usize;
-    walk(std::path::Path::new("/etc"), &mut pn, &mut ps);
-    (pn, ps)
+    let files = super::pacnew::scan_pac_files(std::path::Path::new(super::pacnew::PAC_FILES_ROOT));
+    let pacnew = files.iter().filter(|p| p.ends_with(".pacnew")).count();
+    (pacnew, files.len() - pacnew)
 }
 
 /// What: Produce a best-effort summary of potential post-transaction tasks.
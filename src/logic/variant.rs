@@ -0,0 +1,195 @@
+//! Flip the selected result between its AUR and official repository variants.
+
+use tokio::sync::mpsc;
+
+use crate::state::{AppState, PackageItem, Source};
+
+/// What: Find the opposite-source variant of a package.
+///
+/// Inputs:
+/// - `app`: Application state holding the unfiltered result set
+/// - `item`: Package whose counterpart should be located
+///
+/// Output:
+/// - `Some(PackageItem)` for the AUR variant of an official package (or vice versa);
+///   `None` when only one variant is known.
+///
+/// Details:
+/// - Prefers rows already present in `app.all_results` so metadata from the current
+///   search (version, popularity) is kept.
+/// - For AUR items, falls back to the official index when the search did not return
+///   the repository row. AUR variants are only taken from the current result set
+///   because no AUR data is available offline.
+#[must_use]
+pub fn find_alternate_variant(app: &AppState, item: &PackageItem) -> Option<PackageItem> {
+    let want_aur = !matches!(item.source, Source::Aur);
+    let from_results = app
+        .all_results
+        .iter()
+        .find(|p| p.name == item.name && matches!(p.source, Source::Aur) == want_aur)
+        .cloned();
+    if from_results.is_some() || want_aur {
+        return from_results;
+    }
+    crate::index::find_package_by_name(&item.name).filter(|p| p.name == item.name)
+}
+
+/// What: Swap the selected result with its AUR/official counterpart in place.
+///
+/// Inputs:
+/// - `app`: Mutable application state (results, selection, details)
+/// - `details_tx`: Channel used to request details for the newly shown variant
+///
+/// Output:
+/// - `true` when the selected row was swapped; `false` when there is no selection or
+///   the package only exists in one source.
+///
+/// Details:
+/// - When the counterpart is also listed as another row, the two rows trade places so
+///   the list never shows the same variant twice.
+/// - Drops the name-keyed details cache entry so the details pane reloads for the new
+///   source instead of showing the previous variant's metadata.
+/// - Schedules a PKGBUILD reload when the viewer is open, mirroring selection moves.
+pub fn toggle_selected_variant(
+    app: &mut AppState,
+    details_tx: &mpsc::UnboundedSender<PackageItem>,
+) -> bool {
+    let Some(current) = app.results.get(app.selected).cloned() else {
+        return false;
+    };
+    let Some(alternate) = find_alternate_variant(app, &current) else {
+        return false;
+    };
+    let alternate_is_aur = matches!(alternate.source, Source::Aur);
+    if let Some(other) = app.results.iter().position(|p| {
+        p.name == alternate.name && matches!(p.source, Source::Aur) == alternate_is_aur
+    }) {
+        app.results.swap(app.selected, other);
+    } else if let Some(slot) = app.results.get_mut(app.selected) {
+        *slot = alternate.clone();
+    }
+
+    app.details_focus = Some(alternate.name.clone());
+    app.details_cache.remove(&alternate.name);
+    app.details.name.clone_from(&alternate.name);
+    app.details.version.clone_from(&alternate.version);
+    app.details.description.clear();
+    match &alternate.source {
+        Source::Official { repo, arch } => {
+            app.details.repository.clone_from(repo);
+            app.details.architecture.clone_from(arch);
+        }
+        Source::Aur => {
+            app.details.repository = "AUR".to_string();
+            app.details.architecture = "any".to_string();
+        }
+    }
+    if app.pkgb_visible {
+        app.pkgb_reload_requested_at = Some(std::time::Instant::now());
+        app.pkgb_reload_requested_for = Some(alternate.name.clone());
+        app.pkgb_text = None;
+        app.pkgb_package_name = None;
+    }
+    let _ = details_tx.send(alternate);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// What: Verify the toggle swaps an official row for its AUR variant and requests details.
+    ///
+    /// Inputs:
+    /// - `all_results` seeded with both variants of `foo`; only the official row displayed.
+    ///
+    /// Output:
+    /// - Selected row becomes the AUR variant, details request targets the AUR item,
+    ///   and toggling again restores the official row.
+    ///
+    /// Details:
+    /// - Also checks the stale name-keyed details cache entry is dropped.
+    fn toggle_selected_variant_swaps_source_and_refreshes_details() {
+        let mut app = AppState {
+            all_results: vec![
+                PackageItem::test_item("foo", "extra"),
+                PackageItem::test_item("foo", "aur"),
+            ],
+            results: vec![PackageItem::test_item("foo", "extra")],
+            selected: 0,
+            ..AppState::default()
+        };
+        app.details_cache.insert(
+            "foo".to_string(),
+            crate::state::PackageDetails {
+                name: "foo".to_string(),
+                ..Default::default()
+            },
+        );
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        assert!(toggle_selected_variant(&mut app, &tx));
+        assert!(matches!(app.results[0].source, Source::Aur));
+        assert_eq!(app.results.len(), 1);
+        assert!(!app.details_cache.contains_key("foo"));
+        assert_eq!(app.details.repository, "AUR");
+        let sent = rx.try_recv().expect("details request sent");
+        assert!(matches!(sent.source, Source::Aur));
+
+        assert!(toggle_selected_variant(&mut app, &tx));
+        assert!(matches!(app.results[0].source, Source::Official { .. }));
+        let sent = rx.try_recv().expect("second details request sent");
+        assert!(matches!(sent.source, Source::Official { .. }));
+    }
+
+    #[test]
+    /// What: Verify both variants listed as rows trade places instead of duplicating.
+    ///
+    /// Inputs:
+    /// - Results listing the official and AUR variant of `foo`, official selected.
+    ///
+    /// Output:
+    /// - Selected index now holds the AUR row and the other index the official row.
+    ///
+    /// Details:
+    /// - Keeps the visible set unchanged while flipping the selected row in place.
+    fn toggle_selected_variant_swaps_rows_when_both_listed() {
+        let mut app = AppState::default();
+        app.all_results = vec![
+            PackageItem::test_item("foo", "extra"),
+            PackageItem::test_item("foo", "aur"),
+        ];
+        app.results = app.all_results.clone();
+        app.selected = 0;
+        let (tx, _rx) = mpsc::unbounded_channel();
+
+        assert!(toggle_selected_variant(&mut app, &tx));
+        assert!(matches!(app.results[0].source, Source::Aur));
+        assert!(matches!(app.results[1].source, Source::Official { .. }));
+    }
+
+    #[test]
+    /// What: Verify the toggle is a no-op when only one variant exists.
+    ///
+    /// Inputs:
+    /// - A single AUR-only package not present in the official index.
+    ///
+    /// Output:
+    /// - Returns `false`, leaves the row untouched, and sends no details request.
+    ///
+    /// Details:
+    /// - Uses a name that cannot collide with a real official package.
+    fn toggle_selected_variant_noop_for_single_variant() {
+        let mut app = AppState::default();
+        let only = PackageItem::test_item("pacsea-test-aur-only-variant", "aur");
+        app.all_results = vec![only.clone()];
+        app.results = vec![only];
+        app.selected = 0;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        assert!(!toggle_selected_variant(&mut app, &tx));
+        assert!(matches!(app.results[0].source, Source::Aur));
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Startup visibility of the PKGBUILD and AUR comments viewers.
+//!
+//! The `pkgb_visible` and `comments_visible` settings queue the viewers on startup; they
+//! open once the first selection's details have loaded and the details pane is tall enough.
+//! With `remember_viewers` enabled, toggling a viewer writes its state back to
+//! `settings.conf` so the next session starts the same way.
+
+use tokio::sync::mpsc;
+
+use crate::state::{AppState, Source};
+
+/// Minimum inner height (rows) of the Package Info pane for viewers to open automatically.
+pub const MIN_STARTUP_VIEWER_HEIGHT: u16 = 8;
+
+/// What: Queue the viewers configured to be visible on startup.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `prefs`: Loaded settings.
+///
+/// Output:
+/// - Sets `startup_pkgb_pending` / `startup_comments_pending`.
+///
+/// Details:
+/// - Called once during initialization, not on config reload, so reloading settings does not
+///   reopen viewers the user closed.
+pub const fn queue_startup_viewers(app: &mut AppState, prefs: &crate::theme::Settings) {
+    app.startup_pkgb_pending = prefs.pkgb_visible;
+    app.startup_comments_pending = prefs.comments_visible;
+}
+
+/// What: Open queued startup viewers once the selected package's details are loaded.
+///
+/// Inputs:
+/// - `app`: Application state.
+/// - `comments_tx`: Channel to request AUR comments.
+///
+/// Output:
+/// - `true` when at least one viewer was opened.
+///
+/// Details:
+/// - Waits until the selection has cached details and the details pane has been rendered.
+/// - The PKGBUILD viewer is loaded through the debounced reload in the tick handler.
+/// - The comments viewer only applies to AUR packages and stays queued until one is selected.
+/// - When the Package Info pane is shorter than [`MIN_STARTUP_VIEWER_HEIGHT`], the queue is
+///   dropped instead of squeezing the viewers into a tiny pane.
+pub fn apply_startup_viewers(
+    app: &mut AppState,
+    comments_tx: &mpsc::UnboundedSender<String>,
+) -> bool {
+    if !app.startup_pkgb_pending && !app.startup_comments_pending {
+        return false;
+    }
+    let Some(item) = app.results.get(app.selected).cloned() else {
+        return false;
+    };
+    if !app.details_cache.contains_key(&item.name) {
+        return false;
+    }
+    let Some((_, _, _, height)) = app.details_rect else {
+        return false;
+    };
+    if height < MIN_STARTUP_VIEWER_HEIGHT {
+        tracing::debug!(
+            height,
+            min = MIN_STARTUP_VIEWER_HEIGHT,
+            "Details pane too short; not opening startup viewers"
+        );
+        app.startup_pkgb_pending = false;
+        app.startup_comments_pending = false;
+        return false;
+    }
+
+    let mut opened = false;
+    if app.startup_pkgb_pending {
+        app.startup_pkgb_pending = false;
+        if !app.pkgb_visible {
+            app.pkgb_visible = true;
+            app.pkgb_text = None;
+            app.pkgb_package_name = None;
+            app.pkgb_reload_requested_at = Some(std::time::Instant::now());
+            app.pkgb_reload_requested_for = Some(item.name.clone());
+            opened = true;
+        }
+    }
+    if app.startup_comments_pending && matches!(item.source, Source::Aur) {
+        app.startup_comments_pending = false;
+        if !app.comments_visible {
+            app.comments_visible = true;
+            app.comments.clear();
+            app.comments_hidden.clear();
+            app.comments_package_name = None;
+            app.comments_fetched_at = None;
+            app.comments_scroll = 0;
+            app.comments_loading = true;
+            app.comments_error = None;
+            let _ = comments_tx.send(item.name);
+            opened = true;
+        }
+    }
+    opened
+}
+
+/// What: Record a manual viewer toggle.
+///
+/// Inputs:
+/// - `app`: Application state after a viewer was toggled.
+///
+/// Output:
+/// - Clears queued startup viewers and, when `remember_viewers` is enabled, writes
+///   `pkgb_visible` and `comments_visible` to `settings.conf`.
+///
+/// Details:
+/// - A manual toggle wins over the startup defaults, so the queue never reopens a viewer the
+///   user just closed.
+pub fn note_viewer_toggled(app: &mut AppState) {
+    app.startup_pkgb_pending = false;
+    app.startup_comments_pending = false;
+    if crate::theme::settings().remember_viewers {
+        crate::theme::save_pkgb_visible(app.pkgb_visible);
+        crate::theme::save_comments_visible(app.comments_visible);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PackageDetails, PackageItem};
+
+    /// What: Build an app whose selected AUR package has loaded details.
+    ///
+    /// Inputs:
+    /// - `details_height`: Inner height of the rendered Package Info pane.
+    ///
+    /// Output:
+    /// - `AppState` with both startup viewers queued.
+    fn app_with_loaded_aur_selection(details_height: u16) -> AppState {
+        let item = PackageItem::test_item("yay-bin", "aur");
+        let mut app = AppState {
+            results: vec![item.clone()],
+            details_rect: Some((0, 0, 80, details_height)),
+            startup_pkgb_pending: true,
+            startup_comments_pending: true,
+            ..Default::default()
+        };
+        app.details_cache.insert(
+            item.name.clone(),
+            PackageDetails {
+                name: item.name,
+                ..Default::default()
+            },
+        );
+        app
+    }
+
+    #[test]
+    /// What: Verify queued viewers open once an AUR selection's details are loaded.
+    ///
+    /// Inputs:
+    /// - Both viewers queued; first without cached details, then with them.
+    ///
+    /// Output:
+    /// - Nothing opens before details load; afterwards both viewers are visible, the PKGBUILD
+    ///   reload is scheduled, and comments are requested.
+    fn startup_viewers_open_for_loaded_aur_selection() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut app = app_with_loaded_aur_selection(20);
+        let cached = app.details_cache.clone();
+        app.details_cache.clear();
+        assert!(!apply_startup_viewers(&mut app, &tx));
+        assert!(!app.pkgb_visible);
+
+        app.details_cache = cached;
+        assert!(apply_startup_viewers(&mut app, &tx));
+        assert!(app.pkgb_visible);
+        assert!(app.comments_visible);
+        assert_eq!(app.pkgb_reload_requested_for.as_deref(), Some("yay-bin"));
+        assert_eq!(rx.try_recv().ok().as_deref(), Some("yay-bin"));
+        assert!(!app.startup_pkgb_pending && !app.startup_comments_pending);
+    }
+
+    #[test]
+    /// What: Verify the comments viewer waits for an AUR selection.
+    ///
+    /// Inputs:
+    /// - Both viewers queued with an official package selected.
+    ///
+    /// Output:
+    /// - PKGBUILD opens; comments stay queued and closed.
+    fn startup_comments_wait_for_aur_selection() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut app = app_with_loaded_aur_selection(20);
+        app.results[0].source = Source::Official {
+            repo: "extra".into(),
+            arch: "x86_64".into(),
+        };
+        assert!(apply_startup_viewers(&mut app, &tx));
+        assert!(app.pkgb_visible);
+        assert!(!app.comments_visible);
+        assert!(app.startup_comments_pending);
+    }
+
+    #[test]
+    /// What: Verify a short details pane keeps the viewers closed.
+    ///
+    /// Inputs:
+    /// - Both viewers queued with a Package Info pane below the minimum height.
+    ///
+    /// Output:
+    /// - No viewer opens and the queue is cleared.
+    fn startup_viewers_respect_min_height() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut app = app_with_loaded_aur_selection(MIN_STARTUP_VIEWER_HEIGHT - 1);
+        assert!(!apply_startup_viewers(&mut app, &tx));
+        assert!(!app.pkgb_visible);
+        assert!(!app.comments_visible);
+        assert!(rx.try_recv().is_err());
+        assert!(!app.startup_pkgb_pending && !app.startup_comments_pending);
+    }
+}
@@ -5,6 +5,9 @@ use tracing::{info, warn};
 /// Result type alias for advisory fetching operations.
 type Result<T> = super::Result<T>;
 
+/// Built-in security advisory Atom feed URL (overridable with `news_advisory_url`).
+pub const SECURITY_ADVISORY_FEED_URL: &str = "https://security.archlinux.org/advisory/feed.atom";
+
 /// What: Fetch security advisories from security.archlinux.org and convert to feed items.
 ///
 /// Inputs:
@@ -26,8 +29,30 @@ pub async fn fetch_security_advisories(
     limit: usize,
     cutoff_date: Option<&str>,
 ) -> Result<Vec<NewsFeedItem>> {
-    // Official advisory Atom feed
-    let url = "https://security.archlinux.org/advisory/feed.atom";
+    fetch_security_advisories_from(SECURITY_ADVISORY_FEED_URL, limit, cutoff_date).await
+}
+
+/// What: Fetch security advisories from a specific Atom feed URL.
+///
+/// Inputs:
+/// - `url`: Advisory feed URL (the built-in [`SECURITY_ADVISORY_FEED_URL`] or a configured override).
+/// - `limit`: Maximum number of advisories to return (best-effort).
+/// - `cutoff_date`: Optional date string (YYYY-MM-DD) for early filtering.
+///
+/// Output:
+/// - `Ok(Vec<NewsFeedItem>)` on success; `Err` on network/parse failure.
+///
+/// Details:
+/// - Shared implementation behind [`fetch_security_advisories`].
+///
+/// # Errors
+/// - Network fetch failures
+/// - JSON parsing failures
+pub async fn fetch_security_advisories_from(
+    url: &str,
+    limit: usize,
+    cutoff_date: Option<&str>,
+) -> Result<Vec<NewsFeedItem>> {
     let resp = reqwest::get(url).await?;
     let status = resp.status();
     let body = resp.text().await?;
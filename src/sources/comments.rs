@@ -4,11 +4,14 @@ use scraper::{ElementRef, Html, Selector};
 use std::time::Duration;
 use tracing::debug;
 
-use crate::state::types::AurComment;
+use crate::state::types::{AurComment, AurCommentsPage};
 
 /// Result type alias for AUR comments fetching operations.
 type Result<T> = super::Result<T>;
 
+/// Number of (non-pinned) comments the AUR shows per page; one fetch retrieves one page.
+pub const COMMENTS_PAGE_SIZE: usize = 10;
+
 /// Context for extracting comment data from HTML elements.
 struct CommentExtractionContext<'a> {
     /// Parsed HTML document
@@ -25,13 +28,49 @@ struct CommentExtractionContext<'a> {
     latest_comments_pos: Option<usize>,
 }
 
-/// What: Fetch AUR package comments by scraping the AUR package page.
+/// What: Fetch the latest page of AUR package comments.
 ///
 /// Inputs:
 /// - `pkgname`: Package name to fetch comments for.
 ///
 /// Output:
-/// - `Ok(Vec<AurComment>)` with parsed comments sorted by date (latest first); `Err` on failure.
+/// - `Ok(Vec<AurComment>)` with pinned comments first, then the newest
+///   [`COMMENTS_PAGE_SIZE`] comments; `Err` on failure.
+///
+/// # Errors
+/// - Returns `Err` under the same conditions as [`fetch_aur_comments_page`].
+///
+/// Details:
+/// - Shorthand for the first page of [`fetch_aur_comments_page`].
+pub async fn fetch_aur_comments(pkgname: String) -> Result<Vec<AurComment>> {
+    Ok(fetch_aur_comments_page(pkgname, 0).await?.comments)
+}
+
+/// What: Next page cursor after a fetched comments page.
+///
+/// Inputs:
+/// - `offset`: Offset the fetched page started at.
+/// - `has_more`: Whether the page reported older comments.
+///
+/// Output:
+/// - `Some(offset + COMMENTS_PAGE_SIZE)` while more comments exist, `None` at the end.
+#[must_use]
+pub const fn next_comments_offset(offset: usize, has_more: bool) -> Option<usize> {
+    if has_more {
+        Some(offset + COMMENTS_PAGE_SIZE)
+    } else {
+        None
+    }
+}
+
+/// What: Fetch one page of AUR package comments by scraping the AUR package page.
+///
+/// Inputs:
+/// - `pkgname`: Package name to fetch comments for.
+/// - `offset`: Number of (non-pinned) comments to skip; a multiple of [`COMMENTS_PAGE_SIZE`].
+///
+/// Output:
+/// - `Ok(AurCommentsPage)` with parsed comments sorted by date (latest first); `Err` on failure.
 ///
 /// # Errors
 /// - Returns `Err` when network request fails
@@ -42,15 +81,18 @@ struct CommentExtractionContext<'a> {
 /// - Panics if selector parsing fails in fallback path (should not occur with valid selectors)
 ///
 /// Details:
-/// - Fetches HTML from `https://aur.archlinux.org/packages/<pkgname>`
+/// - Fetches HTML from `https://aur.archlinux.org/packages/<pkgname>?O=<offset>&PP=<page size>`
 /// - Uses `scraper` to parse HTML and extract comment elements
 /// - Parses dates to Unix timestamps for sorting
 /// - Sorts comments by date descending (latest first)
+/// - Pinned comments are dropped from pages after the first (the AUR repeats them)
+/// - A full page of regular comments means older comments may follow (`has_more`)
 /// - Only works for AUR packages
-pub async fn fetch_aur_comments(pkgname: String) -> Result<Vec<AurComment>> {
+pub async fn fetch_aur_comments_page(pkgname: String, offset: usize) -> Result<AurCommentsPage> {
     use reqwest::header::{ACCEPT, ACCEPT_LANGUAGE, HeaderMap, HeaderValue};
 
-    let url = format!("https://aur.archlinux.org/packages/{pkgname}");
+    let url =
+        format!("https://aur.archlinux.org/packages/{pkgname}?O={offset}&PP={COMMENTS_PAGE_SIZE}");
 
     // Create HTTP client with browser-like headers and reasonable timeout.
     // Increased from 500ms to 5s to handle archlinux.org's DDoS protection delays.
@@ -142,8 +184,16 @@ pub async fn fetch_aur_comments(pkgname: String) -> Result<Vec<AurComment>> {
         }
     }
 
+    if offset > 0 {
+        comments.retain(|c| !c.pinned);
+    }
+    let regular = comments.iter().filter(|c| !c.pinned).count();
     // Separate, sort, and combine comments
-    Ok(separate_and_sort_comments(comments))
+    Ok(AurCommentsPage {
+        offset,
+        comments: separate_and_sort_comments(comments),
+        has_more: regular >= COMMENTS_PAGE_SIZE,
+    })
 }
 
 /// What: Extract comment data from a header element.
@@ -314,6 +364,51 @@ fn separate_and_sort_comments(comments: Vec<AurComment>) -> Vec<AurComment> {
     pinned_comments
 }
 
+/// What: Order fetched comments and split them at the display cap.
+///
+/// Inputs:
+/// - `comments`: Comments as returned by [`fetch_aur_comments`] (pinned first, newest first)
+/// - `order`: `pinned_first` keeps the fetch order; `newest_first` sorts purely by date
+/// - `max_display`: Number of comments to show (`0` shows all)
+///
+/// Output:
+/// - Tuple of (`shown`, `hidden`) where `hidden` holds the comments beyond the cap in order
+///
+/// Details:
+/// - Unknown orders behave like `pinned_first`.
+#[must_use]
+pub fn arrange_comments(
+    mut comments: Vec<AurComment>,
+    order: &str,
+    max_display: usize,
+) -> (Vec<AurComment>, Vec<AurComment>) {
+    if order == "newest_first" {
+        sort_comments_by_date(&mut comments);
+    }
+    if max_display == 0 || comments.len() <= max_display {
+        return (comments, Vec::new());
+    }
+    let hidden = comments.split_off(max_display);
+    (comments, hidden)
+}
+
+/// What: Number of hidden comments revealed by one "load more" click.
+///
+/// Inputs:
+/// - `hidden`: Count of comments still hidden beyond the cap
+/// - `max_display`: Configured display cap (`0` reveals everything)
+///
+/// Output:
+/// - Size of the next batch, never more than `hidden`
+#[must_use]
+pub const fn load_more_batch(hidden: usize, max_display: usize) -> usize {
+    if max_display == 0 || max_display > hidden {
+        hidden
+    } else {
+        max_display
+    }
+}
+
 /// What: Sort comments by date descending (latest first).
 ///
 /// Inputs:
@@ -892,4 +987,84 @@ mod tests {
             "Same date/time should produce same timestamp regardless of format"
         );
     }
+
+    fn comment(id: &str, ts: i64, pinned: bool) -> AurComment {
+        AurComment {
+            id: Some(id.to_string()),
+            author: "user".to_string(),
+            date: String::new(),
+            date_timestamp: Some(ts),
+            date_url: None,
+            content: format!("comment {id}"),
+            pinned,
+        }
+    }
+
+    fn ids(comments: &[AurComment]) -> Vec<&str> {
+        comments.iter().filter_map(|c| c.id.as_deref()).collect()
+    }
+
+    /// What: Ensure the display cap splits comments into shown and hidden parts.
+    ///
+    /// Inputs:
+    /// - Five comments with caps of 2 and 0.
+    ///
+    /// Output:
+    /// - Cap 2 shows the first two and hides the remaining three in order; cap 0 shows all.
+    #[test]
+    fn arrange_comments_truncates_at_cap() {
+        let all: Vec<AurComment> = (0..5)
+            .map(|i| comment(&i.to_string(), 100 - i, false))
+            .collect();
+
+        let (shown, hidden) = arrange_comments(all.clone(), "pinned_first", 2);
+        assert_eq!(ids(&shown), ["0", "1"]);
+        assert_eq!(ids(&hidden), ["2", "3", "4"]);
+
+        let (shown, hidden) = arrange_comments(all, "pinned_first", 0);
+        assert_eq!(shown.len(), 5);
+        assert!(hidden.is_empty());
+        assert_eq!(load_more_batch(3, 2), 2);
+        assert_eq!(load_more_batch(1, 2), 1);
+        assert_eq!(load_more_batch(3, 0), 3);
+    }
+
+    #[test]
+    /// What: Verify the page cursor advances by one page while more comments exist.
+    ///
+    /// Inputs:
+    /// - First and second page offsets with and without `has_more`.
+    ///
+    /// Output:
+    /// - Offsets step by `COMMENTS_PAGE_SIZE`; the last page ends the cursor.
+    fn next_comments_offset_advances_by_page() {
+        assert_eq!(next_comments_offset(0, true), Some(COMMENTS_PAGE_SIZE));
+        assert_eq!(
+            next_comments_offset(COMMENTS_PAGE_SIZE, true),
+            Some(2 * COMMENTS_PAGE_SIZE)
+        );
+        assert_eq!(next_comments_offset(COMMENTS_PAGE_SIZE, false), None);
+    }
+
+    /// What: Ensure `newest_first` ignores pinning while `pinned_first` keeps the fetch order.
+    ///
+    /// Inputs:
+    /// - An old pinned comment followed by two newer regular comments (fetch order).
+    ///
+    /// Output:
+    /// - `pinned_first` leaves the pinned comment on top; `newest_first` sorts it last.
+    #[test]
+    fn arrange_comments_order_setting_reorders() {
+        let fetched = separate_and_sort_comments(vec![
+            comment("new", 300, false),
+            comment("pinned", 100, true),
+            comment("mid", 200, false),
+        ]);
+
+        let (pinned_first, _) = arrange_comments(fetched.clone(), "pinned_first", 0);
+        assert_eq!(ids(&pinned_first), ["pinned", "new", "mid"]);
+
+        let (newest_first, _) = arrange_comments(fetched, "newest_first", 0);
+        assert_eq!(ids(&newest_first), ["new", "mid", "pinned"]);
+    }
 }
@@ -378,7 +378,10 @@ pub async fn fetch_aur_details(item: PackageItem) -> Result<PackageDetails> {
         "https://aur.archlinux.org/rpc/v5/info?arg={}",
         crate::util::percent_encode(&item.name)
     );
-    let v = tokio::task::spawn_blocking(move || crate::util::curl::curl_json(&url)).await??;
+    let v = tokio::task::spawn_blocking(move || {
+        crate::util::curl::curl_json_for(crate::util::NetworkSource::AurRpc, &url)
+    })
+    .await??;
     let arr = v
         .get("results")
         .and_then(|x| x.as_array())
@@ -464,7 +467,7 @@ pub async fn fetch_official_details(
     }
 
     let arch_candidates: Vec<String> = if arch.trim().is_empty() {
-        vec!["x86_64".to_string(), "any".to_string()]
+        vec![crate::index::host_arch(), "any".to_string()]
     } else if arch.to_lowercase() == "any" {
         vec!["any".to_string()]
     } else {
@@ -148,12 +148,12 @@ pub(super) fn ts_to_date_string(ts: i64) -> Option<String> {
 /// - Vector of architectures to try, in order of preference.
 ///
 /// Details:
-/// - If arch is empty or `x86_64`, tries both `x86_64` and "any" (for arch-independent packages).
+/// - If arch is empty, tries the host architecture ([`crate::index::host_arch`]) and "any".
 /// - If arch is "any", only tries "any".
 /// - Otherwise tries the specific arch, then "any" as fallback.
 fn build_arch_candidates(arch: &str) -> Vec<String> {
-    if arch.is_empty() || arch.eq_ignore_ascii_case("x86_64") {
-        vec!["x86_64".to_string(), "any".to_string()]
+    if arch.is_empty() {
+        vec![crate::index::host_arch(), "any".to_string()]
     } else if arch.eq_ignore_ascii_case("any") {
         vec!["any".to_string()]
     } else {
@@ -0,0 +1,115 @@
+//! JSON Feed (<https://jsonfeed.org>) parsing for news sources that do not publish RSS.
+use serde::Deserialize;
+
+use crate::state::types::{NewsFeedItem, NewsFeedSource};
+
+use super::Result;
+use super::helpers::normalize_pkg_date;
+
+/// Version URL prefix shared by JSON Feed 1.0 and 1.1 documents.
+const JSON_FEED_VERSION_PREFIX: &str = "https://jsonfeed.org/version/1";
+
+/// Top-level JSON Feed document (only the fields Pacsea uses).
+#[derive(Deserialize)]
+struct JsonFeedDocument {
+    /// Spec version URL, e.g. `https://jsonfeed.org/version/1.1`.
+    #[serde(default)]
+    version: String,
+    /// Feed entries, newest first by convention.
+    #[serde(default)]
+    items: Vec<JsonFeedEntry>,
+}
+
+/// Single JSON Feed item.
+#[derive(Deserialize)]
+struct JsonFeedEntry {
+    /// Unique item identifier (required by the spec, but not always present).
+    id: Option<serde_json::Value>,
+    /// Item permalink.
+    url: Option<String>,
+    /// Item title.
+    title: Option<String>,
+    /// Plain-text body.
+    content_text: Option<String>,
+    /// Short plain-text summary.
+    summary: Option<String>,
+    /// RFC 3339 publication timestamp.
+    date_published: Option<String>,
+    /// RFC 3339 modification timestamp.
+    date_modified: Option<String>,
+}
+
+/// What: Trim an optional string and drop it when empty.
+///
+/// Inputs:
+/// - `s`: Optional raw field value.
+///
+/// Output:
+/// - `Some(trimmed)` for non-blank values, otherwise `None`.
+fn non_empty(s: Option<&str>) -> Option<String> {
+    s.map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// What: Parse a JSON Feed 1.x document into news feed items.
+///
+/// Inputs:
+/// - `body`: Raw JSON Feed document.
+/// - `limit`: Maximum number of items to return.
+///
+/// Output:
+/// - `Ok(Vec<NewsFeedItem>)` tagged as [`NewsFeedSource::ArchNews`]; `Err` when the body is not
+///   valid JSON or not a JSON Feed document.
+///
+/// # Errors
+/// - Invalid JSON or a `version` that is not a JSON Feed 1.x URL.
+///
+/// Details:
+/// - Maps `title`, `url`, `date_published` and `content_text` (falling back to `summary`).
+/// - Items without `date_published` use `date_modified`; items with neither keep an empty date.
+/// - Items with neither `id` nor `url` are skipped; `id` defaults to `url` and vice versa
+///   when the `id` looks like a link.
+pub fn parse_json_feed(body: &str, limit: usize) -> Result<Vec<NewsFeedItem>> {
+    let doc: JsonFeedDocument = serde_json::from_str(body)?;
+    if !doc.version.starts_with(JSON_FEED_VERSION_PREFIX) {
+        return Err(format!("not a JSON Feed document (version: {:?})", doc.version).into());
+    }
+    let items = doc
+        .items
+        .into_iter()
+        .filter_map(|entry| {
+            // The spec allows numeric ids; normalise them to strings.
+            let id = match entry.id {
+                Some(serde_json::Value::String(s)) => non_empty(Some(&s)),
+                Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+                _ => None,
+            };
+            let url = non_empty(entry.url.as_deref()).or_else(|| {
+                id.as_ref()
+                    .filter(|id| id.starts_with("https://") || id.starts_with("http://"))
+                    .cloned()
+            });
+            let id = id.or_else(|| url.clone())?;
+            let date = entry
+                .date_published
+                .as_deref()
+                .and_then(normalize_pkg_date)
+                .or_else(|| entry.date_modified.as_deref().and_then(normalize_pkg_date))
+                .unwrap_or_default();
+            Some(NewsFeedItem {
+                title: non_empty(entry.title.as_deref()).unwrap_or_else(|| id.clone()),
+                id,
+                date,
+                summary: non_empty(entry.content_text.as_deref())
+                    .or_else(|| non_empty(entry.summary.as_deref())),
+                url,
+                source: NewsFeedSource::ArchNews,
+                severity: None,
+                packages: Vec::new(),
+            })
+        })
+        .take(limit)
+        .collect();
+    Ok(items)
+}
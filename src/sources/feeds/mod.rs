@@ -1,6 +1,7 @@
 //! Aggregated news feed fetcher (Arch news + security advisories).
 mod cache;
 mod helpers;
+mod json_feed;
 mod news_fetch;
 mod rate_limit;
 mod updates;
@@ -9,7 +10,7 @@ use std::collections::{HashMap, HashSet};
 use std::hash::BuildHasher;
 use std::path::PathBuf;
 
-use crate::state::types::{NewsFeedItem, NewsSortMode, severity_rank};
+use crate::state::types::{NewsFeedItem, NewsFeedSource, NewsSortMode, severity_rank};
 use tracing::{info, warn};
 
 use helpers::load_update_versions;
@@ -136,6 +137,96 @@ where
     pub seen_aur_comments: &'a mut HashMap<String, String, HC>,
     /// Optional maximum age in days for early date filtering during fetch.
     pub max_age_days: Option<u32>,
+    /// Feed URLs for Arch news and security advisories.
+    pub source_urls: NewsSourceUrls,
+    /// Maximum number of slow sources (Arch news, advisories) fetched at the same time.
+    /// `1` fetches them one after another; `0` is treated as `1`.
+    pub max_parallel_slow_fetches: usize,
+}
+
+/// Feed URLs used for the Arch news and security advisory sources.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewsSourceUrls {
+    /// Arch news RSS feed URL.
+    pub arch_feed: String,
+    /// Security advisory Atom feed URL.
+    pub advisories: String,
+}
+
+impl Default for NewsSourceUrls {
+    /// What: Use the built-in archlinux.org feed URLs.
+    ///
+    /// Inputs: None.
+    ///
+    /// Output:
+    /// - [`crate::sources::ARCH_NEWS_FEED_URL`] and [`crate::sources::SECURITY_ADVISORY_FEED_URL`].
+    fn default() -> Self {
+        Self {
+            arch_feed: crate::sources::ARCH_NEWS_FEED_URL.to_string(),
+            advisories: crate::sources::SECURITY_ADVISORY_FEED_URL.to_string(),
+        }
+    }
+}
+
+impl NewsSourceUrls {
+    /// What: Resolve feed URLs from the `news_arch_feed_url` / `news_advisory_url` settings.
+    ///
+    /// Inputs:
+    /// - `settings`: Settings holding the optional overrides.
+    ///
+    /// Output:
+    /// - Configured URLs where valid, the built-in URLs otherwise.
+    ///
+    /// Details:
+    /// - Empty overrides silently use the default; invalid ones log a warning first.
+    #[must_use]
+    pub fn from_settings(settings: &crate::theme::Settings) -> Self {
+        let defaults = Self::default();
+        Self {
+            arch_feed: resolve_feed_url(
+                "news_arch_feed_url",
+                &settings.news_arch_feed_url,
+                defaults.arch_feed,
+            ),
+            advisories: resolve_feed_url(
+                "news_advisory_url",
+                &settings.news_advisory_url,
+                defaults.advisories,
+            ),
+        }
+    }
+}
+
+/// What: Validate a configured feed URL override.
+///
+/// Inputs:
+/// - `key`: Setting name (for the warning).
+/// - `value`: Configured override.
+/// - `default`: URL used when the override is empty or invalid.
+///
+/// Output:
+/// - The trimmed override when it is an `http://`, `https://`, or `file://` URL without
+///   whitespace; otherwise `default`.
+fn resolve_feed_url(key: &str, value: &str, default: String) -> String {
+    let value = value.trim();
+    if value.is_empty() {
+        return default;
+    }
+    let valid = ["http://", "https://", "file://"]
+        .iter()
+        .any(|scheme| value.len() > scheme.len() && value.starts_with(scheme))
+        && !value.contains(char::is_whitespace);
+    if valid {
+        value.to_string()
+    } else {
+        warn!(
+            key,
+            value,
+            default = %default,
+            "invalid news source URL; using default"
+        );
+        default
+    }
 }
 
 /// Configuration for fetching fast sources.
@@ -291,6 +382,7 @@ where
 ///
 /// Details:
 /// - Gracefully handles errors by logging warnings and continuing.
+/// - Collapses items sharing a URL via [`dedup_by_url`] before sorting.
 /// - Sorts items according to the specified sort mode.
 fn combine_feed_results(
     arch_result: std::result::Result<Vec<NewsFeedItem>, Box<dyn std::error::Error + Send + Sync>>,
@@ -310,12 +402,24 @@ fn combine_feed_results(
 ) -> Vec<NewsFeedItem> {
     let mut items: Vec<NewsFeedItem> = Vec::new();
     match arch_result {
-        Ok(mut arch_items) => items.append(&mut arch_items),
-        Err(e) => warn!(error = %e, "arch news fetch failed; continuing without Arch news"),
+        Ok(mut arch_items) => {
+            items.append(&mut arch_items);
+            rate_limit::record_fetch_outcome(crate::state::types::FetchCategory::News, true);
+        }
+        Err(e) => {
+            warn!(error = %e, "arch news fetch failed; continuing without Arch news");
+            rate_limit::record_fetch_outcome(crate::state::types::FetchCategory::News, false);
+        }
     }
     match advisories_result {
-        Ok(mut adv_items) => items.append(&mut adv_items),
-        Err(e) => warn!(error = %e, "advisories fetch failed; continuing without advisories"),
+        Ok(mut adv_items) => {
+            items.append(&mut adv_items);
+            rate_limit::record_fetch_outcome(crate::state::types::FetchCategory::Advisories, true);
+        }
+        Err(e) => {
+            warn!(error = %e, "advisories fetch failed; continuing without advisories");
+            rate_limit::record_fetch_outcome(crate::state::types::FetchCategory::Advisories, false);
+        }
     }
     match updates_result {
         Ok(mut upd_items) => items.append(&mut upd_items),
@@ -325,10 +429,77 @@ fn combine_feed_results(
         Ok(mut cmt_items) => items.append(&mut cmt_items),
         Err(e) => warn!(error = %e, "comments fetch failed; continuing without comments"),
     }
+    let mut items = dedup_by_url(items);
     sort_news_items(&mut items, sort_mode);
     items
 }
 
+/// What: Normalize a news item URL for duplicate detection.
+///
+/// Inputs:
+/// - `url`: Item link.
+///
+/// Output:
+/// - Trimmed, lowercased URL without trailing slashes, or `None` when blank.
+fn normalize_item_url(url: &str) -> Option<String> {
+    let normalized = url.trim().trim_end_matches('/').to_lowercase();
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+/// What: Rank how specific a news item is when several sources report the same URL.
+///
+/// Inputs:
+/// - `item`: Candidate item.
+///
+/// Output:
+/// - Tuple compared lexicographically; higher wins.
+///
+/// Details:
+/// - Security advisories beat Arch news, which beats update/comment entries; ties are broken
+///   by advisory severity and then by the number of affected packages.
+const fn item_specificity(item: &NewsFeedItem) -> (u8, u8, usize) {
+    let source = match item.source {
+        NewsFeedSource::SecurityAdvisory => 2,
+        NewsFeedSource::ArchNews => 1,
+        NewsFeedSource::InstalledPackageUpdate
+        | NewsFeedSource::AurPackageUpdate
+        | NewsFeedSource::AurComment => 0,
+    };
+    (source, severity_rank(item.severity), item.packages.len())
+}
+
+/// What: Collapse news items that point to the same URL.
+///
+/// Inputs:
+/// - `items`: Combined items from all sources.
+///
+/// Output:
+/// - Items with at most one entry per normalized URL, in first-seen order.
+///
+/// Details:
+/// - URLs are compared lowercased with trailing slashes stripped.
+/// - The most specific item wins (see [`item_specificity`]); on ties the first one is kept.
+/// - Items without a URL are never deduplicated against each other.
+fn dedup_by_url(items: Vec<NewsFeedItem>) -> Vec<NewsFeedItem> {
+    let mut out: Vec<NewsFeedItem> = Vec::with_capacity(items.len());
+    let mut by_url: HashMap<String, usize> = HashMap::new();
+    for item in items {
+        let Some(key) = item.url.as_deref().and_then(normalize_item_url) else {
+            out.push(item);
+            continue;
+        };
+        if let Some(&idx) = by_url.get(&key) {
+            if item_specificity(&item) > item_specificity(&out[idx]) {
+                out[idx] = item;
+            }
+        } else {
+            by_url.insert(key, out.len());
+            out.push(item);
+        }
+    }
+    out
+}
+
 /// Return type for `prepare_fetch_context` function.
 type PrepareFetchContextReturn<'a, HS, HV, HC> = (
     Option<String>,
@@ -344,6 +515,8 @@ type PrepareFetchContextReturn<'a, HS, HV, HC> = (
     &'a mut HashMap<String, String, HV>,
     &'a mut HashMap<String, String, HC>,
     bool,
+    NewsSourceUrls,
+    usize,
 );
 
 /// What: Prepare fetch context and calculate derived values.
@@ -378,6 +551,8 @@ where
         force_emit_all,
         updates_list_path,
         max_age_days,
+        source_urls,
+        max_parallel_slow_fetches,
     } = ctx;
 
     info!(
@@ -418,6 +593,8 @@ where
         seen_pkg_versions,
         seen_aur_comments,
         force_emit_all,
+        source_urls,
+        max_parallel_slow_fetches,
     )
 }
 
@@ -487,6 +664,8 @@ where
         seen_pkg_versions,
         seen_aur_comments,
         force_emit_all,
+        source_urls,
+        max_parallel_slow_fetches,
     ) = prepare_fetch_context(ctx);
 
     info!(
@@ -496,8 +675,9 @@ where
 
     // Fetch ALL sources in parallel for best responsiveness:
     // - Fast sources (AUR comments, package updates) run in parallel and complete quickly
-    // - Slow sources (arch news, advisories from archlinux.org) run sequentially with each other
-    //   but IN PARALLEL with the fast sources, so they don't block everything
+    // - Slow sources (arch news, advisories from archlinux.org) run at most
+    //   `max_parallel_slow_fetches` at a time (sequentially by default) but IN PARALLEL with the
+    //   fast sources, so they don't block everything
     let ((updates_result, comments_result), (arch_result, advisories_result)) = tokio::join!(
         fetch_fast_sources(FastSourcesConfig {
             include_pkg_updates,
@@ -510,6 +690,8 @@ where
             updates_versions: updates_versions.as_ref(),
         }),
         fetch_slow_sources(
+            &source_urls,
+            max_parallel_slow_fetches,
             include_arch_news,
             include_advisories,
             limit,
@@ -595,6 +777,9 @@ where
         "starting continuation fetch"
     );
 
+    let prefs = crate::theme::settings();
+    let source_urls = NewsSourceUrls::from_settings(&prefs);
+
     // Fetch from all sources in parallel
     let ((updates_result, comments_result), (arch_result, advisories_result)) = tokio::join!(
         async {
@@ -619,6 +804,8 @@ where
             (updates, comments)
         },
         fetch_slow_sources(
+            &source_urls,
+            prefs.news_max_parallel_slow_fetches,
             true, // include_arch_news
             true, // include_advisories
             CONTINUATION_LIMIT,
@@ -693,10 +880,12 @@ where
 }
 
 // Re-export public functions from submodules
+pub use json_feed::parse_json_feed;
 pub use rate_limit::{
-    check_circuit_breaker, extract_endpoint_pattern, extract_retry_after_from_error,
-    increase_archlinux_backoff, rate_limit_archlinux, record_circuit_breaker_outcome,
-    reset_archlinux_backoff, take_network_error,
+    CircuitBreakerStatus, archlinux_backoff_ms, check_circuit_breaker, circuit_breaker_snapshot,
+    extract_endpoint_pattern, extract_retry_after_from_error, increase_archlinux_backoff,
+    rate_limit_archlinux, record_circuit_breaker_outcome, record_fetch_outcome,
+    reset_archlinux_backoff, take_fetch_outcomes, take_network_error,
 };
 pub use updates::{
     get_aur_json_changes, get_official_json_changes, load_official_json_cache,
@@ -18,6 +18,7 @@ use super::rate_limit::{
 /// What: Fetch Arch news items with optional early date filtering and caching.
 ///
 /// Inputs:
+/// - `url`: Arch news RSS feed URL.
 /// - `limit`: Maximum items to fetch.
 /// - `cutoff_date`: Optional date string (YYYY-MM-DD) for early filtering.
 ///
@@ -29,6 +30,7 @@ use super::rate_limit::{
 /// - Falls back to disk cache (configurable TTL, default 14 days) if in-memory cache misses.
 /// - Saves fetched data to both in-memory and disk caches.
 pub(super) async fn append_arch_news(
+    url: &str,
     limit: usize,
     cutoff_date: Option<&str>,
 ) -> Result<Vec<NewsFeedItem>> {
@@ -70,7 +72,7 @@ pub(super) async fn append_arch_news(
         // Acquire semaphore permit and hold it during the request
         // This ensures only one archlinux.org request is in flight at a time
         let _permit = rate_limit_archlinux().await;
-        let result = crate::sources::fetch_arch_news(limit, cutoff_date).await;
+        let result = crate::sources::fetch_arch_news_from(url, limit, cutoff_date).await;
         // Check for HTTP 429/503 and update backoff for future requests
         if let Err(ref e) = result {
             let error_str = e.to_string();
@@ -173,6 +175,7 @@ pub(super) async fn append_arch_news(
 /// What: Fetch security advisories with optional early date filtering and caching.
 ///
 /// Inputs:
+/// - `url`: Security advisory feed URL.
 /// - `limit`: Maximum items to fetch.
 /// - `installed_filter`: Optional installed set for filtering.
 /// - `installed_only`: Whether to drop advisories unrelated to installed packages.
@@ -186,6 +189,7 @@ pub(super) async fn append_arch_news(
 /// - Falls back to disk cache (configurable TTL, default 14 days) if in-memory cache misses.
 /// - Note: Cache key includes `installed_only` flag to handle different filtering needs.
 pub(super) async fn append_advisories<S>(
+    url: &str,
     limit: usize,
     installed_filter: Option<&HashSet<String, S>>,
     installed_only: bool,
@@ -230,7 +234,7 @@ where
     let fetch_result = retry_with_backoff(
         || async {
             rate_limit().await;
-            crate::sources::fetch_security_advisories(limit, cutoff_date).await
+            crate::sources::fetch_security_advisories_from(url, limit, cutoff_date).await
         },
         2, // Max 2 retries (3 total attempts)
     )
@@ -291,9 +295,34 @@ where
     }
 }
 
-/// What: Fetch slow sources (Arch news and advisories) in parallel with timeout.
+/// What: Run a future once a permit from the slow-source semaphore is available.
 ///
 /// Inputs:
+/// - `semaphore`: Semaphore bounding concurrent slow fetches.
+/// - `fetch`: Fetch future to run.
+///
+/// Output:
+/// - Output of `fetch`.
+///
+/// Details:
+/// - The permit is held until `fetch` completes. The semaphore is never closed, so a failed
+///   acquire only means the fetch runs unthrottled.
+pub(super) async fn with_slow_fetch_permit<F>(
+    semaphore: &tokio::sync::Semaphore,
+    fetch: F,
+) -> F::Output
+where
+    F: std::future::Future,
+{
+    let _permit = semaphore.acquire().await.ok();
+    fetch.await
+}
+
+/// What: Fetch slow sources (Arch news and advisories) with bounded concurrency and timeout.
+///
+/// Inputs:
+/// - `urls`: Feed URLs for Arch news and advisories.
+/// - `max_parallel`: Maximum number of slow sources fetched at the same time (minimum 1).
 /// - `include_arch_news`: Whether to fetch Arch news.
 /// - `include_advisories`: Whether to fetch advisories.
 /// - `limit`: Maximum items per source.
@@ -305,9 +334,17 @@ where
 /// - Tuple of (`arch_result`, `advisories_result`).
 ///
 /// Details:
-/// - Applies 30-second timeout to match HTTP client timeout.
+/// - With `max_parallel = 1` Arch news is fetched before advisories (the semaphore is FIFO).
+/// - Applies 30-second timeout to match HTTP client timeout; waiting for a permit is not
+///   counted against it.
 /// - Returns empty vectors on timeout or errors (graceful degradation).
+// Eight inputs mirror the fetch options the two callers already hold as separate locals;
+// `max_parallel` tipped it over the lint threshold, and an options struct used only here
+// would just repackage them at each call site.
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn fetch_slow_sources<HS>(
+    urls: &super::NewsSourceUrls,
+    max_parallel: usize,
     include_arch_news: bool,
     include_advisories: bool,
     limit: usize,
@@ -321,59 +358,69 @@ pub(super) async fn fetch_slow_sources<HS>(
 where
     HS: BuildHasher + Send + Sync + 'static,
 {
-    let arch_result: std::result::Result<
-        Vec<NewsFeedItem>,
-        Box<dyn std::error::Error + Send + Sync>,
-    > = if include_arch_news {
-        info!("fetching arch news...");
-        tokio::time::timeout(
-            Duration::from_secs(30),
-            append_arch_news(limit, cutoff_date),
-        )
+    let semaphore = tokio::sync::Semaphore::new(max_parallel.max(1));
+
+    let arch_fetch = async {
+        if !include_arch_news {
+            return Ok(Vec::new());
+        }
+        with_slow_fetch_permit(&semaphore, async {
+            info!("fetching arch news...");
+            tokio::time::timeout(
+                Duration::from_secs(30),
+                append_arch_news(&urls.arch_feed, limit, cutoff_date),
+            )
+            .await
+            .map_or_else(
+                |_| {
+                    warn!("arch news fetch timed out after 30s, continuing without arch news");
+                    Err("Arch news fetch timeout".into())
+                },
+                |result| {
+                    info!(
+                        "arch news fetch completed: items={}",
+                        result.as_ref().map_or(0, Vec::len)
+                    );
+                    result
+                },
+            )
+        })
         .await
-        .map_or_else(
-            |_| {
-                warn!("arch news fetch timed out after 30s, continuing without arch news");
-                Err("Arch news fetch timeout".into())
-            },
-            |result| {
-                info!(
-                    "arch news fetch completed: items={}",
-                    result.as_ref().map_or(0, Vec::len)
-                );
-                result
-            },
-        )
-    } else {
-        Ok(Vec::new())
     };
 
-    let advisories_result: std::result::Result<
-        Vec<NewsFeedItem>,
-        Box<dyn std::error::Error + Send + Sync>,
-    > = if include_advisories {
-        info!("fetching advisories...");
-        tokio::time::timeout(
-            Duration::from_secs(30),
-            append_advisories(limit, installed_filter, installed_only, cutoff_date),
-        )
+    let advisories_fetch = async {
+        if !include_advisories {
+            return Ok(Vec::new());
+        }
+        with_slow_fetch_permit(&semaphore, async {
+            info!("fetching advisories...");
+            tokio::time::timeout(
+                Duration::from_secs(30),
+                append_advisories(
+                    &urls.advisories,
+                    limit,
+                    installed_filter,
+                    installed_only,
+                    cutoff_date,
+                ),
+            )
+            .await
+            .map_or_else(
+                |_| {
+                    warn!("advisories fetch timed out after 30s, continuing without advisories");
+                    Err("Advisories fetch timeout".into())
+                },
+                |result| {
+                    info!(
+                        "advisories fetch completed: items={}",
+                        result.as_ref().map_or(0, Vec::len)
+                    );
+                    result
+                },
+            )
+        })
         .await
-        .map_or_else(
-            |_| {
-                warn!("advisories fetch timed out after 30s, continuing without advisories");
-                Err("Advisories fetch timeout".into())
-            },
-            |result| {
-                info!(
-                    "advisories fetch completed: items={}",
-                    result.as_ref().map_or(0, Vec::len)
-                );
-                result
-            },
-        )
-    } else {
-        Ok(Vec::new())
     };
 
-    (arch_result, advisories_result)
+    tokio::join!(arch_fetch, advisories_fetch)
 }
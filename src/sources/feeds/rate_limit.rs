@@ -118,6 +118,42 @@ pub(super) fn set_network_error() {
     NETWORK_ERROR_FLAG.store(true, std::sync::atomic::Ordering::SeqCst);
 }
 
+/// Fetch outcomes recorded by background workers since the UI last drained them.
+static FETCH_OUTCOMES: LazyLock<Mutex<Vec<(crate::state::types::FetchCategory, bool)>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// What: Record whether a background fetch category succeeded or failed.
+///
+/// Inputs:
+/// - `category`: Fetch category that just completed
+/// - `success`: `false` when the attempt failed
+///
+/// Output: None
+///
+/// Details:
+/// - The UI drains outcomes in order via [`take_fetch_outcomes`] to track retryable failures.
+pub fn record_fetch_outcome(category: crate::state::types::FetchCategory, success: bool) {
+    let mut outcomes = match FETCH_OUTCOMES.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    outcomes.push((category, success));
+}
+
+/// What: Drain fetch outcomes recorded since the last call.
+///
+/// Inputs: None
+///
+/// Output: `(category, success)` pairs in the order they were recorded.
+#[must_use]
+pub fn take_fetch_outcomes() -> Vec<(crate::state::types::FetchCategory, bool)> {
+    let mut outcomes = match FETCH_OUTCOMES.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    std::mem::take(&mut *outcomes)
+}
+
 /// What: Retry a network operation with exponential backoff on failure.
 ///
 /// Inputs:
@@ -446,6 +482,90 @@ pub fn record_circuit_breaker_outcome(endpoint_pattern: &str, success: bool) {
     }
 }
 
+/// Read-only view of the archlinux.org backoff and circuit breaker state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CircuitBreakerStatus {
+    /// `true` while at least one endpoint circuit is open and still cooling down.
+    pub open: bool,
+    /// Endpoint patterns whose circuit is open, sorted.
+    pub open_endpoints: Vec<String>,
+    /// Endpoint patterns allowing a single test request after cooldown, sorted.
+    pub half_open_endpoints: Vec<String>,
+    /// Consecutive archlinux.org failures since the last successful request.
+    pub consecutive_failures: u32,
+    /// Current archlinux.org backoff delay in milliseconds.
+    pub backoff_ms: u64,
+    /// Time until the next archlinux.org request is allowed (backoff and open circuits).
+    pub retry_in: Duration,
+}
+
+/// What: Take a snapshot of the archlinux.org backoff and circuit breaker state.
+///
+/// Inputs: None
+///
+/// Output:
+/// - `CircuitBreakerStatus` describing open circuits, failures, and the remaining wait.
+///
+/// Details:
+/// - Each lock is held only long enough to copy the state out; nothing is modified, so an
+///   open circuit whose cooldown expired is reported half-open without transitioning it.
+/// - `retry_in` is the larger of the remaining backoff delay and the longest open cooldown.
+#[must_use]
+pub fn circuit_breaker_snapshot() -> CircuitBreakerStatus {
+    let (consecutive_failures, backoff_ms, since_last_request) = {
+        let limiter = match ARCHLINUX_RATE_LIMITER.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        (
+            limiter.consecutive_failures,
+            limiter.current_backoff_ms,
+            limiter.last_request.elapsed(),
+        )
+    };
+    let states: Vec<(String, CircuitState)> = {
+        let breakers = match CIRCUIT_BREAKERS.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        breakers
+            .iter()
+            .map(|(pattern, breaker)| (pattern.clone(), breaker.state.clone()))
+            .collect()
+    };
+
+    let cooldown = Duration::from_secs(CIRCUIT_BREAKER_COOLDOWN_SECS);
+    let mut retry_in = Duration::from_millis(backoff_ms).saturating_sub(since_last_request);
+    let mut open_endpoints = Vec::new();
+    let mut half_open_endpoints = Vec::new();
+    for (pattern, state) in states {
+        match state {
+            CircuitState::Open { opened_at } => {
+                let remaining = cooldown.saturating_sub(opened_at.elapsed());
+                if remaining.is_zero() {
+                    half_open_endpoints.push(pattern);
+                } else {
+                    retry_in = retry_in.max(remaining);
+                    open_endpoints.push(pattern);
+                }
+            }
+            CircuitState::HalfOpen => half_open_endpoints.push(pattern),
+            CircuitState::Closed => {}
+        }
+    }
+    open_endpoints.sort();
+    half_open_endpoints.sort();
+
+    CircuitBreakerStatus {
+        open: !open_endpoints.is_empty(),
+        open_endpoints,
+        half_open_endpoints,
+        consecutive_failures,
+        backoff_ms,
+        retry_in,
+    }
+}
+
 /// What: Extract Retry-After value from error message string.
 ///
 /// Inputs:
@@ -509,6 +629,19 @@ pub fn increase_archlinux_backoff(retry_after_seconds: Option<u64>) {
     }
 }
 
+/// What: Current archlinux.org backoff delay in milliseconds.
+///
+/// Inputs: None
+///
+/// Output: Delay applied before the next archlinux.org request.
+#[must_use]
+pub fn archlinux_backoff_ms() -> u64 {
+    match ARCHLINUX_RATE_LIMITER.lock() {
+        Ok(guard) => guard.current_backoff_ms,
+        Err(poisoned) => poisoned.into_inner().current_backoff_ms,
+    }
+}
+
 /// What: Reset backoff delay for archlinux.org after a successful request.
 ///
 /// Inputs: None
@@ -476,6 +476,8 @@ fn test_news_feed_context_toggles() {
         seen_pkg_versions: &mut seen_versions,
         seen_aur_comments: &mut seen_comments,
         max_age_days: None,
+        source_urls: NewsSourceUrls::default(),
+        max_parallel_slow_fetches: 1,
     };
 
     assert!(ctx.include_arch_news);
@@ -484,6 +486,101 @@ fn test_news_feed_context_toggles() {
     assert!(!ctx.include_aur_comments);
 }
 
+#[test]
+/// What: Ensure news source URL overrides are validated with fallback to the built-in URLs.
+///
+/// Inputs:
+/// - Settings with a valid mirror feed URL and an invalid advisory URL, then empty overrides.
+///
+/// Output:
+/// - The valid override is kept; invalid and empty values resolve to the defaults.
+fn news_source_urls_fall_back_for_invalid_overrides() {
+    let settings = crate::theme::Settings {
+        news_arch_feed_url: " https://mirror.example.org/feeds/news/ ".into(),
+        news_advisory_url: "not a url".into(),
+        ..crate::theme::Settings::default()
+    };
+    let urls = NewsSourceUrls::from_settings(&settings);
+    assert_eq!(urls.arch_feed, "https://mirror.example.org/feeds/news/");
+    assert_eq!(urls.advisories, crate::sources::SECURITY_ADVISORY_FEED_URL);
+
+    let defaults = NewsSourceUrls::from_settings(&crate::theme::Settings::default());
+    assert_eq!(defaults, NewsSourceUrls::default());
+    assert_eq!(defaults.arch_feed, crate::sources::ARCH_NEWS_FEED_URL);
+}
+
+#[tokio::test]
+/// What: Ensure the Arch news fetch uses the feed URL carried by `NewsFeedContext`.
+///
+/// Inputs:
+/// - A context whose `source_urls.arch_feed` points at a local `file://` RSS feed.
+///
+/// Output:
+/// - `append_arch_news` returns the items of the local feed.
+///
+/// Details:
+/// - A `cutoff_date` bypasses the in-memory and disk caches so the URL is actually fetched.
+/// - Skipped when `curl` is not installed.
+async fn arch_news_fetch_uses_context_feed_url() {
+    if !crate::util::command::binary_available("curl") {
+        return;
+    }
+    let dir = std::env::temp_dir().join(format!(
+        "pacsea_news_url_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    std::fs::create_dir_all(&dir).expect("create test dir");
+    let feed = dir.join("feed.xml");
+    std::fs::write(
+        &feed,
+        "<rss><channel>\
+         <item><title>Mirror item</title><link>https://mirror.example.org/news/1/</link>\
+         <pubDate>Mon, 06 Jan 2025 10:00:00 +0000</pubDate></item>\
+         </channel></rss>",
+    )
+    .expect("write feed");
+
+    let settings = crate::theme::Settings {
+        news_arch_feed_url: format!("file://{}", feed.display()),
+        ..crate::theme::Settings::default()
+    };
+    let mut seen_versions = HashMap::new();
+    let mut seen_comments = HashMap::new();
+    let ctx: NewsFeedContext<'_, std::hash::RandomState, _, _> = NewsFeedContext {
+        force_emit_all: false,
+        updates_list_path: None,
+        limit: 10,
+        include_arch_news: true,
+        include_advisories: false,
+        include_pkg_updates: false,
+        include_aur_comments: false,
+        installed_filter: None,
+        installed_only: false,
+        sort_mode: NewsSortMode::DateDesc,
+        seen_pkg_versions: &mut seen_versions,
+        seen_aur_comments: &mut seen_comments,
+        max_age_days: None,
+        source_urls: NewsSourceUrls::from_settings(&settings),
+        max_parallel_slow_fetches: 1,
+    };
+
+    let items =
+        super::news_fetch::append_arch_news(&ctx.source_urls.arch_feed, 10, Some("2000-01-01"))
+            .await
+            .expect("fetch local feed");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].title, "Mirror item");
+    assert_eq!(
+        items[0].url.as_deref(),
+        Some("https://mirror.example.org/news/1/")
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 #[test]
 /// What: Test `max_age_days` cutoff date calculation.
 ///
@@ -548,3 +645,282 @@ fn test_seen_map_updates_for_comments() {
     // Should update seen map with latest comment ID
     assert_eq!(seen.get("foo"), Some(&"c2".to_string()));
 }
+
+/// Sample JSON Feed 1.1 document covering the date, id and summary fallbacks.
+const JSON_FEED_FIXTURE: &str = r#"{
+  "version": "https://jsonfeed.org/version/1.1",
+  "title": "Derivative blog",
+  "home_page_url": "https://blog.example.org/",
+  "items": [
+    {
+      "id": "https://blog.example.org/posts/kernel-6-12",
+      "url": "https://blog.example.org/posts/kernel-6-12",
+      "title": "Kernel 6.12 lands",
+      "content_text": "The new kernel is now in the stable repos.",
+      "date_published": "2025-01-10T08:30:00+01:00"
+    },
+    {
+      "id": 42,
+      "url": "https://blog.example.org/posts/42",
+      "title": "Mirror maintenance",
+      "summary": "Mirrors will be offline briefly.",
+      "date_modified": "2025-01-08T12:00:00Z"
+    },
+    {
+      "title": "Draft without id or url",
+      "content_text": "Should be skipped.",
+      "date_published": "2025-01-07T00:00:00Z"
+    },
+    {
+      "id": "https://blog.example.org/posts/undated",
+      "content_text": "No title and no dates."
+    }
+  ]
+}"#;
+
+#[test]
+/// What: Ensure JSON Feed documents map onto news feed items.
+///
+/// Inputs:
+/// - `JSON_FEED_FIXTURE` with a complete item, a numeric id using `date_modified`, an item
+///   without id/url, and an untitled undated item.
+///
+/// Output:
+/// - Three items; the id/url-less one is skipped and fallbacks fill date, summary, url and title.
+fn json_feed_items_map_to_news_items() {
+    let items = parse_json_feed(JSON_FEED_FIXTURE, 10).expect("valid JSON Feed");
+    assert_eq!(items.len(), 3);
+
+    assert_eq!(items[0].id, "https://blog.example.org/posts/kernel-6-12");
+    assert_eq!(items[0].title, "Kernel 6.12 lands");
+    assert_eq!(items[0].date, "2025-01-10");
+    assert_eq!(
+        items[0].summary.as_deref(),
+        Some("The new kernel is now in the stable repos.")
+    );
+    assert_eq!(items[0].source, NewsFeedSource::ArchNews);
+
+    assert_eq!(items[1].id, "42");
+    assert_eq!(items[1].date, "2025-01-08");
+    assert_eq!(
+        items[1].summary.as_deref(),
+        Some("Mirrors will be offline briefly.")
+    );
+    assert_eq!(
+        items[1].url.as_deref(),
+        Some("https://blog.example.org/posts/42")
+    );
+
+    assert_eq!(items[2].title, "https://blog.example.org/posts/undated");
+    assert_eq!(
+        items[2].url.as_deref(),
+        Some("https://blog.example.org/posts/undated")
+    );
+    assert!(items[2].date.is_empty());
+
+    assert_eq!(
+        parse_json_feed(JSON_FEED_FIXTURE, 1).expect("valid").len(),
+        1
+    );
+}
+
+#[test]
+/// What: Ensure non-JSON-Feed input is rejected.
+///
+/// Inputs:
+/// - An RSS document, and JSON with a foreign `version`.
+///
+/// Output:
+/// - Both return `Err`.
+fn json_feed_rejects_other_documents() {
+    assert!(parse_json_feed("<rss><channel></channel></rss>", 10).is_err());
+    assert!(parse_json_feed(r#"{"version":"https://example.org/v2","items":[]}"#, 10).is_err());
+}
+
+#[test]
+/// What: Ensure the circuit breaker snapshot follows recorded failures and successes.
+///
+/// Inputs:
+/// - A dedicated endpoint pattern driven through ten failures, then one success.
+/// - Two archlinux.org backoff increases followed by a reset.
+///
+/// Output:
+/// - The endpoint is listed as open with a pending cooldown, then closed again; failures and
+///   backoff are reported until the reset.
+///
+/// Details:
+/// - Uses a unique endpoint pattern because breaker state is process-global.
+fn circuit_breaker_snapshot_tracks_outcomes() {
+    let endpoint = "/pacsea-test/snapshot/*";
+    for _ in 0..9 {
+        record_circuit_breaker_outcome(endpoint, false);
+    }
+    assert!(
+        !circuit_breaker_snapshot()
+            .open_endpoints
+            .iter()
+            .any(|e| e == endpoint)
+    );
+
+    record_circuit_breaker_outcome(endpoint, false);
+    let snapshot = circuit_breaker_snapshot();
+    assert!(snapshot.open);
+    assert!(snapshot.open_endpoints.iter().any(|e| e == endpoint));
+    assert!(snapshot.retry_in > std::time::Duration::from_secs(50));
+    assert!(check_circuit_breaker(endpoint).is_err());
+
+    record_circuit_breaker_outcome(endpoint, true);
+    let snapshot = circuit_breaker_snapshot();
+    assert!(!snapshot.open_endpoints.iter().any(|e| e == endpoint));
+    assert!(!snapshot.half_open_endpoints.iter().any(|e| e == endpoint));
+
+    increase_archlinux_backoff(None);
+    increase_archlinux_backoff(Some(30));
+    let snapshot = circuit_breaker_snapshot();
+    assert!(snapshot.consecutive_failures >= 2);
+    assert_eq!(snapshot.backoff_ms, 30_000);
+    assert!(snapshot.retry_in > std::time::Duration::ZERO);
+
+    reset_archlinux_backoff();
+    let snapshot = circuit_breaker_snapshot();
+    assert_eq!(snapshot.consecutive_failures, 0);
+    assert_eq!(snapshot.backoff_ms, archlinux_backoff_ms());
+}
+
+/// What: Build a minimal feed item for URL de-duplication tests.
+///
+/// Inputs:
+/// - `id`: Item id.
+/// - `url`: Optional link.
+/// - `source`: Item source.
+/// - `severity`: Optional advisory severity.
+///
+/// Output:
+/// - `NewsFeedItem` with the given fields and empty remaining data.
+fn dedup_item(
+    id: &str,
+    url: Option<&str>,
+    source: NewsFeedSource,
+    severity: Option<AdvisorySeverity>,
+) -> NewsFeedItem {
+    NewsFeedItem {
+        id: id.into(),
+        date: "2025-01-01".into(),
+        title: id.into(),
+        summary: None,
+        url: url.map(Into::into),
+        source,
+        severity,
+        packages: Vec::new(),
+    }
+}
+
+#[test]
+/// What: Ensure items with the same normalized URL collapse to the most specific source.
+///
+/// Inputs:
+/// - An Arch news item and an advisory sharing a URL (differing in case and trailing slash).
+/// - Two advisories on another URL with different severities.
+///
+/// Output:
+/// - One item per URL: the advisory beats Arch news and the higher severity wins, kept at the
+///   position of the first occurrence.
+fn dedup_by_url_prefers_advisories() {
+    let items = vec![
+        dedup_item(
+            "news-1",
+            Some("https://security.archlinux.org/AVG-1/"),
+            NewsFeedSource::ArchNews,
+            None,
+        ),
+        dedup_item(
+            "AVG-2-low",
+            Some("https://security.archlinux.org/AVG-2"),
+            NewsFeedSource::SecurityAdvisory,
+            Some(AdvisorySeverity::Low),
+        ),
+        dedup_item(
+            "AVG-1",
+            Some("HTTPS://security.archlinux.org/AVG-1"),
+            NewsFeedSource::SecurityAdvisory,
+            Some(AdvisorySeverity::High),
+        ),
+        dedup_item(
+            "AVG-2-high",
+            Some("https://security.archlinux.org/AVG-2/"),
+            NewsFeedSource::SecurityAdvisory,
+            Some(AdvisorySeverity::High),
+        ),
+        dedup_item(
+            "news-1-dup",
+            Some("https://security.archlinux.org/avg-1"),
+            NewsFeedSource::ArchNews,
+            None,
+        ),
+    ];
+    let ids: Vec<String> = dedup_by_url(items).into_iter().map(|i| i.id).collect();
+    assert_eq!(ids, vec!["AVG-1".to_string(), "AVG-2-high".to_string()]);
+}
+
+#[test]
+/// What: Ensure items without a URL are never de-duplicated.
+///
+/// Inputs:
+/// - Two URL-less items with the same title plus one with a blank URL.
+///
+/// Output:
+/// - All three items are returned unchanged and in order.
+fn dedup_by_url_keeps_url_less_items() {
+    let items = vec![
+        dedup_item("a", None, NewsFeedSource::ArchNews, None),
+        dedup_item("a", None, NewsFeedSource::SecurityAdvisory, None),
+        dedup_item("b", Some("  "), NewsFeedSource::AurComment, None),
+    ];
+    let out = dedup_by_url(items);
+    let keys: Vec<(String, NewsFeedSource)> = out.into_iter().map(|i| (i.id, i.source)).collect();
+    assert_eq!(
+        keys,
+        vec![
+            ("a".to_string(), NewsFeedSource::ArchNews),
+            ("a".to_string(), NewsFeedSource::SecurityAdvisory),
+            ("b".to_string(), NewsFeedSource::AurComment),
+        ]
+    );
+}
+
+#[tokio::test]
+/// What: Ensure the slow-source semaphore bounds the number of in-flight fetches.
+///
+/// Inputs:
+/// - Four delay-producing fake fetches run through `with_slow_fetch_permit` with limits 1 and 2.
+///
+/// Output:
+/// - At most one fetch is in flight with limit 1 and at most two with limit 2.
+///
+/// Details:
+/// - Each fake fetch records the in-flight count on entry and sleeps before leaving.
+async fn slow_fetch_permit_limits_in_flight_fetches() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn peak_in_flight(limit: usize) -> usize {
+        let semaphore = tokio::sync::Semaphore::new(limit);
+        let (in_flight, peak) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        let (in_flight, peak) = (&in_flight, &peak);
+        let fetch = move || async move {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        };
+        tokio::join!(
+            super::news_fetch::with_slow_fetch_permit(&semaphore, fetch()),
+            super::news_fetch::with_slow_fetch_permit(&semaphore, fetch()),
+            super::news_fetch::with_slow_fetch_permit(&semaphore, fetch()),
+            super::news_fetch::with_slow_fetch_permit(&semaphore, fetch()),
+        );
+        peak.load(Ordering::SeqCst)
+    }
+
+    assert_eq!(peak_in_flight(1).await, 1);
+    assert_eq!(peak_in_flight(2).await, 2);
+}
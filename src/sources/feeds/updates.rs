@@ -967,7 +967,10 @@ async fn fetch_aur_versions(pkgnames: &[String]) -> Result<Vec<AurVersionInfo>>
     let cache_path = aur_json_cache_path(pkgnames);
     let old_json = load_aur_json_cache(&cache_path);
 
-    let resp = tokio::task::spawn_blocking(move || crate::util::curl::curl_json(&url)).await??;
+    let resp = tokio::task::spawn_blocking(move || {
+        crate::util::curl::curl_json_for(crate::util::NetworkSource::AurRpc, &url)
+    })
+    .await??;
 
     // Compare with previous JSON if it exists
     if let Some(old_json) = old_json
@@ -28,22 +28,30 @@ pub mod status;
 /// Details: Standard error type for network and parsing operations in the sources module.
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-pub use advisories::fetch_security_advisories;
+pub use advisories::{
+    SECURITY_ADVISORY_FEED_URL, fetch_security_advisories, fetch_security_advisories_from,
+};
 pub use aur_vote::{
     AurPackageVoteState, AurVoteContext, AurVoteError, AurVoteOutcome, VoteAction, aur_vote,
     aur_vote_state, is_vote_state_unsupported_error,
 };
-pub use comments::fetch_aur_comments;
+pub use comments::{
+    COMMENTS_PAGE_SIZE, arrange_comments, fetch_aur_comments, fetch_aur_comments_page,
+    load_more_batch, next_comments_offset,
+};
 pub use details::fetch_details;
 pub use feeds::{
-    NewsFeedContext, check_circuit_breaker, extract_endpoint_pattern,
+    CircuitBreakerStatus, NewsFeedContext, NewsSourceUrls, archlinux_backoff_ms,
+    check_circuit_breaker, circuit_breaker_snapshot, extract_endpoint_pattern,
     extract_retry_after_from_error, fetch_continuation_items, fetch_news_feed,
     get_aur_json_changes, get_official_json_changes, increase_archlinux_backoff,
     load_official_json_cache, official_json_cache_path, optimize_max_age_for_startup,
-    rate_limit_archlinux, record_circuit_breaker_outcome, reset_archlinux_backoff,
-    take_network_error,
+    parse_json_feed, rate_limit_archlinux, record_circuit_breaker_outcome, record_fetch_outcome,
+    reset_archlinux_backoff, take_fetch_outcomes, take_network_error,
+};
+pub use news::{
+    ARCH_NEWS_FEED_URL, fetch_arch_news, fetch_arch_news_from, fetch_news_content, parse_news_html,
 };
-pub use news::{fetch_arch_news, fetch_news_content, parse_news_html};
 pub use pkgbuild::fetch_pkgbuild_fast;
 pub use search::fetch_all_with_errors;
 pub use status::fetch_arch_status_text;
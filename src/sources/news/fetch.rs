@@ -112,6 +112,9 @@ static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
         .expect("Failed to create HTTP client")
 });
 
+/// Built-in Arch Linux news RSS feed URL (overridable with `news_arch_feed_url`).
+pub const ARCH_NEWS_FEED_URL: &str = "https://archlinux.org/feeds/news/";
+
 /// What: Fetch recent Arch Linux news items with optional early date filtering.
 ///
 /// Input:
@@ -130,15 +133,34 @@ static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
 /// date-only form via `strip_time_and_tz`. If `cutoff_date` is provided, stops fetching when
 /// items exceed the date limit.
 pub async fn fetch_arch_news(limit: usize, cutoff_date: Option<&str>) -> Result<Vec<NewsItem>> {
+    fetch_arch_news_from(ARCH_NEWS_FEED_URL, limit, cutoff_date).await
+}
+
+/// What: Fetch Arch Linux news items from a specific RSS feed URL.
+///
+/// Input:
+/// - `url`: RSS feed URL (the built-in [`ARCH_NEWS_FEED_URL`] or a configured override)
+/// - `limit`: Maximum number of items to return (best-effort)
+/// - `cutoff_date`: Optional date string (YYYY-MM-DD) for early filtering
+///
+/// Output: `Ok(Vec<NewsItem>)` with date/title/url; `Err` on network or parse failures
+///
+/// # Errors
+/// - Same as [`fetch_arch_news`]
+///
+/// Details: Shared implementation behind [`fetch_arch_news`]; the body is fetched with curl, so
+/// any URL scheme curl supports works.
+pub async fn fetch_arch_news_from(
+    url: &str,
+    limit: usize,
+    cutoff_date: Option<&str>,
+) -> Result<Vec<NewsItem>> {
     use crate::sources::news::utils::{extract_between, strip_time_and_tz};
 
-    let url = "https://archlinux.org/feeds/news/";
-    // Use shorter timeout (10s connect, 15s max) to avoid blocking on slow/unreachable servers
+    let url = url.to_string();
+    // Short built-in timeout (10s connect, 15s max) unless `network_timeout_news` overrides it
     let body = tokio::task::spawn_blocking(move || {
-        crate::util::curl::curl_text_with_args(
-            url,
-            &["--connect-timeout", "10", "--max-time", "15"],
-        )
+        crate::util::curl::curl_text_for(crate::util::NetworkSource::News, &url)
     })
     .await?
     .map_err(|e| {
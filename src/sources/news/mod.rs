@@ -9,7 +9,7 @@ mod utils;
 /// Result type alias for Arch Linux news fetching operations.
 type Result<T> = super::Result<T>;
 
-pub use fetch::{fetch_arch_news, fetch_news_content};
+pub use fetch::{ARCH_NEWS_FEED_URL, fetch_arch_news, fetch_arch_news_from, fetch_news_content};
 pub use parse::parse_arch_news_html;
 
 /// What: Parse raw news/advisory HTML into displayable text (public helper).
@@ -17,8 +17,6 @@ static PKGBUILD_RATE_LIMITER: Mutex<Option<Instant>> = Mutex::new(None);
 ///
 /// Reduced from 500ms to 200ms for faster preview operations.
 const PKGBUILD_MIN_INTERVAL_MS: u64 = 200;
-/// Extra curl flags for PKGBUILD HTTP fetches (override base connect timeout; cap total time).
-const PKGBUILD_CURL_EXTRA: &[&str] = &["--connect-timeout", "8", "--max-time", "10"];
 
 /// What: Fetch PKGBUILD content for a package from AUR or official Git packaging repos.
 ///
@@ -99,7 +97,7 @@ pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
             // Use curl with timeout to prevent hanging
             let res = tokio::task::spawn_blocking({
                 let url = url.clone();
-                move || crate::util::curl::curl_text_with_args(&url, PKGBUILD_CURL_EXTRA)
+                move || crate::util::curl::curl_text_for(crate::util::NetworkSource::Pkgbuild, &url)
             })
             .await??;
             Ok(res)
@@ -111,7 +109,7 @@ pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
             );
             let main_result = tokio::task::spawn_blocking({
                 let u = url_main.clone();
-                move || crate::util::curl::curl_text_with_args(&u, PKGBUILD_CURL_EXTRA)
+                move || crate::util::curl::curl_text_for(crate::util::NetworkSource::Pkgbuild, &u)
             })
             .await;
             if let Ok(Ok(txt)) = main_result {
@@ -123,7 +121,7 @@ pub async fn fetch_pkgbuild_fast(item: &PackageItem) -> Result<String> {
             );
             let txt = tokio::task::spawn_blocking({
                 let u = url_master;
-                move || crate::util::curl::curl_text_with_args(&u, PKGBUILD_CURL_EXTRA)
+                move || crate::util::curl::curl_text_for(crate::util::NetworkSource::Pkgbuild, &u)
             })
             .await??;
             Ok(txt)
@@ -5,6 +5,9 @@ use std::num::NonZeroUsize;
 /// Maximum number of recent searches to retain (most-recent-first).
 pub const RECENT_CAPACITY: usize = 20;
 
+/// Directory name (next to `install_list.json`) holding named install-list profiles.
+pub const INSTALL_PROFILES_DIR: &str = "install_lists";
+
 /// What: Provide the non-zero capacity used by the LRU recent cache.
 ///
 /// Inputs: None.
@@ -23,3 +26,6 @@ pub const fn recent_capacity() -> NonZeroUsize {
 
 /// File database sync result type.
 pub type FileSyncResult = std::sync::Arc<std::sync::Mutex<Option<Result<bool, String>>>>;
+
+/// Plain-text import summary type: `(added, unresolved)` once the import thread finishes.
+pub type ImportSummaryResult = std::sync::Arc<std::sync::Mutex<Option<(usize, usize)>>>;
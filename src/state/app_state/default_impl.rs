@@ -195,7 +195,6 @@ impl Default for AppState {
         ) = defaults::default_news_feed_state(
             news_recent_path,
             news_bookmarks_path,
-            &news_feed_path,
             news_content_cache_path,
         );
 
@@ -448,13 +447,19 @@ impl Default for AppState {
             focus,
             last_input_change,
             last_saved_value,
+            search_requery_pending: false,
+            result_jump_input: None,
+            escape_quit_armed: false,
             recent_path,
             recent_dirty,
+            recent_counts: HashMap::new(),
+            recent_views: HashMap::new(),
             latest_query_id,
             next_query_id,
             search_cache_query,
             search_cache_fuzzy,
             search_cache_results,
+            search_suggestions: Vec::new(),
             details_cache,
             cache_path,
             cache_dirty,
@@ -466,6 +471,7 @@ impl Default for AppState {
             news_read_ids_dirty,
             news_items,
             news_results,
+            news_advisory_affected: std::collections::HashSet::new(),
             news_loading,
             news_ready,
             news_selected,
@@ -505,6 +511,8 @@ impl Default for AppState {
             news_content_loading_since,
             news_content_debounce_timer,
             news_content_scroll,
+            news_content_scroll_by_item: HashMap::new(),
+            news_content_scroll_item: None,
             news_feed_path,
             news_seen_pkg_versions,
             news_seen_pkg_versions_path,
@@ -522,6 +530,7 @@ impl Default for AppState {
             last_startup_path,
             install_list,
             install_state,
+            install_group_by_source: false,
             remove_list,
             remove_state,
             downgrade_list,
@@ -561,6 +570,8 @@ impl Default for AppState {
             updates_last_check_authoritative,
             refresh_updates,
             pending_updates_modal,
+            failed_fetches: Vec::new(),
+            pending_fetch_retries: Vec::new(),
             faillock_locked,
             faillock_lockout_until,
             faillock_remaining_minutes,
@@ -598,8 +609,14 @@ impl Default for AppState {
             comments_urls,
             comments_authors,
             comments_dates,
+            comments_hidden: Vec::new(),
+            comments_load_more_rect: None,
+            startup_pkgb_pending: false,
+            startup_comments_pending: false,
             toast_message,
             toast_expires_at,
+            theme_file_watch: None,
+            theme_file_checked_at: None,
             layout_left_pct,
             layout_center_pct,
             layout_right_pct,
@@ -609,9 +626,20 @@ impl Default for AppState {
             locale,
             translations,
             translations_fallback,
+            translations_chain: Vec::new(),
             results_rect,
             details_rect,
             details_scroll,
+            details_description_expanded: false,
+            details_description_toggle_rect: None,
+            details_copy_name_rect: None,
+            details_copy_details_rect: None,
+            pkgb_diff_button_rect: None,
+            pkgb_diff_visible: false,
+            pkgb_previous_text: None,
+            comments_next_offset: None,
+            comments_page_requested: None,
+            comments_page_loading: false,
             recent_rect,
             install_rect,
             downgrade_rect,
@@ -650,6 +678,7 @@ impl Default for AppState {
             sort_menu_auto_close_at,
             sort_cache_repo_name,
             sort_cache_aur_popularity,
+            sort_cache_installed_size: None,
             sort_cache_signature,
             options_menu_open,
             options_button_rect,
@@ -685,6 +714,8 @@ impl Default for AppState {
             results_filter_show_artix_system,
             results_filter_show_blackarch,
             results_filter_show_manjaro,
+            results_filter_only_outofdate: false,
+            results_filter_only_orphaned: false,
             repo_results_filter_by_name: HashMap::new(),
             results_filter_dynamic: HashMap::new(),
             results_filter_aur_rect,
@@ -703,7 +734,12 @@ impl Default for AppState {
             results_filter_blackarch_rect,
             results_filter_manjaro_rect,
             results_filter_custom_repos_rect: None,
+            results_filter_outofdate_rect: None,
+            results_filter_orphaned_rect: None,
             fuzzy_indicator_rect: None,
+            search_suggestion_rects: Vec::new(),
+            middle_row_rect: None,
+            column_drag: None,
             refresh_installed_until,
             next_installed_refresh_at,
             pending_install_names,
@@ -743,6 +779,13 @@ impl Default for AppState {
             preflight_sandbox_resolving,
             last_logged_preflight_deps_state,
             preflight_cancelled,
+            expert_mode: false,
+            preflight_raw_output_open: false,
+            preflight_raw_output_scroll: 0,
+            pac_files_baseline: None,
+            pending_pacnew_reminder: Vec::new(),
+            install_aur_helper: None,
+            pending_update_activity: None,
             pending_aur_vote_intent: None,
             pending_aur_vote_request: None,
             aur_vote_state_by_pkgbase,
@@ -751,6 +794,8 @@ impl Default for AppState {
             aur_vote_state_lookup_supported: true,
             pending_aur_vote_state_request: None,
             pending_executor_request: None,
+            exec_log_follow: true,
+            exec_log_from_bottom: 0,
             pending_exec_header_chips: None,
             pending_post_summary_items: None,
             pending_custom_command: None,
@@ -765,6 +810,9 @@ impl Default for AppState {
             pending_aur_update_command: None,
             pending_executor_password: None,
             pending_file_sync_result: None,
+            pending_import_summary: None,
+            preflight_summary_cache: None,
+            preflight_summary_pending_signature: None,
             pending_aur_ssh_help_check_result: None,
             aur_ssh_help_ready: None,
         }
@@ -171,15 +171,17 @@ pub(super) const fn default_app_mode() -> AppMode {
 /// Inputs:
 /// - `news_recent_path`: Path to persist news recent searches
 /// - `news_bookmarks_path`: Path to persist news bookmarks
-/// - `news_feed_path`: Path to persist news feed items
 /// - `news_content_cache_path`: Path to persist news article content cache
 ///
 /// Output:
 /// - Tuple containing news feed data, UI state, and persistence flags.
+///
+/// Details:
+/// - The news feed itself starts empty; the versioned on-disk feed cache is loaded during
+///   startup (`app::runtime::init`) before the background fetch is spawned.
 pub(super) fn default_news_feed_state(
     news_recent_path: PathBuf,
     news_bookmarks_path: PathBuf,
-    news_feed_path: &PathBuf,
     news_content_cache_path: PathBuf,
 ) -> DefaultNewsFeedState {
     let recent_capacity = super::recent_capacity();
@@ -214,10 +216,6 @@ pub(super) fn default_news_feed_state(
                     })
             })
             .unwrap_or_default();
-    let cached_items: Vec<crate::state::types::NewsFeedItem> = fs::read_to_string(news_feed_path)
-        .ok()
-        .and_then(|s| serde_json::from_str(&s).ok())
-        .unwrap_or_default();
     // Load news content cache from disk (URL -> article content)
     // Filter out any error messages that may have been persisted in older versions
     let news_content_cache: std::collections::HashMap<String, String> =
@@ -240,13 +238,11 @@ pub(super) fn default_news_feed_state(
             "loaded news content cache from disk"
         );
     }
-    let news_loading = cached_items.is_empty();
-    let news_ready = !cached_items.is_empty(); // News are ready if cached items exist
     (
-        cached_items.clone(), // news_items
-        cached_items,         // news_results (filtered later)
-        news_loading,         // news_loading
-        news_ready,           // news_ready
+        Vec::new(),           // news_items (cached feed loaded at startup)
+        Vec::new(),           // news_results (filtered later)
+        true,                 // news_loading
+        false,                // news_ready
         0,                    // news_selected
         ListState::default(), // news_list_state
         String::new(),        // news_search_input
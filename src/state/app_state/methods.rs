@@ -1,10 +1,37 @@
 //! Implementation methods for `AppState`.
 
-use crate::state::app_state::{AppState, recent_capacity};
+use crate::logic::preflight::{PreflightSummaryOutcome, summary_signature};
+use crate::state::app_state::{AppState, INSTALL_PROFILES_DIR, recent_capacity};
+use crate::state::modal::PreflightAction;
 use crate::state::types::{
-    NewsBookmark, NewsFeedItem, NewsReadFilter, NewsSortMode, severity_rank,
+    FetchCategory, NewsBookmark, NewsFeedItem, NewsReadFilter, NewsSortMode, PackageItem,
+    RecentViewState, SortMode, severity_rank,
 };
 use chrono::{NaiveDate, Utc};
+use std::path::PathBuf;
+
+/// What: Validate an install profile name for use as a file stem.
+///
+/// Inputs:
+/// - `name`: User-supplied profile name.
+///
+/// Output:
+/// - Trimmed name, or an `InvalidInput` error for empty names and names that could escape the
+///   profiles directory.
+fn validate_profile_name(name: &str) -> std::io::Result<&str> {
+    let name = name.trim();
+    if name.is_empty()
+        || name.starts_with('.')
+        || name.contains(['/', '\\'])
+        || name.chars().any(char::is_control)
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("invalid install profile name: {name:?}"),
+        ));
+    }
+    Ok(name)
+}
 
 impl AppState {
     /// What: Return recent searches in most-recent-first order.
@@ -37,6 +64,233 @@ impl AppState {
         self.recent.iter().nth(index).map(|(_, v)| v.clone())
     }
 
+    /// What: Look up the last recorded result count for a recent search.
+    ///
+    /// Inputs:
+    /// - `value`: Recent search text (case-insensitive).
+    ///
+    /// Output:
+    /// - `Some(count)` when a count was recorded for the query; `None` otherwise.
+    #[must_use]
+    pub fn recent_count_for(&self, value: &str) -> Option<usize> {
+        self.recent_counts
+            .get(&value.trim().to_ascii_lowercase())
+            .copied()
+    }
+
+    /// What: Record the result count for a query that is part of the recent list.
+    ///
+    /// Inputs:
+    /// - `query`: Search text whose results just arrived.
+    /// - `count`: Number of results shown for the query.
+    ///
+    /// Output:
+    /// - None (updates `recent_counts` and marks `recent_dirty` when the count changes).
+    ///
+    /// Details:
+    /// - Queries that are not in `recent` are ignored so the map never outgrows the LRU.
+    /// - Uses `peek` so recording does not change the recent ordering.
+    pub fn record_recent_count(&mut self, query: &str, count: usize) {
+        let key = query.trim().to_ascii_lowercase();
+        if key.is_empty() || self.recent.peek(&key).is_none() {
+            return;
+        }
+        if self.recent_counts.insert(key, count) != Some(count) {
+            self.recent_dirty = true;
+        }
+    }
+
+    /// What: Path where recent search result counts are persisted.
+    ///
+    /// Inputs:
+    /// - `self`: Application state providing `recent_path`.
+    ///
+    /// Output:
+    /// - `recent_path` with a `.counts.json` extension (e.g. `recent_searches.counts.json`).
+    #[must_use]
+    pub fn recent_counts_path(&self) -> std::path::PathBuf {
+        self.recent_path.with_extension("counts.json")
+    }
+
+    /// What: Built-in Results filter toggles paired with their persisted names.
+    ///
+    /// Output:
+    /// - `(name, value)` for every `results_filter_show_*` / `results_filter_only_*` field.
+    const fn results_filter_flags(&self) -> [(&'static str, bool); 17] {
+        [
+            ("aur", self.results_filter_show_aur),
+            ("core", self.results_filter_show_core),
+            ("extra", self.results_filter_show_extra),
+            ("multilib", self.results_filter_show_multilib),
+            ("eos", self.results_filter_show_eos),
+            ("cachyos", self.results_filter_show_cachyos),
+            ("artix", self.results_filter_show_artix),
+            ("artix_omniverse", self.results_filter_show_artix_omniverse),
+            ("artix_universe", self.results_filter_show_artix_universe),
+            ("artix_lib32", self.results_filter_show_artix_lib32),
+            ("artix_galaxy", self.results_filter_show_artix_galaxy),
+            ("artix_world", self.results_filter_show_artix_world),
+            ("artix_system", self.results_filter_show_artix_system),
+            ("blackarch", self.results_filter_show_blackarch),
+            ("manjaro", self.results_filter_show_manjaro),
+            ("only_outofdate", self.results_filter_only_outofdate),
+            ("only_orphaned", self.results_filter_only_orphaned),
+        ]
+    }
+
+    /// What: Mutable access to a built-in Results filter toggle by persisted name.
+    ///
+    /// Inputs:
+    /// - `name`: Name used by [`Self::results_filter_flags`].
+    ///
+    /// Output:
+    /// - `Some(&mut bool)` for known names; `None` otherwise.
+    fn results_filter_flag_mut(&mut self, name: &str) -> Option<&mut bool> {
+        Some(match name {
+            "aur" => &mut self.results_filter_show_aur,
+            "core" => &mut self.results_filter_show_core,
+            "extra" => &mut self.results_filter_show_extra,
+            "multilib" => &mut self.results_filter_show_multilib,
+            "eos" => &mut self.results_filter_show_eos,
+            "cachyos" => &mut self.results_filter_show_cachyos,
+            "artix" => &mut self.results_filter_show_artix,
+            "artix_omniverse" => &mut self.results_filter_show_artix_omniverse,
+            "artix_universe" => &mut self.results_filter_show_artix_universe,
+            "artix_lib32" => &mut self.results_filter_show_artix_lib32,
+            "artix_galaxy" => &mut self.results_filter_show_artix_galaxy,
+            "artix_world" => &mut self.results_filter_show_artix_world,
+            "artix_system" => &mut self.results_filter_show_artix_system,
+            "blackarch" => &mut self.results_filter_show_blackarch,
+            "manjaro" => &mut self.results_filter_show_manjaro,
+            "only_outofdate" => &mut self.results_filter_only_outofdate,
+            "only_orphaned" => &mut self.results_filter_only_orphaned,
+            _ => return None,
+        })
+    }
+
+    /// What: Snapshot the active sort mode and Results filters.
+    ///
+    /// Output:
+    /// - [`RecentViewState`] holding the sort key, built-in toggles, and dynamic toggles.
+    #[must_use]
+    pub fn view_state(&self) -> RecentViewState {
+        RecentViewState {
+            sort_mode: self.sort_mode.as_config_key().to_string(),
+            filters: self
+                .results_filter_flags()
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+            dynamic_filters: self
+                .results_filter_dynamic
+                .iter()
+                .map(|(id, value)| (id.clone(), *value))
+                .collect(),
+        }
+    }
+
+    /// What: Apply a saved sort mode and Results filters.
+    ///
+    /// Inputs:
+    /// - `view`: Snapshot from [`Self::view_state`].
+    ///
+    /// Output:
+    /// - None (updates `sort_mode` and filter toggles in memory only).
+    ///
+    /// Details:
+    /// - Unknown sort keys, filter names, and dynamic ids that `repos.conf` no longer defines are
+    ///   skipped; toggles missing from the snapshot keep their current value.
+    /// - Nothing is written to `settings.conf`; the saved view is per query, not a new default.
+    pub fn apply_view_state(&mut self, view: &RecentViewState) {
+        if let Some(mode) = SortMode::from_config_key(&view.sort_mode) {
+            self.sort_mode = mode;
+        }
+        for (name, value) in &view.filters {
+            if let Some(flag) = self.results_filter_flag_mut(name) {
+                *flag = *value;
+            }
+        }
+        for (id, value) in &view.dynamic_filters {
+            if let Some(flag) = self.results_filter_dynamic.get_mut(id) {
+                *flag = *value;
+            }
+        }
+    }
+
+    /// What: Record the active sort mode and filters for a query in the recent list.
+    ///
+    /// Inputs:
+    /// - `query`: Search text whose view is being shown.
+    ///
+    /// Output:
+    /// - None (updates `recent_views` and marks `recent_dirty` when the view changes).
+    ///
+    /// Details:
+    /// - Like [`Self::record_recent_count`], queries outside `recent` are ignored.
+    pub fn record_recent_view(&mut self, query: &str) {
+        let key = query.trim().to_ascii_lowercase();
+        if key.is_empty() || self.recent.peek(&key).is_none() {
+            return;
+        }
+        let view = self.view_state();
+        if self.recent_views.get(&key) != Some(&view) {
+            self.recent_views.insert(key, view);
+            self.recent_dirty = true;
+        }
+    }
+
+    /// What: Record the active sort mode and filters for the current Search input.
+    ///
+    /// Output:
+    /// - None (see [`Self::record_recent_view`]).
+    ///
+    /// Details:
+    /// - Called by the sort and Results filter toggle handlers after they apply a change.
+    pub fn record_current_view(&mut self) {
+        let query = self.input.clone();
+        self.record_recent_view(&query);
+    }
+
+    /// What: Restore the sort mode and filters saved with a recent search.
+    ///
+    /// Inputs:
+    /// - `query`: Recent search text about to be re-run (case-insensitive).
+    ///
+    /// Output:
+    /// - `true` when a saved view was applied; `false` when defaults were applied instead.
+    ///
+    /// Details:
+    /// - Defaults are the configured `sort_mode`, every repo filter shown, the out-of-date and
+    ///   orphaned filters off, and dynamic filters as saved in `settings.conf`.
+    pub fn restore_recent_view(&mut self, query: &str) -> bool {
+        let key = query.trim().to_ascii_lowercase();
+        if let Some(view) = self.recent_views.get(&key).cloned() {
+            self.apply_view_state(&view);
+            return true;
+        }
+        let prefs = crate::theme::settings();
+        self.sort_mode = prefs.sort_mode;
+        for (name, _) in self.results_filter_flags() {
+            if let Some(flag) = self.results_filter_flag_mut(name) {
+                *flag = !name.starts_with("only_");
+            }
+        }
+        crate::logic::repos::refresh_dynamic_filters_in_app(self, &prefs);
+        false
+    }
+
+    /// What: Path where per-query sort and filter state of recent searches is persisted.
+    ///
+    /// Inputs:
+    /// - `self`: Application state providing `recent_path`.
+    ///
+    /// Output:
+    /// - `recent_path` with a `.views.json` extension (e.g. `recent_searches.views.json`).
+    #[must_use]
+    pub fn recent_views_path(&self) -> std::path::PathBuf {
+        self.recent_path.with_extension("views.json")
+    }
+
     /// What: Remove a recent search at the provided position.
     ///
     /// Inputs:
@@ -175,13 +429,205 @@ impl AppState {
         }
     }
 
+    /// What: Track whether a background fetch category is currently in a failed state.
+    ///
+    /// Inputs:
+    /// - `category`: Fetch category whose latest outcome is known
+    /// - `failed`: `true` when the latest attempt failed
+    ///
+    /// Output:
+    /// - Adds or removes `category` in `failed_fetches` (no duplicates).
+    pub fn set_fetch_failed(&mut self, category: FetchCategory, failed: bool) {
+        if !failed {
+            self.failed_fetches.retain(|c| *c != category);
+        } else if !self.failed_fetches.contains(&category) {
+            self.failed_fetches.push(category);
+        }
+    }
+
+    /// What: Directory holding named install-list profiles.
+    ///
+    /// Inputs:
+    /// - `self`: Application state (uses `install_path`).
+    ///
+    /// Output:
+    /// - `install_lists/` next to the persisted install list (under the lists dir).
+    #[must_use]
+    pub fn install_profiles_dir(&self) -> PathBuf {
+        self.install_path.with_file_name(INSTALL_PROFILES_DIR)
+    }
+
+    /// What: List the saved install-list profiles.
+    ///
+    /// Inputs:
+    /// - `self`: Application state.
+    ///
+    /// Output:
+    /// - Profile names (file stems of `*.json`), sorted; empty when the directory is missing.
+    #[must_use]
+    pub fn install_profile_names(&self) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(self.install_profiles_dir()) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+            .collect();
+        names.sort_by_key(|n| n.to_lowercase());
+        names
+    }
+
+    /// What: Save the current install list as a named profile.
+    ///
+    /// Inputs:
+    /// - `name`: Profile name (used as the file stem).
+    ///
+    /// Output:
+    /// - `Ok(())` after writing `install_lists/<name>.json`; overwrites an existing profile.
+    ///
+    /// # Errors
+    /// - Invalid profile names, serialization failures, and filesystem errors.
+    ///
+    /// Details:
+    /// - Uses the same `PackageItem` JSON format as `install_list.json`, so names and sources
+    ///   round-trip.
+    pub fn save_install_profile(&self, name: &str) -> std::io::Result<()> {
+        let name = validate_profile_name(name)?;
+        let dir = self.install_profiles_dir();
+        std::fs::create_dir_all(&dir)?;
+        let s = serde_json::to_string(&self.install_list).map_err(std::io::Error::other)?;
+        std::fs::write(dir.join(format!("{name}.json")), s)
+    }
+
+    /// What: Replace the install list with a named profile.
+    ///
+    /// Inputs:
+    /// - `name`: Profile name previously saved with [`Self::save_install_profile`].
+    ///
+    /// Output:
+    /// - Number of packages loaded.
+    ///
+    /// # Errors
+    /// - Invalid profile names, missing or unreadable profile files, and malformed JSON.
+    ///
+    /// Details:
+    /// - Rebuilds `install_list_names`, resets the selection, and marks the list dirty so the
+    ///   loaded profile becomes the persisted install list.
+    pub fn load_install_profile(&mut self, name: &str) -> std::io::Result<usize> {
+        let name = validate_profile_name(name)?;
+        let path = self.install_profiles_dir().join(format!("{name}.json"));
+        let s = std::fs::read_to_string(path)?;
+        let list: Vec<PackageItem> = serde_json::from_str(&s).map_err(std::io::Error::other)?;
+        self.install_list_names = list.iter().map(|p| p.name.to_lowercase()).collect();
+        self.install_list = list;
+        self.install_state
+            .select((!self.install_list.is_empty()).then_some(0));
+        self.install_dirty = true;
+        self.last_install_change = Some(std::time::Instant::now());
+        self.invalidate_preflight_summary_cache();
+        Ok(self.install_list.len())
+    }
+
+    /// What: Look up a cached preflight summary for a package set.
+    ///
+    /// Inputs:
+    /// - `items`: Packages under review.
+    /// - `action`: Install vs. remove context.
+    ///
+    /// Output:
+    /// - Clone of the cached outcome when its signature matches, `None` otherwise.
+    #[must_use]
+    pub fn cached_preflight_summary(
+        &self,
+        items: &[PackageItem],
+        action: PreflightAction,
+    ) -> Option<PreflightSummaryOutcome> {
+        let signature = summary_signature(items, action);
+        self.preflight_summary_cache
+            .as_ref()
+            .filter(|(cached, _)| *cached == signature)
+            .map(|(_, outcome)| outcome.clone())
+    }
+
+    /// What: Remember a computed preflight summary for a package set.
+    ///
+    /// Inputs:
+    /// - `signature`: [`summary_signature`] of the request.
+    /// - `outcome`: Computed summary.
+    ///
+    /// Output:
+    /// - Replaces the previous cache entry.
+    pub fn store_preflight_summary(&mut self, signature: u64, outcome: &PreflightSummaryOutcome) {
+        self.preflight_summary_cache = Some((signature, outcome.clone()));
+    }
+
+    /// What: Drop the cached preflight summary.
+    ///
+    /// Inputs:
+    /// - `self`: Mutable application state.
+    ///
+    /// Output:
+    /// - Clears the cache entry and any pending signature.
+    ///
+    /// Details:
+    /// - Called on install/remove/downgrade list mutations and after transactions, since
+    ///   installed versions may have changed even when the package set is the same.
+    pub fn invalidate_preflight_summary_cache(&mut self) {
+        self.preflight_summary_cache = None;
+        self.preflight_summary_pending_signature = None;
+    }
+
+    /// What: Mark every currently visible News Feed item as read.
+    ///
+    /// Inputs:
+    /// - `self`: Mutable application state; `news_results` holds the filtered items.
+    ///
+    /// Output:
+    /// - Number of items that were unread before the call.
+    ///
+    /// Details:
+    /// - Only `news_results` is touched, so items hidden by the active filters stay unread.
+    /// - Inserts ids into `news_read_ids` and URLs into `news_read_urls`, sets the dirty flags,
+    ///   and re-runs [`Self::refresh_news_results`] when anything changed.
+    pub fn mark_visible_news_read(&mut self) -> usize {
+        let mut marked = 0;
+        for item in &self.news_results {
+            let was_read = self.news_read_ids.contains(&item.id)
+                || item
+                    .url
+                    .as_ref()
+                    .is_some_and(|u| self.news_read_urls.contains(u));
+            if !was_read {
+                marked += 1;
+            }
+            if self.news_read_ids.insert(item.id.clone()) {
+                self.news_read_ids_dirty = true;
+            }
+            if let Some(url) = item.url.as_ref()
+                && self.news_read_urls.insert(url.clone())
+            {
+                self.news_read_dirty = true;
+            }
+        }
+        if marked > 0 {
+            self.refresh_news_results();
+        }
+        marked
+    }
+
     /// What: Recompute news results applying filters, search, age cutoff, and sorting.
     ///
     /// Inputs:
     /// - `self`: Mutable application state containing news items and filter fields.
     ///
     /// Output:
-    /// - Updates `news_results`, selection state, and recent news searches.
+    /// - Updates `news_results`, `news_advisory_affected`, selection state, and recent news
+    ///   searches.
+    ///
+    /// Details:
+    /// - Advisories are flagged against the cached installed versions from `crate::index`.
     pub fn refresh_news_results(&mut self) {
         let query = self.news_search_input.to_lowercase();
         if query.is_empty() {
@@ -303,6 +749,10 @@ impl AppState {
             }),
         }
 
+        self.news_advisory_affected = crate::logic::advisory_match::affected_advisory_ids(
+            &filtered,
+            crate::index::installed_version,
+        );
         self.news_results = filtered;
         if self.news_results.is_empty() {
             self.news_selected = 0;
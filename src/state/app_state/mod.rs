@@ -27,7 +27,9 @@ mod methods;
 #[cfg(test)]
 mod tests;
 
-pub use constants::{FileSyncResult, RECENT_CAPACITY, recent_capacity};
+pub use constants::{
+    FileSyncResult, INSTALL_PROFILES_DIR, ImportSummaryResult, RECENT_CAPACITY, recent_capacity,
+};
 
 /// What: UI-facing live vote-state for an AUR package.
 ///
@@ -157,11 +159,22 @@ pub struct AppState {
     pub last_input_change: Instant,
     /// Last value persisted for the input field, to avoid redundant writes.
     pub last_saved_value: Option<String>,
+    /// Set when `input` was cleared outside the search handlers (e.g. on a mode switch);
+    /// the tick handler re-sends the package query so results match the empty input.
+    pub search_requery_pending: bool,
+    /// Digits typed after `:` in normal mode to jump to a 1-based result row (`None` when inactive).
+    pub result_jump_input: Option<String>,
+    /// Set after a first Escape from Search prompted to quit; the next Escape exits.
+    pub escape_quit_armed: bool,
     // Persisted recent searches
     /// Path where recent searches are persisted as JSON.
     pub recent_path: PathBuf,
     /// Dirty flag indicating `recent` needs to be saved.
     pub recent_dirty: bool,
+    /// Result count last seen for each recent search, keyed like `recent` (lowercase query).
+    pub recent_counts: HashMap<String, usize>,
+    /// Sort mode and Results filters last used with each recent search, keyed like `recent`.
+    pub recent_views: HashMap<String, crate::state::types::RecentViewState>,
 
     // Search coordination
     /// Identifier of the latest query whose results are being displayed.
@@ -175,6 +188,8 @@ pub struct AppState {
     pub search_cache_fuzzy: bool,
     /// Cached search results (None if cache is empty or invalid).
     pub search_cache_results: Option<Vec<PackageItem>>,
+    /// "Did you mean" suggestions for the current query when it returned few or no results.
+    pub search_suggestions: Vec<String>,
     // Details cache
     /// Cache of details keyed by package name.
     pub details_cache: HashMap<String, PackageDetails>,
@@ -200,6 +215,8 @@ pub struct AppState {
     pub news_items: Vec<NewsFeedItem>,
     /// Filtered/sorted news results shown in the UI.
     pub news_results: Vec<NewsFeedItem>,
+    /// Ids of advisories in `news_results` that potentially affect installed package versions.
+    pub news_advisory_affected: std::collections::HashSet<String>,
     /// Whether the news feed is currently loading.
     pub news_loading: bool,
     /// Whether news are ready to be viewed (loading complete and news available).
@@ -285,6 +302,10 @@ pub struct AppState {
     pub news_content_debounce_timer: Option<std::time::Instant>,
     /// Scroll offset for news content details.
     pub news_content_scroll: u16,
+    /// Remembered news content scroll offsets keyed by news item id (session only).
+    pub news_content_scroll_by_item: HashMap<String, u16>,
+    /// Id of the news item whose offset `news_content_scroll` currently holds.
+    pub news_content_scroll_item: Option<String>,
     /// Path where the cached news feed is persisted.
     pub news_feed_path: PathBuf,
     /// Last-seen versions for installed packages (dedup for update feed items).
@@ -321,6 +342,9 @@ pub struct AppState {
     pub install_list: Vec<PackageItem>,
     /// List selection state for the Install pane.
     pub install_state: ListState,
+    /// Whether the Install pane groups official packages before AUR packages under headers
+    /// (mirrors the `install_group_by_source` setting).
+    pub install_group_by_source: bool,
     /// Separate list of packages selected for removal (active in installed-only mode).
     pub remove_list: Vec<PackageItem>,
     /// List selection state for the Remove pane.
@@ -428,6 +452,10 @@ pub struct AppState {
     pub refresh_updates: bool,
     /// Flag to indicate that Updates modal should open after refresh completes.
     pub pending_updates_modal: bool,
+    /// Background fetch categories whose most recent attempt failed.
+    pub failed_fetches: Vec<crate::state::types::FetchCategory>,
+    /// Failed fetch categories queued for an immediate retry by the event loop.
+    pub pending_fetch_retries: Vec<crate::state::types::FetchCategory>,
 
     // Faillock lockout status
     /// Whether the user account is currently locked out.
@@ -462,6 +490,12 @@ pub struct AppState {
     pub pkgb_rect: Option<(u16, u16, u16, u16)>,
     /// Rectangle of the clickable "Run checks" button in PKGBUILD title.
     pub pkgb_run_checks_button_rect: Option<(u16, u16, u16, u16)>,
+    /// Rectangle of the clickable "Diff" toggle in PKGBUILD title.
+    pub pkgb_diff_button_rect: Option<(u16, u16, u16, u16)>,
+    /// Whether the PKGBUILD viewer shows a diff against the last-viewed version.
+    pub pkgb_diff_visible: bool,
+    /// PKGBUILD text from the previous visit of `pkgb_package_name` (`None` when none was cached).
+    pub pkgb_previous_text: Option<String>,
     /// Current status of PKGBUILD checks in preview panel.
     pub pkgb_check_status: PkgbuildCheckStatus,
     /// Parsed findings from latest PKGBUILD check run.
@@ -511,6 +545,20 @@ pub struct AppState {
     /// Dates in comments with their screen positions and URLs for click detection.
     /// Vector of (`x`, `y`, `width`, `url_string`) tuples.
     pub comments_dates: Vec<(u16, u16, u16, String)>,
+    /// Fetched comments beyond the `comments_max_display` cap, revealed via "load more".
+    pub comments_hidden: Vec<crate::state::types::AurComment>,
+    /// Rectangle of the clickable "load more" line in the comments viewer when shown.
+    pub comments_load_more_rect: Option<(u16, u16, u16, u16)>,
+    /// Offset of the next AUR comments page to fetch (`None` once the last page is loaded).
+    pub comments_next_offset: Option<usize>,
+    /// "Load more" page request waiting to be sent to the comments worker (page offset).
+    pub comments_page_requested: Option<usize>,
+    /// Whether a "load more" page fetch is in flight.
+    pub comments_page_loading: bool,
+    /// PKGBUILD viewer queued to open once the first selection loads (`pkgb_visible` setting).
+    pub startup_pkgb_pending: bool,
+    /// Comments viewer queued to open once an AUR selection loads (`comments_visible` setting).
+    pub startup_comments_pending: bool,
 
     // Transient toast message (bottom-right)
     /// Optional short-lived info message rendered at the bottom-right corner.
@@ -518,6 +566,12 @@ pub struct AppState {
     /// Deadline (Instant) after which the toast is automatically hidden.
     pub toast_expires_at: Option<Instant>,
 
+    // `theme_file` hot-reload
+    /// Watched `theme_file` path and the modification time last seen for it.
+    pub theme_file_watch: Option<(PathBuf, Option<std::time::SystemTime>)>,
+    /// When the `theme_file` modification time was last polled (throttles tick checks).
+    pub theme_file_checked_at: Option<Instant>,
+
     // User settings loaded at startup
     /// Left pane width percentage.
     pub layout_left_pct: u16,
@@ -538,6 +592,9 @@ pub struct AppState {
     pub translations: crate::i18n::translations::TranslationMap,
     /// Fallback translation map (English) for missing keys
     pub translations_fallback: crate::i18n::translations::TranslationMap,
+    /// Translation maps consulted between `translations` and `translations_fallback`, most
+    /// specific first (e.g. `pt` for `pt-BR`). Empty for the default two-level lookup.
+    pub translations_chain: Vec<crate::i18n::translations::TranslationMap>,
 
     // Mouse hit-test rectangles for panes
     /// Inner content rectangle of the Results list (x, y, w, h).
@@ -546,6 +603,14 @@ pub struct AppState {
     pub details_rect: Option<(u16, u16, u16, u16)>,
     /// Scroll offset (lines) for the Package Info details pane.
     pub details_scroll: u16,
+    /// Whether an over-long description is currently shown in full (reset on selection change).
+    pub details_description_expanded: bool,
+    /// Clickable rectangle for the description expand/collapse toggle (x, y, w, h).
+    pub details_description_toggle_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable rectangle for the "copy name" action in the details pane (x, y, w, h).
+    pub details_copy_name_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable rectangle for the "copy details" action in the details pane (x, y, w, h).
+    pub details_copy_details_rect: Option<(u16, u16, u16, u16)>,
     /// Inner content rectangle of the Recent pane list (x, y, w, h).
     pub recent_rect: Option<(u16, u16, u16, u16)>,
     /// Inner content rectangle of the Install pane list (x, y, w, h).
@@ -638,6 +703,8 @@ pub struct AppState {
     pub sort_cache_repo_name: Option<Vec<usize>>,
     /// Cached sort order for `AurPopularityThenOfficial` mode (indices into `results`).
     pub sort_cache_aur_popularity: Option<Vec<usize>>,
+    /// Cached sort order for `InstalledSize` mode, keyed by the installed-size cache generation.
+    pub sort_cache_installed_size: Option<(u64, Vec<usize>)>,
     /// Signature of results used to validate caches (order-insensitive hash of names).
     pub sort_cache_signature: Option<u64>,
 
@@ -722,6 +789,10 @@ pub struct AppState {
     pub results_filter_show_blackarch: bool,
     /// Whether to include packages labeled as `manjaro` in the Results view.
     pub results_filter_show_manjaro: bool,
+    /// When `true`, the Results view shows only packages flagged out-of-date (AND with repo filters).
+    pub results_filter_only_outofdate: bool,
+    /// When `true`, the Results view shows only orphaned packages (AND with repo filters).
+    pub results_filter_only_orphaned: bool,
     /// Lowercase pacman `[repo]` name → canonical `results_filter` id from `repos.conf`.
     pub repo_results_filter_by_name: HashMap<String, String>,
     /// Per dynamic filter id (canonical), whether search results include packages from mapped repos.
@@ -758,8 +829,18 @@ pub struct AppState {
     pub results_filter_manjaro_rect: Option<(u16, u16, u16, u16)>,
     /// Clickable rectangle for the custom `repos.conf` filter dropdown chip (x, y, w, h).
     pub results_filter_custom_repos_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable rectangle for the out-of-date-only status toggle in the Results title (x, y, w, h).
+    pub results_filter_outofdate_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable rectangle for the orphaned-only status toggle in the Results title (x, y, w, h).
+    pub results_filter_orphaned_rect: Option<(u16, u16, u16, u16)>,
     /// Clickable rectangle for the fuzzy search mode indicator in the Search title (x, y, w, h).
     pub fuzzy_indicator_rect: Option<(u16, u16, u16, u16)>,
+    /// Clickable rectangles for each "did you mean" suggestion in the Results pane (x, y, w, h).
+    pub search_suggestion_rects: Vec<(u16, u16, u16, u16)>,
+    /// Outer rectangle of the package-mode middle row (x, y, w, h); `None` in News mode.
+    pub middle_row_rect: Option<(u16, u16, u16, u16)>,
+    /// Column border being dragged with the left mouse button, if any.
+    pub column_drag: Option<crate::state::types::ColumnBorder>,
 
     // Background refresh of installed/explicit caches after package mutations
     /// If `Some`, keep polling pacman/yay to refresh installed/explicit caches until this time.
@@ -854,6 +935,24 @@ pub struct AppState {
     pub last_logged_preflight_deps_state: Option<(usize, bool, bool)>,
     /// Cancellation flag for preflight operations (set to true when modal closes).
     pub preflight_cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Last completed preflight summary with its [`crate::logic::preflight::summary_signature`].
+    pub preflight_summary_cache: Option<(u64, crate::logic::preflight::PreflightSummaryOutcome)>,
+    /// Signature of the summary request being computed in the background.
+    pub preflight_summary_pending_signature: Option<u64>,
+    /// Whether expert mode is enabled (mirrors the `expert_mode` setting).
+    pub expert_mode: bool,
+    /// Whether the preflight raw command output subview is open (expert mode only).
+    pub preflight_raw_output_open: bool,
+    /// Scroll offset (lines) of the preflight raw command output subview.
+    pub preflight_raw_output_scroll: u16,
+    /// `.pacnew`/`.pacsave` paths present before the running transaction started.
+    pub pac_files_baseline: Option<std::collections::BTreeSet<String>>,
+    /// Conflict files created by finished transactions, awaiting the reminder modal.
+    pub pending_pacnew_reminder: Vec<String>,
+    /// AUR helper chosen in the install flow (`paru`/`yay`); `None` follows the `aur_helper` setting.
+    pub install_aur_helper: Option<String>,
+    /// Packages a running system update was started for; recorded in the activity log on success.
+    pub pending_update_activity: Option<Vec<String>>,
 
     // Executor integration
     /// Pending AUR vote intent (pkgbase and action) awaiting user confirmation.
@@ -876,6 +975,11 @@ pub struct AppState {
     pub pending_aur_vote_state_request: Option<String>,
     /// Pending executor request to be sent when `PreflightExec` modal is ready.
     pub pending_executor_request: Option<crate::install::ExecutorRequest>,
+    /// Whether the `PreflightExec` log pane follows the newest output (reset per run from
+    /// the `exec_log_follow` setting).
+    pub exec_log_follow: bool,
+    /// Lines the paused `PreflightExec` log view sits above the newest output.
+    pub exec_log_from_bottom: usize,
     /// Pending post-summary computation request (items and success flag to compute summary for).
     pub pending_post_summary_items: Option<(Vec<PackageItem>, Option<bool>)>,
     /// Header chips to use when transitioning to `PreflightExec` modal.
@@ -904,6 +1008,8 @@ pub struct AppState {
     pub pending_executor_password: Option<crate::state::SecureString>,
     /// File database sync result from background thread (checked in tick handler).
     pub pending_file_sync_result: Option<FileSyncResult>,
+    /// Plain-text import summary from the import thread (checked in tick handler).
+    pub pending_import_summary: Option<ImportSummaryResult>,
     /// Background AUR SSH validation result handle for Optional Deps status refresh.
     pub pending_aur_ssh_help_check_result: Option<std::sync::Arc<std::sync::Mutex<Option<bool>>>>,
     /// Latest AUR SSH help validation result (`Some(true/false)`) from the background check.
@@ -537,3 +537,206 @@ fn refresh_news_results_sorts_unread_first_then_date() {
         }
     }
 }
+
+#[test]
+#[allow(clippy::field_reassign_with_default)]
+/// What: Ensure "mark all read" only marks the items visible under the active filters.
+///
+/// Inputs:
+/// - An Arch news item, an advisory hidden by the source filter, and an already-read item.
+///
+/// Output:
+/// - Only the visible unread item is counted; the hidden advisory stays unread and the dirty
+///   flags are set.
+///
+/// Details:
+/// - Uses HOME shim to avoid collisions with persisted paths.
+fn mark_visible_news_read_respects_filters() {
+    let _guard = crate::state::test_mutex()
+        .lock()
+        .expect("Test mutex poisoned");
+    let orig_home = std::env::var_os("HOME");
+    let dir = std::env::temp_dir().join(format!(
+        "pacsea_test_news_mark_all_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    let _ = std::fs::create_dir_all(&dir);
+    unsafe { std::env::set_var("HOME", dir.display().to_string()) };
+
+    let mut app = AppState::default();
+    app.news_items = vec![
+        NewsFeedItem {
+            id: "news".into(),
+            date: "2025-01-02".into(),
+            title: "Arch news".into(),
+            summary: None,
+            url: Some("https://example.com/news".into()),
+            source: NewsFeedSource::ArchNews,
+            severity: None,
+            packages: vec![],
+        },
+        NewsFeedItem {
+            id: "old".into(),
+            date: "2025-01-01".into(),
+            title: "Already read".into(),
+            summary: None,
+            url: None,
+            source: NewsFeedSource::ArchNews,
+            severity: None,
+            packages: vec![],
+        },
+        NewsFeedItem {
+            id: "AVG-1".into(),
+            date: "2025-01-03".into(),
+            title: "Hidden advisory".into(),
+            summary: None,
+            url: Some("https://example.com/avg".into()),
+            source: NewsFeedSource::SecurityAdvisory,
+            severity: Some(AdvisorySeverity::High),
+            packages: vec!["openssl".into()],
+        },
+    ];
+    app.news_read_ids.insert("old".into());
+    app.news_filter_show_advisories = false;
+    app.news_filter_read_status = NewsReadFilter::All;
+    app.news_max_age_days = None;
+    app.news_read_ids_dirty = false;
+    app.news_read_dirty = false;
+    app.refresh_news_results();
+    assert_eq!(app.news_results.len(), 2);
+
+    assert_eq!(app.mark_visible_news_read(), 1);
+    assert!(app.news_read_ids.contains("news"));
+    assert!(app.news_read_urls.contains("https://example.com/news"));
+    assert!(!app.news_read_ids.contains("AVG-1"));
+    assert!(!app.news_read_urls.contains("https://example.com/avg"));
+    assert!(app.news_read_ids_dirty);
+    assert!(app.news_read_dirty);
+
+    assert_eq!(app.mark_visible_news_read(), 0);
+
+    unsafe {
+        if let Some(v) = orig_home {
+            std::env::set_var("HOME", v);
+        } else {
+            std::env::remove_var("HOME");
+        }
+    }
+}
+
+#[test]
+/// What: Verify install profiles round-trip names and sources through `install_lists/`.
+///
+/// Inputs:
+/// - Install list with an official and an AUR package saved as `laptop`, then a second list
+///   saved as `desk`.
+///
+/// Output:
+/// - Both profiles are listed in sorted order; loading `laptop` restores the original packages
+///   and sources and marks the list dirty; invalid and missing names are rejected.
+///
+/// Details:
+/// - Points `install_path` into a temp dir so profiles are written next to it.
+fn install_profiles_round_trip() {
+    let _guard = crate::state::test_mutex()
+        .lock()
+        .expect("Test mutex poisoned");
+    let dir = std::env::temp_dir().join(format!(
+        "pacsea_test_install_profiles_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    let _ = std::fs::create_dir_all(&dir);
+
+    let mut app = AppState {
+        install_path: dir.join("install_list.json"),
+        ..AppState::default()
+    };
+    assert!(app.install_profile_names().is_empty());
+    assert_eq!(app.install_profiles_dir(), dir.join("install_lists"));
+
+    app.install_list = vec![
+        crate::state::PackageItem::test_item("ripgrep", "extra"),
+        crate::state::PackageItem::test_item("yay-bin", "aur"),
+    ];
+    app.save_install_profile("laptop").expect("save laptop");
+    app.install_list = vec![crate::state::PackageItem::test_item("fd", "extra")];
+    app.save_install_profile(" desk ").expect("save desk");
+    assert_eq!(app.install_profile_names(), vec!["desk", "laptop"]);
+
+    app.install_dirty = false;
+    assert_eq!(app.load_install_profile("laptop").expect("load laptop"), 2);
+    let names: Vec<&str> = app.install_list.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["ripgrep", "yay-bin"]);
+    assert!(matches!(
+        &app.install_list[0].source,
+        crate::state::Source::Official { repo, .. } if repo == "extra"
+    ));
+    assert!(matches!(
+        app.install_list[1].source,
+        crate::state::Source::Aur
+    ));
+    assert!(app.install_dirty);
+    assert_eq!(app.install_state.selected(), Some(0));
+
+    assert!(app.save_install_profile("../escape").is_err());
+    assert!(app.save_install_profile("   ").is_err());
+    assert!(app.load_install_profile("missing").is_err());
+    assert_eq!(app.install_list.len(), 2);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+/// What: Verify loading a profile rebuilds the install-list membership set.
+///
+/// Inputs:
+/// - Profile with `Foo` and `bar`; current list holding an unrelated `stale` package.
+///
+/// Output:
+/// - `install_list_names` contains exactly the lowercased names from the profile.
+///
+/// Details:
+/// - Membership checks use lowercase names, matching `add_to_install_list`.
+fn load_install_profile_rebuilds_membership() {
+    let _guard = crate::state::test_mutex()
+        .lock()
+        .expect("Test mutex poisoned");
+    let dir = std::env::temp_dir().join(format!(
+        "pacsea_test_install_profile_names_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    let _ = std::fs::create_dir_all(&dir);
+
+    let mut app = AppState {
+        install_path: dir.join("install_list.json"),
+        ..AppState::default()
+    };
+    app.install_list = vec![
+        crate::state::PackageItem::test_item("Foo", "aur"),
+        crate::state::PackageItem::test_item("bar", "aur"),
+    ];
+    app.save_install_profile("work").expect("save work");
+
+    app.install_list = vec![crate::state::PackageItem::test_item("stale", "aur")];
+    app.install_list_names = std::collections::HashSet::from(["stale".to_string()]);
+    app.load_install_profile("work").expect("load work");
+
+    assert_eq!(
+        app.install_list_names,
+        std::collections::HashSet::from(["foo".to_string(), "bar".to_string()])
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -16,7 +16,8 @@
 //! the roadmap without being able to open it yet.
 
 use crate::theme::{
-    ConfigFile, EditableSetting, KeyChord, KeyMap, ValueKind, find_setting, settings_for,
+    ConfigFile, EDITABLE_KEYBINDS, EditableSetting, KeyChord, KeyMap, ValueKind, find_setting,
+    keybind_scope, settings_for,
 };
 use crossterm::event::{KeyCode, KeyModifiers};
 use std::{fs, path::Path, path::PathBuf, time::Instant};
@@ -543,6 +544,7 @@ pub fn current_value_string(entry: &EditableSetting) -> String {
             }
         }
         "fuzzy_search" => bool_to_canonical(s.fuzzy_search).to_string(),
+        "search_debounce_ms" => s.search_debounce_ms.to_string(),
         "installed_packages_mode" => s.installed_packages_mode.as_config_key().to_string(),
 
         // Preflight / privilege
@@ -608,11 +610,10 @@ pub fn current_value_string(entry: &EditableSetting) -> String {
             .map_or_else(|| "all".to_string(), |n| n.to_string()),
 
         // Misc
-        "package_marker" => match s.package_marker {
-            crate::theme::PackageMarker::FullLine => "full_line".to_string(),
-            crate::theme::PackageMarker::Front => "front".to_string(),
-            crate::theme::PackageMarker::End => "end".to_string(),
-        },
+        "package_marker" => s.package_marker.as_key().to_string(),
+        "package_marker_install" => s.package_marker_install,
+        "package_marker_remove" => s.package_marker_remove,
+        "package_marker_downgrade" => s.package_marker_downgrade,
         "locale" => s.locale,
         "updates_refresh_interval" => s.updates_refresh_interval.to_string(),
         "use_terminal_theme" => bool_to_canonical(s.use_terminal_theme).to_string(),
@@ -709,6 +710,10 @@ pub fn keybind_chords_for_key<'a>(key: &str, keymap: &'a KeyMap) -> &'a [KeyChor
         "keybind_toggle_options" => &keymap.options_menu_toggle,
         "keybind_toggle_panels" => &keymap.panels_menu_toggle,
         "keybind_reload_config" => &keymap.reload_config,
+        "keybind_retry_failed_fetches" => &keymap.retry_failed_fetches,
+        "keybind_command_palette" => &keymap.command_palette,
+        "keybind_copy_package_name" => &keymap.copy_package_name,
+        "keybind_copy_package_details" => &keymap.copy_package_details,
         "keybind_exit" => &keymap.exit,
         "keybind_show_pkgbuild" => &keymap.show_pkgbuild,
         "keybind_comments_toggle" => &keymap.comments_toggle,
@@ -718,6 +723,8 @@ pub fn keybind_chords_for_key<'a>(key: &str, keymap: &'a KeyMap) -> &'a [KeyChor
         "keybind_pane_next" => &keymap.pane_next,
         "keybind_pane_left" => &keymap.pane_left,
         "keybind_pane_right" => &keymap.pane_right,
+        "keybind_pane_up" => &keymap.pane_up,
+        "keybind_pane_down" => &keymap.pane_down,
         "keybind_toggle_fuzzy" => &keymap.toggle_fuzzy,
         "keybind_search_move_up" => &keymap.search_move_up,
         "keybind_search_move_down" => &keymap.search_move_down,
@@ -725,10 +732,13 @@ pub fn keybind_chords_for_key<'a>(key: &str, keymap: &'a KeyMap) -> &'a [KeyChor
         "keybind_search_page_down" => &keymap.search_page_down,
         "keybind_search_add" => &keymap.search_add,
         "keybind_search_install" => &keymap.search_install,
+        "keybind_search_quick_install" => &keymap.search_quick_install,
         "keybind_search_focus_left" => &keymap.search_focus_left,
         "keybind_search_focus_right" => &keymap.search_focus_right,
         "keybind_search_backspace" => &keymap.search_backspace,
         "keybind_search_insert_clear" => &keymap.search_insert_clear,
+        "keybind_search_toggle_variant" => &keymap.search_toggle_variant,
+        "keybind_search_accept_suggestion" => &keymap.search_accept_suggestion,
         "keybind_search_normal_toggle" => &keymap.search_normal_toggle,
         "keybind_search_normal_insert" => &keymap.search_normal_insert,
         "keybind_search_normal_select_left" => &keymap.search_normal_select_left,
@@ -756,15 +766,260 @@ pub fn keybind_chords_for_key<'a>(key: &str, keymap: &'a KeyMap) -> &'a [KeyChor
         "keybind_install_find" => &keymap.install_find,
         "keybind_install_to_search" => &keymap.install_to_search,
         "keybind_install_focus_left" => &keymap.install_focus_left,
+        "keybind_install_profiles" => &keymap.install_profiles,
+        "keybind_install_reorder_up" => &keymap.install_reorder_up,
+        "keybind_install_reorder_down" => &keymap.install_reorder_down,
+        "keybind_install_copy_command" => &keymap.install_copy_command,
         "keybind_news_mark_read" => &keymap.news_mark_read,
         "keybind_news_mark_all_read" => &keymap.news_mark_all_read,
         "keybind_news_feed_mark_read" => &keymap.news_mark_read_feed,
         "keybind_news_feed_mark_unread" => &keymap.news_mark_unread_feed,
         "keybind_news_feed_toggle_read" => &keymap.news_toggle_read_feed,
+        "keybind_news_feed_export_markdown" => &keymap.news_export_markdown_feed,
+        "keybind_news_feed_mark_all_read" => &keymap.news_mark_all_read_feed,
         _ => &[],
     }
 }
 
+/// What: Mutable counterpart of [`keybind_chords_for_key`] used when applying an
+/// imported keymap.
+///
+/// Inputs:
+/// - `key`: Canonical keybind name from [`crate::theme::EDITABLE_KEYBINDS`].
+/// - `keymap`: Keymap to modify.
+///
+/// Output:
+/// - `Some(&mut Vec<KeyChord>)` for recognized actions, `None` otherwise.
+///
+/// Details:
+/// - Must list the same actions as [`keybind_chords_for_key`]; a test guards drift.
+#[must_use]
+#[allow(clippy::too_many_lines, clippy::cognitive_complexity)]
+pub fn keybind_chords_for_key_mut<'a>(
+    key: &str,
+    keymap: &'a mut KeyMap,
+) -> Option<&'a mut Vec<KeyChord>> {
+    let chords = match key {
+        "keybind_help" => &mut keymap.help_overlay,
+        "keybind_toggle_config" => &mut keymap.config_menu_toggle,
+        "keybind_toggle_options" => &mut keymap.options_menu_toggle,
+        "keybind_toggle_panels" => &mut keymap.panels_menu_toggle,
+        "keybind_reload_config" => &mut keymap.reload_config,
+        "keybind_retry_failed_fetches" => &mut keymap.retry_failed_fetches,
+        "keybind_command_palette" => &mut keymap.command_palette,
+        "keybind_copy_package_name" => &mut keymap.copy_package_name,
+        "keybind_copy_package_details" => &mut keymap.copy_package_details,
+        "keybind_exit" => &mut keymap.exit,
+        "keybind_show_pkgbuild" => &mut keymap.show_pkgbuild,
+        "keybind_comments_toggle" => &mut keymap.comments_toggle,
+        "keybind_run_pkgbuild_checks" => &mut keymap.run_pkgbuild_checks,
+        "keybind_cycle_pkgbuild_sections" => &mut keymap.cycle_pkgbuild_sections,
+        "keybind_change_sort" => &mut keymap.change_sort,
+        "keybind_pane_next" => &mut keymap.pane_next,
+        "keybind_pane_left" => &mut keymap.pane_left,
+        "keybind_pane_right" => &mut keymap.pane_right,
+        "keybind_pane_up" => &mut keymap.pane_up,
+        "keybind_pane_down" => &mut keymap.pane_down,
+        "keybind_toggle_fuzzy" => &mut keymap.toggle_fuzzy,
+        "keybind_search_move_up" => &mut keymap.search_move_up,
+        "keybind_search_move_down" => &mut keymap.search_move_down,
+        "keybind_search_page_up" => &mut keymap.search_page_up,
+        "keybind_search_page_down" => &mut keymap.search_page_down,
+        "keybind_search_add" => &mut keymap.search_add,
+        "keybind_search_install" => &mut keymap.search_install,
+        "keybind_search_quick_install" => &mut keymap.search_quick_install,
+        "keybind_search_focus_left" => &mut keymap.search_focus_left,
+        "keybind_search_focus_right" => &mut keymap.search_focus_right,
+        "keybind_search_backspace" => &mut keymap.search_backspace,
+        "keybind_search_insert_clear" => &mut keymap.search_insert_clear,
+        "keybind_search_toggle_variant" => &mut keymap.search_toggle_variant,
+        "keybind_search_accept_suggestion" => &mut keymap.search_accept_suggestion,
+        "keybind_search_normal_toggle" => &mut keymap.search_normal_toggle,
+        "keybind_search_normal_insert" => &mut keymap.search_normal_insert,
+        "keybind_search_normal_select_left" => &mut keymap.search_normal_select_left,
+        "keybind_search_normal_select_right" => &mut keymap.search_normal_select_right,
+        "keybind_search_normal_delete" => &mut keymap.search_normal_delete,
+        "keybind_search_normal_clear" => &mut keymap.search_normal_clear,
+        "keybind_search_normal_open_status" => &mut keymap.search_normal_open_status,
+        "keybind_search_normal_import" => &mut keymap.search_normal_import,
+        "keybind_search_normal_export" => &mut keymap.search_normal_export,
+        "keybind_search_normal_updates" => &mut keymap.search_normal_updates,
+        "keybind_recent_move_up" => &mut keymap.recent_move_up,
+        "keybind_recent_move_down" => &mut keymap.recent_move_down,
+        "keybind_recent_find" => &mut keymap.recent_find,
+        "keybind_recent_use" => &mut keymap.recent_use,
+        "keybind_recent_add" => &mut keymap.recent_add,
+        "keybind_recent_to_search" => &mut keymap.recent_to_search,
+        "keybind_recent_focus_right" => &mut keymap.recent_focus_right,
+        "keybind_recent_remove" => &mut keymap.recent_remove,
+        "keybind_recent_clear" => &mut keymap.recent_clear,
+        "keybind_install_move_up" => &mut keymap.install_move_up,
+        "keybind_install_move_down" => &mut keymap.install_move_down,
+        "keybind_install_confirm" => &mut keymap.install_confirm,
+        "keybind_install_remove" => &mut keymap.install_remove,
+        "keybind_install_clear" => &mut keymap.install_clear,
+        "keybind_install_find" => &mut keymap.install_find,
+        "keybind_install_to_search" => &mut keymap.install_to_search,
+        "keybind_install_focus_left" => &mut keymap.install_focus_left,
+        "keybind_install_profiles" => &mut keymap.install_profiles,
+        "keybind_install_reorder_up" => &mut keymap.install_reorder_up,
+        "keybind_install_reorder_down" => &mut keymap.install_reorder_down,
+        "keybind_install_copy_command" => &mut keymap.install_copy_command,
+        "keybind_news_mark_read" => &mut keymap.news_mark_read,
+        "keybind_news_mark_all_read" => &mut keymap.news_mark_all_read,
+        "keybind_news_feed_mark_read" => &mut keymap.news_mark_read_feed,
+        "keybind_news_feed_mark_unread" => &mut keymap.news_mark_unread_feed,
+        "keybind_news_feed_toggle_read" => &mut keymap.news_toggle_read_feed,
+        "keybind_news_feed_export_markdown" => &mut keymap.news_export_markdown_feed,
+        "keybind_news_feed_mark_all_read" => &mut keymap.news_mark_all_read_feed,
+        _ => return None,
+    };
+    Some(chords)
+}
+
+/// What: Serialize the editable actions of a keymap into shareable `keybinds.conf` lines.
+///
+/// Inputs:
+/// - `keymap`: Keymap to export (typically the live `AppState.keymap`).
+///
+/// Output:
+/// - File content with one `keybind_<action> = <chord>` line per bound chord.
+///
+/// Details:
+/// - Multi-chord actions emit one line per chord; unbound actions emit an empty value
+///   so [`import_keymap`] restores them as unbound.
+#[must_use]
+pub fn export_keymap(keymap: &KeyMap) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::from("# Pacsea keymap export\n");
+    for entry in EDITABLE_KEYBINDS {
+        let chords = keybind_chords_for_key(entry.key, keymap);
+        if chords.is_empty() {
+            let _ = writeln!(out, "{} =", entry.key);
+        }
+        for chord in chords {
+            let _ = writeln!(out, "{} = {}", entry.key, chord_to_canonical_string(chord));
+        }
+    }
+    out
+}
+
+/// Result of a successful [`import_keymap`] call.
+#[derive(Clone, Debug)]
+pub struct KeymapImport {
+    /// Keymap with the imported actions applied on top of the base keymap.
+    pub keymap: KeyMap,
+    /// Number of distinct actions assigned by the imported content.
+    pub actions: usize,
+    /// Same-scope chord collisions involving imported actions, as readable messages.
+    pub conflicts: Vec<String>,
+}
+
+/// What: Parse exported keymap content and apply it on top of a base keymap.
+///
+/// Inputs:
+/// - `content`: Text in the [`export_keymap`] format (`keybinds.conf` syntax).
+/// - `base`: Keymap supplying bindings for actions the content does not mention.
+///
+/// Output:
+/// - `Ok(KeymapImport)` with the merged keymap and any conflicts.
+/// - `Err(reason)` naming the first line with an unknown action or invalid chord.
+///
+/// # Errors
+/// - Returns a message when a line is malformed, names an unknown action, holds
+///   an invalid chord, or when the content contains no keybind entries.
+///
+/// Details:
+/// - The first line for an action replaces its base chords; later lines for the
+///   same action add chords, so multi-chord actions round-trip.
+/// - Conflicts are reported, not rejected, and only for pairs where at least one
+///   side was assigned by the import.
+pub fn import_keymap(content: &str, base: &KeyMap) -> Result<KeymapImport, String> {
+    let mut keymap = base.clone();
+    let mut assigned: Vec<&'static str> = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+        let Some((raw_key, raw_val)) = trimmed.split_once('=') else {
+            return Err(format!(
+                "line {line_no}: expected `keybind_<action> = <chord>`"
+            ));
+        };
+        let raw_key = raw_key.trim();
+        let Some(entry) = find_setting(raw_key).filter(|s| s.file == ConfigFile::Keybinds) else {
+            return Err(format!(
+                "line {line_no}: unknown keybind action `{raw_key}`"
+            ));
+        };
+        let Some(chords) = keybind_chords_for_key_mut(entry.key, &mut keymap) else {
+            return Err(format!(
+                "line {line_no}: unknown keybind action `{raw_key}`"
+            ));
+        };
+        if !assigned.contains(&entry.key) {
+            assigned.push(entry.key);
+            chords.clear();
+        }
+        let value = raw_val.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let chord = crate::theme::parse_key_chord(value).ok_or_else(|| {
+            format!(
+                "line {line_no}: invalid key chord `{value}` for {}; expected forms like Ctrl+R or Shift+Tab",
+                entry.key
+            )
+        })?;
+        if !chords.contains(&chord) {
+            chords.push(chord);
+        }
+    }
+    if assigned.is_empty() {
+        return Err("no keybind entries found".to_string());
+    }
+    let conflicts = keymap_conflicts(&keymap)
+        .into_iter()
+        .filter(|(a, b, _)| assigned.contains(a) || assigned.contains(b))
+        .map(|(a, b, chord)| format!("{a} and {b} both use {chord}"))
+        .collect();
+    Ok(KeymapImport {
+        keymap,
+        actions: assigned.len(),
+        conflicts,
+    })
+}
+
+/// What: Find editable actions in the same scope that share a chord.
+///
+/// Inputs:
+/// - `keymap`: Keymap to scan.
+///
+/// Output:
+/// - `(action_a, action_b, chord)` triples in schema order.
+///
+/// Details:
+/// - Uses the same scope rules as the config editor's save-time conflict check.
+#[must_use]
+pub fn keymap_conflicts(keymap: &KeyMap) -> Vec<(&'static str, &'static str, String)> {
+    let mut out = Vec::new();
+    for (i, a) in EDITABLE_KEYBINDS.iter().enumerate() {
+        let chords_a = keybind_chords_for_key(a.key, keymap);
+        for b in &EDITABLE_KEYBINDS[i + 1..] {
+            if keybind_scope(a.key) != keybind_scope(b.key) {
+                continue;
+            }
+            let chords_b = keybind_chords_for_key(b.key, keymap);
+            if let Some(shared) = chords_a.iter().find(|c| chords_b.contains(c)) {
+                out.push((a.key, b.key, chord_to_canonical_string(shared)));
+            }
+        }
+    }
+    out
+}
+
 /// What: Serialize a [`KeyChord`] into the canonical string format
 /// understood by `theme::parsing::parse_key_chord`.
 ///
@@ -1070,6 +1325,103 @@ mod tests {
         }
     }
 
+    /// What: Guard the mutable chord lookup against drift from the read-only one.
+    ///
+    /// Inputs:
+    /// - Every entry in `EDITABLE_KEYBINDS` and the default keymap.
+    ///
+    /// Output:
+    /// - Fails when `keybind_chords_for_key_mut` misses an action or targets a
+    ///   different field than `keybind_chords_for_key`.
+    #[test]
+    fn mutable_chord_lookup_matches_read_only_lookup() {
+        let km = KeyMap::default();
+        for entry in crate::theme::EDITABLE_KEYBINDS {
+            let mut copy = km.clone();
+            let chords = keybind_chords_for_key_mut(entry.key, &mut copy)
+                .unwrap_or_else(|| panic!("{} has no mutable lookup arm", entry.key));
+            assert_eq!(chords.as_slice(), keybind_chords_for_key(entry.key, &km));
+        }
+        let mut km = km;
+        assert!(keybind_chords_for_key_mut("keybind_unknown_action", &mut km).is_none());
+    }
+
+    /// What: Verify a customized keymap survives an export/import round trip.
+    ///
+    /// Inputs:
+    /// - Default keymap with a rebound action, a multi-chord action, and an unbound action.
+    ///
+    /// Output:
+    /// - Importing the export onto a fresh default keymap reproduces every editable action.
+    ///
+    /// Details:
+    /// - Compares per-action chord lists since `KeyMap` has no `PartialEq`.
+    #[test]
+    fn keymap_round_trips_through_export_and_import() {
+        let km = KeyMap {
+            help_overlay: vec![KeyChord {
+                code: KeyCode::Char('y'),
+                mods: KeyModifiers::CONTROL | KeyModifiers::ALT,
+            }],
+            recent_remove: vec![
+                KeyChord {
+                    code: KeyCode::Delete,
+                    mods: KeyModifiers::empty(),
+                },
+                KeyChord {
+                    code: KeyCode::Char('x'),
+                    mods: KeyModifiers::SHIFT,
+                },
+            ],
+            toggle_fuzzy: Vec::new(),
+            ..KeyMap::default()
+        };
+        let exported = export_keymap(&km);
+        let imported = import_keymap(&exported, &KeyMap::default()).expect("export must import");
+        assert_eq!(imported.actions, crate::theme::EDITABLE_KEYBINDS.len());
+        for entry in crate::theme::EDITABLE_KEYBINDS {
+            assert_eq!(
+                keybind_chords_for_key(entry.key, &imported.keymap),
+                keybind_chords_for_key(entry.key, &km),
+                "{} did not round-trip",
+                entry.key
+            );
+        }
+        assert_eq!(export_keymap(&imported.keymap), exported);
+    }
+
+    /// What: Verify import rejects an invalid chord with a clear error and reports conflicts.
+    ///
+    /// Inputs:
+    /// - Content with a bogus chord, content with an unknown action, and content that
+    ///   binds two search-scope actions to the same chord.
+    ///
+    /// Output:
+    /// - Errors name the line and offending value; the conflicting import succeeds with
+    ///   a conflict message naming both actions.
+    #[test]
+    fn keymap_import_rejects_invalid_chord_and_reports_conflicts() {
+        let base = KeyMap::default();
+        let err = import_keymap("keybind_help = Ctrl+Banana\n", &base)
+            .expect_err("invalid chord must be rejected");
+        assert!(err.contains("line 1"), "{err}");
+        assert!(err.contains("Ctrl+Banana"), "{err}");
+        assert!(err.contains("keybind_help"), "{err}");
+
+        let err = import_keymap("# shared\nkeybind_does_not_exist = F2\n", &base)
+            .expect_err("unknown action must be rejected");
+        assert!(err.contains("line 2"), "{err}");
+
+        let imported = import_keymap(
+            "keybind_search_add = Ctrl+F9\nkeybind_search_install = Ctrl+F9\n",
+            &base,
+        )
+        .expect("conflicts are reported, not rejected");
+        assert_eq!(imported.conflicts.len(), 1);
+        assert!(imported.conflicts[0].contains("keybind_search_add"));
+        assert!(imported.conflicts[0].contains("keybind_search_install"));
+    }
+
     #[test]
     fn color_serialization_round_trips_theme_keys() {
         assert_eq!(
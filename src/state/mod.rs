@@ -21,9 +21,9 @@ pub use main_vertical_pane::{
 };
 pub use modal::{Modal, PreflightAction, PreflightTab, SshSetupStep};
 pub use types::{
-    ArchStatusColor, Focus, InstalledPackagesMode, NewsItem, PackageDetails, PackageItem,
-    PkgbuildCheckRequest, PkgbuildCheckResponse, QueryInput, RightPaneFocus, SearchResults,
-    SecureString, SortMode, Source,
+    ArchStatusColor, FetchCategory, Focus, InstalledPackagesMode, NewsItem, PackageDetails,
+    PackageItem, PkgbuildCheckRequest, PkgbuildCheckResponse, QueryInput, RecentViewState,
+    RightPaneFocus, SearchResults, SecureString, SortMode, Source,
 };
 
 #[cfg(test)]
@@ -350,6 +350,12 @@ impl CascadeMode {
         !matches!(self, Self::Basic)
     }
 
+    /// Whether this `CascadeMode` also removes dependencies left orphaned by the targets.
+    #[must_use]
+    pub const fn removes_orphaned_dependencies(self) -> bool {
+        matches!(self, Self::Cascade | Self::CascadeWithConfigs)
+    }
+
     /// Cycle to the next `CascadeMode`.
     #[must_use]
     pub const fn next(self) -> Self {
@@ -380,6 +386,12 @@ pub struct DependencyInfo {
     pub is_core: bool,
     /// Whether this is a critical system package.
     pub is_system: bool,
+    /// Whether this entry comes from `optdepends` rather than a hard dependency.
+    #[serde(default)]
+    pub optional: bool,
+    /// One-line description attached to an optional dependency (`foo: does bar`).
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
 /// Summary statistics for reverse dependency analysis of removal targets.
@@ -646,6 +658,64 @@ pub struct PreflightPackageSummary {
     pub notes: Vec<String>,
 }
 
+/// What: Kind of conflict reported by the dry-run conflict check.
+///
+/// Inputs:
+/// - Parsed from pacman output by the preflight summary.
+///
+/// Output:
+/// - Selects the wording of [`ConflictInfo::warning`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ConflictKind {
+    /// Two packages declare a conflict with each other.
+    Package,
+    /// A package file already exists on disk or is owned by another package.
+    File,
+}
+
+/// What: A conflict pacman would report for the planned transaction.
+///
+/// Inputs:
+/// - Parsed from `pacman -S --print` output by the preflight summary.
+///
+/// Output:
+/// - Rendered as warnings in the Summary tab.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConflictInfo {
+    /// Conflict kind.
+    pub kind: ConflictKind,
+    /// Package being installed.
+    pub package: String,
+    /// Conflicting package; `filesystem` for files that exist on disk without an owner.
+    pub conflicts_with: String,
+    /// Dependency reason for package conflicts (when pacman gives one), or the file path.
+    pub detail: Option<String>,
+}
+
+impl ConflictInfo {
+    /// What: Human-readable warning for the Summary tab.
+    ///
+    /// Output: Sentence naming both sides of the conflict and the reason or file.
+    #[must_use]
+    pub fn warning(&self) -> String {
+        match (self.kind, &self.detail) {
+            (ConflictKind::Package, Some(reason)) => format!(
+                "{} conflicts with {} ({reason})",
+                self.package, self.conflicts_with
+            ),
+            (ConflictKind::Package, None) => {
+                format!("{} conflicts with {}", self.package, self.conflicts_with)
+            }
+            (ConflictKind::File, detail) => format!(
+                "{}: {} exists in {}",
+                self.package,
+                detail.as_deref().unwrap_or("file"),
+                self.conflicts_with
+            ),
+        }
+    }
+}
+
 /// What: Comprehensive dataset backing the Preflight Summary tab.
 ///
 /// Inputs:
@@ -690,6 +760,17 @@ pub struct PreflightSummaryData {
     pub summary_warnings: Vec<String>,
     /// Notes summarizing key items in the plan.
     pub summary_notes: Vec<String>,
+    /// Installed dependencies that become orphaned and are removed by cascade modes (`-Rs`/`-Rns`).
+    ///
+    /// Only populated for Remove actions; the UI shows them when the cascade mode removes orphans.
+    #[serde(default)]
+    pub orphaned_dependencies: Vec<String>,
+    /// Package and file conflicts reported by the dry-run conflict check (Install only).
+    #[serde(default)]
+    pub conflicts: Vec<ConflictInfo>,
+    /// Notes for alpm hooks (e.g. mkinitcpio, grub) the transaction will trigger.
+    #[serde(default)]
+    pub hook_notes: Vec<String>,
 }
 
 /// What: Captures all dialog state for the various modal overlays presented in
@@ -751,6 +832,45 @@ pub enum Modal {
         /// Preflight header chips to restore [`handle_proceed_install`] context.
         header_chips: PreflightHeaderChips,
     },
+    /// Guidance shown when an AUR install is about to start but no AUR helper (paru/yay) is found.
+    AurHelperMissing {
+        /// Full install set that was about to be executed.
+        items: Vec<PackageItem>,
+        /// Preflight header chips to carry into a pacman-only install.
+        header_chips: PreflightHeaderChips,
+        /// Password collected before execution, reused for a pacman-only install.
+        password: Option<crate::state::SecureString>,
+    },
+    /// Reminder after a transaction created new `.pacnew`/`.pacsave` files.
+    PacnewReminder {
+        /// Newly created conflict file paths, sorted.
+        paths: Vec<String>,
+        /// Scroll offset (lines) of the path list.
+        scroll: u16,
+    },
+    /// Fuzzy launcher listing global actions (toggles, Options items, sort, news).
+    CommandPalette {
+        /// Text typed to filter the actions.
+        query: String,
+        /// Highlighted index into the filtered actions.
+        selected: usize,
+    },
+    /// Browser for the persisted activity log of completed package operations.
+    ActivityLog {
+        /// Log entries, newest first.
+        entries: Vec<crate::app::ActivityEntry>,
+        /// Scroll offset (entries) of the list.
+        scroll: u16,
+    },
+    /// Picker for named install-list profiles.
+    InstallProfiles {
+        /// Saved profile names, sorted.
+        profiles: Vec<String>,
+        /// Highlighted profile index.
+        selected: usize,
+        /// Name typed for saving the current install list.
+        name_input: String,
+    },
     /// Post full repo-apply: foreign packages that share a name with the new sync repository.
     ForeignRepoOverlap {
         /// Repository that was applied (for copy and `pacman -Sl`).
@@ -1199,4 +1319,40 @@ mod tests {
             cached_reverse_deps_report: None,
         };
     }
+
+    #[test]
+    /// What: Confirm the helper, reminder, log, palette, and profile modals construct.
+    ///
+    /// Inputs:
+    /// - No external inputs; instantiates each variant directly inside the test.
+    ///
+    /// Output:
+    /// - Ensures these variant constructors remain stable.
+    ///
+    /// Details:
+    /// - Split from `modal_default_and_variants_construct` to keep each test short.
+    fn modal_feature_variants_construct() {
+        let _ = super::Modal::AurHelperMissing {
+            items: Vec::new(),
+            header_chips: super::PreflightHeaderChips::default(),
+            password: None,
+        };
+        let _ = super::Modal::PacnewReminder {
+            paths: vec!["/etc/pacman.conf.pacnew".into()],
+            scroll: 0,
+        };
+        let _ = super::Modal::ActivityLog {
+            entries: Vec::new(),
+            scroll: 0,
+        };
+        let _ = super::Modal::CommandPalette {
+            query: "sort".into(),
+            selected: 0,
+        };
+        let _ = super::Modal::InstallProfiles {
+            profiles: vec!["laptop".into()],
+            selected: 0,
+            name_input: String::new(),
+        };
+    }
 }
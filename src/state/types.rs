@@ -497,6 +497,18 @@ pub struct SearchResults {
     pub items: Vec<PackageItem>,
 }
 
+/// Sort mode and Results filters saved with a recent search and restored when it is re-run.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RecentViewState {
+    /// Sort mode as its `settings.conf` key (e.g. `best_matches`).
+    pub sort_mode: String,
+    /// Built-in Results filter toggles keyed by name (e.g. `aur`, `core`, `only_orphaned`).
+    pub filters: std::collections::BTreeMap<String, bool>,
+    /// Dynamic `repos.conf` filter toggles keyed by canonical `results_filter` id.
+    #[serde(default)]
+    pub dynamic_filters: std::collections::BTreeMap<String, bool>,
+}
+
 /// What: Request payload to run PKGBUILD static checks.
 #[derive(Clone, Debug)]
 pub struct PkgbuildCheckRequest {
@@ -532,6 +544,8 @@ pub enum SortMode {
     AurPopularityThenOfficial,
     /// Best matches: Relevance by name to current query, then repo order, then name.
     BestMatches,
+    /// Installed size (largest first) for the installed-only view; unknown sizes last.
+    InstalledSize,
 }
 
 impl SortMode {
@@ -548,6 +562,7 @@ impl SortMode {
             Self::RepoThenName => "alphabetical",
             Self::AurPopularityThenOfficial => "aur_popularity",
             Self::BestMatches => "best_matches",
+            Self::InstalledSize => "installed_size",
         }
     }
     /// Parse a sort mode from its settings key or legacy aliases.
@@ -564,6 +579,7 @@ impl SortMode {
             "alphabetical" | "repo_then_name" | "pacman" => Some(Self::RepoThenName),
             "aur_popularity" | "popularity" => Some(Self::AurPopularityThenOfficial),
             "best_matches" | "relevance" => Some(Self::BestMatches),
+            "installed_size" | "size" => Some(Self::InstalledSize),
             _ => None,
         }
     }
@@ -616,6 +632,39 @@ impl InstalledPackagesMode {
     }
 }
 
+#[cfg(test)]
+impl PackageItem {
+    /// What: Build a package fixture for unit tests.
+    ///
+    /// Inputs:
+    /// - `name`: Package name
+    /// - `repo`: Repository name; `"aur"` yields an AUR package
+    ///
+    /// Output:
+    /// - `PackageItem` at version `1.0-1` with an empty description and no status flags
+    ///
+    /// Details:
+    /// - Official packages are `x86_64`; override other fields with struct update syntax.
+    pub(crate) fn test_item(name: &str, repo: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            version: "1.0-1".to_string(),
+            description: String::new(),
+            source: if repo == "aur" {
+                Source::Aur
+            } else {
+                Source::Official {
+                    repo: repo.to_string(),
+                    arch: "x86_64".to_string(),
+                }
+            },
+            popularity: None,
+            out_of_date: None,
+            orphaned: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{InstalledPackagesMode, SortMode};
@@ -661,6 +710,11 @@ mod tests {
             SortMode::from_config_key("relevance"),
             Some(SortMode::BestMatches)
         );
+        assert_eq!(
+            SortMode::from_config_key("installed_size"),
+            Some(SortMode::InstalledSize)
+        );
+        assert_eq!(SortMode::InstalledSize.as_config_key(), "installed_size");
         assert_eq!(SortMode::from_config_key("unknown"), None);
     }
 
@@ -772,6 +826,41 @@ pub enum ArchStatusColor {
     IncidentSevereToday,
 }
 
+/// Background fetch categories that can be retried on demand after a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FetchCategory {
+    /// Arch Linux news feed (archlinux.org).
+    News,
+    /// Security advisories (security.archlinux.org).
+    Advisories,
+    /// Available package updates check.
+    Updates,
+    /// Arch infrastructure status (status.archlinux.org).
+    Status,
+}
+
+impl FetchCategory {
+    /// What: Short lowercase name used in toasts and logs.
+    ///
+    /// Output:
+    /// - `news`, `advisories`, `updates`, or `status`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::News => "news",
+            Self::Advisories => "advisories",
+            Self::Updates => "updates",
+            Self::Status => "status",
+        }
+    }
+
+    /// What: Whether the category is served by archlinux.org and shares its request backoff.
+    #[must_use]
+    pub const fn uses_archlinux_backoff(self) -> bool {
+        matches!(self, Self::News | Self::Advisories)
+    }
+}
+
 /// Which UI pane currently has keyboard focus.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
@@ -794,6 +883,15 @@ pub enum RightPaneFocus {
     Remove,
 }
 
+/// Column border of the middle row that can be dragged to resize panes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnBorder {
+    /// Between the Recent (left) and Search (center) columns.
+    LeftCenter,
+    /// Between the Search (center) and Install (right) columns.
+    CenterRight,
+}
+
 /// Row model for the "TUI Optional Deps" modal/list.
 /// Each row represents a concrete package candidate such as an editor,
 /// terminal, clipboard tool, mirror updater, or AUR helper.
@@ -914,6 +1012,21 @@ pub struct AurComment {
     pub pinned: bool,
 }
 
+/// What: One page of AUR comments as fetched by the comments worker.
+///
+/// Details:
+/// - `offset` is the page cursor the request used (`0` for the first page).
+/// - Pinned comments are repeated on every AUR page; only the first page keeps them.
+#[derive(Clone, Debug, Default)]
+pub struct AurCommentsPage {
+    /// Comment offset this page starts at.
+    pub offset: usize,
+    /// Comments on this page, pinned first then newest first.
+    pub comments: Vec<AurComment>,
+    /// Whether older comments exist beyond this page.
+    pub has_more: bool,
+}
+
 /// Helper function for serde to skip serializing false boolean values.
 #[allow(clippy::trivially_copy_pass_by_ref)]
 const fn is_false(b: &bool) -> bool {
@@ -5,8 +5,11 @@
 //! - `theme_loader`: Theme loading and parsing
 //! - `settings_save`: Functions to persist settings changes
 //! - `settings_ensure`: Settings initialization and migration
+//! - `export`: Effective-configuration snapshot
 //! - `tests`: Test module
 
+/// Effective-configuration snapshot for `--export-config`.
+pub mod export;
 /// Result-returning, dry-run-aware config patch foundation.
 pub mod patch;
 /// Static schema describing editable config keys.
@@ -29,6 +32,9 @@ pub use skeletons::{REPOS_SKELETON_CONTENT, THEME_SKELETON_CONTENT};
 // Re-export theme loading functions
 pub use theme_loader::{try_load_theme_from_content, try_load_theme_with_diagnostics};
 
+// Re-export the effective-config snapshot
+pub use export::{EffectiveConfig, effective_config};
+
 // Re-export the patch foundation and editable schema (Phase 0).
 pub use patch::{
     ConfigFile, ConfigWriteError, PatchOutcome, PatchRequest, patch_key, resolved_config_path,
@@ -41,10 +47,11 @@ pub use schema::{
 
 // Re-export settings save functions
 pub use settings_save::{
-    save_app_start_mode, save_fuzzy_search, save_mirror_count, save_news_filter_installed_only,
-    save_news_filter_show_advisories, save_news_filter_show_arch_news,
-    save_news_filter_show_aur_comments, save_news_filter_show_aur_updates,
-    save_news_filter_show_pkg_updates, save_news_filters_collapsed, save_news_max_age_days,
+    save_app_start_mode, save_comments_visible, save_fuzzy_search, save_layout_pcts,
+    save_mirror_count, save_news_filter_installed_only, save_news_filter_show_advisories,
+    save_news_filter_show_arch_news, save_news_filter_show_aur_comments,
+    save_news_filter_show_aur_updates, save_news_filter_show_pkg_updates,
+    save_news_filters_collapsed, save_news_max_age_days, save_pkgb_visible,
     save_results_filter_show_canonical, save_scan_do_clamav, save_scan_do_custom,
     save_scan_do_semgrep, save_scan_do_shellcheck, save_scan_do_sleuth, save_scan_do_trivy,
     save_scan_do_virustotal, save_selected_countries, save_show_install_pane,
@@ -0,0 +1,159 @@
+//! Snapshot of the effective (merged) configuration for bug reports and `--export-config`.
+//!
+//! Values use the same strings `settings.conf` and `keybinds.conf` accept, so an exported
+//! snapshot can be pasted back into the config files or applied with
+//! [`EffectiveConfig::apply_to`].
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use crate::state::config_editor::{
+    chord_to_canonical_string, keybind_chords_for_key, keybind_chords_for_key_mut,
+};
+use crate::theme::config::schema::EDITABLE_KEYBINDS;
+use crate::theme::config::settings_ensure::get_setting_value;
+use crate::theme::config::skeletons::SETTINGS_SKELETON_CONTENT;
+use crate::theme::parsing::parse_key_chord;
+use crate::theme::types::Settings;
+
+/// Effective settings, keymap, and locale after defaults, files, and normalization are merged.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EffectiveConfig {
+    /// Locale the UI resolves to (e.g. `en-US`), after system detection and fallbacks.
+    pub locale: String,
+    /// `settings.conf` keys (normalized) to their effective values.
+    pub settings: BTreeMap<String, String>,
+    /// `keybinds.conf` keys to their bound chords; an empty list means unbound.
+    pub keybinds: BTreeMap<String, Vec<String>>,
+}
+
+impl EffectiveConfig {
+    /// What: Apply the snapshot onto `settings`, as if it had been read from the config files.
+    ///
+    /// Inputs:
+    /// - `settings`: Settings to overwrite (typically `Settings::default()`).
+    ///
+    /// Output:
+    /// - None; unknown keys and unparsable chords are ignored like in the file parsers.
+    ///
+    /// Details:
+    /// - Settings go through the `settings.conf` parser and normalization; keybinds replace the
+    ///   whole chord list so multi-chord actions survive the round trip.
+    pub fn apply_to(&self, settings: &mut Settings) {
+        let content = self
+            .settings
+            .iter()
+            .fold(String::new(), |mut out, (key, value)| {
+                let _ = writeln!(out, "{key} = {value}");
+                out
+            });
+        crate::theme::settings::apply_settings_content(&content, settings);
+        for (key, chords) in &self.keybinds {
+            if let Some(target) = keybind_chords_for_key_mut(key, &mut settings.keymap) {
+                *target = chords.iter().filter_map(|c| parse_key_chord(c)).collect();
+            }
+        }
+    }
+}
+
+/// What: Build the effective configuration snapshot for `prefs`.
+///
+/// Inputs:
+/// - `prefs`: Loaded settings (usually [`crate::theme::settings`]).
+/// - `locale`: Resolved UI locale.
+///
+/// Output:
+/// - Every `settings.conf` key from the skeleton and every editable keybind with its value.
+///
+/// Details:
+/// - Keys without an in-memory counterpart report the skeleton default, as when seeding a
+///   missing key into `settings.conf`.
+#[must_use]
+pub fn effective_config(prefs: &Settings, locale: &str) -> EffectiveConfig {
+    let mut settings = BTreeMap::new();
+    for line in SETTINGS_SKELETON_CONTENT.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') || trimmed.starts_with("//") {
+            continue;
+        }
+        let Some((raw_key, skeleton_value)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let key = raw_key.trim().to_lowercase().replace(['.', '-', ' '], "_");
+        let value = get_setting_value(&key, skeleton_value.trim().to_string(), prefs);
+        settings.insert(key, value);
+    }
+    let keybinds = EDITABLE_KEYBINDS
+        .iter()
+        .map(|entry| {
+            let chords = keybind_chords_for_key(entry.key, &prefs.keymap)
+                .iter()
+                .map(chord_to_canonical_string)
+                .collect();
+            (entry.key.to_string(), chords)
+        })
+        .collect();
+    EffectiveConfig {
+        locale: locale.to_string(),
+        settings,
+        keybinds,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    /// What: Verify an exported snapshot round-trips through TOML and JSON back into `Settings`.
+    ///
+    /// Inputs:
+    /// - Settings with non-default values, a custom marker, and a two-chord keybind.
+    ///
+    /// Output:
+    /// - Both formats deserialize to the same snapshot, and applying it onto defaults yields
+    ///   settings that export identically.
+    fn export_round_trips_into_settings() {
+        let mut prefs = Settings {
+            layout_left_pct: 30,
+            layout_center_pct: 40,
+            layout_right_pct: 30,
+            fuzzy_search: true,
+            search_startup_mode: true,
+            mirror_count: 7,
+            clipboard_suffix: "-- via pacsea".to_string(),
+            package_marker: crate::theme::PackageMarker::Ascii,
+            ..Settings::default()
+        };
+        prefs.keymap.help_overlay = vec![
+            crate::theme::KeyChord {
+                code: KeyCode::F(1),
+                mods: KeyModifiers::empty(),
+            },
+            crate::theme::KeyChord {
+                code: KeyCode::Char('h'),
+                mods: KeyModifiers::CONTROL,
+            },
+        ];
+        let exported = effective_config(&prefs, "de-DE");
+        assert_eq!(exported.settings["fuzzy_search"], "true");
+        assert_eq!(exported.keybinds["keybind_help"], ["F1", "Ctrl+h"]);
+
+        let toml_text = toml::to_string(&exported).expect("serialize TOML");
+        let from_toml: EffectiveConfig = toml::from_str(&toml_text).expect("parse TOML");
+        assert_eq!(from_toml, exported);
+        let json_text = serde_json::to_string(&exported).expect("serialize JSON");
+        let from_json: EffectiveConfig = serde_json::from_str(&json_text).expect("parse JSON");
+        assert_eq!(from_json, exported);
+
+        let mut restored = Settings::default();
+        from_toml.apply_to(&mut restored);
+        assert!(restored.fuzzy_search);
+        assert!(restored.search_startup_mode);
+        assert_eq!(restored.mirror_count, 7);
+        assert_eq!(restored.package_marker, crate::theme::PackageMarker::Ascii);
+        assert_eq!(restored.keymap.help_overlay, prefs.keymap.help_overlay);
+        assert_eq!(effective_config(&restored, "de-DE"), exported);
+    }
+}
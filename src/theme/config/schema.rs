@@ -173,6 +173,7 @@ pub const EDITABLE_SETTINGS: &[EditableSetting] = &[
                 "alphabetical",
                 "official_first",
                 "aur_popularity",
+                "installed_size",
             ],
         },
         reload: ReloadBehavior::AppliesOnSave,
@@ -354,6 +355,14 @@ pub const EDITABLE_SETTINGS: &[EditableSetting] = &[
         reload: ReloadBehavior::AppliesOnSave,
         sensitivity: Sensitivity::Normal,
     },
+    EditableSetting {
+        key: "search_debounce_ms",
+        aliases: &[],
+        file: ConfigFile::Settings,
+        kind: ValueKind::IntRange { min: 0, max: 5000 },
+        reload: ReloadBehavior::AppliesOnSave,
+        sensitivity: Sensitivity::Normal,
+    },
     EditableSetting {
         key: "installed_packages_mode",
         aliases: &[],
@@ -602,11 +611,35 @@ pub const EDITABLE_SETTINGS: &[EditableSetting] = &[
         aliases: &[],
         file: ConfigFile::Settings,
         kind: ValueKind::Enum {
-            choices: &["full_line", "front", "end"],
+            choices: &["full_line", "front", "end", "ascii", "nerd_font", "custom"],
         },
         reload: ReloadBehavior::AutoReload,
         sensitivity: Sensitivity::Normal,
     },
+    EditableSetting {
+        key: "package_marker_install",
+        aliases: &[],
+        file: ConfigFile::Settings,
+        kind: ValueKind::String,
+        reload: ReloadBehavior::AutoReload,
+        sensitivity: Sensitivity::Normal,
+    },
+    EditableSetting {
+        key: "package_marker_remove",
+        aliases: &[],
+        file: ConfigFile::Settings,
+        kind: ValueKind::String,
+        reload: ReloadBehavior::AutoReload,
+        sensitivity: Sensitivity::Normal,
+    },
+    EditableSetting {
+        key: "package_marker_downgrade",
+        aliases: &[],
+        file: ConfigFile::Settings,
+        kind: ValueKind::String,
+        reload: ReloadBehavior::AutoReload,
+        sensitivity: Sensitivity::Normal,
+    },
     EditableSetting {
         key: "locale",
         aliases: &[],
@@ -686,6 +719,10 @@ pub const EDITABLE_KEYBINDS: &[EditableSetting] = &[
         "keybind_reload_config",
         &["keybind_reload_theme", "keybind_reload"],
     ),
+    keybind_entry("keybind_retry_failed_fetches", &["keybind_retry_fetches"]),
+    keybind_entry("keybind_command_palette", &["keybind_palette"]),
+    keybind_entry("keybind_copy_package_name", &["keybind_copy_name"]),
+    keybind_entry("keybind_copy_package_details", &["keybind_copy_details"]),
     keybind_entry("keybind_exit", &["keybind_quit"]),
     keybind_entry(
         "keybind_show_pkgbuild",
@@ -713,6 +750,8 @@ pub const EDITABLE_KEYBINDS: &[EditableSetting] = &[
     ),
     keybind_entry("keybind_pane_left", &[]),
     keybind_entry("keybind_pane_right", &[]),
+    keybind_entry("keybind_pane_up", &[]),
+    keybind_entry("keybind_pane_down", &[]),
     keybind_entry("keybind_toggle_fuzzy", &["keybind_fuzzy_toggle"]),
     // ── Search pane ──────────────────────────────────────────────────
     keybind_entry("keybind_search_move_up", &[]),
@@ -721,10 +760,16 @@ pub const EDITABLE_KEYBINDS: &[EditableSetting] = &[
     keybind_entry("keybind_search_page_down", &[]),
     keybind_entry("keybind_search_add", &[]),
     keybind_entry("keybind_search_install", &[]),
+    keybind_entry("keybind_search_quick_install", &["keybind_quick_install"]),
     keybind_entry("keybind_search_focus_left", &[]),
     keybind_entry("keybind_search_focus_right", &[]),
     keybind_entry("keybind_search_backspace", &[]),
     keybind_entry("keybind_search_insert_clear", &[]),
+    keybind_entry("keybind_search_toggle_variant", &["keybind_toggle_variant"]),
+    keybind_entry(
+        "keybind_search_accept_suggestion",
+        &["keybind_accept_suggestion"],
+    ),
     // ── Search normal mode ───────────────────────────────────────────
     keybind_entry("keybind_search_normal_toggle", &[]),
     keybind_entry("keybind_search_normal_insert", &[]),
@@ -758,12 +803,18 @@ pub const EDITABLE_KEYBINDS: &[EditableSetting] = &[
     keybind_entry("keybind_install_find", &[]),
     keybind_entry("keybind_install_to_search", &[]),
     keybind_entry("keybind_install_focus_left", &[]),
+    keybind_entry("keybind_install_profiles", &[]),
+    keybind_entry("keybind_install_reorder_up", &[]),
+    keybind_entry("keybind_install_reorder_down", &[]),
+    keybind_entry("keybind_install_copy_command", &[]),
     // ── News modal ───────────────────────────────────────────────────
     keybind_entry("keybind_news_mark_read", &[]),
     keybind_entry("keybind_news_mark_all_read", &[]),
     keybind_entry("keybind_news_feed_mark_read", &[]),
     keybind_entry("keybind_news_feed_mark_unread", &[]),
     keybind_entry("keybind_news_feed_toggle_read", &[]),
+    keybind_entry("keybind_news_feed_export_markdown", &[]),
+    keybind_entry("keybind_news_feed_mark_all_read", &[]),
 ];
 
 /// What: Phase-3 set of editable theme color rows backed by `theme.conf`.
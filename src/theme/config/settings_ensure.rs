@@ -29,6 +29,30 @@ fn bool_to_string(value: bool) -> String {
     }
 }
 
+/// What: Convert an optional network timeout override to a config string.
+///
+/// Inputs:
+/// - `value`: Override, or `None` for the built-in default
+///
+/// Output:
+/// - `"CONNECT,MAX"` or an empty string when unset
+fn timeout_to_string(value: Option<crate::util::CurlTimeouts>) -> String {
+    value
+        .map(crate::util::CurlTimeouts::to_config_value)
+        .unwrap_or_default()
+}
+
+/// What: Convert an optional seconds value to a config string.
+///
+/// Inputs:
+/// - `value`: Seconds, or `None` for the built-in default
+///
+/// Output:
+/// - The number of seconds or an empty string when unset
+fn optional_secs_to_string(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
 /// What: Convert an optional integer to a config string.
 ///
 /// Inputs:
@@ -79,16 +103,23 @@ fn get_app_value(key: &str, prefs: &Settings) -> Option<String> {
         "show_recent_pane" | "show_search_history_pane" => {
             Some(bool_to_string(prefs.show_recent_pane))
         }
+        "recent_show_result_counts" => Some(bool_to_string(prefs.recent_show_result_counts)),
         "show_install_pane" => Some(bool_to_string(prefs.show_install_pane)),
+        "installed_only_default_focus" => Some(prefs.installed_only_default_focus.clone()),
+        "installed_only_show_size" => Some(bool_to_string(prefs.installed_only_show_size)),
+        "mode_switch_search_input" => Some(prefs.mode_switch_search_input.clone()),
+        "results_show_numbers" => Some(bool_to_string(prefs.results_show_numbers)),
+        "results_repo_label_style" => Some(prefs.results_repo_label_style.clone()),
+        "comments_max_display" => Some(prefs.comments_max_display.to_string()),
+        "comments_order" => Some(prefs.comments_order.clone()),
+        "escape_quits_from_search" => Some(bool_to_string(prefs.escape_quits_from_search)),
+        "details_description_max_lines" => Some(prefs.details_description_max_lines.to_string()),
+        "mouse_scroll_lines" => Some(prefs.mouse_scroll_lines.to_string()),
         "show_keybinds_footer" => Some(bool_to_string(prefs.show_keybinds_footer)),
-        "package_marker" => {
-            let marker_str = match prefs.package_marker {
-                crate::theme::types::PackageMarker::FullLine => "full_line",
-                crate::theme::types::PackageMarker::Front => "front",
-                crate::theme::types::PackageMarker::End => "end",
-            };
-            Some(marker_str.to_string())
-        }
+        "package_marker" => Some(prefs.package_marker.as_key().to_string()),
+        "package_marker_install" => Some(prefs.package_marker_install.clone()),
+        "package_marker_remove" => Some(prefs.package_marker_remove.clone()),
+        "package_marker_downgrade" => Some(prefs.package_marker_downgrade.clone()),
         "app_start_mode" => {
             let mode = if prefs.start_in_news {
                 "news"
@@ -98,6 +129,42 @@ fn get_app_value(key: &str, prefs: &Settings) -> Option<String> {
             Some(mode.to_string())
         }
         "skip_preflight" => Some(bool_to_string(prefs.skip_preflight)),
+        "reinstall_confirm_threshold" => Some(prefs.reinstall_confirm_threshold.to_string()),
+        "reinstall_confirm_skip_sources" => Some(prefs.reinstall_confirm_skip_sources.clone()),
+        "preflight_aur_dep_depth" => Some(prefs.preflight_aur_dep_depth.to_string()),
+        "preflight_metadata_chunk_size" => Some(prefs.preflight_metadata_chunk_size.to_string()),
+        "preflight_metadata_parallelism" => Some(prefs.preflight_metadata_parallelism.to_string()),
+        "preflight_risk_weight_core" => Some(prefs.preflight_risk_weights.core.to_string()),
+        "preflight_risk_weight_major_bump" => {
+            Some(prefs.preflight_risk_weights.major_bump.to_string())
+        }
+        "preflight_risk_weight_aur" => Some(prefs.preflight_risk_weights.aur.to_string()),
+        "preflight_risk_weight_pacnew" => Some(prefs.preflight_risk_weights.pacnew.to_string()),
+        "preflight_risk_weight_services" => Some(prefs.preflight_risk_weights.services.to_string()),
+        "preflight_risk_weight_dependent" => {
+            Some(prefs.preflight_risk_weights.dependent.to_string())
+        }
+        "preflight_risk_weight_downgrade_conflict" => {
+            Some(prefs.preflight_risk_weights.downgrade_conflict.to_string())
+        }
+        "preflight_risk_weight_conflict" => Some(prefs.preflight_risk_weights.conflict.to_string()),
+        "preflight_risk_weight_sandbox" => Some(prefs.preflight_risk_weights.sandbox.to_string()),
+        "preflight_risk_high_threshold" => {
+            Some(prefs.preflight_risk_weights.high_threshold.to_string())
+        }
+        "preflight_extra_critical_packages" => {
+            Some(prefs.preflight_extra_critical_packages.join(", "))
+        }
+        "downgrade_conflict_check" => Some(bool_to_string(prefs.downgrade_conflict_check)),
+        "completion_notify" => Some(prefs.completion_notify.clone()),
+        "expert_mode" => Some(bool_to_string(prefs.expert_mode)),
+        "pacnew_reminder" => Some(bool_to_string(prefs.pacnew_reminder)),
+        "ui_size_units" => Some(prefs.size_units.clone()),
+        "pkgb_visible" => Some(bool_to_string(prefs.pkgb_visible)),
+        "comments_visible" => Some(bool_to_string(prefs.comments_visible)),
+        "remember_viewers" => Some(bool_to_string(prefs.remember_viewers)),
+        "install_group_by_source" => Some(bool_to_string(prefs.install_group_by_source)),
+        "exec_log_follow" => Some(bool_to_string(prefs.exec_log_follow)),
         "search_startup_mode" => {
             let mode = if prefs.search_startup_mode {
                 "normal_mode"
@@ -106,11 +173,35 @@ fn get_app_value(key: &str, prefs: &Settings) -> Option<String> {
             };
             Some(mode.to_string())
         }
+        "auto_select_first_result" => Some(bool_to_string(prefs.auto_select_first_result)),
+        "fuzzy_search" => Some(bool_to_string(prefs.fuzzy_search)),
+        "installed_packages_mode" => {
+            Some(prefs.installed_packages_mode.as_config_key().to_string())
+        }
+        "use_passwordless_sudo" => Some(bool_to_string(prefs.use_passwordless_sudo)),
+        "get_announcement" => Some(bool_to_string(prefs.get_announcement)),
+        "search_normalize" => Some(prefs.search_normalize.clone()),
+        "search_suggestions" => Some(bool_to_string(prefs.search_suggestions)),
+        "find_match_description" => Some(prefs.find_match_description.clone()),
+        "search_repo_priority" => Some(prefs.repo_priority.join(", ")),
+        "search_default_arch" => Some(prefs.default_arch.clone()),
+        "search_debounce_ms" => Some(prefs.search_debounce_ms.to_string()),
         "locale" => Some(prefs.locale.clone()),
         "preferred_terminal" => Some(prefs.preferred_terminal.clone()),
         "privilege_tool" => Some(prefs.privilege_mode.as_config_key().to_string()),
         "auth_mode" => Some(prefs.auth_mode.as_config_key().to_string()),
         "use_terminal_theme" => Some(bool_to_string(prefs.use_terminal_theme)),
+        "theme_file" => Some(prefs.theme_file.clone()),
+        "audit_log_commands" => Some(bool_to_string(prefs.audit_log_commands)),
+        "activity_log_max_lines" => Some(prefs.activity_log_max_lines.to_string()),
+        "network_connect_timeout_secs" => {
+            Some(optional_secs_to_string(prefs.network_connect_timeout_secs))
+        }
+        "network_max_time_secs" => Some(optional_secs_to_string(prefs.network_max_time_secs)),
+        "network_timeout_news" => Some(timeout_to_string(prefs.network_timeout_news)),
+        "network_timeout_aur_rpc" => Some(timeout_to_string(prefs.network_timeout_aur_rpc)),
+        "network_timeout_pkgbuild" => Some(timeout_to_string(prefs.network_timeout_pkgbuild)),
+        "network_timeout_status" => Some(timeout_to_string(prefs.network_timeout_status)),
         "aur_vote_enabled" => Some(bool_to_string(prefs.aur_vote_enabled)),
         "aur_vote_ssh_timeout_seconds" => Some(prefs.aur_vote_ssh_timeout_seconds.to_string()),
         "aur_vote_ssh_command" => Some(prefs.aur_vote_ssh_command.clone()),
@@ -131,6 +222,10 @@ fn get_mirror_value(key: &str, prefs: &Settings) -> Option<String> {
         "selected_countries" => Some(prefs.selected_countries.clone()),
         "mirror_count" => Some(prefs.mirror_count.to_string()),
         "aur_helper" => Some(prefs.aur_helper.clone()),
+        "aur_helper_install_check" => Some(bool_to_string(prefs.aur_helper_install_check)),
+        "install_mode" => Some(prefs.install_mode.clone()),
+        "install_aur_parallel_jobs" => Some(prefs.install_aur_parallel_jobs.to_string()),
+        "upgrade_command" => Some(prefs.upgrade_command.clone()),
         "virustotal_api_key" => Some(prefs.virustotal_api_key.clone()),
         _ => None,
     }
@@ -148,6 +243,9 @@ fn get_news_value(key: &str, prefs: &Settings) -> Option<String> {
     match key {
         "news_read_symbol" => Some(prefs.news_read_symbol.clone()),
         "news_unread_symbol" => Some(prefs.news_unread_symbol.clone()),
+        "news_details_fields" => Some(prefs.news_details_fields.clone()),
+        "news_details_compact" => Some(bool_to_string(prefs.news_details_compact)),
+        "news_remember_scroll" => Some(bool_to_string(prefs.news_remember_scroll)),
         "news_filter_show_arch_news" => Some(bool_to_string(prefs.news_filter_show_arch_news)),
         "news_filter_show_advisories" => Some(bool_to_string(prefs.news_filter_show_advisories)),
         "news_filter_show_pkg_updates" => Some(bool_to_string(prefs.news_filter_show_pkg_updates)),
@@ -173,6 +271,9 @@ fn get_news_value(key: &str, prefs: &Settings) -> Option<String> {
             Some(optional_int_to_string(prefs.startup_news_max_age_days))
         }
         "news_cache_ttl_days" => Some(prefs.news_cache_ttl_days.to_string()),
+        "news_arch_feed_url" => Some(prefs.news_arch_feed_url.clone()),
+        "news_advisory_url" => Some(prefs.news_advisory_url.clone()),
+        "news_max_parallel_slow_fetches" => Some(prefs.news_max_parallel_slow_fetches.to_string()),
         _ => None,
     }
 }
@@ -202,15 +303,18 @@ fn get_updates_value(key: &str, prefs: &Settings) -> Option<String> {
 ///
 /// Output:
 /// - Some(String) if key was handled, None otherwise
-fn get_scan_value(key: &str, _prefs: &Settings) -> Option<String> {
-    match key {
-        "scan_do_clamav" | "scan_do_trivy" | "scan_do_semgrep" | "scan_do_shellcheck"
-        | "scan_do_virustotal" | "scan_do_custom" | "scan_do_sleuth" => {
-            // Scan keys default to true
-            Some("true".to_string())
-        }
-        _ => None,
-    }
+fn get_scan_value(key: &str, prefs: &Settings) -> Option<String> {
+    let value = match key {
+        "scan_do_clamav" => prefs.scan_do_clamav,
+        "scan_do_trivy" => prefs.scan_do_trivy,
+        "scan_do_semgrep" => prefs.scan_do_semgrep,
+        "scan_do_shellcheck" => prefs.scan_do_shellcheck,
+        "scan_do_virustotal" => prefs.scan_do_virustotal,
+        "scan_do_custom" => prefs.scan_do_custom,
+        "scan_do_sleuth" => prefs.scan_do_sleuth,
+        _ => return None,
+    };
+    Some(bool_to_string(value))
 }
 
 /// What: Get PKGBUILD static-check related setting values for `ensure_settings_keys_present`.
@@ -247,7 +351,8 @@ fn get_pkgbuild_static_check_value(key: &str, prefs: &Settings) -> Option<String
 /// Details:
 /// - Delegates to category-specific functions to reduce complexity.
 /// - Mirrors the parsing architecture for consistency.
-fn get_setting_value(key: &str, skeleton_value: String, prefs: &Settings) -> String {
+/// - Also backs the effective-config export, so every skeleton key should resolve from `prefs`.
+pub(super) fn get_setting_value(key: &str, skeleton_value: String, prefs: &Settings) -> String {
     get_layout_value(key, prefs)
         .or_else(|| get_app_value(key, prefs))
         .or_else(|| get_mirror_value(key, prefs))
@@ -334,6 +334,32 @@ pub fn save_mirror_count(value: u16) {
     save_string_key("mirror_count", &value.to_string());
 }
 
+/// What: Persist the middle-row column split.
+///
+/// Inputs:
+/// - `left`/`center`/`right`: Column widths in percent (expected to sum to 100).
+///
+/// Output:
+/// - None.
+///
+/// Details:
+/// - Writes `layout_left_pct`, `layout_center_pct`, and `layout_right_pct` via `save_string_key`.
+pub fn save_layout_pcts(left: u16, center: u16, right: u16) {
+    save_string_key("layout_left_pct", &left.to_string());
+    save_string_key("layout_center_pct", &center.to_string());
+    save_string_key("layout_right_pct", &right.to_string());
+}
+
+/// Persist whether the PKGBUILD viewer opens on startup.
+pub fn save_pkgb_visible(value: bool) {
+    save_boolean_key("pkgb_visible", value);
+}
+
+/// Persist whether the AUR comments viewer opens on startup.
+pub fn save_comments_visible(value: bool) {
+    save_boolean_key("comments_visible", value);
+}
+
 /// Persist start mode (package/news).
 pub fn save_app_start_mode(start_in_news: bool) {
     let v = if start_in_news { "news" } else { "package" };
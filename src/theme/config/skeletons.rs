@@ -217,6 +217,28 @@ app_dry_run_default = false\n\
 show_search_history_pane = true\n\
 show_install_pane = true\n\
 show_keybinds_footer = true\n\
+# Show how many results each search history entry returned, e.g. \"ripgrep (3)\"\n\
+recent_show_result_counts = true\n\
+# Subpane focused when entering installed-only mode: remove or downgrade\n\
+installed_only_default_focus = remove\n\
+# Show each package's installed size in installed-only mode (sort by it via the Sort menu)\n\
+installed_only_show_size = true\n\
+# Search input when switching between package and news modes: preserve or clear\n\
+mode_switch_search_input = preserve\n\
+# Prefix result rows with their number; in normal mode type : then a number and Enter to jump\n\
+results_show_numbers = false\n\
+# Repository labels in results/details: full (extra), short (E), or icon\n\
+results_repo_label_style = full\n\
+# Maximum AUR comments shown at once; a \"load more\" line reveals the rest (0 = show all)\n\
+comments_max_display = 50\n\
+# AUR comment order: pinned_first (pinned on top, then newest) or newest_first\n\
+comments_order = pinned_first\n\
+# Escape from Search normal mode with nothing else open asks to quit; press Escape again to exit\n\
+escape_quits_from_search = false\n\
+# Collapse package descriptions longer than this many rows in the details pane (0 = never collapse)\n\
+details_description_max_lines = 4\n\
+# Lines scrolled per mouse-wheel notch in the details, PKGBUILD, and comments panes (minimum 1)\n\
+mouse_scroll_lines = 3\n\
 # Search input mode on startup\n\
 # Allowed values: insert_mode | normal_mode\n\
 # Default is insert_mode\n\
@@ -225,6 +247,25 @@ search_startup_mode = insert_mode\n\
 # When true, uses fuzzy matching (fzf-style) instead of substring search\n\
 # Default is false (normal substring search)\n\
 fuzzy_search = false\n\
+# Auto-select the first result when a new result set arrives and the previous selection is gone\n\
+# When false, the previous list position is kept instead\n\
+auto_select_first_result = true\n\
+# Query normalization before ranking and cache lookup: trim (trim + ignore case), collapse (also collapse inner spaces), or off\n\
+search_normalize = trim\n\
+# Show \"did you mean\" suggestions from official package names when a query has few or no results\n\
+search_suggestions = true\n\
+# Panes whose find (/) also matches package descriptions: install, remove, downgrade (comma list), all, or none\n\
+# Default: install (Remove/Downgrade match names only)\n\
+find_match_description = install\n\
+# Official repositories in ranking order (comma list, e.g. core, cachyos, extra)\n\
+# Unlisted repos rank after the listed ones but before AUR. Empty keeps core, extra, others, AUR.\n\
+search_repo_priority =\n\
+# Architecture official packages are ranked for: auto (uname -m) or a name such as x86_64, aarch64\n\
+# Packages built for other architectures are listed after host and \"any\" packages.\n\
+search_default_arch = auto\n\
+# Quiet time in milliseconds after typing stops before a search runs (0-5000, default 250)\n\
+# Lower it for instant results from a fast index; raise it on slow connections to send fewer AUR requests.\n\
+search_debounce_ms = 250\n\
 \n\
 # Installed packages filter mode\n\
 # Controls which packages are shown when viewing installed packages\n\
@@ -245,6 +286,54 @@ clipboard_suffix = Check PKGBUILD and source for suspicious and malicious activi
 # When true, Pacsea will bypass the Preflight confirmation modal and execute install/remove/downgrade actions immediately.\n\
 # Recommended to keep this false for safety unless you understand the risks of executing package operations directly.\n\
 skip_preflight = false\n\
+# Reinstall confirmation: only ask when at least this many already-installed packages are re-added (1 = always)\n\
+# Packages with an available update never count as reinstalls.\n\
+reinstall_confirm_threshold = 1\n\
+# Sources whose reinstalls never ask: comma list of aur, official, or repo names (e.g. extra). Empty = none\n\
+reinstall_confirm_skip_sources =\n\
+# Deps tab: expand AUR dependencies of AUR dependencies up to this many levels (0 = direct deps only, max 5)\n\
+# Each level costs extra AUR RPC calls, so keep this low.\n\
+preflight_aur_dep_depth = 0\n\
+# Summary tab: packages per batched `pacman -Si` call (keeps huge lists below argv limits)\n\
+preflight_metadata_chunk_size = 100\n\
+# Summary tab: how many batched `pacman -Si` calls may run at once\n\
+preflight_metadata_parallelism = 4\n\
+# Summary tab: extra packages treated as core/system packages (comma list, e.g. nvidia, grub, mkinitcpio)\n\
+preflight_extra_critical_packages =\n\
+# Summary tab: risk points per factor (0 disables a factor) and the score rated High\n\
+# Lower levels: 0 = Low, 1 up to the High threshold = Medium\n\
+preflight_risk_weight_core = 3\n\
+preflight_risk_weight_major_bump = 2\n\
+preflight_risk_weight_aur = 2\n\
+preflight_risk_weight_pacnew = 1\n\
+preflight_risk_weight_services = 1\n\
+# Points per installed package depending on a package being updated\n\
+preflight_risk_weight_dependent = 2\n\
+preflight_risk_weight_downgrade_conflict = 3\n\
+# Package/file conflicts reported by a dry-run `pacman -S --print`\n\
+preflight_risk_weight_conflict = 3\n\
+# AUR packages whose dependencies imply root filesystem or setuid access (dkms, grub, sudo, polkit, ...)\n\
+preflight_risk_weight_sandbox = 3\n\
+preflight_risk_high_threshold = 5\n\
+# Downgrades: warn when installed packages require a newer version than the downgrade target\n\
+downgrade_conflict_check = true\n\
+# Notify when a tracked install/remove finishes: off, bell (terminal bell), desktop (notify-send), or both\n\
+completion_notify = off\n\
+# Expert mode: preflight gets an `o` toggle showing the raw pacman/AUR helper output behind its summaries\n\
+expert_mode = false\n\
+# After a transaction, list newly created .pacnew/.pacsave files and offer to run pacdiff (pacman-contrib)\n\
+pacnew_reminder = true\n\
+# Units for package sizes in preflight, results, and details: binary (KiB/MiB, like pacman) or si (kB/MB)\n\
+ui_size_units = binary\n\
+# Open the PKGBUILD / AUR comments viewers on startup (after the first selection loads, if the pane is tall enough)\n\
+pkgb_visible = false\n\
+comments_visible = false\n\
+# Save viewer toggles to the two keys above so the next session restores them\n\
+remember_viewers = false\n\
+# Install pane: group official packages before AUR packages under headers with counts\n\
+install_group_by_source = false\n\
+# Install log: follow the newest output while it streams in (f pauses/resumes, Up/PgUp scroll back)\n\
+exec_log_follow = true\n\
 \n\
 # Passwordless sudo\n\
 # When true, Pacsea will use passwordless sudo for install operations if configured on the system.\n\
@@ -283,6 +372,16 @@ selected_countries = Worldwide\n\
 mirror_count = 20\n\
 # Preferred AUR helper for CLI operations: auto (detect, paru preferred), paru, or yay\n\
 aur_helper = auto\n\
+# Check for paru/yay right before an AUR install and show guidance when neither is found\n\
+aur_helper_install_check = true\n\
+# Install mode: standard (official + AUR) or pacman_only (never run an AUR helper; AUR installs are refused)\n\
+install_mode = standard\n\
+# Parallel AUR helper terminals for batch installs (1-16; 1 = one serial terminal).\n\
+# Only for independent packages: an AUR package that needs another one from the same batch may fail.\n\
+install_aur_parallel_jobs = 1\n\
+# Custom 'update all' command; empty runs {privilege} pacman -Syu --noconfirm, then {aur_helper} -Sua --noconfirm.\n\
+# Placeholders: {privilege} (sudo/doas) and {aur_helper} (paru/yay). Example: {privilege} pacman -Syu --noconfirm && {aur_helper} -Sua --devel --noconfirm\n\
+upgrade_command =\n\
 # Available countries (commented list; edit selected_countries above as needed):\n\
 # Worldwide\n\
 # Albania\n\
@@ -378,6 +477,12 @@ pkgbuild_checks_show_raw_output = false\n\
 app_start_mode = package\n\
 news_read_symbol = ✓\n\
 news_unread_symbol = ∘\n\
+# News details metadata: fields (date, source, severity, packages) in display order; omit to hide, 'none' hides all\n\
+news_details_fields = date, source, severity, packages\n\
+# Render news metadata on a single line (useful for small terminals)\n\
+news_details_compact = false\n\
+# Remember the article scroll position per news item while Pacsea runs (restored on reselect)\n\
+news_remember_scroll = true\n\
 news_filter_show_arch_news = true\n\
 news_filter_show_advisories = true\n\
 news_filter_show_pkg_updates = true\n\
@@ -405,6 +510,16 @@ startup_news_max_age_days = 7\n\
 # Reduces network requests on startup. Default is 14 days.\n\
 news_cache_ttl_days = 14\n\
 \n\
+# News source URLs\n\
+# Override the Arch news RSS feed and the security advisory Atom feed (e.g. to use a mirror).\n\
+# Leave empty for the built-in archlinux.org URLs; invalid values fall back to them with a warning.\n\
+news_arch_feed_url =\n\
+news_advisory_url =\n\
+\n\
+# How many slow news sources (Arch news, advisories) may be fetched from archlinux.org at once.\n\
+# 1 (default) fetches them one after another; 2 fetches both in parallel.\n\
+news_max_parallel_slow_fetches = 1\n\
+\n\
 # VirusTotal\n\
 # API key used for VirusTotal scans (optional)\n\
 virustotal_api_key = \n\
@@ -415,11 +530,18 @@ preferred_terminal = \n\
 \n\
 # Package selection marker\n\
 # Visual marker for packages added to Install/Remove/Downgrade lists.\n\
-# Allowed values: full_line | front | end\n\
+# Allowed values: full_line | front | end | ascii | nerd_font | custom\n\
 # - full_line: color the entire line\n\
 # - front: add marker at the front of the line (default)\n\
 # - end: add marker at the end of the line\n\
-package_marker = front
+# - ascii: front marker using only ASCII labels ([+], [-], [v])\n\
+# - nerd_font: front marker using Nerd Font glyphs (needs a patched font)\n\
+# - custom: front marker using the package_marker_* labels below\n\
+# Non-ASCII labels fall back to ASCII on the Linux console or a non-UTF-8 locale.\n\
+package_marker = front\n\
+package_marker_install = [+]\n\
+package_marker_remove = [-]\n\
+package_marker_downgrade = [↓]
 
 # Language / Locale
 # Locale code for translations (e.g., \"en-US\", \"de-DE\").
@@ -447,6 +569,10 @@ get_announcement = true\n\
 # and the terminal is supported (alacritty, kitty, konsole, ghostty, wezterm,\n\
 # wezterm-gui, xterm, gnome-terminal, xfce-terminal, tilix, mate-terminal).\n\
 use_terminal_theme = false\n\
+# Custom palette file (TOML or JSON) with all 16 theme colors, e.g. ~/.config/pacsea/palette.toml\n\
+# Keys match theme.conf (background_base, text_primary, ...); values are \"#RRGGBB\", \"R,G,B\", or [R, G, B].\n\
+# Relative paths resolve against the config directory. Empty uses theme.conf; an invalid file is ignored.\n\
+theme_file =\n\
 \n\
 # AUR Voting via SSH\n\
 # Vote/unvote AUR packages directly from Pacsea using SSH.\n\
@@ -457,7 +583,39 @@ aur_vote_enabled = true\n\
 # SSH connect timeout in seconds (default: 10)\n\
 aur_vote_ssh_timeout_seconds = 10\n\
 # SSH binary to use (default: ssh). Override for non-standard setups.\n\
-aur_vote_ssh_command = ssh\n";
+aur_vote_ssh_command = ssh\n\
+\n\
+# Command audit log\n\
+# When true, every command Pacsea spawns (install/remove/update/downgrade executor runs and\n\
+# external terminals) is appended with a timestamp to logs/audit.log. Passwords are redacted.\n\
+# Default is false.\n\
+audit_log_commands = false\n\
+\n\
+# Activity log\n\
+# Completed installs, removals, downgrades, and system updates are appended to\n\
+# lists/activity_log.jsonl. Keep at most this many entries (0 = unlimited). Default is 1000.\n\
+activity_log_max_lines = 1000\n\
+\n\
+# Global network timeouts\n\
+# Seconds for curl's --connect-timeout and --max-time on fetches without a per-source value.\n\
+# Leave empty to keep the built-in defaults: connect 30, max 90.\n\
+network_connect_timeout_secs =\n\
+network_max_time_secs =\n\
+\n\
+# Network proxy\n\
+# HTTP(S) or SOCKS proxy URL passed to curl as --proxy (e.g. http://proxy.example.com:3128 or\n\
+# socks5h://127.0.0.1:1080). When the key is absent, HTTPS_PROXY\n\
+# and ALL_PROXY from the environment are used; an empty value disables proxying entirely.\n\
+# network_proxy = http://proxy.example.com:3128\n\
+\n\
+# Network timeouts per source\n\
+# CONNECT,MAX in seconds for --connect-timeout and --max-time (e.g. 10,30).\n\
+# Leave empty to keep the built-in defaults: news 10,15; aur_rpc 30,90; pkgbuild 8,10; status 30,90.\n\
+# AUR RPC and status follow the global timeouts above when left empty.\n\
+network_timeout_news =\n\
+network_timeout_aur_rpc =\n\
+network_timeout_pkgbuild =\n\
+network_timeout_status =\n";
 
 /// Standalone keybinds skeleton used when initializing a separate keybinds.conf
 pub const KEYBINDS_SKELETON_CONTENT: &str = "# Pacsea keybindings configuration\n\
@@ -468,6 +626,13 @@ keybind_help = F1\n\
 # Alternative help shortcut\n\
 keybind_help = ?\n\
 keybind_reload_config = CTRL+R\n\
+# Retry news/advisories/updates/status fetches that last failed\n\
+keybind_retry_failed_fetches = ALT+R\n\
+# Command palette: fuzzy search over actions (filters, Options items, sort, news, ...)\n\
+keybind_command_palette = CTRL+P\n\
+# Copy the focused package's name / a details block (name, version, repo, description, URL)\n\
+keybind_copy_package_name = ALT+C\n\
+keybind_copy_package_details = ALT+D\n\
 keybind_exit = CTRL+Q\n\
 keybind_show_pkgbuild = CTRL+X\n\
 keybind_comments_toggle = CTRL+T\n\
@@ -478,6 +643,9 @@ keybind_cycle_pkgbuild_sections = CTRL+D\n\
 # GLOBAL — Pane switching\n\
 keybind_pane_left = Left\n\
 keybind_pane_right = Right\n\
+# Move the selection in the Install/Recent panes (arrows keep working; e.g. set k and j)\n\
+keybind_pane_up = Up\n\
+keybind_pane_down = Down\n\
 keybind_pane_next = Tab\n\
 # GLOBAL — Sorting\n\
 keybind_change_sort = BackTab\n\
@@ -491,6 +659,8 @@ keybind_search_page_down = PgDn\n\
 # SEARCH — Actions\n\
 keybind_search_add = Space\n\
 keybind_search_install = Enter\n\
+# Install the selected result right away without adding it to the Install list\n\
+keybind_search_quick_install = ALT+Enter\n\
 \n\
 # SEARCH — Focus/Edit\n\
 keybind_search_focus_left = Left\n\
@@ -526,6 +696,14 @@ keybind_search_normal_updates = Shift+U\n\
 # Toggle between normal substring search and fuzzy search (fzf-style)\n\
 keybind_toggle_fuzzy = CTRL+F\n\
 \n\
+# SEARCH — AUR/Official Variant Toggle\n\
+# Flip the selected result between its AUR and official repository variant\n\
+keybind_search_toggle_variant = CTRL+V\n\
+\n\
+# SEARCH — Did You Mean\n\
+# Re-run the search with the first suggestion shown when a query has few or no results\n\
+keybind_search_accept_suggestion = CTRL+Y\n\
+\n\
 # RECENT — Navigation\n\
 keybind_recent_move_up = k\n\
 keybind_recent_move_down = j\n\
@@ -550,15 +728,23 @@ keybind_install_confirm = Enter\n\
 keybind_install_remove = Del\n\
 keybind_install_remove = d\n\
 keybind_install_clear = Shift+Del\n\
+keybind_install_reorder_up = Shift+K\n\
+keybind_install_reorder_down = Shift+J\n\
+keybind_install_copy_command = y\n\
 \n\
 # INSTALL — Find/Focus\n\
 keybind_install_find = /\n\
 keybind_install_to_search = Esc\n\
 keybind_install_focus_left = Left\n\
 \n\
+# INSTALL — Profiles\n\
+keybind_install_profiles = p\n\
+\n\
 # NEWS — Actions\n\
 keybind_news_mark_read = r\n\
 keybind_news_mark_all_read = CTRL+R\n\
 keybind_news_feed_mark_read = r\n\
 keybind_news_feed_mark_unread = u\n\
-keybind_news_feed_toggle_read = t\n";
+keybind_news_feed_toggle_read = t\n\
+keybind_news_feed_export_markdown = y\n\
+keybind_news_feed_mark_all_read = Shift+R\n";
@@ -115,10 +115,6 @@ pub fn try_load_theme_with_diagnostics(path: &Path) -> Result<Theme, String> {
 /// # Errors
 /// - Returns newline-separated diagnostics when required keys are missing,
 ///   duplicated, or colors fail to parse.
-///
-/// # Panics
-/// - Never in practice: the internal `expect` runs only after the
-///   missing-required-keys check guarantees every canonical key is present.
 pub fn try_load_theme_from_content(content: &str) -> Result<Theme, String> {
     let mut errors: Vec<String> = Vec::new();
     let map = build_theme_color_map(content, &mut errors);
@@ -133,35 +129,44 @@ pub fn try_load_theme_from_content(content: &str) -> Result<Theme, String> {
         let preferred: Vec<String> = missing.iter().map(|k| canonical_to_preferred(k)).collect();
         errors.push(format!("- Missing required keys: {}", preferred.join(", ")));
     }
-    if errors.is_empty() {
-        let get = |name: &str| {
-            map.get(name)
-                .copied()
-                .expect("all required keys should be present after validation")
-        };
-        Ok(Theme {
-            base: get("base"),
-            mantle: get("mantle"),
-            crust: get("crust"),
-            surface1: get("surface1"),
-            surface2: get("surface2"),
-            overlay1: get("overlay1"),
-            overlay2: get("overlay2"),
-            text: get("text"),
-            subtext0: get("subtext0"),
-            subtext1: get("subtext1"),
-            sapphire: get("sapphire"),
-            mauve: get("mauve"),
-            green: get("green"),
-            yellow: get("yellow"),
-            red: get("red"),
-            lavender: get("lavender"),
-        })
-    } else {
-        Err(errors.join("\n"))
+    match theme_from_color_map(&map) {
+        Some(theme) if errors.is_empty() => Ok(theme),
+        _ => Err(errors.join("\n")),
     }
 }
 
+/// What: Assemble a `Theme` from a canonical-key color map.
+///
+/// Inputs:
+/// - `map`: Canonical key (see [`THEME_REQUIRED_CANONICAL`]) to parsed color.
+///
+/// Output:
+/// - `Some(Theme)` when every required key is present, `None` otherwise.
+///
+/// Details:
+/// - Shared by theme.conf loading and `theme::load_theme_from_path`.
+pub fn theme_from_color_map(map: &HashMap<String, Color>) -> Option<Theme> {
+    let get = |name: &str| map.get(name).copied();
+    Some(Theme {
+        base: get("base")?,
+        mantle: get("mantle")?,
+        crust: get("crust")?,
+        surface1: get("surface1")?,
+        surface2: get("surface2")?,
+        overlay1: get("overlay1")?,
+        overlay2: get("overlay2")?,
+        text: get("text")?,
+        subtext0: get("subtext0")?,
+        subtext1: get("subtext1")?,
+        sapphire: get("sapphire")?,
+        mauve: get("mauve")?,
+        green: get("green")?,
+        yellow: get("yellow")?,
+        red: get("red")?,
+        lavender: get("lavender")?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::try_load_theme_with_diagnostics;
@@ -19,33 +19,39 @@ mod store;
 mod terminal_detect;
 /// Terminal color query and theme derivation.
 mod terminal_query;
+/// User TOML/JSON palette files (`theme_file`).
+mod theme_file;
 /// Theme type definitions.
 mod types;
 
 pub use config::{
     ConfigFile, ConfigWriteError, EDITABLE_KEYBINDS, EDITABLE_SETTINGS, EDITABLE_THEME,
-    EditableSetting, PatchOutcome, PatchRequest, REPOS_SKELETON_CONTENT, ReloadBehavior,
-    Sensitivity, ValueKind, ensure_settings_keys_present, ensure_theme_keys_present, find_setting,
-    keybind_scope, maybe_migrate_legacy_confs, patch_key, resolved_config_path,
-    save_app_start_mode, save_fuzzy_search, save_mirror_count, save_news_filter_installed_only,
+    EditableSetting, EffectiveConfig, PatchOutcome, PatchRequest, REPOS_SKELETON_CONTENT,
+    ReloadBehavior, Sensitivity, ValueKind, effective_config, ensure_settings_keys_present,
+    ensure_theme_keys_present, find_setting, keybind_scope, maybe_migrate_legacy_confs, patch_key,
+    resolved_config_path, save_app_start_mode, save_comments_visible, save_fuzzy_search,
+    save_layout_pcts, save_mirror_count, save_news_filter_installed_only,
     save_news_filter_show_advisories, save_news_filter_show_arch_news,
     save_news_filter_show_aur_comments, save_news_filter_show_aur_updates,
     save_news_filter_show_pkg_updates, save_news_filters_collapsed, save_news_max_age_days,
-    save_results_filter_show_canonical, save_scan_do_clamav, save_scan_do_custom,
-    save_scan_do_semgrep, save_scan_do_shellcheck, save_scan_do_sleuth, save_scan_do_trivy,
-    save_scan_do_virustotal, save_selected_countries, save_show_install_pane,
+    save_pkgb_visible, save_results_filter_show_canonical, save_scan_do_clamav,
+    save_scan_do_custom, save_scan_do_semgrep, save_scan_do_shellcheck, save_scan_do_sleuth,
+    save_scan_do_trivy, save_scan_do_virustotal, save_selected_countries, save_show_install_pane,
     save_show_keybinds_footer, save_show_recent_pane, save_sort_mode, save_startup_news_configured,
     save_startup_news_max_age_days, save_startup_news_show_advisories,
     save_startup_news_show_arch_news, save_startup_news_show_aur_comments,
     save_startup_news_show_aur_updates, save_startup_news_show_pkg_updates,
     save_virustotal_api_key, settings_for, try_load_theme_from_content, write_full_content,
 };
+pub use parsing::{levenshtein, parse_key_chord};
 pub use paths::{
     config_dir, lists_dir, logs_dir, resolve_repos_config_path, set_config_dir_override,
 };
 pub use settings::settings;
-pub use store::{reload_theme, theme};
-pub use types::{KeyChord, KeyMap, PackageMarker, Settings, Theme};
+pub use store::{reload_theme, reload_theme_checked, theme};
+pub use terminal_detect::supports_unicode_glyphs;
+pub use theme_file::{ThemeError, load_theme_from_path, theme_file_path};
+pub use types::{KeyChord, KeyMap, MarkerKind, PackageMarker, RiskWeights, Settings, Theme};
 
 #[cfg(test)]
 static TEST_MUTEX: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
@@ -61,7 +61,8 @@ pub(super) fn parse_key_identifier(s: &str) -> Option<KeyCode> {
 /// Details:
 /// - Recognizes Ctrl/Alt/Shift/Super modifiers in any case.
 /// - Normalizes `Shift+Tab` to the dedicated `BackTab` key code and clears modifiers.
-pub(super) fn parse_key_chord(spec: &str) -> Option<KeyChord> {
+#[must_use]
+pub fn parse_key_chord(spec: &str) -> Option<KeyChord> {
     // Accept formats like: CTRL+R, Alt+?, Shift+Del, F1, Tab, BackTab, Super+F2
     let mut mods = KeyModifiers::empty();
     let mut key_part: Option<String> = None;
@@ -302,7 +303,8 @@ pub(super) fn nearest_key(input: &str) -> Option<&'static str> {
 ///
 /// Details:
 /// - Uses a rolling dynamic programming table to reduce allocations while iterating.
-pub(super) fn levenshtein(a: &str, b: &str) -> usize {
+#[must_use]
+pub fn levenshtein(a: &str, b: &str) -> usize {
     let m = b.len();
     let mut dp: Vec<usize> = (0..=m).collect();
     for (i, ca) in a.chars().enumerate() {
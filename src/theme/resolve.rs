@@ -5,7 +5,7 @@
 //!
 //! ## Decision Flow
 //!
-//! 1. Try to load theme from theme.conf
+//! 1. Try to load theme from the `theme_file` palette, else from theme.conf
 //! 2. If valid theme from file:
 //!    - If `use_terminal_theme` is true and terminal is supported: query OSC, use terminal theme if successful
 //!    - Otherwise: use file theme
@@ -19,12 +19,13 @@ use super::paths::{config_dir, resolve_theme_config_path};
 use super::settings::settings;
 use super::terminal_detect::is_supported_terminal_for_theme;
 use super::terminal_query::{query_terminal_colors, theme_from_fg_bg};
+use super::theme_file::{load_theme_from_path, theme_file_path_from};
 use super::types::Theme;
 
 /// Source of the resolved theme.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ThemeSource {
-    /// Theme loaded from theme.conf file.
+    /// Theme loaded from the `theme_file` palette or theme.conf.
     File,
     /// Theme derived from terminal colors via OSC 10/11.
     Terminal,
@@ -58,14 +59,14 @@ pub fn resolve_theme() -> ResolvedTheme {
     let prefs = settings();
     let use_terminal = prefs.use_terminal_theme;
 
-    // Step 1: Try to load theme from file
-    let file_theme = try_load_file_theme();
+    // Step 1: Try to load theme from file (user palette first, then theme.conf)
+    let file_theme = try_load_user_theme_file(&prefs.theme_file).or_else(try_load_file_theme);
 
     // Step 2: Determine resolution path
     match file_theme {
         Some(theme) if !use_terminal => {
             // Valid file theme and user doesn't want terminal theme
-            tracing::info!("Using theme from theme.conf");
+            tracing::info!("Using theme from theme file");
             ResolvedTheme {
                 theme,
                 source: ThemeSource::File,
@@ -139,6 +140,35 @@ fn try_load_file_theme() -> Option<Theme> {
     }
 }
 
+/// What: Load the palette named by the `theme_file` setting.
+///
+/// Inputs:
+/// - `setting`: Raw `theme_file` value; empty means unset.
+///
+/// Output:
+/// - `Some(Theme)` when the file loads; `None` when unset or invalid.
+///
+/// Details:
+/// - An invalid file is logged as a warning so resolution continues with theme.conf, the terminal
+///   theme, or the built-in default.
+fn try_load_user_theme_file(setting: &str) -> Option<Theme> {
+    let path = theme_file_path_from(setting)?;
+    match load_theme_from_path(&path) {
+        Ok(theme) => {
+            tracing::info!(path = %path.display(), "Using theme from theme_file");
+            Some(theme)
+        }
+        Err(e) => {
+            tracing::warn!(
+                path = %path.display(),
+                error = %e,
+                "Failed to load theme_file; falling back to built-in theme resolution"
+            );
+            None
+        }
+    }
+}
+
 /// Try to get theme from terminal via OSC 10/11.
 fn try_terminal_theme() -> Option<ResolvedTheme> {
     let (fg, bg) = query_terminal_colors()?;
@@ -183,6 +183,22 @@ pub fn settings() -> Settings {
     out
 }
 
+/// What: Apply `settings.conf`-format content onto existing settings.
+///
+/// Inputs:
+/// - `content`: `key = value` lines as written in `settings.conf`.
+/// - `settings`: Settings to update in place.
+///
+/// Output:
+/// - None; unknown keys are ignored.
+///
+/// Details:
+/// - Runs the same parser and normalization as [`settings`], without touching the cache or disk.
+pub fn apply_settings_content(content: &str, settings: &mut Settings) {
+    parse_settings(content, Path::new("settings.conf"), settings);
+    normalize(settings);
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1,4 +1,4 @@
-use crate::theme::types::Settings;
+use crate::theme::types::{PackageMarker, Settings};
 
 /// What: Clamp vertical layout limits so allocation stays well-defined.
 ///
@@ -40,6 +40,7 @@ fn normalize_vertical_layout(settings: &mut Settings) {
 /// - Normalizes `selected_countries` by trimming and formatting comma-separated values.
 /// - Normalizes `pkgbuild_shellcheck_exclude` the same way (comma-separated `ShellCheck` rule IDs).
 /// - Trims whitespace from `VirusTotal` API key.
+/// - Copies `package_marker_*` labels into a `custom` package marker.
 pub fn normalize(settings: &mut Settings) {
     normalize_vertical_layout(settings);
     // Normalize mirror settings parsed from settings.conf
@@ -69,6 +70,16 @@ pub fn normalize(settings: &mut Settings) {
     }
     // Normalize VirusTotal API key (trim whitespace)
     settings.virustotal_api_key = settings.virustotal_api_key.trim().to_string();
+    if let PackageMarker::Custom {
+        install,
+        remove,
+        downgrade,
+    } = &mut settings.package_marker
+    {
+        install.clone_from(&settings.package_marker_install);
+        remove.clone_from(&settings.package_marker_remove);
+        downgrade.clone_from(&settings.package_marker_downgrade);
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +76,22 @@ fn apply_global_keybind(key: &str, chord: Option<KeyChord>, settings: &mut Setti
             assign_keybind(chord, &mut settings.keymap.reload_config);
             true
         }
+        "keybind_retry_failed_fetches" | "keybind_retry_fetches" => {
+            assign_keybind(chord, &mut settings.keymap.retry_failed_fetches);
+            true
+        }
+        "keybind_command_palette" | "keybind_palette" => {
+            assign_keybind(chord, &mut settings.keymap.command_palette);
+            true
+        }
+        "keybind_copy_package_name" | "keybind_copy_name" => {
+            assign_keybind(chord, &mut settings.keymap.copy_package_name);
+            true
+        }
+        "keybind_copy_package_details" | "keybind_copy_details" => {
+            assign_keybind(chord, &mut settings.keymap.copy_package_details);
+            true
+        }
         "keybind_exit" | "keybind_quit" => {
             assign_keybind(chord, &mut settings.keymap.exit);
             true
@@ -116,6 +132,14 @@ fn apply_global_keybind(key: &str, chord: Option<KeyChord>, settings: &mut Setti
             assign_keybind(chord, &mut settings.keymap.pane_right);
             true
         }
+        "keybind_pane_up" => {
+            assign_keybind(chord, &mut settings.keymap.pane_up);
+            true
+        }
+        "keybind_pane_down" => {
+            assign_keybind(chord, &mut settings.keymap.pane_down);
+            true
+        }
         _ => false,
     }
 }
@@ -159,6 +183,10 @@ fn apply_search_keybind(key: &str, chord: Option<KeyChord>, settings: &mut Setti
             assign_keybind(chord, &mut settings.keymap.search_install);
             true
         }
+        "keybind_search_quick_install" | "keybind_quick_install" => {
+            assign_keybind(chord, &mut settings.keymap.search_quick_install);
+            true
+        }
         "keybind_search_focus_left" => {
             assign_keybind(chord, &mut settings.keymap.search_focus_left);
             true
@@ -221,6 +249,14 @@ fn apply_search_keybind(key: &str, chord: Option<KeyChord>, settings: &mut Setti
             assign_keybind(chord, &mut settings.keymap.toggle_fuzzy);
             true
         }
+        "keybind_search_toggle_variant" | "keybind_toggle_variant" => {
+            assign_keybind(chord, &mut settings.keymap.search_toggle_variant);
+            true
+        }
+        "keybind_search_accept_suggestion" | "keybind_accept_suggestion" => {
+            assign_keybind(chord, &mut settings.keymap.search_accept_suggestion);
+            true
+        }
         _ => false,
     }
 }
@@ -329,6 +365,22 @@ fn apply_install_keybind(key: &str, chord: Option<KeyChord>, settings: &mut Sett
             assign_keybind(chord, &mut settings.keymap.install_focus_left);
             true
         }
+        "keybind_install_profiles" => {
+            assign_keybind(chord, &mut settings.keymap.install_profiles);
+            true
+        }
+        "keybind_install_reorder_up" => {
+            assign_keybind(chord, &mut settings.keymap.install_reorder_up);
+            true
+        }
+        "keybind_install_reorder_down" => {
+            assign_keybind(chord, &mut settings.keymap.install_reorder_down);
+            true
+        }
+        "keybind_install_copy_command" => {
+            assign_keybind(chord, &mut settings.keymap.install_copy_command);
+            true
+        }
         _ => false,
     }
 }
@@ -383,6 +435,20 @@ fn apply_news_keybind(key: &str, chord: Option<KeyChord>, settings: &mut Setting
             assign_keybind(chord, &mut settings.keymap.news_toggle_read_feed);
             true
         }
+        "keybind_news_feed_export_markdown" => {
+            if chord.is_none() {
+                tracing::warn!("Failed to parse keybind_news_feed_export_markdown");
+            }
+            assign_keybind(chord, &mut settings.keymap.news_export_markdown_feed);
+            true
+        }
+        "keybind_news_feed_mark_all_read" => {
+            if chord.is_none() {
+                tracing::warn!("Failed to parse keybind_news_feed_mark_all_read");
+            }
+            assign_keybind(chord, &mut settings.keymap.news_mark_all_read_feed);
+            true
+        }
         _ => false,
     }
 }
@@ -721,6 +787,36 @@ mod tests {
         ));
         assert_eq!(settings2.keymap.install_remove.len(), initial_len + 2); // Appended again
 
+        // Test install profiles picker keybind (replaces default)
+        assert!(apply_install_keybind(
+            "keybind_install_profiles",
+            Some(chord1),
+            &mut settings
+        ));
+        assert_eq!(settings.keymap.install_profiles, vec![chord1]);
+
+        // Test install list reorder keybinds
+        assert!(apply_install_keybind(
+            "keybind_install_reorder_up",
+            Some(chord1),
+            &mut settings
+        ));
+        assert!(apply_install_keybind(
+            "keybind_install_reorder_down",
+            Some(chord2),
+            &mut settings
+        ));
+        assert_eq!(settings.keymap.install_reorder_up, vec![chord1]);
+        assert_eq!(settings.keymap.install_reorder_down, vec![chord2]);
+
+        // Test install command copy keybind
+        assert!(apply_install_keybind(
+            "keybind_install_copy_command",
+            Some(chord2),
+            &mut settings
+        ));
+        assert_eq!(settings.keymap.install_copy_command, vec![chord2]);
+
         // Test invalid keybind
         assert!(!apply_install_keybind(
             "keybind_invalid",
@@ -795,6 +891,26 @@ mod tests {
         assert_eq!(settings5.keymap.news_toggle_read_feed.len(), 1);
         assert_eq!(settings5.keymap.news_toggle_read_feed[0], chord);
 
+        // Test news feed Markdown export
+        let mut settings6 = Settings::default();
+        assert!(apply_news_keybind(
+            "keybind_news_feed_export_markdown",
+            Some(chord),
+            &mut settings6
+        ));
+        assert_eq!(settings6.keymap.news_export_markdown_feed.len(), 1);
+        assert_eq!(settings6.keymap.news_export_markdown_feed[0], chord);
+
+        // Test news feed mark all read
+        let mut settings7 = Settings::default();
+        assert!(apply_news_keybind(
+            "keybind_news_feed_mark_all_read",
+            Some(chord),
+            &mut settings7
+        ));
+        assert_eq!(settings7.keymap.news_mark_all_read_feed.len(), 1);
+        assert_eq!(settings7.keymap.news_mark_all_read_feed[0], chord);
+
         // Test invalid keybind
         assert!(!apply_news_keybind(
             "keybind_invalid",
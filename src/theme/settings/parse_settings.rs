@@ -121,27 +121,225 @@ fn parse_app_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
             settings.show_recent_pane = parse_bool(val);
             true
         }
+        "recent_show_result_counts" => {
+            settings.recent_show_result_counts = parse_bool(val);
+            true
+        }
         "show_install_pane" | "install_visible" | "show_install_list" => {
             settings.show_install_pane = parse_bool(val);
             true
         }
+        "installed_only_show_size" => {
+            settings.installed_only_show_size = parse_bool(val);
+            true
+        }
+        "installed_only_default_focus" => {
+            let v = val.trim().to_ascii_lowercase();
+            if matches!(v.as_str(), "remove" | "downgrade") {
+                settings.installed_only_default_focus = v;
+            }
+            true
+        }
+        "results_show_numbers" => {
+            settings.results_show_numbers = parse_bool(val);
+            true
+        }
+        "results_repo_label_style" => {
+            let v = val.trim().to_ascii_lowercase();
+            if matches!(v.as_str(), "full" | "short" | "icon") {
+                settings.results_repo_label_style = v;
+            }
+            true
+        }
+        "comments_max_display" => {
+            if let Ok(v) = val.parse::<usize>() {
+                settings.comments_max_display = v;
+            }
+            true
+        }
+        "comments_order" => {
+            let v = val.trim().to_ascii_lowercase().replace('-', "_");
+            if matches!(v.as_str(), "pinned_first" | "newest_first") {
+                settings.comments_order = v;
+            }
+            true
+        }
+        "escape_quits_from_search" => {
+            settings.escape_quits_from_search = parse_bool(val);
+            true
+        }
+        "mode_switch_search_input" => {
+            let v = val.trim().to_ascii_lowercase();
+            if matches!(v.as_str(), "preserve" | "clear") {
+                settings.mode_switch_search_input = v;
+            }
+            true
+        }
+        "details_description_max_lines" => {
+            if let Ok(v) = val.parse::<usize>() {
+                settings.details_description_max_lines = v;
+            }
+            true
+        }
+        "mouse_scroll_lines" => {
+            if let Ok(v) = val.parse::<u16>() {
+                settings.mouse_scroll_lines = v.max(1);
+            }
+            true
+        }
         "show_keybinds_footer" | "keybinds_visible" => {
             settings.show_keybinds_footer = parse_bool(val);
             true
         }
+        "completion_notify" => {
+            let v = val.trim().to_ascii_lowercase();
+            let v = match v.as_str() {
+                "true" | "yes" | "on" | "1" => "bell".to_string(),
+                "false" | "no" | "none" | "0" => "off".to_string(),
+                _ => v,
+            };
+            if matches!(v.as_str(), "off" | "bell" | "desktop" | "both") {
+                settings.completion_notify = v;
+            }
+            true
+        }
+        "expert_mode" => {
+            settings.expert_mode = parse_bool(val);
+            true
+        }
+        "pacnew_reminder" => {
+            settings.pacnew_reminder = parse_bool(val);
+            true
+        }
+        "pkgb_visible" => {
+            settings.pkgb_visible = parse_bool(val);
+            true
+        }
+        "comments_visible" => {
+            settings.comments_visible = parse_bool(val);
+            true
+        }
+        "remember_viewers" => {
+            settings.remember_viewers = parse_bool(val);
+            true
+        }
+        "install_group_by_source" => {
+            settings.install_group_by_source = parse_bool(val);
+            true
+        }
+        "exec_log_follow" => {
+            settings.exec_log_follow = parse_bool(val);
+            true
+        }
+        "ui_size_units" => {
+            if let Some(units) = crate::ui::helpers::SizeUnits::from_config_key(val) {
+                settings.size_units = units.as_config_key().to_string();
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// What: Parse package marker settings.
+///
+/// Inputs:
+/// - `key`: Normalized config key
+/// - `val`: Config value
+/// - `settings`: Mutable settings to update
+///
+/// Output:
+/// - `true` if key was handled, `false` otherwise
+fn parse_marker_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
+    match key {
         "package_marker" => {
             let lv = val.to_ascii_lowercase();
             settings.package_marker = match lv.as_str() {
                 "full" | "full_line" | "line" | "color_line" | "color" => PackageMarker::FullLine,
                 "end" | "suffix" => PackageMarker::End,
+                "ascii" => PackageMarker::Ascii,
+                "nerd_font" | "nerdfont" | "nerd" => PackageMarker::NerdFont,
+                // Labels are filled from `package_marker_*` in `normalize`.
+                "custom" => PackageMarker::Custom {
+                    install: String::new(),
+                    remove: String::new(),
+                    downgrade: String::new(),
+                },
                 _ => PackageMarker::Front,
             };
             true
         }
+        "package_marker_install" => {
+            settings.package_marker_install = val.to_string();
+            true
+        }
+        "package_marker_remove" => {
+            settings.package_marker_remove = val.to_string();
+            true
+        }
+        "package_marker_downgrade" => {
+            settings.package_marker_downgrade = val.to_string();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// What: Parse preflight and reinstall confirmation settings.
+///
+/// Inputs:
+/// - `key`: Normalized config key
+/// - `val`: Config value
+/// - `settings`: Mutable settings to update
+///
+/// Output:
+/// - `true` if key was handled, `false` otherwise
+fn parse_preflight_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
+    match key {
         "skip_preflight" | "preflight_skip" | "bypass_preflight" => {
             settings.skip_preflight = parse_bool(val);
             true
         }
+        "reinstall_confirm_threshold" => {
+            if let Ok(v) = val.parse::<usize>() {
+                settings.reinstall_confirm_threshold = v;
+            }
+            true
+        }
+        "reinstall_confirm_skip_sources" => {
+            settings.reinstall_confirm_skip_sources = val.trim().to_ascii_lowercase();
+            true
+        }
+        "preflight_aur_dep_depth" => {
+            if let Ok(v) = val.parse::<usize>() {
+                settings.preflight_aur_dep_depth = v;
+            }
+            true
+        }
+        "preflight_metadata_chunk_size" => {
+            if let Ok(v) = val.parse::<usize>()
+                && v > 0
+            {
+                settings.preflight_metadata_chunk_size = v;
+            }
+            true
+        }
+        "preflight_metadata_parallelism" => {
+            if let Ok(v) = val.parse::<usize>()
+                && v > 0
+            {
+                settings.preflight_metadata_parallelism = v;
+            }
+            true
+        }
+        "preflight_extra_critical_packages" => {
+            settings.preflight_extra_critical_packages = parse_name_list(val);
+            true
+        }
+        "downgrade_conflict_check" => {
+            settings.downgrade_conflict_check = parse_bool(val);
+            true
+        }
         _ => false,
     }
 }
@@ -235,6 +433,31 @@ fn parse_mirror_settings(key: &str, val: &str, settings: &mut Settings) -> bool
             }
             true
         }
+        "aur_helper_install_check" => {
+            settings.aur_helper_install_check = parse_bool(val);
+            true
+        }
+        "install_mode" => {
+            let v = val
+                .trim()
+                .trim_matches(['"', '\''])
+                .to_ascii_lowercase()
+                .replace('-', "_");
+            if matches!(v.as_str(), "standard" | "pacman_only") {
+                settings.install_mode = v;
+            }
+            true
+        }
+        "install_aur_parallel_jobs" => {
+            if let Ok(n) = val.trim().parse::<usize>() {
+                settings.install_aur_parallel_jobs = n.clamp(1, 16);
+            }
+            true
+        }
+        "upgrade_command" => {
+            settings.upgrade_command = val.trim().to_string();
+            true
+        }
         _ => false,
     }
 }
@@ -258,6 +481,18 @@ fn parse_news_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
             settings.news_unread_symbol = val.to_string();
             true
         }
+        "news_details_fields" => {
+            settings.news_details_fields = val.to_string();
+            true
+        }
+        "news_details_compact" => {
+            settings.news_details_compact = parse_bool(val);
+            true
+        }
+        "news_remember_scroll" | "news_restore_scroll" => {
+            settings.news_remember_scroll = parse_bool(val);
+            true
+        }
         "news_filter_show_arch_news" | "news_filter_arch" => {
             settings.news_filter_show_arch_news = parse_bool(val);
             true
@@ -331,6 +566,20 @@ fn parse_news_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
             }
             true
         }
+        "news_max_parallel_slow_fetches" => {
+            if let Ok(n) = val.trim().parse::<usize>() {
+                settings.news_max_parallel_slow_fetches = n.max(1); // Minimum 1 (sequential)
+            }
+            true
+        }
+        "news_arch_feed_url" => {
+            settings.news_arch_feed_url = val.trim().to_string();
+            true
+        }
+        "news_advisory_url" => {
+            settings.news_advisory_url = val.trim().to_string();
+            true
+        }
         _ => false,
     }
 }
@@ -361,10 +610,107 @@ fn parse_search_settings(key: &str, val: &str, settings: &mut Settings) -> bool
             settings.fuzzy_search = parse_bool(val);
             true
         }
+        "auto_select_first_result" => {
+            settings.auto_select_first_result = parse_bool(val);
+            true
+        }
+        "search_normalize" => {
+            let v = val.trim().to_ascii_lowercase();
+            if matches!(v.as_str(), "off" | "trim" | "collapse") {
+                settings.search_normalize = v;
+            }
+            true
+        }
+        "search_suggestions" => {
+            settings.search_suggestions = parse_bool(val);
+            true
+        }
+        "find_match_description" => {
+            settings.find_match_description = val.trim().to_ascii_lowercase();
+            true
+        }
+        "search_repo_priority" => {
+            settings.repo_priority = parse_name_list(val);
+            true
+        }
+        "search_default_arch" => {
+            let v = val.trim().to_ascii_lowercase();
+            settings.default_arch = if v.is_empty() { "auto".to_string() } else { v };
+            true
+        }
+        "search_debounce_ms" => {
+            if let Ok(v) = val.trim().parse::<u64>() {
+                settings.search_debounce_ms = v.min(5000);
+            }
+            true
+        }
         _ => false,
     }
 }
 
+/// What: Parse an ordered name list (`search_repo_priority`, `preflight_extra_critical_packages`).
+///
+/// Inputs:
+/// - `val`: Comma-separated names, optionally wrapped as `["core", "extra"]`
+///
+/// Output:
+/// - Lowercased names in order, without brackets, quotes, blanks, or duplicates.
+fn parse_name_list(val: &str) -> Vec<String> {
+    let inner = val.trim().trim_start_matches('[').trim_end_matches(']');
+    let mut out: Vec<String> = Vec::new();
+    for name in inner.split(',') {
+        let name = name
+            .trim()
+            .trim_matches(['"', '\''])
+            .trim()
+            .to_ascii_lowercase();
+        if !name.is_empty() && !out.contains(&name) {
+            out.push(name);
+        }
+    }
+    out
+}
+
+/// What: Parse preflight risk-scoring weights.
+///
+/// Inputs:
+/// - `key`: Normalized config key
+/// - `val`: Config value
+/// - `settings`: Mutable settings to update
+///
+/// Output:
+/// - `true` if key was handled, `false` otherwise
+///
+/// Details:
+/// - Invalid values keep the previous weight; `preflight_risk_high_threshold` must be at least 1.
+fn parse_risk_weight_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
+    let weights = &mut settings.preflight_risk_weights;
+    let slot = match key {
+        "preflight_risk_weight_core" => &mut weights.core,
+        "preflight_risk_weight_major_bump" => &mut weights.major_bump,
+        "preflight_risk_weight_aur" => &mut weights.aur,
+        "preflight_risk_weight_pacnew" => &mut weights.pacnew,
+        "preflight_risk_weight_services" => &mut weights.services,
+        "preflight_risk_weight_dependent" => &mut weights.dependent,
+        "preflight_risk_weight_downgrade_conflict" => &mut weights.downgrade_conflict,
+        "preflight_risk_weight_conflict" => &mut weights.conflict,
+        "preflight_risk_weight_sandbox" => &mut weights.sandbox,
+        "preflight_risk_high_threshold" => {
+            if let Ok(v) = val.parse::<u8>()
+                && v > 0
+            {
+                weights.high_threshold = v;
+            }
+            return true;
+        }
+        _ => return false,
+    };
+    if let Ok(v) = val.parse::<u8>() {
+        *slot = v;
+    }
+    true
+}
+
 /// What: Parse AUR voting settings.
 ///
 /// Inputs:
@@ -487,10 +833,73 @@ fn parse_misc_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
             settings.use_terminal_theme = parse_bool(val);
             true
         }
+        "theme_file" => {
+            settings.theme_file = val.trim().to_string();
+            true
+        }
+        "audit_log_commands" => {
+            settings.audit_log_commands = parse_bool(val);
+            true
+        }
+        "activity_log_max_lines" => {
+            if let Ok(v) = val.parse::<usize>() {
+                settings.activity_log_max_lines = v;
+            }
+            true
+        }
         _ => false,
     }
 }
 
+/// What: Parse the proxy and the global and per-source network timeout overrides.
+///
+/// Inputs:
+/// - `key`: Normalized config key
+/// - `val`: Config value (proxy URL, where empty disables proxying; seconds for the global
+///   timeout keys; `CONNECT,MAX` for per-source keys; empty or invalid timeouts clear the override)
+/// - `settings`: Mutable settings to update
+///
+/// Output:
+/// - `true` if key was handled, `false` otherwise
+fn parse_network_settings(key: &str, val: &str, settings: &mut Settings) -> bool {
+    if key == "network_proxy" {
+        settings.network_proxy = Some(val.trim().to_string());
+        return true;
+    }
+    let secs_slot = match key {
+        "network_connect_timeout_secs" => Some(&mut settings.network_connect_timeout_secs),
+        "network_max_time_secs" => Some(&mut settings.network_max_time_secs),
+        _ => None,
+    };
+    if let Some(slot) = secs_slot {
+        *slot = val.trim().parse::<u64>().ok().filter(|v| *v > 0);
+        if slot.is_none() && !val.trim().is_empty() {
+            tracing::warn!(
+                key,
+                value = val,
+                "invalid network timeout (expected positive seconds); using default"
+            );
+        }
+        return true;
+    }
+    let slot = match key {
+        "network_timeout_news" => &mut settings.network_timeout_news,
+        "network_timeout_aur_rpc" => &mut settings.network_timeout_aur_rpc,
+        "network_timeout_pkgbuild" => &mut settings.network_timeout_pkgbuild,
+        "network_timeout_status" => &mut settings.network_timeout_status,
+        _ => return false,
+    };
+    *slot = crate::util::CurlTimeouts::parse(val);
+    if slot.is_none() && !val.trim().is_empty() {
+        tracing::warn!(
+            key,
+            value = val,
+            "invalid network timeout (expected CONNECT,MAX seconds); using default"
+        );
+    }
+    true
+}
+
 /// What: Parse non-keybind settings from settings.conf content.
 ///
 /// Inputs:
@@ -524,12 +933,16 @@ pub fn parse_settings(content: &str, _settings_path: &Path, settings: &mut Setti
         // Note: we intentionally ignore keybind_* in settings.conf now; keybinds load below
         let _ = parse_layout_settings(&key, val, settings)
             || parse_app_settings(&key, val, settings)
+            || parse_marker_settings(&key, val, settings)
+            || parse_preflight_settings(&key, val, settings)
             || parse_scan_settings(&key, val, settings)
             || parse_mirror_settings(&key, val, settings)
             || parse_news_settings(&key, val, settings)
             || parse_search_settings(&key, val, settings)
             || parse_aur_vote_settings(&key, val, settings)
             || parse_misc_settings(&key, val, settings)
+            || parse_risk_weight_settings(&key, val, settings)
+            || parse_network_settings(&key, val, settings)
             || parse_results_filter_dynamic(&key, val, settings);
     }
 }
@@ -565,6 +978,26 @@ mod tests {
         assert_eq!(settings.aur_helper, "paru");
     }
 
+    #[test]
+    /// What: Verify `install.mode` normalizes to `install_mode` and only accepts known modes.
+    ///
+    /// Inputs:
+    /// - `install.mode = pacman-only`, then an unknown value.
+    ///
+    /// Output:
+    /// - The dotted/dashed spelling selects `pacman_only`; the unknown value is ignored.
+    fn parse_settings_install_mode_accepts_dotted_key() {
+        let path = Path::new("settings.conf");
+        let mut settings = Settings::default();
+        assert_eq!(settings.install_mode, "standard");
+
+        parse_settings("install.mode = \"pacman-only\"\n", path, &mut settings);
+        assert_eq!(settings.install_mode, "pacman_only");
+
+        parse_settings("install_mode = aur_only\n", path, &mut settings);
+        assert_eq!(settings.install_mode, "pacman_only");
+    }
+
     #[test]
     /// What: Verify mirror keys still parse alongside the new helper key.
     ///
@@ -588,4 +1021,35 @@ mod tests {
         assert_eq!(settings.selected_countries, "Switzerland, Germany");
         assert_eq!(settings.mirror_count, 7);
     }
+
+    #[test]
+    /// What: Verify risk weight keys override defaults and reject invalid values.
+    ///
+    /// Inputs:
+    /// - Dotted `preflight.risk_weight_aur = 0`, `major_bump = 4`, an invalid core weight, and a
+    ///   zero High threshold followed by a valid one.
+    ///
+    /// Output:
+    /// - AUR/major bump weights applied, core keeps its default, threshold ends at 7.
+    fn parse_settings_risk_weights_apply() {
+        let path = Path::new("settings.conf");
+        let mut settings = Settings::default();
+
+        parse_settings(
+            "preflight.risk_weight_aur = 0\n\
+             preflight.risk_weight_major_bump = 4\n\
+             preflight.risk_weight_core = lots\n\
+             preflight.risk_high_threshold = 0\n",
+            path,
+            &mut settings,
+        );
+        let weights = settings.preflight_risk_weights;
+        assert_eq!(weights.aur, 0);
+        assert_eq!(weights.major_bump, 4);
+        assert_eq!(weights.core, 3);
+        assert_eq!(weights.high_threshold, 5);
+
+        parse_settings("preflight_risk_high_threshold = 7\n", path, &mut settings);
+        assert_eq!(settings.preflight_risk_weights.high_threshold, 7);
+    }
 }
@@ -10,6 +10,7 @@ use std::sync::{OnceLock, RwLock};
 use super::config::THEME_SKELETON_CONTENT;
 use super::paths::config_dir;
 use super::resolve::{ThemeSource, resolve_theme};
+use super::theme_file::{load_theme_from_path, theme_file_path};
 use super::types::Theme;
 
 /// Global theme store with live-reload capability.
@@ -135,3 +136,27 @@ pub fn reload_theme() -> std::result::Result<(), String> {
         },
     )
 }
+
+/// What: Reload the theme and report whether the `theme_file` palette is usable.
+///
+/// Inputs:
+/// - None (uses the unified resolution logic and the current `theme_file` setting).
+///
+/// Output:
+/// - `Ok(())` when the theme reloaded and the configured palette (if any) loaded.
+/// - `Err(String)` when the store could not be updated or the palette is invalid.
+///
+/// # Errors
+/// - Returns `Err` with the `reload_theme` failure or the palette's `ThemeError` message
+///
+/// Details:
+/// - Shared by the reload-config shortcut and the `theme_file` watcher so both apply and report
+///   changes the same way. An invalid palette still leaves the fallback theme applied.
+pub fn reload_theme_checked() -> std::result::Result<(), String> {
+    reload_theme()?;
+    theme_file_path().map_or(Ok(()), |path| {
+        load_theme_from_path(&path)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    })
+}
@@ -1,9 +1,11 @@
 //! Terminal detection for theme fallback support.
 //!
 //! This module provides functionality to detect whether the current terminal
-//! is on the supported list for OSC 10/11 color queries.
+//! is on the supported list for OSC 10/11 color queries, and whether it can
+//! render non-ASCII marker glyphs.
 
 use std::env;
+use std::sync::OnceLock;
 
 /// Terminals known to support OSC 10/11 color queries.
 /// These terminals can report their foreground/background colors reliably.
@@ -134,6 +136,49 @@ pub fn get_terminal_name() -> Option<String> {
     None
 }
 
+/// Cached result of [`supports_unicode_glyphs`].
+static UNICODE_GLYPHS: OnceLock<bool> = OnceLock::new();
+
+/// What: Best-effort check whether the terminal can render non-ASCII glyphs.
+///
+/// Output:
+/// - `false` on the Linux virtual console or a non-UTF-8 locale; `true` otherwise.
+///
+/// Details:
+/// - Reads `TERM` and the first non-empty of `LC_ALL`, `LC_CTYPE`, `LANG`; cached for the process.
+/// - Whether a Nerd Font is installed cannot be detected, so this only catches the common
+///   cases where even plain Unicode arrows render as replacement characters.
+#[must_use]
+pub fn supports_unicode_glyphs() -> bool {
+    *UNICODE_GLYPHS.get_or_init(|| {
+        let term = env::var("TERM").unwrap_or_default();
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(|key| env::var(key).ok())
+            .find(|value| !value.is_empty());
+        unicode_capable(&term, locale.as_deref())
+    })
+}
+
+/// What: Decide Unicode capability from `TERM` and the effective locale.
+///
+/// Inputs:
+/// - `term`: Value of `TERM` (may be empty).
+/// - `locale`: Effective locale value, `None` when no locale variable is set.
+///
+/// Output:
+/// - `false` for `linux`/`vt*` terminals and locales without a UTF-8 codeset.
+fn unicode_capable(term: &str, locale: Option<&str>) -> bool {
+    let term = term.to_ascii_lowercase();
+    if term == "linux" || term.starts_with("vt") {
+        return false;
+    }
+    locale.is_none_or(|value| {
+        let value = value.to_ascii_lowercase();
+        value.contains("utf-8") || value.contains("utf8")
+    })
+}
+
 /// Check if stdout is a TTY.
 fn is_tty() -> bool {
     use std::io::IsTerminal;
@@ -229,6 +274,17 @@ mod tests {
         assert!(is_supported_name("WezTerm"));
     }
 
+    #[test]
+    fn test_unicode_capable() {
+        assert!(unicode_capable("xterm-256color", Some("en_US.UTF-8")));
+        assert!(unicode_capable("alacritty", Some("de_DE.utf8")));
+        assert!(unicode_capable("kitty", None));
+        assert!(!unicode_capable("linux", Some("en_US.UTF-8")));
+        assert!(!unicode_capable("vt220", None));
+        assert!(!unicode_capable("xterm", Some("C")));
+        assert!(!unicode_capable("xterm", Some("en_US.ISO-8859-1")));
+    }
+
     #[test]
     fn test_is_supported_name_unsupported() {
         assert!(!is_supported_name("urxvt"));
@@ -0,0 +1,329 @@
+//! Loading a complete theme palette from a user TOML or JSON file (`theme_file`).
+//!
+//! The file is a flat table of color keys, the same names theme.conf accepts
+//! (`background_base`, `text_primary`, or the canonical `base`, `text`, ...):
+//!
+//! ```toml
+//! background_base = "#1e1e2e"
+//! text_primary = "205, 214, 244"
+//! semantic_error = [243, 139, 168]
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::Color;
+
+use super::config::theme_loader::{THEME_REQUIRED_CANONICAL, theme_from_color_map};
+use super::parsing::{canonical_for_key, canonical_to_preferred, nearest_key, parse_color_value};
+use super::types::Theme;
+
+/// Failure modes of [`load_theme_from_path`].
+#[derive(Debug)]
+pub enum ThemeError {
+    /// The file could not be read.
+    Io {
+        /// Path of the theme file.
+        path: PathBuf,
+        /// Underlying I/O error.
+        source: std::io::Error,
+    },
+    /// The file is not valid TOML/JSON or its top level is not a table of colors.
+    Parse {
+        /// Path of the theme file.
+        path: PathBuf,
+        /// Parser message.
+        message: String,
+    },
+    /// A key is not a known theme color.
+    UnknownField {
+        /// Key as written in the file.
+        field: String,
+        /// Closest known key, if any.
+        suggestion: Option<String>,
+    },
+    /// A color value is neither `#RRGGBB`, `R,G,B`, nor `[R, G, B]`.
+    InvalidColor {
+        /// Key as written in the file.
+        field: String,
+        /// Offending value, rendered back as TOML/JSON text.
+        value: String,
+    },
+    /// Required colors are absent; holds their preferred key names.
+    MissingFields(Vec<String>),
+}
+
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "cannot read theme file {}: {source}", path.display())
+            }
+            Self::Parse { path, message } => {
+                write!(f, "invalid theme file {}: {message}", path.display())
+            }
+            Self::UnknownField {
+                field,
+                suggestion: Some(s),
+            } => write!(f, "unknown theme color '{field}' (did you mean '{s}'?)"),
+            Self::UnknownField {
+                field,
+                suggestion: None,
+            } => write!(f, "unknown theme color '{field}'"),
+            Self::InvalidColor { field, value } => write!(
+                f,
+                "invalid color for '{field}': {value} (use \"#RRGGBB\", \"R,G,B\", or [R, G, B])"
+            ),
+            Self::MissingFields(fields) => {
+                write!(f, "missing required theme colors: {}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// What: Resolve a `theme_file` setting value to a filesystem path.
+///
+/// Inputs:
+/// - `setting`: Raw `theme_file` value; empty means unset.
+///
+/// Output:
+/// - `Some(path)` when set; `None` when empty or `~/` is used without `$HOME`.
+///
+/// Details:
+/// - `~/` expands to `$HOME`; relative paths resolve against `config_dir()`.
+pub(super) fn theme_file_path_from(setting: &str) -> Option<PathBuf> {
+    let setting = setting.trim();
+    if setting.is_empty() {
+        return None;
+    }
+    match setting.strip_prefix("~/") {
+        Some(rest) => Some(PathBuf::from(std::env::var_os("HOME")?).join(rest)),
+        None => Some(super::paths::config_dir().join(setting)),
+    }
+}
+
+/// What: Path of the user palette configured by `theme_file`, if any.
+///
+/// Output:
+/// - Resolved path from the current settings, or `None` when `theme_file` is empty.
+#[must_use]
+pub fn theme_file_path() -> Option<PathBuf> {
+    theme_file_path_from(&super::settings::settings().theme_file)
+}
+
+/// What: Load a full `Theme` from a user TOML or JSON palette file.
+///
+/// Inputs:
+/// - `path`: Palette file; a `.json` extension selects JSON, anything else is read as TOML.
+///
+/// Output:
+/// - `Ok(Theme)` when every required color is present and valid.
+///
+/// # Errors
+/// - [`ThemeError::Io`] when the file cannot be read.
+/// - [`ThemeError::Parse`] for malformed TOML/JSON or a non-table top level.
+/// - [`ThemeError::UnknownField`] / [`ThemeError::InvalidColor`] for the first bad entry.
+/// - [`ThemeError::MissingFields`] listing every absent required color.
+///
+/// Details:
+/// - Keys are normalized like theme.conf keys, so aliases such as `background` map to `base`.
+pub fn load_theme_from_path(path: &Path) -> Result<Theme, ThemeError> {
+    let content = std::fs::read_to_string(path).map_err(|source| ThemeError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let parsed = if is_json {
+        serde_json::from_str::<serde_json::Value>(&content).map_err(|e| e.to_string())
+    } else {
+        toml::from_str::<toml::Table>(&content)
+            .map_err(|e| e.to_string())
+            .and_then(|table| serde_json::to_value(table).map_err(|e| e.to_string()))
+    };
+    let serde_json::Value::Object(entries) = parsed.map_err(|message| ThemeError::Parse {
+        path: path.to_path_buf(),
+        message,
+    })?
+    else {
+        return Err(ThemeError::Parse {
+            path: path.to_path_buf(),
+            message: "expected a table of colors".to_string(),
+        });
+    };
+
+    let mut map: HashMap<String, Color> = HashMap::new();
+    for (key, value) in &entries {
+        let norm = key.trim().to_lowercase().replace(['.', '-', ' '], "_");
+        let Some(canon) = canonical_for_key(&norm) else {
+            return Err(ThemeError::UnknownField {
+                field: key.clone(),
+                suggestion: nearest_key(&norm).map(canonical_to_preferred),
+            });
+        };
+        let color = color_from_value(value).ok_or_else(|| ThemeError::InvalidColor {
+            field: key.clone(),
+            value: value.to_string(),
+        })?;
+        map.insert(canon.to_string(), color);
+    }
+    theme_from_color_map(&map).ok_or_else(|| {
+        ThemeError::MissingFields(
+            THEME_REQUIRED_CANONICAL
+                .iter()
+                .filter(|k| !map.contains_key(**k))
+                .map(|k| canonical_to_preferred(k))
+                .collect(),
+        )
+    })
+}
+
+/// What: Parse one palette value into a color.
+///
+/// Inputs:
+/// - `value`: A `"#RRGGBB"` / `"R,G,B"` string or a three-element array of 0-255 integers.
+///
+/// Output:
+/// - `Some(Color::Rgb)` when valid, `None` otherwise.
+fn color_from_value(value: &serde_json::Value) -> Option<Color> {
+    match value {
+        serde_json::Value::String(s) => parse_color_value(s),
+        serde_json::Value::Array(parts) if parts.len() == 3 => {
+            let channel = |v: &serde_json::Value| v.as_u64().and_then(|n| u8::try_from(n).ok());
+            Some(Color::Rgb(
+                channel(&parts[0])?,
+                channel(&parts[1])?,
+                channel(&parts[2])?,
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Complete palette in TOML, mixing preferred and canonical key names and value formats.
+    const FULL_TOML: &str = r##"
+background_base = "#1e1e2e"
+background_mantle = "#181825"
+background_crust = "#11111b"
+surface_level1 = "#45475a"
+surface_level2 = "#585b70"
+overlay_primary = "#7f849c"
+overlay_secondary = "#9399b2"
+text_primary = "205, 214, 244"
+text_secondary = "#a6adc8"
+text_tertiary = "#bac2de"
+accent_interactive = "#74c7ec"
+accent_heading = "#cba6f7"
+accent_emphasis = "#b4befe"
+green = "#a6e3a1"
+yellow = "#f9e2af"
+red = [243, 139, 168]
+"##;
+
+    /// What: Write `content` to a fresh temp file named `name` and return its directory guard and path.
+    fn write_temp(name: &str, content: &str) -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::TempDir::new().expect("temp dir should be creatable");
+        let path = dir.path().join(name);
+        std::fs::write(&path, content).expect("write temp theme file");
+        (dir, path)
+    }
+
+    #[test]
+    /// What: Verify complete TOML and JSON palettes load into a `Theme`.
+    ///
+    /// Inputs:
+    /// - `FULL_TOML` as `palette.toml`, and the same palette converted to `palette.json`.
+    ///
+    /// Output:
+    /// - Both load; hex, `R,G,B`, and array values all parse.
+    fn loads_valid_toml_and_json() {
+        let (_dir, path) = write_temp("palette.toml", FULL_TOML);
+        let theme = load_theme_from_path(&path).expect("valid TOML palette");
+        assert_eq!(theme.base, Color::Rgb(30, 30, 46));
+        assert_eq!(theme.text, Color::Rgb(205, 214, 244));
+        assert_eq!(theme.red, Color::Rgb(243, 139, 168));
+
+        let table: toml::Table = toml::from_str(FULL_TOML).expect("fixture is TOML");
+        let json = serde_json::to_string(&table).expect("fixture converts to JSON");
+        let (_dir, path) = write_temp("palette.json", &json);
+        let from_json = load_theme_from_path(&path).expect("valid JSON palette");
+        assert_eq!(from_json.base, theme.base);
+        assert_eq!(from_json.text, theme.text);
+        assert_eq!(from_json.red, theme.red);
+    }
+
+    #[test]
+    /// What: Verify a palette without a required color reports it by preferred name.
+    ///
+    /// Inputs:
+    /// - `FULL_TOML` without the `green` line.
+    ///
+    /// Output:
+    /// - `ThemeError::MissingFields(["semantic_success"])`.
+    fn reports_missing_field() {
+        let content: String = FULL_TOML
+            .lines()
+            .filter(|line| !line.starts_with("green"))
+            .flat_map(|line| [line, "\n"])
+            .collect();
+        let (_dir, path) = write_temp("palette.toml", &content);
+        match load_theme_from_path(&path) {
+            Err(ThemeError::MissingFields(fields)) => assert_eq!(fields, ["semantic_success"]),
+            other => panic!("expected MissingFields, got {other:?}"),
+        }
+    }
+
+    #[test]
+    /// What: Verify malformed files, bad colors, and unknown keys are rejected.
+    ///
+    /// Inputs:
+    /// - Broken TOML, a JSON array, an invalid color value, a misspelled key, a missing file.
+    ///
+    /// Output:
+    /// - `Parse`, `Parse`, `InvalidColor`, `UnknownField` with a suggestion, and `Io`.
+    fn rejects_malformed_input() {
+        let (_dir, path) = write_temp("palette.toml", "background_base = \"#1e1e2e\n");
+        assert!(matches!(
+            load_theme_from_path(&path),
+            Err(ThemeError::Parse { .. })
+        ));
+
+        let (_dir, path) = write_temp("palette.json", "[\"#1e1e2e\"]");
+        assert!(matches!(
+            load_theme_from_path(&path),
+            Err(ThemeError::Parse { .. })
+        ));
+
+        let (_dir, path) = write_temp("palette.toml", "background_base = \"blue\"\n");
+        assert!(matches!(
+            load_theme_from_path(&path),
+            Err(ThemeError::InvalidColor { field, .. }) if field == "background_base"
+        ));
+
+        let (_dir, path) = write_temp("palette.toml", "lavendr = \"#b4befe\"\n");
+        assert!(matches!(
+            load_theme_from_path(&path),
+            Err(ThemeError::UnknownField { suggestion: Some(s), .. }) if s == "accent_emphasis"
+        ));
+
+        let missing = path.with_file_name("absent.toml");
+        assert!(matches!(
+            load_theme_from_path(&missing),
+            Err(ThemeError::Io { .. })
+        ));
+    }
+}
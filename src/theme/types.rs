@@ -44,7 +44,7 @@ pub struct Theme {
 }
 
 /// User-configurable application settings parsed from `pacsea.conf`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PackageMarker {
     /// Color the entire line for the marked package.
     FullLine,
@@ -52,6 +52,130 @@ pub enum PackageMarker {
     Front,
     /// Add a marker at the end of the line.
     End,
+    /// Front marker using only ASCII labels (`[+]`, `[-]`, `[v]`).
+    Ascii,
+    /// Front marker using Nerd Font glyphs; needs a patched font.
+    NerdFont,
+    /// Front marker with user-defined labels (`package_marker_install/remove/downgrade`).
+    Custom {
+        /// Label for packages queued for install.
+        install: String,
+        /// Label for packages queued for removal.
+        remove: String,
+        /// Label for packages queued for downgrade.
+        downgrade: String,
+    },
+}
+
+/// Which queue a marked package belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkerKind {
+    /// Install list.
+    Install,
+    /// Remove list.
+    Remove,
+    /// Downgrade list.
+    Downgrade,
+}
+
+impl PackageMarker {
+    /// What: Canonical `package_marker` setting value of this marker.
+    #[must_use]
+    pub const fn as_key(&self) -> &'static str {
+        match self {
+            Self::FullLine => "full_line",
+            Self::Front => "front",
+            Self::End => "end",
+            Self::Ascii => "ascii",
+            Self::NerdFont => "nerd_font",
+            Self::Custom { .. } => "custom",
+        }
+    }
+
+    /// What: Marker label shown for a package in the given queue.
+    ///
+    /// Inputs:
+    /// - `kind`: Queue the package is in.
+    /// - `unicode`: Whether the terminal can render non-ASCII glyphs.
+    ///
+    /// Output:
+    /// - The label text, e.g. `[+]` for installs with the default markers.
+    ///
+    /// Details:
+    /// - Non-ASCII labels (built-in or custom) fall back to the `Ascii` label when `unicode` is false.
+    #[must_use]
+    pub const fn label(&self, kind: MarkerKind, unicode: bool) -> &str {
+        let label = match (self, kind) {
+            (Self::Custom { install, .. }, MarkerKind::Install) => install.as_str(),
+            (Self::Custom { remove, .. }, MarkerKind::Remove) => remove.as_str(),
+            (Self::Custom { downgrade, .. }, MarkerKind::Downgrade) => downgrade.as_str(),
+            (Self::NerdFont, MarkerKind::Install) => "\u{f067}",
+            (Self::NerdFont, MarkerKind::Remove) => "\u{f068}",
+            (Self::NerdFont, MarkerKind::Downgrade) => "\u{f063}",
+            (Self::FullLine | Self::Front | Self::End, MarkerKind::Downgrade) => "[↓]",
+            (_, kind) => Self::ascii_label(kind),
+        };
+        if unicode || label.is_ascii() {
+            label
+        } else {
+            Self::ascii_label(kind)
+        }
+    }
+
+    /// What: ASCII-only label for a queue, used by `Ascii` and as the non-Unicode fallback.
+    const fn ascii_label(kind: MarkerKind) -> &'static str {
+        match kind {
+            MarkerKind::Install => "[+]",
+            MarkerKind::Remove => "[-]",
+            MarkerKind::Downgrade => "[v]",
+        }
+    }
+}
+
+/// Preflight risk-scoring weights (`preflight_risk_*` settings).
+///
+/// Each weight is the number of points a risk factor adds to the preflight risk score;
+/// `high_threshold` is the score at which the risk level becomes High (any non-zero score
+/// below it is Medium).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RiskWeights {
+    /// Core/system packages involved.
+    pub core: u8,
+    /// Major version bump detected.
+    pub major_bump: u8,
+    /// AUR packages included.
+    pub aur: u8,
+    /// Configuration files may produce `.pacnew`.
+    pub pacnew: u8,
+    /// Services likely require restart.
+    pub services: u8,
+    /// Per installed package depending on packages being updated.
+    pub dependent: u8,
+    /// Downgrade breaks installed packages' version requirements.
+    pub downgrade_conflict: u8,
+    /// Package or file conflicts reported by the dry-run conflict check.
+    pub conflict: u8,
+    /// AUR packages whose sandbox analysis is rated high risk.
+    pub sandbox: u8,
+    /// Lowest score rated High.
+    pub high_threshold: u8,
+}
+
+impl Default for RiskWeights {
+    fn default() -> Self {
+        Self {
+            core: 3,
+            major_bump: 2,
+            aur: 2,
+            pacnew: 1,
+            services: 1,
+            dependent: 2,
+            downgrade_conflict: 3,
+            conflict: 3,
+            sandbox: 3,
+            high_threshold: 5,
+        }
+    }
 }
 
 /// User-configurable application settings parsed from `pacsea.conf`.
@@ -87,8 +211,35 @@ pub struct Settings {
     pub clipboard_suffix: String,
     /// Whether the Search history pane should be shown on startup.
     pub show_recent_pane: bool,
+    /// Whether the Search history pane shows each query's last result count, e.g. `ripgrep (3)`.
+    pub recent_show_result_counts: bool,
     /// Whether the Install/Remove pane should be shown on startup.
     pub show_install_pane: bool,
+    /// Subpane focused when entering installed-only mode: "remove" (default) or "downgrade".
+    pub installed_only_default_focus: String,
+    /// Whether installed-only results show each package's on-disk installed size.
+    pub installed_only_show_size: bool,
+    /// Search input handling when switching between Package and News modes:
+    /// "preserve" (default) keeps each mode's last input, "clear" empties it on entry.
+    pub mode_switch_search_input: String,
+    /// Whether result rows are prefixed with their 1-based number (for `:` + number jumps).
+    pub results_show_numbers: bool,
+    /// How repository labels render in results/details: "full" (default), "short" (`E`),
+    /// or "icon" (glyphs for well-known repos).
+    pub results_repo_label_style: String,
+    /// Maximum AUR comments shown before a "load more" line (`0` shows all).
+    pub comments_max_display: usize,
+    /// AUR comment ordering: `"pinned_first"` (default) or `"newest_first"`.
+    pub comments_order: String,
+    /// Whether Escape in Search normal mode, with no modal, find prompt, or dropdown active,
+    /// prompts to quit (a second Escape exits). Disabled by default.
+    pub escape_quits_from_search: bool,
+    /// Maximum wrapped rows shown for a package description in the details pane before it is
+    /// collapsed behind an expand toggle. `0` always shows the full description.
+    pub details_description_max_lines: usize,
+    /// Lines scrolled per mouse-wheel notch in the details, PKGBUILD, and comments panes
+    /// (at least 1).
+    pub mouse_scroll_lines: u16,
     /// Whether the keybinds footer should be shown on startup.
     pub show_keybinds_footer: bool,
     /// Selected countries used when updating mirrors (comma-separated or multiple).
@@ -97,6 +248,14 @@ pub struct Settings {
     pub mirror_count: u16,
     /// Preferred AUR helper for CLI operations: "auto" (detect, paru preferred), "paru", or "yay".
     pub aur_helper: String,
+    /// When true, check for paru/yay right before an AUR install and show guidance if neither is found.
+    pub aur_helper_install_check: bool,
+    /// Install mode: `"standard"` (official + AUR) or `"pacman_only"` (never invoke an AUR helper).
+    pub install_mode: String,
+    /// Number of parallel AUR helper terminals for batch installs (1 keeps one serial terminal).
+    pub install_aur_parallel_jobs: usize,
+    /// Custom full-upgrade command template (`{privilege}`/`{aur_helper}` placeholders); empty uses the built-in commands.
+    pub upgrade_command: String,
     /// `VirusTotal` API key for security scanning.
     pub virustotal_api_key: String,
     /// Whether to run `ClamAV` scan on AUR packages.
@@ -152,18 +311,86 @@ pub struct Settings {
     /// How many days to keep Arch news and advisories cached on disk.
     /// Default is 7 days. Helps reduce network requests on startup.
     pub news_cache_ttl_days: u32,
+    /// Override for the Arch news RSS feed URL (`news_arch_feed_url`). Empty uses the built-in URL.
+    pub news_arch_feed_url: String,
+    /// Override for the security advisory feed URL (`news_advisory_url`). Empty uses the built-in URL.
+    pub news_advisory_url: String,
+    /// Maximum number of slow news sources (Arch news, advisories) fetched at the same time.
+    /// Default is 1, which fetches them one after another.
+    pub news_max_parallel_slow_fetches: usize,
     /// Visual marker style for packages added to Install/Remove/Downgrade lists.
     pub package_marker: PackageMarker,
+    /// Install label used when `package_marker = custom`.
+    pub package_marker_install: String,
+    /// Remove label used when `package_marker = custom`.
+    pub package_marker_remove: String,
+    /// Downgrade label used when `package_marker = custom`.
+    pub package_marker_downgrade: String,
     /// Symbol used to mark a news item as read in the News modal.
     pub news_read_symbol: String,
     /// Symbol used to mark a news item as unread in the News modal.
     pub news_unread_symbol: String,
+    /// Comma-separated news metadata fields shown under the title, in display order
+    /// (`date`, `source`, `severity`, `packages`). Omitted fields are hidden; `none` hides all.
+    pub news_details_fields: String,
+    /// When true, render the news metadata fields on a single line (useful for small terminals).
+    pub news_details_compact: bool,
+    /// When true (default), remember the news content scroll offset per item for the session
+    /// and restore it when the item is selected again.
+    pub news_remember_scroll: bool,
     /// Preferred terminal binary name to spawn for shell commands (e.g., "alacritty", "kitty", "gnome-terminal").
     /// When empty, Pacsea auto-detects from available terminals.
     pub preferred_terminal: String,
     /// When true, skip the Preflight modal and execute actions directly (install/remove/downgrade).
     /// Defaults to false to preserve the safer, review-first workflow.
     pub skip_preflight: bool,
+    /// Minimum number of reinstalled (already installed, no update) packages that triggers the
+    /// reinstall confirmation. `1` (default) and `0` always confirm.
+    pub reinstall_confirm_threshold: usize,
+    /// Comma list of sources whose reinstalls never need confirmation
+    /// (`aur`, `official`, or repository names such as `extra`). Empty by default.
+    pub reinstall_confirm_skip_sources: String,
+    /// How many levels of AUR dependencies of AUR dependencies the preflight Deps tab expands.
+    /// `0` (default) lists direct dependencies only and avoids extra AUR RPC calls.
+    pub preflight_aur_dep_depth: usize,
+    /// Maximum packages per batched `pacman -Si` call in the preflight summary (default 100).
+    /// Keeps huge install lists below argv length limits.
+    pub preflight_metadata_chunk_size: usize,
+    /// Maximum batched `pacman -Si` calls the preflight summary runs at once (default 4).
+    pub preflight_metadata_parallelism: usize,
+    /// Extra package names (lowercase) the preflight summary treats as core/system packages,
+    /// in addition to the built-in list (`preflight_extra_critical_packages`). Empty by default.
+    pub preflight_extra_critical_packages: Vec<String>,
+    /// Preflight risk-scoring weights and High cutoff (`preflight_risk_*`).
+    pub preflight_risk_weights: RiskWeights,
+    /// When true (default), the preflight summary for downgrades checks installed dependents'
+    /// versioned requirements (e.g. `foo>=2.0`) and warns when the downgrade would break them.
+    pub downgrade_conflict_check: bool,
+    /// Notification when a tracked install/remove finishes: "off" (default), "bell" (terminal
+    /// bell), "desktop" (`notify-send` when available), or "both".
+    pub completion_notify: String,
+    /// When true, preflight offers a toggle (`o`) revealing the raw `pacman`/AUR helper output
+    /// behind the summarized metadata and dependencies. Defaults to false.
+    pub expert_mode: bool,
+    /// After a transaction, remind about newly created `.pacnew`/`.pacsave` files and offer
+    /// `pacdiff`. Defaults to true.
+    pub pacnew_reminder: bool,
+    /// Unit convention for byte sizes (`ui_size_units`) across preflight, results, and details: "binary"
+    /// (default; KiB/MiB like pacman) or "si" (kB/MB).
+    pub size_units: String,
+    /// Open the PKGBUILD viewer on startup once the first selection loads. Defaults to false.
+    pub pkgb_visible: bool,
+    /// Open the AUR comments viewer on startup once an AUR selection loads. Defaults to false.
+    pub comments_visible: bool,
+    /// Write viewer toggles back to `pkgb_visible`/`comments_visible` so the next session
+    /// restores them. Defaults to false.
+    pub remember_viewers: bool,
+    /// Group the Install pane by source (official repositories first, then AUR) with
+    /// package counts per group. Defaults to false.
+    pub install_group_by_source: bool,
+    /// Keep the install log pane scrolled to the newest output while a transaction streams
+    /// into it. `f` pauses or resumes following per run. Defaults to true.
+    pub exec_log_follow: bool,
     /// Locale code for translations (e.g., "de-DE", "en-US").
     /// Empty string means auto-detect from system locale.
     pub locale: String,
@@ -175,6 +402,27 @@ pub struct Settings {
     /// When false, uses normal substring search (default).
     /// When true, uses fuzzy matching (fzf-style).
     pub fuzzy_search: bool,
+    /// Whether to auto-select the first result when a new result set arrives
+    /// and the previously selected package is no longer present.
+    /// When false, the previous list position is kept (clamped to the new length).
+    pub auto_select_first_result: bool,
+    /// Query normalization before ranking and search-cache comparison: "trim" (default, trims
+    /// and lowercases), "collapse" (also collapses internal whitespace runs), or "off".
+    pub search_normalize: String,
+    /// Show "did you mean" suggestions from the official index when a query has few or no results.
+    pub search_suggestions: bool,
+    /// Right-column panes whose pane-find also matches package descriptions
+    /// (comma list of `install`, `remove`, `downgrade`; `all`/`none` accepted). Default: `install`.
+    pub find_match_description: String,
+    /// Ordered official repository names used to rank results (`search_repo_priority`).
+    /// Unlisted repos follow the listed ones, then AUR. Empty keeps core, extra, others, AUR.
+    pub repo_priority: Vec<String>,
+    /// Architecture official packages are ranked for (`search_default_arch`); `auto` uses `uname -m`.
+    /// Packages of other architectures sink below host and `any` packages in results.
+    pub default_arch: String,
+    /// Quiet time in milliseconds after the last query change before a search runs
+    /// (`search_debounce_ms`, 0-5000). Default is 250.
+    pub search_debounce_ms: u64,
     /// Refresh interval in seconds for pacman -Qu and AUR helper checks.
     /// Default is 30 seconds. Set to a higher value to reduce resource usage on slow systems.
     pub updates_refresh_interval: u64,
@@ -210,6 +458,10 @@ pub struct Settings {
     /// and the terminal is on the supported list (alacritty, kitty, konsole, ghostty, xterm,
     /// gnome-terminal, xfce4-terminal, tilix, mate-terminal, wezterm-gui, `WezTerm`).
     pub use_terminal_theme: bool,
+    /// Path of a user TOML or JSON palette (`theme_file`) that replaces theme.conf when set.
+    /// Relative paths resolve against the config directory; `~/` expands to `$HOME`.
+    /// Empty (default) keeps theme.conf. An invalid file is logged and ignored.
+    pub theme_file: String,
     /// Whether AUR voting via SSH is enabled.
     /// Requires an SSH key uploaded to the user's AUR account.
     pub aur_vote_enabled: bool,
@@ -218,6 +470,29 @@ pub struct Settings {
     /// SSH binary path or name for AUR vote commands.
     /// Defaults to `"ssh"`. Override for non-standard SSH setups.
     pub aur_vote_ssh_command: String,
+    /// Whether spawned executor/terminal commands are appended to `logs/audit.log`.
+    /// Config key `audit_log_commands`; passwords are redacted.
+    pub audit_log_commands: bool,
+    /// Maximum number of entries kept in `lists/activity_log.jsonl`; `0` keeps everything.
+    pub activity_log_max_lines: usize,
+    /// Curl timeout override for the Arch news feed (`network_timeout_news = CONNECT,MAX`).
+    /// `None` keeps the built-in 10s/15s.
+    pub network_timeout_news: Option<crate::util::CurlTimeouts>,
+    /// Curl timeout override for AUR RPC requests. `None` keeps the global 30s/90s.
+    pub network_timeout_aur_rpc: Option<crate::util::CurlTimeouts>,
+    /// Curl timeout override for PKGBUILD downloads. `None` keeps the built-in 8s/10s.
+    pub network_timeout_pkgbuild: Option<crate::util::CurlTimeouts>,
+    /// Curl timeout override for `status.archlinux.org` checks. `None` keeps the global 30s/90s.
+    pub network_timeout_status: Option<crate::util::CurlTimeouts>,
+    /// Global curl `--connect-timeout` in seconds (`network.connect_timeout_secs`).
+    /// `None` keeps the built-in 30s; per-source overrides take precedence.
+    pub network_connect_timeout_secs: Option<u64>,
+    /// Global curl `--max-time` in seconds (`network.max_time_secs`).
+    /// `None` keeps the built-in 90s; per-source overrides take precedence.
+    pub network_max_time_secs: Option<u64>,
+    /// Proxy URL passed to curl as `--proxy` (`network.proxy`).
+    /// `None` falls back to `HTTPS_PROXY`/`ALL_PROXY`; an empty value disables proxying.
+    pub network_proxy: Option<String>,
     /// Dynamic results-list toggles from `repos.conf` filter ids (canonical keys, see `repos` module).
     ///
     /// Keys match canonical `results_filter` tokens from repos.conf (e.g. `vendor_pkgs` for `results_filter_show_vendor_pkgs`).
@@ -253,11 +528,26 @@ impl Default for Settings {
             clipboard_suffix: "Check PKGBUILD and source for suspicious and malicious activities"
                 .to_string(),
             show_recent_pane: true,
+            recent_show_result_counts: true,
             show_install_pane: true,
+            installed_only_default_focus: "remove".to_string(),
+            installed_only_show_size: true,
+            mode_switch_search_input: "preserve".to_string(),
+            results_show_numbers: false,
+            results_repo_label_style: "full".to_string(),
+            comments_max_display: 50,
+            comments_order: "pinned_first".to_string(),
+            escape_quits_from_search: false,
+            details_description_max_lines: 4,
+            mouse_scroll_lines: 3,
             show_keybinds_footer: true,
             selected_countries: "Worldwide".to_string(),
             mirror_count: 20,
             aur_helper: "auto".to_string(),
+            aur_helper_install_check: true,
+            install_mode: "standard".to_string(),
+            install_aur_parallel_jobs: 1,
+            upgrade_command: String::new(),
             virustotal_api_key: String::new(),
             scan_do_clamav: true,
             scan_do_trivy: true,
@@ -284,14 +574,47 @@ impl Default for Settings {
             startup_news_show_pkg_updates: true,
             startup_news_max_age_days: Some(7),
             news_cache_ttl_days: 7,
+            news_arch_feed_url: String::new(),
+            news_advisory_url: String::new(),
+            news_max_parallel_slow_fetches: 1,
             package_marker: PackageMarker::Front,
+            package_marker_install: "[+]".to_string(),
+            package_marker_remove: "[-]".to_string(),
+            package_marker_downgrade: "[↓]".to_string(),
             news_read_symbol: "✓".to_string(),
             news_unread_symbol: "∘".to_string(),
+            news_details_fields: "date, source, severity, packages".to_string(),
+            news_details_compact: false,
+            news_remember_scroll: true,
             preferred_terminal: String::new(),
             skip_preflight: false,
-            locale: String::new(),        // Empty means auto-detect from system
-            search_startup_mode: false,   // Default to insert mode
-            fuzzy_search: false,          // Default to normal substring search
+            reinstall_confirm_threshold: 1,
+            reinstall_confirm_skip_sources: String::new(),
+            preflight_aur_dep_depth: 0,
+            preflight_metadata_chunk_size: 100,
+            preflight_metadata_parallelism: 4,
+            preflight_extra_critical_packages: Vec::new(),
+            preflight_risk_weights: RiskWeights::default(),
+            downgrade_conflict_check: true,
+            completion_notify: "off".to_string(),
+            expert_mode: false,
+            pacnew_reminder: true,
+            size_units: "binary".to_string(),
+            pkgb_visible: false,
+            comments_visible: false,
+            remember_viewers: false,
+            install_group_by_source: false,
+            exec_log_follow: true,
+            locale: String::new(),      // Empty means auto-detect from system
+            search_startup_mode: false, // Default to insert mode
+            fuzzy_search: false,        // Default to normal substring search
+            auto_select_first_result: true,
+            search_normalize: "trim".to_string(),
+            search_suggestions: true,
+            find_match_description: "install".to_string(),
+            repo_priority: Vec::new(),
+            default_arch: "auto".to_string(),
+            search_debounce_ms: 250,
             updates_refresh_interval: 30, // Default to 30 seconds
             installed_packages_mode: crate::state::InstalledPackagesMode::LeafOnly,
             get_announcement: true, // Default to fetching remote announcements
@@ -299,9 +622,19 @@ impl Default for Settings {
             privilege_mode: crate::logic::privilege::PrivilegeMode::Auto, // Default to auto-detect (prefer doas, fallback sudo)
             auth_mode: crate::logic::privilege::AuthMode::Prompt, // Default to Pacsea password modal
             use_terminal_theme: false, // Default to using theme.conf colors
-            aur_vote_enabled: true,    // Enabled by default; requires SSH key configured on AUR
+            theme_file: String::new(),
+            aur_vote_enabled: true, // Enabled by default; requires SSH key configured on AUR
             aur_vote_ssh_timeout_seconds: 10,
             aur_vote_ssh_command: "ssh".to_string(),
+            audit_log_commands: false,
+            activity_log_max_lines: 1000,
+            network_timeout_news: None,
+            network_timeout_aur_rpc: None,
+            network_timeout_pkgbuild: None,
+            network_timeout_status: None,
+            network_connect_timeout_secs: None,
+            network_max_time_secs: None,
+            network_proxy: None,
             results_filter_toggles: HashMap::new(),
         }
     }
@@ -432,6 +765,14 @@ pub struct KeyMap {
     pub help_overlay: Vec<KeyChord>,
     /// Key chords to reload configuration.
     pub reload_config: Vec<KeyChord>,
+    /// Global: Retry background fetches (news, advisories, updates, status) that last failed
+    pub retry_failed_fetches: Vec<KeyChord>,
+    /// Global: Open the command palette (fuzzy action launcher)
+    pub command_palette: Vec<KeyChord>,
+    /// Global: Copy the focused package's name to the clipboard
+    pub copy_package_name: Vec<KeyChord>,
+    /// Global: Copy the focused package's details (name, version, repo, description, URL)
+    pub copy_package_details: Vec<KeyChord>,
     /// Key chords to exit the application.
     pub exit: Vec<KeyChord>,
     /// Global: Show/Hide PKGBUILD viewer
@@ -450,6 +791,10 @@ pub struct KeyMap {
     pub pane_left: Vec<KeyChord>,
     /// Key chords to move focus right.
     pub pane_right: Vec<KeyChord>,
+    /// Key chords to move the selection up in the Install and Recent panes.
+    pub pane_up: Vec<KeyChord>,
+    /// Key chords to move the selection down in the Install and Recent panes.
+    pub pane_down: Vec<KeyChord>,
     /// Global: Toggle Config/Lists dropdown
     pub config_menu_toggle: Vec<KeyChord>,
     /// Global: Toggle Options dropdown
@@ -470,6 +815,8 @@ pub struct KeyMap {
     pub search_add: Vec<KeyChord>,
     /// Key chords to install selected package.
     pub search_install: Vec<KeyChord>,
+    /// Install the selected result as a one-off transaction, bypassing the Install list (default: Alt+Enter)
+    pub search_quick_install: Vec<KeyChord>,
     /// Key chords to move focus left from search pane.
     pub search_focus_left: Vec<KeyChord>,
     /// Key chords to move focus right from search pane.
@@ -502,6 +849,10 @@ pub struct KeyMap {
     pub search_normal_updates: Vec<KeyChord>,
     /// Toggle fuzzy search mode on/off
     pub toggle_fuzzy: Vec<KeyChord>,
+    /// Flip the selected result between its AUR and official variant (default: Ctrl+V)
+    pub search_toggle_variant: Vec<KeyChord>,
+    /// Re-run the search with the first "did you mean" suggestion (default: Ctrl+Y)
+    pub search_accept_suggestion: Vec<KeyChord>,
 
     // Recent
     /// Key chords to move selection up in recent queries.
@@ -540,6 +891,14 @@ pub struct KeyMap {
     pub install_to_search: Vec<KeyChord>,
     /// Key chords to move focus left from install pane.
     pub install_focus_left: Vec<KeyChord>,
+    /// Key chords to open the named install-list profile picker.
+    pub install_profiles: Vec<KeyChord>,
+    /// Key chords to move the selected install list entry up one row.
+    pub install_reorder_up: Vec<KeyChord>,
+    /// Key chords to move the selected install list entry down one row.
+    pub install_reorder_down: Vec<KeyChord>,
+    /// Key chords to copy an install command for the whole install list.
+    pub install_copy_command: Vec<KeyChord>,
 
     // News modal
     /// Mark currently listed News items as read (without opening URL)
@@ -552,6 +911,10 @@ pub struct KeyMap {
     pub news_mark_unread_feed: Vec<KeyChord>,
     /// Toggle read/unread for selected News Feed item.
     pub news_toggle_read_feed: Vec<KeyChord>,
+    /// Copy selected News Feed item to the clipboard as Markdown.
+    pub news_export_markdown_feed: Vec<KeyChord>,
+    /// Mark all visible News Feed items as read.
+    pub news_mark_all_read_feed: Vec<KeyChord>,
 }
 
 /// Type alias for global key bindings tuple.
@@ -629,6 +992,21 @@ type InstallKeys = (
     Vec<KeyChord>,
     Vec<KeyChord>,
     Vec<KeyChord>,
+    Vec<KeyChord>,
+    Vec<KeyChord>,
+    Vec<KeyChord>,
+    Vec<KeyChord>,
+);
+
+/// Type alias for News Feed key bindings tuple.
+///
+/// Contains 5 `Vec<KeyChord>` for marking, toggling, and exporting feed items.
+type NewsFeedKeys = (
+    Vec<KeyChord>,
+    Vec<KeyChord>,
+    Vec<KeyChord>,
+    Vec<KeyChord>,
+    Vec<KeyChord>,
 );
 
 /// What: Create default global key bindings.
@@ -970,6 +1348,22 @@ fn default_install_keys(none: KeyModifiers, shift: KeyModifiers) -> InstallKeys
             code: Left,
             mods: none,
         }],
+        vec![KeyChord {
+            code: Char('p'),
+            mods: none,
+        }],
+        vec![KeyChord {
+            code: Char('k'),
+            mods: shift,
+        }],
+        vec![KeyChord {
+            code: Char('j'),
+            mods: shift,
+        }],
+        vec![KeyChord {
+            code: Char('y'),
+            mods: none,
+        }],
     )
 }
 
@@ -1002,13 +1396,15 @@ fn default_news_keys(none: KeyModifiers, ctrl: KeyModifiers) -> (Vec<KeyChord>,
 ///
 /// Inputs:
 /// - `none`: Empty key modifiers
+/// - `shift`: Shift modifier
 ///
 /// Output:
 /// - Tuple of news feed key binding vectors
 ///
 /// Details:
-/// - Returns `news_mark_read_feed`, `news_mark_unread_feed`, and `news_toggle_read_feed`.
-fn default_news_feed_keys(none: KeyModifiers) -> (Vec<KeyChord>, Vec<KeyChord>, Vec<KeyChord>) {
+/// - Returns `news_mark_read_feed`, `news_mark_unread_feed`, `news_toggle_read_feed`,
+///   `news_export_markdown_feed`, and `news_mark_all_read_feed`.
+fn default_news_feed_keys(none: KeyModifiers, shift: KeyModifiers) -> NewsFeedKeys {
     use KeyCode::Char;
     (
         vec![KeyChord {
@@ -1023,6 +1419,14 @@ fn default_news_feed_keys(none: KeyModifiers) -> (Vec<KeyChord>, Vec<KeyChord>,
             code: Char('t'),
             mods: none,
         }],
+        vec![KeyChord {
+            code: Char('y'),
+            mods: none,
+        }],
+        vec![KeyChord {
+            code: Char('r'),
+            mods: shift,
+        }],
     )
 }
 
@@ -1049,11 +1453,27 @@ fn build_default_keymap() -> KeyMap {
     let recent = default_recent_keys(none, shift);
     let install = default_install_keys(none, shift);
     let news = default_news_keys(none, ctrl);
-    let news_feed = default_news_feed_keys(none);
+    let news_feed = default_news_feed_keys(none, shift);
 
     KeyMap {
         help_overlay: global.0,
         reload_config: global.1,
+        retry_failed_fetches: vec![KeyChord {
+            code: KeyCode::Char('r'),
+            mods: KeyModifiers::ALT,
+        }],
+        command_palette: vec![KeyChord {
+            code: KeyCode::Char('p'),
+            mods: ctrl,
+        }],
+        copy_package_name: vec![KeyChord {
+            code: KeyCode::Char('c'),
+            mods: KeyModifiers::ALT,
+        }],
+        copy_package_details: vec![KeyChord {
+            code: KeyCode::Char('d'),
+            mods: KeyModifiers::ALT,
+        }],
         exit: global.2,
         show_pkgbuild: global.3,
         comments_toggle: global.4,
@@ -1066,6 +1486,14 @@ fn build_default_keymap() -> KeyMap {
         pane_next: global.7,
         pane_left: global.8,
         pane_right: global.9,
+        pane_up: vec![KeyChord {
+            code: KeyCode::Up,
+            mods: none,
+        }],
+        pane_down: vec![KeyChord {
+            code: KeyCode::Down,
+            mods: none,
+        }],
         config_menu_toggle: dropdown.0,
         options_menu_toggle: dropdown.1,
         panels_menu_toggle: dropdown.2,
@@ -1075,6 +1503,10 @@ fn build_default_keymap() -> KeyMap {
         search_page_down: search.3,
         search_add: search.4,
         search_install: search.5,
+        search_quick_install: vec![KeyChord {
+            code: KeyCode::Enter,
+            mods: KeyModifiers::ALT,
+        }],
         search_focus_left: search.6,
         search_focus_right: search.7,
         search_backspace: search.8,
@@ -1093,6 +1525,14 @@ fn build_default_keymap() -> KeyMap {
             code: KeyCode::Char('f'),
             mods: ctrl,
         }],
+        search_toggle_variant: vec![KeyChord {
+            code: KeyCode::Char('v'),
+            mods: ctrl,
+        }],
+        search_accept_suggestion: vec![KeyChord {
+            code: KeyCode::Char('y'),
+            mods: ctrl,
+        }],
         recent_move_up: recent.0,
         recent_move_down: recent.1,
         recent_find: recent.2,
@@ -1110,11 +1550,17 @@ fn build_default_keymap() -> KeyMap {
         install_find: install.5,
         install_to_search: install.6,
         install_focus_left: install.7,
+        install_profiles: install.8,
+        install_reorder_up: install.9,
+        install_reorder_down: install.10,
+        install_copy_command: install.11,
         news_mark_read: news.0,
         news_mark_all_read: news.1,
         news_mark_read_feed: news_feed.0,
         news_mark_unread_feed: news_feed.1,
         news_toggle_read_feed: news_feed.2,
+        news_export_markdown_feed: news_feed.3,
+        news_mark_all_read_feed: news_feed.4,
     }
 }
 
@@ -53,6 +53,33 @@ pub fn cycle_pkgbuild_view_section(app: &mut AppState) {
     details::cycle_pkgbuild_view_section(app);
 }
 
+/// What: Largest `details_scroll` for the package details at the last rendered pane width.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Last zero-based line index of the details content.
+///
+/// Details:
+/// - Lets mouse-wheel handlers clamp at content bounds without reaching into `details`.
+#[must_use]
+pub fn details_max_scroll(app: &AppState) -> usize {
+    details::details_max_scroll(app)
+}
+
+/// What: Largest `pkgb_scroll` for the current PKGBUILD viewer content.
+///
+/// Inputs:
+/// - `app`: Application state.
+///
+/// Output:
+/// - Last zero-based line index of the PKGBUILD pane (body plus checks appendix).
+#[must_use]
+pub fn pkgbuild_max_scroll(app: &AppState) -> usize {
+    details::pkgbuild_max_scroll(app)
+}
+
 /// What: Layout height constraints for UI panes.
 ///
 /// Inputs: None (struct definition)
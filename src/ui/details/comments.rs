@@ -546,6 +546,8 @@ fn render_single_comment(
 /// Details:
 /// - Applies scroll offset by skipping items from top.
 /// - Renders each comment with header, content, and separator.
+/// - Appends a clickable "load more" line when comments are hidden beyond the display cap or
+///   older AUR comment pages remain to be fetched.
 fn build_comment_items(
     app: &mut AppState,
     th: &crate::theme::Theme,
@@ -566,6 +568,37 @@ fn build_comment_items(
         current_y = new_y;
         items.push(item);
     }
+
+    let load_more_label = if !app.comments_hidden.is_empty() {
+        let batch = crate::sources::load_more_batch(
+            app.comments_hidden.len(),
+            crate::theme::settings().comments_max_display,
+        );
+        Some(i18n::t_fmt1(app, "app.details.load_more_comments", batch))
+    } else if app.comments_page_loading {
+        Some(i18n::t(app, "app.details.loading_more_comments"))
+    } else if app.comments_next_offset.is_some() {
+        Some(i18n::t(app, "app.details.load_more_comments_page"))
+    } else {
+        None
+    };
+    if let Some(label) = load_more_label {
+        // Only clickable when the line is inside the pane (above the bottom border)
+        if current_y < comments_area.y + comments_area.height.saturating_sub(1) {
+            app.comments_load_more_rect = Some((
+                content_x,
+                current_y,
+                u16::try_from(label.width()).unwrap_or(u16::MAX),
+                1,
+            ));
+        }
+        items.push(ListItem::new(Line::from(Span::styled(
+            label,
+            Style::default()
+                .fg(th.sapphire)
+                .add_modifier(Modifier::BOLD),
+        ))));
+    }
     items
 }
 
@@ -596,6 +629,7 @@ pub fn render_comments(f: &mut Frame, app: &mut AppState, comments_area: Rect) {
     app.comments_urls.clear();
     app.comments_authors.clear();
     app.comments_dates.clear();
+    app.comments_load_more_rect = None;
 
     let title_text = i18n::t(app, "app.titles.comments");
     let title_span = Span::styled(&title_text, Style::default().fg(th.overlay1));
@@ -622,3 +656,89 @@ pub fn render_comments(f: &mut Frame, app: &mut AppState, comments_area: Rect) {
 
     f.render_widget(list, comments_area);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::state::types::AurComment;
+
+    fn comment(id: &str) -> AurComment {
+        AurComment {
+            id: Some(id.to_string()),
+            author: "user".to_string(),
+            date: "2025-01-01 00:00 (UTC)".to_string(),
+            date_timestamp: None,
+            date_url: None,
+            content: format!("comment {id}"),
+            pinned: false,
+        }
+    }
+
+    /// What: Render the comments pane into a test terminal.
+    fn render(app: &mut crate::state::AppState) {
+        use ratatui::{Terminal, backend::TestBackend};
+        let backend = TestBackend::new(60, 30);
+        let mut term = Terminal::new(backend).expect("failed to create test terminal");
+        term.draw(|f| {
+            let area = f.area();
+            super::render_comments(f, app, area);
+        })
+        .expect("failed to draw test terminal");
+    }
+
+    /// What: Confirm the "load more" line only appears when comments are hidden beyond the cap.
+    ///
+    /// Inputs:
+    /// - Two shown comments, first with no hidden comments, then with one hidden comment.
+    ///
+    /// Output:
+    /// - `comments_load_more_rect` stays `None` without hidden comments and is set once truncated.
+    #[test]
+    fn load_more_line_appears_only_when_truncated() {
+        let mut app = crate::state::AppState {
+            comments: vec![comment("a"), comment("b")],
+            ..Default::default()
+        };
+
+        render(&mut app);
+        assert!(app.comments_load_more_rect.is_none());
+
+        app.comments_hidden = vec![comment("c")];
+        render(&mut app);
+        assert!(app.comments_load_more_rect.is_some());
+    }
+
+    /// What: Confirm appending a comments page keeps the click regions of earlier comments.
+    ///
+    /// Inputs:
+    /// - Two comments with date links, rendered; then a third appended and rendered again.
+    ///
+    /// Output:
+    /// - Author and date regions of the first render are an unchanged prefix of the second, and
+    ///   the "load older comments" line appears while a next page offset is set.
+    #[test]
+    fn appended_page_preserves_prior_click_regions() {
+        let linked = |id: &str| crate::state::types::AurComment {
+            date_url: Some(format!("https://aur.archlinux.org/packages/demo#{id}")),
+            ..comment(id)
+        };
+        let mut app = crate::state::AppState {
+            comments: vec![linked("a"), linked("b")],
+            comments_next_offset: Some(crate::sources::COMMENTS_PAGE_SIZE),
+            ..Default::default()
+        };
+
+        render(&mut app);
+        let authors = app.comments_authors.clone();
+        let dates = app.comments_dates.clone();
+        assert_eq!(authors.len(), 2);
+        assert!(app.comments_load_more_rect.is_some());
+
+        app.comments.push(linked("c"));
+        app.comments_next_offset = None;
+        render(&mut app);
+        assert_eq!(app.comments_authors.len(), 3);
+        assert_eq!(app.comments_authors[..2], authors[..]);
+        assert_eq!(app.comments_dates[..2], dates[..]);
+        assert!(app.comments_load_more_rect.is_none());
+    }
+}
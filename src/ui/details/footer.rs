@@ -478,7 +478,7 @@ fn build_install_section(
         (None, Some((Line::from(d_spans), Line::from(r_spans))))
     } else {
         let install_color = get_label_color(matches!(app.focus, Focus::Install), th);
-        let i_spans = build_right_pane_spans(
+        let mut i_spans = build_right_pane_spans(
             app,
             format!("{}  ", i18n::t(app, "app.headings.install")),
             install_color,
@@ -486,6 +486,28 @@ fn build_install_section(
             key_style,
             sep_style,
         );
+        add_dual_keybind_entry(
+            &mut i_spans,
+            app.keymap.install_reorder_up.first(),
+            app.keymap.install_reorder_down.first(),
+            key_style,
+            &i18n::t(app, "app.actions.reorder"),
+            sep_style,
+        );
+        add_keybind_entry(
+            &mut i_spans,
+            app.keymap.install_copy_command.first(),
+            key_style,
+            &i18n::t(app, "app.actions.copy_install_command"),
+            sep_style,
+        );
+        add_keybind_entry(
+            &mut i_spans,
+            app.keymap.install_profiles.first(),
+            key_style,
+            &i18n::t(app, "app.actions.install_profiles"),
+            sep_style,
+        );
         (Some(Line::from(i_spans)), None)
     }
 }
@@ -885,6 +907,20 @@ fn build_news_footer_lines(app: &AppState, th: &Theme) -> Vec<Line<'static>> {
         "Toggle read",
         sep_style,
     );
+    add_multi_keybind_entry(
+        &mut news_spans,
+        &app.keymap.news_export_markdown_feed,
+        key_style,
+        "Copy Markdown",
+        sep_style,
+    );
+    add_multi_keybind_entry(
+        &mut news_spans,
+        &app.keymap.news_mark_all_read_feed,
+        key_style,
+        "Mark all read",
+        sep_style,
+    );
     lines.push(Line::from(news_spans));
 
     // Normal mode specific help when search pane is focused
@@ -1240,6 +1276,14 @@ fn build_config_editor_editor_line_spans(
                 key_style,
                 &i18n::t(app, "app.modals.config_editor.footer.export"),
                 sep_style,
+                true,
+            );
+            add_literal_key_entry(
+                &mut spans,
+                "Ctrl+X / Ctrl+O",
+                key_style,
+                &i18n::t(app, "app.modals.config_editor.footer.keymap_share"),
+                sep_style,
                 false,
             );
         }
@@ -1298,6 +1342,14 @@ fn build_config_editor_editor_line_spans(
                 key_style,
                 &i18n::t(app, "app.modals.config_editor.footer.export"),
                 sep_style,
+                true,
+            );
+            add_literal_key_entry(
+                &mut spans,
+                "Ctrl+X / Ctrl+O",
+                key_style,
+                &i18n::t(app, "app.modals.config_editor.footer.keymap_share"),
+                sep_style,
                 false,
             );
         }
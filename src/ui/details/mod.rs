@@ -15,7 +15,8 @@ mod package_info;
 /// PKGBUILD viewer rendering.
 mod pkgbuild;
 
-pub use pkgbuild::cycle_pkgbuild_view_section;
+pub use package_info::details_max_scroll;
+pub use pkgbuild::{cycle_pkgbuild_view_section, pkgbuild_max_scroll};
 /// PKGBUILD syntax highlighting.
 mod pkgbuild_highlight;
 
@@ -137,6 +138,119 @@ pub fn render_news_details(f: &mut Frame, app: &mut AppState, area: Rect) {
     }
 }
 
+/// Metadata fields shown below a news item's title.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NewsMetaField {
+    /// Publication date.
+    Date,
+    /// Feed source (Arch news, advisory, update, ...).
+    Source,
+    /// Advisory severity (only present for advisories).
+    Severity,
+    /// Affected packages (only present when non-empty).
+    Packages,
+}
+
+/// Default metadata order used when `news_details_fields` is empty.
+const DEFAULT_NEWS_META_FIELDS: [NewsMetaField; 4] = [
+    NewsMetaField::Date,
+    NewsMetaField::Source,
+    NewsMetaField::Severity,
+    NewsMetaField::Packages,
+];
+
+/// What: Parse the `news_details_fields` setting into an ordered field list.
+///
+/// Inputs:
+/// - `spec`: Comma-separated field names (`date`, `source`, `severity`, `packages`) or `none`
+///
+/// Output:
+/// - Fields in configured order; omitted fields are hidden.
+///
+/// Details:
+/// - Unknown tokens and duplicates are ignored; an empty value falls back to the default order.
+fn parse_news_meta_fields(spec: &str) -> Vec<NewsMetaField> {
+    let trimmed = spec.trim();
+    if trimmed.is_empty() {
+        return DEFAULT_NEWS_META_FIELDS.to_vec();
+    }
+    let mut fields: Vec<NewsMetaField> = Vec::new();
+    for token in trimmed.split(',') {
+        let field = match token.trim().to_ascii_lowercase().as_str() {
+            "date" => NewsMetaField::Date,
+            "source" => NewsMetaField::Source,
+            "severity" => NewsMetaField::Severity,
+            "packages" | "pkgs" => NewsMetaField::Packages,
+            _ => continue,
+        };
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    fields
+}
+
+/// What: Assemble the metadata lines shown under a news item's title.
+///
+/// Inputs:
+/// - `item`: Selected news item
+/// - `fields`: Ordered fields to show (from [`parse_news_meta_fields`])
+/// - `compact`: When true, join all fields into a single line
+/// - `now`: Current Unix timestamp (seconds) for the relative date
+///
+/// Output:
+/// - One string per field, or a single `·`-separated string in compact mode (empty when no field applies).
+///
+/// Details:
+/// - Severity and Packages are skipped when the item has no value for them.
+/// - The date gets a relative suffix such as `(3 days ago)` while it is under 8 weeks old.
+fn news_meta_lines(
+    item: &crate::state::types::NewsFeedItem,
+    fields: &[NewsMetaField],
+    compact: bool,
+    now: i64,
+) -> Vec<String> {
+    let parts: Vec<String> = fields
+        .iter()
+        .filter_map(|field| match field {
+            NewsMetaField::Date => Some(news_date_label(&item.date, now)),
+            NewsMetaField::Source => Some(format!("Source: {:?}", item.source)),
+            NewsMetaField::Severity => item.severity.map(|sev| format!("Severity: {sev:?}")),
+            NewsMetaField::Packages => (!item.packages.is_empty())
+                .then(|| format!("Packages: {}", item.packages.join(", "))),
+        })
+        .collect();
+    if compact && !parts.is_empty() {
+        vec![parts.join(" · ")]
+    } else {
+        parts
+    }
+}
+
+/// What: Format the `Date:` metadata with a relative age.
+///
+/// Inputs:
+/// - `date`: News date (`YYYY-MM-DD`)
+/// - `now`: Current Unix timestamp (seconds)
+///
+/// Output:
+/// - `Date: <date> (<relative>)`, or just `Date: <date>` when the date does not parse or the
+///   relative form would only repeat it.
+///
+/// Details:
+/// - The date is taken as midnight UTC, so same-day items read as hours ago.
+fn news_date_label(date: &str, now: i64) -> String {
+    let relative = chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| crate::util::relative_time(dt.and_utc().timestamp(), now))
+        .filter(|rel| rel != date.trim());
+    relative.map_or_else(
+        || format!("Date: {date}"),
+        |rel| format!("Date: {date} ({rel})"),
+    )
+}
+
 /// What: Build the lines for news metadata and content (without rendering).
 ///
 /// Inputs:
@@ -161,20 +275,18 @@ fn build_news_body(
             .add_modifier(ratatui::style::Modifier::BOLD),
     )));
     body.push(ratatui::text::Line::from(""));
-    body.push(ratatui::text::Line::from(format!("Date: {}", item.date)));
-    body.push(ratatui::text::Line::from(format!(
-        "Source: {:?}",
-        item.source
-    )));
-    if let Some(sev) = item.severity {
-        body.push(ratatui::text::Line::from(format!("Severity: {sev:?}")));
-    }
-    if !item.packages.is_empty() {
-        body.push(ratatui::text::Line::from(format!(
-            "Packages: {}",
-            item.packages.join(", ")
-        )));
-    }
+    let prefs = crate::theme::settings();
+    let fields = parse_news_meta_fields(&prefs.news_details_fields);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .and_then(|d| i64::try_from(d.as_secs()).ok())
+        .unwrap_or(0);
+    body.extend(
+        news_meta_lines(item, &fields, prefs.news_details_compact, now)
+            .into_iter()
+            .map(ratatui::text::Line::from),
+    );
     if let Some(summary) = item.summary.clone() {
         body.push(ratatui::text::Line::from(""));
         body.push(ratatui::text::Line::from(summary));
@@ -459,4 +571,112 @@ mod tests {
         assert!(app.pkgb_rect.is_some());
         assert!(app.mouse_disabled_in_details);
     }
+
+    /// Render time far past every test item's date, so no relative suffix is added.
+    const LONG_AFTER: i64 = 2_000_000_000;
+
+    /// What: Build an advisory news item for metadata assembly tests.
+    ///
+    /// Inputs: None
+    ///
+    /// Output:
+    /// - Advisory `NewsFeedItem` with date, severity, and two packages
+    fn advisory_item() -> crate::state::types::NewsFeedItem {
+        crate::state::types::NewsFeedItem {
+            id: "AVG-1".into(),
+            date: "2025-01-02".into(),
+            title: "openssl issue".into(),
+            summary: None,
+            url: None,
+            source: crate::state::types::NewsFeedSource::SecurityAdvisory,
+            severity: Some(crate::state::types::AdvisorySeverity::High),
+            packages: vec!["openssl".into(), "lib32-openssl".into()],
+        }
+    }
+
+    /// What: Verify configured order and visibility drive the metadata lines.
+    ///
+    /// Inputs:
+    /// - Field spec `packages, date` (Source and Severity hidden) and the default empty spec.
+    ///
+    /// Output:
+    /// - Packages line comes first, then Date; Source/Severity absent. Empty spec keeps the default order.
+    ///
+    /// Details:
+    /// - Unknown and duplicate tokens are ignored.
+    #[test]
+    fn news_meta_lines_follow_configured_order_and_visibility() {
+        let item = advisory_item();
+        let fields = super::parse_news_meta_fields("packages, date, bogus, date");
+        let lines = super::news_meta_lines(&item, &fields, false, LONG_AFTER);
+        assert_eq!(
+            lines,
+            vec![
+                "Packages: openssl, lib32-openssl".to_string(),
+                "Date: 2025-01-02".to_string(),
+            ]
+        );
+
+        let default_lines =
+            super::news_meta_lines(&item, &super::parse_news_meta_fields(""), false, LONG_AFTER);
+        assert_eq!(default_lines.len(), 4);
+        assert!(default_lines[0].starts_with("Date:"));
+        assert!(default_lines[1].starts_with("Source:"));
+        assert!(default_lines[2].starts_with("Severity:"));
+        assert!(default_lines[3].starts_with("Packages:"));
+
+        assert!(super::parse_news_meta_fields("none").is_empty());
+    }
+
+    /// What: Verify compact mode collapses metadata into a single line.
+    ///
+    /// Inputs:
+    /// - Default field order with `compact = true`.
+    ///
+    /// Output:
+    /// - Exactly one line containing every field separated by ` · `.
+    ///
+    /// Details:
+    /// - Also checks that compact mode with no applicable fields yields no line.
+    #[test]
+    fn news_meta_lines_compact_collapses_to_one_line() {
+        let item = advisory_item();
+        let fields = super::parse_news_meta_fields("date, source, severity, packages");
+        let lines = super::news_meta_lines(&item, &fields, true, LONG_AFTER);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("Date: 2025-01-02 · Source: "));
+        assert!(lines[0].contains(" · Severity: High · Packages: openssl, lib32-openssl"));
+
+        let mut plain = item;
+        plain.severity = None;
+        plain.packages.clear();
+        let only_optional = super::parse_news_meta_fields("severity, packages");
+        assert!(super::news_meta_lines(&plain, &only_optional, true, LONG_AFTER).is_empty());
+    }
+
+    /// What: Verify recent news dates get a relative suffix.
+    ///
+    /// Inputs:
+    /// - An item dated 2025-01-02 rendered three days later, long after, and with a bad date.
+    ///
+    /// Output:
+    /// - `(3 days ago)` is appended only while the date is recent and parseable.
+    #[test]
+    fn news_date_shows_relative_age() {
+        let item = advisory_item();
+        let fields = super::parse_news_meta_fields("date");
+        let three_days_later = 1_735_776_000 + 3 * 86_400;
+        assert_eq!(
+            super::news_meta_lines(&item, &fields, false, three_days_later),
+            vec!["Date: 2025-01-02 (3 days ago)".to_string()]
+        );
+        assert_eq!(
+            super::news_date_label("2025-01-02", LONG_AFTER),
+            "Date: 2025-01-02"
+        );
+        assert_eq!(
+            super::news_date_label("soon", three_days_later),
+            "Date: soon"
+        );
+    }
 }
@@ -11,6 +11,26 @@ use crate::i18n;
 use crate::state::AppState;
 use crate::theme::theme;
 
+/// What: Largest valid [`AppState::details_scroll`] for the current package details.
+///
+/// Inputs:
+/// - `app`: Application state (details and the last rendered `details_rect`).
+///
+/// Output:
+/// - Last zero-based line index of the formatted details, or `0` before the pane was rendered.
+///
+/// Details:
+/// - Formats at the same width as [`render_package_info`] (inner rect plus borders).
+#[must_use]
+pub fn details_max_scroll(app: &AppState) -> usize {
+    let Some((_, _, w, _)) = app.details_rect else {
+        return 0;
+    };
+    crate::ui::helpers::format_details_lines(app, w.saturating_add(2), &theme())
+        .len()
+        .saturating_sub(1)
+}
+
 /// What: Calculate content layout dimensions from a details area rect.
 ///
 /// Inputs:
@@ -178,7 +198,8 @@ struct ButtonContext<'a> {
 /// - `app`: Application state to update button rects
 ///
 /// Output:
-/// - Updates `app.url_button_rect` and `app.pkgb_button_rect` with calculated positions.
+/// - Updates `app.url_button_rect`, `app.pkgb_button_rect`, `app.comments_button_rect` and
+///   `app.details_description_toggle_rect` with calculated positions.
 ///
 /// Details:
 /// - Iterates through visible lines, calculates button positions, and accounts for text wrapping.
@@ -192,11 +213,17 @@ fn calculate_button_rects(
     app.url_button_rect = None;
     app.pkgb_button_rect = None;
     app.comments_button_rect = None;
+    app.details_description_toggle_rect = None;
+    app.details_copy_name_rect = None;
+    app.details_copy_details_rect = None;
 
     let show_pkgb = crate::i18n::t(app, "app.details.show_pkgbuild").to_lowercase();
     let hide_pkgb = crate::i18n::t(app, "app.details.hide_pkgbuild").to_lowercase();
     let show_comments = crate::i18n::t(app, "app.details.show_comments").to_lowercase();
     let hide_comments = crate::i18n::t(app, "app.details.hide_comments").to_lowercase();
+    let expand_desc = crate::i18n::t(app, "app.details.expand_description").to_lowercase();
+    let collapse_desc = crate::i18n::t(app, "app.details.collapse_description").to_lowercase();
+    let copy_name = crate::i18n::t(app, "app.details.copy_name");
 
     let mut cur_y = ctx.content_y;
     for (vis_idx, vis_line) in visible_lines.iter().enumerate() {
@@ -219,6 +246,28 @@ fn calculate_button_rects(
             }
         }
 
+        // Check for the copy name / copy details buttons
+        if original_line.spans.len() == 3 && original_line.spans[0].content == copy_name {
+            let name_w = original_line.spans[0].content.width();
+            let gap_w = original_line.spans[1].content.width();
+            let details_w = original_line.spans[2].content.width();
+            app.details_copy_name_rect = calculate_pkgbuild_button_rect(
+                &original_line.spans[0].content,
+                ctx.content_x,
+                cur_y,
+                ctx.inner_w,
+            );
+            let details_x = u16::try_from(name_w + gap_w).unwrap_or(u16::MAX);
+            if usize::from(ctx.inner_w) >= name_w + gap_w + details_w {
+                app.details_copy_details_rect = Some((
+                    ctx.content_x.saturating_add(details_x),
+                    cur_y,
+                    u16::try_from(details_w).unwrap_or(u16::MAX),
+                    1,
+                ));
+            }
+        }
+
         // Check for PKGBUILD button
         if original_line.spans.len() == 1 {
             let txt = original_line.spans[0].content.to_string();
@@ -233,6 +282,11 @@ fn calculate_button_rects(
                     calculate_pkgbuild_button_rect(&txt, ctx.content_x, cur_y, ctx.inner_w)
             {
                 app.comments_button_rect = Some(rect);
+            } else if (lowered.contains(&expand_desc) || lowered.contains(&collapse_desc))
+                && let Some(rect) =
+                    calculate_pkgbuild_button_rect(&txt, ctx.content_x, cur_y, ctx.inner_w)
+            {
+                app.details_description_toggle_rect = Some(rect);
             }
         }
 
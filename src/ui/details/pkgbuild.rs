@@ -101,6 +101,41 @@ fn append_pkgbuild_raw_output_lines(
     }
 }
 
+/// What: Render the PKGBUILD body as a unified diff against the last-viewed version.
+///
+/// Inputs:
+/// - `app`: Application state (`pkgb_previous_text`).
+/// - `pkgb_text`: Freshly fetched PKGBUILD.
+/// - `th`: Theme for added/removed/unchanged colors.
+///
+/// Output:
+/// - One line per diff entry prefixed with `+`, `-`, or a space, or a single
+///   "no previous version cached" line.
+fn pkgbuild_diff_lines(app: &AppState, pkgb_text: &str, th: &Theme) -> Vec<Line<'static>> {
+    use crate::logic::pkgbuild_diff::{DiffLine, line_diff};
+
+    let Some(previous) = app.pkgb_previous_text.as_deref() else {
+        return vec![Line::from(Span::styled(
+            i18n::t(app, "app.details.pkgbuild_no_previous"),
+            Style::default().fg(th.overlay1),
+        ))];
+    };
+    line_diff(previous, pkgb_text)
+        .into_iter()
+        .map(|entry| {
+            let (prefix, text, color) = match entry {
+                DiffLine::Added(text) => ('+', text, th.green),
+                DiffLine::Removed(text) => ('-', text, th.red),
+                DiffLine::Unchanged(text) => (' ', text, th.overlay1),
+            };
+            Line::from(Span::styled(
+                format!("{prefix} {text}"),
+                Style::default().fg(color),
+            ))
+        })
+        .collect()
+}
+
 /// What: Build full PKGBUILD pane lines plus scroll anchors for section cycling.
 ///
 /// Inputs:
@@ -112,6 +147,7 @@ fn append_pkgbuild_raw_output_lines(
 ///
 /// Details:
 /// - Mirrors [`render_pkgbuild`] content: body, then `Static checks` + `ShellCheck` + `Namcap` subsections, optional raw block.
+/// - With `pkgb_diff_visible`, the body is the diff against the last-viewed PKGBUILD.
 pub fn build_pkgbuild_all_lines(
     app: &AppState,
     th: &Theme,
@@ -126,6 +162,8 @@ pub fn build_pkgbuild_all_lines(
     };
     let mut all_lines = if pkgb_text == loading_text {
         vec![Line::from(loading_text)]
+    } else if app.pkgb_diff_visible {
+        pkgbuild_diff_lines(app, pkgb_text, th)
     } else {
         pkgbuild_highlight::highlight_pkgbuild(pkgb_text, th)
     };
@@ -237,6 +275,21 @@ pub fn cycle_pkgbuild_view_section(app: &mut AppState) {
     app.pkgb_scroll = u16::try_from(target.min(max_scroll)).unwrap_or(0);
 }
 
+/// What: Largest valid [`AppState::pkgb_scroll`] for the current PKGBUILD pane content.
+///
+/// Inputs:
+/// - `app`: Application state (PKGBUILD text, check results).
+///
+/// Output:
+/// - Last zero-based line index of [`build_pkgbuild_all_lines`].
+#[must_use]
+pub fn pkgbuild_max_scroll(app: &AppState) -> usize {
+    build_pkgbuild_all_lines(app, &theme())
+        .0
+        .len()
+        .saturating_sub(1)
+}
+
 /// What: Combine the `Run checks` jump sentinel with clamping to the document end.
 ///
 /// Inputs:
@@ -281,7 +334,7 @@ fn resolve_pkgbuild_clamped_scroll(
 /// - `pkgb_area`: Rect assigned to the PKGBUILD pane
 ///
 /// Output:
-/// - Draws PKGBUILD text and updates button rectangles for copy/reload interactions.
+/// - Draws PKGBUILD text and updates button rectangles for copy/reload/checks/diff interactions.
 ///
 /// Details:
 /// - Applies scroll offset, records the scrollable inner region, and toggles presence of the reload
@@ -381,10 +434,21 @@ pub fn render_pkgbuild(f: &mut Frame, app: &mut AppState, pkgb_area: Rect) {
     } else {
         btn_x.saturating_add(btn_w).saturating_add(2)
     };
-    app.pkgb_run_checks_button_rect = Some((
-        run_checks_x,
+    let run_checks_w = u16::try_from(run_checks_button_label.width()).unwrap_or(u16::MAX);
+    app.pkgb_run_checks_button_rect = Some((run_checks_x, btn_y, run_checks_w, 1));
+
+    // Diff toggle against the last-viewed PKGBUILD
+    let diff_button_label = if app.pkgb_diff_visible {
+        i18n::t(app, "app.details.hide_pkgbuild_diff")
+    } else {
+        i18n::t(app, "app.details.show_pkgbuild_diff")
+    };
+    pkgb_title_spans.push(Span::raw("  "));
+    pkgb_title_spans.push(Span::styled(diff_button_label.clone(), check_btn_style));
+    app.pkgb_diff_button_rect = Some((
+        run_checks_x.saturating_add(run_checks_w).saturating_add(2),
         btn_y,
-        u16::try_from(run_checks_button_label.width()).unwrap_or(u16::MAX),
+        u16::try_from(diff_button_label.width()).unwrap_or(u16::MAX),
         1,
     ));
 
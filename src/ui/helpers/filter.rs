@@ -1,10 +1,10 @@
 //! Filtering utilities for pane-specific index calculations.
 //!
 //! This module provides functions for filtering indices in the Recent and Install panes
-//! based on pane-find queries.
+//! based on pane-find queries, and for laying out the Install pane grouped by source.
 
 use crate::state::types::AppMode;
-use crate::state::{AppState, Focus};
+use crate::state::{AppState, Focus, Source};
 
 /// What: Produce visible indices into `app.recent` considering pane-find when applicable.
 ///
@@ -50,22 +50,86 @@ pub fn filtered_recent_indices(app: &AppState) -> Vec<usize> {
         .collect()
 }
 
+/// What: Decide whether pane-find should match descriptions in the given right-column pane.
+///
+/// Inputs:
+/// - `pref`: `find_match_description` value (comma list of pane names, or `all`/`none`)
+/// - `pane`: Pane name (`install`, `remove`, or `downgrade`)
+///
+/// Output:
+/// - `true` when descriptions should be matched in `pane`.
+///
+/// Details:
+/// - Boolean spellings are accepted: `true`/`all` enable every pane, `false`/`none` disable all.
+#[must_use]
+pub fn description_match_enabled(pref: &str, pane: &str) -> bool {
+    match pref.trim().to_ascii_lowercase().as_str() {
+        "all" | "true" | "yes" | "on" | "1" => true,
+        "none" | "false" | "no" | "off" | "0" | "" => false,
+        list => list.split(',').any(|p| p.trim() == pane),
+    }
+}
+
+/// What: Test whether a package matches a pane-find pattern.
+///
+/// Inputs:
+/// - `item`: Package row
+/// - `pat_lower`: Lowercased pattern
+/// - `match_description`: Whether the description is searched in addition to the name
+///
+/// Output:
+/// - `true` when the name (or, if enabled, the description) contains the pattern.
+#[must_use]
+pub fn pane_find_matches(
+    item: &crate::state::PackageItem,
+    pat_lower: &str,
+    match_description: bool,
+) -> bool {
+    item.name.to_lowercase().contains(pat_lower)
+        || (match_description && item.description.to_lowercase().contains(pat_lower))
+}
+
 /// What: Produce visible indices into `app.install_list` with optional pane-find filtering.
 ///
 /// Inputs:
-/// - `app`: Application state (focus, `pane_find`, install list)
+/// - `app`: Application state (focus, `pane_find`, install list, grouping flag)
 ///
 /// Output:
-/// - Vector of indices in ascending order without modifying application state.
+/// - Vector of indices in list order, or official packages first then AUR packages when
+///   `install_group_by_source` is enabled.
 ///
 /// # Panics
 /// - Panics if `pane_find` is `Some` but becomes `None` between the check and the `expect` call (should not happen in single-threaded usage)
 ///
 /// Details:
-/// - Restricts matches to name or description substrings when the Install pane is focused and a
-///   pane-find expression is active; otherwise surfaces all indices.
+/// - Restricts matches to name (and, per `find_match_description`, description) substrings when
+///   the Install pane is focused and a pane-find expression is active; otherwise surfaces all indices.
+/// - Selection and navigation work on these indices, so grouping keeps headers unselectable.
 #[must_use]
 pub fn filtered_install_indices(app: &AppState) -> Vec<usize> {
+    let mut indices = filtered_install_indices_in_list_order(app);
+    if app.install_group_by_source {
+        // Stable sort keeps the list order within each group
+        indices.sort_by_key(|&i| {
+            app.install_list
+                .get(i)
+                .is_some_and(|p| matches!(p.source, Source::Aur))
+        });
+    }
+    indices
+}
+
+/// What: Apply pane-find filtering to the Install list without grouping.
+///
+/// Inputs:
+/// - `app`: Application state (focus, `pane_find`, install list)
+///
+/// Output:
+/// - Matching indices in ascending order.
+///
+/// # Panics
+/// - Panics if `pane_find` is `Some` but becomes `None` between the check and the `expect` call (should not happen in single-threaded usage)
+fn filtered_install_indices_in_list_order(app: &AppState) -> Vec<usize> {
     let apply = matches!(app.focus, Focus::Install)
         && app.pane_find.as_ref().is_some_and(|s| !s.is_empty());
     if !apply {
@@ -76,17 +140,84 @@ pub fn filtered_install_indices(app: &AppState) -> Vec<usize> {
         .as_ref()
         .expect("pane_find should be Some when apply is true")
         .to_lowercase();
+    let match_description =
+        description_match_enabled(&crate::theme::settings().find_match_description, "install");
     app.install_list
         .iter()
         .enumerate()
-        .filter_map(|(i, p)| {
-            let name = p.name.to_lowercase();
-            let desc = p.description.to_lowercase();
-            if name.contains(&pat) || desc.contains(&pat) {
-                Some(i)
-            } else {
-                None
-            }
-        })
+        .filter_map(|(i, p)| pane_find_matches(p, &pat, match_description).then_some(i))
         .collect()
 }
+
+/// One rendered row of the Install pane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstallPaneRow {
+    /// Non-selectable group header with the number of packages below it.
+    Header {
+        /// Whether this header starts the AUR group (otherwise official repositories).
+        aur: bool,
+        /// Packages in the group.
+        count: usize,
+    },
+    /// Package at this position of [`filtered_install_indices`] (the selection index).
+    Package(usize),
+}
+
+/// What: Lay out the Install pane rows, inserting group headers when grouping is enabled.
+///
+/// Inputs:
+/// - `app`: Application state (install list, grouping flag)
+/// - `indices`: Result of [`filtered_install_indices`]
+///
+/// Output:
+/// - One `Package` row per index, preceded by an official and an AUR header when grouped.
+///
+/// Details:
+/// - Empty groups get no header; without grouping the rows map 1:1 to `indices`.
+#[must_use]
+pub fn install_pane_rows(app: &AppState, indices: &[usize]) -> Vec<InstallPaneRow> {
+    if !app.install_group_by_source {
+        return (0..indices.len()).map(InstallPaneRow::Package).collect();
+    }
+    let aur_count = indices
+        .iter()
+        .filter(|&&i| {
+            app.install_list
+                .get(i)
+                .is_some_and(|p| matches!(p.source, Source::Aur))
+        })
+        .count();
+    let official_count = indices.len() - aur_count;
+    let mut rows = Vec::with_capacity(indices.len() + 2);
+    for (aur, count, start) in [
+        (false, official_count, 0),
+        (true, aur_count, official_count),
+    ] {
+        if count > 0 {
+            rows.push(InstallPaneRow::Header { aur, count });
+            rows.extend((start..start + count).map(InstallPaneRow::Package));
+        }
+    }
+    rows
+}
+
+/// What: Map a clicked Install pane row to a selection index.
+///
+/// Inputs:
+/// - `rows`: Result of [`install_pane_rows`]
+/// - `row`: Clicked row relative to the top of the list
+///
+/// Output:
+/// - Selection index of the package on that row; a header selects the first package below it,
+///   and rows past the end select the last package.
+#[must_use]
+pub fn install_row_selection(rows: &[InstallPaneRow], row: usize) -> Option<usize> {
+    let package_at = |r: &InstallPaneRow| match r {
+        InstallPaneRow::Package(d) => Some(*d),
+        InstallPaneRow::Header { .. } => None,
+    };
+    rows.iter()
+        .skip(row)
+        .find_map(package_at)
+        .or_else(|| rows.iter().rev().find_map(package_at))
+}
@@ -8,6 +8,8 @@ use ratatui::{
     text::{Line, Span},
 };
 
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
 use crate::{i18n, state::AppState, theme::Theme};
 
 /// What: Build the AUR vote-state display text for the selected package.
@@ -37,11 +39,94 @@ fn selected_aur_vote_state_text(app: &AppState) -> Option<String> {
     })
 }
 
+/// What: Truncate a package description that would wrap past `max_lines` rows.
+///
+/// Inputs:
+/// - `label`: Rendered key prefix (e.g. `"Description: "`) sharing the first row
+/// - `desc`: Full description text
+/// - `inner_w`: Usable width of the details pane in cells
+/// - `max_lines`: Row budget; `0` disables collapsing
+///
+/// Output:
+/// - `Some(truncated)` ending in `…` when the description overflows; `None` when it fits.
+///
+/// Details:
+/// - Estimates rows from display width, like the details pane does for its button rects, and
+///   cuts at the last word boundary so word wrapping does not spill onto an extra row.
+fn collapse_description(label: &str, desc: &str, inner_w: u16, max_lines: usize) -> Option<String> {
+    if max_lines == 0 || inner_w == 0 {
+        return None;
+    }
+    let budget = max_lines.saturating_mul(usize::from(inner_w));
+    let label_w = label.width();
+    if label_w + desc.width() <= budget {
+        return None;
+    }
+    let keep = budget.saturating_sub(label_w + 1);
+    let mut used = 0usize;
+    let mut out: String = desc
+        .chars()
+        .take_while(|ch| {
+            used += ch.width().unwrap_or(0);
+            used <= keep
+        })
+        .collect();
+    if let Some(idx) = out.rfind(char::is_whitespace) {
+        out.truncate(idx);
+    }
+    let mut out = out.trim_end().to_string();
+    out.push('…');
+    Some(out)
+}
+
+/// What: Resolve the description text and optional expand/collapse line for the details pane.
+///
+/// Inputs:
+/// - `app`: Application state (details, expand flag, translations)
+/// - `desc_label`: Localized "Description" label
+/// - `area_width`: Outer width of the details pane
+/// - `th`: Active theme for the toggle styling
+///
+/// Output:
+/// - `(text, toggle)` where `text` is truncated while collapsed and `toggle` is `Some` only
+///   when the description exceeds `details_description_max_lines`.
+fn description_display(
+    app: &AppState,
+    desc_label: &str,
+    area_width: u16,
+    th: &Theme,
+) -> (String, Option<Line<'static>>) {
+    let full = &app.details.description;
+    let Some(short) = collapse_description(
+        &format!("{desc_label}: "),
+        full,
+        area_width.saturating_sub(2),
+        crate::theme::settings().details_description_max_lines,
+    ) else {
+        return (full.clone(), None);
+    };
+    let (text, toggle_label) = if app.details_description_expanded {
+        (
+            full.clone(),
+            i18n::t(app, "app.details.collapse_description"),
+        )
+    } else {
+        (short, i18n::t(app, "app.details.expand_description"))
+    };
+    let toggle = Line::from(vec![Span::styled(
+        toggle_label,
+        Style::default()
+            .fg(th.mauve)
+            .add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
+    )]);
+    (text, Some(toggle))
+}
+
 /// What: Format the current [`AppState::details`] into themed `ratatui` lines.
 ///
 /// Inputs:
 /// - `app`: Read-only application state; uses `app.details` to render fields
-/// - `_area_width`: Reserved for future wrapping/layout needs (currently unused)
+/// - `area_width`: Outer width of the details pane, used to decide when to collapse the description
 /// - `th`: Active theme for colors/styles
 ///
 /// Output:
@@ -49,8 +134,11 @@ fn selected_aur_vote_state_text(app: &AppState) -> Option<String> {
 ///
 /// Details:
 /// - Applies repo-specific heuristics, formats numeric sizes via `human_bytes`, and appends a
-///   clickable PKGBUILD toggle line using accent styling.
-pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec<Line<'static>> {
+///   clickable copy-name/copy-details line and a PKGBUILD toggle line using accent styling.
+/// - Descriptions longer than `details_description_max_lines` rows are truncated unless
+///   `app.details_description_expanded` is set; either way a clickable expand/collapse line
+///   follows the description.
+pub fn format_details_lines(app: &AppState, area_width: u16, th: &Theme) -> Vec<Line<'static>> {
     /// What: Build a themed key-value line for the details pane.
     ///
     /// Inputs:
@@ -81,6 +169,12 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
     } else {
         d.repository.clone()
     };
+    let repo_display = crate::logic::distro::styled_repo_label(
+        &repo_display,
+        &crate::theme::settings().results_repo_label_style,
+    );
+    let desc_label = i18n::t(app, "app.details.fields.description");
+    let (desc_text, desc_toggle) = description_display(app, &desc_label, area_width, th);
     // Each line is a label/value pair derived from the current details view.
     let mut lines = vec![
         kv(
@@ -98,11 +192,11 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
             d.version.clone(),
             th,
         ),
-        kv(
-            &i18n::t(app, "app.details.fields.description"),
-            d.description.clone(),
-            th,
-        ),
+        kv(&desc_label, desc_text, th),
+    ];
+    // Clickable expand/collapse line directly under an over-long description
+    lines.extend(desc_toggle);
+    lines.extend([
         kv(
             &i18n::t(app, "app.details.fields.architecture"),
             d.architecture.clone(),
@@ -175,8 +269,17 @@ pub fn format_details_lines(app: &AppState, _area_width: u16, th: &Theme) -> Vec
             d.build_date.clone(),
             th,
         ),
-    ];
-    // Add a clickable helper line to Show/Hide PKGBUILD below Build date
+    ]);
+    // Clickable copy actions (name / details block) below Build date
+    let action_style = Style::default()
+        .fg(th.mauve)
+        .add_modifier(Modifier::UNDERLINED | Modifier::BOLD);
+    lines.push(Line::from(vec![
+        Span::styled(i18n::t(app, "app.details.copy_name"), action_style),
+        Span::raw("  "),
+        Span::styled(i18n::t(app, "app.details.copy_details"), action_style),
+    ]));
+    // Add a clickable helper line to Show/Hide PKGBUILD below the copy actions
     let pkgb_label = if app.pkgb_visible {
         i18n::t(app, "app.details.hide_pkgbuild")
     } else {
@@ -225,33 +328,164 @@ pub(crate) fn join(list: &[String]) -> String {
     }
 }
 
-/// What: Format bytes into human-readable string with appropriate unit.
+/// Unit convention for byte sizes shown in the UI.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SizeUnits {
+    /// 1024-based units (KiB, MiB, ...), matching pacman's own output.
+    #[default]
+    Binary,
+    /// 1000-based SI units (kB, MB, ...).
+    Si,
+}
+
+impl SizeUnits {
+    /// What: Parse a `ui_size_units` setting value.
+    ///
+    /// Inputs:
+    /// - `value`: Setting value (case-insensitive).
+    ///
+    /// Output:
+    /// - `Some(SizeUnits)` for `binary`/`iec`/`1024` or `si`/`decimal`/`1000`, otherwise `None`.
+    #[must_use]
+    pub fn from_config_key(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "binary" | "iec" | "1024" => Some(Self::Binary),
+            "si" | "decimal" | "1000" => Some(Self::Si),
+            _ => None,
+        }
+    }
+
+    /// What: Canonical setting value for this convention.
+    ///
+    /// Output:
+    /// - `"binary"` or `"si"`.
+    #[must_use]
+    pub const fn as_config_key(self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::Si => "si",
+        }
+    }
+
+    /// Divisor between consecutive units.
+    const fn base(self) -> f64 {
+        match self {
+            Self::Binary => 1024.0,
+            Self::Si => 1000.0,
+        }
+    }
+
+    /// Unit labels from bytes up to peta.
+    const fn labels(self) -> [&'static str; 6] {
+        match self {
+            Self::Binary => ["B", "KiB", "MiB", "GiB", "TiB", "PiB"],
+            Self::Si => ["B", "kB", "MB", "GB", "TB", "PB"],
+        }
+    }
+}
+
+/// Process-wide unit preference; `true` selects SI units.
+#[cfg(not(test))]
+static SI_SIZE_UNITS: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(test)]
+thread_local! {
+    /// Per-thread preference in tests so parallel tests relying on binary units are unaffected.
+    static SI_SIZE_UNITS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// What: Select the unit convention used by [`format_bytes`] and [`human_bytes`].
 ///
 /// Inputs:
-/// - `value`: Number of bytes to format.
+/// - `units`: Binary (KiB) or SI (kB) units.
 ///
 /// Output:
-/// - Returns a formatted string like "1.5 MiB" or "1024 B".
+/// - None; every subsequent size rendered by the UI uses the new convention.
 ///
 /// Details:
-/// - Uses binary units (KiB, MiB, GiB, etc.) and shows integer for bytes < 1024, otherwise 1 decimal place.
+/// - Applied from the `ui_size_units` setting on startup and on config reload.
+pub fn set_size_units(units: SizeUnits) {
+    #[cfg(not(test))]
+    SI_SIZE_UNITS.store(units == SizeUnits::Si, std::sync::atomic::Ordering::Relaxed);
+    #[cfg(test)]
+    SI_SIZE_UNITS.with(|c| c.set(units == SizeUnits::Si));
+}
+
+/// What: Read the unit convention currently used for byte sizes.
+///
+/// Output:
+/// - The value last passed to [`set_size_units`] (binary by default).
 #[must_use]
-pub fn format_bytes(value: u64) -> String {
-    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+pub fn size_units() -> SizeUnits {
+    #[cfg(not(test))]
+    let si = SI_SIZE_UNITS.load(std::sync::atomic::Ordering::Relaxed);
+    #[cfg(test)]
+    let si = SI_SIZE_UNITS.with(std::cell::Cell::get);
+    if si { SizeUnits::Si } else { SizeUnits::Binary }
+}
+
+/// What: Scale a byte count to the largest unit below it.
+///
+/// Inputs:
+/// - `value`: Number of bytes.
+/// - `units`: Unit convention.
+///
+/// Output:
+/// - Scaled value and the index into the convention's unit labels.
+const fn scale_bytes(value: u64, units: SizeUnits) -> (f64, usize) {
+    let base = units.base();
     #[allow(clippy::cast_precision_loss)]
     let mut size = value as f64;
     let mut unit_index = 0usize;
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
+    while size >= base && unit_index < units.labels().len() - 1 {
+        size /= base;
         unit_index += 1;
     }
+    (size, unit_index)
+}
+
+/// What: Format bytes with an explicit unit convention.
+///
+/// Inputs:
+/// - `value`: Number of bytes to format.
+/// - `units`: Binary or SI units.
+///
+/// Output:
+/// - Returns a formatted string like "1.5 MiB", "1.0 MB", or "1023 B".
+///
+/// Details:
+/// - Shows an integer below one kilo-unit, otherwise 1 decimal place.
+/// - Binary units use [`crate::util::format_bytes`].
+#[must_use]
+pub fn format_bytes_in(value: u64, units: SizeUnits) -> String {
+    if units == SizeUnits::Binary {
+        return crate::util::format_bytes(value);
+    }
+    let (size, unit_index) = scale_bytes(value, units);
+    let label = units.labels()[unit_index];
     if unit_index == 0 {
-        format!("{value} {}", UNITS[unit_index])
+        format!("{value} {label}")
     } else {
-        format!("{size:.1} {}", UNITS[unit_index])
+        format!("{size:.1} {label}")
     }
 }
 
+/// What: Format bytes into human-readable string with appropriate unit.
+///
+/// Inputs:
+/// - `value`: Number of bytes to format.
+///
+/// Output:
+/// - Returns a formatted string like "1.5 MiB" or "1024 B".
+///
+/// Details:
+/// - Uses the configured [`size_units`] (binary KiB/MiB by default, SI kB/MB when
+///   `ui_size_units = si`) and shows integer below one kilo-unit, otherwise 1 decimal place.
+#[must_use]
+pub fn format_bytes(value: u64) -> String {
+    format_bytes_in(value, size_units())
+}
+
 /// What: Format signed bytes into human-readable string with +/- prefix.
 ///
 /// Inputs:
@@ -262,8 +496,12 @@ pub fn format_bytes(value: u64) -> String {
 ///
 /// Details:
 /// - Uses `format_bytes` for magnitude and adds +/- prefix based on sign.
+/// - Binary units use [`crate::util::format_delta_bytes`].
 #[must_use]
 pub fn format_signed_bytes(value: i64) -> String {
+    if size_units() == SizeUnits::Binary {
+        return crate::util::format_delta_bytes(value);
+    }
     if value == 0 {
         return "0 B".to_string();
     }
@@ -275,26 +513,20 @@ pub fn format_signed_bytes(value: i64) -> String {
     }
 }
 
-/// What: Format a byte count using binary units with one decimal place.
+/// What: Format a byte count with one decimal place.
 ///
 /// Inputs:
 /// - `n`: Raw byte count to format
 ///
 /// Output:
-/// - Size string such as "1.5 KiB" using 1024-based units.
+/// - Size string such as "1.5 KiB" (or "1.5 kB" with SI units).
 ///
 /// Details:
-/// - Iteratively divides by 1024 up to PiB, retaining one decimal place for readability.
-/// - Always shows decimal place (unlike `format_bytes` which shows integer for bytes < 1024).
+/// - Uses the configured [`size_units`], scaling up to PiB/PB.
+/// - Always shows decimal place (unlike `format_bytes` which shows integer for bytes < 1 kilo-unit).
 #[must_use]
 pub fn human_bytes(n: u64) -> String {
-    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
-    #[allow(clippy::cast_precision_loss)]
-    let mut v = n as f64;
-    let mut i = 0;
-    while v >= 1024.0 && i < UNITS.len() - 1 {
-        v /= 1024.0;
-        i += 1;
-    }
-    format!("{v:.1} {}", UNITS[i])
+    let units = size_units();
+    let (v, i) = scale_bytes(n, units);
+    format!("{v:.1} {}", units.labels()[i])
 }
@@ -7,6 +7,7 @@
 //! - In-pane filtering for Recent and Install panes
 //! - Triggering background preview fetches for Recent selections
 //! - Resolving a query string to a best-effort first matching package
+//! - Mirroring titles, button rows, and click rectangles for right-to-left locales
 
 pub mod filter;
 pub mod format;
@@ -14,12 +15,20 @@ pub mod format;
 pub mod logging;
 pub mod preflight;
 pub mod query;
+pub mod rtl;
 
-pub use filter::{filtered_install_indices, filtered_recent_indices};
-pub use format::{format_bytes, format_details_lines, format_signed_bytes, human_bytes};
+pub use filter::{
+    InstallPaneRow, filtered_install_indices, filtered_recent_indices, install_pane_rows,
+    install_row_selection,
+};
+pub use format::{
+    SizeUnits, format_bytes, format_bytes_in, format_details_lines, format_signed_bytes,
+    human_bytes, set_size_units, size_units,
+};
 pub use logging::ChangeLogger;
 pub use preflight::is_package_loading_preflight;
 pub use query::{fetch_first_match_for_query, trigger_recent_preview};
+pub use rtl::{label_alignment, mirror_rect, mirror_spans};
 
 #[cfg(test)]
 mod tests;
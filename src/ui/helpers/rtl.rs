@@ -0,0 +1,75 @@
+//! Right-to-left layout helpers for block titles and button rows.
+//!
+//! Renderers build their rows left-to-right as usual and, when [`crate::i18n::is_rtl`] holds for
+//! the active locale, mirror the finished spans and recorded click rectangles with these helpers.
+
+use ratatui::layout::Alignment;
+use ratatui::text::Span;
+
+/// What: Reverse the visual order of a row of title segments.
+///
+/// Inputs:
+/// - `spans`: Row spans in left-to-right order.
+///
+/// Output:
+/// - The same spans with segment order reversed.
+///
+/// Details:
+/// - A segment is either a whitespace-only separator span or a run of adjacent non-whitespace
+///   spans, so multi-span buttons (e.g. an underlined first character) keep their inner order.
+/// - Segment widths are preserved, so a segment at offset `o` with width `w` in a row of width
+///   `W` ends up at offset `W - o - w`, matching [`mirror_rect`].
+#[must_use]
+pub fn mirror_spans(spans: Vec<Span<'_>>) -> Vec<Span<'_>> {
+    let mut segments: Vec<Vec<Span<'_>>> = Vec::new();
+    let mut in_run = false;
+    for span in spans {
+        let separator = span.content.trim().is_empty();
+        match segments.last_mut() {
+            Some(run) if in_run && !separator => run.push(span),
+            _ => segments.push(vec![span]),
+        }
+        in_run = !separator;
+    }
+    segments.into_iter().rev().flatten().collect()
+}
+
+/// What: Mirror a recorded rectangle horizontally within a row.
+///
+/// Inputs:
+/// - `rect`: Rectangle `(x, y, width, height)` laid out left-to-right.
+/// - `row_x`: Left edge of the row the rectangle was laid out in.
+/// - `row_width`: Width of that row.
+///
+/// Output:
+/// - Rectangle with the same size and row whose distance from the right edge equals the
+///   original distance from the left edge.
+#[must_use]
+pub const fn mirror_rect(
+    rect: (u16, u16, u16, u16),
+    row_x: u16,
+    row_width: u16,
+) -> (u16, u16, u16, u16) {
+    let (x, y, w, h) = rect;
+    let mirrored = row_x
+        .saturating_mul(2)
+        .saturating_add(row_width)
+        .saturating_sub(x.saturating_add(w));
+    (mirrored, y, w, h)
+}
+
+/// What: Alignment for block titles and labels in the active text direction.
+///
+/// Inputs:
+/// - `rtl`: Whether the active locale is right-to-left.
+///
+/// Output:
+/// - `Alignment::Right` for right-to-left locales, `Alignment::Left` otherwise.
+#[must_use]
+pub const fn label_alignment(rtl: bool) -> Alignment {
+    if rtl {
+        Alignment::Right
+    } else {
+        Alignment::Left
+    }
+}
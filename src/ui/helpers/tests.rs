@@ -425,3 +425,142 @@ async fn trigger_recent_preview_noop_when_not_recent_or_invalid() {
         .flatten();
     assert!(none3.is_none());
 }
+
+#[test]
+/// What: Ensure over-long descriptions collapse behind an expand toggle and expand in full.
+///
+/// Inputs:
+/// - `details_description_max_lines = 2`, a ~400 character description, and a 40-column pane,
+///   rendered once collapsed and once with `details_description_expanded` set.
+///
+/// Output:
+/// - Collapsed: truncated text ending in `…` followed by a "Show full description" line.
+/// - Expanded: the full description followed by a "Collapse description" line.
+///
+/// Details:
+/// - Points `HOME` at a temp config under `theme::test_mutex` so the row budget is deterministic.
+fn details_lines_collapse_long_description() {
+    let _theme_guard = crate::theme::test_mutex()
+        .lock()
+        .expect("theme test mutex poisoned");
+    let orig_home = std::env::var_os("HOME");
+    let base = std::env::temp_dir().join(format!(
+        "pacsea_test_desc_collapse_{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("System time is before UNIX epoch")
+            .as_nanos()
+    ));
+    let cfg = base.join(".config").join("pacsea");
+    std::fs::create_dir_all(&cfg).expect("create test config dir");
+    unsafe {
+        std::env::set_var("HOME", base.display().to_string());
+    }
+    std::fs::write(
+        cfg.join("settings.conf"),
+        "details_description_max_lines = 2\n",
+    )
+    .expect("write settings");
+
+    let long_desc = "A very long package description that keeps going. ".repeat(8);
+    let mut app = crate::state::AppState::default();
+    init_test_translations(&mut app);
+    app.translations.insert(
+        "app.details.expand_description".to_string(),
+        "Show full description".to_string(),
+    );
+    app.translations.insert(
+        "app.details.collapse_description".to_string(),
+        "Collapse description".to_string(),
+    );
+    app.details.description.clone_from(&long_desc);
+    let th = crate::theme::theme();
+
+    let collapsed = format_details_lines(&app, 40, &th);
+    app.details_description_expanded = true;
+    let expanded = format_details_lines(&app, 40, &th);
+
+    unsafe {
+        if let Some(v) = orig_home {
+            std::env::set_var("HOME", v);
+        } else {
+            std::env::remove_var("HOME");
+        }
+    }
+    let _ = std::fs::remove_dir_all(&base);
+
+    let desc_idx = collapsed
+        .iter()
+        .position(|l| l.spans[0].content.starts_with("Description"))
+        .expect("description line present");
+    let shown = collapsed[desc_idx].spans[1].content.to_string();
+    assert!(shown.ends_with('…'));
+    assert!(shown.len() < long_desc.len());
+    assert_eq!(
+        collapsed[desc_idx + 1].spans[0].content,
+        "Show full description"
+    );
+
+    assert_eq!(expanded[desc_idx].spans[1].content, long_desc);
+    assert_eq!(
+        expanded[desc_idx + 1].spans[0].content,
+        "Collapse description"
+    );
+}
+
+#[test]
+/// What: Ensure short descriptions render unchanged without an expand toggle.
+///
+/// Inputs:
+/// - Default settings and a short description in an 80-column pane.
+///
+/// Output:
+/// - Description shown verbatim and no expand/collapse line after it.
+fn details_lines_short_description_has_no_toggle() {
+    let mut app = crate::state::AppState::default();
+    init_test_translations(&mut app);
+    app.details.description = "short".into();
+    let th = crate::theme::theme();
+    let lines = format_details_lines(&app, 80, &th);
+    let desc_idx = lines
+        .iter()
+        .position(|l| l.spans[0].content.starts_with("Description"))
+        .expect("description line present");
+    assert_eq!(lines[desc_idx].spans[1].content, "short");
+    assert!(
+        lines[desc_idx + 1].spans[0]
+            .content
+            .starts_with("Architecture")
+    );
+}
+
+#[test]
+/// What: Verify right-to-left mirroring keeps multi-span buttons intact and matches rect math.
+///
+/// Inputs:
+/// - Title row `Results  [S][ort]  [AUR]` with a two-span button, and the rect of `[AUR]`.
+///
+/// Output:
+/// - Segments reversed, the button's spans in original order, and the mirrored rect at the
+///   offset where `[AUR]` lands in the mirrored row.
+fn rtl_mirrors_segments_and_rects() {
+    use ratatui::text::Span;
+    let spans = vec![
+        Span::raw("Results"),
+        Span::raw("  "),
+        Span::raw("[S"),
+        Span::raw("ort]"),
+        Span::raw("  "),
+        Span::raw("[AUR]"),
+    ];
+    let mirrored: Vec<String> = mirror_spans(spans)
+        .into_iter()
+        .map(|span| span.content.into_owned())
+        .collect();
+    assert_eq!(mirrored, ["[AUR]", "  ", "[S", "ort]", "  ", "Results"]);
+
+    // `[AUR]` starts at offset 17 of a 22-wide row placed at x = 1; mirrored it starts at 0
+    assert_eq!(mirror_rect((18, 0, 5, 1), 1, 22), (1, 0, 5, 1));
+    assert_eq!(mirror_rect((1, 3, 7, 1), 1, 22), (16, 3, 7, 1));
+}
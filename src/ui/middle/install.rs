@@ -3,7 +3,7 @@ use ratatui::{
     prelude::Rect,
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
 };
 use unicode_width::UnicodeWidthStr;
 
@@ -101,6 +101,41 @@ where
         .collect()
 }
 
+/// What: Interleave group headers with the Install pane package items.
+///
+/// Inputs:
+/// - `app`: Application state for i18n
+/// - `rows`: Row layout from [`crate::ui::helpers::install_pane_rows`]
+/// - `package_items`: One item per selection index, in selection order
+///
+/// Output:
+/// - List items in row order; headers show the group name and package count.
+fn interleave_group_headers<'a>(
+    app: &AppState,
+    rows: &[crate::ui::helpers::InstallPaneRow],
+    package_items: Vec<ListItem<'a>>,
+) -> Vec<ListItem<'a>> {
+    use crate::ui::helpers::InstallPaneRow;
+    let th = theme();
+    let mut packages = package_items.into_iter();
+    rows.iter()
+        .filter_map(|row| match row {
+            InstallPaneRow::Package(_) => packages.next(),
+            InstallPaneRow::Header { aur, count } => {
+                let (key, color) = if *aur {
+                    ("app.install_groups.aur", th.yellow)
+                } else {
+                    ("app.install_groups.official", th.green)
+                };
+                Some(ListItem::new(Line::from(Span::styled(
+                    i18n::t_fmt1(app, key, count),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ))))
+            }
+        })
+        .collect()
+}
+
 /// What: Render the normal Install list (single right pane) with Import/Export buttons.
 ///
 /// Inputs:
@@ -113,6 +148,8 @@ where
 ///
 /// Details:
 /// - Shows filtered install list items with selection indicators and loading indicators.
+/// - With `install_group_by_source`, official and AUR packages sit under headers with counts;
+///   the selection index skips headers and is mapped to the list row for highlighting.
 /// - Import/Export buttons are rendered at the bottom border.
 pub fn render_install(f: &mut Frame, app: &mut AppState, area: Rect) {
     let th = theme();
@@ -121,13 +158,15 @@ pub fn render_install(f: &mut Frame, app: &mut AppState, area: Rect) {
     // Normal Install List (single right pane)
     let indices: Vec<usize> = crate::ui::helpers::filtered_install_indices(app);
     let selected_idx = app.install_state.selected();
-    let install_items = build_package_list_items(
+    let package_items = build_package_list_items(
         &indices,
         &app.install_list,
         selected_idx,
         install_focused,
         |name| crate::ui::helpers::is_package_loading_preflight(app, name),
     );
+    let rows = crate::ui::helpers::install_pane_rows(app, &indices);
+    let install_items = interleave_group_headers(app, &rows, package_items);
     let title_text = if install_focused {
         i18n::t(app, "app.titles.install_list_focused")
     } else {
@@ -142,6 +181,9 @@ pub fn render_install(f: &mut Frame, app: &mut AppState, area: Rect) {
                 th.overlay1
             }),
         )]))
+        .title_alignment(crate::ui::helpers::label_alignment(i18n::is_rtl(
+            &app.locale,
+        )))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(if install_focused {
@@ -162,7 +204,19 @@ pub fn render_install(f: &mut Frame, app: &mut AppState, area: Rect) {
         .block(install_block)
         .highlight_style(Style::default().fg(th.text).bg(th.surface2))
         .highlight_symbol(""); // Empty symbol since we're adding it manually
-    f.render_stateful_widget(install_list, area, &mut app.install_state);
+    if app.install_group_by_source {
+        // The list widget scrolls and highlights by row, while the selection counts packages only
+        let mut row_state = ListState::default()
+            .with_offset(app.install_state.offset())
+            .with_selected(selected_idx.and_then(|sel| {
+                rows.iter()
+                    .position(|r| *r == crate::ui::helpers::InstallPaneRow::Package(sel))
+            }));
+        f.render_stateful_widget(install_list, area, &mut row_state);
+        *app.install_state.offset_mut() = row_state.offset();
+    } else {
+        f.render_stateful_widget(install_list, area, &mut app.install_state);
+    }
     app.install_rect = Some((
         area.x + 1,
         area.y + 1,
@@ -186,7 +240,8 @@ pub fn render_install(f: &mut Frame, app: &mut AppState, area: Rect) {
 /// - Draws Import and Export buttons, records their rects for mouse hit-testing.
 ///
 /// Details:
-/// - Import button is on the far right, Export is to its left with a 2-space gap.
+/// - Import button is on the far right, Export is to its left with a 2-space gap; both are
+///   mirrored for right-to-left locales.
 /// - First character of each label is underlined to indicate keyboard shortcut.
 fn render_buttons(f: &mut Frame, app: &mut AppState, area: Rect, install_focused: bool) {
     let th = theme();
@@ -214,12 +269,42 @@ fn render_buttons(f: &mut Frame, app: &mut AppState, area: Rect, install_focused
     // Use Unicode display width, not byte length, to handle wide characters
     let import_w = u16::try_from(import_label.width()).unwrap_or(u16::MAX);
     let import_sx = area.x + 1 + inner_w.saturating_sub(import_w);
-    let import_rect = Rect {
+    let mut import_rect = Rect {
         x: import_sx,
         y: sy,
         width: import_w.min(inner_w),
         height: 1,
     };
+
+    // Export button to the left of Import with 2 spaces gap
+    let gap: u16 = 2;
+    let export_w = u16::try_from(export_label.width()).unwrap_or(u16::MAX);
+    let export_max_w = inner_w;
+    let export_right = import_rect.x.saturating_sub(gap);
+    let export_sx = if export_w > export_right.saturating_sub(area.x + 1) {
+        area.x + 1
+    } else {
+        export_right.saturating_sub(export_w)
+    };
+    let mut export_rect = Rect {
+        x: export_sx,
+        y: sy,
+        width: export_w.min(export_max_w),
+        height: 1,
+    };
+
+    // Right-to-left locales put Import on the far left and Export to its right
+    if i18n::is_rtl(&app.locale) {
+        for rect in [&mut import_rect, &mut export_rect] {
+            let (x, ..) = crate::ui::helpers::mirror_rect(
+                (rect.x, rect.y, rect.width, rect.height),
+                area.x + 1,
+                inner_w,
+            );
+            rect.x = x;
+        }
+    }
+
     // Split label for styling: first character underlined, rest normal
     let import_first_char = import_label
         .chars()
@@ -239,22 +324,6 @@ fn render_buttons(f: &mut Frame, app: &mut AppState, area: Rect, install_focused
     ));
     f.render_widget(import_line, import_rect);
 
-    // Export button to the left of Import with 2 spaces gap
-    let gap: u16 = 2;
-    let export_w = u16::try_from(export_label.width()).unwrap_or(u16::MAX);
-    let export_max_w = inner_w;
-    let export_right = import_rect.x.saturating_sub(gap);
-    let export_sx = if export_w > export_right.saturating_sub(area.x + 1) {
-        area.x + 1
-    } else {
-        export_right.saturating_sub(export_w)
-    };
-    let export_rect = Rect {
-        x: export_sx,
-        y: sy,
-        width: export_w.min(export_max_w),
-        height: 1,
-    };
     // Split label for styling: first character underlined, rest normal
     let export_first_char = export_label
         .chars()
@@ -300,6 +369,11 @@ mod tests {
         );
         translations.insert("app.actions.import".to_string(), "Import".to_string());
         translations.insert("app.actions.export".to_string(), "Export".to_string());
+        translations.insert(
+            "app.install_groups.official".to_string(),
+            "Official ({})".to_string(),
+        );
+        translations.insert("app.install_groups.aur".to_string(), "AUR ({})".to_string());
         app.translations = translations.clone();
         app.translations_fallback = translations;
     }
@@ -494,4 +568,52 @@ mod tests {
         assert!(app.install_import_rect.is_some());
         assert!(app.install_export_rect.is_some());
     }
+
+    /// What: Verify the grouped Install pane lists packages under source headers with counts.
+    ///
+    /// Inputs:
+    /// - Mixed install list (AUR, official, AUR, official) with grouping enabled and the
+    ///   second official package selected.
+    ///
+    /// Output:
+    /// - Rows read `Official (2)`, both official packages, `AUR (2)`, both AUR packages; the
+    ///   selection marker sits on the selected package row, not on a header.
+    ///
+    /// Details:
+    /// - Reads the rendered buffer row by row inside the pane border.
+    #[test]
+    fn install_grouped_renders_headers_with_subtotals() {
+        let backend = TestBackend::new(60, 12);
+        let mut term = Terminal::new(backend).expect("Failed to create terminal for test");
+        let mut app = crate::state::AppState::default();
+        init_test_translations(&mut app);
+        app.install_group_by_source = true;
+        app.install_list = vec![
+            PackageItem::test_item("aur-one", "aur"),
+            PackageItem::test_item("ripgrep", "extra"),
+            PackageItem::test_item("aur-two", "aur"),
+            PackageItem::test_item("fd", "extra"),
+        ];
+        app.install_state.select(Some(1));
+
+        term.draw(|f| {
+            let area = f.area();
+            render_install(f, &mut app, area);
+        })
+        .expect("Failed to render grouped install pane");
+
+        let buffer = term.backend().buffer();
+        let row_text = |y: u16| -> String {
+            (1..59)
+                .map(|x| buffer[(x, y)].symbol().to_string())
+                .collect::<String>()
+        };
+        assert!(row_text(1).starts_with("Official (2)"));
+        assert!(row_text(2).contains("ripgrep"));
+        assert!(row_text(3).contains("fd"));
+        assert!(row_text(3).starts_with("▶"));
+        assert!(row_text(4).starts_with("AUR (2)"));
+        assert!(row_text(5).contains("aur-one"));
+        assert!(row_text(6).contains("aur-two"));
+    }
 }
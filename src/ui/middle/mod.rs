@@ -35,9 +35,12 @@ mod search;
 /// - Records inner rects for Recent/Install/Downgrade and sets the caret position for the Search input.
 pub fn render_middle(f: &mut Frame, app: &mut AppState, area: Rect) {
     if matches!(app.app_mode, AppMode::News) {
+        app.middle_row_rect = None;
         render_middle_news(f, app, area);
         return;
     }
+    // Remember the row for dragging the column borders
+    app.middle_row_rect = Some((area.x, area.y, area.width, area.height));
     // Middle row split: left Recent, middle Search input, right Install list
     // If a pane is hidden, reassign its percentage to the center pane.
     let left_pct = if app.show_recent_pane {
@@ -10,6 +10,22 @@ use crate::i18n;
 use crate::state::AppState;
 use crate::theme::theme;
 
+/// What: Build the display label for one recent search entry.
+///
+/// Inputs:
+/// - `app`: Application state holding recorded result counts
+/// - `value`: Recent search text
+/// - `show_counts`: Whether result counts should be appended
+///
+/// Output:
+/// - `value` alone, or `value (N)` when a count is known and enabled.
+fn recent_entry_label(app: &AppState, value: &str, show_counts: bool) -> String {
+    match app.recent_count_for(value) {
+        Some(count) if show_counts => format!("{value} ({count})"),
+        _ => value.to_string(),
+    }
+}
+
 /// What: Render the Recent searches list in the left pane of the middle row.
 ///
 /// Inputs:
@@ -33,12 +49,13 @@ pub fn render_recent(f: &mut Frame, app: &mut AppState, area: Rect) {
     let recent_focused = matches!(app.focus, crate::state::Focus::Recent);
     let recents = app.recent_values();
     let rec_inds = crate::ui::helpers::filtered_recent_indices(app);
+    let show_counts = crate::theme::settings().recent_show_result_counts;
     let rec_items: Vec<ListItem> = rec_inds
         .iter()
         .filter_map(|&i| recents.get(i))
         .map(|s| {
             ListItem::new(Span::styled(
-                s.clone(),
+                recent_entry_label(app, s, show_counts),
                 Style::default().fg(if recent_focused { th.text } else { th.subtext0 }),
             ))
         })
@@ -66,8 +83,13 @@ pub fn render_recent(f: &mut Frame, app: &mut AppState, area: Rect) {
         ));
         recent_title_spans.push(Span::styled(pat.clone(), Style::default().fg(th.text)));
     }
+    let rtl = i18n::is_rtl(&app.locale);
+    if rtl {
+        recent_title_spans = crate::ui::helpers::mirror_spans(recent_title_spans);
+    }
     let rec_block = Block::default()
         .title(Line::from(recent_title_spans))
+        .title_alignment(crate::ui::helpers::label_alignment(rtl))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(if recent_focused {
@@ -152,8 +174,13 @@ pub fn render_news_recent(f: &mut Frame, app: &mut AppState, area: Rect) {
         ));
         recent_title_spans.push(Span::styled(pat.clone(), Style::default().fg(th.text)));
     }
+    let rtl = i18n::is_rtl(&app.locale);
+    if rtl {
+        recent_title_spans = crate::ui::helpers::mirror_spans(recent_title_spans);
+    }
     let rec_block = Block::default()
         .title(Line::from(recent_title_spans))
+        .title_alignment(crate::ui::helpers::label_alignment(rtl))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(if recent_focused {
@@ -246,6 +273,49 @@ mod tests {
         assert_eq!(h, 28); // 30 - 2
     }
 
+    /// What: Verify the recent pane renders the result count next to the query.
+    ///
+    /// Inputs:
+    /// - Recent list with `ripgrep` (3 results recorded) and `fd` (no count).
+    ///
+    /// Output:
+    /// - Rendered buffer contains `ripgrep (3)` and a bare `fd` entry.
+    ///
+    /// Details:
+    /// - Also checks the label helper omits counts when disabled.
+    #[test]
+    fn recent_renders_result_count_next_to_query() {
+        let backend = TestBackend::new(40, 8);
+        let mut term = Terminal::new(backend).expect("Failed to create terminal for test");
+        let mut app = crate::state::AppState::default();
+        init_test_translations(&mut app);
+        app.show_recent_pane = true;
+        app.load_recent_items(&["ripgrep".to_string(), "fd".to_string()]);
+        app.record_recent_count("ripgrep", 3);
+
+        term.draw(|f| {
+            let area = f.area();
+            render_recent(f, &mut app, area);
+        })
+        .expect("Failed to render recent pane");
+
+        let buffer = term.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        if crate::theme::settings().recent_show_result_counts {
+            assert!(rows.iter().any(|r| r.contains("ripgrep (3)")));
+        }
+        assert!(rows.iter().any(|r| r.contains("fd") && !r.contains("fd (")));
+        assert_eq!(recent_entry_label(&app, "ripgrep", true), "ripgrep (3)");
+        assert_eq!(recent_entry_label(&app, "ripgrep", false), "ripgrep");
+        assert_eq!(recent_entry_label(&app, "fd", true), "fd");
+    }
+
     /// What: Verify recent pane does not render and clears rect when hidden.
     ///
     /// Inputs:
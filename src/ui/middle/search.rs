@@ -163,11 +163,18 @@ fn build_title_line<'a>(
     } else {
         th.subtext0
     };
+    title_spans.push(Span::raw(" "));
     title_spans.push(Span::styled(
-        format!(" [{mode_text}]"),
+        format!("[{mode_text}]"),
         Style::default().fg(mode_color),
     ));
-    (Line::from(title_spans), base_title_len, mode_text)
+    // Right-to-left locales show the indicator first and right-align the title
+    let rtl = i18n::is_rtl(&app.locale);
+    if rtl {
+        title_spans = crate::ui::helpers::mirror_spans(title_spans);
+    }
+    let title = Line::from(title_spans).alignment(crate::ui::helpers::label_alignment(rtl));
+    (title, base_title_len, mode_text)
 }
 
 /// What: Calculate and store fuzzy indicator rectangle.
@@ -183,6 +190,7 @@ fn build_title_line<'a>(
 ///
 /// Details:
 /// - Stores clickable rectangle for mouse interaction
+/// - Mirrors the rectangle for right-to-left locales, where the indicator leads the title
 fn store_fuzzy_indicator_rect(
     app: &mut AppState,
     area: Rect,
@@ -201,12 +209,21 @@ fn store_fuzzy_indicator_rect(
         )
         .unwrap_or(u16::MAX),
     );
-    app.fuzzy_indicator_rect = Some((
+    let rect = (
         title_end_x,
         area.y,
         max_indicator_width_u16,
         1, // height
-    ));
+    );
+    app.fuzzy_indicator_rect = Some(if i18n::is_rtl(&app.locale) {
+        crate::ui::helpers::mirror_rect(
+            rect,
+            area.x.saturating_add(1),
+            area.width.saturating_sub(2),
+        )
+    } else {
+        rect
+    });
 }
 
 /// What: Render the search input widget in the center of the middle row.
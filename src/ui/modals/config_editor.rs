@@ -90,6 +90,8 @@ const fn clear_stale_main_rects(app: &mut AppState) {
     app.results_filter_cachyos_rect = None;
     app.results_filter_artix_rect = None;
     app.results_filter_custom_repos_rect = None;
+    app.results_filter_outofdate_rect = None;
+    app.results_filter_orphaned_rect = None;
     app.artix_filter_menu_rect = None;
     app.custom_repos_filter_menu_rect = None;
     app.install_import_rect = None;
@@ -320,6 +320,103 @@ pub fn render_confirm_aur_update(f: &mut Frame, app: &AppState, area: Rect, mess
     f.render_widget(paragraph, rect);
 }
 
+/// What: Render the guidance modal shown when no AUR helper is found at install time.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `app`: Application state
+/// - `area`: Full screen area used to center the modal
+/// - `items`: Install set that was blocked
+///
+/// Output:
+/// - Draws the guidance dialog listing the AUR targets and the available choices.
+///
+/// Details:
+/// - Only advertises the pacman-only option when the set contains official packages.
+#[allow(clippy::many_single_char_names)]
+pub fn render_aur_helper_missing(f: &mut Frame, app: &AppState, area: Rect, items: &[PackageItem]) {
+    let th = theme();
+    let w = area.width.saturating_sub(6).min(88);
+    let h = area.height.saturating_sub(6).min(20);
+    let x = area.x + (area.width.saturating_sub(w)) / 2;
+    let y = area.y + (area.height.saturating_sub(h)) / 2;
+    let rect = Rect {
+        x,
+        y,
+        width: w,
+        height: h,
+    };
+    f.render_widget(Clear, rect);
+
+    let aur_names: Vec<&str> = items
+        .iter()
+        .filter(|p| matches!(p.source, crate::state::Source::Aur))
+        .map(|p| p.name.as_str())
+        .collect();
+    let official_count = items.len() - aur_names.len();
+
+    let mut lines: Vec<Line<'static>> = vec![
+        Line::from(Span::styled(
+            i18n::t(app, "app.modals.aur_helper_missing.line1"),
+            Style::default().fg(th.yellow),
+        )),
+        Line::from(Span::styled(
+            i18n::t(app, "app.modals.aur_helper_missing.line2"),
+            Style::default().fg(th.text),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            i18n::t(app, "app.modals.aur_helper_missing.aur_heading"),
+            Style::default().fg(th.subtext1),
+        )),
+    ];
+    let max_rows = (h as usize).saturating_sub(10);
+    for name in aur_names.iter().take(max_rows) {
+        lines.push(Line::from(Span::styled(
+            format!("  • {name}"),
+            Style::default().fg(th.text),
+        )));
+    }
+    if aur_names.len() > max_rows {
+        lines.push(Line::from(Span::styled(
+            format!("  … (+{} more)", aur_names.len() - max_rows),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        i18n::t(app, "app.modals.aur_helper_missing.hint_optional_deps"),
+        Style::default().fg(th.subtext1),
+    )));
+    if official_count > 0 {
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(
+                app,
+                "app.modals.aur_helper_missing.hint_pacman_only",
+                official_count,
+            ),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        i18n::t(app, "app.modals.aur_helper_missing.hint_cancel"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .title(Span::styled(
+                i18n::t(app, "app.modals.aur_helper_missing.title"),
+                Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(th.yellow))
+            .style(Style::default().bg(th.mantle)),
+    );
+    f.render_widget(paragraph, rect);
+}
+
 /// What: Render the confirmation modal for AUR vote/unvote actions.
 ///
 /// Inputs:
@@ -149,6 +149,34 @@ fn build_global_bindings(
         km.reload_config.first().copied(),
         "app.modals.help.key_labels.reload_config",
     );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.retry_failed_fetches.first().copied(),
+        "app.modals.help.key_labels.retry_failed_fetches",
+    );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.command_palette.first().copied(),
+        "app.modals.help.key_labels.command_palette",
+    );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.copy_package_name.first().copied(),
+        "app.modals.help.key_labels.copy_package_name",
+    );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.copy_package_details.first().copied(),
+        "app.modals.help.key_labels.copy_package_details",
+    );
     add_binding_if_some(
         lines,
         app,
@@ -170,6 +198,20 @@ fn build_global_bindings(
         km.pane_right.first().copied(),
         "app.modals.help.key_labels.focus_right",
     );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.pane_up.first().copied(),
+        "app.modals.help.key_labels.pane_up",
+    );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.pane_down.first().copied(),
+        "app.modals.help.key_labels.pane_down",
+    );
     add_binding_if_some(
         lines,
         app,
@@ -281,6 +323,13 @@ fn build_search_bindings(
         km.search_install.first().copied(),
         "app.modals.help.key_labels.install",
     );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.search_quick_install.first().copied(),
+        "app.modals.help.key_labels.quick_install",
+    );
     add_binding_if_some(
         lines,
         app,
@@ -302,6 +351,20 @@ fn build_search_bindings(
         km.toggle_fuzzy.first().copied(),
         "app.modals.help.key_labels.toggle_fuzzy",
     );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.search_toggle_variant.first().copied(),
+        "app.modals.help.key_labels.toggle_variant",
+    );
+    add_binding_if_some(
+        lines,
+        app,
+        th,
+        km.search_accept_suggestion.first().copied(),
+        "app.modals.help.key_labels.accept_suggestion",
+    );
 }
 
 /// What: Build search normal mode keybindings section.
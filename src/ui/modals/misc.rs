@@ -834,6 +834,216 @@ pub fn render_news_setup(
     );
 }
 
+/// What: Render the activity log browser.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `app`: Application state for localized strings
+/// - `area`: Full screen area
+/// - `entries`: Log entries, newest first
+/// - `scroll`: Index of the first visible entry
+///
+/// Output:
+/// - Draws one line per entry (local time, action, packages) and key hints.
+///
+/// Details:
+/// - Shows at most 12 entries so the list fits the 20-row simple modal with its hints.
+pub fn render_activity_log(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    entries: &[crate::app::ActivityEntry],
+    scroll: u16,
+) {
+    const VISIBLE: usize = 12;
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = vec![
+        Line::from(Span::styled(
+            crate::i18n::t_fmt1(app, "app.modals.activity_log.heading", entries.len()),
+            Style::default().fg(th.mauve).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.activity_log.empty"),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    let start = usize::from(scroll).min(entries.len().saturating_sub(1));
+    for entry in entries.iter().skip(start).take(VISIBLE) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", crate::util::ts_to_date_local(Some(entry.timestamp))),
+                Style::default().fg(th.subtext1),
+            ),
+            Span::styled(
+                format!("{:<10}", entry.action.as_str()),
+                Style::default()
+                    .fg(th.sapphire)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(entry.packages.join(" "), Style::default().fg(th.text)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.activity_log.footer_hint"),
+        Style::default().fg(th.overlay1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.activity_log.title"),
+        lines,
+    );
+}
+
+/// What: Render the command palette (fuzzy action launcher).
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `app`: Application state for localized labels and the current mode
+/// - `area`: Full screen area
+/// - `query`: Text typed into the palette
+/// - `selected`: Highlighted index into the matching actions
+///
+/// Output:
+/// - Draws the query line, up to 14 matching actions around the selection, and key hints.
+pub fn render_command_palette(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    query: &str,
+    selected: usize,
+) {
+    const VISIBLE: usize = 14;
+    let th = theme();
+    let matches = crate::logic::command_palette::palette_matches(app, query);
+    let mut lines: Vec<Line<'static>> = vec![
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(th.mauve)),
+            Span::styled(format!("{query}_"), Style::default().fg(th.text)),
+        ]),
+        Line::from(""),
+    ];
+    if matches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.command_palette.no_matches"),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    let start = selected.saturating_sub(VISIBLE - 1);
+    for (i, action) in matches.iter().enumerate().skip(start).take(VISIBLE) {
+        let (marker, style) = if i == selected {
+            (
+                "▶ ",
+                Style::default()
+                    .fg(th.text)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            )
+        } else {
+            ("  ", Style::default().fg(th.subtext1))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(th.mauve)),
+            Span::styled(crate::i18n::t(app, action.label_key), style),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.command_palette.footer_hint"),
+        Style::default().fg(th.overlay1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.command_palette.title"),
+        lines,
+    );
+}
+
+/// What: Render the named install-list profile picker.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `area`: Full screen area
+/// - `app`: Application state for localized strings and the current list size
+/// - `profiles`: Saved profile names
+/// - `selected`: Highlighted profile index
+/// - `name_input`: Name typed for saving
+///
+/// Output:
+/// - Draws the profile list, the save-name field, and key hints.
+pub fn render_install_profiles(
+    f: &mut Frame,
+    area: Rect,
+    app: &AppState,
+    profiles: &[String],
+    selected: usize,
+    name_input: &str,
+) {
+    let th = theme();
+    let mut lines: Vec<Line<'static>> = vec![
+        Line::from(Span::styled(
+            crate::i18n::t_fmt1(
+                app,
+                "app.modals.install_profiles.heading",
+                app.install_list.len(),
+            ),
+            Style::default().fg(th.mauve).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    if profiles.is_empty() {
+        lines.push(Line::from(Span::styled(
+            crate::i18n::t(app, "app.modals.install_profiles.empty"),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    for (i, name) in profiles.iter().enumerate() {
+        let (marker, style) = if i == selected {
+            (
+                "▶ ",
+                Style::default()
+                    .fg(th.text)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            )
+        } else {
+            ("  ", Style::default().fg(th.subtext1))
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(th.mauve)),
+            Span::styled(name.clone(), style),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!(
+                "{} ",
+                crate::i18n::t(app, "app.modals.install_profiles.name_label")
+            ),
+            Style::default().fg(th.subtext1),
+        ),
+        Span::styled(format!("{name_input}_"), Style::default().fg(th.text)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        crate::i18n::t(app, "app.modals.install_profiles.footer_hint"),
+        Style::default().fg(th.overlay1),
+    )));
+
+    render_simple_list_modal(
+        f,
+        area,
+        &crate::i18n::t(app, "app.modals.install_profiles.title"),
+        lines,
+    );
+}
+
 /// What: Render the first-startup setup selector checklist modal.
 ///
 /// Inputs:
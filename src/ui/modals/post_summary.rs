@@ -149,3 +149,88 @@ pub fn render_post_summary(
         );
     f.render_widget(boxw, rect);
 }
+
+/// What: Render the reminder listing `.pacnew`/`.pacsave` files created by the last transaction.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `app`: Application state for translations
+/// - `area`: Full screen area used to center the modal
+/// - `paths`: New conflict file paths
+/// - `scroll`: First path shown in the list
+///
+/// Output:
+/// - Draws the reminder dialog with the affected paths and the `pacdiff`/dismiss hints.
+///
+/// Details:
+/// - The path list scrolls while the heading and hints stay in place.
+#[allow(clippy::many_single_char_names)]
+pub fn render_pacnew_reminder(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    paths: &[String],
+    scroll: u16,
+) {
+    let th = theme();
+    let w = area.width.saturating_sub(8).min(80);
+    let list_rows = u16::try_from(paths.len()).unwrap_or(u16::MAX).min(10);
+    // heading + empty + list + empty + two hints, plus borders
+    let h = area.height.saturating_sub(4).min(list_rows + 6);
+    let x = area.x + (area.width.saturating_sub(w)) / 2;
+    let y = area.y + (area.height.saturating_sub(h)) / 2;
+    let rect = Rect {
+        x,
+        y,
+        width: w,
+        height: h,
+    };
+    f.render_widget(Clear, rect);
+
+    let visible = usize::from(h.saturating_sub(6)).max(1);
+    let start = usize::from(scroll).min(paths.len().saturating_sub(1));
+    let mut lines: Vec<Line<'static>> = vec![
+        Line::from(Span::styled(
+            i18n::t_fmt1(app, "app.modals.pacnew_reminder.heading", paths.len()),
+            Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for path in paths.iter().skip(start).take(visible) {
+        lines.push(Line::from(Span::styled(
+            format!("  • {path}"),
+            Style::default().fg(th.text),
+        )));
+    }
+    let hidden = paths.len().saturating_sub(start + visible);
+    if hidden > 0 {
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(app, "app.modals.pacnew_reminder.more", hidden),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        i18n::t(app, "app.modals.pacnew_reminder.hint_pacdiff"),
+        Style::default().fg(th.subtext1),
+    )));
+    lines.push(Line::from(Span::styled(
+        i18n::t(app, "app.modals.pacnew_reminder.hint_dismiss"),
+        Style::default().fg(th.subtext1),
+    )));
+
+    let boxw = Paragraph::new(lines)
+        .style(Style::default().fg(th.text).bg(th.mantle))
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    format!(" {} ", i18n::t(app, "app.modals.pacnew_reminder.title")),
+                    Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(Style::default().fg(th.yellow))
+                .style(Style::default().bg(th.mantle)),
+        );
+    f.render_widget(boxw, rect);
+}
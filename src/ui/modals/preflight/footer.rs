@@ -28,7 +28,8 @@ use crate::theme::theme;
 ///
 /// Details:
 /// - Builds footer hint based on current tab and whether AUR packages are present.
-/// - Adds cascade mode hint for remove actions.
+/// - Adds the AUR helper selector hint for installs with AUR packages, the cascade mode hint
+///   for remove actions, and the raw output hint in expert mode.
 #[allow(clippy::too_many_arguments)]
 pub fn render_footer(
     f: &mut Frame,
@@ -84,12 +85,30 @@ pub fn render_footer(
         }
     };
 
+    if matches!(action, PreflightAction::Install) && has_aur {
+        let helper = crate::install::selected_aur_helper(
+            app.install_aur_helper.as_deref(),
+            &crate::theme::settings().aur_helper,
+        )
+        .unwrap_or("auto");
+        scan_hint.push_str(&i18n::t_fmt1(
+            app,
+            "app.modals.preflight.footer_hints.aur_helper",
+            helper,
+        ));
+    }
     if matches!(action, PreflightAction::Remove) {
         scan_hint.push_str(&i18n::t(
             app,
             "app.modals.preflight.footer_hints.cascade_mode",
         ));
     }
+    if app.expert_mode {
+        scan_hint.push_str(&i18n::t(
+            app,
+            "app.modals.preflight.footer_hints.raw_output",
+        ));
+    }
 
     let keybinds_lines = vec![
         Line::from(""), // Empty line for spacing
@@ -271,3 +271,67 @@ fn test_format_signed_bytes_fractional_positive() {
 fn test_format_signed_bytes_fractional_negative() {
     assert_eq!(format_signed_bytes(-1536), "-1.5 KiB");
 }
+
+/// What: Test the byte formatting under both unit conventions.
+///
+/// Inputs:
+/// - `value`: 1,000,000 bytes
+///
+/// Output:
+/// - "1.0 MB" with SI units and "976.6 KiB" with binary units
+///
+/// Details:
+/// - Uses the explicit-unit formatter so the global preference is not involved.
+#[test]
+fn test_format_bytes_in_si_and_binary() {
+    use crate::ui::helpers::{SizeUnits, format_bytes_in};
+    assert_eq!(format_bytes_in(1_000_000, SizeUnits::Si), "1.0 MB");
+    assert_eq!(format_bytes_in(1_000_000, SizeUnits::Binary), "976.6 KiB");
+    assert_eq!(format_bytes_in(999, SizeUnits::Si), "999 B");
+    assert_eq!(format_bytes_in(1500, SizeUnits::Si), "1.5 kB");
+}
+
+/// What: Test that the `ui_size_units` preference switches all size formatters.
+///
+/// Inputs:
+/// - `set_size_units` toggled between SI and binary
+///
+/// Output:
+/// - `format_bytes`, `format_signed_bytes`, and `human_bytes` follow the preference
+///
+/// Details:
+/// - The preference is per-thread under test, so other tests keep binary units.
+#[test]
+fn test_size_units_setting_switches_globally() {
+    use crate::ui::helpers::{SizeUnits, human_bytes, set_size_units, size_units};
+    assert_eq!(size_units(), SizeUnits::Binary);
+    assert_eq!(format_bytes(1_000_000), "976.6 KiB");
+
+    set_size_units(SizeUnits::Si);
+    assert_eq!(format_bytes(1_000_000), "1.0 MB");
+    assert_eq!(format_signed_bytes(-1_000_000), "-1.0 MB");
+    assert_eq!(human_bytes(2_500), "2.5 kB");
+
+    set_size_units(SizeUnits::Binary);
+    assert_eq!(format_bytes(1_000_000), "976.6 KiB");
+}
+
+/// What: Test parsing of `ui_size_units` setting values.
+///
+/// Inputs:
+/// - Canonical values, aliases, and an unknown value
+///
+/// Output:
+/// - Aliases map to the canonical convention; unknown values are rejected
+///
+/// Details:
+/// - Unknown values leave the current setting untouched in the settings parser.
+#[test]
+fn test_size_units_from_config_key() {
+    use crate::ui::helpers::SizeUnits;
+    assert_eq!(SizeUnits::from_config_key("SI"), Some(SizeUnits::Si));
+    assert_eq!(SizeUnits::from_config_key(" decimal "), Some(SizeUnits::Si));
+    assert_eq!(SizeUnits::from_config_key("iec"), Some(SizeUnits::Binary));
+    assert_eq!(SizeUnits::from_config_key("metric-ish"), None);
+    assert_eq!(SizeUnits::Si.as_config_key(), "si");
+}
@@ -12,6 +12,8 @@ use crate::theme::theme;
 pub mod extract;
 /// Layout calculation helpers for preflight modal.
 pub mod layout;
+/// Raw command output subview helpers for expert mode.
+pub mod raw_output;
 /// Scroll handling helpers for preflight modal.
 pub mod scroll;
 /// Sync information helpers for preflight modal.
@@ -140,6 +142,8 @@ mod format_tests;
 #[cfg(test)]
 mod layout_tests;
 #[cfg(test)]
+mod raw_output_tests;
+#[cfg(test)]
 mod render_tests;
 #[cfg(test)]
 mod sync_dependencies_tests;
@@ -0,0 +1,78 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::i18n;
+use crate::logic::raw_output::RawOutputEntry;
+use crate::state::AppState;
+use crate::state::PackageItem;
+use crate::state::modal::DependencyInfo;
+use crate::theme::theme;
+
+/// What: Collect captured raw output for the packages shown in the preflight modal.
+///
+/// Inputs:
+/// - `items`: Packages under review.
+/// - `dependency_info`: Resolved dependencies (nested AUR deps have their own captures).
+///
+/// Output:
+/// - Captures for the items first, then for their dependencies.
+#[must_use]
+pub fn collect_raw_output(
+    items: &[PackageItem],
+    dependency_info: &[DependencyInfo],
+) -> Vec<RawOutputEntry> {
+    crate::logic::raw_output::raw_output_for(
+        items
+            .iter()
+            .map(|p| p.name.as_str())
+            .chain(dependency_info.iter().map(|d| d.name.as_str())),
+    )
+}
+
+/// What: Build the lines of the raw command output subview.
+///
+/// Inputs:
+/// - `app`: Application state for i18n.
+/// - `entries`: Captured command output to display.
+///
+/// Output:
+/// - A header line followed by one `$ command` line and the unmodified stdout per capture.
+///
+/// Details:
+/// - Shows a hint instead when nothing has been captured for these packages yet.
+#[must_use]
+pub fn build_raw_output_lines(app: &AppState, entries: &[RawOutputEntry]) -> Vec<Line<'static>> {
+    let th = theme();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            i18n::t_fmt1(app, "app.modals.preflight.raw_output.header", entries.len()),
+            Style::default().fg(th.overlay1),
+        )),
+        Line::from(""),
+    ];
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            i18n::t(app, "app.modals.preflight.raw_output.empty"),
+            Style::default().fg(th.subtext1),
+        )));
+        return lines;
+    }
+    for entry in entries {
+        lines.push(Line::from(Span::styled(
+            format!("$ {}", entry.command),
+            Style::default()
+                .fg(th.sapphire)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(
+            entry
+                .output
+                .lines()
+                .map(|l| Line::from(Span::styled(l.to_string(), Style::default().fg(th.text)))),
+        );
+        lines.push(Line::from(""));
+    }
+    lines
+}
@@ -0,0 +1,131 @@
+//! Unit tests for the expert-mode raw output subview.
+
+use super::raw_output::{build_raw_output_lines, collect_raw_output};
+use crate::logic::raw_output::store_raw_output;
+use crate::state::modal::{CascadeMode, PreflightHeaderChips};
+use crate::state::{AppState, Modal, PackageItem, PreflightAction, PreflightTab};
+use ratatui::{Terminal, backend::TestBackend};
+use std::collections::{HashMap, HashSet};
+
+/// What: Build a Preflight modal for a single official package.
+///
+/// Inputs:
+/// - `name`: Package name.
+///
+/// Output:
+/// - `Modal::Preflight` on the Summary tab with no resolved data.
+fn preflight_modal(name: &str) -> Modal {
+    Modal::Preflight {
+        items: vec![PackageItem::test_item(name, "extra")],
+        action: PreflightAction::Install,
+        tab: PreflightTab::Summary,
+        summary: None,
+        summary_scroll: 0,
+        header_chips: PreflightHeaderChips::default(),
+        dependency_info: Vec::new(),
+        dep_selected: 0,
+        dep_tree_expanded: HashSet::new(),
+        deps_error: None,
+        file_info: Vec::new(),
+        file_selected: 0,
+        file_tree_expanded: HashSet::new(),
+        files_error: None,
+        service_info: Vec::new(),
+        service_selected: 0,
+        services_loaded: false,
+        services_error: None,
+        sandbox_info: Vec::new(),
+        sandbox_selected: 0,
+        sandbox_tree_expanded: HashSet::new(),
+        sandbox_loaded: false,
+        sandbox_error: None,
+        selected_optdepends: HashMap::new(),
+        cascade_mode: CascadeMode::Basic,
+        cached_reverse_deps_report: None,
+    }
+}
+
+/// What: Render the Preflight modal and return the buffer text.
+///
+/// Inputs:
+/// - `app`: Application state (expert mode and subview flags).
+/// - `modal`: Preflight modal to render.
+///
+/// Output:
+/// - All buffer cells concatenated row by row.
+fn render_text(app: &mut AppState, modal: &mut Modal) -> String {
+    let mut term = Terminal::new(TestBackend::new(120, 30)).expect("test terminal");
+    term.draw(|f| {
+        let area = f.area();
+        super::super::render_preflight(f, area, app, modal);
+    })
+    .expect("render preflight");
+    let buffer = term.backend().buffer();
+    buffer
+        .content()
+        .iter()
+        .map(ratatui::buffer::Cell::symbol)
+        .collect()
+}
+
+/// What: Verify captured output is listed with its command line.
+///
+/// Inputs:
+/// - One capture for a unique package name.
+///
+/// Output:
+/// - Lines contain `$ pacman -Si ...` followed by the unmodified stdout lines.
+///
+/// Details:
+/// - Uses a unique package name because the capture store is process-global.
+#[test]
+fn raw_output_lines_include_command_and_output() {
+    store_raw_output(
+        "raw-lines-pkg",
+        "pacman -Si extra/raw-lines-pkg",
+        "Name            : raw-lines-pkg\nDepends On      : glibc",
+    );
+    let Modal::Preflight { items, .. } = preflight_modal("raw-lines-pkg") else {
+        unreachable!()
+    };
+    let entries = collect_raw_output(&items, &[]);
+    let text: Vec<String> = build_raw_output_lines(&AppState::default(), &entries)
+        .iter()
+        .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+        .collect();
+    assert!(text.contains(&"$ pacman -Si extra/raw-lines-pkg".to_string()));
+    assert!(text.contains(&"Depends On      : glibc".to_string()));
+}
+
+/// What: Verify the modal shows captured raw output only in expert mode.
+///
+/// Inputs:
+/// - A capture for the previewed package and the subview flag set, rendered with and without
+///   expert mode.
+///
+/// Output:
+/// - Expert mode renders the captured text; otherwise the regular tab content is shown.
+///
+/// Details:
+/// - A stale open flag must not leak the subview when expert mode is turned off.
+#[test]
+fn raw_output_subview_renders_only_in_expert_mode() {
+    store_raw_output(
+        "raw-view-pkg",
+        "pacman -Si extra/raw-view-pkg",
+        "Description     : captured-marker-text",
+    );
+    let mut modal = preflight_modal("raw-view-pkg");
+    let mut app = AppState {
+        preflight_raw_output_open: true,
+        ..Default::default()
+    };
+
+    let hidden = render_text(&mut app, &mut modal);
+    assert!(!hidden.contains("captured-marker-text"));
+
+    app.expert_mode = true;
+    let shown = render_text(&mut app, &mut modal);
+    assert!(shown.contains("captured-marker-text"));
+    assert!(shown.contains("$ pacman -Si extra/raw-view-pkg"));
+}
@@ -114,6 +114,8 @@ fn test_sync_dependencies_filters_by_required_by() {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         DependencyInfo {
             name: "dep2".to_string(),
@@ -126,6 +128,8 @@ fn test_sync_dependencies_filters_by_required_by() {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ];
 
@@ -183,6 +187,8 @@ fn test_sync_dependencies_resets_selection_on_first_load() {
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
 
     let items = vec![PackageItem {
@@ -238,6 +244,8 @@ fn test_sync_dependencies_preserves_selection_on_subsequent_load() {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         DependencyInfo {
             name: "dep2".to_string(),
@@ -250,6 +258,8 @@ fn test_sync_dependencies_preserves_selection_on_subsequent_load() {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ];
 
@@ -275,6 +285,8 @@ fn test_sync_dependencies_preserves_selection_on_subsequent_load() {
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
     let mut dep_selected = 2;
 
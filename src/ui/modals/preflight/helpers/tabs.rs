@@ -100,15 +100,27 @@ pub fn render_tab_content(
             deps_error,
             content_rect,
         ),
-        PreflightTab::Files => render_files_tab(
-            app,
-            items,
-            file_info,
-            file_selected,
-            file_tree_expanded,
-            files_error,
-            content_rect,
-        ),
+        PreflightTab::Files => {
+            let orphaned_dependencies = match (action, summary) {
+                (PreflightAction::Remove, Some(data)) => {
+                    crate::logic::deps::orphan_removal_preview(
+                        &data.orphaned_dependencies,
+                        cascade_mode,
+                    )
+                }
+                _ => &[],
+            };
+            render_files_tab(
+                app,
+                items,
+                file_info,
+                file_selected,
+                file_tree_expanded,
+                files_error,
+                orphaned_dependencies,
+                content_rect,
+            )
+        }
         PreflightTab::Services => render_services_tab(
             app,
             service_info,
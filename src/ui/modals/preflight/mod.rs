@@ -15,7 +15,7 @@ mod tabs;
 
 use footer::render_footer;
 use header::render_tab_header;
-use helpers::{extract, layout, scroll, sync, tabs as tab_helpers, widget};
+use helpers::{extract, layout, raw_output, scroll, sync, tabs as tab_helpers, widget};
 
 use ratatui::text::Line;
 
@@ -142,6 +142,8 @@ fn build_preflight_content_lines(
 /// Details:
 /// - Lazily resolves dependencies/files when first accessed, lays out tab headers, records tab
 ///   rectangles for mouse navigation, and tailors summaries per tab with theming cues.
+/// - In expert mode with the raw output subview open, shows the captured command output instead
+///   of the tab content.
 pub fn render_preflight(
     f: &mut Frame,
     area: Rect,
@@ -187,6 +189,9 @@ pub fn render_preflight(
     f.render_widget(Clear, rect);
 
     let title = match *fields.action {
+        _ if app.expert_mode && app.preflight_raw_output_open => {
+            i18n::t(app, "app.modals.preflight.raw_output.title")
+        }
         PreflightAction::Install => i18n::t(app, "app.modals.preflight.title_install"),
         PreflightAction::Remove => i18n::t(app, "app.modals.preflight.title_remove"),
         PreflightAction::Downgrade => i18n::t(app, "app.modals.preflight.title_downgrade"),
@@ -194,8 +199,17 @@ pub fn render_preflight(
     let border_color = preflight_modal_border_color(*fields.action, &th);
     let bg_color = th.crust;
 
-    let lines = build_preflight_content_lines(app, &mut fields, content_rect);
-    let scroll_offset = scroll::calculate_scroll_offset(app, *fields.tab);
+    let (lines, scroll_offset) = if app.expert_mode && app.preflight_raw_output_open {
+        let entries = raw_output::collect_raw_output(fields.items, fields.dependency_info);
+        let lines = raw_output::build_raw_output_lines(app, &entries);
+        // Keep at least one line visible when the captures shrink after re-resolution
+        let max_scroll = u16::try_from(lines.len().saturating_sub(1)).unwrap_or(u16::MAX);
+        app.preflight_raw_output_scroll = app.preflight_raw_output_scroll.min(max_scroll);
+        (lines, (app.preflight_raw_output_scroll, 0))
+    } else {
+        let lines = build_preflight_content_lines(app, &mut fields, content_rect);
+        (lines, scroll::calculate_scroll_offset(app, *fields.tab))
+    };
 
     let content_widget = widget::ParagraphBuilder::new()
         .with_lines(lines)
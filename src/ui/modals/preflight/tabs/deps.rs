@@ -61,6 +61,8 @@ struct DepStats {
     conflict: usize,
     /// Number of missing dependencies.
     missing: usize,
+    /// Number of optional dependencies (not counted in the status fields above).
+    optional: usize,
 }
 
 /// What: Calculate dependency statistics from unique dependencies.
@@ -73,29 +75,27 @@ struct DepStats {
 ///
 /// Details:
 /// - Counts each dependency only once regardless of how many packages require it.
+/// - Optional dependencies are counted separately; they are never installed by the transaction.
 fn calculate_dep_stats(unique_deps: &HashMap<String, &DependencyInfo>) -> DepStats {
+    let hard = || unique_deps.values().filter(|d| !d.optional);
     DepStats {
         total: unique_deps.len(),
-        installed: unique_deps
-            .values()
+        installed: hard()
             .filter(|d| matches!(d.status, DependencyStatus::Installed { .. }))
             .count(),
-        to_install: unique_deps
-            .values()
+        to_install: hard()
             .filter(|d| matches!(d.status, DependencyStatus::ToInstall))
             .count(),
-        to_upgrade: unique_deps
-            .values()
+        to_upgrade: hard()
             .filter(|d| matches!(d.status, DependencyStatus::ToUpgrade { .. }))
             .count(),
-        conflict: unique_deps
-            .values()
+        conflict: hard()
             .filter(|d| matches!(d.status, DependencyStatus::Conflict { .. }))
             .count(),
-        missing: unique_deps
-            .values()
+        missing: hard()
             .filter(|d| matches!(d.status, DependencyStatus::Missing))
             .count(),
+        optional: unique_deps.values().filter(|d| d.optional).count(),
     }
 }
 
@@ -236,6 +236,15 @@ fn render_summary_header(
                 count_text,
             ));
         }
+        if stats.optional > 0 {
+            let count_text =
+                format_count_with_indicator(stats.optional, stats.total, has_incomplete);
+            summary_parts.push(i18n::t_fmt1(
+                app,
+                "app.modals.preflight.deps.optional",
+                count_text,
+            ));
+        }
         lines.push(Line::from(Span::styled(
             i18n::t_fmt1(
                 app,
@@ -384,6 +393,8 @@ fn render_empty_state(
 ///
 /// Details:
 /// - Includes all packages even if they have no dependencies.
+/// - Lists hard dependencies first; optional ones follow an "Optional" group row, which is the
+///   only non-header entry without a dependency.
 fn build_display_items<'a>(
     items: &[PackageItem],
     grouped: &'a HashMap<String, Vec<&'a DependencyInfo>>,
@@ -396,8 +407,17 @@ fn build_display_items<'a>(
 
         if is_expanded && let Some(pkg_deps) = grouped.get(pkg_name) {
             let mut seen_deps = HashSet::new();
-            for dep in pkg_deps {
+            let mut optional_group = false;
+            let ordered = pkg_deps
+                .iter()
+                .filter(|d| !d.optional)
+                .chain(pkg_deps.iter().filter(|d| d.optional));
+            for dep in ordered {
                 if seen_deps.insert(dep.name.as_str()) {
+                    if dep.optional && !optional_group {
+                        optional_group = true;
+                        display_items.push((false, String::new(), None));
+                    }
                     display_items.push((false, String::new(), Some(*dep)));
                 }
             }
@@ -454,7 +474,7 @@ fn calculate_viewport(
 /// - Returns vector of spans for the header line.
 ///
 /// Details:
-/// - Shows arrow symbol, package name, and dependency count.
+/// - Shows arrow symbol, package name, and dependency count (optional ones counted apart).
 fn render_package_header(
     header_name: &str,
     is_expanded: bool,
@@ -481,14 +501,22 @@ fn render_package_header(
 
     if let Some(pkg_deps) = grouped.get(header_name) {
         let mut seen_deps = HashSet::new();
-        let dep_count = pkg_deps
+        let (optional_count, dep_count) = pkg_deps
             .iter()
             .filter(|dep| seen_deps.insert(dep.name.as_str()))
-            .count();
-        spans.push(Span::styled(
-            format!("({dep_count} deps)"),
-            Style::default().fg(th.subtext1),
-        ));
+            .fold((0usize, 0usize), |(opt, hard), dep| {
+                if dep.optional {
+                    (opt + 1, hard)
+                } else {
+                    (opt, hard + 1)
+                }
+            });
+        let count_text = if optional_count > 0 {
+            format!("({dep_count} deps, {optional_count} optional)")
+        } else {
+            format!("({dep_count} deps)")
+        };
+        spans.push(Span::styled(count_text, Style::default().fg(th.subtext1)));
     } else {
         spans.push(Span::styled("(0 deps)", Style::default().fg(th.subtext1)));
     }
@@ -496,10 +524,44 @@ fn render_package_header(
     spans
 }
 
+/// What: Render the "Optional" group row inside an expanded package.
+///
+/// Inputs:
+/// - `is_selected`: Whether this row is selected.
+/// - `app`: Application state for i18n.
+/// - `th`: Theme colors.
+///
+/// Output:
+/// - Returns vector of spans for the group label line.
+fn render_optional_group(
+    is_selected: bool,
+    app: &AppState,
+    th: &crate::theme::Theme,
+) -> Vec<Span<'static>> {
+    let style = if is_selected {
+        Style::default()
+            .fg(th.crust)
+            .bg(th.lavender)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+            .fg(th.overlay1)
+            .add_modifier(Modifier::ITALIC)
+    };
+    vec![
+        Span::styled("  ", Style::default()),
+        Span::styled(
+            i18n::t(app, "app.modals.preflight.deps.optional_group"),
+            style,
+        ),
+    ]
+}
+
 /// What: Render a dependency item line.
 ///
 /// Inputs:
 /// - `dep`: Dependency information.
+/// - `root_names`: Names of the packages under review.
 /// - `is_selected`: Whether this item is selected.
 /// - `app`: Application state for i18n.
 /// - `th`: Theme colors.
@@ -509,16 +571,22 @@ fn render_package_header(
 ///
 /// Details:
 /// - Shows status icon, name, version, source badge, and additional status info.
+/// - Nested AUR dependencies also name the intermediate packages that require them.
+/// - Optional dependencies are indented under their group, use `○` when not installed and
+///   end with their one-line description.
 fn render_dependency_item(
     dep: &DependencyInfo,
+    root_names: &HashSet<&str>,
     is_selected: bool,
     app: &AppState,
     th: &crate::theme::Theme,
 ) -> Vec<Span<'static>> {
     let mut spans = Vec::new();
-    spans.push(Span::styled("  ", Style::default()));
+    let indent = if dep.optional { "    " } else { "  " };
+    spans.push(Span::styled(indent, Style::default()));
 
     let (status_icon, status_color) = match &dep.status {
+        DependencyStatus::ToInstall if dep.optional => ("○", th.subtext1),
         DependencyStatus::Installed { .. } => ("✓", th.green),
         DependencyStatus::ToInstall => ("+", th.yellow),
         DependencyStatus::ToUpgrade { .. } => ("↑", th.yellow),
@@ -599,6 +667,27 @@ fn render_dependency_item(
         _ => {}
     }
 
+    // Nested AUR expansion: name the intermediate packages that pulled this dependency in
+    let via: Vec<&str> = dep
+        .required_by
+        .iter()
+        .map(String::as_str)
+        .filter(|r| !root_names.contains(*r))
+        .collect();
+    if !via.is_empty() {
+        spans.push(Span::styled(
+            i18n::t_fmt1(app, "app.modals.preflight.deps.via_parent", via.join(", ")),
+            Style::default().fg(th.overlay1),
+        ));
+    }
+
+    if let Some(description) = &dep.description {
+        spans.push(Span::styled(
+            format!(" — {description}"),
+            Style::default().fg(th.subtext1),
+        ));
+    }
+
     spans
 }
 
@@ -672,6 +761,7 @@ pub fn render_deps_tab(
 
     // Build display items and render viewport
     let display_items = build_display_items(items, &grouped, dep_tree_expanded);
+    let root_names: HashSet<&str> = items.iter().map(|p| p.name.as_str()).collect();
     let available_height = (content_rect.height as usize).saturating_sub(6);
     let total_items = display_items.len();
     tracing::debug!(
@@ -697,9 +787,9 @@ pub fn render_deps_tab(
             let is_expanded = dep_tree_expanded.contains(header_name);
             render_package_header(header_name, is_expanded, is_selected, &grouped, &th)
         } else if let Some(dep) = dep {
-            render_dependency_item(dep, is_selected, app, &th)
+            render_dependency_item(dep, &root_names, is_selected, app, &th)
         } else {
-            continue;
+            render_optional_group(is_selected, app, &th)
         };
         lines.push(Line::from(spans));
     }
@@ -764,6 +764,8 @@ struct FileListContext<'a> {
     file_tree_expanded: &'a std::collections::HashSet<String>,
     /// Optional sync information tuple: (`download_size`, `package_name`, `risk_level`).
     sync_info: &'a Option<(u64, String, u8)>,
+    /// Orphaned dependencies removed alongside the targets (empty unless cascading).
+    orphaned_dependencies: &'a [String],
 }
 
 /// What: Render file entry line.
@@ -893,7 +895,13 @@ fn render_file_list(
         lines.push(Line::from(""));
     }
 
-    let header_lines = 4 + sync_timestamp_lines;
+    // Orphans are listed below the file list so click offsets above it stay unchanged
+    let orphan_lines = if ctx.orphaned_dependencies.is_empty() {
+        0
+    } else {
+        2
+    };
+    let header_lines = 4 + sync_timestamp_lines + orphan_lines;
     let available_height = (content_rect.height.saturating_sub(1) as usize)
         .saturating_sub(header_lines)
         .max(1);
@@ -931,6 +939,18 @@ fn render_file_list(
         )));
     }
 
+    if !ctx.orphaned_dependencies.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(
+                app,
+                "app.modals.preflight.files.orphaned_dependencies",
+                ctx.orphaned_dependencies.join(", "),
+            ),
+            Style::default().fg(th.sapphire),
+        )));
+    }
+
     lines
 }
 
@@ -943,6 +963,7 @@ fn render_file_list(
 /// - `file_selected`: Currently selected file index (mutable).
 /// - `file_tree_expanded`: Set of expanded package names.
 /// - `files_error`: Optional error message.
+/// - `orphaned_dependencies`: Orphaned dependencies the removal drops (already filtered by mode).
 /// - `content_rect`: Content area rectangle.
 ///
 /// Output:
@@ -951,6 +972,7 @@ fn render_file_list(
 /// Details:
 /// - Shows file changes grouped by package with expand/collapse.
 /// - Supports viewport-based rendering for large file lists.
+/// - Lists cascade-removed orphans below the file list for Remove actions.
 #[allow(clippy::too_many_arguments)]
 pub fn render_files_tab(
     app: &AppState,
@@ -959,6 +981,7 @@ pub fn render_files_tab(
     file_selected: &mut usize,
     file_tree_expanded: &std::collections::HashSet<String>,
     files_error: Option<&String>,
+    orphaned_dependencies: &[String],
     content_rect: Rect,
 ) -> Vec<Line<'static>> {
     const STALE_THRESHOLD_DAYS: u64 = 7;
@@ -1020,6 +1043,7 @@ pub fn render_files_tab(
             display_items: &display_items,
             file_tree_expanded,
             sync_info: &sync_info,
+            orphaned_dependencies,
         };
         render_file_list(app, &ctx, file_selected, content_rect)
     }
@@ -176,6 +176,8 @@ fn get_source_badge(source: &DependencySource) -> (String, ratatui::style::Color
 ///
 /// Details:
 /// - Shows risk factors if available.
+/// - Shows conflicts from the dry-run conflict check as warnings.
+/// - Lists alpm hooks the transaction triggers (slow ones such as mkinitcpio stand out).
 fn render_summary_data(
     app: &AppState,
     summary_data: &PreflightSummaryData,
@@ -203,6 +205,30 @@ fn render_summary_data(
             )));
         }
     }
+    if !summary_data.conflicts.is_empty() {
+        lines.push(Line::from(Span::styled(
+            i18n::t(app, "app.modals.preflight.summary.pacman_conflicts"),
+            Style::default().fg(th.red).add_modifier(Modifier::BOLD),
+        )));
+        for conflict in &summary_data.conflicts {
+            lines.push(Line::from(Span::styled(
+                format!("  ⚠ {}", conflict.warning()),
+                Style::default().fg(th.red),
+            )));
+        }
+    }
+    if !summary_data.hook_notes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            i18n::t(app, "app.modals.preflight.summary.hooks"),
+            Style::default().fg(th.yellow).add_modifier(Modifier::BOLD),
+        )));
+        for note in &summary_data.hook_notes {
+            lines.push(Line::from(Span::styled(
+                format!("  • {note}"),
+                Style::default().fg(th.subtext1),
+            )));
+        }
+    }
     lines.push(Line::from(""));
     lines
 }
@@ -1188,24 +1214,78 @@ fn render_cascade_footer(app: &AppState, allows_dependents: bool) -> Vec<Line<'s
     lines
 }
 
+/// What: Render orphaned dependencies removed together with the targets.
+///
+/// Inputs:
+/// - `app`: Application state for i18n.
+/// - `orphaned_dependencies`: Orphans computed during summary resolution.
+/// - `cascade_mode`: Removal cascade mode.
+///
+/// Output:
+/// - Returns a vector of lines to render (empty when the mode keeps orphans).
+///
+/// Details:
+/// - Lists orphans separately from dependents so `-Rs`/`-Rns` side effects are visible.
+fn render_orphan_preview(
+    app: &AppState,
+    orphaned_dependencies: &[String],
+    cascade_mode: CascadeMode,
+) -> Vec<Line<'static>> {
+    let orphans = crate::logic::deps::orphan_removal_preview(orphaned_dependencies, cascade_mode);
+    if orphans.is_empty() {
+        return Vec::new();
+    }
+    let th = theme();
+    let mut lines = vec![Line::from(Span::styled(
+        i18n::t_fmt(
+            app,
+            "app.modals.preflight.summary.orphaned_dependencies_header",
+            &[&cascade_mode.flag(), &orphans.len()],
+        ),
+        Style::default()
+            .fg(th.sapphire)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for name in orphans.iter().take(CASCADE_PREVIEW_MAX) {
+        lines.push(Line::from(vec![
+            Span::styled("◌ ", Style::default().fg(th.sapphire)),
+            Span::styled(name.clone(), Style::default().fg(th.text)),
+        ]));
+    }
+    if orphans.len() > CASCADE_PREVIEW_MAX {
+        lines.push(Line::from(Span::styled(
+            i18n::t_fmt1(
+                app,
+                "app.modals.preflight.summary.and_more_orphans",
+                orphans.len() - CASCADE_PREVIEW_MAX,
+            ),
+            Style::default().fg(th.subtext1),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
 /// What: Render remove action content (removal plan and cascade impact).
 ///
 /// Inputs:
 /// - `app`: Application state for i18n and data access.
 /// - `items`: Packages to remove.
 /// - `dependency_info`: Dependency information.
+/// - `orphaned_dependencies`: Dependencies orphaned by the removal.
 /// - `cascade_mode`: Removal cascade mode.
 ///
 /// Output:
 /// - Returns a vector of lines to render.
 ///
 /// Details:
-/// - Shows removal plan, dependent count, and cascade impact preview.
+/// - Shows removal plan, dependent count, orphan preview, and cascade impact preview.
 /// - Uses helper functions and data structures to reduce complexity.
 fn render_remove_action(
     app: &AppState,
     items: &[PackageItem],
     dependency_info: &[DependencyInfo],
+    orphaned_dependencies: &[String],
     cascade_mode: CascadeMode,
 ) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
@@ -1237,6 +1317,13 @@ fn render_remove_action(
         ctx.allows_dependents,
     ));
 
+    // Render orphaned dependencies dropped by cascade modes
+    lines.extend(render_orphan_preview(
+        app,
+        orphaned_dependencies,
+        cascade_mode,
+    ));
+
     // Render dependent impact section if there are dependents
     if dependent_count > 0 {
         lines.extend(render_impact_overview(app));
@@ -1317,10 +1404,13 @@ pub fn render_summary_tab(
             ));
         }
         PreflightAction::Remove => {
+            let orphaned_dependencies =
+                summary.map_or(&[][..], |data| data.orphaned_dependencies.as_slice());
             lines.extend(render_remove_action(
                 app,
                 items,
                 dependency_info,
+                orphaned_dependencies,
                 cascade_mode,
             ));
         }
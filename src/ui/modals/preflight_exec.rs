@@ -204,6 +204,8 @@ fn render_sidebar(
 /// - `log_lines`: Buffered log output
 /// - `verbose`: Whether verbose logging is enabled
 /// - `abortable`: Whether abort is currently available
+/// - `follow`: Whether the view follows the newest output
+/// - `from_bottom`: Scroll-back offset while following is paused
 /// - `title`: Title for the log panel block
 /// - `border_color`: Color for log panel border
 /// - `log_area_height`: Height of the log area in characters (full area including borders)
@@ -213,12 +215,15 @@ fn render_sidebar(
 ///
 /// Details:
 /// - Shows placeholder message if no logs, otherwise displays all log lines with auto-scroll to bottom.
-/// - Calculates scroll offset to always show the most recent output at the bottom.
-/// - Follows the same pattern as archinstall-rs: calculate visible lines by taking the last N lines.
+/// - While following, shows the last N lines (the archinstall-rs pattern); a paused view keeps
+///   its window `from_bottom` lines above the newest output.
+#[allow(clippy::too_many_arguments)]
 fn render_log_panel(
     log_lines: &[String],
     verbose: bool,
     abortable: bool,
+    follow: bool,
+    from_bottom: usize,
     title: String,
     border_color: ratatui::style::Color,
     log_area_height: u16,
@@ -246,8 +251,13 @@ fn render_log_panel(
     let footer_reserve = usize::from(!abortable);
     let max_log_lines = inner_height.saturating_sub(footer_reserve);
 
-    // Simple: take last N lines
-    let start = log_lines.len().saturating_sub(max_log_lines);
+    let start = crate::logic::exec_log::exec_log_window_start(
+        log_lines.len(),
+        max_log_lines,
+        follow,
+        from_bottom,
+    );
+    let end = start.saturating_add(max_log_lines).min(log_lines.len());
 
     let mut visible_lines: Vec<Line> = if log_lines.is_empty() {
         vec![Line::from(Span::styled(
@@ -255,7 +265,7 @@ fn render_log_panel(
             Style::default().fg(th.subtext1),
         ))]
     } else {
-        log_lines[start..]
+        log_lines[start..end]
             .iter()
             .map(|l| Line::from(Span::styled(l.clone(), Style::default().fg(th.text))))
             .collect()
@@ -385,6 +395,15 @@ pub fn render_preflight_exec(
         }
     };
 
+    let title = if app.exec_log_follow {
+        title
+    } else {
+        format!(
+            "{title}{}",
+            crate::i18n::t(app, "app.modals.preflight_exec.follow_paused")
+        )
+    };
+
     let sidebar = render_sidebar(items, tab, header_chips, border_color, bg_color);
     f.render_widget(sidebar, cols[0]);
 
@@ -392,6 +411,8 @@ pub fn render_preflight_exec(
         log_lines,
         verbose,
         abortable,
+        app.exec_log_follow,
+        app.exec_log_from_bottom,
         title,
         border_color,
         cols[1].height,
@@ -493,6 +493,22 @@ struct WarnAurRepoDuplicateContext {
     header_chips: PreflightHeaderChips,
 }
 
+/// What: Context struct grouping `AurHelperMissing` modal fields.
+///
+/// Inputs: None (constructed from `Modal` variant).
+///
+/// Output: Fields for the missing AUR helper guidance renderer.
+///
+/// Details: Preserves the install set, chips, and collected password for the pacman-only path.
+struct AurHelperMissingContext {
+    /// Install set that was blocked.
+    items: Vec<crate::state::PackageItem>,
+    /// Preflight chips carried through the guidance step.
+    header_chips: PreflightHeaderChips,
+    /// Password collected before execution, if any.
+    password: Option<crate::state::SecureString>,
+}
+
 /// What: Context struct grouping `ForeignRepoOverlap` modal fields.
 ///
 /// Inputs: None (constructed from `Modal` variant).
@@ -847,6 +863,32 @@ impl ModalRenderer for Modal {
                 };
                 render_warn_aur_repo_duplicate_modal(f, app, area, ctx)
             }
+            Self::AurHelperMissing {
+                items,
+                header_chips,
+                password,
+            } => {
+                let ctx = AurHelperMissingContext {
+                    items,
+                    header_chips,
+                    password,
+                };
+                render_aur_helper_missing_modal(f, app, area, ctx)
+            }
+            Self::PacnewReminder { paths, scroll } => {
+                render_pacnew_reminder_modal(f, app, area, paths, scroll)
+            }
+            Self::ActivityLog { entries, scroll } => {
+                render_activity_log_modal(f, app, area, entries, scroll)
+            }
+            Self::CommandPalette { query, selected } => {
+                render_command_palette_modal(f, app, area, query, selected)
+            }
+            Self::InstallProfiles {
+                profiles,
+                selected,
+                name_input,
+            } => render_install_profiles_modal(f, app, area, profiles, selected, name_input),
             Self::ForeignRepoOverlap {
                 repo_name,
                 entries,
@@ -1475,6 +1517,74 @@ fn render_warn_aur_repo_duplicate_modal(
     }
 }
 
+/// What: Render `AurHelperMissing` and return reconstructed state.
+fn render_aur_helper_missing_modal(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    ctx: AurHelperMissingContext,
+) -> Modal {
+    confirm::render_aur_helper_missing(f, app, area, &ctx.items);
+    Modal::AurHelperMissing {
+        items: ctx.items,
+        header_chips: ctx.header_chips,
+        password: ctx.password,
+    }
+}
+
+/// What: Render `PacnewReminder` and return reconstructed state.
+fn render_pacnew_reminder_modal(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    paths: Vec<String>,
+    scroll: u16,
+) -> Modal {
+    post_summary::render_pacnew_reminder(f, app, area, &paths, scroll);
+    Modal::PacnewReminder { paths, scroll }
+}
+
+/// What: Render `ActivityLog` and return reconstructed state.
+fn render_activity_log_modal(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    entries: Vec<crate::app::ActivityEntry>,
+    scroll: u16,
+) -> Modal {
+    misc::render_activity_log(f, app, area, &entries, scroll);
+    Modal::ActivityLog { entries, scroll }
+}
+
+/// What: Render `CommandPalette` and return reconstructed state.
+fn render_command_palette_modal(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    query: String,
+    selected: usize,
+) -> Modal {
+    misc::render_command_palette(f, app, area, &query, selected);
+    Modal::CommandPalette { query, selected }
+}
+
+/// What: Render `InstallProfiles` and return reconstructed state.
+fn render_install_profiles_modal(
+    f: &mut Frame,
+    app: &AppState,
+    area: Rect,
+    profiles: Vec<String>,
+    selected: usize,
+    name_input: String,
+) -> Modal {
+    misc::render_install_profiles(f, area, app, &profiles, selected, &name_input);
+    Modal::InstallProfiles {
+        profiles,
+        selected,
+        name_input,
+    }
+}
+
 /// What: Render `ForeignRepoOverlap` and return reconstructed state.
 fn render_foreign_repo_overlap_modal(
     f: &mut Frame,
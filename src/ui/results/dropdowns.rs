@@ -434,6 +434,9 @@ fn render_options_menu(
     } else {
         opts.push(mode_toggle_label);
     }
+    if !news_mode && !config_editor_mode {
+        opts.push(i18n::t(app, "app.results.options_menu.activity_log"));
+    }
     let widest = opts
         .iter()
         .map(|s| u16::try_from(s.width()).map_or(u16::MAX, |x| x))
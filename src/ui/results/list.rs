@@ -5,7 +5,7 @@ use ratatui::{
 };
 
 use crate::state::{AppState, PackageItem, Source};
-use crate::theme::{PackageMarker, Theme};
+use crate::theme::{MarkerKind, PackageMarker, Theme};
 
 /// What: Check if a package is in any of the install/remove/downgrade lists.
 ///
@@ -116,7 +116,7 @@ pub fn determine_source_label_and_color(
 ///
 /// Inputs:
 /// - `segs`: Vector of spans representing the package information
-/// - `marker_type`: Type of marker to apply (`FullLine`, `Front`, or `End`)
+/// - `marker_type`: Type of marker to apply (`FullLine`, `End`, or a front-placed style)
 /// - `label`: Marker label text (e.g., "[+]", "[-]", "[↓]")
 /// - `color`: Color for the marker
 /// - `in_install`: Whether package is in install list (affects `FullLine` background)
@@ -127,11 +127,11 @@ pub fn determine_source_label_and_color(
 ///
 /// Details:
 /// - `FullLine`: Colors the entire line background with dimmed color for installs
-/// - Front: Adds marker at the beginning of the line
+/// - Front, Ascii, `NerdFont`, Custom: Adds marker at the beginning of the line
 /// - End: Adds marker at the end of the line
 pub fn build_package_marker_item(
     segs: Vec<Span<'static>>,
-    marker_type: PackageMarker,
+    marker_type: &PackageMarker,
     label: &str,
     color: ratatui::style::Color,
     in_install: bool,
@@ -156,7 +156,10 @@ pub fn build_package_marker_item(
             item = item.style(Style::default().fg(theme.crust).bg(bgc));
             item
         }
-        PackageMarker::Front => {
+        PackageMarker::Front
+        | PackageMarker::Ascii
+        | PackageMarker::NerdFont
+        | PackageMarker::Custom { .. } => {
             let mut new_segs: Vec<Span> = Vec::new();
             new_segs.push(Span::styled(
                 label.to_string(),
@@ -230,6 +233,105 @@ fn aur_vote_state_spans(
     ])
 }
 
+/// What: Query context used to highlight why a visible row matched.
+pub struct RowQuery<'a> {
+    /// Normalized search text (non-empty).
+    pub text: &'a str,
+    /// Whether fuzzy search is enabled (otherwise substring matching applies).
+    pub fuzzy: bool,
+    /// Matcher shared by all visible rows.
+    pub matcher: &'a fuzzy_matcher::skim::SkimMatcherV2,
+}
+
+/// What: Find the description characters that explain a description-only match.
+///
+/// Inputs:
+/// - `name`: Package name
+/// - `description`: Package description as displayed
+/// - `query`: Active search query
+///
+/// Output:
+/// - Ascending char positions in `description`; empty when the name itself matches or the
+///   description does not.
+///
+/// Details:
+/// - Fuzzy mode reports the fuzzy run; otherwise the first case-insensitive occurrence of the
+///   whole query is reported.
+#[must_use]
+pub fn description_match_positions(
+    name: &str,
+    description: &str,
+    query: &RowQuery<'_>,
+) -> Vec<usize> {
+    if query.fuzzy {
+        if crate::util::fuzzy_match_rank_with_matcher(name, query.text, query.matcher).is_some() {
+            return Vec::new();
+        }
+        return crate::util::fuzzy_match_rank_indices(description, query.text, query.matcher)
+            .map(|(_, indices)| indices)
+            .unwrap_or_default();
+    }
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = query.text.trim().chars().map(lower).collect();
+    let name_chars: Vec<char> = name.chars().map(lower).collect();
+    if needle.is_empty()
+        || name_chars
+            .windows(needle.len())
+            .any(|w| w == needle.as_slice())
+    {
+        return Vec::new();
+    }
+    let hay: Vec<char> = description.chars().map(lower).collect();
+    hay.windows(needle.len())
+        .position(|w| w == needle.as_slice())
+        .map(|start| (start..start + needle.len()).collect())
+        .unwrap_or_default()
+}
+
+/// What: Split text (a package name or description) into spans, highlighting matched characters.
+///
+/// Inputs:
+/// - `name`: Text to split
+/// - `matched`: Ascending char positions in `name` that matched the query
+/// - `base`: Style for unmatched characters
+/// - `highlight`: Style for matched characters
+///
+/// Output:
+/// - Spans covering `name`, one per run of matched or unmatched characters
+///
+/// Details:
+/// - Positions are chars, not bytes, so multi-byte names are never split mid-character.
+#[must_use]
+pub fn name_match_spans(
+    name: &str,
+    matched: &[usize],
+    base: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    if matched.is_empty() {
+        return vec![Span::styled(name.to_string(), base)];
+    }
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (idx, ch) in name.chars().enumerate() {
+        let is_match = matched.binary_search(&idx).is_ok();
+        if is_match != run_matched && !run.is_empty() {
+            let style = if run_matched { highlight } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = is_match;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(
+            run,
+            if run_matched { highlight } else { base },
+        ));
+    }
+    spans
+}
+
 /// What: Build a `ListItem` for a package in the results list.
 ///
 /// Inputs:
@@ -238,20 +340,27 @@ fn aur_vote_state_spans(
 /// - `theme`: Theme for styling
 /// - `prefs`: Theme preferences including package marker type
 /// - `in_viewport`: Whether this item is in the visible viewport
+/// - `row_number`: Optional `(1-based number, column width)` prefix when `results_show_numbers` is on
+/// - `query`: Active search query, used to highlight matched characters (`None` when blank)
 ///
 /// Output:
 /// - `ListItem` ready for rendering
 ///
 /// Details:
 /// - Returns empty item if not in viewport for performance.
-/// - Builds spans for popularity, source label, name, version, description, and installed status.
+/// - Builds spans for row number, popularity, source label, name, version, installed size
+///   (installed-only mode), description, and installed status.
 /// - Applies package markers if package is in install/remove/downgrade lists.
+/// - Fuzzy-matched name characters are drawn in the accent color and underlined; when only the
+///   description matched, the matched description characters are highlighted the same way.
 pub fn build_list_item(
     package: &PackageItem,
     app: &AppState,
     theme: &Theme,
     prefs: &crate::theme::Settings,
     in_viewport: bool,
+    row_number: Option<(usize, usize)>,
+    query: Option<&RowQuery<'_>>,
 ) -> ListItem<'static> {
     // For rows outside the viewport, render a cheap empty item
     if !in_viewport {
@@ -259,6 +368,7 @@ pub fn build_list_item(
     }
 
     let (src, color) = determine_source_label_and_color(&package.source, &package.name, app, theme);
+    let src = crate::logic::distro::styled_repo_label(&src, &prefs.results_repo_label_style);
 
     let desc = if package.description.is_empty() {
         app.details_cache
@@ -273,6 +383,12 @@ pub fn build_list_item(
 
     // Build the main content spans
     let mut segs: Vec<Span<'static>> = Vec::new();
+    if let Some((number, width)) = row_number {
+        segs.push(Span::styled(
+            format!("{number:>width$} "),
+            Style::default().fg(theme.overlay1),
+        ));
+    }
     if let Some(pop) = package.popularity {
         segs.push(Span::styled(
             format!("Pop: {pop:.2} "),
@@ -295,17 +411,47 @@ pub fn build_list_item(
             ));
         }
     }
-    segs.push(Span::styled(
-        package.name.clone(),
-        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+    let name_matches = query
+        .filter(|q| q.fuzzy)
+        .and_then(|q| crate::util::fuzzy_match_rank_indices(&package.name, q.text, q.matcher))
+        .map(|(_, indices)| indices)
+        .unwrap_or_default();
+    let name_style = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
+    segs.extend(name_match_spans(
+        &package.name,
+        &name_matches,
+        name_style,
+        name_style
+            .fg(theme.yellow)
+            .add_modifier(Modifier::UNDERLINED),
     ));
     segs.push(Span::styled(
         format!("  {}", package.version),
         Style::default().fg(theme.overlay1),
     ));
+    if app.installed_only_mode
+        && prefs.installed_only_show_size
+        && let Some(bytes) = crate::index::installed_size(&package.name)
+    {
+        segs.push(Span::styled(
+            format!("  {}", crate::ui::helpers::format_bytes(bytes)),
+            Style::default().fg(theme.sapphire),
+        ));
+    }
     if !desc.is_empty() {
+        let desc_matches = query
+            .map(|q| description_match_positions(&package.name, &desc, q))
+            .unwrap_or_default();
+        let desc_style = Style::default().fg(theme.overlay2);
         segs.push(Span::raw("  - "));
-        segs.push(Span::styled(desc, Style::default().fg(theme.overlay2)));
+        segs.extend(name_match_spans(
+            &desc,
+            &desc_matches,
+            desc_style,
+            desc_style
+                .fg(theme.yellow)
+                .add_modifier(Modifier::UNDERLINED),
+        ));
     }
     if installed {
         segs.push(Span::raw("  "));
@@ -323,16 +469,19 @@ pub fn build_list_item(
     // Check if package is in any lists and apply markers if needed
     let list_status = check_package_in_lists(package, app);
     if list_status.in_install || list_status.in_remove || list_status.in_downgrade {
-        let (label, marker_color) = if list_status.in_remove {
-            ("[-]", theme.red)
+        let (kind, marker_color) = if list_status.in_remove {
+            (MarkerKind::Remove, theme.red)
         } else if list_status.in_downgrade {
-            ("[↓]", theme.yellow)
+            (MarkerKind::Downgrade, theme.yellow)
         } else {
-            ("[+]", theme.green)
+            (MarkerKind::Install, theme.green)
         };
+        let label = prefs
+            .package_marker
+            .label(kind, crate::theme::supports_unicode_glyphs());
         build_package_marker_item(
             segs,
-            prefs.package_marker,
+            &prefs.package_marker,
             label,
             marker_color,
             list_status.in_install,
@@ -382,6 +531,114 @@ mod tests {
         assert!(!status.in_downgrade);
     }
 
+    #[test]
+    /// What: Verify every `PackageMarker` variant yields its expected label per queue.
+    ///
+    /// Inputs:
+    /// - Each variant, for install/remove/downgrade, with and without Unicode support.
+    ///
+    /// Output:
+    /// - Built-in, Nerd Font, and custom labels; non-ASCII labels fall back to ASCII.
+    fn test_package_marker_labels_per_variant() {
+        let custom = PackageMarker::Custom {
+            install: "ADD".to_string(),
+            remove: "✗".to_string(),
+            downgrade: "OLD".to_string(),
+        };
+        let cases: [(PackageMarker, [&str; 3], [&str; 3]); 6] = [
+            (
+                PackageMarker::FullLine,
+                ["[+]", "[-]", "[↓]"],
+                ["[+]", "[-]", "[v]"],
+            ),
+            (
+                PackageMarker::Front,
+                ["[+]", "[-]", "[↓]"],
+                ["[+]", "[-]", "[v]"],
+            ),
+            (
+                PackageMarker::End,
+                ["[+]", "[-]", "[↓]"],
+                ["[+]", "[-]", "[v]"],
+            ),
+            (
+                PackageMarker::Ascii,
+                ["[+]", "[-]", "[v]"],
+                ["[+]", "[-]", "[v]"],
+            ),
+            (
+                PackageMarker::NerdFont,
+                ["\u{f067}", "\u{f068}", "\u{f063}"],
+                ["[+]", "[-]", "[v]"],
+            ),
+            (custom, ["ADD", "✗", "OLD"], ["ADD", "[-]", "OLD"]),
+        ];
+        let kinds = [
+            MarkerKind::Install,
+            MarkerKind::Remove,
+            MarkerKind::Downgrade,
+        ];
+        for (marker, unicode_labels, ascii_labels) in &cases {
+            for (i, kind) in kinds.into_iter().enumerate() {
+                assert_eq!(
+                    marker.label(kind, true),
+                    unicode_labels[i],
+                    "{marker:?} {kind:?}"
+                );
+                assert_eq!(
+                    marker.label(kind, false),
+                    ascii_labels[i],
+                    "{marker:?} {kind:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    /// What: Verify `build_list_item` places the configured marker label on queued rows.
+    ///
+    /// Inputs:
+    /// - A package in the remove list, rendered with a custom ASCII marker and with `End`.
+    ///
+    /// Output:
+    /// - The custom label leads the row; the `End` label follows the package text.
+    fn test_build_list_item_uses_marker_label() {
+        use ratatui::widgets::Widget;
+
+        let render = |item: ListItem<'static>| {
+            let area = ratatui::layout::Rect::new(0, 0, 100, 1);
+            let mut buf = ratatui::buffer::Buffer::empty(area);
+            ratatui::widgets::List::new(vec![item]).render(area, &mut buf);
+            buf.content
+                .iter()
+                .map(ratatui::buffer::Cell::symbol)
+                .collect::<String>()
+        };
+        let package = PackageItem::test_item("test-pkg", "aur");
+        let mut app = crate::state::AppState::default();
+        app.remove_list.push(package.clone());
+        let theme = crate::theme::theme();
+
+        let mut prefs = crate::theme::Settings {
+            package_marker: PackageMarker::Custom {
+                install: "ADD".to_string(),
+                remove: "DEL".to_string(),
+                downgrade: "OLD".to_string(),
+            },
+            ..crate::theme::Settings::default()
+        };
+        let row = render(build_list_item(
+            &package, &app, &theme, &prefs, true, None, None,
+        ));
+        assert!(row.starts_with("DEL "), "row: {row:?}");
+
+        prefs.package_marker = PackageMarker::End;
+        let row = render(build_list_item(
+            &package, &app, &theme, &prefs, true, None, None,
+        ));
+        assert!(row.trim_end().ends_with("[-]"), "row: {row:?}");
+    }
+
     #[test]
     fn test_determine_source_label_and_color_aur() {
         let app = crate::state::AppState::default();
@@ -438,7 +695,7 @@ mod tests {
             orphaned: false,
         };
 
-        let item = build_list_item(&package, &app, &theme, &prefs, false);
+        let item = build_list_item(&package, &app, &theme, &prefs, false, None, None);
         // Verify that the item is created (not in viewport returns empty item)
         let _ = item;
     }
@@ -458,8 +715,37 @@ mod tests {
             orphaned: false,
         };
 
-        let item = build_list_item(&package, &app, &theme, &prefs, true);
+        let item = build_list_item(&package, &app, &theme, &prefs, true, None, None);
         // Verify that the item is created (in viewport returns populated item)
         let _ = item;
     }
+
+    #[test]
+    /// What: Verify fuzzy-matched characters of a multi-byte name get their own spans.
+    ///
+    /// Inputs:
+    /// - Name `größe-tool` with matched char positions 3 (`ß`) and 6 (`t`).
+    ///
+    /// Output:
+    /// - Five spans alternating base/highlight styles that rejoin to the original name.
+    fn name_match_spans_split_by_chars() {
+        let base = Style::default();
+        let highlight = Style::default().add_modifier(Modifier::UNDERLINED);
+        let spans = name_match_spans("größe-tool", &[3, 6], base, highlight);
+        let parts: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style == highlight))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("grö", false),
+                ("ß", true),
+                ("e-", false),
+                ("t", true),
+                ("ool", false)
+            ]
+        );
+        assert_eq!(name_match_spans("ripgrep", &[], base, highlight).len(), 1);
+    }
 }
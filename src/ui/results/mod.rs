@@ -19,6 +19,8 @@ mod news;
 mod sort_menu;
 /// Status bar rendering module.
 mod status;
+/// "Did you mean" suggestion line rendering module.
+mod suggestions;
 /// Title bar rendering module.
 mod title;
 pub use title::{
@@ -130,6 +132,10 @@ pub struct FilterStates {
     pub show_blackarch: bool,
     /// Whether to show `Manjaro` repository packages.
     pub show_manjaro: bool,
+    /// Whether only out-of-date packages are shown.
+    pub only_outofdate: bool,
+    /// Whether only orphaned packages are shown.
+    pub only_orphaned: bool,
 }
 
 /// What: Render the top results list and title controls.
@@ -165,6 +171,7 @@ pub fn render_results(f: &mut Frame, app: &mut AppState, area: Rect) {
 
     // Render list widget
     render_list_widget(f, app, area, &title_spans);
+    suggestions::render_suggestions(f, app, area);
 
     // Render status and sort menu, record rects (all mutate app)
     status::render_status(f, app, area);
@@ -194,12 +201,26 @@ fn render_list_widget(
     title_spans: &[ratatui::text::Span<'static>],
 ) {
     let th = theme();
+    // The title spans are already mirrored for right-to-left locales; right-align them too
+    let title_rtl = crate::i18n::is_rtl(&app.locale);
     let list_offset = app.list_state.offset();
     let viewport_rows = area.height.saturating_sub(2) as usize;
     let start = list_offset;
     let end = std::cmp::min(app.results.len(), start + viewport_rows);
     // Settings are cached; avoid per-frame reloads by fetching once and cloning.
     let prefs = crate::theme::settings();
+    // Right-align row numbers to the widest index so names stay in one column
+    let number_width = prefs
+        .results_show_numbers
+        .then(|| app.results.len().to_string().len());
+    // Highlight matched name/description characters; one matcher serves all visible rows
+    let query_text = crate::logic::query::normalize_query(&app.input, &prefs.search_normalize);
+    let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+    let row_query = (!query_text.trim().is_empty()).then(|| list::RowQuery {
+        text: &query_text,
+        fuzzy: app.fuzzy_search_enabled,
+        matcher: &matcher,
+    });
 
     let items: Vec<ListItem> = app
         .results
@@ -207,7 +228,16 @@ fn render_list_widget(
         .enumerate()
         .map(|(i, p)| {
             let in_viewport = i >= start && i < end;
-            list::build_list_item(p, app, &th, &prefs, in_viewport)
+            let row_number = number_width.map(|width| (i + 1, width));
+            list::build_list_item(
+                p,
+                app,
+                &th,
+                &prefs,
+                in_viewport,
+                row_number,
+                row_query.as_ref(),
+            )
         })
         .collect();
 
@@ -216,6 +246,7 @@ fn render_list_widget(
         .block(
             Block::default()
                 .title(Line::from(title_spans.to_vec()))
+                .title_alignment(crate::ui::helpers::label_alignment(title_rtl))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(th.surface2)),
@@ -350,6 +381,50 @@ mod tests {
         assert!(app.results_rect.is_some());
     }
 
+    /// What: Ensure a right-to-left locale mirrors the results title and its click rects.
+    ///
+    /// Inputs:
+    /// - Same results pane rendered with `en-US` and then `ar-SA`.
+    ///
+    /// Output:
+    /// - The RTL Sort and AUR rects are the LTR rects mirrored across the title row, and the
+    ///   Sort label is drawn at the mirrored rect.
+    ///
+    /// Details:
+    /// - The title row spans the block width minus the two border cells.
+    #[test]
+    fn results_title_mirrors_rects_for_rtl_locale() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let render = |locale: &str| {
+            let mut term =
+                Terminal::new(TestBackend::new(120, 20)).expect("failed to create test terminal");
+            let mut app = crate::state::AppState::default();
+            init_test_translations(&mut app);
+            for (key, label) in [("outofdate", "Out-of-date"), ("orphaned", "Orphaned")] {
+                app.translations
+                    .insert(format!("app.results.filters.{key}"), label.to_string());
+            }
+            app.locale = locale.to_string();
+            term.draw(|f| render_results(f, &mut app, f.area()))
+                .expect("failed to draw test terminal");
+            (app, term.backend().buffer().clone())
+        };
+        let (ltr, _) = render("en-US");
+        let (rtl, buffer) = render("ar-SA");
+
+        let ltr_sort = ltr.sort_button_rect.expect("LTR sort rect");
+        let rtl_sort = rtl.sort_button_rect.expect("RTL sort rect");
+        assert_eq!(rtl_sort, crate::ui::helpers::mirror_rect(ltr_sort, 1, 118));
+        assert!(rtl_sort.0 > 60, "sort button should sit on the right half");
+        assert_eq!(buffer[(rtl_sort.0, rtl_sort.1)].symbol(), "S");
+
+        let ltr_aur = ltr.results_filter_aur_rect.expect("LTR AUR rect");
+        let rtl_aur = rtl.results_filter_aur_rect.expect("RTL AUR rect");
+        assert_eq!(rtl_aur, crate::ui::helpers::mirror_rect(ltr_aur, 1, 118));
+        assert!(rtl_aur.0 < rtl_sort.0, "filters follow Sort leftwards");
+    }
+
     /// What: Ensure config editor mode records separate updates and news top-bar hit targets.
     ///
     /// Inputs:
@@ -399,6 +474,55 @@ mod tests {
         );
     }
 
+    /// What: Ensure a description-only query highlights the matched run inside the description.
+    ///
+    /// Inputs:
+    /// - One result `rg` described as "A fast search tool"; substring query `search`.
+    ///
+    /// Output:
+    /// - Exactly the six `search` cells are drawn in the accent color and underlined; the name
+    ///   and the rest of the description are not.
+    #[test]
+    fn description_only_query_highlights_description_cells() {
+        use ratatui::{Terminal, backend::TestBackend};
+        let backend = TestBackend::new(80, 6);
+        let mut term = Terminal::new(backend).expect("failed to create test terminal");
+        let mut app = crate::state::AppState::default();
+        init_test_translations(&mut app);
+        app.fuzzy_search_enabled = false;
+        app.input = "search".into();
+        app.results = vec![crate::state::PackageItem {
+            description: "A fast search tool".into(),
+            ..crate::state::PackageItem::test_item("rg", "aur")
+        }];
+
+        term.draw(|f| render_list_widget(f, &mut app, f.area(), &[]))
+            .expect("failed to draw test terminal");
+
+        let th = theme();
+        let buffer = term.backend().buffer();
+        // Match per cell: symbols can be multi-byte, so byte offsets are not columns.
+        let cells: Vec<&str> = (0..80u16).map(|x| buffer[(x, 1)].symbol()).collect();
+        let desc_col = cells
+            .windows("A fast".len())
+            .position(|w| w.concat() == "A fast")
+            .expect("description rendered");
+        let highlighted: Vec<usize> = (0..80u16)
+            .filter(|&x| {
+                let cell = &buffer[(x, 1)];
+                cell.fg == th.yellow && cell.modifier.contains(ratatui::style::Modifier::UNDERLINED)
+            })
+            .map(usize::from)
+            .collect();
+        let start = desc_col + "A fast ".len();
+        assert_eq!(
+            highlighted,
+            (start..start + "search".len()).collect::<Vec<_>>()
+        );
+        let marked: String = highlighted.iter().map(|&x| cells[x]).collect();
+        assert_eq!(marked, "search");
+    }
+
     /// What: Initialize minimal English translations for tests.
     ///
     /// Inputs:
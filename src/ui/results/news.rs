@@ -13,7 +13,7 @@ use unicode_width::UnicodeWidthStr;
 /// What: Build list items for news feed results.
 ///
 /// Inputs:
-/// - `app`: Application state for i18n translations
+/// - `app`: Application state for i18n translations and affected-advisory flags
 /// - `news_loading`: Whether news is currently loading
 /// - `news_results`: Reference to news results
 /// - `news_read_ids`: Set of read news IDs
@@ -62,7 +62,16 @@ pub fn build_news_list_items<'a>(
         (
             news_results
                 .iter()
-                .map(|item| build_news_list_item(item, news_read_ids, news_read_urls, &th, &prefs))
+                .map(|item| {
+                    build_news_list_item(
+                        item,
+                        app.news_advisory_affected.contains(&item.id),
+                        news_read_ids,
+                        news_read_urls,
+                        &th,
+                        &prefs,
+                    )
+                })
                 .collect(),
             false, // doesn't need to select None
         )
@@ -73,7 +82,9 @@ pub fn build_news_list_items<'a>(
 ///
 /// Inputs:
 /// - `item`: News feed item to render
-/// - `app`: Application state for read status
+/// - `affected`: Whether the advisory potentially affects an installed package version
+/// - `news_read_ids`: Set of read news IDs
+/// - `news_read_urls`: Set of read news URLs
 /// - `th`: Theme for colors
 /// - `prefs`: Theme preferences for symbols
 ///
@@ -85,6 +96,7 @@ pub fn build_news_list_items<'a>(
 /// - Applies keyword highlighting to titles for Arch News items
 fn build_news_list_item(
     item: &crate::state::types::NewsFeedItem,
+    affected: bool,
     news_read_ids: &std::collections::HashSet<String>,
     news_read_urls: &std::collections::HashSet<String>,
     th: &crate::theme::Theme,
@@ -147,6 +159,13 @@ fn build_news_list_item(
             Style::default().fg(th.yellow),
         ));
     }
+    if affected {
+        spans.push(ratatui::text::Span::raw(" "));
+        spans.push(ratatui::text::Span::styled(
+            "[Installed]",
+            Style::default().fg(th.red).add_modifier(Modifier::BOLD),
+        ));
+    }
     if let Some(summary) = item.summary.as_ref() {
         spans.push(ratatui::text::Span::raw(" – "));
         spans.extend(render_summary_spans(summary, th, item.source));
@@ -58,7 +58,7 @@ pub fn render_sort_menu(f: &mut Frame, app: &mut AppState, area: Rect, btn_x: u1
                 ),
             ]
         } else {
-            vec![
+            let mut opts = vec![
                 (
                     i18n::t(app, "app.results.sort_menu.options.alphabetical"),
                     matches!(app.sort_mode, SortMode::RepoThenName),
@@ -71,7 +71,14 @@ pub fn render_sort_menu(f: &mut Frame, app: &mut AppState, area: Rect, btn_x: u1
                     i18n::t(app, "app.results.sort_menu.options.best_matches"),
                     matches!(app.sort_mode, SortMode::BestMatches),
                 ),
-            ]
+            ];
+            if app.installed_only_mode {
+                opts.push((
+                    i18n::t(app, "app.results.sort_menu.options.installed_size"),
+                    matches!(app.sort_mode, SortMode::InstalledSize),
+                ));
+            }
+            opts
         };
         let widest = opts
             .iter()
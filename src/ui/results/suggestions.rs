@@ -0,0 +1,82 @@
+use ratatui::{
+    Frame,
+    prelude::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::i18n;
+use crate::state::AppState;
+use crate::theme::{KeyChord, theme};
+
+/// What: Draw the "did you mean" line on the last inner row of the Results block.
+///
+/// Inputs:
+/// - `f`: Frame to render into
+/// - `app`: Mutable application state (reads suggestions, updates click rects)
+/// - `area`: Target rectangle for the results block
+///
+/// Output:
+/// - Renders the suggestions and records one clickable rect per suggestion.
+///
+/// Details:
+/// - Skipped when there are no suggestions or the results would overlap the line.
+/// - The first suggestion carries the `search_accept_suggestion` key hint.
+pub fn render_suggestions(f: &mut Frame, app: &mut AppState, area: Rect) {
+    app.search_suggestion_rects.clear();
+    let inner_rows = usize::from(area.height.saturating_sub(2));
+    if app.search_suggestions.is_empty() || app.results.len() >= inner_rows {
+        return;
+    }
+    let th = theme();
+    let y = area.y.saturating_add(area.height.saturating_sub(2));
+    let x0 = area.x.saturating_add(2);
+    let max_x = area.x.saturating_add(area.width.saturating_sub(2));
+
+    let label = format!("{} ", i18n::t(app, "app.results.did_you_mean"));
+    let mut x = x0.saturating_add(u16::try_from(label.width()).unwrap_or(u16::MAX));
+    let mut spans = vec![Span::styled(label, Style::default().fg(th.overlay1))];
+    let key_hint = app
+        .keymap
+        .search_accept_suggestion
+        .first()
+        .map(KeyChord::label);
+    for (i, name) in app.search_suggestions.iter().enumerate() {
+        let w = u16::try_from(name.width()).unwrap_or(u16::MAX);
+        let x_name = if i > 0 { x.saturating_add(2) } else { x };
+        if x_name.saturating_add(w) > max_x {
+            break;
+        }
+        if i > 0 {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(
+            name.clone(),
+            Style::default()
+                .fg(th.sapphire)
+                .add_modifier(Modifier::UNDERLINED),
+        ));
+        app.search_suggestion_rects.push((x_name, y, w, 1));
+        x = x_name.saturating_add(w);
+        if i == 0
+            && let Some(hint) = &key_hint
+        {
+            let hint = format!(" [{hint}]");
+            x = x.saturating_add(u16::try_from(hint.width()).unwrap_or(u16::MAX));
+            spans.push(Span::styled(hint, Style::default().fg(th.overlay1)));
+        }
+    }
+
+    let rect = Rect {
+        x: x0,
+        y,
+        width: max_x.saturating_sub(x0),
+        height: 1,
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().bg(th.base)),
+        rect,
+    );
+}
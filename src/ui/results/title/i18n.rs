@@ -35,5 +35,7 @@ pub(super) fn build_title_i18n_strings(app: &AppState) -> TitleI18nStrings {
         filter_blackarch: i18n::t(app, "app.results.filters.blackarch"),
         filter_manjaro: i18n::t(app, "app.results.filters.manjaro"),
         filter_custom_repos: i18n::t(app, "app.results.filters.custom_repos"),
+        filter_outofdate: i18n::t(app, "app.results.filters.outofdate"),
+        filter_orphaned: i18n::t(app, "app.results.filters.orphaned"),
     }
 }
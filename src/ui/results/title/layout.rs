@@ -2,8 +2,8 @@ use unicode_width::UnicodeWidthStr;
 
 use super::super::OptionalRepos;
 use super::types::{
-    ArtixVisibilityContext, CoreFilterLabels, OptionalReposLabels, TitleI18nStrings,
-    TitleLayoutInfo,
+    ArtixVisibilityContext, CoreFilterLabels, OptionalReposLabels, StatusFilterLabels,
+    TitleI18nStrings, TitleLayoutInfo,
 };
 use super::width::{
     calculate_base_consumed_space, calculate_consumed_without_specific,
@@ -195,9 +195,17 @@ pub(super) fn calculate_title_layout_info(
     let custom_extra = custom_repos_chip_label.as_ref().map_or(0u16, |s| {
         1u16.saturating_add(u16::try_from(s.width()).unwrap_or(u16::MAX))
     });
+    let status_labels = StatusFilterLabels {
+        outofdate: format!("[{}]", i18n.filter_outofdate),
+        orphaned: format!("[{}]", i18n.filter_orphaned),
+    };
+    let status_extra =
+        u16::try_from(1 + status_labels.outofdate.width() + 1 + status_labels.orphaned.width())
+            .unwrap_or(u16::MAX);
     let consumed_left = base_consumed
         .saturating_add(optional_consumed)
-        .saturating_add(custom_extra);
+        .saturating_add(custom_extra)
+        .saturating_add(status_extra);
 
     // Use Unicode display width, not byte length, to handle wide characters
     let options_w = u16::try_from(options_button_label.width()).unwrap_or(u16::MAX);
@@ -281,5 +289,6 @@ pub(super) fn calculate_title_layout_info(
         optional_labels,
         show_artix_specific_repos,
         custom_repos_chip_label,
+        status_labels,
     }
 }
@@ -42,7 +42,8 @@ use rects::record_title_rects;
 use rendering::{
     render_artix_filter, render_artix_specific_filters, render_blackarch_filter,
     render_core_filters, render_custom_repos_dynamic_chip, render_manjaro_filter,
-    render_optional_eos_cachyos_filters, render_sort_button, render_title_prefix,
+    render_optional_eos_cachyos_filters, render_sort_button, render_status_filters,
+    render_title_prefix,
 };
 
 pub use top_bar_menu::{
@@ -119,6 +120,7 @@ pub fn build_title_spans_from_context(
 /// - Applies theme styling for active buttons and toggles optional repo chips based on availability flags.
 /// - Uses pre-computed i18n strings and focused rendering functions to reduce complexity.
 /// - Reuses layout calculation logic from `calculate_title_layout_info`.
+/// - Mirrors the segment order for right-to-left locales; the results block right-aligns it.
 fn build_title_spans_from_values(
     app: &AppState,
     results_len: usize,
@@ -174,7 +176,11 @@ fn build_title_spans_from_values(
         app,
         layout_info.custom_repos_chip_label.as_deref(),
     ));
+    title_spans.extend(render_status_filters(&i18n, filter_states));
 
+    if crate::i18n::is_rtl(&app.locale) {
+        return crate::ui::helpers::mirror_spans(title_spans);
+    }
     title_spans
 }
 
@@ -193,6 +193,7 @@ fn record_optional_repo_rects(
 /// - Mirrors title layout calculations to align rects with rendered elements and clears entries when
 ///   controls cannot fit in the available width.
 /// - Uses shared layout calculation logic and helper functions to reduce complexity.
+/// - Mirrors all rects for right-to-left locales to follow the mirrored title.
 pub(super) fn record_title_rects(app: &mut AppState, area: Rect, optional_repos: &OptionalRepos) {
     let inner_width = area.width.saturating_sub(2); // exclude borders
     let i18n = build_title_i18n_strings(app);
@@ -249,4 +250,53 @@ pub(super) fn record_title_rects(app: &mut AppState, area: Rect, optional_repos:
     } else {
         app.results_filter_custom_repos_rect = None;
     }
+
+    let status = &layout_info.status_labels;
+    app.results_filter_outofdate_rect = Some(layout.record_rect(&status.outofdate));
+    layout.advance(
+        u16::try_from(status.outofdate.width()).unwrap_or(u16::MAX),
+        1,
+    );
+    app.results_filter_orphaned_rect = Some(layout.record_rect(&status.orphaned));
+
+    if crate::i18n::is_rtl(&app.locale) {
+        mirror_title_rects(app, area.x.saturating_add(1), inner_width);
+    }
+}
+
+/// What: Mirror every recorded title control rect for a right-to-left title.
+///
+/// Inputs:
+/// - `app`: Mutable application state holding the left-to-right rects
+/// - `row_x`: Left edge of the title row (inside the border)
+/// - `row_width`: Width of the title row
+///
+/// Output: Rewrites the sort button and filter rects in place.
+///
+/// Details: Matches the right-aligned, segment-reversed title from `mirror_spans`.
+fn mirror_title_rects(app: &mut AppState, row_x: u16, row_width: u16) {
+    let rects = [
+        &mut app.sort_button_rect,
+        &mut app.results_filter_aur_rect,
+        &mut app.results_filter_core_rect,
+        &mut app.results_filter_extra_rect,
+        &mut app.results_filter_multilib_rect,
+        &mut app.results_filter_eos_rect,
+        &mut app.results_filter_cachyos_rect,
+        &mut app.results_filter_artix_rect,
+        &mut app.results_filter_artix_omniverse_rect,
+        &mut app.results_filter_artix_universe_rect,
+        &mut app.results_filter_artix_lib32_rect,
+        &mut app.results_filter_artix_galaxy_rect,
+        &mut app.results_filter_artix_world_rect,
+        &mut app.results_filter_artix_system_rect,
+        &mut app.results_filter_blackarch_rect,
+        &mut app.results_filter_manjaro_rect,
+        &mut app.results_filter_custom_repos_rect,
+        &mut app.results_filter_outofdate_rect,
+        &mut app.results_filter_orphaned_rect,
+    ];
+    for rect in rects.into_iter().flatten() {
+        *rect = crate::ui::helpers::mirror_rect(*rect, row_x, row_width);
+    }
 }
@@ -356,6 +356,28 @@ pub(super) fn render_manjaro_filter(
     spans
 }
 
+/// What: Render the out-of-date/orphaned status filter toggles.
+///
+/// Inputs:
+/// - `i18n`: Pre-computed i18n strings
+/// - `filter_states`: Filter toggle states
+///
+/// Output: Vector of spans for both status toggles.
+///
+/// Details: Always shown; active styling means the list is narrowed to matching packages.
+pub(super) fn render_status_filters(
+    i18n: &TitleI18nStrings,
+    filter_states: &FilterStates,
+) -> Vec<Span<'static>> {
+    let filt = create_filter_renderer();
+    vec![
+        Span::raw(" "),
+        filt(&i18n.filter_outofdate, filter_states.only_outofdate),
+        Span::raw(" "),
+        filt(&i18n.filter_orphaned, filter_states.only_orphaned),
+    ]
+}
+
 /// What: Render the `[Custom] v` chip when `repos.conf` defines dynamic filter ids.
 ///
 /// Inputs:
@@ -50,6 +50,10 @@ pub(super) struct TitleI18nStrings {
     pub(super) filter_manjaro: String,
     /// Short label for custom `repos.conf` results filters (chip text without brackets).
     pub(super) filter_custom_repos: String,
+    /// Translated out-of-date-only status filter text.
+    pub(super) filter_outofdate: String,
+    /// Translated orphaned-only status filter text.
+    pub(super) filter_orphaned: String,
 }
 
 /// What: Represents pre-formatted label strings for optional repos.
@@ -102,6 +106,20 @@ pub(super) struct CoreFilterLabels {
     pub(super) multilib: String,
 }
 
+/// What: Represents labels for the package status filters.
+///
+/// Inputs: Individual label strings.
+///
+/// Output: Struct containing status filter labels.
+///
+/// Details: Status filters are always shown after the repo chips.
+pub(super) struct StatusFilterLabels {
+    /// Out-of-date-only filter label.
+    pub(super) outofdate: String,
+    /// Orphaned-only filter label.
+    pub(super) orphaned: String,
+}
+
 /// What: Shared layout calculation information for title bar.
 ///
 /// Inputs: Calculated values from title text, button labels, and area dimensions.
@@ -122,6 +140,8 @@ pub(super) struct TitleLayoutInfo {
     pub(super) show_artix_specific_repos: bool,
     /// When set, title bar shows a `[Custom] v` chip for `repos.conf` filter ids.
     pub(super) custom_repos_chip_label: Option<String>,
+    /// Out-of-date/orphaned status filter labels.
+    pub(super) status_labels: StatusFilterLabels,
 }
 
 /// What: Layout state tracker for recording rectangles.
@@ -209,6 +209,8 @@ pub fn extract_render_context(app: &AppState) -> RenderContext {
             show_artix_system: app.results_filter_show_artix_system,
             show_blackarch: app.results_filter_show_blackarch,
             show_manjaro: app.results_filter_show_manjaro,
+            only_outofdate: app.results_filter_only_outofdate,
+            only_orphaned: app.results_filter_only_orphaned,
         },
     }
 }
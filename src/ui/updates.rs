@@ -44,9 +44,9 @@ fn package_updates_top_bar_label(app: &AppState) -> String {
     } else if let Some(count) = app.updates_count {
         if count > 0 {
             if app.updates_last_check_authoritative == Some(false) {
-                i18n::t_fmt1(app, "app.updates_button.available_degraded", count)
+                i18n::tn(app, "app.updates_button.available_degraded", count)
             } else {
-                i18n::t_fmt1(app, "app.updates_button.available", count)
+                i18n::tn(app, "app.updates_button.available", count)
             }
         } else if app.updates_last_check_authoritative == Some(false) {
             i18n::t(app, "app.updates_button.none_maybe_stale")
@@ -7,7 +7,7 @@
 //! - Uses absolute paths for curl binary when available (defense-in-depth against PATH hijacking)
 //! - Redacts URL query parameters in debug logs to prevent potential secret leakage
 
-use super::curl_args;
+use super::{NetworkSource, curl_args, curl_args_for};
 use chrono;
 use serde_json::Value;
 use std::sync::OnceLock;
@@ -288,7 +288,41 @@ fn map_curl_error(code: Option<i32>, status: std::process::ExitStatus) -> String
 /// - Provides user-friendly error messages for common curl failure cases.
 /// - For HTTP errors, includes the actual status code in the error message when available.
 pub fn curl_json(url: &str) -> Result<Value> {
-    let mut args = curl_args(url, &[]);
+    run_curl_json(curl_args(url, &[]))
+}
+
+/// What: Fetch JSON from a URL using the curl timeouts configured for a network source.
+///
+/// Inputs:
+/// - `source`: Fetch category whose `network_timeout_*` override applies
+/// - `url`: HTTP(S) URL to request
+///
+/// Output:
+/// - `Ok(Value)` on success; `Err` if curl fails or the response is not valid JSON
+///
+/// # Errors
+/// - Same failure modes as [`curl_json`]
+///
+/// Details:
+/// - Identical to [`curl_json`] except for `--connect-timeout`/`--max-time`.
+pub fn curl_json_for(source: NetworkSource, url: &str) -> Result<Value> {
+    run_curl_json(curl_args_for(source, url, &[]))
+}
+
+/// What: Execute curl with prepared arguments and parse the body as JSON.
+///
+/// Inputs:
+/// - `args`: Base curl arguments from [`curl_args`] or [`curl_args_for`]
+///
+/// Output:
+/// - `Ok(Value)` on success; `Err` if curl fails or the response is not valid JSON
+///
+/// Details:
+/// - Appends the `-w` status-code marker and strips it again before parsing.
+fn run_curl_json(mut args: Vec<String>) -> Result<Value> {
+    // URL is the last base argument; only needed for Windows diagnostics
+    #[cfg(target_os = "windows")]
+    let url = args.last().cloned().unwrap_or_default();
     // Add write-out format to capture HTTP status code on failure
     // The %{http_code} is curl's write-out format, not a Rust format string
     #[allow(clippy::literal_string_with_formatting_args)]
@@ -299,7 +333,7 @@ pub fn curl_json(url: &str) -> Result<Value> {
     #[cfg(target_os = "windows")]
     {
         // On Windows, log curl command for debugging (URL redacted for security)
-        let safe_url = redact_url_for_logging(url);
+        let safe_url = redact_url_for_logging(&url);
         tracing::debug!(
             curl_bin = %curl_bin,
             url = %safe_url,
@@ -324,7 +358,7 @@ pub fn curl_json(url: &str) -> Result<Value> {
 
         #[cfg(target_os = "windows")]
         {
-            let safe_url = redact_url_for_logging(url);
+            let safe_url = redact_url_for_logging(&url);
             // On Windows, also log stderr for debugging
             if !stderr.is_empty() {
                 tracing::warn!(stderr = %stderr, url = %safe_url, "curl stderr output on Windows");
@@ -346,7 +380,7 @@ pub fn curl_json(url: &str) -> Result<Value> {
     #[cfg(target_os = "windows")]
     {
         // On Windows, log response details for debugging API issues (URL redacted)
-        let safe_url = redact_url_for_logging(url);
+        let safe_url = redact_url_for_logging(&url);
         if body.len() < 500 {
             tracing::debug!(
                 url = %safe_url,
@@ -386,6 +420,24 @@ pub fn curl_text(url: &str) -> Result<String> {
     curl_text_with_args(url, &[])
 }
 
+/// What: Fetch plain text from a URL using the curl timeouts configured for a network source.
+///
+/// Inputs:
+/// - `source`: Fetch category whose `network_timeout_*` override applies
+/// - `url`: URL to request
+///
+/// Output:
+/// - `Ok(String)` with response body; `Err` if curl or UTF-8 decoding fails
+///
+/// # Errors
+/// - Same failure modes as [`curl_text_with_args`]
+///
+/// Details:
+/// - Identical to [`curl_text`] except for `--connect-timeout`/`--max-time`.
+pub fn curl_text_for(source: NetworkSource, url: &str) -> Result<String> {
+    run_curl_text(curl_args_for(source, url, &[]))
+}
+
 /// What: Parse Retry-After header value into seconds.
 ///
 /// Inputs:
@@ -611,7 +663,20 @@ pub fn curl_text_with_args_headers(url: &str, extra_args: &[&str]) -> Result<Cur
 /// - Provides user-friendly error messages for common curl failure cases.
 /// - HTTP 429/503 errors include Retry-After information when available.
 pub fn curl_text_with_args(url: &str, extra_args: &[&str]) -> Result<String> {
-    let mut args = curl_args(url, extra_args);
+    run_curl_text(curl_args(url, extra_args))
+}
+
+/// What: Execute curl with prepared arguments and return the response body.
+///
+/// Inputs:
+/// - `args`: Base curl arguments from [`curl_args`] or [`curl_args_for`]
+///
+/// Output:
+/// - `Ok(String)` with response body; `Err` on execution, HTTP, or UTF-8 failures
+///
+/// Details:
+/// - Adds `-i` and the `-w` status-code marker, then maps 429/503 and other errors.
+fn run_curl_text(mut args: Vec<String>) -> Result<String> {
     // Include headers in output (-i flag) for Retry-After parsing
     args.push("-i".to_string());
     // Append write-out format to get HTTP status code at the end
@@ -8,6 +8,7 @@ pub mod clipboard;
 pub mod command;
 pub mod config;
 pub mod curl;
+pub mod notify;
 pub mod pacman;
 pub mod srcinfo;
 
@@ -320,6 +321,36 @@ pub fn fuzzy_match_rank_with_matcher(
     matcher.fuzzy_match(name, query)
 }
 
+/// Rank a fuzzy match and report which characters of the name matched.
+///
+/// Inputs:
+/// - `name`: Package name to match against
+/// - `query`: Query string to match
+/// - `matcher`: Reference to a `SkimMatcherV2` instance to reuse across multiple calls
+///
+/// Output:
+/// - `Some((score, indices))` on a match, `None` otherwise (including blank queries)
+///
+/// Details:
+/// - Scores equal those of [`fuzzy_match_rank_with_matcher`].
+/// - `indices` are ascending positions in `name.chars()`, not byte offsets, so multi-byte
+///   names can be highlighted without splitting a character.
+#[must_use]
+pub fn fuzzy_match_rank_indices(
+    name: &str,
+    query: &str,
+    matcher: &fuzzy_matcher::skim::SkimMatcherV2,
+) -> Option<(i64, Vec<usize>)> {
+    use fuzzy_matcher::FuzzyMatcher;
+
+    if query.trim().is_empty() {
+        return None;
+    }
+
+    // SkimMatcherV2 walks `choice.chars()`, so its indices are already char positions
+    matcher.fuzzy_indices(name, query)
+}
+
 /// Rank how well a package name matches a query using fuzzy matching (fzf-style).
 ///
 /// Inputs:
@@ -378,21 +409,51 @@ pub fn fuzzy_match_rank(name: &str, query: &str) -> Option<i64> {
 ///
 /// Details:
 /// - Used to sort results such that official repositories precede AUR, and core repos precede others.
-/// - Order: `core` => 0, `extra` => 1, other official repos => 2, AUR => 3.
-/// - Case-insensitive comparison for repository names.
+/// - Reads the `search_repo_priority` setting; see [`repo_order_with`] for the weights.
+/// - Sorting many items should resolve the setting once and call [`repo_order_with`] instead.
 #[must_use]
 pub fn repo_order(src: &Source) -> u8 {
-    match src {
-        Source::Official { repo, .. } => {
-            if repo.eq_ignore_ascii_case("core") {
-                0
-            } else if repo.eq_ignore_ascii_case("extra") {
-                1
-            } else {
-                2
+    repo_order_with(src, &crate::theme::settings().repo_priority)
+}
+
+/// What: Determine ordering weight for a package source using an explicit repo priority list.
+///
+/// Inputs:
+/// - `src`: Package source to rank.
+/// - `priority`: Ordered repository names (highest priority first); empty for the default.
+///
+/// Output:
+/// - Returns a `u8` weight where lower values indicate higher priority.
+///
+/// Details:
+/// - Empty list: `core` => 0, `extra` => 1, other official repos => 2, AUR => 3.
+/// - Otherwise a listed repo gets its list position, unlisted official repos rank right after
+///   the list, and AUR comes last.
+/// - Case-insensitive comparison for repository names.
+#[must_use]
+pub fn repo_order_with(src: &Source, priority: &[String]) -> u8 {
+    if priority.is_empty() {
+        return match src {
+            Source::Official { repo, .. } => {
+                if repo.eq_ignore_ascii_case("core") {
+                    0
+                } else if repo.eq_ignore_ascii_case("extra") {
+                    1
+                } else {
+                    2
+                }
             }
-        }
-        Source::Aur => 3,
+            Source::Aur => 3,
+        };
+    }
+    let unlisted = u8::try_from(priority.len()).unwrap_or(u8::MAX - 1);
+    match src {
+        Source::Official { repo, .. } => priority
+            .iter()
+            .position(|p| p.eq_ignore_ascii_case(repo))
+            .and_then(|i| u8::try_from(i).ok())
+            .map_or(unlisted, |i| i.min(unlisted)),
+        Source::Aur => unlisted.saturating_add(1),
     }
 }
 /// What: Rank how well a package name matches a query (lower is better).
@@ -402,26 +463,33 @@ pub fn repo_order(src: &Source) -> u8 {
 /// - `query_lower`: Query string (must be lowercase).
 ///
 /// Output:
-/// - Returns a `u8` rank: 0 = exact match, 1 = prefix match, 2 = substring match, 3 = no match.
+/// - Returns a `u8` rank: 0 = exact match, 1 = prefix match, 2 = match starting right after a
+///   `-` or `_` (word boundary), 3 = other substring match, 4 = no match.
 ///
 /// Details:
 /// - Expects `query_lower` to be lowercase; the name is lowercased internally.
-/// - Returns 3 (no match) if the query is empty.
+/// - Boundary matches let `python-requests` outrank names that merely contain `requests`.
+/// - Returns 3 if the query is empty, so every name ranks the same.
 #[must_use]
 pub fn match_rank(name: &str, query_lower: &str) -> u8 {
+    if query_lower.is_empty() {
+        return 3;
+    }
     let n = name.to_lowercase();
-    if !query_lower.is_empty() {
-        if n == query_lower {
-            return 0;
-        }
-        if n.starts_with(query_lower) {
-            return 1;
-        }
-        if n.contains(query_lower) {
+    if n == query_lower {
+        return 0;
+    }
+    if n.starts_with(query_lower) {
+        return 1;
+    }
+    let mut found = false;
+    for (pos, _) in n.match_indices(query_lower) {
+        if n[..pos].ends_with(['-', '_']) {
             return 2;
         }
+        found = true;
     }
-    3
+    if found { 3 } else { 4 }
 }
 
 /// What: Convert an optional Unix timestamp (seconds) to a UTC date-time string.
@@ -460,7 +528,138 @@ pub fn ts_to_date(ts: Option<i64>) -> String {
     if t < 0 {
         return t.to_string();
     }
+    format_epoch_seconds(t)
+}
 
+/// What: Convert an optional Unix timestamp (seconds) to a local date-time string.
+///
+/// Inputs:
+/// - `ts`: Optional Unix timestamp in seconds since epoch.
+///
+/// Output:
+/// - `YYYY-MM-DD HH:MM:SS` in the system's local time zone; `None` and negative timestamps
+///   behave exactly like [`ts_to_date`].
+///
+/// Details:
+/// - The UTC offset is looked up once per call for that instant, so dates on either side of a
+///   DST change use the offset that applied at the time.
+/// - Falls back to UTC when the offset cannot be determined.
+#[must_use]
+pub fn ts_to_date_local(ts: Option<i64>) -> String {
+    ts_to_date_with_offset(ts, local_utc_offset_secs)
+}
+
+/// What: Convert an optional Unix timestamp to a date-time string shifted by a UTC offset.
+///
+/// Inputs:
+/// - `ts`: Optional Unix timestamp in seconds since epoch.
+/// - `offset_at`: Returns the UTC offset in seconds for a timestamp, or `None` when unknown.
+///
+/// Output:
+/// - `YYYY-MM-DD HH:MM:SS` of `ts` plus the offset; UTC when the offset is unknown or the
+///   shifted time would precede the epoch.
+///
+/// Details:
+/// - `None` and negative timestamps behave exactly like [`ts_to_date`].
+#[must_use]
+pub fn ts_to_date_with_offset<F>(ts: Option<i64>, offset_at: F) -> String
+where
+    F: FnOnce(i64) -> Option<i32>,
+{
+    let Some(t) = ts else {
+        return String::new();
+    };
+    if t < 0 {
+        return t.to_string();
+    }
+    let shifted = offset_at(t).map_or(t, |offset| t.saturating_add(i64::from(offset)));
+    format_epoch_seconds(if shifted < 0 { t } else { shifted })
+}
+
+/// What: Describe how long ago a timestamp was, in coarse buckets.
+///
+/// Inputs:
+/// - `ts`: Unix timestamp in seconds.
+/// - `now`: Current Unix timestamp in seconds (passed in so results are deterministic).
+///
+/// Output:
+/// - `"just now"`, `"N minutes ago"`, `"N hours ago"`, `"N days ago"`, or `"N weeks ago"`;
+///   the UTC `YYYY-MM-DD` date once `ts` is 8 weeks old or more, or more than a minute ahead of `now`.
+///
+/// Details:
+/// - Counts are truncated (119 seconds is "1 minute ago") and use the singular for 1.
+/// # Examples
+/// ```
+/// use pacsea::util::relative_time;
+///
+/// let now = 1_704_067_200; // 2024-01-01 00:00:00 UTC
+/// assert_eq!(relative_time(now - 30, now), "just now");
+/// assert_eq!(relative_time(now - 3 * 86_400, now), "3 days ago");
+/// assert_eq!(relative_time(now - 60 * 86_400, now), "2023-11-02");
+/// ```
+#[must_use]
+pub fn relative_time(ts: i64, now: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const WEEK: i64 = 7 * DAY;
+
+    let ago = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {unit} ago")
+        } else {
+            format!("{n} {unit}s ago")
+        }
+    };
+    let diff = now.saturating_sub(ts);
+    if diff <= -MINUTE || diff >= 8 * WEEK {
+        let full = ts_to_date(Some(ts));
+        return full
+            .split_once(' ')
+            .map_or_else(|| full.clone(), |(date, _)| date.to_string());
+    }
+    if diff < MINUTE {
+        "just now".to_string()
+    } else if diff < HOUR {
+        ago(diff / MINUTE, "minute")
+    } else if diff < DAY {
+        ago(diff / HOUR, "hour")
+    } else if diff < WEEK {
+        ago(diff / DAY, "day")
+    } else {
+        ago(diff / WEEK, "week")
+    }
+}
+
+/// What: Look up the system's UTC offset at a given instant.
+///
+/// Inputs:
+/// - `t`: Unix timestamp in seconds.
+///
+/// Output:
+/// - Offset in seconds east of UTC, or `None` when `t` is out of chrono's range.
+fn local_utc_offset_secs(t: i64) -> Option<i32> {
+    use chrono::{Offset, TimeZone};
+    let utc = chrono::DateTime::from_timestamp(t, 0)?;
+    Some(
+        chrono::Local
+            .offset_from_utc_datetime(&utc.naive_utc())
+            .fix()
+            .local_minus_utc(),
+    )
+}
+
+/// What: Format non-negative seconds since the epoch as `YYYY-MM-DD HH:MM:SS`.
+///
+/// Inputs:
+/// - `t`: Seconds since 1970-01-01 00:00:00 (no time zone applied).
+///
+/// Output:
+/// - Formatted date-time string.
+///
+/// Details:
+/// - Uses simple year/month loops and does not account for leap seconds.
+fn format_epoch_seconds(t: i64) -> String {
     // Split into days and seconds-of-day
     let mut days = t / 86_400;
     let mut sod = t % 86_400; // 0..86399
@@ -530,6 +729,59 @@ const fn is_leap(y: i32) -> bool {
     (y % 4 == 0 && y % 100 != 0) || (y % 400 == 0)
 }
 
+/// Binary unit labels from bytes up to pebibytes.
+const BINARY_BYTE_UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+/// What: Format a byte count with binary units.
+///
+/// Inputs:
+/// - `bytes`: Number of bytes.
+///
+/// Output:
+/// - `"0 B"`/`"1023 B"` below one KiB, otherwise one decimal place such as `"1.2 MiB"`.
+///
+/// Details:
+/// - Uses 1024-based units like pacman does, scaling up to PiB.
+/// # Examples
+/// ```
+/// use pacsea::util::format_bytes;
+///
+/// assert_eq!(format_bytes(0), "0 B");
+/// assert_eq!(format_bytes(1536), "1.5 KiB");
+/// assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+/// ```
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let mut size = bytes as f64;
+    let mut unit = 0usize;
+    while size >= 1024.0 && unit < BINARY_BYTE_UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", BINARY_BYTE_UNITS[unit])
+}
+
+/// What: Format a signed byte delta with binary units.
+///
+/// Inputs:
+/// - `delta`: Signed number of bytes (e.g. an install size change).
+///
+/// Output:
+/// - `"0 B"` for zero, otherwise [`format_bytes`] of the magnitude prefixed with `+` or `-`
+///   (e.g. `"+1.2 MiB"`, `"-340.0 KiB"`).
+#[must_use]
+pub fn format_delta_bytes(delta: i64) -> String {
+    match delta.cmp(&0) {
+        std::cmp::Ordering::Equal => "0 B".to_string(),
+        std::cmp::Ordering::Greater => format!("+{}", format_bytes(delta.unsigned_abs())),
+        std::cmp::Ordering::Less => format!("-{}", format_bytes(delta.unsigned_abs())),
+    }
+}
+
 /// What: Open a file in the default editor (cross-platform).
 ///
 /// Inputs:
@@ -618,7 +870,8 @@ pub fn open_file(path: &std::path::Path) {
 ///
 /// Details:
 /// - On Windows, uses `cmd /c start`, with fallback to `PowerShell` `Start-Process`.
-/// - On Unix-like systems (Linux/macOS), uses `xdg-open` (Linux) or `open` (macOS).
+/// - On Unix-like systems (Linux/macOS), tries the commands listed in `BROWSER` first, then
+///   `xdg-open` (Linux) or `open` (macOS).
 /// - Spawns the command in a background thread and ignores errors.
 /// - During tests, this is a no-op to avoid opening real browser windows.
 /// # Examples
@@ -668,26 +921,203 @@ pub fn open_url(url: &str) {
             }
             #[cfg(not(target_os = "windows"))]
             {
-                // Try xdg-open first (Linux), then open (macOS)
-                let _ = std::process::Command::new("xdg-open")
-                    .arg(&url)
-                    .stdin(std::process::Stdio::null())
-                    .stdout(std::process::Stdio::null())
-                    .stderr(std::process::Stdio::null())
-                    .spawn()
-                    .or_else(|_| {
-                        std::process::Command::new("open")
-                            .arg(&url)
-                            .stdin(std::process::Stdio::null())
-                            .stdout(std::process::Stdio::null())
-                            .stderr(std::process::Stdio::null())
-                            .spawn()
-                    });
+                let _ = spawn_url_opener(&url);
             }
         });
     }
 }
 
+/// What: Launch a browser for `url` on Unix-like systems.
+///
+/// Inputs:
+/// - `url`: URL to open.
+///
+/// Output:
+/// - `Some(child)` for the launcher that spawned, or `None` when nothing could be started.
+///
+/// Details:
+/// - Tries the `BROWSER` environment variable first, then `xdg-open` (Linux), then `open` (macOS).
+#[cfg(not(target_os = "windows"))]
+fn spawn_url_opener(url: &str) -> Option<std::process::Child> {
+    if let Some(child) = spawn_from_browser_var(&std::env::var("BROWSER").unwrap_or_default(), url)
+    {
+        return Some(child);
+    }
+    ["xdg-open", "open"].into_iter().find_map(|program| {
+        std::process::Command::new(program)
+            .arg(url)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .ok()
+    })
+}
+
+/// What: Launch the first usable browser command from a `BROWSER`-style list.
+///
+/// Inputs:
+/// - `browser`: Value of `BROWSER`, a `:`-separated list of commands.
+/// - `url`: URL appended as the last argument of each command.
+///
+/// Output:
+/// - `Some(child)` for the first command that spawns; `None` when the list is empty or every
+///   command fails to spawn.
+///
+/// Details:
+/// - Empty entries are skipped; an entry may carry its own arguments (e.g. `firefox --new-tab`).
+#[cfg(not(target_os = "windows"))]
+fn spawn_from_browser_var(browser: &str, url: &str) -> Option<std::process::Child> {
+    browser.split(':').find_map(|entry| {
+        let mut parts = entry.split_whitespace();
+        let program = parts.next()?;
+        std::process::Command::new(program)
+            .args(parts)
+            .arg(url)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .ok()
+    })
+}
+
+/// What: Connect and total-time limits (seconds) passed to curl.
+///
+/// Details:
+/// - Rendered as `--connect-timeout` / `--max-time` by [`curl_args_with_timeouts`].
+/// - Parsed from settings as `CONNECT,MAX` (e.g. `10,30`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurlTimeouts {
+    /// Seconds allowed to establish the connection (`--connect-timeout`).
+    pub connect_secs: u64,
+    /// Seconds allowed for the whole transfer (`--max-time`).
+    pub max_secs: u64,
+}
+
+impl CurlTimeouts {
+    /// Built-in global defaults (30s connect, 90s total).
+    pub const DEFAULT: Self = Self {
+        connect_secs: 30,
+        max_secs: 90,
+    };
+
+    /// What: Resolve the global timeouts from settings.
+    ///
+    /// Inputs:
+    /// - `settings`: Settings holding the optional `network_connect_timeout_secs` and
+    ///   `network_max_time_secs` values
+    ///
+    /// Output:
+    /// - The configured values, each falling back to [`Self::DEFAULT`] when unset.
+    #[must_use]
+    pub const fn global(settings: &crate::theme::Settings) -> Self {
+        Self {
+            connect_secs: match settings.network_connect_timeout_secs {
+                Some(v) => v,
+                None => Self::DEFAULT.connect_secs,
+            },
+            max_secs: match settings.network_max_time_secs {
+                Some(v) => v,
+                None => Self::DEFAULT.max_secs,
+            },
+        }
+    }
+
+    /// What: Parse a `CONNECT,MAX` timeout pair from a settings value.
+    ///
+    /// Inputs:
+    /// - `value`: Raw config value such as `"10,30"` (`/` is accepted as separator too)
+    ///
+    /// Output:
+    /// - `Some(CurlTimeouts)` when both parts are positive integers; `None` otherwise
+    ///   (including an empty value, which means "use the default").
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let (connect, max) = value.split_once([',', '/'])?;
+        let connect_secs = connect.trim().parse::<u64>().ok().filter(|v| *v > 0)?;
+        let max_secs = max.trim().parse::<u64>().ok().filter(|v| *v > 0)?;
+        Some(Self {
+            connect_secs,
+            max_secs,
+        })
+    }
+
+    /// What: Render the pair back into its `CONNECT,MAX` config form.
+    ///
+    /// Inputs: None.
+    ///
+    /// Output:
+    /// - String such as `"10,30"`.
+    #[must_use]
+    pub fn to_config_value(self) -> String {
+        format!("{},{}", self.connect_secs, self.max_secs)
+    }
+}
+
+/// What: Network fetch categories whose curl timeouts can be overridden in settings.
+///
+/// Details:
+/// - Each source keeps its historical built-in timeouts when no override is configured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkSource {
+    /// Arch news RSS feed (`network_timeout_news`).
+    News,
+    /// AUR RPC search/info requests (`network_timeout_aur_rpc`).
+    AurRpc,
+    /// PKGBUILD downloads from AUR cgit and `GitLab` (`network_timeout_pkgbuild`).
+    Pkgbuild,
+    /// `status.archlinux.org` API and page (`network_timeout_status`).
+    Status,
+}
+
+impl NetworkSource {
+    /// What: Timeouts used for this source when settings do not override them.
+    ///
+    /// Inputs: None.
+    ///
+    /// Output:
+    /// - News 10s/15s and PKGBUILD 8s/10s (fast-fail UI fetches); AUR RPC and status use
+    ///   [`CurlTimeouts::DEFAULT`].
+    #[must_use]
+    pub const fn builtin_timeouts(self) -> CurlTimeouts {
+        match self {
+            Self::News => CurlTimeouts {
+                connect_secs: 10,
+                max_secs: 15,
+            },
+            Self::Pkgbuild => CurlTimeouts {
+                connect_secs: 8,
+                max_secs: 10,
+            },
+            Self::AurRpc | Self::Status => CurlTimeouts::DEFAULT,
+        }
+    }
+
+    /// What: Resolve the effective timeouts for this source from settings.
+    ///
+    /// Inputs:
+    /// - `settings`: Settings holding the optional per-source overrides
+    ///
+    /// Output:
+    /// - The configured override; otherwise the global timeouts ([`CurlTimeouts::global`]) for
+    ///   AUR RPC and status, and [`Self::builtin_timeouts`] for news and PKGBUILD.
+    #[must_use]
+    pub const fn timeouts(self, settings: &crate::theme::Settings) -> CurlTimeouts {
+        let configured = match self {
+            Self::News => settings.network_timeout_news,
+            Self::AurRpc => settings.network_timeout_aur_rpc,
+            Self::Pkgbuild => settings.network_timeout_pkgbuild,
+            Self::Status => settings.network_timeout_status,
+        };
+        match (configured, self) {
+            (Some(t), _) => t,
+            (None, Self::AurRpc | Self::Status) => CurlTimeouts::global(settings),
+            (None, Self::News | Self::Pkgbuild) => self.builtin_timeouts(),
+        }
+    }
+}
+
 /// Build curl command arguments for fetching a URL.
 ///
 /// On Windows, adds `-k` flag to skip SSL certificate verification to work around
@@ -704,6 +1134,11 @@ pub fn open_url(url: &str) {
 /// Details:
 /// - Base arguments: `-sSLf` (silent, show errors, follow redirects, fail on HTTP errors)
 /// - Windows: Adds `-k` to skip SSL verification
+/// - Uses the global `network_connect_timeout_secs`/`network_max_time_secs` settings for
+///   `--connect-timeout`/`--max-time`, falling back to [`CurlTimeouts::DEFAULT`]; per-source
+///   overrides go through [`curl_args_for`]
+/// - Adds `--proxy` from the `network_proxy` setting or `HTTPS_PROXY`/`ALL_PROXY` (see
+///   [`proxy_args`])
 /// - Adds `--max-filesize 10485760` to cap response bodies at 10 MiB
 /// - Adds User-Agent header to avoid being blocked by APIs
 /// - Appends `extra_args` and `url` at the end
@@ -736,6 +1171,130 @@ pub fn open_url(url: &str) {
 /// ```
 #[must_use]
 pub fn curl_args(url: &str, extra_args: &[&str]) -> Vec<String> {
+    let settings = crate::theme::settings();
+    let timeouts = CurlTimeouts::global(&settings);
+    let extra = with_proxy_args(settings.network_proxy.as_deref(), extra_args);
+    let extra: Vec<&str> = extra.iter().map(String::as_str).collect();
+    curl_args_with(url, &extra, timeouts.connect_secs, timeouts.max_secs)
+}
+
+/// Environment variables consulted for a proxy when `network_proxy` is not configured.
+const PROXY_ENV_VARS: [&str; 4] = ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"];
+
+/// What: Build the curl proxy arguments for a configured or environment proxy.
+///
+/// Inputs:
+/// - `setting`: The `network_proxy` setting (`None` when the key is absent)
+///
+/// Output:
+/// - `["--proxy", url]` for a proxy, `["--noproxy", "*"]` when explicitly disabled, or no
+///   arguments when neither the setting nor the environment names a proxy.
+///
+/// Details:
+/// - An explicit setting wins over the environment; an empty setting also stops curl from
+///   picking up proxy environment variables on its own.
+/// - Environment lookup order: `HTTPS_PROXY`, `https_proxy`, `ALL_PROXY`, `all_proxy`.
+#[must_use]
+pub fn proxy_args(setting: Option<&str>) -> Vec<String> {
+    let proxy = match setting.map(str::trim) {
+        Some("") => return vec!["--noproxy".to_string(), "*".to_string()],
+        Some(url) => Some(url.to_string()),
+        None => PROXY_ENV_VARS.iter().find_map(|var| {
+            std::env::var(var)
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        }),
+    };
+    proxy.map_or_else(Vec::new, |url| vec!["--proxy".to_string(), url])
+}
+
+/// What: Prepend the proxy arguments to caller-supplied curl arguments.
+///
+/// Inputs:
+/// - `setting`: The `network_proxy` setting
+/// - `extra_args`: Additional curl arguments
+///
+/// Output:
+/// - Owned argument list: proxy arguments from [`proxy_args`] followed by `extra_args`.
+fn with_proxy_args(setting: Option<&str>, extra_args: &[&str]) -> Vec<String> {
+    let mut out = proxy_args(setting);
+    out.extend(extra_args.iter().map(ToString::to_string));
+    out
+}
+
+/// What: Build curl arguments with explicit connect/total timeouts in seconds.
+///
+/// Inputs:
+/// - `url`: The URL to fetch
+/// - `extra_args`: Additional curl arguments appended before the URL
+/// - `connect_secs`: Value for `--connect-timeout`
+/// - `max_secs`: Value for `--max-time`
+///
+/// Output:
+/// - Vector of curl arguments ready to pass to `Command::args()`
+///
+/// Details:
+/// - Does not read settings, so callers and tests control the timeouts directly.
+#[must_use]
+pub fn curl_args_with(
+    url: &str,
+    extra_args: &[&str],
+    connect_secs: u64,
+    max_secs: u64,
+) -> Vec<String> {
+    curl_args_with_timeouts(
+        url,
+        extra_args,
+        CurlTimeouts {
+            connect_secs,
+            max_secs,
+        },
+    )
+}
+
+/// What: Build curl arguments using the configured timeouts for a network source.
+///
+/// Inputs:
+/// - `source`: Fetch category whose timeout override should apply
+/// - `url`: The URL to fetch
+/// - `extra_args`: Additional curl arguments appended before the URL
+///
+/// Output:
+/// - Vector of curl arguments ready to pass to `Command::args()`
+///
+/// Details:
+/// - Reads `network_timeout_<source>` from settings and falls back to the source's built-in
+///   timeouts (see [`NetworkSource::builtin_timeouts`]).
+/// - Applies the same proxy handling as [`curl_args`].
+#[must_use]
+pub fn curl_args_for(source: NetworkSource, url: &str, extra_args: &[&str]) -> Vec<String> {
+    let settings = crate::theme::settings();
+    let timeouts = source.timeouts(&settings);
+    let extra = with_proxy_args(settings.network_proxy.as_deref(), extra_args);
+    let extra: Vec<&str> = extra.iter().map(String::as_str).collect();
+    curl_args_with_timeouts(url, &extra, timeouts)
+}
+
+/// What: Build curl arguments with explicit connect/total timeouts.
+///
+/// Inputs:
+/// - `url`: The URL to fetch
+/// - `extra_args`: Additional curl arguments appended before the URL
+/// - `timeouts`: Values for `--connect-timeout` and `--max-time`
+///
+/// Output:
+/// - Vector of curl arguments ready to pass to `Command::args()`
+///
+/// Details:
+/// - Shared implementation behind [`curl_args`], [`curl_args_with`] and [`curl_args_for`]; see
+///   [`curl_args`] for the full list of base arguments.
+#[must_use]
+pub fn curl_args_with_timeouts(
+    url: &str,
+    extra_args: &[&str],
+    timeouts: CurlTimeouts,
+) -> Vec<String> {
     let mut args = vec!["-sSLf".to_string()];
 
     #[cfg(target_os = "windows")]
@@ -744,15 +1303,15 @@ pub fn curl_args(url: &str, extra_args: &[&str]) -> Vec<String> {
         args.push("-k".to_string());
     }
 
-    // Add default timeouts to prevent indefinite hangs:
-    // --connect-timeout 30: fail if connection not established within 30 seconds
-    // --max-time 90: fail if entire operation exceeds 90 seconds
+    // Add timeouts to prevent indefinite hangs (defaults: 30s connect, 90s total):
+    // --connect-timeout: fail if connection not established in time
+    // --max-time: fail if entire operation exceeds the limit
     // --max-filesize 10485760: cap response body to 10 MiB to avoid excessive memory use
     // Note: archlinux.org has DDoS protection that can make responses slower
     args.push("--connect-timeout".to_string());
-    args.push("30".to_string());
+    args.push(timeouts.connect_secs.to_string());
     args.push("--max-time".to_string());
-    args.push("90".to_string());
+    args.push(timeouts.max_secs.to_string());
     args.push("--max-filesize".to_string());
     args.push("10485760".to_string());
 
@@ -794,8 +1353,10 @@ pub fn curl_args(url: &str, extra_args: &[&str]) -> Vec<String> {
 ///
 /// Details:
 /// - Parses format: "name - `old_version` -> name - `new_version`"
-/// - Returns `None` for empty lines or invalid formats
-/// - Uses `rfind` to find the last occurrence of " - " to handle package names that may contain dashes
+/// - Returns `None` for empty lines, invalid formats, or when the names on both sides differ
+/// - Anchors on the first ` -> `, then splits each side at the *first* ` - ` after the package
+///   name token (no whitespace), so epochs (`2:1.0-1`) and ` - ` inside version metadata survive
+/// - The right-hand name is optional (`name - old -> new`)
 /// - Normalizes the right-hand side with the same ` -> ` rules as pacman parsing so a merged
 ///   `oldver -> newver` chain never becomes a single `new_version` string in column three
 /// # Examples
@@ -823,32 +1384,44 @@ pub fn parse_update_entry(line: &str) -> Option<(String, String, String)> {
         return None;
     }
     // Parse format: "name - old_version -> name - new_version"
-    trimmed.find(" -> ").and_then(|arrow_pos| {
-        let before_arrow = trimmed[..arrow_pos].trim();
-        let after_arrow = trimmed[arrow_pos + 4..].trim();
-
-        // Parse "name - old_version" from before_arrow
-        before_arrow.rfind(" - ").and_then(|old_dash_pos| {
-            let name = before_arrow[..old_dash_pos].trim().to_string();
-            let old_version = before_arrow[old_dash_pos + 3..].trim().to_string();
-            if name.is_empty() || old_version.is_empty() {
-                return None;
-            }
+    let (before_arrow, after_arrow) = trimmed.split_once(" -> ")?;
 
-            let rhs_tail = after_arrow.rsplit(" -> ").next()?.trim();
-            if rhs_tail.is_empty() {
-                return None;
-            }
-            let new_version = rhs_tail.rfind(" - ").map_or_else(
-                || rhs_tail.to_string(),
-                |new_dash_pos| rhs_tail[new_dash_pos + 3..].trim().to_string(),
-            );
-            if new_version.is_empty() {
-                return None;
-            }
-            Some((name, old_version, new_version))
-        })
-    })
+    // Parse "name - old_version" from before_arrow
+    let (name, old_version) = split_name_version(before_arrow.trim())?;
+    if old_version.is_empty() {
+        return None;
+    }
+
+    let rhs_tail = after_arrow.trim().rsplit(" -> ").next()?.trim();
+    let new_version = match split_name_version(rhs_tail) {
+        Some((new_name, version)) if new_name == name => version,
+        Some(_) => return None,
+        None => rhs_tail,
+    };
+    if new_version.is_empty() {
+        return None;
+    }
+    Some((
+        name.to_string(),
+        old_version.to_string(),
+        new_version.to_string(),
+    ))
+}
+
+/// What: Split `name - version` at the first ` - ` following a package-name token.
+///
+/// Inputs:
+/// - `s`: Trimmed `name - version` text.
+///
+/// Output:
+/// - `Some((name, version))` when `s` starts with a non-empty, whitespace-free name followed by
+///   ` - `; `None` otherwise.
+fn split_name_version(s: &str) -> Option<(&str, &str)> {
+    let (name, version) = s.split_once(" - ")?;
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((name, version.trim()))
 }
 
 /// What: Return today's UTC date formatted as `YYYYMMDD` using only the standard library.
@@ -1003,8 +1576,63 @@ mod tests {
 
         assert_eq!(match_rank("ripgrep", "ripgrep"), 0);
         assert_eq!(match_rank("ripgrep", "rip"), 1);
-        assert_eq!(match_rank("ripgrep", "pg"), 2);
-        assert_eq!(match_rank("ripgrep", "zzz"), 3);
+        assert_eq!(match_rank("ripgrep", "pg"), 3);
+        assert_eq!(match_rank("ripgrep", "zzz"), 4);
+        assert_eq!(match_rank("ripgrep", ""), 3);
+    }
+
+    #[test]
+    /// What: Ensure a configured repo priority list drives repository ordering.
+    ///
+    /// Inputs:
+    /// - `priority`: `core, cachyos, extra`; sources from listed repos, an unlisted repo, and AUR.
+    ///
+    /// Output:
+    /// - Listed repos follow list order, unlisted repos come next, AUR last; an empty list keeps
+    ///   the default core/extra/other/AUR weights.
+    ///
+    /// Details:
+    /// - Repository names are compared case-insensitively.
+    fn util_repo_order_with_custom_priority() {
+        let official = |repo: &str| Source::Official {
+            repo: repo.into(),
+            arch: "x86_64".into(),
+        };
+        let priority: Vec<String> = ["core", "cachyos", "extra"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(repo_order_with(&official("core"), &priority), 0);
+        assert_eq!(repo_order_with(&official("CachyOS"), &priority), 1);
+        assert_eq!(repo_order_with(&official("extra"), &priority), 2);
+        assert_eq!(repo_order_with(&official("multilib"), &priority), 3);
+        assert_eq!(repo_order_with(&Source::Aur, &priority), 4);
+
+        assert_eq!(repo_order_with(&official("core"), &[]), 0);
+        assert_eq!(repo_order_with(&official("extra"), &[]), 1);
+        assert_eq!(repo_order_with(&official("cachyos"), &[]), 2);
+        assert_eq!(repo_order_with(&Source::Aur, &[]), 3);
+    }
+
+    #[test]
+    /// What: Rank matches at a `-`/`_` boundary between prefix and plain substring matches.
+    ///
+    /// Inputs:
+    /// - `python-requests`/`requests`, `webkit2gtk`/`gtk`, `gtk3`/`gtk`, and `lib_foo-x`/`foo`.
+    ///
+    /// Output:
+    /// - Boundary matches rank 2, plain substrings 3, prefixes 1, so `gtk3` sorts above
+    ///   `webkit2gtk` for `gtk`.
+    ///
+    /// Details:
+    /// - A later boundary occurrence still counts when the first occurrence is mid-word.
+    fn util_match_rank_word_boundaries() {
+        assert_eq!(match_rank("python-requests", "requests"), 2);
+        assert_eq!(match_rank("webkit2gtk", "gtk"), 3);
+        assert_eq!(match_rank("gtk3", "gtk"), 1);
+        assert!(match_rank("gtk3", "gtk") < match_rank("webkit2gtk", "gtk"));
+        assert_eq!(match_rank("lib_foo-x", "foo"), 2);
+        assert_eq!(match_rank("afoo-foo", "foo"), 2);
     }
 
     #[test]
@@ -1041,6 +1669,69 @@ mod tests {
         assert!(fuzzy_match_rank("RIPGREP", "rip").is_some());
     }
 
+    #[test]
+    /// What: Report matched character positions for fuzzy highlighting.
+    ///
+    /// Inputs:
+    /// - ASCII name `ripgrep` with query `rg`, a non-ASCII name `größe-tool` with query `ßt`,
+    ///   and a blank query.
+    ///
+    /// Output:
+    /// - Scores equal `fuzzy_match_rank_with_matcher`; indices are char positions; blank
+    ///   queries return `None`.
+    ///
+    /// Details:
+    /// - `ß` and `ö` are two bytes each, so byte offsets would differ from the char positions.
+    fn util_fuzzy_match_rank_indices_are_char_based() {
+        let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+        let (score, indices) =
+            fuzzy_match_rank_indices("ripgrep", "rg", &matcher).expect("ripgrep matches");
+        assert_eq!(
+            Some(score),
+            fuzzy_match_rank_with_matcher("ripgrep", "rg", &matcher)
+        );
+        assert_eq!(indices.first(), Some(&0));
+        assert_eq!(indices.len(), 2);
+
+        let name = "größe-tool";
+        let (_, indices) = fuzzy_match_rank_indices(name, "ßt", &matcher).expect("ßt matches");
+        let chars: Vec<char> = name.chars().collect();
+        assert_eq!(indices, vec![3, 6]);
+        assert_eq!(chars[indices[0]], 'ß');
+        assert_eq!(chars[indices[1]], 't');
+
+        assert_eq!(fuzzy_match_rank_indices(name, "  ", &matcher), None);
+    }
+
+    #[test]
+    /// What: Format byte counts and deltas with binary units.
+    ///
+    /// Inputs:
+    /// - Zero, values around 1024, and multi-GiB counts; positive, negative, and zero deltas.
+    ///
+    /// Output:
+    /// - Integer bytes below 1 KiB, one decimal place above, and a `0 B` special case.
+    ///
+    /// Details:
+    /// - `i64::MIN` must not overflow when taking the magnitude.
+    fn util_format_bytes_and_deltas() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(1025), "1.0 KiB");
+        assert_eq!(format_bytes(1_258_291), "1.2 MiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+        assert_eq!(format_bytes(3_758_096_384), "3.5 GiB");
+        assert_eq!(format_bytes(1024u64.pow(4) * 2), "2.0 TiB");
+
+        assert_eq!(format_delta_bytes(0), "0 B");
+        assert_eq!(format_delta_bytes(-348_160), "-340.0 KiB");
+        assert_eq!(format_delta_bytes(1_258_291), "+1.2 MiB");
+        assert_eq!(format_delta_bytes(-1023), "-1023 B");
+        assert_eq!(format_delta_bytes(1024), "+1.0 KiB");
+        assert_eq!(format_delta_bytes(i64::MIN), "-8192.0 PiB");
+    }
+
     #[test]
     /// What: Convert timestamps into UTC date strings, including leap-year handling.
     ///
@@ -1075,6 +1766,63 @@ mod tests {
         assert_eq!(ts_to_date(Some(946_684_799)), "1999-12-31 23:59:59");
     }
 
+    #[test]
+    /// What: Apply positive and negative UTC offsets before formatting.
+    ///
+    /// Inputs:
+    /// - Y2K midnight UTC with +05:30 and -08:00 offsets, plus an unknown offset.
+    ///
+    /// Output:
+    /// - Dates shift forward/backward across the day boundary; unknown offsets format as UTC.
+    ///
+    /// Details:
+    /// - `None` and negative timestamps match `ts_to_date`, and a shift before the epoch falls
+    ///   back to UTC.
+    fn util_ts_to_date_with_offset_signs() {
+        let y2k = Some(946_684_800);
+        assert_eq!(
+            ts_to_date_with_offset(y2k, |_| Some(19_800)),
+            "2000-01-01 05:30:00"
+        );
+        assert_eq!(
+            ts_to_date_with_offset(y2k, |_| Some(-28_800)),
+            "1999-12-31 16:00:00"
+        );
+        assert_eq!(ts_to_date_with_offset(y2k, |_| None), ts_to_date(y2k));
+        assert_eq!(ts_to_date_with_offset(None, |_| Some(3600)), "");
+        assert_eq!(ts_to_date_with_offset(Some(-1), |_| Some(3600)), "-1");
+        assert_eq!(
+            ts_to_date_with_offset(Some(0), |_| Some(-3600)),
+            "1970-01-01 00:00:00"
+        );
+    }
+
+    #[test]
+    /// What: Use the offset valid at each instant across a DST change.
+    ///
+    /// Inputs:
+    /// - The last second before and the first second of Central European Summer Time on
+    ///   2024-03-31 (01:00:00 UTC), with a CET/CEST offset lookup.
+    ///
+    /// Output:
+    /// - Local clock jumps from 01:59:59 to 03:00:00.
+    ///
+    /// Details:
+    /// - `ts_to_date_local` uses the system zone, so the rule is injected via the offset lookup.
+    fn util_ts_to_date_with_offset_dst_boundary() {
+        const CEST_START: i64 = 1_711_846_800;
+        let cet_cest = |t: i64| Some(if t >= CEST_START { 7200 } else { 3600 });
+        assert_eq!(
+            ts_to_date_with_offset(Some(CEST_START - 1), cet_cest),
+            "2024-03-31 01:59:59"
+        );
+        assert_eq!(
+            ts_to_date_with_offset(Some(CEST_START), cet_cest),
+            "2024-03-31 03:00:00"
+        );
+        assert_eq!(ts_to_date(Some(CEST_START)), "2024-03-31 01:00:00");
+    }
+
     #[test]
     /// What: Ensure `parse_update_entry` collapses chained ` -> ` on the right-hand side.
     ///
@@ -1099,6 +1847,77 @@ mod tests {
         );
     }
 
+    #[test]
+    /// What: Ensure `relative_time` switches buckets exactly at each boundary.
+    ///
+    /// Inputs:
+    /// - Differences just below and at one minute, hour, day, week, and eight weeks.
+    ///
+    /// Output:
+    /// - Matching bucket text, singular for 1, and the UTC date from eight weeks on.
+    ///
+    /// Details:
+    /// - Timestamps more than a minute in the future also fall back to the date.
+    fn util_relative_time_buckets() {
+        let now = 1_704_067_200; // 2024-01-01 00:00:00 UTC
+        let cases = [
+            (0, "just now"),
+            (59, "just now"),
+            (60, "1 minute ago"),
+            (3_599, "59 minutes ago"),
+            (3_600, "1 hour ago"),
+            (86_399, "23 hours ago"),
+            (86_400, "1 day ago"),
+            (604_799, "6 days ago"),
+            (604_800, "1 week ago"),
+            (8 * 604_800 - 1, "7 weeks ago"),
+            (8 * 604_800, "2023-11-06"),
+            (-59, "just now"),
+            (-60, "2024-01-01"),
+        ];
+        for (diff, expected) in cases {
+            assert_eq!(relative_time(now - diff, now), expected, "diff {diff}");
+        }
+    }
+
+    #[test]
+    /// What: Ensure `parse_update_entry` handles epochs, unusual names, and mismatched names.
+    ///
+    /// Inputs:
+    /// - Epoch versions, a name with digits and a colon, a version with an embedded ` - `,
+    ///   and lines whose two halves name different packages.
+    ///
+    /// Output:
+    /// - Names and versions are split at the first ` - ` after the name; mismatches yield `None`.
+    ///
+    /// Details:
+    /// - A right-hand side without a name still parses as a bare version.
+    fn util_parse_update_entry_epochs_and_names() {
+        let entry = |n: &str, o: &str, v: &str| Some((n.to_string(), o.to_string(), v.to_string()));
+        assert_eq!(
+            parse_update_entry("foo - 2:1.0-1 -> foo - 2:1.1-1"),
+            entry("foo", "2:1.0-1", "2:1.1-1")
+        );
+        assert_eq!(
+            parse_update_entry("lib32-gst:2 - 1:1.24.0-1 -> lib32-gst:2 - 1:1.24.1-1"),
+            entry("lib32-gst:2", "1:1.24.0-1", "1:1.24.1-1")
+        );
+        assert_eq!(
+            parse_update_entry("tool - 1.0 - rc1-1 -> tool - 1.0 - rc2-1"),
+            entry("tool", "1.0 - rc1-1", "1.0 - rc2-1")
+        );
+        assert_eq!(
+            parse_update_entry("foo - 1:2.0-1 -> 1:2.1-1"),
+            entry("foo", "1:2.0-1", "1:2.1-1")
+        );
+        assert_eq!(parse_update_entry("foo - 1.0-1 -> bar - 1.1-1"), None);
+        assert_eq!(parse_update_entry("foo - 1.0-1 -> foo2 - 1.1-1"), None);
+        assert_eq!(
+            parse_update_entry("foo bar - 1.0-1 -> foo bar - 1.1-1"),
+            None
+        );
+    }
+
     #[test]
     /// What: Ensure curl argument defaults include an explicit response-size limit.
     ///
@@ -1117,4 +1936,328 @@ mod tests {
                 .any(|pair| { pair[0] == "--max-filesize" && pair[1] == "10485760" })
         );
     }
+
+    /// What: Extract the `--connect-timeout` / `--max-time` values from curl args.
+    ///
+    /// Inputs:
+    /// - `args`: Arguments produced by one of the `curl_args*` builders
+    ///
+    /// Output:
+    /// - Parsed `CurlTimeouts`, panicking if either flag is missing.
+    fn timeouts_in_args(args: &[String]) -> CurlTimeouts {
+        let value_after = |flag: &str| -> u64 {
+            args.windows(2)
+                .find(|pair| pair[0] == flag)
+                .and_then(|pair| pair[1].parse().ok())
+                .unwrap_or_else(|| panic!("missing {flag} in {args:?}"))
+        };
+        CurlTimeouts {
+            connect_secs: value_after("--connect-timeout"),
+            max_secs: value_after("--max-time"),
+        }
+    }
+
+    #[test]
+    /// What: Ensure each fetcher source builds curl args with its configured timeout override.
+    ///
+    /// Inputs:
+    /// - `Settings` with distinct `network_timeout_*` overrides for every source.
+    ///
+    /// Output:
+    /// - `--connect-timeout` / `--max-time` in the args match the override of each source.
+    ///
+    /// Details:
+    /// - Uses distinct values per source so a mixed-up mapping is caught.
+    fn util_curl_args_use_per_source_timeout_overrides() {
+        let settings = crate::theme::Settings {
+            network_timeout_news: CurlTimeouts::parse("3,4"),
+            network_timeout_aur_rpc: CurlTimeouts::parse("5,6"),
+            network_timeout_pkgbuild: CurlTimeouts::parse("7,8"),
+            network_timeout_status: CurlTimeouts::parse("9,11"),
+            ..crate::theme::Settings::default()
+        };
+        let cases = [
+            (NetworkSource::News, (3, 4)),
+            (NetworkSource::AurRpc, (5, 6)),
+            (NetworkSource::Pkgbuild, (7, 8)),
+            (NetworkSource::Status, (9, 11)),
+        ];
+        for (source, (connect_secs, max_secs)) in cases {
+            let args =
+                curl_args_with_timeouts("https://example.com", &[], source.timeouts(&settings));
+            assert_eq!(
+                timeouts_in_args(&args),
+                CurlTimeouts {
+                    connect_secs,
+                    max_secs
+                },
+                "{source:?}"
+            );
+            assert_eq!(args.last().map(String::as_str), Some("https://example.com"));
+        }
+    }
+
+    #[test]
+    /// What: Ensure absent overrides fall back to the built-in timeouts of each source.
+    ///
+    /// Inputs:
+    /// - Default `Settings` (no `network_timeout_*` values).
+    ///
+    /// Output:
+    /// - News uses 10/15, PKGBUILD 8/10, AUR RPC and status the global 30/90 defaults;
+    ///   unset global settings resolve to `CurlTimeouts::DEFAULT`.
+    fn util_curl_args_default_timeouts_without_overrides() {
+        let settings = crate::theme::Settings::default();
+        let cases = [
+            (NetworkSource::News, (10, 15)),
+            (NetworkSource::AurRpc, (30, 90)),
+            (NetworkSource::Pkgbuild, (8, 10)),
+            (NetworkSource::Status, (30, 90)),
+        ];
+        for (source, (connect_secs, max_secs)) in cases {
+            let args =
+                curl_args_with_timeouts("https://example.com", &[], source.timeouts(&settings));
+            assert_eq!(
+                timeouts_in_args(&args),
+                CurlTimeouts {
+                    connect_secs,
+                    max_secs
+                },
+                "{source:?}"
+            );
+        }
+        assert_eq!(CurlTimeouts::global(&settings), CurlTimeouts::DEFAULT);
+    }
+
+    /// What: Run `f` with the proxy environment variables replaced, restoring them afterwards.
+    ///
+    /// Inputs:
+    /// - `vars`: `(name, value)` pairs to set; every other proxy variable is removed.
+    /// - `f`: Closure run while the environment is modified.
+    ///
+    /// Output:
+    /// - The closure's return value.
+    ///
+    /// Details:
+    /// - Callers must hold `global_test_mutex_lock`.
+    fn with_proxy_env<T>(vars: &[(&str, &str)], f: impl FnOnce() -> T) -> T {
+        let saved: Vec<_> = PROXY_ENV_VARS
+            .iter()
+            .map(|name| (*name, std::env::var_os(name)))
+            .collect();
+        unsafe {
+            for name in PROXY_ENV_VARS {
+                std::env::remove_var(name);
+            }
+            for (name, value) in vars {
+                std::env::set_var(name, value);
+            }
+        }
+        let out = f();
+        unsafe {
+            for (name, value) in saved {
+                match value {
+                    Some(v) => std::env::set_var(name, v),
+                    None => std::env::remove_var(name),
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    /// What: Ensure proxy arguments come from the environment when no setting is configured.
+    ///
+    /// Inputs:
+    /// - `HTTPS_PROXY`/`ALL_PROXY` set, then only `ALL_PROXY`, then nothing; no `network_proxy`.
+    ///
+    /// Output:
+    /// - `HTTPS_PROXY` wins over `ALL_PROXY`; no proxy variables yield no arguments.
+    fn util_proxy_args_from_env_only() {
+        let _guard = crate::global_test_mutex_lock();
+        let both = with_proxy_env(
+            &[
+                ("HTTPS_PROXY", "http://corp:3128"),
+                ("ALL_PROXY", "socks5h://127.0.0.1:1080"),
+            ],
+            || proxy_args(None),
+        );
+        assert_eq!(both, vec!["--proxy", "http://corp:3128"]);
+        let all = with_proxy_env(&[("ALL_PROXY", "socks5h://127.0.0.1:1080")], || {
+            proxy_args(None)
+        });
+        assert_eq!(all, vec!["--proxy", "socks5h://127.0.0.1:1080"]);
+        assert!(with_proxy_env(&[], || proxy_args(None)).is_empty());
+    }
+
+    #[test]
+    /// What: Ensure a configured proxy is used without any proxy environment variables.
+    ///
+    /// Inputs:
+    /// - `network_proxy = socks5://proxy:1080` with a clean environment.
+    ///
+    /// Output:
+    /// - `--proxy socks5://proxy:1080` precedes the caller's extra arguments.
+    fn util_proxy_args_from_setting_only() {
+        let _guard = crate::global_test_mutex_lock();
+        let args = with_proxy_env(&[], || {
+            with_proxy_args(Some(" socks5://proxy:1080 "), &["--compressed"])
+        });
+        assert_eq!(args, vec!["--proxy", "socks5://proxy:1080", "--compressed"]);
+    }
+
+    #[test]
+    /// What: Ensure the setting overrides proxy environment variables.
+    ///
+    /// Inputs:
+    /// - `HTTPS_PROXY` set; `network_proxy` set to another URL, then to an empty value.
+    ///
+    /// Output:
+    /// - The configured URL wins; an empty setting disables proxying with `--noproxy *`.
+    fn util_proxy_args_setting_overrides_env() {
+        let _guard = crate::global_test_mutex_lock();
+        let env = [("HTTPS_PROXY", "http://env-proxy:8080")];
+        let configured = with_proxy_env(&env, || proxy_args(Some("http://configured:3128")));
+        assert_eq!(configured, vec!["--proxy", "http://configured:3128"]);
+        let disabled = with_proxy_env(&env, || proxy_args(Some("")));
+        assert_eq!(disabled, vec!["--noproxy", "*"]);
+    }
+
+    #[test]
+    /// What: Ensure global timeout settings reach the emitted curl arguments.
+    ///
+    /// Inputs:
+    /// - `curl_args_with` with explicit 5/20 seconds; `Settings` with global 4/12 seconds.
+    ///
+    /// Output:
+    /// - `--connect-timeout` / `--max-time` carry the given values; AUR RPC and status follow
+    ///   the global settings while news keeps its built-in timeouts.
+    ///
+    /// Details:
+    /// - A partially set global pair falls back to the default for the missing half.
+    fn util_curl_args_with_overridden_timeouts() {
+        let args = curl_args_with("https://example.com", &["--compressed"], 5, 20);
+        assert_eq!(
+            timeouts_in_args(&args),
+            CurlTimeouts {
+                connect_secs: 5,
+                max_secs: 20
+            }
+        );
+        assert_eq!(
+            &args[args.len() - 2..],
+            &[
+                "--compressed".to_string(),
+                "https://example.com".to_string()
+            ]
+        );
+
+        let settings = crate::theme::Settings {
+            network_connect_timeout_secs: Some(4),
+            network_max_time_secs: Some(12),
+            ..crate::theme::Settings::default()
+        };
+        let global = CurlTimeouts {
+            connect_secs: 4,
+            max_secs: 12,
+        };
+        assert_eq!(CurlTimeouts::global(&settings), global);
+        assert_eq!(NetworkSource::AurRpc.timeouts(&settings), global);
+        assert_eq!(NetworkSource::Status.timeouts(&settings), global);
+        assert_eq!(
+            NetworkSource::News.timeouts(&settings),
+            NetworkSource::News.builtin_timeouts()
+        );
+
+        let partial = crate::theme::Settings {
+            network_max_time_secs: Some(12),
+            ..crate::theme::Settings::default()
+        };
+        assert_eq!(
+            CurlTimeouts::global(&partial),
+            CurlTimeouts {
+                connect_secs: 30,
+                max_secs: 12
+            }
+        );
+    }
+
+    #[test]
+    /// What: Validate parsing of `CONNECT,MAX` timeout values.
+    ///
+    /// Inputs:
+    /// - Valid pairs with `,` and `/` separators plus empty, zero, and malformed values.
+    ///
+    /// Output:
+    /// - Valid pairs parse and round-trip; everything else yields `None`.
+    fn util_curl_timeouts_parse() {
+        let parsed = CurlTimeouts::parse(" 10 , 30 ").expect("valid pair");
+        assert_eq!(
+            parsed,
+            CurlTimeouts {
+                connect_secs: 10,
+                max_secs: 30
+            }
+        );
+        assert_eq!(parsed.to_config_value(), "10,30");
+        assert_eq!(CurlTimeouts::parse("5/20"), CurlTimeouts::parse("5,20"));
+        assert_eq!(CurlTimeouts::parse(""), None);
+        assert_eq!(CurlTimeouts::parse("10"), None);
+        assert_eq!(CurlTimeouts::parse("0,30"), None);
+        assert_eq!(CurlTimeouts::parse("ten,30"), None);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    /// What: Ensure `BROWSER` entries are tried in order with the URL appended.
+    ///
+    /// Inputs:
+    /// - `BROWSER` listing a missing command, an empty entry, and a fake browser script.
+    ///
+    /// Output:
+    /// - The fake script runs and records the URL; an empty list spawns nothing.
+    ///
+    /// Details:
+    /// - Goes through `spawn_url_opener`, the launcher `open_url` runs on its worker thread.
+    /// - Holds `global_test_mutex_lock` and restores `BROWSER` afterwards.
+    fn util_open_url_uses_browser_env() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let _guard = crate::global_test_mutex_lock();
+        let dir = tempfile::TempDir::new().expect("temp dir should be creatable");
+        let out = dir.path().join("url.txt");
+        let script = dir.path().join("fake-browser");
+        std::fs::write(
+            &script,
+            format!("#!/bin/sh\nprintf '%s' \"$1\" > '{}'\n", out.display()),
+        )
+        .expect("write fake browser script");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+            .expect("make fake browser executable");
+
+        let saved = std::env::var_os("BROWSER");
+        unsafe {
+            std::env::set_var(
+                "BROWSER",
+                format!("pacsea-missing-browser::{}", script.display()),
+            );
+        }
+        let child = spawn_url_opener("https://example.org/pkg");
+        unsafe {
+            match saved {
+                Some(v) => std::env::set_var("BROWSER", v),
+                None => std::env::remove_var("BROWSER"),
+            }
+        }
+        let status = child
+            .expect("fake browser should spawn")
+            .wait()
+            .expect("fake browser should exit");
+        assert!(status.success());
+        assert_eq!(
+            std::fs::read_to_string(&out).expect("fake browser should record the URL"),
+            "https://example.org/pkg"
+        );
+        assert!(spawn_from_browser_var(":", "https://example.org").is_none());
+    }
 }
@@ -0,0 +1,146 @@
+//! Completion notifications for long-running package transactions.
+//!
+//! Installs and updates run in an external terminal, so Pacsea can ring the
+//! terminal bell and/or send a desktop notification (via `notify-send`) once a
+//! tracked transaction is detected as finished. The [`CompletionNotifier`]
+//! trait keeps the side effects injectable for tests.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// What: Side-effect interface for completion notifications.
+///
+/// Inputs:
+/// - `summary`/`body`: Notification title and message for desktop notifications.
+///
+/// Output:
+/// - None; implementations perform the notification.
+///
+/// Details:
+/// - Production code uses [`SystemNotifier`]; tests substitute a recording mock.
+pub trait CompletionNotifier {
+    /// Ring the terminal bell.
+    fn bell(&self);
+    /// Show a desktop notification.
+    fn desktop(&self, summary: &str, body: &str);
+}
+
+/// What: Real notifier writing `\a` to stdout and spawning `notify-send`.
+///
+/// Inputs: Satisfies the [`CompletionNotifier`] trait without additional parameters.
+///
+/// Output:
+/// - Emits the bell or a desktop notification on the host system.
+///
+/// Details:
+/// - Desktop notifications are skipped silently when `notify-send` is not installed.
+#[derive(Default)]
+pub struct SystemNotifier;
+
+impl CompletionNotifier for SystemNotifier {
+    fn bell(&self) {
+        let mut out = std::io::stdout();
+        let _ = out.write_all(b"\x07");
+        let _ = out.flush();
+    }
+
+    fn desktop(&self, summary: &str, body: &str) {
+        if !crate::util::command::binary_available("notify-send") {
+            tracing::debug!("notify-send not available; skipping desktop notification");
+            return;
+        }
+        match Command::new("notify-send")
+            .args(["--app-name=Pacsea", summary, body])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            // Reap the child off-thread so the UI never waits on the notification daemon
+            Ok(mut child) => {
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+            }
+            Err(e) => tracing::warn!(error = %e, "failed to spawn notify-send"),
+        }
+    }
+}
+
+/// What: Fire the completion notification selected by a `completion_notify` mode.
+///
+/// Inputs:
+/// - `notifier`: Notification backend.
+/// - `mode`: `off`, `bell`, `desktop`, or `both`.
+/// - `summary`: Desktop notification title.
+/// - `body`: Desktop notification message.
+///
+/// Output:
+/// - None; notifications are emitted through `notifier`.
+///
+/// Details:
+/// - Unknown modes behave like `off`.
+pub fn notify_completion_with<N: CompletionNotifier + ?Sized>(
+    notifier: &N,
+    mode: &str,
+    summary: &str,
+    body: &str,
+) {
+    let (bell, desktop) = match mode {
+        "bell" => (true, false),
+        "desktop" => (false, true),
+        "both" => (true, true),
+        _ => (false, false),
+    };
+    if bell {
+        notifier.bell();
+    }
+    if desktop {
+        notifier.desktop(summary, body);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct Recorder(RefCell<Vec<String>>);
+
+    impl CompletionNotifier for Recorder {
+        fn bell(&self) {
+            self.0.borrow_mut().push("bell".into());
+        }
+        fn desktop(&self, summary: &str, body: &str) {
+            self.0
+                .borrow_mut()
+                .push(format!("desktop:{summary}:{body}"));
+        }
+    }
+
+    #[test]
+    /// What: Verify each `completion_notify` mode triggers the matching notifications.
+    ///
+    /// Inputs:
+    /// - Recording notifier and the modes `off`, `bell`, `desktop`, `both`, and an unknown value.
+    ///
+    /// Output:
+    /// - Only the selected notifications are recorded; `off`/unknown record nothing.
+    ///
+    /// Details:
+    /// - Unknown modes must stay silent rather than guessing a default.
+    fn notify_completion_respects_mode() {
+        for (mode, expected) in [
+            ("off", vec![]),
+            ("loud", vec![]),
+            ("bell", vec!["bell"]),
+            ("desktop", vec!["desktop:Pacsea:done"]),
+            ("both", vec!["bell", "desktop:Pacsea:done"]),
+        ] {
+            let rec = Recorder::default();
+            notify_completion_with(&rec, mode, "Pacsea", "done");
+            assert_eq!(*rec.0.borrow(), expected, "mode {mode}");
+        }
+    }
+}
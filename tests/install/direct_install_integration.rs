@@ -256,6 +256,7 @@ fn integration_direct_install_executor_request() {
         items,
         password,
         dry_run: app.dry_run,
+        aur_helper: None,
     });
 
     // Verify executor request
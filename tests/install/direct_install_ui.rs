@@ -115,6 +115,7 @@ fn ui_direct_install_preflight_exec_structure() {
             items,
             password: None,
             dry_run: false,
+            aur_helper: None,
         }),
         ..Default::default()
     };
@@ -260,6 +261,7 @@ fn ui_direct_install_modal_transition() {
         items,
         password,
         dry_run: app.dry_run,
+        aur_helper: None,
     });
 
     // Verify transition to PreflightExec
@@ -494,6 +494,7 @@ fn integration_executor_request_install() {
         items: items.clone(),
         password: Some("testpassword".to_string().into()),
         dry_run: false,
+        aur_helper: None,
     };
 
     match request {
@@ -501,6 +502,7 @@ fn integration_executor_request_install() {
             items,
             password,
             dry_run,
+            ..
         } => {
             assert_eq!(items.len(), 2);
             assert_eq!(items[0].name, "ripgrep");
@@ -140,6 +140,7 @@ fn integration_executor_request_creation() {
         items,
         password: Some("testpass".to_string().into()),
         dry_run: false,
+        aur_helper: None,
     };
 
     match request {
@@ -147,6 +148,7 @@ fn integration_executor_request_creation() {
             items: req_items,
             password,
             dry_run,
+            ..
         } => {
             assert_eq!(req_items.len(), 2);
             // Avoid logging password on assert failure.
@@ -344,6 +346,7 @@ fn integration_empty_password_handling() {
         items,
         password: None,
         dry_run: false,
+        aur_helper: None,
     };
 
     match request {
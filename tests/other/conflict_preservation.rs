@@ -184,6 +184,8 @@ fn create_pacsea_bin_dependencies() -> Vec<crate_root::state::modal::DependencyI
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // pacsea-bin's conflict with pacsea-git
         crate_root::state::modal::DependencyInfo {
@@ -197,6 +199,8 @@ fn create_pacsea_bin_dependencies() -> Vec<crate_root::state::modal::DependencyI
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // pacsea-bin's regular dependency (to test that conflicts aren't overwritten by deps)
         crate_root::state::modal::DependencyInfo {
@@ -210,6 +214,8 @@ fn create_pacsea_bin_dependencies() -> Vec<crate_root::state::modal::DependencyI
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ]
 }
@@ -246,6 +252,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // CRITICAL TEST CASE: jujutsu-git also depends on "pacsea" (which is already a CONFLICT from pacsea-bin)
         // This tests that when merging, the existing conflict status is preserved
@@ -261,6 +269,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // jujutsu-git also depends on common-dep (same as pacsea-bin)
         // This tests that pacsea-bin's regular dependency entries merge correctly
@@ -275,6 +285,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // jujutsu-git's unique dependency
         crate_root::state::modal::DependencyInfo {
@@ -288,6 +300,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ]
 }
@@ -670,6 +684,8 @@ fn test_cached_conflicts_preserved_in_cache_merge() {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         }],
         ..Default::default()
     };
@@ -689,6 +705,8 @@ fn test_cached_conflicts_preserved_in_cache_merge() {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         });
 
     // Step 3: Simulate what happens when the UI displays dependencies
@@ -60,6 +60,7 @@ fn integration_dry_run_install() {
         items,
         password: None,
         dry_run: true,
+        aur_helper: None,
     };
 
     match request {
@@ -326,6 +327,7 @@ fn integration_dry_run_all_false() {
         items: vec![],
         password: None,
         dry_run: false,
+        aur_helper: None,
     };
 
     let remove_req = ExecutorRequest::Remove {
@@ -433,6 +435,7 @@ fn integration_dry_run_state_to_request() {
         items: vec![],
         password: None,
         dry_run: app.dry_run,
+        aur_helper: None,
     };
 
     match request {
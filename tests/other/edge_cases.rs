@@ -50,6 +50,7 @@ fn integration_edge_case_install_empty_list() {
         items: vec![],
         password: None,
         dry_run: false,
+        aur_helper: None,
     };
 
     match request {
@@ -261,6 +262,7 @@ fn integration_edge_case_concurrent_operation_check() {
             items: vec![create_test_package("pkg1", Source::Aur)],
             password: None,
             dry_run: false,
+            aur_helper: None,
         }),
         ..Default::default()
     };
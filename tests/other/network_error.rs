@@ -306,6 +306,7 @@ fn integration_network_error_install_request() {
         items,
         password: Some("testpassword".to_string().into()),
         dry_run: false,
+        aur_helper: None,
     };
 
     match request {
@@ -1009,6 +1009,7 @@ fn integration_preflight_install_passwordless_sudo_creates_request() {
             items,
             password: None, // Passwordless sudo
             dry_run: true,
+            aur_helper: None,
         };
 
         match request {
@@ -1016,6 +1017,7 @@ fn integration_preflight_install_passwordless_sudo_creates_request() {
                 items: req_items,
                 password,
                 dry_run,
+                ..
             } => {
                 assert_eq!(req_items.len(), 1);
                 assert!(
@@ -1050,6 +1052,7 @@ fn integration_preflight_install_requires_password_when_deactivated() {
             items,
             password: Some("testpassword".to_string().into()),
             dry_run: true,
+            aur_helper: None,
         };
 
         match request {
@@ -1127,11 +1130,13 @@ fn integration_all_executor_requests_support_password() {
         items: vec![create_official_package("pkg")],
         password: Some("pass".to_string().into()),
         dry_run: true,
+        aur_helper: None,
     };
     let install_without = ExecutorRequest::Install {
         items: vec![create_official_package("pkg")],
         password: None,
         dry_run: true,
+        aur_helper: None,
     };
 
     // Remove with and without password
@@ -65,6 +65,8 @@ fn setup_test_app_state(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "aur-dep-1".to_string(),
@@ -75,6 +77,8 @@ fn setup_test_app_state(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ];
 
@@ -55,6 +55,8 @@ fn setup_initial_app_state(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         }],
         // Files are not cached yet (still resolving)
         install_list_files: vec![],
@@ -228,6 +230,8 @@ fn simulate_background_resolution_complete(app: &mut crate_root::state::AppState
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         });
 
     // Files resolution completes - update cache
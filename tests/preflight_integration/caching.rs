@@ -55,6 +55,8 @@ fn populate_test_dependencies(app: &mut crate_root::state::AppState) {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "test-conflict".to_string(),
@@ -69,6 +71,8 @@ fn populate_test_dependencies(app: &mut crate_root::state::AppState) {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "test-dep-2".to_string(),
@@ -84,6 +88,8 @@ fn populate_test_dependencies(app: &mut crate_root::state::AppState) {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ];
 }
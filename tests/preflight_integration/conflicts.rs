@@ -25,6 +25,8 @@ fn create_test_dependencies() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "pkg1-dep".to_string(),
@@ -37,6 +39,8 @@ fn create_test_dependencies() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // Package 2 dependencies - includes conflict with common-dep
         crate_root::state::modal::DependencyInfo {
@@ -52,6 +56,8 @@ fn create_test_dependencies() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "pkg2-dep".to_string(),
@@ -64,6 +70,8 @@ fn create_test_dependencies() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // AUR package dependency
         crate_root::state::modal::DependencyInfo {
@@ -75,6 +83,8 @@ fn create_test_dependencies() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ]
 }
@@ -625,6 +635,8 @@ fn create_pacsea_bin_dependencies() -> Vec<crate_root::state::modal::DependencyI
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // pacsea-bin's conflict with pacsea-git
         crate_root::state::modal::DependencyInfo {
@@ -638,6 +650,8 @@ fn create_pacsea_bin_dependencies() -> Vec<crate_root::state::modal::DependencyI
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // pacsea-bin's regular dependency (to test that conflicts aren't overwritten by deps)
         crate_root::state::modal::DependencyInfo {
@@ -651,6 +665,8 @@ fn create_pacsea_bin_dependencies() -> Vec<crate_root::state::modal::DependencyI
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ]
 }
@@ -680,6 +696,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // jujutsu-git also depends on common-dep (same as pacsea-bin)
         // This tests that pacsea-bin's conflict entries aren't overwritten
@@ -694,6 +712,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         // jujutsu-git's unique dependency
         crate_root::state::modal::DependencyInfo {
@@ -707,6 +727,8 @@ fn create_jujutsu_git_dependencies() -> Vec<crate_root::state::modal::Dependency
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ]
 }
@@ -283,6 +283,8 @@ async fn process_and_verify_deps_result(
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
     let _ = deps_res_tx.send(deps_result.clone());
 
@@ -374,6 +376,9 @@ async fn process_and_verify_summary_result(
             service_restart_units: vec![],
             summary_warnings: vec![],
             summary_notes: vec![],
+            orphaned_dependencies: vec![],
+            conflicts: vec![],
+            hook_notes: vec![],
         },
         header: crate_root::state::modal::PreflightHeaderChips {
             package_count: test_packages.len(),
@@ -629,6 +634,8 @@ async fn preflight_cancellation_aborts_in_flight_work() {
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
     let _ = deps_res_tx.send(deps_result.clone());
 
@@ -41,6 +41,8 @@ fn setup_test_app_state() -> crate_root::state::AppState {
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
 
     // Pre-populate cache with files (successful)
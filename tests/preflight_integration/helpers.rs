@@ -32,6 +32,8 @@ fn merge_dependencies(
                 depends_on: Vec::new(),
                 is_core: dep.is_core,
                 is_system: dep.is_system,
+                optional: false,
+                description: None,
             }
         });
 
@@ -78,6 +78,8 @@ fn populate_dependencies(
                     depends_on: Vec::new(),
                     is_core: false,
                     is_system: false,
+                    optional: false,
+                    description: None,
                 });
             expected_dep_count += 1;
         }
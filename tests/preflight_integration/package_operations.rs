@@ -52,6 +52,8 @@ fn setup_first_package_with_cache(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "first-dep-2".to_string(),
@@ -64,6 +66,8 @@ fn setup_first_package_with_cache(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ];
 
@@ -161,6 +165,8 @@ fn setup_second_package_with_conflict(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         });
 
     app.install_list_deps
@@ -177,6 +183,8 @@ fn setup_second_package_with_conflict(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         });
 
     app.install_list_files
@@ -760,6 +768,8 @@ fn setup_independent_loading_test_data(
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
 
     // First package's files (loaded)
@@ -822,6 +832,8 @@ fn setup_independent_loading_test_data(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         });
 
     // Add a conflict: second package requires a different version of first-dep-1
@@ -839,6 +851,8 @@ fn setup_independent_loading_test_data(
             depends_on: Vec::new(),
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         });
 
     // Second package's files (loaded independently)
@@ -28,6 +28,8 @@ fn create_test_reverse_deps() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: vec!["test-package-1".to_string()],
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
         crate_root::state::modal::DependencyInfo {
             name: "dependent-package-2".to_string(),
@@ -42,6 +44,8 @@ fn create_test_reverse_deps() -> Vec<crate_root::state::modal::DependencyInfo> {
             depends_on: vec!["test-package-1".to_string()],
             is_core: false,
             is_system: false,
+            optional: false,
+            description: None,
         },
     ]
 }
@@ -58,6 +58,8 @@ fn setup_mixed_completion_state(
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
 
     // Pre-populate cache with files (loaded)
@@ -50,6 +50,8 @@ fn preflight_tab_switching_order_variations() {
         depends_on: Vec::new(),
         is_core: false,
         is_system: false,
+        optional: false,
+        description: None,
     }];
 
     app.install_list_files = vec![crate_root::state::modal::PackageFileInfo {